@@ -509,6 +509,7 @@ impl Render for StoryWorkspace {
         let drawer_layer = Root::render_drawer_layer(window, cx);
         let modal_layer = Root::render_modal_layer(window, cx);
         let notification_layer = Root::render_notification_layer(window, cx);
+        let overlay_layer = Root::render_overlay_layer(window, cx);
 
         div()
             .id("story-workspace")
@@ -524,6 +525,7 @@ impl Render for StoryWorkspace {
             .children(drawer_layer)
             .children(modal_layer)
             .children(notification_layer)
+            .children(overlay_layer)
     }
 }
 
@@ -9,7 +9,7 @@ use std::{
 use anyhow::Ok;
 use gpui::{prelude::FluentBuilder, *};
 use gpui_component::{
-    ActiveTheme, ContextModal, IconName, IndexPath, Selectable, Sizable,
+    ActiveTheme, ContextModal, Icon, IconName, IndexPath, Selectable, Sizable,
     button::{Button, ButtonVariants as _},
     dropdown::{Dropdown, DropdownEvent, DropdownState},
     h_flex,
@@ -48,6 +48,7 @@ pub struct Example {
     line_number: bool,
     need_update: bool,
     soft_wrap: bool,
+    show_invisibles: bool,
     lsp_store: ExampleLspStore,
     _subscriptions: Vec<Subscription>,
     _lint_task: Task<()>,
@@ -676,6 +677,10 @@ impl Example {
                     hard_tabs: false,
                 })
                 .soft_wrap(false)
+                .highlight_trailing_whitespace(true)
+                .indent_guides(true)
+                .bracket_colorization(true)
+                .sticky_scroll(true)
                 .default_value(default_language.1)
                 .placeholder("Enter your code here...");
 
@@ -727,6 +732,7 @@ impl Example {
             line_number: true,
             need_update: false,
             soft_wrap: false,
+            show_invisibles: false,
             lsp_store,
             _subscriptions,
             _lint_task: Task::ready(()),
@@ -936,7 +942,91 @@ impl Render for Example {
                                         .label("Soft Wrap")
                                         .selected(self.soft_wrap)
                                         .on_click(cx.listener(Self::toggle_soft_wrap))
-                                }),
+                                })
+                                .child(
+                                    Button::new("show-invisibles")
+                                        .ghost()
+                                        .xsmall()
+                                        .label("Invisibles")
+                                        .selected(self.show_invisibles)
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.show_invisibles = !this.show_invisibles;
+                                            this.editor.update(cx, |state, cx| {
+                                                state.set_show_invisibles(
+                                                    this.show_invisibles,
+                                                    window,
+                                                    cx,
+                                                );
+                                            });
+                                            cx.notify();
+                                        })),
+                                )
+                                .child(
+                                    Button::new("trim-trailing-whitespace")
+                                        .ghost()
+                                        .xsmall()
+                                        .label("Trim Trailing Whitespace")
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.editor.update(cx, |state, cx| {
+                                                state.trim_trailing_whitespace(window, cx);
+                                            });
+                                        })),
+                                )
+                                .child(
+                                    Button::new("bookmarks")
+                                        .ghost()
+                                        .xsmall()
+                                        .icon(IconName::Bookmark)
+                                        .label("Bookmarks")
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.editor.update(cx, |state, cx| {
+                                                state.show_bookmarks(window, cx);
+                                            });
+                                        })),
+                                )
+                                .child(
+                                    Button::new("annotate-line")
+                                        .ghost()
+                                        .xsmall()
+                                        .label("Annotate Line")
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.editor.update(cx, |state, cx| {
+                                                let row = state.cursor_position().line as usize;
+                                                // The rows of the decorations are moved by the edits.
+                                                let annotated = state
+                                                    .decorations()
+                                                    .find(|(key, decoration)| {
+                                                        key.starts_with("annotate:")
+                                                            && decoration.row() == row
+                                                    })
+                                                    .map(|(key, _)| key.clone());
+                                                if let Some(key) = annotated {
+                                                    state.remove_decoration(&key, window, cx);
+                                                    return;
+                                                }
+
+                                                let decoration = input::LineDecoration::new(row)
+                                                    .background(cx.theme().info.opacity(0.1))
+                                                    .gutter_icon(
+                                                        Icon::new(IconName::Info)
+                                                            .text_color(cx.theme().info),
+                                                    )
+                                                    .after_line(|_, cx| {
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(cx.theme().muted_foreground)
+                                                            .child("Annotated by the host")
+                                                    })
+                                                    .margin_text("just now");
+                                                state.set_decoration(
+                                                    format!("annotate:{}", row),
+                                                    decoration,
+                                                    window,
+                                                    cx,
+                                                );
+                                            });
+                                        })),
+                                ),
                         )
                         .child({
                             let position = self.editor.read(cx).cursor_position();
@@ -3,13 +3,17 @@ use gpui_component::{
     highlighter::Language,
     input::{InputEvent, InputState, TabSize, TextInput},
     resizable::{h_resizable, resizable_panel, ResizableState},
-    text::TextView,
+    scroll::ScrollSync,
+    text::{FenceOutput, TextView, TextViewStyle},
+    v_flex, ActiveTheme as _,
 };
 use story::Assets;
 
 pub struct Example {
     input_state: Entity<InputState>,
     resizable_state: Entity<ResizableState>,
+    preview_scroll_handle: ScrollHandle,
+    scroll_sync: ScrollSync,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -30,12 +34,19 @@ impl Example {
                 .default_value(EXAMPLE)
         });
         let resizable_state = ResizableState::new(cx);
+        let preview_scroll_handle = ScrollHandle::new();
+        // Hold `alt` while scrolling to scroll the editor or the preview alone.
+        let scroll_sync = ScrollSync::new()
+            .link(input_state.clone())
+            .link(preview_scroll_handle.clone());
 
         let _subscriptions = vec![cx.subscribe(&input_state, |_, _, _: &InputEvent, _| {})];
 
         Self {
             resizable_state,
             input_state,
+            preview_scroll_handle,
+            scroll_sync,
             _subscriptions,
         }
     }
@@ -47,6 +58,8 @@ impl Example {
 
 impl Render for Example {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.scroll_sync.sync(window, cx);
+
         h_resizable("container", self.resizable_state.clone())
             .child(
                 resizable_panel().child(
@@ -71,6 +84,7 @@ impl Render for Example {
                         .size_full()
                         .p_5()
                         .overflow_y_scroll()
+                        .track_scroll(&self.preview_scroll_handle)
                         .child(
                             TextView::markdown(
                                 "preview",
@@ -78,7 +92,39 @@ impl Render for Example {
                                 window,
                                 cx,
                             )
-                            .selectable(),
+                            .style(TextViewStyle::default().code_block_line_numbers(true))
+                            .selectable()
+                            .fence_renderer("mermaid", |code, _, cx| {
+                                // A fake renderer, a real app may render the diagram to an SVG image.
+                                let code = code.clone();
+                                cx.spawn(async move |cx| {
+                                    cx.background_executor()
+                                        .timer(std::time::Duration::from_millis(500))
+                                        .await;
+                                    Ok(FenceOutput::element(move |_, cx| {
+                                        v_flex()
+                                            .p_3()
+                                            .gap_1()
+                                            .border_1()
+                                            .border_color(cx.theme().border)
+                                            .rounded(cx.theme().radius)
+                                            .text_sm()
+                                            .children(code.lines().map(|line| {
+                                                SharedString::from(line.trim().to_string())
+                                            }))
+                                    }))
+                                })
+                            })
+                            .on_task_toggle({
+                                let input_state = self.input_state.clone();
+                                move |event, window, cx| {
+                                    input_state.update(cx, |state, cx| {
+                                        let mut text = state.value().to_string();
+                                        text.replace_range(event.range.clone(), event.marker());
+                                        state.set_value(text, window, cx);
+                                    });
+                                }
+                            }),
                         ),
                 ),
             )
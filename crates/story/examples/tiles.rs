@@ -186,6 +186,7 @@ impl StoryTiles {
                 DockEvent::DragDrop(item) => {
                     println!("drag drop: {:?}", item);
                 }
+                _ => {}
             },
         )
         .detach();
@@ -399,6 +400,7 @@ impl Render for StoryTiles {
         let drawer_layer = Root::render_drawer_layer(window, cx);
         let modal_layer = Root::render_modal_layer(window, cx);
         let notification_layer = Root::render_notification_layer(window, cx);
+        let overlay_layer = Root::render_overlay_layer(window, cx);
 
         div()
             .font_family(".SystemUIFont")
@@ -413,6 +415,7 @@ impl Render for StoryTiles {
             .children(drawer_layer)
             .children(modal_layer)
             .children(notification_layer)
+            .children(overlay_layer)
     }
 }
 
@@ -3,8 +3,11 @@ use gpui::{
     Styled, Window,
 };
 use gpui_component::{
-    avatar::Avatar, badge::Badge, dock::PanelControl, v_flex, ActiveTheme as _, Icon, IconName,
-    Sizable as _,
+    avatar::Avatar,
+    badge::{Badge, BadgePosition},
+    dock::PanelControl,
+    tag::TagVariant,
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _,
 };
 
 use crate::section;
@@ -116,6 +119,51 @@ impl Render for BadgeStory {
                         Avatar::new().src("https://avatars.githubusercontent.com/u/5518?v=4"),
                     )),
             )
+            .child(
+                section("Badge with variant")
+                    .max_w_md()
+                    .child(
+                        Badge::new()
+                            .count(5)
+                            .variant(TagVariant::Primary)
+                            .child(Icon::new(IconName::Bell).large()),
+                    )
+                    .child(
+                        Badge::new()
+                            .count(12)
+                            .variant(TagVariant::Success)
+                            .child(Icon::new(IconName::Inbox).large()),
+                    )
+                    .child(
+                        Badge::new()
+                            .count(1000)
+                            .max(999)
+                            .variant(TagVariant::Warning)
+                            .child(Icon::new(IconName::Inbox).large()),
+                    ),
+            )
+            .child(
+                section("Badge position")
+                    .max_w_md()
+                    .child(
+                        Badge::new()
+                            .dot()
+                            .position(BadgePosition::TopLeft)
+                            .child(Icon::new(IconName::Bell).large()),
+                    )
+                    .child(
+                        Badge::new()
+                            .dot()
+                            .position(BadgePosition::BottomLeft)
+                            .child(Icon::new(IconName::Bell).large()),
+                    )
+                    .child(
+                        Badge::new()
+                            .dot()
+                            .position(BadgePosition::BottomRight)
+                            .child(Icon::new(IconName::Bell).large()),
+                    ),
+            )
             .child(
                 section("Complex use")
                     .max_w_md()
@@ -4,7 +4,7 @@ use gpui::{
 };
 
 use gpui_component::{
-    ActiveTheme, Disableable as _, Icon, IconName, Selectable as _, Sizable as _, Theme,
+    ActiveTheme, Disableable as _, Icon, IconName, Selectable as _, Side, Sizable as _, Theme,
     button::{Button, ButtonCustomVariant, ButtonGroup, ButtonVariants as _, DropdownButton},
     checkbox::Checkbox,
     h_flex, v_flex,
@@ -287,6 +287,17 @@ impl Render for ButtonStory {
                             .when(compact, |this| this.compact())
                             .on_click(Self::on_click),
                     )
+                    .child(
+                        Button::new("button-icon-next")
+                            .label("Next")
+                            .icon(IconName::ArrowRight)
+                            .icon_position(Side::Right)
+                            .disabled(disabled)
+                            .selected(selected)
+                            .loading(loading)
+                            .when(compact, |this| this.compact())
+                            .on_click(Self::on_click),
+                    )
                     .child(
                         Button::new("button-icon-4")
                             .primary()
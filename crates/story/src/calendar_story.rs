@@ -1,10 +1,12 @@
+use chrono::{Datelike as _, NaiveDate, Weekday};
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement as _,
-    Render, Styled as _, Window,
+    Render, Styled as _, Subscription, Window,
 };
 use gpui_component::{
-    calendar::{Calendar, CalendarState},
-    v_flex,
+    calendar::{Calendar, CalendarEvent, CalendarState, DayDecoration},
+    label::Label,
+    v_flex, ActiveTheme as _,
 };
 
 use crate::section;
@@ -14,6 +16,10 @@ pub struct CalendarStory {
     calendar: Entity<CalendarState>,
     calendar_wide: Entity<CalendarState>,
     calendar_with_disabled_matcher: Entity<CalendarState>,
+    calendar_with_decorations: Entity<CalendarState>,
+    calendar_with_week_numbers: Entity<CalendarState>,
+    hovered_date: Option<NaiveDate>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl super::Story for CalendarStory {
@@ -40,12 +46,49 @@ impl CalendarStory {
         let calendar_wide = cx.new(|cx| CalendarState::new(window, cx));
         let calendar_with_disabled_matcher =
             cx.new(|cx| CalendarState::new(window, cx).disabled_matcher(vec![0, 3, 6]));
+        let calendar_with_decorations = cx.new(|cx| {
+            let red = cx.theme().red;
+            let green = cx.theme().green;
+            CalendarState::new(window, cx).day_decorator(move |date: &NaiveDate| {
+                if date.day() == 1 {
+                    Some(DayDecoration::new().color(red).tooltip("Holiday"))
+                } else if date.weekday() == Weekday::Sun {
+                    Some(DayDecoration::new().disabled(true).tooltip("Closed"))
+                } else if date.day() % 5 == 0 {
+                    Some(DayDecoration::new().dot(green).tooltip("Available"))
+                } else {
+                    None
+                }
+            })
+        });
+
+        let today = chrono::Local::now().naive_local().date();
+        let calendar_with_week_numbers = cx.new(|cx| {
+            CalendarState::new(window, cx)
+                .week_start(Weekday::Mon)
+                .min_date(today - chrono::Duration::days(30))
+                .max_date(today + chrono::Duration::days(60))
+        });
+
+        let _subscriptions = vec![cx.subscribe(
+            &calendar_with_decorations,
+            |this, _, ev: &CalendarEvent, cx| {
+                if let CalendarEvent::Hovered(date) = ev {
+                    this.hovered_date = *date;
+                    cx.notify();
+                }
+            },
+        )];
 
         Self {
             calendar,
             calendar_wide,
             calendar_with_disabled_matcher,
+            calendar_with_decorations,
+            calendar_with_week_numbers,
+            hovered_date: None,
             focus_handle: cx.focus_handle(),
+            _subscriptions,
         }
     }
 }
@@ -75,5 +118,19 @@ impl Render for CalendarStory {
                     .max_w_md()
                     .child(Calendar::new(&self.calendar_with_disabled_matcher)),
             )
+            .child(
+                section("With Day Decorations")
+                    .max_w_md()
+                    .child(Calendar::new(&self.calendar_with_decorations))
+                    .child(Label::new(match self.hovered_date {
+                        Some(date) => format!("Hovered: {}", date),
+                        None => "Hovered: -".to_string(),
+                    })),
+            )
+            .child(
+                section("Week Numbers, Monday first, -30 to +60 days")
+                    .max_w_md()
+                    .child(Calendar::new(&self.calendar_with_week_numbers).week_numbers(true)),
+            )
     }
 }
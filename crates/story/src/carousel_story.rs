@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use gpui::{
+    div, px, App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render,
+    SharedString, Styled, Window,
+};
+
+use gpui_component::{
+    carousel::{Carousel, CarouselState, ImageGallery},
+    v_flex, ColorName,
+};
+
+use crate::section;
+
+const IMAGES: [&str; 5] = [
+    "https://picsum.photos/id/10/800/480",
+    "https://picsum.photos/id/15/800/480",
+    "https://picsum.photos/id/29/800/480",
+    "https://picsum.photos/id/42/800/480",
+    "https://picsum.photos/id/58/800/480",
+];
+
+pub struct CarouselStory {
+    focus_handle: gpui::FocusHandle,
+    carousel: Entity<CarouselState>,
+    autoplay: Entity<CarouselState>,
+    gallery: Entity<CarouselState>,
+}
+
+impl super::Story for CarouselStory {
+    fn title() -> &'static str {
+        "Carousel"
+    }
+
+    fn description() -> &'static str {
+        "A slideshow to cycle through a series of content, and an image gallery with lightbox."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl CarouselStory {
+    pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            carousel: cx.new(|_| CarouselState::new().looped(false)),
+            autoplay: cx.new(|cx| CarouselState::new().autoplay(Duration::from_secs(3), cx)),
+            gallery: cx.new(|_| CarouselState::new()),
+        }
+    }
+
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+}
+impl Focusable for CarouselStory {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+impl Render for CarouselStory {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        let colors = [
+            ColorName::Red,
+            ColorName::Orange,
+            ColorName::Green,
+            ColorName::Blue,
+            ColorName::Purple,
+        ];
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Carousel")
+                    .max_w_xl()
+                    .child(
+                        Carousel::new(&self.carousel).slides(colors.len(), move |ix, _, _| {
+                            div()
+                                .size_full()
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .text_2xl()
+                                .bg(colors[ix].scale(500))
+                                .text_color(gpui::white())
+                                .child(SharedString::from(format!("Slide {}", ix + 1)))
+                        }),
+                    ),
+            )
+            .child(
+                section("Autoplay").max_w_xl().child(
+                    Carousel::new(&self.autoplay)
+                        .h(px(180.))
+                        .arrows(false)
+                        .slides(IMAGES.len(), |ix, _, _| gpui::img(IMAGES[ix]).size_full()),
+                ),
+            )
+            .child(
+                section("Image Gallery")
+                    .max_w_xl()
+                    .child(ImageGallery::new(&self.gallery, IMAGES)),
+            )
+    }
+}
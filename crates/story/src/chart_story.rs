@@ -1,10 +1,11 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
 use gpui::{
     div, linear_color_stop, linear_gradient, prelude::FluentBuilder, px, App, AppContext, Context,
     Entity, FocusHandle, Focusable, Hsla, IntoElement, ParentElement, Render, SharedString, Styled,
     Window,
 };
 use gpui_component::{
-    chart::{AreaChart, BarChart, LineChart, PieChart},
+    chart::{AreaChart, BarChart, CalendarHeatmap, Gauge, Heatmap, LineChart, PieChart},
     divider::Divider,
     dock::PanelControl,
     h_flex, v_flex, ActiveTheme, StyledExt,
@@ -35,6 +36,10 @@ pub struct ChartStory {
     focus_handle: FocusHandle,
     daily_devices: Vec<DailyDevice>,
     monthly_devices: Vec<MonthlyDevice>,
+    selected_month: Option<usize>,
+    contributions: Vec<(NaiveDate, f64)>,
+    selected_date: Option<NaiveDate>,
+    selected_cell: Option<(usize, usize)>,
 }
 
 impl ChartStory {
@@ -47,9 +52,23 @@ impl ChartStory {
         ))
         .unwrap();
 
+        let today = Local::now().date_naive();
+        let contributions = (0..365)
+            .map(|ix| {
+                let date = today - Duration::days(ix);
+                // A fake random count by the date.
+                let count = (date.ordinal() * 7 + date.day() * 13) % 17;
+                (date, count.saturating_sub(5) as f64)
+            })
+            .collect();
+
         Self {
             daily_devices,
             monthly_devices,
+            selected_month: None,
+            contributions,
+            selected_date: None,
+            selected_cell: None,
             focus_handle: cx.focus_handle(),
         }
     }
@@ -129,6 +148,11 @@ fn chart_container(
 impl Render for ChartStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let color = cx.theme().chart_3;
+        let selected_month = self
+            .selected_month
+            .and_then(|ix| self.monthly_devices.get(ix))
+            .map(|d| format!("Selected: {}", d.month))
+            .unwrap_or("Click a slice to select".to_string());
         v_flex()
             .size_full()
             .gap_y_4()
@@ -193,6 +217,114 @@ impl Render for ChartStory {
                     )),
             )
             .child(Divider::horizontal())
+            .child(
+                v_flex()
+                    .gap_4()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded_lg()
+                    .p_4()
+                    .child(div().font_semibold().child("Heatmap"))
+                    .child(
+                        CalendarHeatmap::new("contributions", self.contributions.clone())
+                            .selected(self.selected_date)
+                            .on_select(cx.listener(|this, date: &NaiveDate, _, cx| {
+                                this.selected_date = Some(*date);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Heatmap::new(
+                            "weekly-usage",
+                            (0..4)
+                                .map(|row| {
+                                    (0..7)
+                                        .map(|col| ((row * 7 + col) * 37 % 23) as f64)
+                                        .collect()
+                                })
+                                .collect(),
+                        )
+                        .x_labels(["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"])
+                        .y_labels(["Night", "Morning", "Afternoon", "Evening"])
+                        .color(cx.theme().chart_1)
+                        .legend(true)
+                        .selected(self.selected_cell)
+                        .on_select(cx.listener(
+                            |this, cell: &(usize, usize), _, cx| {
+                                this.selected_cell = Some(*cell);
+                                cx.notify();
+                            },
+                        )),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!(
+                                "Selected date: {}, cell: {:?}",
+                                self.selected_date
+                                    .map(|d| d.to_string())
+                                    .unwrap_or_default(),
+                                self.selected_cell
+                            )),
+                    ),
+            )
+            .child(Divider::horizontal())
+            .child(
+                h_flex()
+                    .gap_x_8()
+                    .h(px(400.))
+                    .child(chart_container(
+                        "Pie Chart - Label & Legend",
+                        PieChart::new(self.monthly_devices.clone())
+                            .value(|d| d.desktop as f32)
+                            .outer_radius(100.)
+                            .inner_radius(50.)
+                            .label(|d| d.month.clone())
+                            .legend(true)
+                            .on_click(cx.listener(|this, ix: &usize, _, cx| {
+                                this.selected_month = Some(*ix);
+                                cx.notify();
+                            })),
+                        true,
+                        cx,
+                    ))
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .h_full()
+                            .gap_4()
+                            .items_center()
+                            .justify_center()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded_lg()
+                            .p_4()
+                            .child(div().font_semibold().child("Gauge"))
+                            .child(
+                                h_flex()
+                                    .gap_6()
+                                    .child(
+                                        Gauge::new("cpu", 72.)
+                                            .label("CPU")
+                                            .threshold(80., cx.theme().danger),
+                                    )
+                                    .child(
+                                        Gauge::new("memory", 86.)
+                                            .label("Memory")
+                                            .threshold(80., cx.theme().danger),
+                                    )
+                                    .child(Gauge::new("disk", 40.).label("Disk").radial()),
+                            )
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(selected_month),
+                            ),
+                    ),
+            )
+            .child(Divider::horizontal())
             .child(
                 h_flex()
                     .gap_x_8()
@@ -4,7 +4,8 @@ use gpui::{
 };
 
 use gpui_component::{
-    checkbox::Checkbox, h_flex, text::TextView, v_flex, ActiveTheme, Disableable as _, Sizable,
+    checkbox::Checkbox, form::FieldChange, h_flex, text::TextView, v_flex, ActiveTheme,
+    Disableable as _, Sizable,
 };
 
 use crate::section;
@@ -114,6 +115,36 @@ impl Render for CheckboxStory {
                             })),
                     ),
                 )
+                .child({
+                    let all = self.check1 && self.check2;
+                    let some = self.check1 || self.check2;
+
+                    section("Indeterminate").child(
+                        Checkbox::new("indeterminate")
+                            .label("Select all")
+                            .checked(all)
+                            .indeterminate(some && !all)
+                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                this.check1 = *checked;
+                                this.check2 = *checked;
+                                cx.notify();
+                            })),
+                    )
+                })
+                .child(
+                    section("Invalid").child(
+                        Checkbox::new("invalid")
+                            .label("I agree to the terms")
+                            .name("terms")
+                            .value("agree")
+                            .invalid(!self.check5)
+                            .checked(self.check5)
+                            .on_field_change(cx.listener(|this, change: &FieldChange, _, cx| {
+                                this.check5 = change.checked;
+                                cx.notify();
+                            })),
+                    ),
+                )
                 .child(
                     section("Disabled").max_w_md().child(
                         h_flex()
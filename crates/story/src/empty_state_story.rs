@@ -0,0 +1,79 @@
+use gpui::{
+    App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled, Window,
+};
+
+use gpui_component::{
+    button::{Button, ButtonVariants as _},
+    empty_state::{EmptyState, ErrorState},
+    v_flex, IconName, Sizable as _,
+};
+
+use crate::section;
+
+pub struct EmptyStateStory {
+    focus_handle: gpui::FocusHandle,
+}
+
+impl super::Story for EmptyStateStory {
+    fn title() -> &'static str {
+        "EmptyState"
+    }
+
+    fn description() -> &'static str {
+        "A placeholder to show when there is no data or failed to load."
+    }
+
+    fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
+        Self::view(window, cx)
+    }
+}
+
+impl EmptyStateStory {
+    pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn view(window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| Self::new(window, cx))
+    }
+}
+impl Focusable for EmptyStateStory {
+    fn focus_handle(&self, _: &gpui::App) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+impl Render for EmptyStateStory {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(section("No Data").child(EmptyState::no_data()))
+            .child(
+                section("Empty State").child(
+                    EmptyState::new()
+                        .icon(IconName::Inbox)
+                        .title("No messages")
+                        .description("New messages will appear here when someone contacts you.")
+                        .primary_action(Button::new("compose").primary().label("Compose"))
+                        .secondary_action(Button::new("learn-more").outline().label("Learn More")),
+                ),
+            )
+            .child(
+                section("Error State").child(
+                    ErrorState::new()
+                        .description("Network request failed, please check your connection.")
+                        .primary_action(Button::new("retry").small().label("Retry")),
+                ),
+            )
+            .child(
+                section("Small").child(
+                    EmptyState::new()
+                        .small()
+                        .icon(IconName::Search)
+                        .title("No results")
+                        .description("Try a different keyword."),
+                ),
+            )
+    }
+}
@@ -208,7 +208,9 @@ impl Render for FormStory {
                     )
                     .child(
                         form_field()
+                            .id("email")
                             .label("Email")
+                            .track_focus(&self.email_input.focus_handle(cx))
                             .child(TextInput::new(&self.email_input))
                             .required(true),
                     )
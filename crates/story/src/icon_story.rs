@@ -5,17 +5,30 @@ use gpui::{
 use gpui_component::{
     button::{Button, ButtonVariant, ButtonVariants},
     dock::PanelControl,
-    h_flex, neutral_500, v_flex, ActiveTheme as _, Icon, IconName, Sizable,
+    h_flex, icon_names, neutral_500, v_flex, ActiveTheme as _, Icon, IconName, IconRegistry,
+    Sizable,
 };
 
 use crate::section;
 
+const TRIANGLE_SVG: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M12 3 22 20H2Z"/></svg>"#;
+
+icon_names! {
+    enum StoryIcon {
+        Sun => "icons/sun.svg",
+        Moon => "icons/moon.svg",
+    }
+}
+
 pub struct IconStory {
     focus_handle: gpui::FocusHandle,
 }
 
 impl IconStory {
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        IconRegistry::singleton().register_bytes("triangle", TRIANGLE_SVG);
+        IconRegistry::singleton().register("star-alias", "icons/star.svg");
+
         Self {
             focus_handle: cx.focus_handle(),
         }
@@ -78,6 +91,19 @@ impl Render for IconStory {
                             .text_color(cx.theme().red),
                     ),
             )
+            .child(
+                section("Custom Icon")
+                    .text_lg()
+                    .child(IconName::custom("triangle"))
+                    .child(
+                        Icon::new(IconName::custom("triangle"))
+                            .large()
+                            .text_color(cx.theme().blue),
+                    )
+                    .child(IconName::custom("star-alias"))
+                    .child(Icon::new(StoryIcon::Sun).text_color(cx.theme().yellow))
+                    .child(Icon::new(StoryIcon::Moon)),
+            )
             .child(
                 section("Icon Button").child(
                     h_flex()
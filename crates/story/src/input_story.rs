@@ -125,13 +125,14 @@ impl InputStory {
         _cx: &mut Context<Self>,
     ) {
         match event {
-            InputEvent::Change => {
+            InputEvent::Change(_) => {
                 let text = state.read(_cx).value();
                 println!("Change: {}", text)
             }
             InputEvent::PressEnter { secondary } => println!("PressEnter secondary: {}", secondary),
             InputEvent::Focus => println!("Focus"),
             InputEvent::Blur => println!("Blur"),
+            InputEvent::MediaAdded { token, .. } => println!("MediaAdded: {}", token),
         };
     }
 }
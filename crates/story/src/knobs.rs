@@ -0,0 +1,282 @@
+use gpui::{
+    AnyElement, AppContext, Context, Entity, IntoElement, ParentElement as _, Render, SharedString,
+    Styled as _, Window,
+};
+use gpui_component::{
+    button::{Button, ButtonGroup, ButtonVariants as _},
+    h_flex,
+    label::Label,
+    switch::Switch,
+    v_flex, ActiveTheme as _, Density, IconName, Selectable as _, Sizable as _,
+};
+
+/// The value of a knob.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KnobValue {
+    Bool(bool),
+    Number {
+        value: f32,
+        min: f32,
+        max: f32,
+        step: f32,
+    },
+    Select {
+        options: Vec<SharedString>,
+        selected: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Knob {
+    name: SharedString,
+    value: KnobValue,
+}
+
+/// The tweakable props of a story, rendered as the controls panel in the [`super::StoryContainer`].
+///
+/// The container toolbar also set the density and RTL to the knobs,
+/// so the story can read them in render, the container only aligns the text to right in RTL,
+/// the story should mirror the layout by [`Knobs::is_rtl`].
+///
+/// ```ignore
+/// let knobs = Knobs::new(cx, |knobs| {
+///     knobs
+///         .bool("Disabled", false)
+///         .select("Size", ["Small", "Medium", "Large"], 1)
+/// });
+///
+/// // In render
+/// let disabled = self.knobs.read(cx).get_bool("Disabled");
+/// ```
+pub struct Knobs {
+    knobs: Vec<Knob>,
+    density: Density,
+    rtl: bool,
+}
+
+impl Knobs {
+    /// Create a new knobs entity, and observe it to re-render the story when the knobs changed.
+    pub fn new<T: 'static>(cx: &mut Context<T>, build: impl FnOnce(Self) -> Self) -> Entity<Self> {
+        let knobs = cx.new(|_| {
+            build(Self {
+                knobs: vec![],
+                density: Density::default(),
+                rtl: false,
+            })
+        });
+        cx.observe(&knobs, |_, _, cx| cx.notify()).detach();
+        knobs
+    }
+
+    fn push(mut self, name: impl Into<SharedString>, value: KnobValue) -> Self {
+        self.knobs.push(Knob {
+            name: name.into(),
+            value,
+        });
+        self
+    }
+
+    /// Add a bool knob, rendered as a switch.
+    pub fn bool(self, name: impl Into<SharedString>, value: bool) -> Self {
+        self.push(name, KnobValue::Bool(value))
+    }
+
+    /// Add a number knob in the `min..=max` range, rendered as a stepper.
+    pub fn number(
+        self,
+        name: impl Into<SharedString>,
+        value: f32,
+        min: f32,
+        max: f32,
+        step: f32,
+    ) -> Self {
+        self.push(
+            name,
+            KnobValue::Number {
+                value: value.clamp(min, max),
+                min,
+                max,
+                step,
+            },
+        )
+    }
+
+    /// Add a select knob, rendered as a button group.
+    pub fn select(
+        self,
+        name: impl Into<SharedString>,
+        options: impl IntoIterator<Item = impl Into<SharedString>>,
+        selected: usize,
+    ) -> Self {
+        self.push(
+            name,
+            KnobValue::Select {
+                options: options.into_iter().map(Into::into).collect(),
+                selected,
+            },
+        )
+    }
+
+    /// Returns the value of the knob by name.
+    pub fn get(&self, name: &str) -> Option<&KnobValue> {
+        self.knobs
+            .iter()
+            .find(|knob| knob.name == name)
+            .map(|knob| &knob.value)
+    }
+
+    /// Returns the value of the bool knob, false if not found.
+    pub fn get_bool(&self, name: &str) -> bool {
+        match self.get(name) {
+            Some(KnobValue::Bool(value)) => *value,
+            _ => false,
+        }
+    }
+
+    /// Returns the value of the number knob, 0.0 if not found.
+    pub fn get_number(&self, name: &str) -> f32 {
+        match self.get(name) {
+            Some(KnobValue::Number { value, .. }) => *value,
+            _ => 0.,
+        }
+    }
+
+    /// Returns the selected index of the select knob, 0 if not found.
+    pub fn get_selected(&self, name: &str) -> usize {
+        match self.get(name) {
+            Some(KnobValue::Select { selected, .. }) => *selected,
+            _ => 0,
+        }
+    }
+
+    /// Returns the density set by the container toolbar.
+    pub fn density(&self) -> Density {
+        self.density
+    }
+
+    /// Returns true if the right-to-left layout is set by the container toolbar.
+    pub fn is_rtl(&self) -> bool {
+        self.rtl
+    }
+
+    pub(crate) fn set_density(&mut self, density: Density, cx: &mut Context<Self>) {
+        self.density = density;
+        cx.notify();
+    }
+
+    pub(crate) fn set_rtl(&mut self, rtl: bool, cx: &mut Context<Self>) {
+        self.rtl = rtl;
+        cx.notify();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.knobs.is_empty()
+    }
+
+    fn set_value(&mut self, ix: usize, value: KnobValue, cx: &mut Context<Self>) {
+        if let Some(knob) = self.knobs.get_mut(ix) {
+            knob.value = value;
+            cx.notify();
+        }
+    }
+
+    fn render_knob(&self, ix: usize, knob: &Knob, cx: &mut Context<Self>) -> AnyElement {
+        let control = match knob.value.clone() {
+            KnobValue::Bool(value) => Switch::new(("knob", ix))
+                .checked(value)
+                .small()
+                .on_click(cx.listener(move |this, checked: &bool, _, cx| {
+                    this.set_value(ix, KnobValue::Bool(*checked), cx);
+                }))
+                .into_any_element(),
+            KnobValue::Number {
+                value,
+                min,
+                max,
+                step,
+            } => {
+                let update = move |value: f32| KnobValue::Number {
+                    value: value.clamp(min, max),
+                    min,
+                    max,
+                    step,
+                };
+
+                h_flex()
+                    .gap_1()
+                    .child(
+                        Button::new(("knob-dec", ix))
+                            .icon(IconName::Minus)
+                            .ghost()
+                            .xsmall()
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.set_value(ix, update(value - step), cx);
+                            })),
+                    )
+                    .child(Label::new(format!("{}", value)).text_sm().min_w_8())
+                    .child(
+                        Button::new(("knob-inc", ix))
+                            .icon(IconName::Plus)
+                            .ghost()
+                            .xsmall()
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.set_value(ix, update(value + step), cx);
+                            })),
+                    )
+                    .into_any_element()
+            }
+            KnobValue::Select { options, selected } => ButtonGroup::new(("knob", ix))
+                .outline()
+                .compact()
+                .xsmall()
+                .children(options.iter().enumerate().map(|(i, option)| {
+                    Button::new(i).label(option.clone()).selected(i == selected)
+                }))
+                .on_click(cx.listener(move |this, selecteds: &Vec<usize>, _, cx| {
+                    let Some(selected) = selecteds.first().copied() else {
+                        return;
+                    };
+                    this.set_value(
+                        ix,
+                        KnobValue::Select {
+                            options: options.clone(),
+                            selected,
+                        },
+                        cx,
+                    );
+                }))
+                .into_any_element(),
+        };
+
+        v_flex()
+            .gap_1()
+            .child(Label::new(knob.name.clone()).text_xs())
+            .child(control)
+            .into_any_element()
+    }
+}
+
+impl Render for Knobs {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let knobs = self.knobs.clone();
+
+        v_flex()
+            .gap_3()
+            .p_3()
+            .w_64()
+            .h_full()
+            .border_l_1()
+            .border_color(cx.theme().border)
+            .child(
+                Label::new("Controls")
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .children(
+                knobs
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, knob)| self.render_knob(ix, knob, cx)),
+            )
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Local, TimeDelta};
 use gpui::{
     div, px, rems, App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render,
     SharedString, Styled, Subscription, Window,
@@ -9,6 +10,7 @@ use gpui_component::{
     green_500, h_flex,
     input::{InputEvent, InputState, TextInput},
     label::{HighlightsMatch, Label},
+    relative_time::{Countdown, RelativeTime},
     v_flex, IconName, StyledExt,
 };
 
@@ -20,6 +22,9 @@ pub struct LabelStory {
     highlights_text: SharedString,
     highlights_input: Entity<InputState>,
     prefix: bool,
+    now: DateTime<Local>,
+    deadline: DateTime<Local>,
+    completed_count: usize,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -48,7 +53,7 @@ impl LabelStory {
         let _subscriptions =
             vec![
                 cx.subscribe(&highlights_input, |this, state, e: &InputEvent, cx| {
-                    if let InputEvent::Change = e {
+                    if let InputEvent::Change(_) = e {
                         this.highlights_text = state.read(cx).value();
                         cx.notify();
                     }
@@ -61,6 +66,9 @@ impl LabelStory {
             highlights_text: Default::default(),
             highlights_input,
             prefix: false,
+            now: Local::now(),
+            deadline: Local::now() + TimeDelta::seconds(10),
+            completed_count: 0,
             _subscriptions,
         }
     }
@@ -90,6 +98,7 @@ impl Focusable for LabelStory {
 impl Render for LabelStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let ht = self.highlights_text();
+        let view = cx.entity().downgrade();
 
         v_flex()
             .gap_6()
@@ -207,5 +216,42 @@ impl Render for LabelStory {
                         ),
                 ),
             )
+            .child(
+                section("Relative Time").max_w_md().child(
+                    v_flex()
+                        .gap_2()
+                        .child(RelativeTime::new("opened", self.now))
+                        .child(RelativeTime::new(
+                            "hours-ago",
+                            self.now - TimeDelta::minutes(150),
+                        ))
+                        .child(RelativeTime::new("tomorrow", self.now + TimeDelta::days(1))),
+                ),
+            )
+            .child(
+                section("Countdown").max_w_md().child(
+                    h_flex()
+                        .gap_3()
+                        .child(
+                            Countdown::new("countdown", self.deadline)
+                                .text_xl()
+                                .on_complete(move |_, cx| {
+                                    _ = view.update(cx, |this, cx| {
+                                        this.completed_count += 1;
+                                        cx.notify();
+                                    });
+                                }),
+                        )
+                        .child(format!("Completed: {}", self.completed_count))
+                        .child(
+                            Button::new("restart")
+                                .label("Restart")
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.deadline = Local::now() + TimeDelta::seconds(10);
+                                    cx.notify();
+                                })),
+                        ),
+                ),
+            )
     }
 }
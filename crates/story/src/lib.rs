@@ -6,6 +6,7 @@ mod avatar_story;
 mod badge_story;
 mod button_story;
 mod calendar_story;
+mod carousel_story;
 mod chart_story;
 mod checkbox_story;
 mod clipboard_story;
@@ -14,6 +15,7 @@ mod date_picker_story;
 mod description_list_story;
 mod drawer_story;
 mod dropdown_story;
+mod empty_state_story;
 mod form_story;
 mod group_box_story;
 mod icon_story;
@@ -21,6 +23,7 @@ mod image_story;
 mod indicator_story;
 mod input_story;
 mod kbd_story;
+mod knobs;
 mod label_story;
 mod list_story;
 mod menu_story;
@@ -64,6 +67,7 @@ pub use avatar_story::AvatarStory;
 pub use badge_story::BadgeStory;
 pub use button_story::ButtonStory;
 pub use calendar_story::CalendarStory;
+pub use carousel_story::CarouselStory;
 pub use chart_story::ChartStory;
 pub use checkbox_story::CheckboxStory;
 pub use clipboard_story::ClipboardStory;
@@ -72,6 +76,7 @@ pub use date_picker_story::DatePickerStory;
 pub use description_list_story::DescriptionListStory;
 pub use drawer_story::DrawerStory;
 pub use dropdown_story::DropdownStory;
+pub use empty_state_story::EmptyStateStory;
 pub use form_story::FormStory;
 pub use group_box_story::GroupBoxStory;
 pub use icon_story::IconStory;
@@ -79,6 +84,7 @@ pub use image_story::ImageStory;
 pub use indicator_story::IndicatorStory;
 pub use input_story::InputStory;
 pub use kbd_story::KbdStory;
+pub use knobs::{KnobValue, Knobs};
 pub use label_story::LabelStory;
 pub use list_story::ListStory;
 pub use menu_story::MenuStory;
@@ -109,14 +115,15 @@ pub use webview_story::WebViewStory;
 pub use welcome_story::WelcomeStory;
 
 use gpui_component::{
-    ActiveTheme, ContextModal, IconName, Root, TitleBar,
-    button::Button,
+    ActiveTheme, ContextModal, Density, IconName, Root, Selectable as _, Sizable as _, Theme,
+    ThemeMode, TitleBar,
+    button::{Button, ButtonGroup, ButtonVariants as _},
     context_menu::ContextMenuExt,
     dock::{Panel, PanelControl, PanelEvent, PanelInfo, PanelState, TitleStyle, register_panel},
     group_box::GroupBox,
     h_flex,
     notification::Notification,
-    popup_menu::PopupMenu,
+    popup_menu::{PopupMenu, PopupMenuExt as _},
     scroll::ScrollbarShow,
     v_flex,
 };
@@ -261,6 +268,7 @@ impl Render for StoryRoot {
         let drawer_layer = Root::render_drawer_layer(window, cx);
         let modal_layer = Root::render_modal_layer(window, cx);
         let notification_layer = Root::render_notification_layer(window, cx);
+        let overlay_layer = Root::render_overlay_layer(window, cx);
 
         div()
             .size_full()
@@ -273,6 +281,7 @@ impl Render for StoryRoot {
             .children(drawer_layer)
             .children(modal_layer)
             .children(notification_layer)
+            .children(overlay_layer)
     }
 }
 
@@ -314,6 +323,10 @@ pub fn init(cx: &mut App) {
         cx.quit();
     });
 
+    cx.on_action(|locale: &SelectLocale, cx: &mut App| {
+        gpui_component::locale::Locale::set(locale.0.clone(), cx);
+    });
+
     register_panel(cx, PANEL_NAME, |_, _, info, window, cx| {
         let story_state = match info {
             PanelInfo::Panel(value) => StoryState::from_value(value.clone()),
@@ -323,10 +336,12 @@ pub fn init(cx: &mut App) {
         };
 
         let view = cx.new(|cx| {
-            let (title, description, closable, zoomable, story, on_active) =
+            let (title, description, closable, zoomable, story, on_active, knobs) =
                 story_state.to_story(window, cx);
+            let knobs = knobs(story.clone(), cx);
             let mut container = StoryContainer::new(window, cx)
                 .story(story, story_state.story_klass)
+                .knobs(knobs)
                 .on_active(on_active);
 
             cx.on_focus_in(
@@ -452,6 +467,8 @@ pub struct StoryContainer {
     closable: bool,
     zoomable: Option<PanelControl>,
     on_active: Option<fn(AnyView, bool, &mut Window, &mut App)>,
+    knobs: Option<Entity<Knobs>>,
+    show_knobs: bool,
 }
 
 #[derive(Debug)]
@@ -479,6 +496,19 @@ pub trait Story: Render + Sized {
     }
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render>;
 
+    /// Returns the knobs of the story to tweak the props at runtime, default is `None`.
+    fn knobs(&self) -> Option<Entity<Knobs>> {
+        None
+    }
+    fn knobs_any(view: AnyView, cx: &App) -> Option<Entity<Knobs>>
+    where
+        Self: 'static,
+    {
+        view.downcast::<Self>()
+            .ok()
+            .and_then(|story| story.read(cx).knobs())
+    }
+
     fn on_active(&mut self, active: bool, window: &mut Window, cx: &mut App) {
         let _ = active;
         let _ = window;
@@ -514,6 +544,8 @@ impl StoryContainer {
             closable: true,
             zoomable: Some(PanelControl::default()),
             on_active: None,
+            knobs: None,
+            show_knobs: true,
         }
     }
 
@@ -522,10 +554,12 @@ impl StoryContainer {
         let description = S::description();
         let story = S::new_view(window, cx);
         let story_klass = S::klass();
+        let knobs = S::knobs_any(story.clone().into(), cx);
 
         let view = cx.new(|cx| {
             let mut story = Self::new(window, cx)
                 .story(story.into(), story_klass)
+                .knobs(knobs)
                 .on_active(S::on_active_any);
             story.focus_handle = cx.focus_handle();
             story.closable = S::closable();
@@ -560,6 +594,133 @@ impl StoryContainer {
         self
     }
 
+    /// Set the knobs of the story, the density, RTL toggles and controls panel
+    /// are only available with knobs.
+    pub fn knobs(mut self, knobs: Option<Entity<Knobs>>) -> Self {
+        self.knobs = knobs;
+        self
+    }
+
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_dark = cx.theme().mode.is_dark();
+        let locale = gpui_component::locale().to_string();
+
+        h_flex()
+            .id("story-toolbar")
+            .justify_end()
+            .gap_1()
+            .px_4()
+            .pt_2()
+            .when_some(self.knobs.clone(), |this, knobs| {
+                let density = knobs.read(cx).density();
+                let rtl = knobs.read(cx).is_rtl();
+                let has_controls = !knobs.read(cx).is_empty();
+
+                this.child(
+                    ButtonGroup::new("density")
+                        .outline()
+                        .compact()
+                        .xsmall()
+                        .child(
+                            Button::new("compact")
+                                .label("Compact")
+                                .selected(density == Density::Compact),
+                        )
+                        .child(
+                            Button::new("comfortable")
+                                .label("Comfortable")
+                                .selected(density == Density::Comfortable),
+                        )
+                        .child(
+                            Button::new("spacious")
+                                .label("Spacious")
+                                .selected(density == Density::Spacious),
+                        )
+                        .on_click({
+                            let knobs = knobs.clone();
+                            move |selecteds: &Vec<usize>, _, cx| {
+                                let density = match selecteds.first() {
+                                    Some(0) => Density::Compact,
+                                    Some(2) => Density::Spacious,
+                                    _ => Density::Comfortable,
+                                };
+                                knobs.update(cx, |knobs, cx| knobs.set_density(density, cx));
+                            }
+                        }),
+                )
+                .child(
+                    Button::new("rtl")
+                        .label("RTL")
+                        .ghost()
+                        .xsmall()
+                        .selected(rtl)
+                        .tooltip("Toggle right-to-left layout")
+                        .on_click({
+                            let knobs = knobs.clone();
+                            move |_, _, cx| {
+                                knobs.update(cx, |knobs, cx| knobs.set_rtl(!rtl, cx));
+                            }
+                        }),
+                )
+                .when(has_controls, |this| {
+                    this.child(
+                        Button::new("controls")
+                            .icon(IconName::Settings2)
+                            .ghost()
+                            .xsmall()
+                            .selected(self.show_knobs)
+                            .tooltip("Toggle controls")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.show_knobs = !this.show_knobs;
+                                cx.notify();
+                            })),
+                    )
+                })
+            })
+            .child(
+                Button::new("theme-mode")
+                    .icon(if is_dark {
+                        IconName::Sun
+                    } else {
+                        IconName::Moon
+                    })
+                    .ghost()
+                    .xsmall()
+                    .tooltip("Toggle theme mode")
+                    .on_click(move |_, window, cx| {
+                        let mode = if is_dark {
+                            ThemeMode::Light
+                        } else {
+                            ThemeMode::Dark
+                        };
+                        Theme::change(mode, Some(window), cx);
+                        cx.refresh_windows();
+                    }),
+            )
+            .child(
+                Button::new("locale")
+                    .icon(IconName::Globe)
+                    .ghost()
+                    .xsmall()
+                    .popup_menu(move |menu, _, _| {
+                        [
+                            ("English", "en"),
+                            ("简体中文", "zh-CN"),
+                            ("繁體中文", "zh-HK"),
+                            ("Italiano", "it"),
+                        ]
+                        .into_iter()
+                        .fold(menu, |menu, (label, value)| {
+                            menu.menu_with_check(
+                                label,
+                                locale == value,
+                                Box::new(SelectLocale(value.into())),
+                            )
+                        })
+                    }),
+            )
+    }
+
     fn on_action_panel_info(
         &mut self,
         _: &ShowPanelInfo,
@@ -619,6 +780,7 @@ impl StoryState {
         Option<PanelControl>,
         AnyView,
         fn(AnyView, bool, &mut Window, &mut App),
+        fn(AnyView, &App) -> Option<Entity<Knobs>>,
     ) {
         macro_rules! story {
             ($klass:tt) => {
@@ -629,6 +791,7 @@ impl StoryState {
                     $klass::zoomable(),
                     $klass::view(window, cx).into(),
                     $klass::on_active_any,
+                    $klass::knobs_any,
                 )
             };
         }
@@ -754,14 +917,31 @@ impl Render for StoryContainer {
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::on_action_panel_info))
             .on_action(cx.listener(Self::on_action_toggle_search))
+            .child(self.render_toolbar(cx))
             .when_some(self.story.clone(), |this, story| {
+                let knobs = self
+                    .knobs
+                    .clone()
+                    .filter(|knobs| !knobs.read(cx).is_empty());
+                let rtl = self
+                    .knobs
+                    .as_ref()
+                    .map_or(false, |knobs| knobs.read(cx).is_rtl());
+
                 this.child(
-                    v_flex()
-                        .id("story-children")
+                    h_flex()
                         .w_full()
                         .flex_1()
-                        .p_4()
-                        .child(story),
+                        .items_start()
+                        .child(
+                            v_flex()
+                                .id("story-children")
+                                .flex_1()
+                                .p_4()
+                                .when(rtl, |this| this.text_right())
+                                .child(story),
+                        )
+                        .when(self.show_knobs, |this| this.children(knobs)),
                 )
             })
     }
@@ -1,6 +1,6 @@
 use gpui::{prelude::*, *};
 use gpui_component::{
-    ActiveTheme as _, Icon, IconName, h_flex,
+    ActiveTheme as _, Icon, IconAssets, IconName, h_flex,
     input::{InputEvent, InputState, TextInput},
     resizable::{ResizableState, h_resizable, resizable_panel},
     sidebar::{Sidebar, SidebarGroup, SidebarHeader, SidebarMenu, SidebarMenuItem},
@@ -22,7 +22,7 @@ impl Gallery {
     pub fn new(init_story: Option<&str>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search..."));
         let _subscriptions = vec![cx.subscribe(&search_input, |this, _, e, cx| match e {
-            InputEvent::Change => {
+            InputEvent::Change(_) => {
                 this.active_group_index = Some(0);
                 this.active_index = Some(0);
                 cx.notify()
@@ -43,6 +43,7 @@ impl Gallery {
                     StoryContainer::panel::<BadgeStory>(window, cx),
                     StoryContainer::panel::<ButtonStory>(window, cx),
                     StoryContainer::panel::<CalendarStory>(window, cx),
+                    StoryContainer::panel::<CarouselStory>(window, cx),
                     StoryContainer::panel::<ChartStory>(window, cx),
                     StoryContainer::panel::<CheckboxStory>(window, cx),
                     StoryContainer::panel::<ClipboardStory>(window, cx),
@@ -51,6 +52,7 @@ impl Gallery {
                     StoryContainer::panel::<DescriptionListStory>(window, cx),
                     StoryContainer::panel::<DrawerStory>(window, cx),
                     StoryContainer::panel::<DropdownStory>(window, cx),
+                    StoryContainer::panel::<EmptyStateStory>(window, cx),
                     StoryContainer::panel::<FormStory>(window, cx),
                     StoryContainer::panel::<GroupBoxStory>(window, cx),
                     StoryContainer::panel::<IconStory>(window, cx),
@@ -288,7 +290,7 @@ impl Render for Gallery {
 }
 
 fn main() {
-    let app = Application::new().with_assets(Assets);
+    let app = Application::new().with_assets(IconAssets::new(Assets));
 
     // Parse `cargo run -- <story_name>`
     let name = std::env::args().nth(1);
@@ -4,8 +4,13 @@ use gpui::{
     actions, div, px,
 };
 use gpui_component::{
-    ActiveTheme as _, IconName, button::Button, context_menu::ContextMenuExt, h_flex,
-    popup_menu::PopupMenuExt as _, v_flex,
+    ActiveTheme as _, IconName,
+    button::Button,
+    context_menu::ContextMenuExt,
+    h_flex,
+    menu::{MenuBar, MenuBarMenu},
+    popup_menu::{PopupMenuExt as _, PopupMenuItem},
+    v_flex,
 };
 use serde::Deserialize;
 
@@ -15,6 +20,10 @@ use crate::section;
 #[action(namespace = menu_story, no_json)]
 struct Info(usize);
 
+#[derive(Action, Clone, PartialEq, Deserialize)]
+#[action(namespace = menu_story, no_json)]
+struct SetZoom(usize);
+
 actions!(menu_story, [Copy, Paste, Cut, SearchAll, ToggleCheck]);
 
 const CONTEXT: &str = "menu_story";
@@ -42,7 +51,9 @@ pub fn init(cx: &mut App) {
 pub struct MenuStory {
     focus_handle: FocusHandle,
     checked: bool,
+    zoom: usize,
     message: String,
+    menu_bar: Entity<MenuBar>,
 }
 
 impl super::Story for MenuStory {
@@ -66,14 +77,57 @@ impl MenuStory {
 
     fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         cx.focus_self(window);
+        let menu_bar = Self::build_menu_bar(cx.entity(), window, cx);
 
         Self {
             checked: true,
+            zoom: 100,
             focus_handle: cx.focus_handle(),
             message: "".to_string(),
+            menu_bar,
         }
     }
 
+    fn build_menu_bar(story: Entity<Self>, window: &mut Window, cx: &mut App) -> Entity<MenuBar> {
+        let story = story.downgrade();
+
+        MenuBar::new(
+            vec![
+                MenuBarMenu::new("&File", |menu, window, cx| {
+                    menu.menu("Search All", Box::new(SearchAll))
+                        .separator()
+                        .submenu("Open Recent", window, cx, |menu, _, _| {
+                            menu.menu("project-a", Box::new(Info(1)))
+                                .menu("project-b", Box::new(Info(2)))
+                        })
+                }),
+                MenuBarMenu::new("&Edit", |menu, _, _| {
+                    menu.menu("Cut", Box::new(Cut))
+                        .menu("Copy", Box::new(Copy))
+                        .menu("Paste", Box::new(Paste))
+                }),
+                MenuBarMenu::new("&View", move |menu, _, cx| {
+                    let Some(story) = story.upgrade() else {
+                        return menu;
+                    };
+                    let (checked, zoom) = {
+                        let story = story.read(cx);
+                        (story.checked, story.zoom)
+                    };
+
+                    menu.menu_with_check("Toggle Check", checked, Box::new(ToggleCheck))
+                        .separator()
+                        .label("Zoom")
+                        .menu_with_radio("50%", zoom == 50, Box::new(SetZoom(50)))
+                        .menu_with_radio("100%", zoom == 100, Box::new(SetZoom(100)))
+                        .menu_with_radio("200%", zoom == 200, Box::new(SetZoom(200)))
+                }),
+            ],
+            window,
+            cx,
+        )
+    }
+
     fn on_copy(&mut self, _: &Copy, _: &mut Window, cx: &mut Context<Self>) {
         self.message = "You have clicked copy".to_string();
         cx.notify()
@@ -99,6 +153,12 @@ impl MenuStory {
         cx.notify()
     }
 
+    fn on_action_set_zoom(&mut self, zoom: &SetZoom, _: &mut Window, cx: &mut Context<Self>) {
+        self.zoom = zoom.0;
+        self.message = format!("You have set zoom: {}%", self.zoom);
+        cx.notify()
+    }
+
     fn on_action_toggle_check(&mut self, _: &ToggleCheck, _: &mut Window, cx: &mut Context<Self>) {
         self.checked = !self.checked;
         self.message = format!("You have clicked toggle check: {}", self.checked);
@@ -125,9 +185,15 @@ impl Render for MenuStory {
             .on_action(cx.listener(Self::on_search_all))
             .on_action(cx.listener(Self::on_action_info))
             .on_action(cx.listener(Self::on_action_toggle_check))
+            .on_action(cx.listener(Self::on_action_set_zoom))
             .size_full()
             .min_h(px(400.))
             .gap_6()
+            .child(
+                section("Menu Bar")
+                    .child(div().h_8().child(self.menu_bar.clone()))
+                    .child("Press Alt with the underlined letter to open a menu."),
+            )
             .child(
                 section("Popup Menu")
                     .child(
@@ -223,6 +289,52 @@ impl Render for MenuStory {
                         }
                     }),
             )
+            .child(
+                section("Searchable Menu")
+                    .child(
+                        Button::new("popup-menu-rich")
+                            .outline()
+                            .label("File Actions")
+                            .popup_menu(move |this, _, _| {
+                                this.item(
+                                    PopupMenuItem::new("Copy")
+                                        .icon(IconName::Copy)
+                                        .description("Copy the file to clipboard")
+                                        .action(Box::new(Copy)),
+                                )
+                                .item(
+                                    PopupMenuItem::new("Search")
+                                        .icon(IconName::Search)
+                                        .description("Search in the folder")
+                                        .action(Box::new(SearchAll)),
+                                )
+                                .separator()
+                                .item(
+                                    PopupMenuItem::new("Delete")
+                                        .icon(IconName::Delete)
+                                        .description("Move the file to trash")
+                                        .danger(true)
+                                        .action(Box::new(Info(0))),
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("popup-menu-searchable")
+                            .outline()
+                            .label("Searchable Menu (50 items)")
+                            .popup_menu(move |this, window, cx| {
+                                let mut this =
+                                    this.searchable(window, cx).scrollable().max_h(px(300.));
+                                for i in 0..50 {
+                                    this = this.menu(
+                                        SharedString::from(format!("Item {}", i)),
+                                        Box::new(Info(i)),
+                                    )
+                                }
+                                this.min_w(px(200.))
+                            }),
+                    ),
+            )
             .child(
                 section("Menu with scrollbar")
                     .child(
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use gpui::{
     div, px, App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _,
     IntoElement, ParentElement, Render, SharedString, Styled, Window,
@@ -12,7 +14,7 @@ use gpui_component::{
     input::{InputState, TextInput},
     modal::ModalButtonProps,
     text::TextView,
-    v_flex, ActiveTheme, ContextModal as _, Icon, IconName,
+    v_flex, ActiveTheme, ContextModal as _, Icon, IconName, Sizable as _,
 };
 
 use crate::{section, TestAction};
@@ -403,6 +405,103 @@ impl Render for ModalStory {
                                     });
                                 })),
                         ),
+                    )
+                    .child(
+                        section("Modal Sizes")
+                            .child(
+                                Button::new("small-modal")
+                                    .outline()
+                                    .label("Small")
+                                    .on_click(cx.listener(move |_, _, window, cx| {
+                                        window.open_modal(cx, move |modal, _, _| {
+                                            modal
+                                                .small()
+                                                .title("Small Modal")
+                                                .child("This is a small modal.")
+                                        });
+                                    })),
+                            )
+                            .child(
+                                Button::new("large-modal")
+                                    .outline()
+                                    .label("Large")
+                                    .on_click(cx.listener(move |_, _, window, cx| {
+                                        window.open_modal(cx, move |modal, _, _| {
+                                            modal
+                                                .large()
+                                                .title("Large Modal")
+                                                .child("This is a large modal.")
+                                        });
+                                    })),
+                            )
+                            .child(
+                                Button::new("full-screen-modal")
+                                    .outline()
+                                    .label("Full Screen")
+                                    .on_click(cx.listener(move |_, _, window, cx| {
+                                        window.open_modal(cx, move |modal, _, _| {
+                                            modal
+                                                .full_screen(true)
+                                                .title("Full Screen Modal")
+                                                .child("This modal fills the window.")
+                                        });
+                                    })),
+                            ),
+                    )
+                    .child(
+                        section("Async Confirm").child(
+                            Button::new("async-confirm-modal")
+                                .outline()
+                                .label("Save")
+                                .on_click(cx.listener(move |_, _, window, cx| {
+                                    window.open_modal(cx, move |modal, _, _| {
+                                        modal
+                                            .confirm()
+                                            .title("Save Changes")
+                                            .child("This will take 2 seconds to save.")
+                                            .on_ok_async(|_, cx| {
+                                                let timer = cx
+                                                    .background_executor()
+                                                    .timer(Duration::from_secs(2));
+                                                cx.spawn(async move |_| {
+                                                    timer.await;
+                                                    Ok(())
+                                                })
+                                            })
+                                            .on_close(|_, window, cx| {
+                                                window.push_notification("Saved.", cx);
+                                            })
+                                    });
+                                })),
+                        ),
+                    )
+                    .child(
+                        section("Unsaved Changes Guard").child(
+                            Button::new("unsaved-modal")
+                                .outline()
+                                .label("Edit Document")
+                                .on_click(cx.listener(move |_, _, window, cx| {
+                                    window.open_modal(cx, move |modal, _, _| {
+                                        modal
+                                            .title("Edit Document")
+                                            .child("Try to close this modal with unsaved changes.")
+                                            .on_close_requested(|window, cx| {
+                                                window.open_modal(cx, |modal, _, _| {
+                                                    modal
+                                                        .confirm()
+                                                        .small()
+                                                        .child("Discard the unsaved changes?")
+                                                        .on_ok(|_, window, cx| {
+                                                            // Close the modal twice to discard the edit modal as well.
+                                                            window.close_modal(cx);
+                                                            true
+                                                        })
+                                                });
+                                                false
+                                            })
+                                    });
+                                })),
+                        ),
                     ),
             )
     }
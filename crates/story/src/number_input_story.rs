@@ -123,7 +123,7 @@ impl NumberInputStory {
         cx: &mut Context<Self>,
     ) {
         match event {
-            InputEvent::Change => {
+            InputEvent::Change(_) => {
                 let text = state.read(cx).value();
                 if state == &self.number_input1 {
                     if let Ok(value) = text.parse::<i64>() {
@@ -145,6 +145,7 @@ impl NumberInputStory {
             }
             InputEvent::Focus => println!("Focus"),
             InputEvent::Blur => println!("Blur"),
+            InputEvent::MediaAdded { token, .. } => println!("MediaAdded: {}", token),
         }
     }
 
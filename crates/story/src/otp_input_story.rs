@@ -54,7 +54,7 @@ impl OtpInputStory {
 
         let _subscriptions = vec![
             cx.subscribe(&otp_state, |this, state, ev: &InputEvent, cx| match ev {
-                InputEvent::Change => {
+                InputEvent::Change(_) => {
                     let text = state.read(cx).value();
                     this.otp_value = Some(text.clone());
                     cx.notify();
@@ -1,7 +1,12 @@
+use std::time::Duration;
+
 use gpui::{
     App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled, Window,
 };
-use gpui_component::{button::Button, h_flex, progress::Progress, v_flex, IconName, Sizable};
+use gpui_component::{
+    button::Button, h_flex, progress::Progress, v_flex, ActiveTheme as _, ContextModal as _,
+    IconName, Sizable,
+};
 
 use crate::section;
 
@@ -39,6 +44,31 @@ impl ProgressStory {
     pub fn set_value(&mut self, value: f32) {
         self.value = value;
     }
+
+    /// Start a task of the loading bar, finished after the `steps` of 500ms.
+    fn start_loading(
+        &mut self,
+        steps: usize,
+        progress: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let id = window.start_loading(cx);
+        cx.spawn_in(window, async move |_, cx| {
+            for step in 1..=steps {
+                cx.background_executor()
+                    .timer(Duration::from_millis(500))
+                    .await;
+                if progress {
+                    _ = cx.update(|window, cx| {
+                        window.set_loading_progress(id, step as f32 / steps as f32, cx)
+                    });
+                }
+            }
+            _ = cx.update(|window, cx| window.finish_loading(id, cx));
+        })
+        .detach();
+    }
 }
 
 impl Focusable for ProgressStory {
@@ -49,53 +79,124 @@ impl Focusable for ProgressStory {
 
 impl Render for ProgressStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex().items_center().gap_y_3().child(
-            section("Progress Bar").max_w_md().child(
-                v_flex()
-                    .w_full()
-                    .gap_3()
-                    .justify_center()
-                    .items_center()
-                    .child(
-                        h_flex()
-                            .gap_2()
-                            .child(Button::new("button-1").small().label("0%").on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value(0.);
-                                }),
-                            ))
-                            .child(Button::new("button-2").small().label("25%").on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value(25.);
-                                }),
-                            ))
-                            .child(Button::new("button-3").small().label("75%").on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value(75.);
-                                }),
-                            ))
-                            .child(Button::new("button-4").small().label("100%").on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value(100.);
-                                }),
-                            )),
-                    )
-                    .child(Progress::new().value(self.value))
-                    .child(
-                        h_flex()
-                            .gap_x_2()
-                            .child(Button::new("button-5").icon(IconName::Minus).on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value((this.value - 1.).max(0.));
-                                }),
-                            ))
-                            .child(Button::new("button-6").icon(IconName::Plus).on_click(
-                                cx.listener(|this, _, _, _| {
-                                    this.set_value((this.value + 1.).min(100.));
-                                }),
-                            )),
-                    ),
-            ),
-        )
+        v_flex()
+            .items_center()
+            .gap_y_3()
+            .child(
+                section("Progress Bar").max_w_md().child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .justify_center()
+                        .items_center()
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(Button::new("button-1").small().label("0%").on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value(0.);
+                                    }),
+                                ))
+                                .child(Button::new("button-2").small().label("25%").on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value(25.);
+                                    }),
+                                ))
+                                .child(Button::new("button-3").small().label("75%").on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value(75.);
+                                    }),
+                                ))
+                                .child(Button::new("button-4").small().label("100%").on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value(100.);
+                                    }),
+                                )),
+                        )
+                        .child(Progress::new().value(self.value))
+                        .child(
+                            h_flex()
+                                .gap_x_2()
+                                .child(Button::new("button-5").icon(IconName::Minus).on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value((this.value - 1.).max(0.));
+                                    }),
+                                ))
+                                .child(Button::new("button-6").icon(IconName::Plus).on_click(
+                                    cx.listener(|this, _, _, _| {
+                                        this.set_value((this.value + 1.).min(100.));
+                                    }),
+                                )),
+                        ),
+                ),
+            )
+            .child(
+                section("Indeterminate")
+                    .max_w_md()
+                    .child(Progress::new().indeterminate(true)),
+            )
+            .child(
+                section("Steps").max_w_md().child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(Progress::new().steps(5).value(self.value))
+                        .child(Progress::new().small().steps(10).value(self.value)),
+                ),
+            )
+            .child(
+                section("Circular").max_w_md().child(
+                    h_flex()
+                        .gap_4()
+                        .items_center()
+                        .child(Progress::new().circular().xsmall().value(self.value))
+                        .child(Progress::new().circular().small().value(self.value))
+                        .child(
+                            Progress::new()
+                                .circular()
+                                .value(self.value)
+                                .label(format!("{}%", self.value.round())),
+                        )
+                        .child(
+                            Progress::new()
+                                .circular()
+                                .large()
+                                .value(self.value)
+                                .label(format!("{}%", self.value.round())),
+                        )
+                        .child(Progress::new().circular().indeterminate(true)),
+                ),
+            )
+            .child(
+                section("Thresholds").max_w_md().child(
+                    Progress::new()
+                        .value(self.value)
+                        .threshold(0., cx.theme().danger)
+                        .threshold(20., cx.theme().warning)
+                        .threshold(60., cx.theme().success),
+                ),
+            )
+            .child(
+                section("Loading Bar").max_w_md().child(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new("loading-1")
+                                .small()
+                                .label("Start a 2s task")
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.start_loading(4, false, window, cx)
+                                })),
+                        )
+                        .child(
+                            Button::new("loading-2")
+                                .small()
+                                .label("Start a task with progress")
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.start_loading(8, true, window, cx)
+                                })),
+                        ),
+                ),
+            )
     }
 }
@@ -1,6 +1,6 @@
 use gpui::{
-    div, px, AnyElement, App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement,
-    ParentElement as _, Pixels, Render, SharedString, Styled, Window,
+    div, px, relative, AnyElement, App, AppContext, Context, Entity, FocusHandle, Focusable,
+    IntoElement, ParentElement as _, Pixels, Render, SharedString, Styled, Window,
 };
 use gpui_component::{
     resizable::{h_resizable, resizable_panel, v_resizable, ResizableState},
@@ -109,9 +109,14 @@ impl Render for ResizableStory {
                                 resizable_panel()
                                     .size(px(200.))
                                     .size_range(px(200.)..px(400.))
-                                    .child(panel_box("Left 2", cx)),
+                                    .collapsible(true)
+                                    .child(panel_box("Left 2 (Collapsible)", cx)),
                             )
-                            .child(resizable_panel().child(panel_box("Right (Grow)", cx))),
+                            .child(
+                                resizable_panel()
+                                    .min_size(relative(0.3))
+                                    .child(panel_box("Right (Grow, min 30%)", cx)),
+                            ),
                     ),
             )
     }
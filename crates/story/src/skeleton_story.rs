@@ -2,13 +2,18 @@ use gpui::{
     px, App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled,
     Window,
 };
-use gpui_component::{skeleton::Skeleton, v_flex};
+use gpui_component::{
+    button::Button,
+    skeleton::{Skeleton, SkeletonExt as _, SkeletonList, SkeletonTableRow, SkeletonText},
+    v_flex,
+};
 
 use crate::section;
 
 pub struct SkeletonStory {
     focus_handle: gpui::FocusHandle,
     value: f32,
+    loading: bool,
 }
 
 impl super::Story for SkeletonStory {
@@ -34,6 +39,7 @@ impl SkeletonStory {
         Self {
             focus_handle: cx.focus_handle(),
             value: 50.,
+            loading: true,
         }
     }
 
@@ -49,7 +55,7 @@ impl Focusable for SkeletonStory {
 }
 
 impl Render for SkeletonStory {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         v_flex()
             .items_center()
             .gap_y_3()
@@ -77,5 +83,40 @@ impl Render for SkeletonStory {
                         ),
                 ),
             )
+            .child(
+                section("Shimmer")
+                    .max_w_md()
+                    .child(SkeletonText::new(3).shimmer(true).w(px(250.))),
+            )
+            .child(
+                section("Table Rows").max_w_md().child(
+                    v_flex()
+                        .w(px(400.))
+                        .children((0..3).map(|_| SkeletonTableRow::new(4).shimmer(true))),
+                ),
+            )
+            .child(
+                section("List")
+                    .max_w_md()
+                    .child(SkeletonList::new(3).w(px(300.))),
+            )
+            .child(
+                section("When Loading")
+                    .max_w_md()
+                    .child(
+                        Button::new("toggle-loading")
+                            .outline()
+                            .label("Toggle Loading")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.loading = !this.loading;
+                                cx.notify();
+                            })),
+                    )
+                    .child(v_flex().w(px(300.)).when_loading(
+                        self.loading,
+                        SkeletonText::new(2),
+                        || "The content is loaded, click the button to show the skeleton again.",
+                    )),
+            )
     }
 }
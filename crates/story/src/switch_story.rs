@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use gpui::{
     px, App, AppContext, Context, Div, Entity, FocusHandle, Focusable, IntoElement, ParentElement,
     Render, SharedString, Styled, Window,
@@ -14,6 +16,7 @@ pub struct SwitchStory {
     switch1: bool,
     switch2: bool,
     switch3: bool,
+    switch4: bool,
 }
 
 impl super::Story for SwitchStory {
@@ -41,6 +44,7 @@ impl SwitchStory {
             switch1: true,
             switch2: false,
             switch3: true,
+            switch4: false,
         }
     }
 }
@@ -143,6 +147,25 @@ impl Render for SwitchStory {
                                 cx.notify();
                             })),
                     ),
+                )
+                .child(
+                    section("Before Change").child(
+                        Switch::new("switch4")
+                            .checked(self.switch4)
+                            .label("Sync")
+                            .description("Wait 1 second to confirm the change.")
+                            .on_before_change(|_, _, cx| {
+                                let timer = cx.background_executor().timer(Duration::from_secs(1));
+                                cx.background_spawn(async move {
+                                    timer.await;
+                                    true
+                                })
+                            })
+                            .on_click(cx.listener(move |view, checked, _, cx| {
+                                view.switch4 = *checked;
+                                cx.notify();
+                            })),
+                    ),
                 ),
         )
     }
@@ -6,9 +6,9 @@ use std::{
 
 use fake::Fake;
 use gpui::{
-    div, prelude::FluentBuilder as _, Action, AnyElement, App, AppContext, ClickEvent, Context,
-    Entity, Focusable, InteractiveElement, IntoElement, ParentElement, Render, SharedString,
-    StatefulInteractiveElement, Styled, TextAlign, Timer, Window,
+    div, prelude::FluentBuilder as _, Action, AnyElement, App, AppContext, ClickEvent,
+    ClipboardItem, Context, Entity, Focusable, InteractiveElement, IntoElement, ParentElement,
+    Render, SharedString, StatefulInteractiveElement, Styled, TextAlign, Timer, Window,
 };
 use gpui_component::{
     button::Button,
@@ -18,8 +18,11 @@ use gpui_component::{
     input::{InputEvent, InputState, TextInput},
     label::Label,
     popup_menu::{PopupMenu, PopupMenuExt},
-    table::{Column, ColumnFixed, ColumnSort, Table, TableDelegate, TableEvent},
-    v_flex, ActiveTheme as _, Selectable, Sizable as _, Size, StyleSized as _, StyledExt,
+    table::{
+        Column, ColumnFixed, ColumnSort, DataTable, Table, TableDelegate, TableEvent, TableState,
+    },
+    v_flex, ActiveTheme as _, Density, Disableable as _, Selectable, Sizable as _, Size,
+    StyleSized as _, StyledExt,
 };
 use serde::{Deserialize, Serialize};
 
@@ -252,7 +255,7 @@ impl StockTableDelegate {
                 Column::new("day_10_ranking", "10d Ranking"),
                 Column::new("day_30_ranking", "30d Ranking"),
                 Column::new("day_120_ranking", "120d Ranking"),
-                Column::new("day_250_ranking", "250d Ranking"),
+                Column::new("day_250_ranking", "250d Ranking").fixed(ColumnFixed::Right),
             ],
             loading: false,
             full_loading: false,
@@ -506,6 +509,51 @@ impl TableDelegate for StockTableDelegate {
         self.columns.insert(to_ix, col);
     }
 
+    fn cell_text(&self, row_ix: usize, col_ix: usize, _: &App) -> SharedString {
+        let (Some(stock), Some(col)) = (self.stocks.get(row_ix), self.columns.get(col_ix)) else {
+            return SharedString::default();
+        };
+
+        match col.key.as_ref() {
+            "id" => stock.id.to_string().into(),
+            "market" => stock.counter.market.clone(),
+            "symbol" => stock.counter.symbol_code(),
+            "name" => stock.counter.name.clone(),
+            "price" => format!("{:.3}", stock.price).into(),
+            "change" => format!("{:.3}", stock.change).into(),
+            "change_percent" => format!("{:.2}%", stock.change_percent * 100.).into(),
+            "volume" => format!("{:.0}", stock.volume).into(),
+            _ => SharedString::default(),
+        }
+    }
+
+    fn render_expanded_row(
+        &self,
+        row_ix: usize,
+        _: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> impl IntoElement {
+        let stock = &self.stocks[row_ix];
+        v_flex()
+            .size_full()
+            .gap_1()
+            .p_3()
+            .bg(cx.theme().muted)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(Label::new(stock.counter.name.clone()).font_semibold())
+                    .child(
+                        Label::new(format!("{}.{}", stock.counter.symbol, stock.counter.market))
+                            .text_color(cx.theme().muted_foreground),
+                    ),
+            )
+            .child(format!("Price: {:.3}", stock.price))
+            .child(format!("Change: {:.2}%", stock.change_percent * 100.))
+            .child(format!("Volume: {:.0}", stock.volume))
+            .child(format!("Market Cap: {:.0}", stock.market_cap))
+    }
+
     fn perform_sort(
         &mut self,
         col_ix: usize,
@@ -588,6 +636,12 @@ pub struct TableStory {
     table: Entity<Table<StockTableDelegate>>,
     num_stocks_input: Entity<InputState>,
     stripe: bool,
+    row_hover: bool,
+    row_dividers: bool,
+    compact: bool,
+    frozen_rows: bool,
+    expandable: bool,
+    saved_layout: Option<TableState>,
     refresh_data: bool,
     size: Size,
 }
@@ -671,6 +725,12 @@ impl TableStory {
             table,
             num_stocks_input,
             stripe: false,
+            row_hover: true,
+            row_dividers: true,
+            compact: false,
+            frozen_rows: false,
+            expandable: false,
+            saved_layout: None,
             refresh_data: false,
             size: Size::default(),
         }
@@ -738,6 +798,13 @@ impl TableStory {
         });
     }
 
+    fn toggle_cell_selection(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.table.update(cx, |table, cx| {
+            table.cell_selectable = *checked;
+            cx.notify();
+        });
+    }
+
     fn toggle_col_selection(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
         self.table.update(cx, |table, cx| {
             table.col_selectable = *checked;
@@ -754,6 +821,47 @@ impl TableStory {
         });
     }
 
+    fn toggle_row_hover(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.row_hover = *checked;
+        self.table.update(cx, |table, cx| {
+            table.set_row_hover(*checked, cx);
+        });
+    }
+
+    fn toggle_row_dividers(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.row_dividers = *checked;
+        self.table.update(cx, |table, cx| {
+            table.set_row_dividers(*checked, cx);
+        });
+    }
+
+    fn toggle_compact(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.compact = *checked;
+        let density = if *checked {
+            Density::Compact
+        } else {
+            Density::Comfortable
+        };
+        self.table.update(cx, |table, cx| {
+            table.set_density(density, cx);
+        });
+    }
+
+    fn toggle_frozen_rows(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.frozen_rows = *checked;
+        let frozen_rows = if *checked { 2 } else { 0 };
+        self.table.update(cx, |table, cx| {
+            table.set_frozen_rows(frozen_rows, cx);
+        });
+    }
+
+    fn toggle_expandable(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.expandable = *checked;
+        self.table.update(cx, |table, cx| {
+            table.set_expandable(*checked, cx);
+        });
+    }
+
     fn on_change_size(&mut self, a: &ChangeSize, _: &mut Window, cx: &mut Context<Self>) {
         self.size = a.0;
         self.table.update(cx, |table, cx| {
@@ -784,6 +892,8 @@ impl TableStory {
             TableEvent::MoveColumn(origin_idx, target_idx) => {
                 println!("Move col index: {} -> {}", origin_idx, target_idx);
             }
+            TableEvent::SelectCells(range) => println!("Select cells: {:?}", range),
+            _ => {}
         }
     }
 }
@@ -835,6 +945,12 @@ impl Render for TableStory {
                             .selected(table.col_selectable)
                             .on_click(cx.listener(Self::toggle_col_selection)),
                     )
+                    .child(
+                        Checkbox::new("cell-selection")
+                            .label("Cell Selectable")
+                            .selected(table.cell_selectable)
+                            .on_click(cx.listener(Self::toggle_cell_selection)),
+                    )
                     .child(
                         Checkbox::new("fixed")
                             .label("Column Fixed")
@@ -847,6 +963,36 @@ impl Render for TableStory {
                             .selected(self.stripe)
                             .on_click(cx.listener(Self::toggle_stripe)),
                     )
+                    .child(
+                        Checkbox::new("row-hover")
+                            .label("Row Hover")
+                            .selected(self.row_hover)
+                            .on_click(cx.listener(Self::toggle_row_hover)),
+                    )
+                    .child(
+                        Checkbox::new("row-dividers")
+                            .label("Row Dividers")
+                            .selected(self.row_dividers)
+                            .on_click(cx.listener(Self::toggle_row_dividers)),
+                    )
+                    .child(
+                        Checkbox::new("compact")
+                            .label("Compact")
+                            .selected(self.compact)
+                            .on_click(cx.listener(Self::toggle_compact)),
+                    )
+                    .child(
+                        Checkbox::new("frozen-rows")
+                            .label("Frozen Rows")
+                            .selected(self.frozen_rows)
+                            .on_click(cx.listener(Self::toggle_frozen_rows)),
+                    )
+                    .child(
+                        Checkbox::new("expandable")
+                            .label("Expandable")
+                            .selected(self.expandable)
+                            .on_click(cx.listener(Self::toggle_expandable)),
+                    )
                     .child(
                         Checkbox::new("loading")
                             .label("Loading")
@@ -917,6 +1063,40 @@ impl Render for TableStory {
                                     table.scroll_to_row(table.delegate().rows_count(cx) - 1, cx);
                                 })
                             })),
+                    )
+                    .child(
+                        Button::new("save-layout")
+                            .outline()
+                            .small()
+                            .child("Save Layout")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.saved_layout = Some(TableState::save(this.table.read(cx)));
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("restore-layout")
+                            .outline()
+                            .small()
+                            .child("Restore Layout")
+                            .disabled(self.saved_layout.is_none())
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                if let Some(state) = this.saved_layout.clone() {
+                                    this.table.update(cx, |table, cx| {
+                                        state.load(table, window, cx);
+                                    });
+                                }
+                            })),
+                    )
+                    .child(
+                        Button::new("export-csv")
+                            .outline()
+                            .small()
+                            .child("Copy as CSV")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                let csv = this.table.read(cx).export_csv(cx);
+                                cx.write_to_clipboard(ClipboardItem::new_string(csv));
+                            })),
                     ), // .child(
                        //     Button::new("scroll-first-col")
                        //         .child("Scroll to First Column")
@@ -975,6 +1155,6 @@ impl Render for TableStory {
                         ),
                 ),
             )
-            .child(self.table.clone())
+            .child(DataTable::new(&self.table))
     }
 }
@@ -1,13 +1,13 @@
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Render,
-    Styled, Window,
+    SharedString, Styled, Window,
 };
 
 use gpui_component::{
     button::{Button, ButtonGroup, ButtonVariants},
     checkbox::Checkbox,
     h_flex,
-    tab::{Tab, TabBar},
+    tab::{Tab, TabBar, TabItem, Tabs},
     v_flex, IconName, Selectable as _, Sizable, Size,
 };
 
@@ -18,6 +18,8 @@ pub struct TabsStory {
     active_tab_ix: usize,
     size: Size,
     menu: bool,
+    documents: Vec<SharedString>,
+    document_ix: usize,
 }
 
 impl super::Story for TabsStory {
@@ -45,6 +47,10 @@ impl TabsStory {
             active_tab_ix: 0,
             size: Size::default(),
             menu: false,
+            documents: (1..=12)
+                .map(|ix| SharedString::from(format!("Document {}", ix)))
+                .collect(),
+            document_ix: 0,
         }
     }
 
@@ -244,5 +250,58 @@ impl Render for TabsStory {
                         .children(vec!["Appearance", "Settings", "About", "License"]),
                 ),
             )
+            .child(
+                section("Tabs with Content").max_w_md().child(
+                    Tabs::new("closable-tabs")
+                        .w_full()
+                        .h_32()
+                        .with_size(self.size)
+                        .with_menu(self.menu)
+                        .selected_index(self.document_ix)
+                        .on_change(cx.listener(|this, ix: &usize, _, cx| {
+                            this.document_ix = *ix;
+                            cx.notify();
+                        }))
+                        .on_close(cx.listener(|this, ix: &usize, _, cx| {
+                            this.documents.remove(*ix);
+                            if this.document_ix > *ix {
+                                this.document_ix -= 1;
+                            }
+                            cx.notify();
+                        }))
+                        .children(self.documents.iter().enumerate().map(|(ix, name)| {
+                            let name = name.clone();
+                            TabItem::new(name.clone())
+                                .icon(IconName::BookOpen)
+                                .badge(if ix == 1 { 3 } else { 0 })
+                                .disabled(ix == 2)
+                                .closable(true)
+                                .content(move |_, _| {
+                                    v_flex().p_3().child(format!("The content of {}.", name))
+                                })
+                        })),
+                ),
+            )
+            .child(
+                section("Vertical Tabs").max_w_md().child(
+                    Tabs::new("vertical-tabs")
+                        .vertical()
+                        .pill()
+                        .w_full()
+                        .h_40()
+                        .with_size(self.size)
+                        .selected_index(self.active_tab_ix)
+                        .on_change(cx.listener(|this, ix: &usize, window, cx| {
+                            this.set_active_tab(*ix, window, cx);
+                        }))
+                        .child(TabItem::new("Account").icon(IconName::CircleUser))
+                        .child(TabItem::new("Profile").disabled(true))
+                        .child(TabItem::new("Mail").icon(IconName::Inbox).badge(12))
+                        .child(TabItem::new("Settings").icon(IconName::Settings))
+                        .children(["Appearance", "About", "License"].map(|label| {
+                            TabItem::new(label).content(move |_, _| v_flex().p_3().child(label))
+                        })),
+                ),
+            )
     }
 }
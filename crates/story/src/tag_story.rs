@@ -1,14 +1,18 @@
 use gpui::{
-    px, App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement,
-    Render, Styled, Window,
+    prelude::FluentBuilder as _, px, App, AppContext, Context, Entity, FocusHandle, Focusable,
+    IntoElement, ParentElement, Render, SharedString, Styled, Window,
 };
 
-use gpui_component::{h_flex, indigo_50, indigo_500, tag::Tag, v_flex, ColorName, Sizable};
+use gpui_component::{
+    h_flex, indigo_50, indigo_500, tag::Tag, v_flex, ColorName, IconName, Sizable,
+};
 
-use crate::section;
+use crate::{section, Knobs};
 
 pub struct TagStory {
     focus_handle: FocusHandle,
+    knobs: Entity<Knobs>,
+    chips: Vec<SharedString>,
 }
 
 impl super::Story for TagStory {
@@ -23,12 +27,43 @@ impl super::Story for TagStory {
     fn new_view(window: &mut Window, cx: &mut App) -> Entity<impl Render> {
         Self::view(window, cx)
     }
+
+    fn knobs(&self) -> Option<Entity<Knobs>> {
+        Some(self.knobs.clone())
+    }
 }
 
 impl TagStory {
-    pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
+    pub(crate) fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        let knobs = Knobs::new(cx, |knobs| {
+            knobs
+                .select(
+                    "Variant",
+                    [
+                        "Primary",
+                        "Secondary",
+                        "Danger",
+                        "Success",
+                        "Warning",
+                        "Info",
+                    ],
+                    0,
+                )
+                .bool("Outline", false)
+                .bool("Small", false)
+                .bool("Rounded Full", false)
+                .number("Radius", 4., 0., 12., 1.)
+        });
+
         Self {
             focus_handle: cx.focus_handle(),
+            knobs,
+            chips: vec![
+                "Rust".into(),
+                "GPUI".into(),
+                "Zed".into(),
+                "Component".into(),
+            ],
         }
     }
 
@@ -42,9 +77,57 @@ impl Focusable for TagStory {
     }
 }
 impl Render for TagStory {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let knobs = self.knobs.read(cx);
+        let tag = match knobs.get_selected("Variant") {
+            1 => Tag::secondary(),
+            2 => Tag::danger(),
+            3 => Tag::success(),
+            4 => Tag::warning(),
+            5 => Tag::info(),
+            _ => Tag::primary(),
+        };
+        let tag = tag
+            .when(knobs.get_bool("Outline"), |this| this.outline())
+            .when(knobs.get_bool("Small"), |this| this.small())
+            .map(|this| {
+                if knobs.get_bool("Rounded Full") {
+                    this.rounded_full()
+                } else {
+                    this.rounded(px(knobs.get_number("Radius")))
+                }
+            })
+            .child("Playground");
+
         v_flex()
             .gap_6()
+            .child(section("Playground").child(tag))
+            .child(
+                section("Tag with icon").child(
+                    h_flex()
+                        .gap_2()
+                        .child(Tag::success().icon(IconName::CircleCheck).child("Passed"))
+                        .child(Tag::danger().icon(IconName::CircleX).child("Failed"))
+                        .child(
+                            Tag::secondary()
+                                .outline()
+                                .icon(IconName::Star)
+                                .child("Starred"),
+                        ),
+                ),
+            )
+            .child(section("Closable Tag").child(h_flex().gap_2().children(
+                self.chips.iter().enumerate().map(|(ix, chip)| {
+                    Tag::secondary()
+                        .id(ix)
+                        .rounded_full()
+                        .child(chip.clone())
+                        .on_close(cx.listener(move |this, _, _, cx| {
+                            this.chips.remove(ix);
+                            cx.notify();
+                        }))
+                }),
+            )))
             .child(
                 section("Tag (default)").child(
                     h_flex()
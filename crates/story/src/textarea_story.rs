@@ -1,13 +1,16 @@
+use std::rc::Rc;
+
 use gpui::{
     px, App, AppContext as _, ClickEvent, Context, Entity, Focusable, InteractiveElement,
-    IntoElement, ParentElement as _, Render, Styled, Window,
+    IntoElement, ParentElement as _, Render, Styled, Subscription, Window,
 };
 
 use crate::section;
 use gpui_component::{
     button::Button,
     h_flex,
-    input::{InputState, TextInput},
+    input::{InputEvent, InputState, PatternLinkProvider, TextInput, UrlLinkProvider},
+    text::{SelectionToolbar, SelectionToolbarItem},
     v_flex, Sizable,
 };
 
@@ -17,6 +20,7 @@ pub struct TextareaStory {
     textarea: Entity<InputState>,
     textarea_auto_grow: Entity<InputState>,
     textarea_no_wrap: Entity<InputState>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl super::Story for TextareaStory {
@@ -49,6 +53,28 @@ impl TextareaStory {
                 .rows(10)
                 .placeholder("Enter text here...")
                 .searchable(true)
+                .selection_toolbar(
+                    SelectionToolbar::new()
+                        .item(SelectionToolbarItem::copy())
+                        .item(SelectionToolbarItem::search_web(
+                            "https://www.google.com/search?q={query}",
+                        )),
+                )
+                // Reject the images larger than 10MB.
+                .accept_media(|image, _| image.bytes().len() <= 10 * 1024 * 1024)
+                // Linkify the issue IDs, e.g.: `#123`, in addition to the URLs.
+                .link_providers(vec![
+                    Rc::new(UrlLinkProvider),
+                    Rc::new(PatternLinkProvider::new(
+                        regex::Regex::new(r"#\d+").unwrap(),
+                        |link, _, cx| {
+                            cx.open_url(&format!(
+                                "https://github.com/longbridge/gpui-component/issues/{}",
+                                &link.target[1..]
+                            ))
+                        },
+                    )),
+                ])
                 .default_value(
                     unindent::unindent(
                         r#"Hello 世界，this is GPUI component.
@@ -68,6 +94,8 @@ impl TextareaStory {
                     ## Demo
 
                     If you want to see the demo, here is a some demo applications.
+
+                    See https://github.com/longbridge/gpui-component and #1 for more details.
                     "#,
                     )
                 )
@@ -88,10 +116,17 @@ impl TextareaStory {
                 .default_value("This is a very long line of text to test if the horizontal scrolling function is working properly, and it should not wrap automatically but display a horizontal scrollbar.\nThe second line is also very long text, used to test the horizontal scrolling effect under multiple lines, and you can input more content to test.\nThe third line: Here you can input other long text content that requires horizontal scrolling.\n")
         });
 
+        let _subscriptions = vec![cx.subscribe(&textarea, |_, _, event: &InputEvent, _| {
+            if let InputEvent::MediaAdded { image, token } = event {
+                println!("MediaAdded: {} ({} bytes)", token, image.bytes().len());
+            }
+        })];
+
         Self {
             textarea,
             textarea_auto_grow,
             textarea_no_wrap,
+            _subscriptions,
         }
     }
 
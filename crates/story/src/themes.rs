@@ -55,9 +55,8 @@ pub fn init(cx: &mut App) {
     .detach();
 
     cx.on_action(|switch: &SwitchTheme, cx| {
-        let theme_name = switch.0.clone();
-        if let Some(theme_config) = ThemeRegistry::global(cx).themes().get(&theme_name).cloned() {
-            Theme::global_mut(cx).apply_config(&theme_config);
+        if let Err(err) = Theme::change_theme(&switch.0, None, cx) {
+            tracing::error!("Failed to switch theme: {}", err);
         }
         cx.refresh_windows();
     });
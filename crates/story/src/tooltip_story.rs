@@ -11,7 +11,7 @@ use gpui_component::{
     radio::Radio,
     switch::Switch,
     tooltip::Tooltip,
-    v_flex, ActiveTheme, IconName,
+    v_flex, ActiveTheme, IconName, Placement,
 };
 
 use crate::{section, Story};
@@ -101,6 +101,57 @@ impl Render for TooltipStory {
                             }),
                     ),
             )
+            .child(
+                section("Tooltip Placement")
+                    .child(
+                        Button::new("placement-top")
+                            .label("Top")
+                            .tooltip("Tooltip on the top")
+                            .tooltip_placement(Placement::Top),
+                    )
+                    .child(
+                        Button::new("placement-bottom")
+                            .label("Bottom")
+                            .tooltip("Tooltip on the bottom")
+                            .tooltip_placement(Placement::Bottom),
+                    )
+                    .child(
+                        Button::new("placement-left")
+                            .label("Left")
+                            .tooltip("Tooltip on the left")
+                            .tooltip_placement(Placement::Left),
+                    )
+                    .child(
+                        Button::new("placement-right")
+                            .label("Right")
+                            .tooltip("Tooltip on the right")
+                            .tooltip_placement(Placement::Right),
+                    ),
+            )
+            .child(
+                section("Rich Tooltip").child(
+                    Button::new("rich-tooltip")
+                        .label("Rich Content")
+                        .tooltip_builder(|window, cx| {
+                            Tooltip::element(|_, cx| {
+                                v_flex()
+                                    .gap_1()
+                                    .child(
+                                        h_flex()
+                                            .gap_x_1()
+                                            .child(IconName::Info)
+                                            .child("Keyboard accessible"),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child("Focus this button by Tab to show the tooltip."),
+                                    )
+                            })
+                            .build(window, cx)
+                        }),
+                ),
+            )
             .child(
                 section("Label Tooltip").child(div().child("Hover me").id("tooltip-2").tooltip(
                     |window, cx| {
@@ -1,13 +1,36 @@
+use std::time::Duration;
+
 use gpui::{
-    App, AppContext, Context, Entity, FocusHandle, Focusable, ParentElement, Render, Styled, Window,
+    App, AppContext, Context, Entity, FocusHandle, Focusable, ParentElement, Render, SharedString,
+    Styled, Task, Window,
 };
 
-use gpui_component::{dock::PanelControl, text::TextView, v_flex};
+use gpui_component::{
+    dock::PanelControl,
+    text::{SelectionToolbar, SelectionToolbarItem, SpeechProvider, SpeechState, TextView},
+    v_flex, ContextModal as _, IconName,
+};
 
 use crate::Story;
 
 pub struct WelcomeStory {
     focus_handle: FocusHandle,
+    speech: Entity<SpeechState>,
+}
+
+/// A fake speech provider that only waits for the reading time of the text,
+/// replace it with a real TTS service in your application.
+struct DemoSpeech;
+
+impl SpeechProvider for DemoSpeech {
+    fn speak(&self, text: SharedString, cx: &mut App) -> Task<anyhow::Result<()>> {
+        let duration = Duration::from_millis(60 * text.chars().count() as u64);
+        let timer = cx.background_executor().timer(duration);
+        cx.background_spawn(async move {
+            timer.await;
+            Ok(())
+        })
+    }
 }
 
 impl WelcomeStory {
@@ -18,6 +41,7 @@ impl WelcomeStory {
     fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
         Self {
             focus_handle: cx.focus_handle(),
+            speech: cx.new(|_| SpeechState::new(DemoSpeech)),
         }
     }
 }
@@ -54,7 +78,35 @@ impl Render for WelcomeStory {
     ) -> impl gpui::IntoElement {
         v_flex().p_4().gap_5().child(
             TextView::markdown("intro", include_str!("../../../README.md"), window, cx)
-                .selectable(),
+                .selectable()
+                .selection_toolbar(
+                    SelectionToolbar::new()
+                        .item(SelectionToolbarItem::copy())
+                        .item(
+                            SelectionToolbarItem::new(
+                                "highlight",
+                                IconName::Palette,
+                                |text, window, cx| {
+                                    window.push_notification(format!("Highlighted: {}", text), cx);
+                                },
+                            )
+                            .tooltip("Highlight"),
+                        )
+                        .item(
+                            SelectionToolbarItem::new(
+                                "comment",
+                                IconName::Inbox,
+                                |text, window, cx| {
+                                    window.push_notification(format!("Commented: {}", text), cx);
+                                },
+                            )
+                            .tooltip("Comment"),
+                        )
+                        .item(SelectionToolbarItem::search_web(
+                            "https://www.google.com/search?q={query}",
+                        )),
+                )
+                .speech(&self.speech),
         )
     }
 }
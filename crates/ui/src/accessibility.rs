@@ -0,0 +1,277 @@
+//! The accessibility metadata of the components.
+//!
+//! GPUI has no platform accessibility tree yet, so the components only declare their
+//! metadata (role, label, states) while rendering, and the host application can plug
+//! an [`AccessibilityHost`] to bridge them to a screen reader.
+//!
+//! ```ignore
+//! use gpui_component::accessibility::{Accessibility, AccessibilityHost};
+//!
+//! Accessibility::set_host(MyScreenReaderBridge::new(), cx);
+//! ```
+use std::rc::Rc;
+
+use gpui::{App, ElementId, Global, SharedString, Window};
+
+/// The role of an accessible element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Alert,
+    Button,
+    Checkbox,
+    ComboBox,
+    Dialog,
+    Group,
+    Link,
+    List,
+    ListItem,
+    Menu,
+    MenuItem,
+    Radio,
+    Status,
+    Switch,
+    Tab,
+    TabList,
+    Table,
+    TextInput,
+}
+
+/// The checked state of a Checkbox, Switch or Radio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Checked {
+    False,
+    True,
+    /// The indeterminate state of the Checkbox.
+    Mixed,
+}
+
+impl From<bool> for Checked {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// The accessibility metadata of an element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccessibilityProps {
+    pub role: Option<Role>,
+    /// The accessible name, default is the visible label of the component.
+    pub label: Option<SharedString>,
+    /// The accessible description, e.g.: the help text of a form field.
+    pub description: Option<SharedString>,
+    /// The id of the element that describes this element, e.g.: the error message.
+    pub described_by: Option<ElementId>,
+    /// The id of the element that labels this element, e.g.: the label of a form field.
+    pub labelled_by: Option<ElementId>,
+    pub checked: Option<Checked>,
+    pub expanded: Option<bool>,
+    pub selected: Option<bool>,
+    pub disabled: bool,
+    pub invalid: bool,
+}
+
+impl AccessibilityProps {
+    /// Create the props with the `role`.
+    pub fn new(role: Role) -> Self {
+        Self {
+            role: Some(role),
+            ..Default::default()
+        }
+    }
+
+    pub fn checked(mut self, checked: impl Into<Checked>) -> Self {
+        self.checked = Some(checked.into());
+        self
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Merge the props set by the user, the user props have higher priority.
+    pub fn merge(mut self, other: &AccessibilityProps) -> Self {
+        if other.role.is_some() {
+            self.role = other.role;
+        }
+        if other.label.is_some() {
+            self.label = other.label.clone();
+        }
+        if other.description.is_some() {
+            self.description = other.description.clone();
+        }
+        if other.described_by.is_some() {
+            self.described_by = other.described_by.clone();
+        }
+        if other.labelled_by.is_some() {
+            self.labelled_by = other.labelled_by.clone();
+        }
+        if other.checked.is_some() {
+            self.checked = other.checked;
+        }
+        if other.expanded.is_some() {
+            self.expanded = other.expanded;
+        }
+        if other.selected.is_some() {
+            self.selected = other.selected;
+        }
+        self.disabled |= other.disabled;
+        self.invalid |= other.invalid;
+        self
+    }
+}
+
+/// The politeness of a live-region announcement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Politeness {
+    /// Announce when the user is idle, e.g.: a notification.
+    #[default]
+    Polite,
+    /// Announce immediately, e.g.: an error.
+    Assertive,
+}
+
+/// A live-region announcement to be read by the screen reader.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Announcement {
+    pub message: SharedString,
+    pub politeness: Politeness,
+}
+
+/// The bridge of the accessibility metadata to the platform accessibility tree.
+pub trait AccessibilityHost: 'static {
+    /// Called when an accessible element is rendered.
+    fn node(&self, id: &ElementId, props: &AccessibilityProps, window: &mut Window, cx: &mut App);
+
+    /// Called when an announcement is made, see [`Accessibility::announce`].
+    fn announce(&self, announcement: &Announcement, window: &mut Window, cx: &mut App);
+}
+
+/// The global accessibility state.
+#[derive(Default)]
+pub struct Accessibility {
+    host: Option<Rc<dyn AccessibilityHost>>,
+}
+
+impl Global for Accessibility {}
+
+impl Accessibility {
+    /// Set the accessibility host to receive the metadata of the components.
+    pub fn set_host(host: impl AccessibilityHost, cx: &mut App) {
+        cx.set_global(Self {
+            host: Some(Rc::new(host)),
+        });
+    }
+
+    /// Returns true if there is an accessibility host.
+    pub fn is_enabled(cx: &App) -> bool {
+        Self::host(cx).is_some()
+    }
+
+    fn host(cx: &App) -> Option<Rc<dyn AccessibilityHost>> {
+        cx.try_global::<Self>().and_then(|this| this.host.clone())
+    }
+
+    /// Declare the accessibility metadata of the element, this should be called on render.
+    pub fn declare(id: &ElementId, props: &AccessibilityProps, window: &mut Window, cx: &mut App) {
+        if let Some(host) = Self::host(cx) {
+            host.node(id, props, window, cx);
+        }
+    }
+
+    /// Make a live-region announcement, e.g.: for notifications and validation errors.
+    pub fn announce(
+        message: impl Into<SharedString>,
+        politeness: Politeness,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(host) = Self::host(cx) {
+            let announcement = Announcement {
+                message: message.into(),
+                politeness,
+            };
+            host.announce(&announcement, window, cx);
+        }
+    }
+}
+
+/// A trait for the elements that have accessibility metadata.
+pub trait Accessible: Sized {
+    /// Returns the mutable accessibility props set by the user.
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps;
+
+    /// Set the accessible name of the element, default is the visible label.
+    fn aria_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessibility_props().label = Some(label.into());
+        self
+    }
+
+    /// Set the accessible description of the element.
+    fn aria_description(mut self, description: impl Into<SharedString>) -> Self {
+        self.accessibility_props().description = Some(description.into());
+        self
+    }
+
+    /// Set the id of the element that describes this element.
+    fn described_by(mut self, id: impl Into<ElementId>) -> Self {
+        self.accessibility_props().described_by = Some(id.into());
+        self
+    }
+
+    /// Set the id of the element that labels this element.
+    fn labelled_by(mut self, id: impl Into<ElementId>) -> Self {
+        self.accessibility_props().labelled_by = Some(id.into());
+        self
+    }
+
+    /// Override the role of the element.
+    fn role(mut self, role: Role) -> Self {
+        self.accessibility_props().role = Some(role);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessibilityProps, Checked, Role};
+
+    #[test]
+    fn test_merge_props() {
+        let props = AccessibilityProps::new(Role::Checkbox)
+            .checked(true)
+            .disabled(false);
+        let user = AccessibilityProps {
+            label: Some("Accept".into()),
+            checked: Some(Checked::Mixed),
+            disabled: true,
+            ..Default::default()
+        };
+
+        let props = props.merge(&user);
+        assert_eq!(props.role, Some(Role::Checkbox));
+        assert_eq!(props.label, Some("Accept".into()));
+        assert_eq!(props.checked, Some(Checked::Mixed));
+        assert!(props.disabled);
+        assert!(!props.invalid);
+    }
+}
@@ -1,12 +1,15 @@
 use std::{cell::RefCell, collections::HashSet, rc::Rc, sync::Arc};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, rems, AnyElement, App, ElementId, InteractiveElement as _,
-    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled,
-    Window,
+    canvas, div, prelude::FluentBuilder as _, rems, AnyElement, App, ElementId,
+    InteractiveElement as _, IntoElement, ParentElement, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, Window,
 };
 
-use crate::{h_flex, v_flex, ActiveTheme as _, Icon, IconName, Sizable, Size};
+use crate::{
+    animation::{Transition, TransitionExt as _},
+    h_flex, v_flex, ActiveTheme as _, Icon, IconName, Sizable, Size,
+};
 
 /// An AccordionGroup is a container for multiple Accordion elements.
 #[derive(IntoElement)]
@@ -210,7 +213,7 @@ impl Sizable for AccordionItem {
 }
 
 impl RenderOnce for AccordionItem {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let text_size = match self.size {
             Size::XSmall => rems(0.875),
             Size::Small => rems(0.875),
@@ -286,15 +289,54 @@ impl RenderOnce for AccordionItem {
                         }),
                 )
                 .when(self.open, |this| {
+                    // The height of the content is measured to expand it from 0.
+                    let content_height = window.use_keyed_state(
+                        ElementId::NamedInteger(
+                            "accordion-content-height".into(),
+                            self.index as u64,
+                        ),
+                        cx,
+                        |_, _| None::<Pixels>,
+                    );
+                    let height = *content_height.read(cx);
+
                     this.child(
                         div()
-                            .map(|this| match self.size {
-                                Size::XSmall => this.p_1p5(),
-                                Size::Small => this.p_2(),
-                                Size::Large => this.p_4(),
-                                _ => this.p_3(),
-                            })
-                            .child(self.content),
+                            .overflow_hidden()
+                            .child(
+                                div()
+                                    .relative()
+                                    .map(|this| match self.size {
+                                        Size::XSmall => this.p_1p5(),
+                                        Size::Small => this.p_2(),
+                                        Size::Large => this.p_4(),
+                                        _ => this.p_3(),
+                                    })
+                                    .child(self.content)
+                                    .child(
+                                        canvas(
+                                            move |bounds, _, cx| {
+                                                content_height.update(cx, |height, _| {
+                                                    *height = Some(bounds.size.height)
+                                                })
+                                            },
+                                            |_, _, _, _| {},
+                                        )
+                                        .absolute()
+                                        .size_full(),
+                                    ),
+                            )
+                            .with_transition(
+                                ElementId::NamedInteger("expand".into(), self.index as u64),
+                                Transition::NORMAL,
+                                cx,
+                                move |this, delta| {
+                                    this.opacity(delta)
+                                        .when_some(height.filter(|_| delta < 1.), |this, height| {
+                                            this.h(height * delta)
+                                        })
+                                },
+                            ),
                     )
                 }),
         )
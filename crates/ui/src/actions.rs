@@ -10,5 +10,15 @@ pub struct Confirm {
 
 actions!(
     list,
-    [Cancel, SelectPrev, SelectNext, SelectLeft, SelectRight]
+    [
+        Cancel,
+        SelectPrev,
+        SelectNext,
+        SelectLeft,
+        SelectRight,
+        SelectFirst,
+        SelectLast,
+        SelectPageUp,
+        SelectPageDown
+    ]
 );
@@ -1,3 +1,22 @@
+//! The animation presets (duration, easing), the enter / exit transitions and the spring
+//! interpolation used by the components.
+//!
+//! The transitions are disabled (the final state is applied directly) when the
+//! [`crate::Theme::reduce_motion`] is enabled.
+//!
+//! ```ignore
+//! use gpui_component::animation::{Transition, TransitionExt as _};
+//!
+//! div()
+//!     .child("Hello")
+//!     .with_transition("fade-in", Transition::NORMAL, cx, |this, delta| this.opacity(delta))
+//! ```
+use std::time::Duration;
+
+use gpui::{Animation, AnimationExt as _, AnyElement, App, ElementId, IntoElement};
+
+use crate::ActiveTheme as _;
+
 /// A cubic bezier function like CSS `cubic-bezier`.
 ///
 /// Builder:
@@ -17,3 +36,244 @@ pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> impl Fn(f32) -> f32 {
         y
     }
 }
+
+/// Linear interpolate between `from` and `to` by the `delta` (0.0 - 1.0).
+#[inline]
+pub fn lerp(from: f32, to: f32, delta: f32) -> f32 {
+    from + (to - from) * delta
+}
+
+/// A spring to interpolate the values with a physical motion, the value may overshoot
+/// the target when the spring is under damped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170., 26.)
+    }
+}
+
+impl Spring {
+    /// A spring with a little bounce, e.g.: for the popups.
+    pub const BOUNCY: Self = Self::new(180., 12.);
+
+    /// Create a new spring with the `stiffness` and `damping`, the mass is 1.0.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.,
+        }
+    }
+
+    #[inline]
+    fn omega(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    #[inline]
+    fn damping_ratio(&self) -> f32 {
+        self.damping / (2. * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the duration for the spring to settle (within 0.1% of the target).
+    pub fn duration(&self) -> Duration {
+        let decay = self.damping_ratio().min(1.) * self.omega();
+        Duration::from_secs_f32(1000f32.ln() / decay.max(f32::EPSILON))
+    }
+
+    /// Returns the progress (0.0 at start, 1.0 at rest) of the spring at the `elapsed` seconds.
+    pub fn value(&self, elapsed: f32) -> f32 {
+        let omega = self.omega();
+        let zeta = self.damping_ratio();
+
+        if zeta < 1. {
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let envelope = (-zeta * omega * elapsed).exp();
+            1. - envelope
+                * ((omega_d * elapsed).cos() + (zeta * omega / omega_d) * (omega_d * elapsed).sin())
+        } else {
+            // Critically damped, the over damped spring is approximated by it.
+            1. - (-omega * elapsed).exp() * (1. + omega * elapsed)
+        }
+    }
+
+    /// Interpolate between `from` and `to` by the `delta` (0.0 - 1.0) of the [`Spring::duration`].
+    ///
+    /// The `to` is returned when the `delta` is 1.0, to avoid the residual oscillation.
+    pub fn interpolate(&self, from: f32, to: f32, delta: f32) -> f32 {
+        if delta >= 1. {
+            return to;
+        }
+
+        let elapsed = delta.clamp(0., 1.) * self.duration().as_secs_f32();
+        lerp(from, to, self.value(elapsed))
+    }
+}
+
+/// The easing presets of the transitions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Easing {
+    Linear,
+    /// `cubic-bezier(0.4, 0, 0.2, 1)`, for the most of the transitions.
+    #[default]
+    Standard,
+    /// `cubic-bezier(0.32, 0.72, 0, 1)`, for the large surfaces, e.g.: Modal, Drawer.
+    Emphasized,
+    /// `cubic-bezier(0, 0, 0.2, 1)`, for the entering elements.
+    Decelerate,
+    /// `cubic-bezier(0.4, 0, 1, 1)`, for the exiting elements.
+    Accelerate,
+    /// A custom cubic bezier.
+    CubicBezier(f32, f32, f32, f32),
+    /// The spring motion, see [`Spring::interpolate`].
+    Spring(Spring),
+}
+
+impl Easing {
+    /// Returns the eased value of the `t` (0.0 - 1.0).
+    pub fn ease(&self, t: f32) -> f32 {
+        match *self {
+            Self::Linear => t,
+            Self::Standard => cubic_bezier(0.4, 0., 0.2, 1.)(t),
+            Self::Emphasized => cubic_bezier(0.32, 0.72, 0., 1.)(t),
+            Self::Decelerate => cubic_bezier(0., 0., 0.2, 1.)(t),
+            Self::Accelerate => cubic_bezier(0.4, 0., 1., 1.)(t),
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2)(t),
+            Self::Spring(spring) => spring.interpolate(0., 1., t),
+        }
+    }
+}
+
+/// The duration and easing of a transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// 150ms, for the small elements, e.g.: Dropdown menu, Tooltip.
+    pub const FAST: Self = Self::new(Duration::from_millis(150), Easing::Standard);
+    /// 250ms, for the most of the transitions, e.g.: Notification, Accordion.
+    pub const NORMAL: Self = Self::new(Duration::from_millis(250), Easing::Standard);
+    /// 250ms with the emphasized easing, for the large surfaces, e.g.: Modal, Drawer.
+    pub const EMPHASIZED: Self = Self::new(Duration::from_millis(250), Easing::Emphasized);
+    /// 400ms, for the large movements.
+    pub const SLOW: Self = Self::new(Duration::from_millis(400), Easing::Standard);
+
+    /// Create a new transition.
+    pub const fn new(duration: Duration, easing: Easing) -> Self {
+        Self { duration, easing }
+    }
+
+    /// Create a new spring transition, the duration is the settle duration of the spring.
+    pub fn spring(spring: Spring) -> Self {
+        Self::new(spring.duration(), Easing::Spring(spring))
+    }
+
+    /// Set the duration of the transition.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing of the transition.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the [`Animation`] of the transition, None if the motion is reduced.
+    pub fn animation(&self, cx: &App) -> Option<Animation> {
+        if cx.theme().reduce_motion || self.duration.is_zero() {
+            return None;
+        }
+
+        let easing = self.easing;
+        Some(Animation::new(self.duration).with_easing(move |t| easing.ease(t)))
+    }
+}
+
+/// Extension trait to animate the elements by the [`Transition`].
+pub trait TransitionExt: IntoElement + Sized + 'static {
+    /// Run the enter transition, the `delta` of the `animator` goes from 0.0 to 1.0.
+    ///
+    /// The `animator` is called with 1.0 directly when the motion is reduced.
+    fn with_transition(
+        self,
+        id: impl Into<ElementId>,
+        transition: Transition,
+        cx: &App,
+        animator: impl Fn(Self, f32) -> Self + 'static,
+    ) -> AnyElement {
+        match transition.animation(cx) {
+            Some(animation) => self
+                .with_animation(id, animation, animator)
+                .into_any_element(),
+            None => animator(self, 1.).into_any_element(),
+        }
+    }
+
+    /// Run the exit transition, the `delta` of the `animator` goes from 1.0 to 0.0.
+    ///
+    /// The `animator` is called with 0.0 directly when the motion is reduced.
+    fn with_exit_transition(
+        self,
+        id: impl Into<ElementId>,
+        transition: Transition,
+        cx: &App,
+        animator: impl Fn(Self, f32) -> Self + 'static,
+    ) -> AnyElement {
+        match transition.animation(cx) {
+            Some(animation) => self
+                .with_animation(id, animation, move |this, delta| animator(this, 1. - delta))
+                .into_any_element(),
+            None => animator(self, 0.).into_any_element(),
+        }
+    }
+}
+
+impl<E: IntoElement + 'static> TransitionExt for E {}
+
+#[cfg(test)]
+mod tests {
+    use super::{lerp, Easing, Spring};
+
+    #[test]
+    fn test_easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::Standard,
+            Easing::Emphasized,
+            Easing::Decelerate,
+            Easing::Accelerate,
+            Easing::Spring(Spring::default()),
+        ] {
+            assert!(easing.ease(0.).abs() < 0.001, "{:?}", easing);
+            assert!((easing.ease(1.) - 1.).abs() < 0.01, "{:?}", easing);
+        }
+        assert_eq!(Easing::Linear.ease(0.3), 0.3);
+        assert_eq!(lerp(10., 20., 0.5), 15.);
+    }
+
+    #[test]
+    fn test_spring() {
+        let spring = Spring::default();
+        assert!(spring.duration().as_secs_f32() > 0.1);
+        assert_eq!(spring.interpolate(0., 100., 1.), 100.);
+        assert!((spring.interpolate(0., 100., 0.95) - 100.).abs() < 5.);
+
+        // The bouncy spring overshoots the target.
+        let spring = Spring::BOUNCY;
+        let max = (0..=100)
+            .map(|i| spring.interpolate(0., 1., i as f32 / 100.))
+            .fold(0f32, f32::max);
+        assert!(max > 1.);
+    }
+}
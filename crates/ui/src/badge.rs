@@ -3,7 +3,9 @@ use gpui::{
     RenderOnce, StyleRefinement, Styled, Window,
 };
 
-use crate::{h_flex, white, ActiveTheme, Icon, Sizable, Size, StyledExt};
+use crate::{
+    h_flex, tag::TagVariant, white, ActiveTheme, Icon, Sizable, Size, StyleOverrides, StyledExt,
+};
 
 #[derive(Default, Clone)]
 enum BadgeVariant {
@@ -26,6 +28,16 @@ impl BadgeVariant {
     }
 }
 
+/// The corner of the element to place the [`Badge`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BadgePosition {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
 /// A badge for displaying a count, dot, or icon on an element.
 #[derive(IntoElement)]
 pub struct Badge {
@@ -35,6 +47,8 @@ pub struct Badge {
     variant: BadgeVariant,
     children: Vec<AnyElement>,
     color: Option<Hsla>,
+    color_variant: Option<TagVariant>,
+    position: Option<BadgePosition>,
     size: Size,
 }
 
@@ -47,6 +61,8 @@ impl Badge {
             max: 99,
             variant: Default::default(),
             color: None,
+            color_variant: None,
+            position: None,
             children: Vec::new(),
             size: Size::default(),
         }
@@ -83,6 +99,20 @@ impl Badge {
         self.color = Some(color.into());
         self
     }
+
+    /// Set the theme color of the badge by a [`TagVariant`], default is red.
+    pub fn variant(mut self, variant: TagVariant) -> Self {
+        self.color_variant = Some(variant);
+        self
+    }
+
+    /// Set the corner to place the badge.
+    ///
+    /// Default is [`BadgePosition::TopRight`], or [`BadgePosition::BottomRight`] for icon badge.
+    pub fn position(mut self, position: BadgePosition) -> Self {
+        self.position = Some(position);
+        self
+    }
 }
 
 impl ParentElement for Badge {
@@ -111,8 +141,19 @@ impl RenderOnce for Badge {
             Size::Small | Size::XSmall => (px(10.), px(8.)),
         };
 
+        let (bg, fg) = match (self.color, self.color_variant) {
+            (Some(color), _) => (color, white()),
+            (None, Some(variant)) => (variant.bg(cx), variant.fg(false, cx)),
+            (None, None) => (cx.theme().red, white()),
+        };
+        let position = self.position.unwrap_or(match self.variant {
+            BadgeVariant::Icon(_) => BadgePosition::BottomRight,
+            _ => BadgePosition::TopRight,
+        });
+
         div()
             .relative()
+            .refine_style(&StyleOverrides::style_for::<Self>(cx))
             .refine_style(&self.style)
             .children(self.children)
             .when(visible, |this| {
@@ -122,42 +163,54 @@ impl RenderOnce for Badge {
                         .justify_center()
                         .items_center()
                         .rounded_full()
-                        .bg(self.color.unwrap_or(cx.theme().red))
-                        .text_color(white())
+                        .bg(bg)
+                        .text_color(fg)
                         .text_size(text_size)
-                        .map(|this| match self.variant {
-                            BadgeVariant::Dot => this.top_0().right_0().size(px(6.)),
-                            BadgeVariant::Number => {
-                                let count = if self.count > self.max {
-                                    format!("{}+", self.max)
-                                } else {
-                                    self.count.to_string()
-                                };
-
-                                let (top, left) = match self.size {
-                                    Size::Large => (px(2.), -px(count.len() as f32)),
-                                    Size::Medium | Size::Size(_) => {
-                                        (-px(3.), -px(3.) * count.len())
-                                    }
-                                    Size::Small | Size::XSmall => (-px(4.), -px(4.) * count.len()),
-                                };
-
-                                this.top(top)
-                                    .right(left)
-                                    .py_0p5()
-                                    .px_0p5()
-                                    .min_w_3p5()
-                                    .text_size(px(10.))
-                                    .line_height(relative(1.))
-                                    .child(count)
+                        .map(|this| {
+                            let (this, y, x) = match self.variant {
+                                BadgeVariant::Dot => (this.size(px(6.)), px(0.), px(0.)),
+                                BadgeVariant::Number => {
+                                    let count = if self.count > self.max {
+                                        format!("{}+", self.max)
+                                    } else {
+                                        self.count.to_string()
+                                    };
+
+                                    let (y, x) = match self.size {
+                                        Size::Large => (px(2.), -px(count.len() as f32)),
+                                        Size::Medium | Size::Size(_) => {
+                                            (-px(3.), -px(3.) * count.len())
+                                        }
+                                        Size::Small | Size::XSmall => {
+                                            (-px(4.), -px(4.) * count.len())
+                                        }
+                                    };
+
+                                    let this = this
+                                        .py_0p5()
+                                        .px_0p5()
+                                        .min_w_3p5()
+                                        .text_size(px(10.))
+                                        .line_height(relative(1.))
+                                        .child(count);
+                                    (this, y, x)
+                                }
+                                BadgeVariant::Icon(icon) => (
+                                    this.size(size)
+                                        .border_1()
+                                        .border_color(cx.theme().background)
+                                        .child(*icon),
+                                    px(0.),
+                                    px(0.),
+                                ),
+                            };
+
+                            match position {
+                                BadgePosition::TopLeft => this.top(y).left(x),
+                                BadgePosition::TopRight => this.top(y).right(x),
+                                BadgePosition::BottomLeft => this.bottom(y).left(x),
+                                BadgePosition::BottomRight => this.bottom(y).right(x),
                             }
-                            BadgeVariant::Icon(icon) => this
-                                .right_0()
-                                .bottom_0()
-                                .size(size)
-                                .border_1()
-                                .border_color(cx.theme().background)
-                                .child(*icon),
                         }),
                 )
             })
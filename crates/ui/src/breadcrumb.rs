@@ -6,7 +6,7 @@ use gpui::{
     StyleRefinement, Styled, Window,
 };
 
-use crate::{h_flex, ActiveTheme, Icon, IconName, StyledExt};
+use crate::{h_flex, ActiveTheme, DirectionExt as _, Icon, IconName, StyledExt};
 
 #[derive(IntoElement)]
 pub struct Breadcrumb {
@@ -102,7 +102,7 @@ impl Breadcrumb {
 struct BreadcrumbSeparator;
 impl RenderOnce for BreadcrumbSeparator {
     fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
-        Icon::new(IconName::ChevronRight)
+        Icon::new(IconName::ChevronRight.mirrored(cx.theme().direction))
             .text_color(cx.theme().muted_foreground)
             .size_3p5()
             .into_any_element()
@@ -133,6 +133,7 @@ impl RenderOnce for Breadcrumb {
             .gap_1p5()
             .text_sm()
             .text_color(cx.theme().muted_foreground)
+            .direction(cx.theme().direction)
             .refine_style(&self.style)
             .children(children)
     }
@@ -1,14 +1,19 @@
 use std::rc::Rc;
 
 use crate::{
-    h_flex, indicator::Indicator, tooltip::Tooltip, ActiveTheme, Colorize as _, Disableable,
-    FocusableExt as _, Icon, Selectable, Sizable, Size, StyleSized, StyledExt,
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Role},
+    debug_overlay::DebugOverlay,
+    h_flex,
+    indicator::Indicator,
+    tooltip::{managed_tooltip, Tooltip},
+    ActiveTheme, Colorize as _, DensityExt as _, DirectionExt as _, Disableable, FocusableExt as _,
+    Icon, Placement, Selectable, Side, Sizable, Size, StyleOverrides, StyleSized, StyledExt,
 };
 use gpui::{
-    div, prelude::FluentBuilder as _, px, relative, Action, AnyElement, App, ClickEvent, Corners,
-    Div, Edges, ElementId, Hsla, InteractiveElement, Interactivity, IntoElement, ParentElement,
-    Pixels, RenderOnce, SharedString, Stateful, StatefulInteractiveElement as _, StyleRefinement,
-    Styled, Window,
+    div, prelude::FluentBuilder as _, px, relative, Action, AnyElement, AnyView, App, ClickEvent,
+    Corners, Div, Edges, ElementId, Hsla, InteractiveElement, Interactivity, IntoElement,
+    ParentElement, Pixels, RenderOnce, SharedString, Stateful, StatefulInteractiveElement as _,
+    StyleRefinement, Styled, Window,
 };
 
 #[derive(Default, Clone, Copy)]
@@ -136,9 +141,10 @@ impl ButtonCustomVariant {
 }
 
 /// The veriant of the Button.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
 pub enum ButtonVariant {
     Primary,
+    #[default]
     Secondary,
     Danger,
     Info,
@@ -150,12 +156,6 @@ pub enum ButtonVariant {
     Custom(ButtonCustomVariant),
 }
 
-impl Default for ButtonVariant {
-    fn default() -> Self {
-        Self::Secondary
-    }
-}
-
 impl ButtonVariant {
     #[inline]
     pub fn is_link(&self) -> bool {
@@ -185,6 +185,8 @@ pub struct Button {
     base: Stateful<Div>,
     style: StyleRefinement,
     icon: Option<Icon>,
+    icon_position: Side,
+    gap: Option<Pixels>,
     label: Option<SharedString>,
     children: Vec<AnyElement>,
     disabled: bool,
@@ -196,10 +198,8 @@ pub struct Button {
     border_edges: Edges<bool>,
     size: Size,
     compact: bool,
-    tooltip: Option<(
-        SharedString,
-        Option<(Rc<Box<dyn Action>>, Option<SharedString>)>,
-    )>,
+    tooltip: Option<Rc<dyn Fn(&mut Window, &mut App) -> AnyView>>,
+    tooltip_placement: Placement,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
     on_hover: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
     pub(crate) stop_propagation: bool,
@@ -208,6 +208,7 @@ pub struct Button {
 
     tab_index: isize,
     tab_stop: bool,
+    accessibility: AccessibilityProps,
 }
 
 impl From<Button> for AnyElement {
@@ -227,6 +228,8 @@ impl Button {
             base: div().flex_shrink_0().id(id),
             style: StyleRefinement::default(),
             icon: None,
+            icon_position: Side::Left,
+            gap: None,
             label: None,
             disabled: false,
             selected: false,
@@ -236,6 +239,7 @@ impl Button {
             border_edges: Edges::all(true),
             size: Size::Medium,
             tooltip: None,
+            tooltip_placement: Placement::Bottom,
             on_click: None,
             on_hover: None,
             stop_propagation: true,
@@ -246,6 +250,7 @@ impl Button {
             loading_icon: None,
             tab_index: 0,
             tab_stop: true,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -286,8 +291,40 @@ impl Button {
     }
 
     /// Set the tooltip of the button.
+    /// Set the side of the icon (and the loading indicator) to the label, default is [`Side::Left`].
+    pub fn icon_position(mut self, side: Side) -> Self {
+        self.icon_position = side;
+        self
+    }
+
+    /// Set the gap between the icon and the label, default is by the size.
+    pub fn icon_gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
     pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
-        self.tooltip = Some((tooltip.into(), None));
+        let tooltip: SharedString = tooltip.into();
+        self.tooltip = Some(Rc::new(move |window, cx| {
+            Tooltip::new(tooltip.clone()).build(window, cx)
+        }));
+        self
+    }
+
+    /// Set the tooltip builder of the button, to use rich content, e.g.: [`Tooltip::element`].
+    pub fn tooltip_builder(
+        mut self,
+        builder: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+    ) -> Self {
+        self.tooltip = Some(Rc::new(builder));
+        self
+    }
+
+    /// Set the placement of the tooltip, default: [`Placement::Bottom`].
+    ///
+    /// The tooltip will be flipped to the opposite side when there is no enough space.
+    pub fn tooltip_placement(mut self, placement: Placement) -> Self {
+        self.tooltip_placement = placement;
         self
     }
 
@@ -297,17 +334,21 @@ impl Button {
         action: &dyn Action,
         context: Option<&str>,
     ) -> Self {
-        self.tooltip = Some((
-            tooltip.into(),
-            Some((
-                Rc::new(action.boxed_clone()),
-                context.map(|c| c.to_string().into()),
-            )),
-        ));
+        let tooltip: SharedString = tooltip.into();
+        let action: Rc<Box<dyn Action>> = Rc::new(action.boxed_clone());
+        let context: Option<SharedString> = context.map(|c| c.to_string().into());
+        self.tooltip = Some(Rc::new(move |window, cx| {
+            Tooltip::new(tooltip.clone())
+                .action(
+                    action.as_ref().as_ref(),
+                    context.as_ref().map(|c| c.as_ref()),
+                )
+                .build(window, cx)
+        }));
         self
     }
 
-    /// Set true to show the loading indicator.
+    /// Set true to show the loading indicator instead of the icon, and disable the clicks.
     pub fn loading(mut self, loading: bool) -> Self {
         self.loading = loading;
         self
@@ -378,6 +419,12 @@ impl Disableable for Button {
     }
 }
 
+impl Accessible for Button {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Selectable for Button {
     fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -437,6 +484,13 @@ impl RenderOnce for Button {
             .read(cx)
             .clone();
         let is_focused = focus_handle.is_focused(window);
+        let on_hover = self.on_hover.clone().filter(|_| hoverable);
+
+        let mut props = AccessibilityProps::new(Role::Button)
+            .selected(self.selected)
+            .disabled(self.disabled);
+        props.label = self.label.clone();
+        Accessibility::declare(&self.id, &props.merge(&self.accessibility), window, cx);
 
         self.base
             .when(!self.disabled, |this| {
@@ -530,6 +584,9 @@ impl RenderOnce for Button {
                     .border_color(disabled_style.border)
                     .shadow_none()
             })
+            .density(cx.theme().density)
+            .direction(cx.theme().direction)
+            .refine_style(&StyleOverrides::style_for::<Self>(cx))
             .refine_style(&self.style)
             .on_mouse_down(gpui::MouseButton::Left, |_, window, _| {
                 // Avoid focus on mouse down.
@@ -537,18 +594,22 @@ impl RenderOnce for Button {
             })
             .when_some(self.on_click.filter(|_| clickable), |this, on_click| {
                 let stop_propagation = self.stop_propagation;
+                let id = self.id.clone();
                 this.on_click(move |_, _, cx| {
                     if stop_propagation {
                         cx.stop_propagation();
                     }
                 })
                 .on_click(move |event, window, cx| {
+                    DebugOverlay::log(format!("Button `{}` clicked", id), cx);
                     (on_click)(event, window, cx);
                 })
             })
-            .when_some(self.on_hover.filter(|_| hoverable), |this, on_hover| {
-                this.on_hover(move |hovered, window, cx| {
-                    (on_hover)(hovered, window, cx);
+            .when(self.tooltip.is_none(), |this| {
+                this.when_some(on_hover.clone(), |this, on_hover| {
+                    this.on_hover(move |hovered, window, cx| {
+                        (on_hover)(hovered, window, cx);
+                    })
                 })
             })
             .when(self.disabled, |this| {
@@ -559,49 +620,57 @@ impl RenderOnce for Button {
                     .shadow_none()
             })
             .child({
+                let icon = if self.loading {
+                    Some(
+                        Indicator::new()
+                            .with_size(self.size)
+                            .when_some(self.loading_icon, |this, icon| this.icon(icon))
+                            .into_any_element(),
+                    )
+                } else {
+                    self.icon
+                        .map(|icon| icon.with_size(icon_size).into_any_element())
+                };
+                let (leading_icon, trailing_icon) = if self.icon_position.is_left() {
+                    (icon, None)
+                } else {
+                    (None, icon)
+                };
+
                 h_flex()
                     .id("label")
                     .items_center()
                     .justify_center()
                     .button_text_size(self.size)
-                    .map(|this| match self.size {
-                        Size::XSmall => this.gap_1(),
-                        Size::Small => this.gap_1(),
-                        _ => this.gap_2(),
-                    })
-                    .when(!self.loading, |this| {
-                        this.when_some(self.icon, |this, icon| {
-                            this.child(icon.with_size(icon_size))
-                        })
-                    })
-                    .when(self.loading, |this| {
-                        this.child(
-                            Indicator::new()
-                                .with_size(self.size)
-                                .when_some(self.loading_icon, |this, icon| this.icon(icon)),
-                        )
+                    .map(|this| match (self.gap, self.size) {
+                        (Some(gap), _) => this.gap(gap),
+                        (None, Size::XSmall | Size::Small) => this.gap_1(),
+                        (None, _) => this.gap_2(),
                     })
+                    .children(leading_icon)
                     .when_some(self.label, |this, label| {
                         this.child(div().flex_none().line_height(relative(1.)).child(label))
                     })
                     .children(self.children)
+                    .children(trailing_icon)
+                    .direction(cx.theme().direction)
             })
             .when(self.loading && !self.disabled, |this| {
                 this.bg(normal_style.bg.opacity(0.8))
                     .border_color(normal_style.border.opacity(0.8))
                     .text_color(normal_style.fg.opacity(0.8))
             })
-            .when_some(self.tooltip, |this, (tooltip, action)| {
-                this.tooltip(move |window, cx| {
-                    Tooltip::new(tooltip.clone())
-                        .when_some(action.clone(), |this, (action, context)| {
-                            this.action(
-                                action.boxed_clone().as_ref(),
-                                context.as_ref().map(|c| c.as_ref()),
-                            )
-                        })
-                        .build(window, cx)
-                })
+            .when_some(self.tooltip, |this, tooltip| {
+                managed_tooltip(
+                    this,
+                    &self.id,
+                    self.tooltip_placement,
+                    is_focused,
+                    tooltip,
+                    on_hover,
+                    window,
+                    cx,
+                )
             })
             .focus_ring(is_focused, px(0.), window, cx)
     }
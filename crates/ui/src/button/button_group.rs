@@ -54,7 +54,7 @@ impl ButtonGroup {
 
     /// Adds a button as a child to the ButtonGroup.
     pub fn child(mut self, child: Button) -> Self {
-        self.children.push(child.disabled(self.disabled));
+        self.children.push(child);
         self
     }
 
@@ -201,6 +201,7 @@ impl RenderOnce for ButtonGroup {
                                 })
                         }
                         .stop_propagation(false)
+                        .when(self.disabled, |this| this.disabled(true))
                         .when_some(self.size, |this, size| this.with_size(size))
                         .when_some(self.variant, |this, variant| this.with_variant(variant))
                         .when(self.compact, |this| this.compact())
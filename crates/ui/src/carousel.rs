@@ -0,0 +1,531 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    canvas, deferred, div, img, prelude::FluentBuilder as _, px, relative, AnyElement, App, Bounds,
+    Context, Empty, Entity, EventEmitter, InteractiveElement as _, IntoElement, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ObjectFit, ParentElement as _, Pixels, Render,
+    RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    StyledImage as _, Task, Timer, Window,
+};
+
+use crate::{
+    black,
+    button::{Button, ButtonVariants as _},
+    h_flex, white, ActiveTheme as _, Disableable as _, IconName, Sizable as _, StyledExt,
+};
+
+/// The ratio of the carousel width to drag to switch the slide.
+const SWIPE_THRESHOLD: f32 = 0.2;
+
+pub enum CarouselEvent {
+    /// The active slide has changed.
+    Change(usize),
+}
+
+/// State of the [`Carousel`] and [`ImageGallery`].
+pub struct CarouselState {
+    index: usize,
+    count: usize,
+    looped: bool,
+    hovered: bool,
+    lightbox: bool,
+    /// The mouse position when start dragging.
+    drag_start: Option<Pixels>,
+    drag_offset: Pixels,
+    /// The bounds of the carousel after rendered.
+    bounds: Bounds<Pixels>,
+    _autoplay_task: Task<()>,
+}
+
+impl CarouselState {
+    pub fn new() -> Self {
+        Self {
+            index: 0,
+            count: 0,
+            looped: true,
+            hovered: false,
+            lightbox: false,
+            drag_start: None,
+            drag_offset: px(0.),
+            bounds: Bounds::default(),
+            _autoplay_task: Task::ready(()),
+        }
+    }
+
+    /// Set to go back to the first slide after the last one, default: true
+    pub fn looped(mut self, looped: bool) -> Self {
+        self.looped = looped;
+        self
+    }
+
+    /// Set to switch to the next slide automatically by the interval.
+    ///
+    /// The autoplay is paused when the mouse is hovering the carousel.
+    pub fn autoplay(mut self, interval: Duration, cx: &mut Context<Self>) -> Self {
+        self._autoplay_task = cx.spawn(async move |this, cx| loop {
+            Timer::after(interval).await;
+            let result = this.update(cx, |this, cx| {
+                if !this.hovered && this.drag_start.is_none() && !this.lightbox {
+                    this.next(cx);
+                }
+            });
+            if result.is_err() {
+                break;
+            }
+        });
+        self
+    }
+
+    /// Returns the index of the active slide.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Set the active slide.
+    pub fn select(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let ix = ix.min(self.count.saturating_sub(1));
+        if ix == self.index {
+            return;
+        }
+
+        self.index = ix;
+        cx.emit(CarouselEvent::Change(ix));
+        cx.notify();
+    }
+
+    /// Go to the next slide.
+    pub fn next(&mut self, cx: &mut Context<Self>) {
+        let ix = step_index(self.index, 1, self.count, self.looped);
+        self.select(ix, cx);
+    }
+
+    /// Go to the previous slide.
+    pub fn prev(&mut self, cx: &mut Context<Self>) {
+        let ix = step_index(self.index, -1, self.count, self.looped);
+        self.select(ix, cx);
+    }
+
+    /// Returns true if the lightbox of the [`ImageGallery`] is open.
+    pub fn is_lightbox_open(&self) -> bool {
+        self.lightbox
+    }
+
+    /// Open or close the lightbox of the [`ImageGallery`].
+    pub fn set_lightbox(&mut self, open: bool, cx: &mut Context<Self>) {
+        self.lightbox = open;
+        cx.notify();
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, _: &mut Window, _: &mut Context<Self>) {
+        self.drag_start = Some(event.position.x);
+        self.drag_offset = px(0.);
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(start) = self.drag_start else {
+            return;
+        };
+
+        self.drag_offset = event.position.x - start;
+        cx.notify();
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if self.drag_start.take().is_none() {
+            return;
+        }
+
+        let offset = std::mem::take(&mut self.drag_offset);
+        let threshold = self.bounds.size.width * SWIPE_THRESHOLD;
+        if offset > threshold {
+            self.prev(cx);
+        } else if offset < -threshold {
+            self.next(cx);
+        }
+        cx.notify();
+    }
+}
+
+/// Returns the index after moving `delta` slides.
+fn step_index(ix: usize, delta: isize, count: usize, looped: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+
+    let ix = ix as isize + delta;
+    if looped {
+        ix.rem_euclid(count as isize) as usize
+    } else {
+        ix.clamp(0, count as isize - 1) as usize
+    }
+}
+
+impl EventEmitter<CarouselEvent> for CarouselState {}
+impl Render for CarouselState {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// A Carousel element to show slides one by one.
+///
+/// Only the active slide and its neighbours are rendered.
+#[derive(IntoElement)]
+pub struct Carousel {
+    id: &'static str,
+    state: Entity<CarouselState>,
+    style: StyleRefinement,
+    count: usize,
+    render_slide: Option<Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement>>,
+    arrows: bool,
+    indicators: bool,
+}
+
+impl Carousel {
+    /// Create a new [`Carousel`] element bind to the [`CarouselState`].
+    pub fn new(state: &Entity<CarouselState>) -> Self {
+        Self {
+            id: "carousel",
+            state: state.clone(),
+            style: StyleRefinement::default(),
+            count: 0,
+            render_slide: None,
+            arrows: true,
+            indicators: true,
+        }
+    }
+
+    /// Set the number of slides and the render function of the slide.
+    pub fn slides<E: IntoElement>(
+        mut self,
+        count: usize,
+        render_slide: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.count = count;
+        self.render_slide = Some(Rc::new(move |ix, window, cx| {
+            render_slide(ix, window, cx).into_any_element()
+        }));
+        self
+    }
+
+    /// Set to show the previous and next buttons, default: true
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Set to show the dot indicators, default: true
+    pub fn indicators(mut self, indicators: bool) -> Self {
+        self.indicators = indicators;
+        self
+    }
+
+    fn render_indicators(&self, index: usize, window: &mut Window) -> impl IntoElement {
+        h_flex()
+            .absolute()
+            .bottom_3()
+            .left_0()
+            .right_0()
+            .justify_center()
+            .gap_1p5()
+            .children((0..self.count).map(|ix| {
+                let active = ix == index;
+                div()
+                    .id(ix)
+                    .h_1p5()
+                    .rounded_full()
+                    .cursor_pointer()
+                    .map(|this| {
+                        if active {
+                            this.w_4().bg(white())
+                        } else {
+                            this.w_1p5()
+                                .bg(white().opacity(0.5))
+                                .hover(|this| this.bg(white().opacity(0.8)))
+                        }
+                    })
+                    .on_click(window.listener_for(&self.state, move |state, _, _, cx| {
+                        state.select(ix, cx);
+                    }))
+            }))
+    }
+}
+
+impl Styled for Carousel {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Carousel {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let count = self.count;
+        self.state.update(cx, |state, _| {
+            state.count = count;
+            state.index = state.index.min(count.saturating_sub(1));
+        });
+        let state = self.state.read(cx);
+        let index = state.index;
+        let looped = state.looped;
+        let drag_offset = state.drag_offset;
+        let dragging = state.drag_start.is_some();
+        let entity_id = self.state.entity_id();
+
+        let render_slide = self.render_slide.clone();
+        // Only render the active slide and the neighbours for swiping.
+        let visible_slides = (index.saturating_sub(1)..(index + 2).min(count)).collect::<Vec<_>>();
+
+        div()
+            .id((self.id, entity_id))
+            .relative()
+            .w_full()
+            .h(px(240.))
+            .overflow_hidden()
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().muted)
+            .refine_style(&self.style)
+            .on_hover(
+                window.listener_for(&self.state, |state, hovered: &bool, _, cx| {
+                    state.hovered = *hovered;
+                    cx.notify();
+                }),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                window.listener_for(&self.state, CarouselState::on_mouse_down),
+            )
+            .on_mouse_move(window.listener_for(&self.state, CarouselState::on_mouse_move))
+            .on_mouse_up(
+                MouseButton::Left,
+                window.listener_for(&self.state, CarouselState::on_mouse_up),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                window.listener_for(&self.state, CarouselState::on_mouse_up),
+            )
+            .when(dragging, |this| this.cursor_grabbing())
+            .children(visible_slides.into_iter().map(|ix| {
+                let offset = ix as f32 - index as f32;
+                div()
+                    .absolute()
+                    .top_0()
+                    .size_full()
+                    .left(relative(offset))
+                    .ml(drag_offset)
+                    .overflow_hidden()
+                    .when_some(render_slide.as_ref(), |this, render_slide| {
+                        this.child(render_slide(ix, window, cx))
+                    })
+            }))
+            .when(self.arrows && count > 1, |this| {
+                this.child(
+                    Button::new("prev")
+                        .icon(IconName::ChevronLeft)
+                        .small()
+                        .rounded_full()
+                        .absolute()
+                        .left_2()
+                        .top(relative(0.5))
+                        .mt(-px(12.))
+                        .disabled(!looped && index == 0)
+                        .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                            state.prev(cx);
+                        })),
+                )
+                .child(
+                    Button::new("next")
+                        .icon(IconName::ChevronRight)
+                        .small()
+                        .rounded_full()
+                        .absolute()
+                        .right_2()
+                        .top(relative(0.5))
+                        .mt(-px(12.))
+                        .disabled(!looped && index + 1 >= count)
+                        .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                            state.next(cx);
+                        })),
+                )
+            })
+            .when(self.indicators && count > 1, |this| {
+                this.child(self.render_indicators(index, window))
+            })
+            .child({
+                let state = self.state.clone();
+                canvas(
+                    move |bounds, _, cx| state.update(cx, |state, _| state.bounds = bounds),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+    }
+}
+
+/// An image gallery with a [`Carousel`], a thumbnail strip, and a full-screen lightbox mode.
+#[derive(IntoElement)]
+pub struct ImageGallery {
+    state: Entity<CarouselState>,
+    style: StyleRefinement,
+    images: Rc<Vec<SharedString>>,
+    thumbnails: bool,
+}
+
+impl ImageGallery {
+    /// Create a new [`ImageGallery`] bind to the [`CarouselState`] with the image sources.
+    pub fn new(
+        state: &Entity<CarouselState>,
+        images: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self {
+            state: state.clone(),
+            style: StyleRefinement::default(),
+            images: Rc::new(images.into_iter().map(Into::into).collect()),
+            thumbnails: true,
+        }
+    }
+
+    /// Set to show the thumbnail strip, default: true
+    pub fn thumbnails(mut self, thumbnails: bool) -> Self {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+    fn carousel(&self, contain: bool) -> Carousel {
+        let images = self.images.clone();
+        Carousel::new(&self.state).slides(images.len(), move |ix, _, _| {
+            img(images[ix].clone()).size_full().object_fit(if contain {
+                ObjectFit::Contain
+            } else {
+                ObjectFit::Cover
+            })
+        })
+    }
+}
+
+impl Styled for ImageGallery {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for ImageGallery {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let index = state.index;
+        let lightbox = state.lightbox;
+        let entity_id = self.state.entity_id();
+        let viewport_size = window.viewport_size();
+
+        div()
+            .id(("image-gallery", entity_id))
+            .flex()
+            .flex_col()
+            .w_full()
+            .gap_2()
+            .refine_style(&self.style)
+            .child(
+                div().relative().w_full().child(self.carousel(false)).child(
+                    Button::new("open-lightbox")
+                        .icon(IconName::Maximize)
+                        .xsmall()
+                        .absolute()
+                        .top_2()
+                        .right_2()
+                        .on_click(window.listener_for(&self.state, |state, _, _, cx| {
+                            state.set_lightbox(true, cx);
+                        })),
+                ),
+            )
+            .when(self.thumbnails, |this| {
+                this.child(
+                    h_flex()
+                        .id("thumbnails")
+                        .w_full()
+                        .gap_2()
+                        .overflow_x_scroll()
+                        .children(self.images.iter().enumerate().map(|(ix, src)| {
+                            div()
+                                .id(ix)
+                                .flex_shrink_0()
+                                .size_16()
+                                .overflow_hidden()
+                                .rounded(cx.theme().radius)
+                                .border_2()
+                                .cursor_pointer()
+                                .map(|this| {
+                                    if ix == index {
+                                        this.border_color(cx.theme().ring)
+                                    } else {
+                                        this.border_color(cx.theme().transparent).opacity(0.7)
+                                    }
+                                })
+                                .hover(|this| this.opacity(1.))
+                                .child(img(src.clone()).size_full().object_fit(ObjectFit::Cover))
+                                .on_click(window.listener_for(
+                                    &self.state,
+                                    move |state, _, _, cx| {
+                                        state.select(ix, cx);
+                                    },
+                                ))
+                        })),
+                )
+            })
+            .when(lightbox, |this| {
+                let mut carousel = self.carousel(true);
+                carousel.id = "carousel-lightbox";
+
+                this.child(
+                    deferred(
+                        div()
+                            .id("lightbox")
+                            .occlude()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .w(viewport_size.width)
+                            .h(viewport_size.height)
+                            .p_8()
+                            .bg(black().opacity(0.9))
+                            .child(
+                                carousel
+                                    .size_full()
+                                    .rounded_none()
+                                    .bg(cx.theme().transparent),
+                            )
+                            .child(
+                                Button::new("close-lightbox")
+                                    .icon(IconName::Close)
+                                    .ghost()
+                                    .text_color(white())
+                                    .absolute()
+                                    .top_2()
+                                    .right_2()
+                                    .on_click(window.listener_for(
+                                        &self.state,
+                                        |state, _, _, cx| {
+                                            state.set_lightbox(false, cx);
+                                        },
+                                    )),
+                            ),
+                    )
+                    .with_priority(2),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step_index;
+
+    #[test]
+    fn test_step_index() {
+        assert_eq!(step_index(0, 1, 3, true), 1);
+        assert_eq!(step_index(2, 1, 3, true), 0);
+        assert_eq!(step_index(0, -1, 3, true), 2);
+        assert_eq!(step_index(2, 1, 3, false), 2);
+        assert_eq!(step_index(0, -1, 3, false), 0);
+        assert_eq!(step_index(0, 1, 0, true), 0);
+    }
+}
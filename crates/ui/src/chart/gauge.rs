@@ -0,0 +1,253 @@
+use std::{
+    f32::consts::{PI, TAU},
+    time::Duration,
+};
+
+use gpui::{
+    div, ease_in_out, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, App, Bounds,
+    ElementId, Hsla, IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString,
+    StyleRefinement, Styled, Window,
+};
+use gpui_component_macros::IntoPlot;
+
+use crate::{
+    plot::{
+        shape::{Arc, ArcData},
+        Plot,
+    },
+    v_flex, ActiveTheme, PixelsExt, StyledExt,
+};
+
+/// The track and value arcs of the [`Gauge`].
+#[derive(IntoPlot)]
+struct GaugeArc {
+    fraction: f32,
+    start_angle: f32,
+    end_angle: f32,
+    thickness: f32,
+    track: Hsla,
+    color: Hsla,
+}
+
+impl Plot for GaugeArc {
+    fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, _: &mut App) {
+        let radius = bounds.size.width.min(bounds.size.height).as_f32() / 2.;
+        let arc = Arc::new()
+            .inner_radius((radius - self.thickness).max(0.))
+            .outer_radius(radius);
+
+        let track = ArcData {
+            data: &(),
+            index: 0,
+            value: 1.,
+            start_angle: self.start_angle,
+            end_angle: self.end_angle,
+            pad_angle: 0.,
+        };
+        arc.paint(&track, self.track, &bounds, window);
+
+        let fraction = self.fraction.clamp(0., 1.);
+        let value = ArcData {
+            end_angle: self.start_angle + (self.end_angle - self.start_angle) * fraction,
+            ..track
+        };
+        arc.paint(&value, self.color, &bounds, window);
+    }
+}
+
+/// The animation state of the [`Gauge`], to animate from the last value.
+struct GaugeState {
+    from: f32,
+    to: f32,
+    generation: usize,
+}
+
+/// A Gauge to display a value in a range, or a radial progress by [`Gauge::radial`].
+#[derive(IntoElement)]
+pub struct Gauge {
+    id: ElementId,
+    style: StyleRefinement,
+    value: f32,
+    min: f32,
+    max: f32,
+    thresholds: Vec<(f32, Hsla)>,
+    color: Option<Hsla>,
+    size: Pixels,
+    thickness: Pixels,
+    radial: bool,
+    label: Option<SharedString>,
+    show_value: bool,
+}
+
+impl Gauge {
+    /// Create a new Gauge with the value, the default range is 0..100.
+    pub fn new(id: impl Into<ElementId>, value: f32) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            value,
+            min: 0.,
+            max: 100.,
+            thresholds: Vec::new(),
+            color: None,
+            size: px(120.),
+            thickness: px(10.),
+            radial: false,
+            label: None,
+            show_value: true,
+        }
+    }
+
+    /// Set the min value, default: 0.0
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the max value, default: 100.0
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Add a threshold, the color is used when the value is greater than or equal to it.
+    pub fn threshold(mut self, value: f32, color: impl Into<Hsla>) -> Self {
+        self.thresholds.push((value, color.into()));
+        self
+    }
+
+    /// Set the color of the value arc, default is the chart color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the width and height of the Gauge, default: 120px
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Set the thickness of the arc, default: 10px
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into();
+        self
+    }
+
+    /// Use a full circle as a radial progress, default is a 270° gauge.
+    pub fn radial(mut self) -> Self {
+        self.radial = true;
+        self
+    }
+
+    /// Set the label below the value.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set to show the value in the center, default: true
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    fn value_color(&self, cx: &App) -> Hsla {
+        let mut thresholds = self.thresholds.clone();
+        thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        thresholds
+            .iter()
+            .rev()
+            .find(|(threshold, _)| self.value >= *threshold)
+            .map(|(_, color)| *color)
+            .or(self.color)
+            .unwrap_or(cx.theme().chart_2)
+    }
+}
+
+impl Styled for Gauge {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Gauge {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let range = self.max - self.min;
+        let fraction = if range > 0. {
+            ((self.value - self.min) / range).clamp(0., 1.)
+        } else {
+            0.
+        };
+
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| GaugeState {
+            from: fraction,
+            to: fraction,
+            generation: 0,
+        });
+        let (from, generation) = state.update(cx, |state, _| {
+            if state.to != fraction {
+                state.from = state.to;
+                state.to = fraction;
+                state.generation += 1;
+            }
+            (state.from, state.generation)
+        });
+
+        let (start_angle, end_angle) = if self.radial {
+            (0., TAU)
+        } else {
+            (-0.75 * PI, 0.75 * PI)
+        };
+        let arc = GaugeArc {
+            fraction,
+            start_angle,
+            end_angle,
+            thickness: self.thickness.as_f32(),
+            track: cx.theme().secondary,
+            color: self.value_color(cx),
+        };
+
+        let animated = from != fraction && !cx.theme().reduce_motion;
+
+        div()
+            .relative()
+            .size(self.size)
+            .flex_shrink_0()
+            .refine_style(&self.style)
+            .map(|this| {
+                if animated {
+                    this.child(arc.with_animation(
+                        ElementId::named_usize("gauge-animation", generation),
+                        Animation::new(Duration::from_millis(400)).with_easing(ease_in_out),
+                        move |mut arc, delta| {
+                            arc.fraction = from + (fraction - from) * delta;
+                            arc
+                        },
+                    ))
+                } else {
+                    this.child(arc)
+                }
+            })
+            .child(
+                v_flex()
+                    .absolute()
+                    .inset_0()
+                    .items_center()
+                    .justify_center()
+                    .when(self.show_value, |this| {
+                        this.child(div().text_xl().font_semibold().child(SharedString::from(
+                            crate::locale::format_number(self.value.round() as f64, 0),
+                        )))
+                    })
+                    .when_some(self.label, |this, label| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(label),
+                        )
+                    }),
+            )
+    }
+}
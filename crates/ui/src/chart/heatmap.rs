@@ -0,0 +1,495 @@
+use std::rc::Rc;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, App, ElementId, Hsla, InteractiveElement as _,
+    IntoElement, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    StyleRefinement, Styled, Window,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, locale, tooltip::Tooltip, v_flex, ActiveTheme, StyledExt};
+
+/// The number of color levels, the level 0 is used for the empty values.
+const LEVELS: usize = 5;
+
+/// Returns the color level (0..LEVELS) of the value in the range.
+fn level(value: f64, min: f64, max: f64) -> usize {
+    if !value.is_finite() || max <= min || value <= min {
+        return 0;
+    }
+
+    let t = ((value - min) / (max - min)).min(1.);
+    ((t * (LEVELS - 1) as f64).ceil() as usize).clamp(1, LEVELS - 1)
+}
+
+fn level_color(level: usize, color: Hsla, cx: &App) -> Hsla {
+    if level == 0 {
+        cx.theme().secondary
+    } else {
+        color.opacity(level as f32 / (LEVELS - 1) as f32)
+    }
+}
+
+fn format_value(value: f64) -> String {
+    if value.fract() == 0. {
+        locale::format_number(value, 0)
+    } else {
+        locale::format_number(value, 2)
+    }
+}
+
+fn legend(color: Hsla, cell_size: Pixels, cx: &App) -> impl IntoElement {
+    h_flex()
+        .gap_1()
+        .text_xs()
+        .text_color(cx.theme().muted_foreground)
+        .child(SharedString::from(t!("Heatmap.less")))
+        .children((0..LEVELS).map(|level| {
+            div()
+                .size(cell_size)
+                .rounded_xs()
+                .bg(level_color(level, color, cx))
+        }))
+        .child(SharedString::from(t!("Heatmap.more")))
+}
+
+/// A matrix heatmap, each cell is colored by the value with the color scale.
+///
+/// ```ignore
+/// Heatmap::new("heatmap", vec![vec![1., 2.], vec![3., 4.]])
+///     .x_labels(["Mon", "Tue"])
+///     .y_labels(["AM", "PM"])
+///     .on_select(|(row, col), _, _| println!("{row}, {col}"))
+/// ```
+#[derive(IntoElement)]
+pub struct Heatmap {
+    id: ElementId,
+    style: StyleRefinement,
+    values: Vec<Vec<f64>>,
+    x_labels: Vec<SharedString>,
+    y_labels: Vec<SharedString>,
+    color: Option<Hsla>,
+    cell_size: Pixels,
+    legend: bool,
+    selected: Option<(usize, usize)>,
+    on_select: Option<Rc<dyn Fn(&(usize, usize), &mut Window, &mut App)>>,
+}
+
+impl Heatmap {
+    /// Create a new Heatmap with the values of rows.
+    pub fn new(id: impl Into<ElementId>, values: Vec<Vec<f64>>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            values,
+            x_labels: Vec::new(),
+            y_labels: Vec::new(),
+            color: None,
+            cell_size: px(24.),
+            legend: false,
+            selected: None,
+            on_select: None,
+        }
+    }
+
+    /// Set the labels of the columns.
+    pub fn x_labels(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.x_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the labels of the rows.
+    pub fn y_labels(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.y_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the color of the max value, default is the chart color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the size of the cells, default: 24px
+    pub fn cell_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.cell_size = size.into();
+        self
+    }
+
+    /// Set to show the color scale legend, default: false
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Set the selected cell by `(row, col)`.
+    pub fn selected(mut self, selected: Option<(usize, usize)>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set the handler when a cell is clicked, the argument is the `(row, col)`.
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&(usize, usize), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Styled for Heatmap {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Heatmap {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().chart_2);
+        let values = self.values.iter().flatten().filter(|v| v.is_finite());
+        let min = values.clone().copied().fold(f64::INFINITY, f64::min);
+        let max = values.copied().fold(f64::NEG_INFINITY, f64::max);
+        // Keep the min value visible, when all values are same.
+        let min = if min < max { min } else { min - 1. };
+        let cell_size = self.cell_size;
+        let cols = self.values.iter().map(|row| row.len()).max().unwrap_or(0);
+        let has_y_labels = !self.y_labels.is_empty();
+
+        v_flex()
+            .id(self.id)
+            .gap_1()
+            .text_xs()
+            .refine_style(&self.style)
+            .children(self.values.iter().enumerate().map(|(row_ix, row)| {
+                let y_label = self.y_labels.get(row_ix).cloned();
+                h_flex()
+                    .gap_1()
+                    .when(has_y_labels, |this| {
+                        this.child(
+                            div()
+                                .w_16()
+                                .truncate()
+                                .text_color(cx.theme().muted_foreground)
+                                .children(y_label.clone()),
+                        )
+                    })
+                    .children(row.iter().enumerate().map(|(col_ix, value)| {
+                        let value = *value;
+                        let selected = self.selected == Some((row_ix, col_ix));
+                        let tooltip = SharedString::from(
+                            [
+                                y_label.clone(),
+                                self.x_labels.get(col_ix).cloned(),
+                                Some(format_value(value).into()),
+                            ]
+                            .into_iter()
+                            .flatten()
+                            .collect::<Vec<_>>()
+                            .join(" · "),
+                        );
+
+                        div()
+                            .id(("heatmap-cell", row_ix * cols + col_ix))
+                            .size(cell_size)
+                            .rounded_xs()
+                            .bg(level_color(level(value, min, max), color, cx))
+                            .when(selected, |this| {
+                                this.border_2().border_color(cx.theme().ring)
+                            })
+                            .tooltip(move |window, cx| {
+                                Tooltip::new(tooltip.clone()).build(window, cx)
+                            })
+                            .when_some(self.on_select.clone(), |this, on_select| {
+                                this.cursor_pointer().on_click(move |_, window, cx| {
+                                    on_select(&(row_ix, col_ix), window, cx)
+                                })
+                            })
+                    }))
+            }))
+            .when(!self.x_labels.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .gap_1()
+                        .when(has_y_labels, |this| this.child(div().w_16()))
+                        .text_color(cx.theme().muted_foreground)
+                        .children(self.x_labels.iter().take(cols).map(|label| {
+                            div()
+                                .w(cell_size)
+                                .text_center()
+                                .truncate()
+                                .child(label.clone())
+                        })),
+                )
+            })
+            .when(self.legend, |this| {
+                this.child(h_flex().justify_end().child(legend(color, px(10.), cx)))
+            })
+    }
+}
+
+/// A GitHub-style calendar heatmap, the columns are weeks and the rows are weekdays.
+///
+/// ```ignore
+/// CalendarHeatmap::new("contributions", contributions)
+///     .on_select(|date, _, _| println!("{date}"))
+/// ```
+#[derive(IntoElement)]
+pub struct CalendarHeatmap {
+    id: ElementId,
+    style: StyleRefinement,
+    values: Vec<(NaiveDate, f64)>,
+    end: Option<NaiveDate>,
+    weeks: usize,
+    color: Option<Hsla>,
+    cell_size: Pixels,
+    legend: bool,
+    selected: Option<NaiveDate>,
+    on_select: Option<Rc<dyn Fn(&NaiveDate, &mut Window, &mut App)>>,
+}
+
+impl CalendarHeatmap {
+    /// Create a new CalendarHeatmap with the values of the dates.
+    pub fn new(
+        id: impl Into<ElementId>,
+        values: impl IntoIterator<Item = (NaiveDate, f64)>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            values: values.into_iter().collect(),
+            end: None,
+            weeks: 53,
+            color: None,
+            cell_size: px(12.),
+            legend: true,
+            selected: None,
+            on_select: None,
+        }
+    }
+
+    /// Set the last date to show, default is today.
+    pub fn end(mut self, end: NaiveDate) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Set the number of weeks to show, default: 53
+    pub fn weeks(mut self, weeks: usize) -> Self {
+        self.weeks = weeks.max(1);
+        self
+    }
+
+    /// Set the color of the max value, default is the chart color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the size of the day cells, default: 12px
+    pub fn cell_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.cell_size = size.into();
+        self
+    }
+
+    /// Set to show the color scale legend, default: true
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Set the selected date.
+    pub fn selected(mut self, selected: Option<NaiveDate>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set the handler when a day is clicked.
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&NaiveDate, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Returns the first day (Sunday) of the first week, to show `weeks` weeks until the `end`.
+fn calendar_start(end: NaiveDate, weeks: usize) -> NaiveDate {
+    let days = end.weekday().num_days_from_sunday() as i64 + (weeks as i64 - 1) * 7;
+    end - Duration::days(days)
+}
+
+fn short_month_name(month: u32) -> SharedString {
+    match month {
+        1 => t!("Heatmap.month.1"),
+        2 => t!("Heatmap.month.2"),
+        3 => t!("Heatmap.month.3"),
+        4 => t!("Heatmap.month.4"),
+        5 => t!("Heatmap.month.5"),
+        6 => t!("Heatmap.month.6"),
+        7 => t!("Heatmap.month.7"),
+        8 => t!("Heatmap.month.8"),
+        9 => t!("Heatmap.month.9"),
+        10 => t!("Heatmap.month.10"),
+        11 => t!("Heatmap.month.11"),
+        12 => t!("Heatmap.month.12"),
+        _ => "".into(),
+    }
+    .into()
+}
+
+impl Styled for CalendarHeatmap {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for CalendarHeatmap {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().chart_2);
+        let end = self.end.unwrap_or_else(|| Local::now().date_naive());
+        let start = calendar_start(end, self.weeks);
+        let max = self
+            .values
+            .iter()
+            .map(|(_, v)| *v)
+            .filter(|v| v.is_finite())
+            .fold(0., f64::max);
+        let value_of = |date: NaiveDate| {
+            self.values
+                .iter()
+                .filter(|(d, _)| *d == date)
+                .map(|(_, v)| *v)
+                .sum::<f64>()
+        };
+        let cell_size = self.cell_size;
+        let gap = px(3.);
+
+        let weekdays = [
+            None,
+            Some(t!("Calendar.week.1")),
+            None,
+            Some(t!("Calendar.week.3")),
+            None,
+            Some(t!("Calendar.week.5")),
+            None,
+        ];
+
+        v_flex()
+            .id(self.id)
+            .gap_1()
+            .text_xs()
+            .refine_style(&self.style)
+            .child(
+                // Month labels, show at the first week of the month.
+                h_flex()
+                    .gap(gap)
+                    .text_color(cx.theme().muted_foreground)
+                    .child(div().w_6().flex_shrink_0())
+                    .children((0..self.weeks).map(|week| {
+                        let first_day = start + Duration::weeks(week as i64);
+                        let show = week == 0
+                            || (first_day - Duration::weeks(1)).month() != first_day.month();
+                        div()
+                            .w(cell_size)
+                            .flex_shrink_0()
+                            .whitespace_nowrap()
+                            .when(show, |this| this.child(short_month_name(first_day.month())))
+                    })),
+            )
+            .child(
+                h_flex()
+                    .gap(gap)
+                    .items_start()
+                    .child(
+                        v_flex()
+                            .w_6()
+                            .flex_shrink_0()
+                            .gap(gap)
+                            .text_color(cx.theme().muted_foreground)
+                            .children(weekdays.into_iter().map(|label| {
+                                div()
+                                    .h(cell_size)
+                                    .flex()
+                                    .items_center()
+                                    .children(label.map(SharedString::from))
+                            })),
+                    )
+                    .children((0..self.weeks).map(|week| {
+                        v_flex()
+                            .gap(gap)
+                            .flex_shrink_0()
+                            .children((0..7).map(|weekday| {
+                                let ix = week * 7 + weekday;
+                                let date = start + Duration::days(ix as i64);
+                                if date > end {
+                                    return div().size(cell_size).into_any_element();
+                                }
+
+                                let value = value_of(date);
+                                let selected = self.selected == Some(date);
+                                let tooltip = SharedString::from(format!(
+                                    "{} · {}",
+                                    date.format("%Y-%m-%d"),
+                                    format_value(value)
+                                ));
+
+                                div()
+                                    .id(("heatmap-day", ix))
+                                    .size(cell_size)
+                                    .rounded_xs()
+                                    .bg(level_color(level(value, 0., max), color, cx))
+                                    .when(selected, |this| {
+                                        this.border_1().border_color(cx.theme().ring)
+                                    })
+                                    .tooltip(move |window, cx| {
+                                        Tooltip::new(tooltip.clone()).build(window, cx)
+                                    })
+                                    .when_some(self.on_select.clone(), |this, on_select| {
+                                        this.cursor_pointer().on_click(move |_, window, cx| {
+                                            on_select(&date, window, cx)
+                                        })
+                                    })
+                                    .into_any_element()
+                            }))
+                    })),
+            )
+            .when(self.legend, |this| {
+                this.child(h_flex().justify_end().child(legend(color, cell_size, cx)))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    use super::{calendar_start, level};
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0., 0., 10.), 0);
+        assert_eq!(level(1., 0., 10.), 1);
+        assert_eq!(level(2.5, 0., 10.), 1);
+        assert_eq!(level(5., 0., 10.), 2);
+        assert_eq!(level(7.6, 0., 10.), 4);
+        assert_eq!(level(10., 0., 10.), 4);
+        assert_eq!(level(20., 0., 10.), 4);
+        assert_eq!(level(f64::NAN, 0., 10.), 0);
+        assert_eq!(level(1., 1., 1.), 0);
+    }
+
+    #[test]
+    fn test_calendar_start() {
+        let end = NaiveDate::from_ymd_opt(2025, 6, 18).unwrap();
+        let start = calendar_start(end, 1);
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 6, 15).unwrap());
+        assert_eq!(start.weekday(), Weekday::Sun);
+
+        let start = calendar_start(end, 53);
+        assert_eq!(start.weekday(), Weekday::Sun);
+        assert_eq!((end - start).num_days(), 52 * 7 + 3);
+    }
+}
@@ -1,9 +1,13 @@
 mod area_chart;
 mod bar_chart;
+mod gauge;
+mod heatmap;
 mod line_chart;
 mod pie_chart;
 
 pub use area_chart::AreaChart;
 pub use bar_chart::BarChart;
+pub use gauge::Gauge;
+pub use heatmap::{CalendarHeatmap, Heatmap};
 pub use line_chart::LineChart;
 pub use pie_chart::PieChart;
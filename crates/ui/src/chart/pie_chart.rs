@@ -1,17 +1,30 @@
-use std::rc::Rc;
+use std::{
+    f32::consts::{PI, TAU},
+    rc::Rc,
+};
 
-use gpui::{App, Bounds, Hsla, Pixels, Window};
+use gpui::{
+    fill, point, px, size, App, Bounds, Hsla, MouseButton, MouseDownEvent, MouseMoveEvent, Pixels,
+    Point, SharedString, TextAlign, Window,
+};
 use gpui_component_macros::IntoPlot;
 use num_traits::Zero;
 
 use crate::{
     plot::{
+        label::{Label, Text, TEXT_HEIGHT, TEXT_SIZE},
         shape::{Arc, Pie},
         Plot,
     },
     ActiveTheme, PixelsExt,
 };
 
+/// The outer radius offset of the hovered slice.
+const HOVER_OFFSET: f32 = 6.;
+/// The width ratio of the legend column.
+const LEGEND_RATIO: f32 = 0.4;
+
+#[allow(clippy::type_complexity)]
 #[derive(IntoPlot)]
 pub struct PieChart<T: 'static> {
     data: Vec<T>,
@@ -20,6 +33,9 @@ pub struct PieChart<T: 'static> {
     pad_angle: f32,
     value: Option<Rc<dyn Fn(&T) -> f32>>,
     color: Option<Rc<dyn Fn(&T) -> Hsla>>,
+    label: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    legend: bool,
+    on_click: Option<Rc<dyn Fn(&usize, &mut Window, &mut App)>>,
 }
 
 impl<T> PieChart<T> {
@@ -34,6 +50,9 @@ impl<T> PieChart<T> {
             pad_angle: 0.,
             value: None,
             color: None,
+            label: None,
+            legend: false,
+            on_click: None,
         }
     }
 
@@ -57,6 +76,7 @@ impl<T> PieChart<T> {
         self
     }
 
+    /// Set the color of the slice, default is the chart colors of the theme.
     pub fn color<H>(mut self, color: impl Fn(&T) -> H + 'static) -> Self
     where
         H: Into<Hsla> + 'static,
@@ -64,6 +84,48 @@ impl<T> PieChart<T> {
         self.color = Some(Rc::new(move |t| color(t).into()));
         self
     }
+
+    /// Set the label of the slice, the label is shown outside the slice.
+    pub fn label<S>(mut self, label: impl Fn(&T) -> S + 'static) -> Self
+    where
+        S: Into<SharedString> + 'static,
+    {
+        self.label = Some(Rc::new(move |t| label(t).into()));
+        self
+    }
+
+    /// Set to show the legend with the percentage of each slice, default: false
+    ///
+    /// The legend uses the [`PieChart::label`] as the name.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Set the click handler of the slice, the argument is the index of the data.
+    pub fn on_click(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Returns the index of the slice (in `angles`) at the position relative to the center.
+fn hit_test(
+    position: Point<f32>,
+    inner_radius: f32,
+    outer_radius: f32,
+    angles: &[(f32, f32)],
+) -> Option<usize> {
+    let r = (position.x * position.x + position.y * position.y).sqrt();
+    if r < inner_radius || r > outer_radius {
+        return None;
+    }
+
+    // The angle starts from the top and clockwise, same as the Arc.
+    let angle = (position.y.atan2(position.x) + PI / 2.).rem_euclid(TAU);
+    angles
+        .iter()
+        .position(|(start, end)| angle >= *start && angle < *end)
 }
 
 impl<T> Plot for PieChart<T> {
@@ -72,31 +134,224 @@ impl<T> Plot for PieChart<T> {
             return;
         };
 
+        let (pie_bounds, legend_bounds) = if self.legend {
+            let legend_width = bounds.size.width * LEGEND_RATIO;
+            (
+                Bounds::new(
+                    bounds.origin,
+                    size(bounds.size.width - legend_width, bounds.size.height),
+                ),
+                Some(Bounds::new(
+                    point(bounds.right() - legend_width, bounds.origin.y),
+                    size(legend_width, bounds.size.height),
+                )),
+            )
+        } else {
+            (bounds, None)
+        };
+
         let outer_radius = if self.outer_radius.is_zero() {
-            bounds.size.height.as_f32() * 0.4
+            pie_bounds.size.height.as_f32() * 0.4
         } else {
             self.outer_radius
         };
+        let inner_radius = self.inner_radius;
 
-        let arc = Arc::new()
-            .inner_radius(self.inner_radius)
-            .outer_radius(outer_radius);
         let value_fn = value_fn.clone();
         let mut pie = Pie::<T>::new().value(move |d| Some(value_fn(d)));
         pie = pie.pad_angle(self.pad_angle);
         let arcs = pie.arcs(&self.data);
+        let total = arcs.iter().map(|a| a.value).sum::<f32>();
+        let angles = arcs
+            .iter()
+            .map(|a| (a.start_angle, a.end_angle))
+            .collect::<Vec<_>>();
+        let indexes = arcs.iter().map(|a| a.index).collect::<Vec<_>>();
 
-        for a in &arcs {
-            arc.paint(
-                a,
-                if let Some(color_fn) = self.color.as_ref() {
-                    color_fn(a.data)
+        let center = pie_bounds.center();
+        let relative_position = move |position: Point<Pixels>| {
+            point(
+                (position.x - center.x).as_f32(),
+                (position.y - center.y).as_f32(),
+            )
+        };
+        let hovered = hit_test(
+            relative_position(window.mouse_position()),
+            inner_radius,
+            outer_radius,
+            &angles,
+        );
+
+        let palette = [
+            cx.theme().chart_1,
+            cx.theme().chart_2,
+            cx.theme().chart_3,
+            cx.theme().chart_4,
+            cx.theme().chart_5,
+        ];
+        let colors = arcs
+            .iter()
+            .enumerate()
+            .map(|(ix, a)| match self.color.as_ref() {
+                Some(color_fn) => color_fn(a.data),
+                None => palette[ix % palette.len()],
+            })
+            .collect::<Vec<_>>();
+
+        let arc = Arc::new()
+            .inner_radius(inner_radius)
+            .outer_radius(outer_radius);
+        let hovered_arc = Arc::new()
+            .inner_radius(inner_radius)
+            .outer_radius(outer_radius + HOVER_OFFSET);
+        for (ix, a) in arcs.iter().enumerate() {
+            if hovered == Some(ix) {
+                hovered_arc.paint(a, colors[ix], &pie_bounds, window);
+            } else {
+                arc.paint(a, colors[ix], &pie_bounds, window);
+            }
+        }
+
+        let labels = self.label.as_ref().map(|label_fn| {
+            arcs.iter()
+                .map(|a| label_fn(a.data))
+                .collect::<Vec<SharedString>>()
+        });
+
+        // Draw labels outside the slices.
+        if let Some(labels) = labels.as_ref() {
+            let label_radius = outer_radius + HOVER_OFFSET + TEXT_HEIGHT;
+            let label_arc = Arc::new()
+                .inner_radius(label_radius)
+                .outer_radius(label_radius);
+            let offset = pie_bounds.origin - bounds.origin;
+            let half_width = pie_bounds.size.width.as_f32() / 2.;
+            let half_height = pie_bounds.size.height.as_f32() / 2.;
+
+            Label::new(
+                arcs.iter()
+                    .zip(labels.iter())
+                    .map(|(a, label)| {
+                        let p = label_arc.centroid(a);
+                        Text::new(
+                            label.clone(),
+                            point(
+                                px(half_width + p.x) + offset.x,
+                                px(half_height + p.y - TEXT_SIZE / 2.) + offset.y,
+                            ),
+                            cx.theme().muted_foreground,
+                        )
+                        .align(TextAlign::Center)
+                    })
+                    .collect(),
+            )
+            .paint(&bounds, window, cx);
+        }
+
+        // Draw legend with percentages.
+        if let Some(legend_bounds) = legend_bounds {
+            let row_height = TEXT_HEIGHT + 8.;
+            let top = (legend_bounds.size.height.as_f32() - row_height * arcs.len() as f32) / 2.;
+            let left = (legend_bounds.origin.x - bounds.origin.x).as_f32();
+
+            let mut texts = Vec::with_capacity(arcs.len());
+            for (ix, a) in arcs.iter().enumerate() {
+                let y = top.max(0.) + row_height * ix as f32;
+                let swatch = Bounds::new(
+                    point(
+                        legend_bounds.origin.x,
+                        bounds.origin.y + px(y + (TEXT_SIZE - 8.) / 2.),
+                    ),
+                    size(px(8.), px(8.)),
+                );
+                window.paint_quad(fill(swatch, colors[ix]).corner_radii(px(2.)));
+
+                let name = labels
+                    .as_ref()
+                    .map(|labels| labels[ix].clone())
+                    .unwrap_or_else(|| format!("#{}", a.index + 1).into());
+                let percentage = if total > 0. {
+                    a.value / total * 100.
                 } else {
-                    cx.theme().chart_2
-                },
-                &bounds,
-                window,
-            );
+                    0.
+                };
+                texts.push(Text::new(
+                    format!(
+                        "{}  {}%",
+                        name,
+                        crate::locale::format_number(percentage as f64, 1)
+                    ),
+                    point(px(left + 14.), px(y)),
+                    if hovered == Some(ix) {
+                        cx.theme().foreground
+                    } else {
+                        cx.theme().muted_foreground
+                    },
+                ));
+            }
+            Label::new(texts).paint(&bounds, window, cx);
         }
+
+        // Refresh to highlight the slice under the mouse.
+        window.on_mouse_event({
+            let angles = angles.clone();
+            move |event: &MouseMoveEvent, phase, window, _| {
+                if !phase.bubble() {
+                    return;
+                }
+
+                let ix = hit_test(
+                    relative_position(event.position),
+                    inner_radius,
+                    outer_radius,
+                    &angles,
+                );
+                if ix != hovered {
+                    window.refresh();
+                }
+            }
+        });
+
+        if let Some(on_click) = self.on_click.clone() {
+            window.on_mouse_event(move |event: &MouseDownEvent, phase, window, cx| {
+                if !phase.bubble() || event.button != MouseButton::Left {
+                    return;
+                }
+
+                if let Some(ix) = hit_test(
+                    relative_position(event.position),
+                    inner_radius,
+                    outer_radius,
+                    &angles,
+                ) {
+                    on_click(&indexes[ix], window, cx);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::TAU;
+
+    use gpui::point;
+
+    use super::hit_test;
+
+    #[test]
+    fn test_hit_test() {
+        let angles = [(0., TAU / 4.), (TAU / 4., TAU / 2.), (TAU / 2., TAU)];
+
+        // Top right
+        assert_eq!(hit_test(point(10., -10.), 0., 20., &angles), Some(0));
+        // Bottom right
+        assert_eq!(hit_test(point(10., 10.), 0., 20., &angles), Some(1));
+        // Left
+        assert_eq!(hit_test(point(-10., 0.), 0., 20., &angles), Some(2));
+        // Outside
+        assert_eq!(hit_test(point(30., 0.), 0., 20., &angles), None);
+        // In the donut hole
+        assert_eq!(hit_test(point(5., 0.), 10., 20., &angles), None);
     }
 }
@@ -1,13 +1,17 @@
 use std::{rc::Rc, time::Duration};
 
 use crate::{
-    text::Text, v_flex, ActiveTheme, Disableable, FocusableExt, IconName, Selectable, Sizable,
-    Size, StyledExt as _,
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Checked, Role},
+    form::{apply_change, before_change_pending, BeforeChange, FieldChange},
+    indicator::Indicator,
+    text::Text,
+    v_flex, ActiveTheme, Disableable, FocusableExt, IconName, Selectable, Side, Sizable, Size,
+    StyleOverrides, StyledExt as _,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, relative, rems, svg, Animation, AnimationExt, AnyElement,
-    App, Div, ElementId, InteractiveElement, IntoElement, ParentElement, RenderOnce,
-    StatefulInteractiveElement, StyleRefinement, Styled, Window,
+    App, Div, ElementId, InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement, StyleRefinement, Styled, Task, Window,
 };
 
 /// A Checkbox element.
@@ -17,13 +21,22 @@ pub struct Checkbox {
     base: Div,
     style: StyleRefinement,
     label: Option<Text>,
+    label_side: Side,
+    description: Option<Text>,
     children: Vec<AnyElement>,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
+    invalid: bool,
     size: Size,
     tab_stop: bool,
     tab_index: isize,
+    name: Option<SharedString>,
+    value: Option<SharedString>,
     on_click: Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    on_field_change: Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App) + 'static>>,
+    before_change: Option<BeforeChange>,
+    accessibility: AccessibilityProps,
 }
 
 impl Checkbox {
@@ -33,13 +46,22 @@ impl Checkbox {
             base: div(),
             style: StyleRefinement::default(),
             label: None,
+            label_side: Side::Right,
+            description: None,
             children: Vec::new(),
             checked: false,
+            indeterminate: false,
             disabled: false,
+            invalid: false,
             size: Size::default(),
             on_click: None,
+            on_field_change: None,
+            before_change: None,
             tab_stop: true,
             tab_index: 0,
+            name: None,
+            value: None,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -48,16 +70,77 @@ impl Checkbox {
         self
     }
 
+    /// Set the side of the label to the checkbox, default is [`Side::Right`].
+    pub fn label_side(mut self, label_side: Side) -> Self {
+        self.label_side = label_side;
+        self
+    }
+
+    /// Set the description below the label.
+    pub fn description(mut self, description: impl Into<Text>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = checked;
         self
     }
 
+    /// Set the indeterminate state, default is false.
+    ///
+    /// The indeterminate checkbox shows a dash (e.g.: some of the children are checked),
+    /// and it will be checked when clicked.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Set the invalid state to show the validation error, default is false.
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Set the name of the checkbox in the form, see [`FieldChange`].
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the value of the checkbox in the form, see [`FieldChange`].
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
         self.on_click = Some(Rc::new(handler));
         self
     }
 
+    /// Listen to the change with the form metadata, this is called after the `on_click`.
+    pub fn on_field_change(
+        mut self,
+        handler: impl Fn(&FieldChange, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_field_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the guard to confirm the change before the `on_click` and `on_field_change` are called,
+    /// the first argument is the new checked state.
+    ///
+    /// The checkbox shows the loading state until the task is resolved,
+    /// and the change is vetoed if it resolves `false`, e.g.: the user cancels a confirm dialog.
+    pub fn on_before_change(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) -> Task<bool> + 'static,
+    ) -> Self {
+        self.before_change = Some(Rc::new(handler));
+        self
+    }
+
     /// Set the tab stop for the checkbox, default is true.
     pub fn tab_stop(mut self, tab_stop: bool) -> Self {
         self.tab_stop = tab_stop;
@@ -72,13 +155,16 @@ impl Checkbox {
 
     fn handle_click(
         on_click: &Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
-        checked: bool,
+        on_field_change: &Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App) + 'static>>,
+        change: FieldChange,
         window: &mut Window,
         cx: &mut App,
     ) {
-        let new_checked = !checked;
         if let Some(f) = on_click {
-            (f)(&new_checked, window, cx);
+            (f)(&change.checked, window, cx);
+        }
+        if let Some(f) = on_field_change {
+            (f)(&change, window, cx);
         }
     }
 }
@@ -96,6 +182,12 @@ impl Styled for Checkbox {
     }
 }
 
+impl Accessible for Checkbox {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Disableable for Checkbox {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -130,11 +222,18 @@ pub(crate) fn checkbox_check_icon(
     id: ElementId,
     size: Size,
     checked: bool,
+    indeterminate: bool,
     disabled: bool,
     window: &mut Window,
     cx: &mut App,
 ) -> impl IntoElement {
-    let toggle_state = window.use_keyed_state(id, cx, |_, _| checked);
+    let state = (checked, indeterminate);
+    let toggle_state = window.use_keyed_state(id, cx, |_, _| state);
+    let visible = checked || indeterminate;
+    let changed = state != *toggle_state.read(cx);
+    if changed && (disabled || cx.theme().reduce_motion) {
+        toggle_state.update(cx, |this, _| *this = state);
+    }
     let color = if disabled {
         cx.theme().primary_foreground.opacity(0.5)
     } else {
@@ -153,27 +252,31 @@ pub(crate) fn checkbox_check_icon(
             _ => this.size_3(),
         })
         .text_color(color)
-        .map(|this| match checked {
-            true => this.path(IconName::Check.path()),
+        .map(|this| match (indeterminate, checked) {
+            (true, _) => this.path(IconName::Minus.path()),
+            (false, true) => this.path(IconName::Check.path()),
             _ => this,
         })
         .map(|this| {
-            if !disabled && checked != *toggle_state.read(cx) {
+            if changed && state != *toggle_state.read(cx) {
                 let duration = Duration::from_secs_f64(0.25);
                 cx.spawn({
                     let toggle_state = toggle_state.clone();
                     async move |cx| {
                         cx.background_executor().timer(duration).await;
-                        _ = toggle_state.update(cx, |this, _| *this = checked);
+                        _ = toggle_state.update(cx, |this, _| *this = state);
                     }
                 })
                 .detach();
 
                 this.with_animation(
-                    ElementId::NamedInteger("toggle".into(), checked as u64),
-                    Animation::new(Duration::from_secs_f64(0.25)),
+                    ElementId::NamedInteger(
+                        "toggle".into(),
+                        checked as u64 | (indeterminate as u64) << 1,
+                    ),
+                    Animation::new(duration),
                     move |this, delta| {
-                        this.opacity(if checked { 1.0 * delta } else { 1.0 - delta })
+                        this.opacity(if visible { 1.0 * delta } else { 1.0 - delta })
                     },
                 )
                 .into_any_element()
@@ -186,14 +289,35 @@ pub(crate) fn checkbox_check_icon(
 impl RenderOnce for Checkbox {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let checked = self.checked;
+        let indeterminate = self.indeterminate;
+        let filled = checked || indeterminate;
 
         let focus_handle = window
             .use_keyed_state(self.id.clone(), cx, |_, cx| cx.focus_handle())
             .read(cx)
             .clone();
         let is_focused = focus_handle.is_focused(window);
+        let pending = self
+            .before_change
+            .as_ref()
+            .map(|_| before_change_pending(&self.id, window, cx));
+        let loading = pending.as_ref().is_some_and(|pending| *pending.read(cx));
 
-        let border_color = if checked {
+        let mut props = AccessibilityProps::new(Role::Checkbox)
+            .checked(if indeterminate {
+                Checked::Mixed
+            } else {
+                Checked::from(checked)
+            })
+            .disabled(self.disabled)
+            .invalid(self.invalid);
+        props.label = self
+            .label
+            .as_ref()
+            .and_then(|label| label.as_str().cloned());
+        Accessibility::declare(&self.id, &props.merge(&self.accessibility), window, cx);
+
+        let border_color = if filled {
             cx.theme().primary
         } else {
             cx.theme().input
@@ -218,6 +342,7 @@ impl RenderOnce for Checkbox {
                 .h_flex()
                 .gap_2()
                 .items_start()
+                .when(self.label_side.is_left(), |this| this.flex_row_reverse())
                 .line_height(relative(1.))
                 .text_color(cx.theme().foreground)
                 .map(|this| match self.size {
@@ -232,71 +357,118 @@ impl RenderOnce for Checkbox {
                 })
                 .rounded(cx.theme().radius * 0.5)
                 .focus_ring(is_focused, px(2.), window, cx)
+                .refine_style(&StyleOverrides::style_for::<Self>(cx))
                 .refine_style(&self.style)
-                .child(
-                    div()
-                        .relative()
-                        .map(|this| match self.size {
-                            Size::XSmall => this.size_3(),
-                            Size::Small => this.size_3p5(),
-                            Size::Medium => this.size_4(),
-                            Size::Large => this.size(rems(1.125)),
-                            _ => this.size_4(),
-                        })
-                        .flex_shrink_0()
-                        .border_1()
-                        .border_color(color)
-                        .rounded(radius)
-                        .when(cx.theme().shadow && !self.disabled, |this| this.shadow_xs())
-                        .map(|this| match checked {
-                            false => this.bg(cx.theme().background),
-                            _ => this.bg(color),
-                        })
-                        .child(checkbox_check_icon(
-                            self.id,
-                            self.size,
-                            checked,
-                            self.disabled,
-                            window,
-                            cx,
-                        )),
-                )
-                .when(self.label.is_some() || !self.children.is_empty(), |this| {
+                .when(loading, |this| {
+                    this.child(
+                        div()
+                            .flex_shrink_0()
+                            .child(Indicator::new().with_size(self.size)),
+                    )
+                })
+                .when(!loading, |this| {
                     this.child(
-                        v_flex()
-                            .w_full()
-                            .line_height(relative(1.2))
-                            .gap_1()
-                            .map(|this| {
-                                if let Some(label) = self.label {
+                        div()
+                            .relative()
+                            .map(|this| match self.size {
+                                Size::XSmall => this.size_3(),
+                                Size::Small => this.size_3p5(),
+                                Size::Medium => this.size_4(),
+                                Size::Large => this.size(rems(1.125)),
+                                _ => this.size_4(),
+                            })
+                            .flex_shrink_0()
+                            .border_1()
+                            .border_color(color)
+                            .when(self.invalid, |this| this.border_color(cx.theme().danger))
+                            .rounded(radius)
+                            .when(cx.theme().shadow && !self.disabled, |this| this.shadow_xs())
+                            .map(|this| match filled {
+                                false => this.bg(cx.theme().background),
+                                _ => this.bg(color),
+                            })
+                            .child(checkbox_check_icon(
+                                self.id,
+                                self.size,
+                                checked,
+                                indeterminate,
+                                self.disabled,
+                                window,
+                                cx,
+                            )),
+                    )
+                })
+                .when(
+                    self.label.is_some() || self.description.is_some() || !self.children.is_empty(),
+                    |this| {
+                        this.child(
+                            v_flex()
+                                .w_full()
+                                .line_height(relative(1.2))
+                                .gap_1()
+                                .map(|this| {
+                                    if let Some(label) = self.label {
+                                        this.child(
+                                            div()
+                                                .size_full()
+                                                .text_color(cx.theme().foreground)
+                                                .when(self.disabled, |this| {
+                                                    this.text_color(cx.theme().muted_foreground)
+                                                })
+                                                .line_height(relative(1.))
+                                                .child(label),
+                                        )
+                                    } else {
+                                        this
+                                    }
+                                })
+                                .when_some(self.description, |this, description| {
                                     this.child(
                                         div()
-                                            .size_full()
-                                            .text_color(cx.theme().foreground)
-                                            .when(self.disabled, |this| {
-                                                this.text_color(cx.theme().muted_foreground)
+                                            .text_color(cx.theme().muted_foreground)
+                                            .map(|this| match self.size {
+                                                Size::XSmall | Size::Small => this.text_xs(),
+                                                _ => this.text_sm(),
                                             })
-                                            .line_height(relative(1.))
-                                            .child(label),
+                                            .child(description),
                                     )
-                                } else {
-                                    this
-                                }
-                            })
-                            .children(self.children),
-                    )
-                })
+                                })
+                                .children(self.children),
+                        )
+                    },
+                )
                 .on_mouse_down(gpui::MouseButton::Left, |_, window, _| {
                     // Avoid focus on mouse down.
                     window.prevent_default();
                 })
-                .when(!self.disabled, |this| {
+                .when(!self.disabled && !loading, |this| {
                     this.on_click({
                         let on_click = self.on_click.clone();
+                        let on_field_change = self.on_field_change.clone();
+                        let before_change = self.before_change.clone();
+                        let change = FieldChange {
+                            name: self.name.clone(),
+                            value: self.value.clone(),
+                            // The indeterminate checkbox is always checked by click.
+                            checked: indeterminate || !checked,
+                        };
                         move |_, window, cx| {
                             window.prevent_default();
                             cx.stop_propagation();
-                            Self::handle_click(&on_click, checked, window, cx);
+                            let on_click = on_click.clone();
+                            let on_field_change = on_field_change.clone();
+                            let apply =
+                                move |change: &FieldChange, window: &mut Window, cx: &mut App| {
+                                    Self::handle_click(
+                                        &on_click,
+                                        &on_field_change,
+                                        change.clone(),
+                                        window,
+                                        cx,
+                                    );
+                                };
+                            let guard = before_change.clone().zip(pending.clone());
+                            apply_change(change.clone(), guard, apply, window, cx);
                         }
                     })
                 }),
@@ -1,13 +1,15 @@
-use std::{cell::Cell, rc::Rc, time::Duration};
+use std::{cell::Cell, ops::Range, rc::Rc, time::Duration};
 
 use gpui::{
-    prelude::FluentBuilder, AnyElement, App, ClipboardItem, Element, ElementId, GlobalElementId,
-    IntoElement, LayoutId, ParentElement, SharedString, Styled, Window,
+    prelude::FluentBuilder, AnyElement, App, ClipboardEntry, ClipboardItem, ClipboardString,
+    Element, ElementId, FontStyle, FontWeight, GlobalElementId, HighlightStyle, Image, IntoElement,
+    LayoutId, ParentElement, SharedString, Styled, Window,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
     button::{Button, ButtonVariants as _},
-    h_flex, IconName, Sizable as _,
+    h_flex, Colorize as _, IconName, Sizable as _,
 };
 
 pub struct Clipboard {
@@ -180,3 +182,263 @@ impl Element for Clipboard {
         element.paint(window, cx)
     }
 }
+
+/// The metadata of the rich text in the clipboard.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct RichTextMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    markdown: Option<String>,
+}
+
+/// The content to write into or read from the system clipboard, with the plain text,
+/// the rich text (HTML, Markdown) and the images.
+///
+/// GPUI only exchanges the plain text or an image with the platform clipboard, so:
+///
+/// - The HTML and Markdown are kept as the metadata of the text, they are only available
+///   when pasting into the GPUI applications, other applications got the plain text.
+/// - The image is only written when the text is empty.
+///
+/// ```ignore
+/// ClipboardContent::new(text)
+///     .html(html)
+///     .write(cx);
+///
+/// if let Some(content) = ClipboardContent::read(cx) {
+///     let html = content.html().unwrap_or(content.text());
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ClipboardContent {
+    text: String,
+    metadata: RichTextMetadata,
+    image: Option<Image>,
+}
+
+impl ClipboardContent {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the HTML of the content.
+    pub fn html(mut self, html: impl Into<String>) -> Self {
+        self.metadata.html = Some(html.into());
+        self
+    }
+
+    /// Set the Markdown of the content.
+    pub fn markdown(mut self, markdown: impl Into<String>) -> Self {
+        self.metadata.markdown = Some(markdown.into());
+        self
+    }
+
+    /// Set the image of the content.
+    pub fn image(mut self, image: Image) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Returns the plain text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the HTML, None if copied from other applications.
+    pub fn html_text(&self) -> Option<&str> {
+        self.metadata.html.as_deref()
+    }
+
+    /// Returns the Markdown, None if copied from other applications.
+    pub fn markdown_text(&self) -> Option<&str> {
+        self.metadata.markdown.as_deref()
+    }
+
+    /// Returns the image.
+    pub fn image_data(&self) -> Option<&Image> {
+        self.image.as_ref()
+    }
+
+    /// Write the content into the system clipboard.
+    pub fn write(self, cx: &mut App) {
+        cx.write_to_clipboard(self.into_item());
+    }
+
+    /// Read the content from the system clipboard, None if the clipboard is empty.
+    pub fn read(cx: &App) -> Option<Self> {
+        cx.read_from_clipboard().map(Self::from_item)
+    }
+
+    fn into_item(self) -> ClipboardItem {
+        if self.text.is_empty() {
+            if let Some(image) = self.image {
+                return ClipboardItem::new_image(&image);
+            }
+        }
+
+        let mut text = ClipboardString::new(self.text);
+        if self.metadata != RichTextMetadata::default() {
+            text = text.with_json_metadata(self.metadata);
+        }
+        ClipboardEntry::String(text).into()
+    }
+
+    fn from_item(item: ClipboardItem) -> Self {
+        let mut content = Self::default();
+        for entry in item.into_entries() {
+            match entry {
+                ClipboardEntry::String(text) => {
+                    if let Some(metadata) = text.metadata_json::<RichTextMetadata>() {
+                        content.metadata = metadata;
+                    }
+                    if !content.text.is_empty() {
+                        content.text.push('\n');
+                    }
+                    content.text.push_str(text.text());
+                }
+                ClipboardEntry::Image(image) => {
+                    if content.image.is_none() {
+                        content.image = Some(image);
+                    }
+                }
+            }
+        }
+        content
+    }
+}
+
+/// Escape the special characters of HTML.
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns the HTML of the `text` as a `<pre>` block with the highlight `styles`,
+/// the ranges of the `styles` are the byte offsets in the `text`.
+pub(crate) fn highlighted_html(
+    text: &str,
+    styles: &[(Range<usize>, HighlightStyle)],
+    font_family: &str,
+) -> String {
+    let mut html = format!(
+        "<pre style=\"font-family: {};\"><code>",
+        escape_html(font_family)
+    );
+    let mut offset = 0;
+    for (range, style) in styles {
+        let range = range.start.max(offset).min(text.len())..range.end.min(text.len());
+        if range.is_empty()
+            || !text.is_char_boundary(range.start)
+            || !text.is_char_boundary(range.end)
+        {
+            continue;
+        }
+        html.push_str(&escape_html(&text[offset..range.start]));
+
+        let mut css = vec![];
+        if let Some(color) = style.color {
+            css.push(format!("color: {};", color.to_hex()));
+        }
+        if style
+            .font_weight
+            .is_some_and(|weight| weight >= FontWeight::BOLD)
+        {
+            css.push("font-weight: bold;".to_string());
+        }
+        if style.font_style == Some(FontStyle::Italic) {
+            css.push("font-style: italic;".to_string());
+        }
+
+        let content = escape_html(&text[range.clone()]);
+        if css.is_empty() {
+            html.push_str(&content);
+        } else {
+            html.push_str(&format!(
+                "<span style=\"{}\">{}</span>",
+                css.join(" "),
+                content
+            ));
+        }
+        offset = range.end;
+    }
+    html.push_str(&escape_html(&text[offset..]));
+    html.push_str("</code></pre>");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{
+        ClipboardEntry, ClipboardItem, FontWeight, HighlightStyle, Hsla, Image, ImageFormat,
+    };
+
+    use super::{escape_html, highlighted_html, ClipboardContent};
+
+    #[test]
+    fn test_clipboard_content() {
+        let content = ClipboardContent::new("Hello")
+            .html("<b>Hello</b>")
+            .markdown("**Hello**");
+        let item = content.clone().into_item();
+        assert_eq!(item.text(), Some("Hello".to_string()));
+        assert_eq!(ClipboardContent::from_item(item), content);
+        assert_eq!(content.html_text(), Some("<b>Hello</b>"));
+        assert_eq!(content.markdown_text(), Some("**Hello**"));
+
+        // From other applications.
+        let content = ClipboardContent::from_item(ClipboardItem::new_string("Plain".to_string()));
+        assert_eq!(content.text(), "Plain");
+        assert_eq!(content.html_text(), None);
+        assert_eq!(content.markdown_text(), None);
+
+        let image = Image::from_bytes(ImageFormat::Png, vec![1, 2, 3]);
+        let content = ClipboardContent::new("").image(image.clone());
+        let item = content.clone().into_item();
+        assert_eq!(item.entries(), &[ClipboardEntry::Image(image.clone())]);
+        assert_eq!(ClipboardContent::from_item(item).image_data(), Some(&image));
+    }
+
+    #[test]
+    fn test_highlighted_html() {
+        assert_eq!(
+            escape_html("<a href=\"#\">&'</a>"),
+            "&lt;a href=&quot;#&quot;&gt;&amp;&#39;&lt;/a&gt;"
+        );
+
+        let red = Hsla {
+            h: 0.,
+            s: 1.,
+            l: 0.5,
+            a: 1.,
+        };
+        let styles = vec![
+            (
+                0..2,
+                HighlightStyle {
+                    color: Some(red),
+                    font_weight: Some(FontWeight::BOLD),
+                    ..Default::default()
+                },
+            ),
+            (2..3, HighlightStyle::default()),
+        ];
+        assert_eq!(
+            highlighted_html("fn <a>", &styles, "Menlo"),
+            "<pre style=\"font-family: Menlo;\"><code><span style=\"color: #FF0000; font-weight: bold;\">fn</span> &lt;a&gt;</code></pre>"
+        );
+    }
+}
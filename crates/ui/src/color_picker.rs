@@ -1,9 +1,9 @@
 use gpui::{
-    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, relative, App, AppContext,
-    Bounds, ClickEvent, Context, Corner, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
-    Hsla, InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels,
-    Point, Render, RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement,
-    Styled, Subscription, Window,
+    canvas, deferred, div, prelude::FluentBuilder as _, px, relative, App, AppContext, Bounds,
+    ClickEvent, Context, Corner, ElementId, Entity, EventEmitter, FocusHandle, Focusable, Hsla,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Render,
+    RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    Subscription, Window,
 };
 
 use crate::{
@@ -12,6 +12,7 @@ use crate::{
     divider::Divider,
     h_flex,
     input::{InputEvent, InputState, TextInput},
+    position::{Position, Positioned},
     tooltip::Tooltip,
     v_flex, ActiveTheme as _, Colorize as _, FocusableExt as _, Icon, Selectable as _, Sizable,
     Size, StyleSized, StyledExt,
@@ -75,7 +76,7 @@ impl ColorPickerState {
             &state,
             window,
             |this, state, ev: &InputEvent, window, cx| match ev {
-                InputEvent::Change => {
+                InputEvent::Change(_) => {
                     let value = state.read(cx).value();
                     if let Ok(color) = Hsla::parse_hex(value.as_str()) {
                         this.value = Some(color);
@@ -336,15 +337,6 @@ impl ColorPicker {
                 )
             })
     }
-
-    fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
-        bounds.corner(match self.anchor {
-            Corner::TopLeft => Corner::BottomLeft,
-            Corner::TopRight => Corner::BottomRight,
-            Corner::BottomLeft => Corner::TopLeft,
-            Corner::BottomRight => Corner::TopRight,
-        })
-    }
 }
 
 impl Sizable for ColorPicker {
@@ -447,17 +439,11 @@ impl RenderOnce for ColorPicker {
             .when(state.open, |this| {
                 this.child(
                     deferred(
-                        anchored()
-                            .anchor(self.anchor)
-                            .snap_to_window_with_margin(px(8.))
-                            .position(self.resolved_corner(bounds))
+                        Positioned::new("color-picker-position", bounds)
+                            .position(Position::from_corner(self.anchor).gap(px(6.)))
                             .child(
                                 div()
                                     .occlude()
-                                    .map(|this| match self.anchor {
-                                        Corner::TopLeft | Corner::TopRight => this.mt_1p5(),
-                                        Corner::BottomLeft | Corner::BottomRight => this.mb_1p5(),
-                                    })
                                     .w_72()
                                     .overflow_hidden()
                                     .rounded(cx.theme().radius)
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, VecDeque};
+
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, App, Bounds,
+    EntityId, FocusHandle, Global, IntoElement, KeyBinding, MouseMoveEvent, ParentElement as _,
+    Pixels, SharedString, Styled as _, Window, WindowId,
+};
+
+use crate::{h_flex, v_flex, ActiveTheme as _, StyledExt as _};
+
+actions!(debug_overlay, [ToggleDebugOverlay]);
+
+/// The max number of recent events to keep.
+const MAX_EVENTS: usize = 20;
+
+pub(crate) fn init(cx: &mut App) {
+    cx.set_global(DebugOverlay::default());
+    cx.bind_keys(vec![
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-shift-d", ToggleDebugOverlay, None),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-shift-d", ToggleDebugOverlay, None),
+    ]);
+
+    cx.on_action(|_: &ToggleDebugOverlay, cx| {
+        DebugOverlay::toggle(cx);
+    });
+}
+
+/// A component painted in the last frame, recorded by [`DebugOverlay::record`].
+#[derive(Debug, Clone)]
+pub struct DebugElement {
+    /// The component type, e.g.: `TextView`.
+    pub name: SharedString,
+    /// The entity id of the view that the component is painted in.
+    pub entity_id: EntityId,
+    /// The bounds of the component in window coordinates.
+    pub bounds: Bounds<Pixels>,
+    /// Whether the component is focused, `None` if it's not focusable.
+    pub focused: Option<bool>,
+}
+
+#[derive(Default)]
+struct WindowFrames {
+    current: Vec<DebugElement>,
+    last: Vec<DebugElement>,
+}
+
+/// A debug overlay to help debugging the UI, toggle it by [`ToggleDebugOverlay`].
+///
+/// When enabled, it highlights the component bounds under the mouse, and shows the component type,
+/// entity id, focus state and the recent events.
+///
+/// The components in this crate record themselves when painted, your custom elements can do the
+/// same by [`DebugOverlay::record`], and log the events by [`DebugOverlay::log`].
+///
+/// The layout and paint of the major components are also wrapped in `tracing` spans
+/// (at `TRACE` level), so they can be collected by a `tracing` subscriber.
+#[derive(Default)]
+pub struct DebugOverlay {
+    enabled: bool,
+    frames: HashMap<WindowId, WindowFrames>,
+    events: VecDeque<SharedString>,
+}
+
+impl Global for DebugOverlay {}
+
+impl DebugOverlay {
+    /// Returns true if the debug overlay is enabled.
+    pub fn is_enabled(cx: &App) -> bool {
+        cx.try_global::<Self>().map_or(false, |this| this.enabled)
+    }
+
+    /// Toggle the debug overlay for all windows.
+    pub fn toggle(cx: &mut App) {
+        let this = cx.global_mut::<Self>();
+        this.enabled = !this.enabled;
+        if !this.enabled {
+            this.frames.clear();
+            this.events.clear();
+        }
+        cx.refresh_windows();
+    }
+
+    /// Record the component painted in current frame, do nothing if the overlay is not enabled.
+    ///
+    /// This should be called in the `paint` of the element.
+    pub fn record(
+        name: impl Into<SharedString>,
+        bounds: Bounds<Pixels>,
+        focus_handle: Option<&FocusHandle>,
+        window: &Window,
+        cx: &mut App,
+    ) {
+        if !Self::is_enabled(cx) {
+            return;
+        }
+
+        let element = DebugElement {
+            name: name.into(),
+            entity_id: window.current_view(),
+            bounds,
+            focused: focus_handle.map(|handle| handle.is_focused(window)),
+        };
+        let window_id = window.window_handle().window_id();
+        cx.global_mut::<Self>()
+            .frames
+            .entry(window_id)
+            .or_default()
+            .current
+            .push(element);
+    }
+
+    /// Log an event, the event is also sent to `tracing` at `DEBUG` level.
+    pub fn log(event: impl Into<SharedString>, cx: &mut App) {
+        let event: SharedString = event.into();
+        tracing::debug!(target: "gpui_component::debug_overlay", "{}", event);
+
+        if !Self::is_enabled(cx) {
+            return;
+        }
+
+        let this = cx.global_mut::<Self>();
+        if this.events.len() >= MAX_EVENTS {
+            this.events.pop_front();
+        }
+        this.events.push_back(event);
+    }
+
+    /// Render the overlay, this is called by the [`crate::Root`] in every frame.
+    pub(crate) fn render(window: &mut Window, cx: &mut App) -> Option<impl IntoElement> {
+        if !Self::is_enabled(cx) {
+            return None;
+        }
+
+        // The elements of the current frame are recorded after the root rendered,
+        // so we use the last frame to render the overlay.
+        let window_id = window.window_handle().window_id();
+        let this = cx.global_mut::<Self>();
+        let frames = this.frames.entry(window_id).or_default();
+        frames.last = std::mem::take(&mut frames.current);
+
+        let mouse_position = window.mouse_position();
+        let hovered = frames
+            .last
+            .iter()
+            .filter(|el| el.bounds.contains(&mouse_position))
+            .min_by(|a, b| {
+                let a = f32::from(a.bounds.size.width) * f32::from(a.bounds.size.height);
+                let b = f32::from(b.bounds.size.width) * f32::from(b.bounds.size.height);
+                a.total_cmp(&b)
+            })
+            .cloned();
+        let events = this.events.iter().rev().cloned().collect::<Vec<_>>();
+
+        let highlight = cx.theme().blue;
+        Some(
+            deferred(
+                div()
+                    .absolute()
+                    .size_full()
+                    .when_some(hovered.as_ref(), |this, el| {
+                        this.child(
+                            anchored().position(el.bounds.origin).child(
+                                div()
+                                    .w(el.bounds.size.width)
+                                    .h(el.bounds.size.height)
+                                    .border_1()
+                                    .border_color(highlight)
+                                    .bg(highlight.opacity(0.1)),
+                            ),
+                        )
+                    })
+                    .child(
+                        v_flex()
+                            .absolute()
+                            .bottom_2()
+                            .right_2()
+                            .w(px(280.))
+                            .popover_style(cx)
+                            .p_2()
+                            .gap_1()
+                            .text_xs()
+                            .map(|this| match &hovered {
+                                Some(el) => this
+                                    .child(div().font_semibold().child(el.name.clone()))
+                                    .child(debug_row("Entity", format!("{}", el.entity_id)))
+                                    .child(debug_row("Origin", format!("{}", el.bounds.origin)))
+                                    .child(debug_row("Size", format!("{}", el.bounds.size)))
+                                    .child(debug_row(
+                                        "Focused",
+                                        match el.focused {
+                                            Some(true) => "Yes",
+                                            Some(false) => "No",
+                                            None => "-",
+                                        },
+                                    )),
+                                None => this.child(
+                                    div()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("No component under the mouse"),
+                                ),
+                            })
+                            .child(
+                                div()
+                                    .mt_1()
+                                    .pt_1()
+                                    .border_t_1()
+                                    .border_color(cx.theme().border)
+                                    .font_semibold()
+                                    .child("Recent Events"),
+                            )
+                            .children(events.into_iter().map(|event| {
+                                div()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .truncate()
+                                    .child(event)
+                            })),
+                    )
+                    .child(
+                        // Refresh the overlay when the mouse moves.
+                        canvas(
+                            |_, _, _| {},
+                            |_, _, window, _| {
+                                window.on_mouse_event(|_: &MouseMoveEvent, phase, window, _| {
+                                    if phase.bubble() {
+                                        window.refresh();
+                                    }
+                                });
+                            },
+                        )
+                        .size_0(),
+                    ),
+            )
+            .with_priority(100),
+        )
+    }
+}
+
+fn debug_row(label: &'static str, value: impl Into<SharedString>) -> impl IntoElement {
+    h_flex()
+        .gap_2()
+        .justify_between()
+        .child(label)
+        .child(value.into())
+}
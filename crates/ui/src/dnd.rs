@@ -0,0 +1,364 @@
+//! A drag and drop framework with the typed payloads on top of the GPUI drag and drop.
+//!
+//! ```ignore
+//! use gpui_component::dnd::{Draggable, DraggableElement as _, DropZone, DropZoneElement as _};
+//!
+//! div()
+//!     .id(("row", ix))
+//!     .draggable(
+//!         Draggable::new(row.clone())
+//!             .allow_copy(true)
+//!             .preview(|row: &Row, _, _| div().child(row.name.clone())),
+//!     );
+//!
+//! div()
+//!     .id("folder")
+//!     .drop_zone(
+//!         DropZone::new()
+//!             .accept(|row: &Row, _, _| !row.locked)
+//!             .on_drop(cx.listener(|this, (row, effect): &(Row, DropEffect), _, cx| { ... })),
+//!     )
+//!     // Scroll the container when dragging near the edges.
+//!     .child(auto_scroll(&scroll_handle));
+//! ```
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, point, prelude::FluentBuilder as _, px, AnyElement, App, AppContext as _, Bounds,
+    Context, InteractiveElement, IntoElement, Modifiers, ParentElement as _, Pixels, Point, Render,
+    ScrollHandle, StatefulInteractiveElement, Styled as _, Window,
+};
+
+use crate::{h_flex, ActiveTheme as _, Icon, IconName, Sizable as _};
+
+/// The distance to the edges of the scroll container to start the auto scrolling.
+const AUTO_SCROLL_EDGE: Pixels = px(32.);
+/// The max auto scrolling distance per frame.
+const AUTO_SCROLL_SPEED: Pixels = px(12.);
+
+/// The effect of a drop, resolved by the modifiers at the drop time.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DropEffect {
+    #[default]
+    Move,
+    Copy,
+}
+
+impl DropEffect {
+    /// Returns `Copy` if the copy is allowed and the copy modifier is held,
+    /// the copy modifier is `alt` on macOS and `ctrl` on the other platforms.
+    pub fn from_modifiers(allow_copy: bool, modifiers: &Modifiers) -> Self {
+        let copy_modifier = if cfg!(target_os = "macos") {
+            modifiers.alt
+        } else {
+            modifiers.control
+        };
+
+        if allow_copy && copy_modifier {
+            Self::Copy
+        } else {
+            Self::Move
+        }
+    }
+
+    #[inline]
+    pub fn is_copy(&self) -> bool {
+        matches!(self, Self::Copy)
+    }
+}
+
+/// The dragged value of a [`Draggable`], accepted by the [`DropZone`] of the same payload type.
+#[derive(Clone)]
+pub struct DragPayload<T: Clone + 'static> {
+    payload: T,
+    allow_copy: bool,
+}
+
+impl<T: Clone + 'static> DragPayload<T> {
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    /// Returns the drop effect by the current modifiers.
+    pub fn effect(&self, window: &Window) -> DropEffect {
+        DropEffect::from_modifiers(self.allow_copy, &window.modifiers())
+    }
+}
+
+type PreviewBuilder<T> = Rc<dyn Fn(&T, &mut Window, &mut App) -> AnyElement>;
+
+/// The preview view following the mouse during the drag.
+pub struct DragPreview<T: Clone + 'static> {
+    drag: DragPayload<T>,
+    builder: Option<PreviewBuilder<T>>,
+}
+
+impl<T: Clone + 'static> Render for DragPreview<T> {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let effect = self.drag.effect(window);
+
+        div()
+            .relative()
+            .cursor_grabbing()
+            .when_some(self.builder.clone(), |this, builder| {
+                this.child(builder(&self.drag.payload, window, cx))
+            })
+            .when(effect.is_copy(), |this| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .top(px(-6.))
+                        .right(px(-6.))
+                        .size_4()
+                        .justify_center()
+                        .rounded_full()
+                        .bg(cx.theme().primary)
+                        .text_color(cx.theme().primary_foreground)
+                        .child(Icon::new(IconName::Plus).xsmall()),
+                )
+            })
+    }
+}
+
+/// The drag source configuration, see [`DraggableElement::draggable`].
+pub struct Draggable<T: Clone + 'static> {
+    payload: T,
+    allow_copy: bool,
+    preview: Option<PreviewBuilder<T>>,
+}
+
+impl<T: Clone + 'static> Draggable<T> {
+    pub fn new(payload: T) -> Self {
+        Self {
+            payload,
+            allow_copy: false,
+            preview: None,
+        }
+    }
+
+    /// Set whether to allow copying by holding the copy modifier, default is false.
+    pub fn allow_copy(mut self, allow_copy: bool) -> Self {
+        self.allow_copy = allow_copy;
+        self
+    }
+
+    /// Set the preview following the mouse, a copy badge is shown over it when copying.
+    pub fn preview<E: IntoElement>(
+        mut self,
+        preview: impl Fn(&T, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.preview = Some(Rc::new(move |payload, window, cx| {
+            preview(payload, window, cx).into_any_element()
+        }));
+        self
+    }
+}
+
+/// Extension trait to make an element draggable with a typed payload.
+pub trait DraggableElement: StatefulInteractiveElement + Sized {
+    /// Start dragging the payload of the `draggable` from this element.
+    fn draggable<T: Clone + 'static>(self, draggable: Draggable<T>) -> Self {
+        let drag = DragPayload {
+            payload: draggable.payload,
+            allow_copy: draggable.allow_copy,
+        };
+        let builder = draggable.preview;
+
+        self.on_drag(drag, move |drag, _, _, cx| {
+            cx.stop_propagation();
+            cx.new(|_| DragPreview {
+                drag: drag.clone(),
+                builder: builder.clone(),
+            })
+        })
+    }
+}
+
+impl<E: StatefulInteractiveElement> DraggableElement for E {}
+
+type AcceptFn<T> = Rc<dyn Fn(&T, &mut Window, &mut App) -> bool>;
+
+/// The drop target configuration, see [`DropZoneElement::drop_zone`].
+pub struct DropZone<T: Clone + 'static> {
+    accept: Option<AcceptFn<T>>,
+    on_drop: Option<Rc<dyn Fn(&(T, DropEffect), &mut Window, &mut App)>>,
+    highlight: bool,
+}
+
+impl<T: Clone + 'static> Default for DropZone<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + 'static> DropZone<T> {
+    pub fn new() -> Self {
+        Self {
+            accept: None,
+            on_drop: None,
+            highlight: true,
+        }
+    }
+
+    /// Set the predicate to accept the payload, default accepts all payloads of the type.
+    pub fn accept(mut self, accept: impl Fn(&T, &mut Window, &mut App) -> bool + 'static) -> Self {
+        self.accept = Some(Rc::new(accept));
+        self
+    }
+
+    /// Set the listener called with the payload and the drop effect when dropped.
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(&(T, DropEffect), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_drop = Some(Rc::new(on_drop));
+        self
+    }
+
+    /// Set whether to highlight the zone when dragging an accepted payload over it, default is true.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+}
+
+/// Extension trait to make an element a drop target of a typed payload.
+pub trait DropZoneElement: InteractiveElement + Sized {
+    /// Accept the payloads of the [`Draggable`]s with the same type.
+    ///
+    /// Only one drop zone is supported on the same element.
+    fn drop_zone<T: Clone + 'static>(self, zone: DropZone<T>) -> Self {
+        let accept = zone.accept;
+        let on_drop = zone.on_drop;
+
+        let this = self.can_drop(move |value, window, cx| {
+            value.downcast_ref::<DragPayload<T>>().is_some_and(|drag| {
+                accept
+                    .as_ref()
+                    .is_none_or(|accept| accept(&drag.payload, window, cx))
+            })
+        });
+        let this = if zone.highlight {
+            this.drag_over::<DragPayload<T>>(|style, _, _, cx| {
+                style
+                    .bg(cx.theme().drop_target)
+                    .border_color(cx.theme().drag_border)
+            })
+        } else {
+            this
+        };
+
+        this.on_drop(move |drag: &DragPayload<T>, window, cx| {
+            if let Some(on_drop) = on_drop.as_ref() {
+                let effect = drag.effect(window);
+                on_drop(&(drag.payload.clone(), effect), window, cx);
+            }
+        })
+    }
+}
+
+impl<E: InteractiveElement> DropZoneElement for E {}
+
+/// Returns an element to scroll the container of the `scroll_handle`,
+/// when dragging near the edges of the container.
+///
+/// The element can be placed anywhere, it uses the bounds of the `scroll_handle`.
+pub fn auto_scroll(scroll_handle: &ScrollHandle) -> impl IntoElement {
+    let scroll_handle = scroll_handle.clone();
+
+    canvas(
+        move |_, window, cx| {
+            if !cx.has_active_drag() {
+                return;
+            }
+
+            let delta = auto_scroll_delta(window.mouse_position(), scroll_handle.bounds());
+            if delta == Point::default() {
+                return;
+            }
+
+            let max_offset = scroll_handle.max_offset();
+            let offset = scroll_handle.offset() + delta;
+            let offset = point(
+                offset.x.clamp(-max_offset.width, px(0.)),
+                offset.y.clamp(-max_offset.height, px(0.)),
+            );
+            if offset != scroll_handle.offset() {
+                scroll_handle.set_offset(offset);
+            }
+            window.request_animation_frame();
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+}
+
+/// Returns the scroll offset delta when the `position` is near the edges of the `bounds`,
+/// the closer to the edge the faster.
+fn auto_scroll_delta(position: Point<Pixels>, bounds: Bounds<Pixels>) -> Point<Pixels> {
+    if !bounds.contains(&position) {
+        return Point::default();
+    }
+
+    let speed = |distance: Pixels| {
+        if distance < AUTO_SCROLL_EDGE {
+            AUTO_SCROLL_SPEED * (1. - distance / AUTO_SCROLL_EDGE)
+        } else {
+            px(0.)
+        }
+    };
+
+    point(
+        speed(position.x - bounds.left()) - speed(bounds.right() - position.x),
+        speed(position.y - bounds.top()) - speed(bounds.bottom() - position.y),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds, Modifiers};
+
+    use super::{auto_scroll_delta, DropEffect};
+
+    #[test]
+    fn test_drop_effect() {
+        let copy_modifiers = if cfg!(target_os = "macos") {
+            Modifiers::alt()
+        } else {
+            Modifiers::control()
+        };
+        assert_eq!(
+            DropEffect::from_modifiers(true, &copy_modifiers),
+            DropEffect::Copy
+        );
+        assert_eq!(
+            DropEffect::from_modifiers(false, &copy_modifiers),
+            DropEffect::Move
+        );
+        assert_eq!(
+            DropEffect::from_modifiers(true, &Modifiers::none()),
+            DropEffect::Move
+        );
+    }
+
+    #[test]
+    fn test_auto_scroll_delta() {
+        let bounds = Bounds::new(point(px(0.), px(0.)), size(px(200.), px(200.)));
+        assert_eq!(
+            auto_scroll_delta(point(px(100.), px(100.)), bounds),
+            point(px(0.), px(0.))
+        );
+        assert_eq!(
+            auto_scroll_delta(point(px(100.), px(0.)), bounds),
+            point(px(0.), px(12.))
+        );
+        assert_eq!(
+            auto_scroll_delta(point(px(100.), px(184.)), bounds),
+            point(px(0.), px(-6.))
+        );
+        assert_eq!(
+            auto_scroll_delta(point(px(100.), px(300.)), bounds),
+            point(px(0.), px(0.))
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, point, px, size, AppContext as _, Bounds, Context, CursorStyle, DragMoveEvent, Entity,
+    InteractiveElement as _, IntoElement, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement as _, Pixels, Point, Size, Styled as _, Window,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::ActiveTheme as _;
+
+use super::{DockArea, DockEvent, DragPanel, Panel as _, PanelState, PanelView, TabPanel};
+
+/// The default size of the floating panel.
+const DEFAULT_SIZE: Size<Pixels> = size(px(480.), px(320.));
+const MIN_SIZE: Size<Pixels> = size(px(160.), px(100.));
+/// The min visible size of the floating panel, to keep it able to move back.
+const MIN_VISIBLE: Pixels = px(48.);
+const HANDLE_SIZE: Pixels = px(6.);
+
+/// Used to serialize and deserialize the floating panel of the [`DockArea`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FloatingPanelState {
+    pub panel: PanelState,
+    /// The bounds relative to the dock area.
+    pub bounds: Bounds<Pixels>,
+}
+
+/// A [`TabPanel`] floating over the dock area.
+#[derive(Clone)]
+pub(super) struct FloatingPanel {
+    pub(super) tab_panel: Entity<TabPanel>,
+    /// The bounds relative to the dock area.
+    pub(super) bounds: Bounds<Pixels>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FloatingDragKind {
+    Move,
+    Resize,
+}
+
+/// The state of moving or resizing a floating panel.
+#[derive(Clone, Copy)]
+pub(super) struct FloatingDrag {
+    ix: usize,
+    kind: FloatingDragKind,
+    start_position: Point<Pixels>,
+    start_bounds: Bounds<Pixels>,
+}
+
+/// Keep the floating panel larger than the [`MIN_SIZE`], and a part of it inside the area.
+fn clamp_floating_bounds(bounds: Bounds<Pixels>, area_size: Size<Pixels>) -> Bounds<Pixels> {
+    let width = bounds.size.width.max(MIN_SIZE.width);
+    let height = bounds.size.height.max(MIN_SIZE.height);
+    let x = bounds
+        .origin
+        .x
+        .min(area_size.width - MIN_VISIBLE)
+        .max(MIN_VISIBLE - width);
+    let y = bounds
+        .origin
+        .y
+        .min(area_size.height - MIN_VISIBLE)
+        .max(px(0.));
+
+    Bounds {
+        origin: point(x, y),
+        size: size(width, height),
+    }
+}
+
+impl DockArea {
+    /// Move the panel out of the docks to a floating panel over the dock area.
+    ///
+    /// The `bounds` is relative to the dock area, if None, the last floating bounds of the panel
+    /// is used, or centered in the dock area.
+    ///
+    /// The panel will not float if the [`super::Panel::can_float`] is false or the dock area is locked.
+    pub fn float_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        bounds: Option<Bounds<Pixels>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.locked || !panel.can_float(cx) {
+            return;
+        }
+
+        self.remove_panel_from_all_docks(panel.clone(), window, cx);
+
+        let panel_id = panel.panel_id(cx);
+        let area_size = self.bounds.size;
+        let bounds = bounds
+            .or_else(|| self.floating_bounds.get(&panel_id).copied())
+            .unwrap_or_else(|| {
+                let size = DEFAULT_SIZE.min(&area_size);
+                Bounds {
+                    origin: point(
+                        (area_size.width - size.width) / 2.,
+                        (area_size.height - size.height) / 2.,
+                    ),
+                    size,
+                }
+            });
+        let bounds = clamp_floating_bounds(bounds, area_size);
+
+        let dock_area = cx.entity().downgrade();
+        let tab_panel = cx.new(|cx| {
+            let mut tab_panel = TabPanel::new(None, dock_area, window, cx);
+            tab_panel.set_floating(true);
+            tab_panel.add_panel(panel, window, cx);
+            tab_panel
+        });
+        self.add_floating_panel(tab_panel, bounds, window, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    pub(super) fn add_floating_panel(
+        &mut self,
+        tab_panel: Entity<TabPanel>,
+        bounds: Bounds<Pixels>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.subscribe_panel(&tab_panel, window, cx);
+        self.floating_panels
+            .push(FloatingPanel { tab_panel, bounds });
+        self.remember_floating_bounds(self.floating_panels.len() - 1, cx);
+        self.record_layout_later(window, cx);
+    }
+
+    /// Move the panel from the floating panels back to the center of the dock area.
+    pub fn dock_floating_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.remove_floating_panel(panel.clone(), window, cx);
+        self.add_panel(panel, super::DockPlacement::Center, None, window, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    pub(super) fn remove_floating_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for floating in self.floating_panels.iter() {
+            floating.tab_panel.update(cx, |tab_panel, cx| {
+                tab_panel.remove_panel(panel.clone(), window, cx);
+            });
+        }
+        self.prune_floating_panels(cx);
+    }
+
+    /// Returns true if the panel is in a floating panel.
+    pub fn is_floating(&self, panel: &Arc<dyn PanelView>, cx: &gpui::App) -> bool {
+        let panel_view = panel.view();
+        self.floating_panels.iter().any(|floating| {
+            floating
+                .tab_panel
+                .read(cx)
+                .panels
+                .iter()
+                .any(|p| p.view() == panel_view)
+        })
+    }
+
+    /// Remove the floating panels that have no panels, e.g.: the last panel is dragged back to dock.
+    pub(super) fn prune_floating_panels(&mut self, cx: &mut Context<Self>) {
+        let len = self.floating_panels.len();
+        self.floating_panels
+            .retain(|floating| !floating.tab_panel.read(cx).panels.is_empty());
+        if self.floating_panels.len() != len {
+            self.floating_drag = None;
+            cx.notify();
+        }
+    }
+
+    /// Remember the bounds of the panels in the floating panel, to float them at the same place next time.
+    fn remember_floating_bounds(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(floating) = self.floating_panels.get(ix) else {
+            return;
+        };
+
+        for panel in floating.tab_panel.read(cx).panels.iter() {
+            self.floating_bounds
+                .insert(panel.panel_id(cx), floating.bounds);
+        }
+    }
+
+    pub(super) fn dump_floating_panels(&self, cx: &gpui::App) -> Vec<FloatingPanelState> {
+        self.floating_panels
+            .iter()
+            .map(|floating| FloatingPanelState {
+                panel: floating.tab_panel.read(cx).dump(cx),
+                bounds: floating.bounds,
+            })
+            .collect()
+    }
+
+    /// Track the panel dragged out of the dock area, to tear off it on mouse up.
+    pub(super) fn on_panel_drag_move(
+        &mut self,
+        event: &DragMoveEvent<DragPanel>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.locked || event.bounds.contains(&event.event.position) {
+            self.tear_off_panel = None;
+            return;
+        }
+
+        let drag = event.drag(cx);
+        if drag.panel.can_float(cx) {
+            self.tear_off_panel = Some(drag.clone());
+        }
+    }
+
+    /// Float the panel that is dropped out of the dock area.
+    pub(super) fn on_mouse_up_out(
+        &mut self,
+        event: &MouseUpEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drag) = self.tear_off_panel.take() else {
+            return;
+        };
+        if !cx.has_active_drag() {
+            return;
+        }
+
+        let panel = drag.panel.clone();
+        let origin = event.position - self.bounds.origin;
+        let size = self
+            .floating_bounds
+            .get(&panel.panel_id(cx))
+            .map(|bounds| bounds.size)
+            .unwrap_or(DEFAULT_SIZE);
+
+        drag.tab_panel.update(cx, |tab_panel, cx| {
+            tab_panel.remove_panel(panel.clone(), window, cx);
+        });
+        self.prune_floating_panels(cx);
+        self.float_panel(panel, Some(Bounds { origin, size }), window, cx);
+    }
+
+    fn on_floating_mouse_down(
+        &mut self,
+        ix: usize,
+        kind: FloatingDragKind,
+        event: &MouseDownEvent,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(floating) = self.floating_panels.get(ix) else {
+            return;
+        };
+
+        self.floating_drag = Some(FloatingDrag {
+            ix,
+            kind,
+            start_position: event.position,
+            start_bounds: floating.bounds,
+        });
+        cx.stop_propagation();
+        cx.notify();
+    }
+
+    pub(super) fn on_floating_mouse_move(
+        &mut self,
+        event: &MouseMoveEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(drag) = self.floating_drag else {
+            return;
+        };
+        if event.pressed_button != Some(MouseButton::Left) {
+            self.end_floating_drag(cx);
+            return;
+        }
+
+        let area_size = self.bounds.size;
+        let Some(floating) = self.floating_panels.get_mut(drag.ix) else {
+            return;
+        };
+
+        let delta = event.position - drag.start_position;
+        let mut bounds = drag.start_bounds;
+        match drag.kind {
+            FloatingDragKind::Move => bounds.origin = bounds.origin + delta,
+            FloatingDragKind::Resize => {
+                bounds.size = size(bounds.size.width + delta.x, bounds.size.height + delta.y)
+            }
+        }
+        floating.bounds = clamp_floating_bounds(bounds, area_size);
+        cx.notify();
+    }
+
+    pub(super) fn end_floating_drag(&mut self, cx: &mut Context<Self>) {
+        let Some(drag) = self.floating_drag.take() else {
+            return;
+        };
+
+        self.remember_floating_bounds(drag.ix, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    pub(super) fn render_floating_panel(
+        &self,
+        ix: usize,
+        floating: &FloatingPanel,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let bounds = floating.bounds;
+
+        div()
+            .id(("floating-panel", ix))
+            .occlude()
+            .absolute()
+            .left(bounds.origin.x)
+            .top(bounds.origin.y)
+            .w(bounds.size.width)
+            .h(bounds.size.height)
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .shadow_lg()
+            .bg(cx.theme().background)
+            .child(
+                // Move handle
+                div()
+                    .id("move-handle")
+                    .flex_shrink_0()
+                    .w_full()
+                    .h(HANDLE_SIZE)
+                    .cursor(CursorStyle::OpenHand)
+                    .bg(cx.theme().tab_bar)
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, event, _, cx| {
+                            this.on_floating_mouse_down(ix, FloatingDragKind::Move, event, cx)
+                        }),
+                    ),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(floating.tab_panel.clone()),
+            )
+            .child(
+                // Resize handle
+                div()
+                    .id("resize-handle")
+                    .absolute()
+                    .right_0()
+                    .bottom_0()
+                    .size(HANDLE_SIZE * 2.)
+                    .cursor(CursorStyle::ResizeUpLeftDownRight)
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, event, _, cx| {
+                            this.on_floating_mouse_down(ix, FloatingDragKind::Resize, event, cx)
+                        }),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::clamp_floating_bounds;
+
+    #[test]
+    fn test_clamp_floating_bounds() {
+        let area = size(px(800.), px(600.));
+        let bounds = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(300.), px(200.)),
+        };
+        assert_eq!(clamp_floating_bounds(bounds, area), bounds);
+
+        // Too small
+        let bounds = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(10.), px(10.)),
+        };
+        assert_eq!(
+            clamp_floating_bounds(bounds, area).size,
+            size(px(160.), px(100.))
+        );
+
+        // Out of the area, keep a part of it visible.
+        let bounds = Bounds {
+            origin: point(px(-1000.), px(-20.)),
+            size: size(px(300.), px(200.)),
+        };
+        assert_eq!(
+            clamp_floating_bounds(bounds, area).origin,
+            point(px(-252.), px(0.))
+        );
+        let bounds = Bounds {
+            origin: point(px(1000.), px(1000.)),
+            size: size(px(300.), px(200.)),
+        };
+        assert_eq!(
+            clamp_floating_bounds(bounds, area).origin,
+            point(px(752.), px(552.))
+        );
+    }
+}
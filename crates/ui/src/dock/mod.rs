@@ -1,32 +1,86 @@
 mod dock;
+mod floating;
 mod invalid_panel;
 mod panel;
 mod stack_panel;
 mod state;
+mod switcher;
 mod tab_panel;
 mod tiles;
+mod undo;
 
 use anyhow::Result;
 use gpui::{
     actions, canvas, div, prelude::FluentBuilder, AnyElement, AnyView, App, AppContext, Axis,
-    Bounds, Context, Edges, Entity, EntityId, EventEmitter, InteractiveElement as _, IntoElement,
-    ParentElement as _, Pixels, Render, SharedString, Styled, Subscription, WeakEntity, Window,
+    Bounds, Context, Edges, Entity, EntityId, EventEmitter, FocusHandle, InteractiveElement as _,
+    IntoElement, KeyBinding, MouseButton, ParentElement as _, Pixels, Render, SharedString, Styled,
+    Subscription, Task, WeakEntity, Window,
+};
+use rust_i18n::t;
+use std::{collections::HashMap, sync::Arc};
+use switcher::{collect_panels, PanelSwitcher};
+
+use crate::{
+    actions::{Cancel, Confirm, SelectNext, SelectPrev},
+    button::Button,
+    history::History,
+    IconName, Sizable as _,
 };
-use std::sync::Arc;
 
 pub use dock::*;
+pub use floating::FloatingPanelState;
 pub use panel::*;
 pub use stack_panel::*;
 pub use state::*;
 pub use tab_panel::*;
 pub use tiles::*;
 
+const CONTEXT: &str = "DockArea";
+const SWITCHER_CONTEXT: &str = "PanelSwitcher";
+
 pub(crate) fn init(cx: &mut App) {
     PanelRegistry::init(cx);
+    cx.bind_keys([
+        KeyBinding::new("ctrl-tab", SwitchNextPanel, Some(CONTEXT)),
+        KeyBinding::new("ctrl-shift-tab", SwitchPrevPanel, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-z", UndoLayout, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-y", RedoLayout, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-z", UndoLayout, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-y", RedoLayout, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-k z", ToggleZenMode, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-k z", ToggleZenMode, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(SWITCHER_CONTEXT)),
+        KeyBinding::new(
+            "enter",
+            Confirm { secondary: false },
+            Some(SWITCHER_CONTEXT),
+        ),
+        KeyBinding::new("up", SelectPrev, Some(SWITCHER_CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(SWITCHER_CONTEXT)),
+    ]);
 }
 
-actions!(dock, [ToggleZoom, ClosePanel]);
-
+actions!(
+    dock,
+    [
+        ToggleZoom,
+        ClosePanel,
+        SwitchNextPanel,
+        SwitchPrevPanel,
+        UndoLayout,
+        RedoLayout,
+        ToggleFloating,
+        ToggleZenMode
+    ]
+);
+
+#[non_exhaustive]
 pub enum DockEvent {
     /// The layout of the dock has changed, subscribers this to save the layout.
     ///
@@ -36,6 +90,24 @@ pub enum DockEvent {
 
     /// The drag item drop event.
     DragDrop(AnyDrag),
+
+    /// A panel is maximized or restored, or the zen mode is toggled.
+    ///
+    /// The panels may be hidden or shown again, subscribe this to pause the expensive
+    /// rendering of the hidden panels, see [`DockArea::is_panel_hidden`].
+    VisibilityChanged,
+
+    /// A panel is opened or moved by [`DockArea::open_panel`] or [`DockArea::move_panel`].
+    PanelOpened {
+        panel_id: EntityId,
+        placement: DockPlacement,
+    },
+
+    /// A panel is activated by [`DockArea::activate_panel`] or the panel switcher.
+    PanelActivated(EntityId),
+
+    /// A panel is closed by [`DockArea::close_panel`].
+    PanelClosed(EntityId),
 }
 
 /// The main area of the dock.
@@ -61,14 +133,38 @@ pub struct DockArea {
     right_dock: Option<Entity<Dock>>,
     /// The top zoom view of the dock_area, if any.
     zoom_view: Option<AnyView>,
+    /// Hide the left, right, bottom docks and the floating panels, only keep the center.
+    zen_mode: bool,
 
     /// Lock panels layout, but allow to resize.
     locked: bool,
 
+    /// The panels floating over the dock area, the later is on top.
+    floating_panels: Vec<floating::FloatingPanel>,
+    /// The last floating bounds of the panels, to float them at the same place next time.
+    floating_bounds: HashMap<EntityId, Bounds<Pixels>>,
+    /// Moving or resizing a floating panel.
+    floating_drag: Option<floating::FloatingDrag>,
+    /// The panel is dragged out of the dock area, it will be floated on mouse up.
+    tear_off_panel: Option<DragPanel>,
+
     /// The panel style, default is [`PanelStyle::Default`](PanelStyle::Default).
     pub(crate) panel_style: PanelStyle,
 
+    /// The entity_id of the recently focused panels, most recently used first.
+    recent_panels: Vec<EntityId>,
+    panel_switcher: Option<PanelSwitcher>,
+    switcher_focus_handle: FocusHandle,
+
+    /// The layout changes for undo/redo.
+    layout_history: History<undo::LayoutChange>,
+    /// The last recorded layout, this is the old state of the next change.
+    last_layout: Option<DockAreaState>,
+    /// Whether to skip recording the next layout change, e.g.: the layout is loaded or restored.
+    skip_layout_record: bool,
+
     _subscriptions: Vec<Subscription>,
+    _record_layout_task: Task<()>,
 }
 
 /// DockItem is a tree structure that represents the layout of the dock.
@@ -457,14 +553,26 @@ impl DockArea {
             bounds: Bounds::default(),
             items: dock_item,
             zoom_view: None,
+            zen_mode: false,
             toggle_button_panels: Edges::default(),
             toggle_button_visible: true,
             left_dock: None,
             right_dock: None,
             bottom_dock: None,
             locked: false,
+            floating_panels: vec![],
+            floating_bounds: HashMap::new(),
+            floating_drag: None,
+            tear_off_panel: None,
             panel_style: PanelStyle::Default,
+            recent_panels: vec![],
+            panel_switcher: None,
+            switcher_focus_handle: cx.focus_handle(),
+            layout_history: History::new().max_undo(100),
+            last_layout: None,
+            skip_layout_record: false,
             _subscriptions: vec![],
+            _record_layout_task: Task::ready(()),
         };
 
         this.subscribe_panel(&stack_panel, window, cx);
@@ -521,6 +629,7 @@ impl DockArea {
         self.subscribe_item(&item, window, cx);
         self.items = item;
         self.update_toggle_button_tab_panels(window, cx);
+        self.reset_layout_record(window, cx);
         cx.notify();
     }
 
@@ -544,6 +653,7 @@ impl DockArea {
             dock
         }));
         self.update_toggle_button_tab_panels(window, cx);
+        self.reset_layout_record(window, cx);
     }
 
     pub fn set_bottom_dock(
@@ -566,6 +676,7 @@ impl DockArea {
             dock
         }));
         self.update_toggle_button_tab_panels(window, cx);
+        self.reset_layout_record(window, cx);
     }
 
     pub fn set_right_dock(
@@ -588,6 +699,7 @@ impl DockArea {
             dock
         }));
         self.update_toggle_button_tab_panels(window, cx);
+        self.reset_layout_record(window, cx);
     }
 
     /// Set locked state of the dock area, if locked, the dock area cannot be split or move, but allows to resize panels.
@@ -814,6 +926,143 @@ impl DockArea {
         self.remove_panel(panel.clone(), DockPlacement::Left, window, cx);
         self.remove_panel(panel.clone(), DockPlacement::Right, window, cx);
         self.remove_panel(panel.clone(), DockPlacement::Bottom, window, cx);
+        self.remove_floating_panel(panel, window, cx);
+    }
+
+    /// Returns the panel by the panel id, include the panels in the docks and floating panels.
+    pub fn find_panel(&self, panel_id: EntityId, cx: &App) -> Option<Arc<dyn PanelView>> {
+        self.find_panel_item(panel_id, cx).map(|item| item.panel)
+    }
+
+    /// Returns the current placement of the panel, `None` if the panel is not in the dock area.
+    ///
+    /// The floating panels are returned as [`DockPlacement::Center`], use [`DockArea::is_floating`] to check it.
+    pub fn panel_placement(&self, panel_id: EntityId, cx: &App) -> Option<DockPlacement> {
+        self.find_panel_item(panel_id, cx)
+            .map(|item| item.placement)
+    }
+
+    fn find_panel_item(&self, panel_id: EntityId, cx: &App) -> Option<switcher::SwitcherItem> {
+        self.all_panels(cx)
+            .into_iter()
+            .find(|item| item.panel.panel_id(cx) == panel_id)
+    }
+
+    /// Open a panel at the given placement and activate it.
+    ///
+    /// If the panel is already in the dock area, it will be moved to the placement.
+    /// The `size` is used to resize the left, bottom or right dock, it is ignored for the center.
+    pub fn open_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        placement: DockPlacement,
+        size: Option<Pixels>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panel_id = panel.panel_id(cx);
+        if self.find_panel_item(panel_id, cx).is_some() {
+            self.remove_panel_from_all_docks(panel.clone(), window, cx);
+        }
+
+        self.add_panel(panel, placement, None, window, cx);
+        let dock = match placement {
+            DockPlacement::Left => self.left_dock.as_ref(),
+            DockPlacement::Bottom => self.bottom_dock.as_ref(),
+            DockPlacement::Right => self.right_dock.as_ref(),
+            DockPlacement::Center => None,
+        };
+        if let Some(dock) = dock {
+            dock.update(cx, |dock, cx| {
+                if let Some(size) = size {
+                    dock.set_size(size, window, cx);
+                }
+                dock.set_open(true, window, cx);
+            });
+        }
+
+        cx.emit(DockEvent::PanelOpened {
+            panel_id,
+            placement,
+        });
+        self.activate_panel(panel_id, window, cx);
+    }
+
+    /// Move the panel to the given placement, returns false if the panel is not found.
+    ///
+    /// See also [`DockArea::open_panel`].
+    pub fn move_panel(
+        &mut self,
+        panel_id: EntityId,
+        placement: DockPlacement,
+        size: Option<Pixels>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(panel) = self.find_panel(panel_id, cx) else {
+            return false;
+        };
+
+        self.open_panel(panel, placement, size, window, cx);
+        true
+    }
+
+    /// Activate the panel by the panel id, returns false if the panel is not found.
+    ///
+    /// The dock of the panel will be opened, and the panel will be selected in its tabs and focused.
+    pub fn activate_panel(
+        &mut self,
+        panel_id: EntityId,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(item) = self.find_panel_item(panel_id, cx) else {
+            return false;
+        };
+
+        self.activate_item(item, window, cx);
+        true
+    }
+
+    fn activate_item(
+        &mut self,
+        item: switcher::SwitcherItem,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if item.placement != DockPlacement::Center && !self.is_dock_open(item.placement, cx) {
+            self.toggle_dock(item.placement, window, cx);
+        }
+
+        let panel_id = item.panel.panel_id(cx);
+        item.tab_panel.update(cx, |tab_panel, cx| {
+            if let Some(ix) = tab_panel
+                .panels
+                .iter()
+                .position(|panel| panel.panel_id(cx) == panel_id)
+            {
+                tab_panel.set_active_ix(ix, window, cx);
+            }
+        });
+        item.panel.focus_handle(cx).focus(window);
+        cx.emit(DockEvent::PanelActivated(panel_id));
+        cx.notify();
+    }
+
+    /// Close the panel by the panel id, returns false if the panel is not found.
+    pub fn close_panel(
+        &mut self,
+        panel_id: EntityId,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(panel) = self.find_panel(panel_id, cx) else {
+            return false;
+        };
+
+        self.remove_panel_from_all_docks(panel, window, cx);
+        cx.emit(DockEvent::PanelClosed(panel_id));
+        true
     }
 
     /// Load the state of the DockArea from the DockAreaState.
@@ -825,24 +1074,53 @@ impl DockArea {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Result<()> {
+        self.load_with_panels(state, &mut vec![], window, cx);
+        self.reset_layout_record(window, cx);
+        Ok(())
+    }
+
+    /// Load the state and reuse the `panels` that match the panel state.
+    fn load_with_panels(
+        &mut self,
+        state: DockAreaState,
+        panels: &mut ReusablePanels,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
         self.version = state.version;
         let weak_self = cx.entity().downgrade();
 
         if let Some(left_dock_state) = state.left_dock {
-            self.left_dock = Some(left_dock_state.to_dock(weak_self.clone(), window, cx));
+            self.left_dock =
+                Some(left_dock_state.to_dock_with_panels(weak_self.clone(), panels, window, cx));
         }
 
         if let Some(right_dock_state) = state.right_dock {
-            self.right_dock = Some(right_dock_state.to_dock(weak_self.clone(), window, cx));
+            self.right_dock =
+                Some(right_dock_state.to_dock_with_panels(weak_self.clone(), panels, window, cx));
         }
 
         if let Some(bottom_dock_state) = state.bottom_dock {
-            self.bottom_dock = Some(bottom_dock_state.to_dock(weak_self.clone(), window, cx));
+            self.bottom_dock =
+                Some(bottom_dock_state.to_dock_with_panels(weak_self.clone(), panels, window, cx));
         }
 
-        self.items = state.center.to_item(weak_self, window, cx);
+        self.items = state
+            .center
+            .to_item_with_panels(weak_self.clone(), panels, window, cx);
+
+        self.floating_panels.clear();
+        self.floating_drag = None;
+        for floating in state.floating {
+            let item = floating
+                .panel
+                .to_item_with_panels(weak_self.clone(), panels, window, cx);
+            if let DockItem::Tabs { view, .. } = item {
+                view.update(cx, |view, _| view.set_floating(true));
+                self.add_floating_panel(view, floating.bounds, window, cx);
+            }
+        }
         self.update_toggle_button_tab_panels(window, cx);
-        Ok(())
     }
 
     /// Dump the dock panels layout to PanelState.
@@ -871,6 +1149,7 @@ impl DockArea {
             left_dock,
             right_dock,
             bottom_dock,
+            floating: self.dump_floating_panels(cx),
         }
     }
 
@@ -886,7 +1165,7 @@ impl DockArea {
                 self._subscriptions.push(cx.subscribe_in(
                     view,
                     window,
-                    move |_, _, event, window, cx| match event {
+                    move |this, _, event, window, cx| match event {
                         PanelEvent::LayoutChanged => {
                             cx.spawn_in(window, async move |view, window| {
                                 _ = view.update_in(window, |view, window, cx| {
@@ -894,6 +1173,7 @@ impl DockArea {
                                 });
                             })
                             .detach();
+                            this.record_layout_later(window, cx);
                             cx.emit(DockEvent::LayoutChanged);
                         }
                         _ => {}
@@ -923,7 +1203,7 @@ impl DockArea {
             cx.subscribe_in(
                 view,
                 window,
-                move |_, panel, event, window, cx| match event {
+                move |this, panel, event, window, cx| match event {
                     PanelEvent::ZoomIn => {
                         let panel = panel.clone();
                         cx.spawn_in(window, async move |view, window| {
@@ -948,6 +1228,7 @@ impl DockArea {
                             });
                         })
                         .detach();
+                        this.record_layout_later(window, cx);
                         cx.emit(DockEvent::LayoutChanged);
                     }
                 },
@@ -968,11 +1249,182 @@ impl DockArea {
         cx: &mut Context<Self>,
     ) {
         self.zoom_view = Some(panel.into());
+        cx.emit(DockEvent::VisibilityChanged);
         cx.notify();
     }
 
     pub fn set_zoomed_out(&mut self, _: &mut Window, cx: &mut Context<Self>) {
-        self.zoom_view = None;
+        if self.zoom_view.take().is_some() {
+            cx.emit(DockEvent::VisibilityChanged);
+        }
+        cx.notify();
+    }
+
+    /// Returns true if the zen mode is enabled.
+    pub fn is_zen_mode(&self) -> bool {
+        self.zen_mode
+    }
+
+    /// Set the zen mode, the left, right, bottom docks and the floating panels are hidden in zen mode.
+    pub fn set_zen_mode(&mut self, zen_mode: bool, _: &mut Window, cx: &mut Context<Self>) {
+        if self.zen_mode == zen_mode {
+            return;
+        }
+
+        self.zen_mode = zen_mode;
+        cx.emit(DockEvent::VisibilityChanged);
+        cx.notify();
+    }
+
+    fn on_action_toggle_zen_mode(
+        &mut self,
+        _: &ToggleZenMode,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_zen_mode(!self.zen_mode, window, cx);
+    }
+
+    /// Returns true if the panel is hidden by the maximized panel or the zen mode.
+    ///
+    /// The collapsed docks and the inactive tabs are not counted.
+    pub fn is_panel_hidden(&self, panel: &Arc<dyn PanelView>, cx: &App) -> bool {
+        let panel_id = panel.panel_id(cx);
+
+        if let Some(zoom_view) = self.zoom_view.as_ref() {
+            if zoom_view.entity_id() == panel_id {
+                return false;
+            }
+
+            return match zoom_view.clone().downcast::<TabPanel>() {
+                Ok(tab_panel) => tab_panel
+                    .read(cx)
+                    .active_panel(cx)
+                    .map_or(true, |active| active.panel_id(cx) != panel_id),
+                Err(_) => true,
+            };
+        }
+
+        if self.zen_mode {
+            return self.is_floating(panel, cx)
+                || self.all_panels(cx).iter().any(|item| {
+                    item.placement != DockPlacement::Center && item.panel.panel_id(cx) == panel_id
+                });
+        }
+
+        false
+    }
+
+    /// Move the panel to the front of the recent panels, this is called when the panel is focused.
+    pub(crate) fn push_recent_panel(&mut self, panel_id: EntityId) {
+        self.recent_panels.retain(|id| *id != panel_id);
+        self.recent_panels.insert(0, panel_id);
+    }
+
+    /// Returns all panels in the dock area, include the left, bottom and right docks.
+    fn all_panels(&self, cx: &App) -> Vec<switcher::SwitcherItem> {
+        let mut items = vec![];
+        collect_panels(&self.items.view(), DockPlacement::Center, cx, &mut items);
+        for (dock, placement) in [
+            (&self.left_dock, DockPlacement::Left),
+            (&self.bottom_dock, DockPlacement::Bottom),
+            (&self.right_dock, DockPlacement::Right),
+        ] {
+            if let Some(dock) = dock {
+                collect_panels(&dock.read(cx).panel.view(), placement, cx, &mut items);
+            }
+        }
+        for floating in self.floating_panels.iter() {
+            let panel: Arc<dyn PanelView> = Arc::new(floating.tab_panel.clone());
+            collect_panels(&panel, DockPlacement::Center, cx, &mut items);
+        }
+        items
+    }
+
+    /// Open the panel switcher to switch between the recently used panels,
+    /// the panels are listed in most recently used order.
+    ///
+    /// The selected panel will be activated when the modifiers are released.
+    pub fn toggle_panel_switcher(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.panel_switcher.is_some() {
+            self.close_panel_switcher(window, cx);
+            return;
+        }
+
+        let items = self
+            .all_panels(cx)
+            .into_iter()
+            .filter(|item| item.panel.visible(cx))
+            .collect::<Vec<_>>();
+        if items.len() < 2 {
+            return;
+        }
+
+        // Remove the closed panels.
+        self.recent_panels
+            .retain(|id| items.iter().any(|item| item.panel.panel_id(cx) == *id));
+        self.panel_switcher = Some(PanelSwitcher::new(items, &self.recent_panels, cx));
+        self.switcher_focus_handle.focus(window);
+        cx.notify();
+    }
+
+    fn close_panel_switcher(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(switcher) = self.panel_switcher.take() else {
+            return;
+        };
+
+        // Restore the focus to the current panel.
+        if let Some(item) = switcher.items.first() {
+            item.panel.focus_handle(cx).focus(window);
+        }
+        cx.notify();
+    }
+
+    fn confirm_panel_switcher(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(item) = self
+            .panel_switcher
+            .take()
+            .and_then(|switcher| switcher.selected_item().cloned())
+        else {
+            return;
+        };
+
+        self.activate_item(item, window, cx);
+    }
+
+    fn on_action_switch_next_panel(
+        &mut self,
+        _: &SwitchNextPanel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match self.panel_switcher.as_mut() {
+            Some(switcher) => switcher.select_next(),
+            None => {
+                self.toggle_panel_switcher(window, cx);
+                if let Some(switcher) = self.panel_switcher.as_mut() {
+                    switcher.select_next();
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_action_switch_prev_panel(
+        &mut self,
+        _: &SwitchPrevPanel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match self.panel_switcher.as_mut() {
+            Some(switcher) => switcher.select_prev(),
+            None => {
+                self.toggle_panel_switcher(window, cx);
+                if let Some(switcher) = self.panel_switcher.as_mut() {
+                    switcher.select_prev();
+                }
+            }
+        }
         cx.notify();
     }
 
@@ -1010,9 +1462,29 @@ impl EventEmitter<DockEvent> for DockArea {}
 impl Render for DockArea {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let view = cx.entity().clone();
+        self.prune_floating_panels(cx);
+        let zen_mode = self.zen_mode;
+        let left_dock = self.left_dock.clone().filter(|_| !zen_mode);
+        let bottom_dock = self.bottom_dock.clone().filter(|_| !zen_mode);
+        let right_dock = self.right_dock.clone().filter(|_| !zen_mode);
 
         div()
             .id("dock-area")
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::on_action_switch_next_panel))
+            .on_action(cx.listener(Self::on_action_switch_prev_panel))
+            .on_action(cx.listener(Self::on_action_undo_layout))
+            .on_action(cx.listener(Self::on_action_redo_layout))
+            .on_action(cx.listener(Self::on_action_toggle_zen_mode))
+            .on_drag_move(cx.listener(Self::on_panel_drag_move))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up_out))
+            .when(self.floating_drag.is_some(), |this| {
+                this.on_mouse_move(cx.listener(Self::on_floating_mouse_move))
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        cx.listener(|this, _, _, cx| this.end_floating_drag(cx)),
+                    )
+            })
             .relative()
             .size_full()
             .overflow_hidden()
@@ -1041,7 +1513,7 @@ impl Render for DockArea {
                                     .flex_row()
                                     .h_full()
                                     // Left dock
-                                    .when_some(self.left_dock.clone(), |this, dock| {
+                                    .when_some(left_dock, |this, dock| {
                                         this.child(div().flex().flex_none().child(dock))
                                     })
                                     // Center
@@ -1059,12 +1531,10 @@ impl Render for DockArea {
                                                     .child(self.render_items(window, cx)),
                                             )
                                             // Bottom Dock
-                                            .when_some(self.bottom_dock.clone(), |this, dock| {
-                                                this.child(dock)
-                                            }),
+                                            .when_some(bottom_dock, |this, dock| this.child(dock)),
                                     )
                                     // Right Dock
-                                    .when_some(self.right_dock.clone(), |this, dock| {
+                                    .when_some(right_dock, |this, dock| {
                                         this.child(div().flex().flex_none().child(dock))
                                     }),
                             )
@@ -1072,5 +1542,31 @@ impl Render for DockArea {
                     }
                 }
             })
+            .when(self.zoom_view.is_none() && !zen_mode, |this| {
+                this.children(
+                    self.floating_panels
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, floating)| self.render_floating_panel(ix, floating, cx)),
+                )
+            })
+            .when(zen_mode, |this| {
+                this.child(
+                    div().absolute().bottom_2().right_2().child(
+                        Button::new("exit-zen-mode")
+                            .icon(IconName::Minimize)
+                            .label(t!("Dock.Exit Zen Mode"))
+                            .xsmall()
+                            .outline()
+                            .tooltip_with_action(t!("Dock.Exit Zen Mode"), &ToggleZenMode, None)
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.set_zen_mode(false, window, cx);
+                            })),
+                    ),
+                )
+            })
+            .when_some(self.panel_switcher.as_ref(), |this, switcher| {
+                this.child(self.render_panel_switcher(switcher, window, cx))
+            })
     }
 }
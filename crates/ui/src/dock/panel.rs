@@ -97,6 +97,13 @@ pub trait Panel: EventEmitter<PanelEvent> + Render + Focusable {
         Some(PanelControl::Menu)
     }
 
+    /// Whether the panel can be floated out of the dock area, default is `true`.
+    ///
+    /// This method called in Panel render, we should make sure it is fast.
+    fn can_float(&self, cx: &App) -> bool {
+        true
+    }
+
     /// Return false to hide panel, true to show panel, default is `true`.
     ///
     /// This method called in Panel render, we should make sure it is fast.
@@ -150,6 +157,7 @@ pub trait PanelView: 'static + Send + Sync {
     fn title_style(&self, cx: &App) -> Option<TitleStyle>;
     fn closable(&self, cx: &App) -> bool;
     fn zoomable(&self, cx: &App) -> Option<PanelControl>;
+    fn can_float(&self, cx: &App) -> bool;
     fn visible(&self, cx: &App) -> bool;
     fn set_active(&self, active: bool, window: &mut Window, cx: &mut App);
     fn set_zoomed(&self, zoomed: bool, window: &mut Window, cx: &mut App);
@@ -194,6 +202,10 @@ impl<T: Panel> PanelView for Entity<T> {
         self.read(cx).zoomable(cx)
     }
 
+    fn can_float(&self, cx: &App) -> bool {
+        self.read(cx).can_float(cx)
+    }
+
     fn visible(&self, cx: &App) -> bool {
         self.read(cx).visible(cx)
     }
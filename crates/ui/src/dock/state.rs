@@ -1,8 +1,16 @@
+use std::sync::Arc;
+
 use gpui::{point, px, size, App, AppContext, Axis, Bounds, Entity, Pixels, WeakEntity, Window};
 use itertools::Itertools as _;
 use serde::{Deserialize, Serialize};
 
-use super::{Dock, DockArea, DockItem, DockPlacement, Panel, PanelRegistry};
+use super::{
+    Dock, DockArea, DockItem, DockPlacement, FloatingPanelState, Panel, PanelRegistry, PanelView,
+};
+
+/// The opened panels with their dumped state, to reuse the panel views when restore the layout,
+/// the panels not in the list are built by the [`PanelRegistry`].
+pub(super) type ReusablePanels = Vec<(PanelState, Arc<dyn PanelView>)>;
 
 /// Used to serialize and deserialize the DockArea
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
@@ -19,6 +27,9 @@ pub struct DockAreaState {
     pub right_dock: Option<DockState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bottom_dock: Option<DockState>,
+    /// The panels floating over the dock area.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub floating: Vec<FloatingPanelState>,
 }
 
 /// Used to serialize and deserialize the Dock
@@ -49,7 +60,19 @@ impl DockState {
         window: &mut Window,
         cx: &mut App,
     ) -> Entity<Dock> {
-        let item = self.panel.to_item(dock_area.clone(), window, cx);
+        self.to_dock_with_panels(dock_area, &mut vec![], window, cx)
+    }
+
+    pub(super) fn to_dock_with_panels(
+        &self,
+        dock_area: WeakEntity<DockArea>,
+        panels: &mut ReusablePanels,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Entity<Dock> {
+        let item = self
+            .panel
+            .to_item_with_panels(dock_area.clone(), panels, window, cx);
         cx.new(|cx| {
             Dock::from_state(
                 dock_area.clone(),
@@ -184,13 +207,23 @@ impl PanelState {
         dock_area: WeakEntity<DockArea>,
         window: &mut Window,
         cx: &mut App,
+    ) -> DockItem {
+        self.to_item_with_panels(dock_area, &mut vec![], window, cx)
+    }
+
+    pub(super) fn to_item_with_panels(
+        &self,
+        dock_area: WeakEntity<DockArea>,
+        panels: &mut ReusablePanels,
+        window: &mut Window,
+        cx: &mut App,
     ) -> DockItem {
         let info = self.info.clone();
 
         let items: Vec<DockItem> = self
             .children
             .iter()
-            .map(|child| child.to_item(dock_area.clone(), window, cx))
+            .map(|child| child.to_item_with_panels(dock_area.clone(), panels, window, cx))
             .collect();
 
         match info {
@@ -222,15 +255,19 @@ impl PanelState {
                 DockItem::tabs(items, Some(active_index), &dock_area, window, cx)
             }
             PanelInfo::Panel(_) => {
-                let view = PanelRegistry::build_panel(
-                    &self.panel_name,
-                    dock_area.clone(),
-                    self,
-                    &info,
-                    window,
-                    cx,
-                );
-                DockItem::tabs(vec![view.into()], None, &dock_area, window, cx)
+                let view = match panels.iter().position(|(state, _)| state == self) {
+                    Some(ix) => panels.remove(ix).1,
+                    None => PanelRegistry::build_panel(
+                        &self.panel_name,
+                        dock_area.clone(),
+                        self,
+                        &info,
+                        window,
+                        cx,
+                    )
+                    .into(),
+                };
+                DockItem::tabs(vec![view], None, &dock_area, window, cx)
             }
             PanelInfo::Tiles { metas } => DockItem::tiles(items, metas, &dock_area, window, cx),
         }
@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, App, Context, Entity, EntityId, InteractiveElement as _,
+    IntoElement, ModifiersChangedEvent, ParentElement as _, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::{Cancel, Confirm, SelectNext, SelectPrev},
+    h_flex, v_flex, ActiveTheme as _,
+};
+
+use super::{DockArea, DockPlacement, PanelView, StackPanel, TabPanel, Tiles, SWITCHER_CONTEXT};
+
+/// A panel listed in the [`PanelSwitcher`].
+#[derive(Clone)]
+pub(super) struct SwitcherItem {
+    pub(super) panel: Arc<dyn PanelView>,
+    pub(super) tab_panel: Entity<TabPanel>,
+    pub(super) placement: DockPlacement,
+}
+
+/// The overlay to switch between the recently used panels, opened by [`super::SwitchNextPanel`].
+pub(super) struct PanelSwitcher {
+    pub(super) items: Vec<SwitcherItem>,
+    pub(super) selected_ix: usize,
+}
+
+impl PanelSwitcher {
+    /// Create the switcher with the panels ordered by the `recent_panels` (MRU first).
+    pub(super) fn new(mut items: Vec<SwitcherItem>, recent_panels: &[EntityId], cx: &App) -> Self {
+        items.sort_by_key(|item| {
+            let panel_id = item.panel.panel_id(cx);
+            recent_panels
+                .iter()
+                .position(|id| *id == panel_id)
+                .unwrap_or(usize::MAX)
+        });
+
+        Self {
+            items,
+            selected_ix: 0,
+        }
+    }
+
+    pub(super) fn select_next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected_ix = (self.selected_ix + 1) % self.items.len();
+        }
+    }
+
+    pub(super) fn select_prev(&mut self) {
+        if !self.items.is_empty() {
+            self.selected_ix = (self.selected_ix + self.items.len() - 1) % self.items.len();
+        }
+    }
+
+    pub(super) fn selected_item(&self) -> Option<&SwitcherItem> {
+        self.items.get(self.selected_ix)
+    }
+}
+
+/// Collect the panels in the `panel` recursively.
+pub(super) fn collect_panels(
+    panel: &Arc<dyn PanelView>,
+    placement: DockPlacement,
+    cx: &App,
+    items: &mut Vec<SwitcherItem>,
+) {
+    let view = panel.view();
+    if let Ok(tab_panel) = view.clone().downcast::<TabPanel>() {
+        for panel in tab_panel.read(cx).panels.iter() {
+            items.push(SwitcherItem {
+                panel: panel.clone(),
+                tab_panel: tab_panel.clone(),
+                placement,
+            });
+        }
+    } else if let Ok(stack_panel) = view.clone().downcast::<StackPanel>() {
+        for panel in stack_panel.read(cx).panels.iter() {
+            collect_panels(panel, placement, cx, items);
+        }
+    } else if let Ok(tiles) = view.downcast::<Tiles>() {
+        for item in tiles.read(cx).panels.iter() {
+            collect_panels(&item.panel, placement, cx, items);
+        }
+    }
+}
+
+fn placement_label(placement: DockPlacement) -> SharedString {
+    match placement {
+        DockPlacement::Center => t!("Dock.Center"),
+        DockPlacement::Left => t!("Dock.Left"),
+        DockPlacement::Bottom => t!("Dock.Bottom"),
+        DockPlacement::Right => t!("Dock.Right"),
+    }
+    .into()
+}
+
+impl DockArea {
+    fn on_action_switcher_cancel(
+        &mut self,
+        _: &Cancel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.close_panel_switcher(window, cx);
+    }
+
+    fn on_action_switcher_confirm(
+        &mut self,
+        _: &Confirm,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.confirm_panel_switcher(window, cx);
+    }
+
+    fn on_action_switcher_select_next(
+        &mut self,
+        _: &SelectNext,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(switcher) = self.panel_switcher.as_mut() {
+            switcher.select_next();
+            cx.notify();
+        }
+    }
+
+    fn on_action_switcher_select_prev(
+        &mut self,
+        _: &SelectPrev,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(switcher) = self.panel_switcher.as_mut() {
+            switcher.select_prev();
+            cx.notify();
+        }
+    }
+
+    pub(super) fn render_panel_switcher(
+        &self,
+        switcher: &PanelSwitcher,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .flex()
+            .justify_center()
+            .pt_12()
+            .child(
+                v_flex()
+                    .id("panel-switcher")
+                    .key_context(SWITCHER_CONTEXT)
+                    .track_focus(&self.switcher_focus_handle)
+                    .on_action(cx.listener(Self::on_action_switcher_cancel))
+                    .on_action(cx.listener(Self::on_action_switcher_confirm))
+                    .on_action(cx.listener(Self::on_action_switcher_select_next))
+                    .on_action(cx.listener(Self::on_action_switcher_select_prev))
+                    .occlude()
+                    .w(px(360.))
+                    .max_h(px(480.))
+                    .overflow_y_scroll()
+                    .p_1()
+                    .gap_0p5()
+                    .bg(cx.theme().popover)
+                    .text_color(cx.theme().popover_foreground)
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius)
+                    .shadow_lg()
+                    .on_modifiers_changed(cx.listener(
+                        |this, ev: &ModifiersChangedEvent, window, cx| {
+                            // Activate the selected panel on release the modifiers.
+                            if !ev.modifiers.modified() {
+                                this.confirm_panel_switcher(window, cx);
+                            }
+                        },
+                    ))
+                    .on_mouse_down_out(cx.listener(|this, _, window, cx| {
+                        this.close_panel_switcher(window, cx);
+                    }))
+                    .children(switcher.items.iter().enumerate().map(|(ix, item)| {
+                        let selected = ix == switcher.selected_ix;
+                        h_flex()
+                            .id(ix)
+                            .gap_2()
+                            .px_2()
+                            .py_1()
+                            .rounded(cx.theme().radius)
+                            .when(selected, |this| {
+                                this.bg(cx.theme().accent)
+                                    .text_color(cx.theme().accent_foreground)
+                            })
+                            .hover(|this| this.bg(cx.theme().accent))
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .overflow_hidden()
+                                    .text_ellipsis()
+                                    .child(item.panel.title(window, cx)),
+                            )
+                            .when(item.placement != DockPlacement::Center, |this| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(placement_label(item.placement)),
+                                )
+                            })
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                if let Some(switcher) = this.panel_switcher.as_mut() {
+                                    switcher.selected_ix = ix;
+                                }
+                                this.confirm_panel_switcher(window, cx);
+                            }))
+                    })),
+            )
+    }
+}
@@ -1,27 +1,57 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, rems, App, AppContext, Context, Corner,
-    DismissEvent, Div, DragMoveEvent, Empty, Entity, EventEmitter, FocusHandle, Focusable,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement, StyleRefinement, Styled, WeakEntity, Window,
+    div, prelude::FluentBuilder, px, relative, rems, Action, App, AppContext, Bounds, ClickEvent,
+    Context, Corner, DismissEvent, Div, DragMoveEvent, Empty, Entity, EntityId, EventEmitter,
+    FocusHandle, Focusable, InteractiveElement as _, IntoElement, ParentElement, Pixels, Render,
+    ScrollHandle, SharedString, StatefulInteractiveElement, StyleRefinement, Styled, Subscription,
+    WeakEntity, Window,
 };
 use rust_i18n::t;
 
 use crate::{
     button::{Button, ButtonVariants as _},
+    context_menu::ContextMenuExt,
     dock::PanelInfo,
     h_flex,
     popup_menu::{PopupMenu, PopupMenuExt},
     tab::{Tab, TabBar},
-    v_flex, ActiveTheme, AxisExt, IconName, Placement, Selectable, Sizable,
+    v_flex, ActiveTheme, AxisExt, Disableable as _, Icon, IconName, Placement, Selectable, Sizable,
 };
 
 use super::{
     ClosePanel, DockArea, DockPlacement, Panel, PanelControl, PanelEvent, PanelState, PanelStyle,
-    PanelView, StackPanel, ToggleZoom,
+    PanelView, StackPanel, ToggleFloating, ToggleZoom,
 };
 
+/// Activate the tab at the index, used in the hidden tabs menu.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+pub struct ActivateTab(pub usize);
+
+/// Close the tab at the index.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+pub struct CloseTab(pub usize);
+
+/// Close all tabs except the tab at the index, the pinned tabs are kept.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+pub struct CloseOtherTabs(pub usize);
+
+/// Close the tabs to the right of the tab at the index, the pinned tabs are kept.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+pub struct CloseTabsToRight(pub usize);
+
+/// Pin or unpin the tab at the index.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+pub struct TogglePinTab(pub usize);
+
 #[derive(Clone)]
 struct TabState {
     closable: bool,
@@ -83,6 +113,12 @@ pub struct TabPanel {
     will_split_placement: Option<Placement>,
     /// Is TabPanel used in Tiles.
     in_tiles: bool,
+    /// Is TabPanel floating over the DockArea.
+    floating: bool,
+    /// The focus subscriptions of the panels to track the recent panels in the DockArea.
+    focus_subscriptions: HashMap<EntityId, Subscription>,
+    /// The pinned panels, they are always in the front of the tabs and can't be closed.
+    pinned: HashSet<EntityId>,
 }
 
 impl Panel for TabPanel {
@@ -93,7 +129,7 @@ impl Panel for TabPanel {
     fn title(&self, window: &Window, cx: &App) -> gpui::AnyElement {
         self.active_panel(cx)
             .map(|panel| panel.title(window, cx))
-            .unwrap_or("Empty Tab".into_any_element())
+            .unwrap_or_else(|| SharedString::from(t!("Dock.Empty Tab")).into_any_element())
     }
 
     fn closable(&self, cx: &App) -> bool {
@@ -110,6 +146,12 @@ impl Panel for TabPanel {
         self.active_panel(cx).and_then(|panel| panel.zoomable(cx))
     }
 
+    fn can_float(&self, cx: &App) -> bool {
+        self.active_panel(cx)
+            .map(|panel| panel.can_float(cx))
+            .unwrap_or(false)
+    }
+
     fn visible(&self, cx: &App) -> bool {
         self.visible_panels(cx).next().is_some()
     }
@@ -161,6 +203,9 @@ impl TabPanel {
             collapsed: false,
             closable: true,
             in_tiles: false,
+            floating: false,
+            focus_subscriptions: HashMap::new(),
+            pinned: HashSet::new(),
         }
     }
 
@@ -169,6 +214,11 @@ impl TabPanel {
         self.in_tiles = in_tiles;
     }
 
+    /// Mark the TabPanel as floating over the DockArea.
+    pub(super) fn set_floating(&mut self, floating: bool) {
+        self.floating = floating;
+    }
+
     pub(super) fn set_parent(&mut self, view: WeakEntity<StackPanel>) {
         self.stack_panel = Some(view);
     }
@@ -189,7 +239,7 @@ impl TabPanel {
         }
     }
 
-    fn set_active_ix(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+    pub(super) fn set_active_ix(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
         if ix == self.active_ix {
             return;
         }
@@ -250,6 +300,7 @@ impl TabPanel {
             return;
         }
 
+        self.subscribe_panel_focus(&panel, window, cx);
         self.panels.push(panel);
         // set the active panel to the new panel
         if active {
@@ -298,6 +349,7 @@ impl TabPanel {
             return;
         }
 
+        self.subscribe_panel_focus(&panel, window, cx);
         self.panels.insert(ix, panel);
         self.set_active_ix(ix, window, cx);
         cx.emit(PanelEvent::LayoutChanged);
@@ -324,12 +376,29 @@ impl TabPanel {
         cx: &mut Context<Self>,
     ) {
         let panel_view = panel.view();
+        self.focus_subscriptions.remove(&panel_view.entity_id());
+        self.pinned.remove(&panel_view.entity_id());
         self.panels.retain(|p| p.view() != panel_view);
         if self.active_ix >= self.panels.len() {
             self.set_active_ix(self.panels.len().saturating_sub(1), window, cx)
         }
     }
 
+    fn subscribe_panel_focus(
+        &mut self,
+        panel: &Arc<dyn PanelView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panel_id = panel.panel_id(cx);
+        let subscription = cx.on_focus_in(&panel.focus_handle(cx), window, move |this, _, cx| {
+            _ = this.dock_area.update(cx, |dock_area, _| {
+                dock_area.push_recent_panel(panel_id);
+            });
+        });
+        self.focus_subscriptions.insert(panel_id, subscription);
+    }
+
     /// Check to remove self from the parent StackPanel, if there is no panel left
     fn remove_self_if_empty(&self, window: &mut Window, cx: &mut Context<Self>) {
         if !self.panels.is_empty() {
@@ -370,7 +439,7 @@ impl TabPanel {
             return true;
         }
 
-        self.stack_panel.is_none()
+        self.stack_panel.is_none() && !self.floating
     }
 
     /// Return true if self or parent only have last panel.
@@ -400,8 +469,9 @@ impl TabPanel {
     /// Return true if the tab panel is draggable.
     ///
     /// E.g. if the parent and self only have one panel, it is not draggable.
+    /// The floating panel is always draggable, to allow dragging it back to dock.
     fn draggable(&self, cx: &App) -> bool {
-        !self.is_locked(cx) && !self.is_last_panel(cx)
+        !self.is_locked(cx) && (self.floating || !self.is_last_panel(cx))
     }
 
     /// Return true if the tab panel is droppable.
@@ -470,6 +540,8 @@ impl TabPanel {
                     .popup_menu({
                         let zoomable = state.zoomable.map_or(false, |v| v.menu_visible());
                         let closable = state.closable;
+                        let floatable = !self.is_locked(cx) && self.can_float(cx);
+                        let floating = self.floating;
 
                         move |this, window, cx| {
                             view.read(cx)
@@ -484,6 +556,16 @@ impl TabPanel {
                                     Box::new(ToggleZoom),
                                     !zoomable,
                                 )
+                                .when(floatable, |this| {
+                                    this.menu(
+                                        if floating {
+                                            t!("Dock.Dock")
+                                        } else {
+                                            t!("Dock.Float")
+                                        },
+                                        Box::new(ToggleFloating),
+                                    )
+                                })
                                 .when(closable, |this| {
                                     this.separator()
                                         .menu(t!("Dock.Close"), Box::new(ClosePanel))
@@ -505,7 +587,7 @@ impl TabPanel {
         }
 
         let dock_area = self.dock_area.upgrade()?.read(cx);
-        if !dock_area.toggle_button_visible {
+        if !dock_area.toggle_button_visible || dock_area.is_zen_mode() {
             return None;
         }
         if !dock_area.is_dock_collapsible(placement, cx) {
@@ -642,6 +724,11 @@ impl TabPanel {
                         .text_ellipsis()
                         .whitespace_nowrap()
                         .child(panel.title(window, cx))
+                        .on_click(cx.listener(|view, event: &ClickEvent, window, cx| {
+                            if event.click_count() == 2 {
+                                view.on_action_toggle_zoom(&ToggleZoom, window, cx);
+                            }
+                        }))
                         .when(state.draggable, |this| {
                             this.on_drag(
                                 DragPanel {
@@ -668,6 +755,8 @@ impl TabPanel {
         }
 
         let tabs_count = self.panels.len();
+        let focus_handle = self.focus_handle(cx);
+        let tab_overflow = self.render_tab_overflow(cx);
 
         TabBar::new("tab-bar")
             .tab_item_top_offset(-px(1.))
@@ -705,22 +794,78 @@ impl TabPanel {
                     active = false;
                 }
 
+                let pinned = self.is_pinned(panel, cx);
+                let closable = self.is_tab_closable(ix, state, cx);
+                let others_closable =
+                    (0..tabs_count).any(|i| i != ix && self.is_tab_closable(i, state, cx));
+                let right_closable =
+                    (ix + 1..tabs_count).any(|i| self.is_tab_closable(i, state, cx));
+
                 Some(
                     Tab::empty()
-                        .map(|this| {
-                            if let Some(tab_name) = panel.tab_name(cx) {
-                                this.child(tab_name)
-                            } else {
-                                this.child(panel.title(window, cx))
-                            }
+                        .child(
+                            h_flex()
+                                .gap_1()
+                                .map(|this| {
+                                    if let Some(tab_name) = panel.tab_name(cx) {
+                                        this.child(tab_name)
+                                    } else {
+                                        this.child(panel.title(window, cx))
+                                    }
+                                })
+                                .when(pinned, |this| {
+                                    this.child(
+                                        Icon::new(IconName::Pin)
+                                            .xsmall()
+                                            .text_color(cx.theme().muted_foreground),
+                                    )
+                                }),
+                        )
+                        .when(!self.collapsed, |this| {
+                            this.context_menu({
+                                let focus_handle = focus_handle.clone();
+                                move |menu, _, _| {
+                                    menu.action_context(focus_handle.clone())
+                                        .menu_with_disabled(
+                                            t!("Dock.Close"),
+                                            Box::new(CloseTab(ix)),
+                                            !closable,
+                                        )
+                                        .menu_with_disabled(
+                                            t!("Dock.Close Others"),
+                                            Box::new(CloseOtherTabs(ix)),
+                                            !others_closable,
+                                        )
+                                        .menu_with_disabled(
+                                            t!("Dock.Close to the Right"),
+                                            Box::new(CloseTabsToRight(ix)),
+                                            !right_closable,
+                                        )
+                                        .separator()
+                                        .menu(
+                                            if pinned {
+                                                t!("Dock.Unpin Tab")
+                                            } else {
+                                                t!("Dock.Pin Tab")
+                                            },
+                                            Box::new(TogglePinTab(ix)),
+                                        )
+                                }
+                            })
                         })
                         .selected(active)
                         .on_click(cx.listener({
                             let is_collapsed = self.collapsed;
                             let dock_area = self.dock_area.clone();
-                            move |view, _, window, cx| {
+                            move |view, event: &ClickEvent, window, cx| {
                                 view.set_active_ix(ix, window, cx);
 
+                                // Double click to maximize or restore the panel
+                                if event.click_count() == 2 && !is_collapsed {
+                                    view.on_action_toggle_zoom(&ToggleZoom, window, cx);
+                                    return;
+                                }
+
                                 // Open dock if clicked on the collapsed bottom dock
                                 if is_bottom_dock && is_collapsed {
                                     _ = dock_area.update(cx, |dock_area, cx| {
@@ -795,6 +940,7 @@ impl TabPanel {
                         .bg(cx.theme().tab_bar)
                         .px_2()
                         .gap_1()
+                        .children(tab_overflow)
                         .children(
                             self.active_panel(cx)
                                 .and_then(|panel| panel.title_suffix(window, cx)),
@@ -878,6 +1024,12 @@ impl TabPanel {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        // The floating panel has no stack panel to split.
+        if self.floating {
+            self.will_split_placement = None;
+            return;
+        }
+
         let bounds = drag.bounds;
         let position = drag.event.position;
 
@@ -1069,6 +1221,255 @@ impl TabPanel {
         cx.emit(PanelEvent::LayoutChanged);
     }
 
+    /// Return true if the panel is pinned.
+    pub fn is_pinned(&self, panel: &Arc<dyn PanelView>, cx: &App) -> bool {
+        self.pinned.contains(&panel.panel_id(cx))
+    }
+
+    /// Pin or unpin the panel at the index.
+    ///
+    /// The pinned panel is moved to the end of the pinned panels, and the unpinned panel
+    /// is moved to the front of the unpinned panels, so the pinned panels always stay left.
+    pub fn set_pinned(
+        &mut self,
+        ix: usize,
+        pinned: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+        let panel_id = panel.panel_id(cx);
+        if self.pinned.contains(&panel_id) == pinned {
+            return;
+        }
+
+        let active_panel = self.panels.get(self.active_ix).cloned();
+        if pinned {
+            self.pinned.insert(panel_id);
+        } else {
+            self.pinned.remove(&panel_id);
+        }
+
+        let pinned_count = self
+            .panels
+            .iter()
+            .filter(|panel| self.is_pinned(panel, cx) && panel.panel_id(cx) != panel_id)
+            .count();
+        self.panels.remove(ix);
+        self.panels.insert(pinned_count, panel);
+        if let Some(ix) =
+            active_panel.and_then(|active| self.panels.iter().position(|p| p == &active))
+        {
+            self.active_ix = ix;
+            self.tab_bar_scroll_handle.scroll_to_item(ix);
+        }
+
+        self.focus_active_panel(window, cx);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Return true if the panel at the index can be closed by the tab context menu.
+    fn is_tab_closable(&self, ix: usize, state: &TabState, cx: &App) -> bool {
+        let closable = self.closable && (state.draggable || self.in_tiles);
+        self.panels.get(ix).map_or(false, |panel| {
+            closable && panel.closable(cx) && !self.is_pinned(panel, cx)
+        })
+    }
+
+    /// Close the panels, the pinned and non-closable panels are skipped.
+    fn close_panels(
+        &mut self,
+        panels: Vec<Arc<dyn PanelView>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for panel in panels {
+            if !panel.closable(cx) || self.is_pinned(&panel, cx) {
+                continue;
+            }
+            self.remove_panel(panel, window, cx);
+        }
+
+        self.remove_self_from_tiles_if_empty(window, cx);
+    }
+
+    /// Remove self from the parent DockArea if there is no panel left.
+    ///
+    /// This is ensure to remove from Tiles.
+    fn remove_self_from_tiles_if_empty(&self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.panels.is_empty() || !self.in_tiles {
+            return;
+        }
+
+        let tab_panel = Arc::new(cx.entity());
+        window.defer(cx, {
+            let dock_area = self.dock_area.clone();
+            move |window, cx| {
+                _ = dock_area.update(cx, |this, cx| {
+                    this.remove_panel_from_all_docks(tab_panel, window, cx);
+                });
+            }
+        });
+    }
+
+    /// Returns the indexes of the visible panels that are scrolled out of the tab bar.
+    fn hidden_tabs(&self, cx: &App) -> Vec<usize> {
+        let scroll_handle = &self.tab_bar_scroll_handle;
+        let viewport = scroll_handle.bounds();
+        let offset_x = scroll_handle.offset().x;
+
+        self.panels
+            .iter()
+            .enumerate()
+            .filter(|(_, panel)| panel.visible(cx))
+            .enumerate()
+            .filter_map(|(item_ix, (ix, _))| {
+                let bounds = scroll_handle.bounds_for_item(item_ix)?;
+                is_clipped(viewport, offset_x, bounds).then_some(ix)
+            })
+            .collect()
+    }
+
+    /// Scroll the tab bar by half of the width, to the right if `forward` is true.
+    fn scroll_tabs(&mut self, forward: bool, cx: &mut Context<Self>) {
+        let scroll_handle = &self.tab_bar_scroll_handle;
+        let step = scroll_handle.bounds().size.width / 2.;
+        let max_offset = scroll_handle.max_offset().width;
+        let mut offset = scroll_handle.offset();
+        offset.x = if forward {
+            (offset.x - step).max(-max_offset)
+        } else {
+            (offset.x + step).min(px(0.))
+        };
+        scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+
+    fn render_tab_overflow(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let scroll_handle = &self.tab_bar_scroll_handle;
+        let max_offset = scroll_handle.max_offset().width;
+        if max_offset <= px(0.) {
+            return None;
+        }
+
+        let offset_x = scroll_handle.offset().x;
+        let hidden_tabs = self
+            .hidden_tabs(cx)
+            .into_iter()
+            .filter_map(|ix| self.panels.get(ix).map(|panel| (ix, panel.clone())))
+            .collect::<Vec<_>>();
+
+        Some(
+            h_flex()
+                .gap_0p5()
+                .child(
+                    Button::new("scroll-tabs-left")
+                        .icon(IconName::ChevronLeft)
+                        .xsmall()
+                        .ghost()
+                        .tab_stop(false)
+                        .disabled(offset_x >= px(0.))
+                        .on_click(cx.listener(|this, _, _, cx| this.scroll_tabs(false, cx))),
+                )
+                .child(
+                    Button::new("scroll-tabs-right")
+                        .icon(IconName::ChevronRight)
+                        .xsmall()
+                        .ghost()
+                        .tab_stop(false)
+                        .disabled(offset_x <= -max_offset)
+                        .on_click(cx.listener(|this, _, _, cx| this.scroll_tabs(true, cx))),
+                )
+                .child(
+                    Button::new("hidden-tabs")
+                        .icon(IconName::ChevronDown)
+                        .xsmall()
+                        .ghost()
+                        .tab_stop(false)
+                        .disabled(hidden_tabs.is_empty())
+                        .popup_menu(move |menu, _, _| {
+                            hidden_tabs
+                                .iter()
+                                .fold(menu.scrollable(), |menu, (ix, panel)| {
+                                    let panel = panel.clone();
+                                    menu.menu_element(
+                                        Box::new(ActivateTab(*ix)),
+                                        move |window, cx| panel.title(window, cx),
+                                    )
+                                })
+                        })
+                        .anchor(Corner::TopRight),
+                ),
+        )
+    }
+
+    fn on_action_activate_tab(
+        &mut self,
+        action: &ActivateTab,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if action.0 < self.panels.len() {
+            self.set_active_ix(action.0, window, cx);
+        }
+    }
+
+    fn on_action_close_tab(
+        &mut self,
+        action: &CloseTab,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panels = self.panels.get(action.0).cloned().into_iter().collect();
+        self.close_panels(panels, window, cx);
+    }
+
+    fn on_action_close_other_tabs(
+        &mut self,
+        action: &CloseOtherTabs,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if action.0 < self.panels.len() {
+            self.set_active_ix(action.0, window, cx);
+        }
+        let panels = self
+            .panels
+            .iter()
+            .enumerate()
+            .filter(|(ix, _)| *ix != action.0)
+            .map(|(_, panel)| panel.clone())
+            .collect();
+        self.close_panels(panels, window, cx);
+    }
+
+    fn on_action_close_tabs_to_right(
+        &mut self,
+        action: &CloseTabsToRight,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panels = self.panels.iter().skip(action.0 + 1).cloned().collect();
+        self.close_panels(panels, window, cx);
+    }
+
+    fn on_action_toggle_pin_tab(
+        &mut self,
+        action: &TogglePinTab,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(panel) = self.panels.get(action.0) else {
+            return;
+        };
+
+        let pinned = self.is_pinned(panel, cx);
+        self.set_pinned(action.0, !pinned, window, cx);
+    }
+
     fn focus_active_panel(&self, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(active_panel) = self.active_panel(cx) {
             active_panel.focus_handle(cx).focus(window);
@@ -1112,22 +1513,41 @@ impl TabPanel {
         cx: &mut Context<Self>,
     ) {
         if let Some(panel) = self.active_panel(cx) {
+            if self.is_pinned(&panel, cx) {
+                return;
+            }
             self.remove_panel(panel, window, cx);
         }
 
-        // Remove self from the parent DockArea.
-        // This is ensure to remove from Tiles
-        if self.panels.is_empty() && self.in_tiles {
-            let tab_panel = Arc::new(cx.entity());
-            window.defer(cx, {
-                let dock_area = self.dock_area.clone();
-                move |window, cx| {
-                    _ = dock_area.update(cx, |this, cx| {
-                        this.remove_panel_from_all_docks(tab_panel, window, cx);
-                    });
-                }
-            });
+        self.remove_self_from_tiles_if_empty(window, cx);
+    }
+
+    fn on_action_toggle_floating(
+        &mut self,
+        _: &ToggleFloating,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_locked(cx) || !self.can_float(cx) {
+            return;
         }
+        let Some(panel) = self.active_panel(cx) else {
+            return;
+        };
+
+        let floating = self.floating;
+        window.defer(cx, {
+            let dock_area = self.dock_area.clone();
+            move |window, cx| {
+                _ = dock_area.update(cx, |this, cx| {
+                    if floating {
+                        this.dock_floating_panel(panel, window, cx);
+                    } else {
+                        this.float_panel(panel, None, window, cx);
+                    }
+                });
+            }
+        });
     }
 
     // Bind actions to the tab panel, only when the tab panel is not collapsed.
@@ -1135,6 +1555,12 @@ impl TabPanel {
         v_flex().when(!self.collapsed, |this| {
             this.on_action(cx.listener(Self::on_action_toggle_zoom))
                 .on_action(cx.listener(Self::on_action_close_panel))
+                .on_action(cx.listener(Self::on_action_toggle_floating))
+                .on_action(cx.listener(Self::on_action_activate_tab))
+                .on_action(cx.listener(Self::on_action_close_tab))
+                .on_action(cx.listener(Self::on_action_close_other_tabs))
+                .on_action(cx.listener(Self::on_action_close_tabs_to_right))
+                .on_action(cx.listener(Self::on_action_toggle_pin_tab))
         })
     }
 }
@@ -1179,3 +1605,34 @@ impl Render for TabPanel {
             .child(self.render_active_panel(&state, window, cx))
     }
 }
+
+/// Returns true if the item is not fully visible in the horizontal scrolled viewport.
+///
+/// The `item` bounds is the layout bounds without the scroll offset.
+fn is_clipped(viewport: Bounds<Pixels>, offset_x: Pixels, item: Bounds<Pixels>) -> bool {
+    // Allow 1px tolerance for the subpixel layout
+    item.left() + offset_x < viewport.left() - px(1.)
+        || item.right() + offset_x > viewport.right() + px(1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::is_clipped;
+
+    #[test]
+    fn test_is_clipped() {
+        let viewport = Bounds::new(point(px(100.), px(0.)), size(px(200.), px(30.)));
+        let item = Bounds::new(point(px(120.), px(0.)), size(px(80.), px(30.)));
+        assert!(!is_clipped(viewport, px(0.), item));
+        // Scrolled out to the left
+        assert!(is_clipped(viewport, px(-40.), item));
+        // Scrolled out to the right
+        assert!(is_clipped(viewport, px(120.), item));
+
+        let item = Bounds::new(point(px(280.), px(0.)), size(px(80.), px(30.)));
+        assert!(is_clipped(viewport, px(0.), item));
+        assert!(!is_clipped(viewport, px(-60.), item));
+    }
+}
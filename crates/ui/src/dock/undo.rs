@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use gpui::{Context, Window};
+use smol::Timer;
+
+use crate::history::HistoryItem;
+
+use super::{DockArea, DockAreaState, DockEvent, RedoLayout, UndoLayout};
+
+/// The delay to record the layout after the last layout change,
+/// so the continuous changes (e.g. resizing) are recorded as one change.
+const RECORD_DELAY: Duration = Duration::from_millis(300);
+
+/// A layout change of the [`DockArea`], used for undo/redo in [`crate::history::History`].
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct LayoutChange {
+    old: DockAreaState,
+    new: DockAreaState,
+    version: usize,
+}
+
+impl HistoryItem for LayoutChange {
+    fn version(&self) -> usize {
+        self.version
+    }
+
+    fn set_version(&mut self, version: usize) {
+        self.version = version;
+    }
+}
+
+impl DockArea {
+    /// Record the layout after the [`RECORD_DELAY`], the previous pending record is canceled.
+    pub(super) fn record_layout_later(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self._record_layout_task = cx.spawn_in(window, async move |this, window| {
+            Timer::after(RECORD_DELAY).await;
+            _ = this.update_in(window, |this, _, cx| {
+                this.record_layout(cx);
+            });
+        });
+    }
+
+    /// Use the current layout as the base of the next change, without recording a change.
+    pub(super) fn reset_layout_record(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.skip_layout_record = true;
+        self.record_layout_later(window, cx);
+    }
+
+    fn record_layout(&mut self, cx: &mut Context<Self>) {
+        let layout = self.dump(cx);
+        let skip = std::mem::take(&mut self.skip_layout_record);
+        if let Some(old) = self.last_layout.replace(layout.clone()) {
+            if !skip && old != layout {
+                self.layout_history.push(LayoutChange {
+                    old,
+                    new: layout,
+                    version: 0,
+                });
+            }
+        }
+    }
+
+    /// Returns true if there is a layout change to undo.
+    pub fn can_undo_layout(&self) -> bool {
+        !self.locked && !self.layout_history.undos().is_empty()
+    }
+
+    /// Returns true if there is a layout change to redo.
+    pub fn can_redo_layout(&self) -> bool {
+        !self.locked && !self.layout_history.redos().is_empty()
+    }
+
+    /// Undo the last layout change, e.g.: split, resize, move or close panels.
+    ///
+    /// The opened panels are reused, and the closed panels are rebuilt by the [`super::PanelRegistry`].
+    pub fn undo_layout(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.can_undo_layout() {
+            return;
+        }
+
+        if let Some(change) = self.layout_history.undo().and_then(|c| c.last().cloned()) {
+            self.restore_layout(change.old, window, cx);
+        }
+    }
+
+    /// Redo the last undone layout change.
+    pub fn redo_layout(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.can_redo_layout() {
+            return;
+        }
+
+        if let Some(change) = self.layout_history.redo().and_then(|c| c.last().cloned()) {
+            self.restore_layout(change.new, window, cx);
+        }
+    }
+
+    fn restore_layout(
+        &mut self,
+        state: DockAreaState,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut panels = self
+            .all_panels(cx)
+            .into_iter()
+            .map(|item| (item.panel.dump(cx), item.panel))
+            .collect();
+
+        self.last_layout = Some(state.clone());
+        self.load_with_panels(state, &mut panels, window, cx);
+        self.reset_layout_record(window, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    pub(super) fn on_action_undo_layout(
+        &mut self,
+        _: &UndoLayout,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.undo_layout(window, cx);
+    }
+
+    pub(super) fn on_action_redo_layout(
+        &mut self,
+        _: &RedoLayout,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.redo_layout(window, cx);
+    }
+}
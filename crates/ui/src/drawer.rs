@@ -1,20 +1,20 @@
-use std::{rc::Rc, time::Duration};
+use std::rc::Rc;
 
 use gpui::{
-    anchored, div, point, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
-    AnyElement, App, Axis, ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter,
-    FocusHandle, InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement,
-    Pixels, RenderOnce, Styled, Window,
+    anchored, div, point, prelude::FluentBuilder as _, px, AnyElement, App, Axis, ClickEvent,
+    DefiniteLength, DismissEvent, Div, EventEmitter, FocusHandle, InteractiveElement as _,
+    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, RenderOnce, Styled, Window,
 };
 
 use crate::{
     actions::Cancel,
+    animation::{Easing, Transition, TransitionExt as _},
     button::{Button, ButtonVariants as _},
     h_flex,
     modal::overlay_color,
     root::ContextModal as _,
     title_bar::TITLE_BAR_HEIGHT,
-    v_flex, ActiveTheme, IconName, Placement, Sizable, StyledExt as _,
+    v_flex, ActiveTheme, FocusTrapElement as _, IconName, Placement, Sizable, StyledExt as _,
 };
 
 const CONTEXT: &str = "Drawer";
@@ -122,7 +122,7 @@ impl Styled for Drawer {
 
 impl RenderOnce for Drawer {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let placement = self.placement;
+        let placement = cx.theme().direction.placement(self.placement);
         let titlebar_height = self.margin_top;
         let window_paddings = crate::window_border::window_paddings(window);
         let size = window.viewport_size()
@@ -159,6 +159,7 @@ impl RenderOnce for Drawer {
                             .tab_group()
                             .key_context(CONTEXT)
                             .track_focus(&self.focus_handle)
+                            .focus_trap(&self.focus_handle)
                             .on_action({
                                 let on_close = self.on_close.clone();
                                 move |_: &Cancel, window, cx| {
@@ -181,7 +182,7 @@ impl RenderOnce for Drawer {
                                     this.w_full().h(self.size)
                                 }
                             })
-                            .map(|this| match self.placement {
+                            .map(|this| match placement {
                                 Placement::Top => this.top_0().left_0().right_0().border_b_1(),
                                 Placement::Right => this.top_0().right_0().bottom_0().border_l_1(),
                                 Placement::Bottom => {
@@ -228,9 +229,10 @@ impl RenderOnce for Drawer {
                                         .child(footer),
                                 )
                             })
-                            .with_animation(
+                            .with_transition(
                                 "slide",
-                                Animation::new(Duration::from_secs_f64(0.15)),
+                                Transition::FAST.easing(Easing::Decelerate),
+                                cx,
                                 move |this, delta| {
                                     let y = px(-100.) + delta * px(100.);
                                     this.map(|this| match placement {
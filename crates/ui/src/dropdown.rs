@@ -1,19 +1,25 @@
 use gpui::{
-    anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement, App, AppContext,
-    Bounds, ClickEvent, Context, DismissEvent, Edges, ElementId, Empty, Entity, EventEmitter,
-    FocusHandle, Focusable, InteractiveElement, IntoElement, KeyBinding, Length, ParentElement,
+    canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement, App, AppContext, Bounds,
+    ClickEvent, Context, DismissEvent, Edges, ElementId, Empty, Entity, EventEmitter, FocusHandle,
+    Focusable, InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, Length, ParentElement,
     Pixels, Render, RenderOnce, SharedString, StatefulInteractiveElement, StyleRefinement, Styled,
     Subscription, Task, WeakEntity, Window,
 };
 use rust_i18n::t;
 
 use crate::{
-    actions::{Cancel, Confirm, SelectNext, SelectPrev},
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Role},
+    actions::{
+        Cancel, Confirm, SelectFirst, SelectLast, SelectNext, SelectPageDown, SelectPageUp,
+        SelectPrev,
+    },
+    animation::{Easing, Transition, TransitionExt as _},
     h_flex,
     input::clear_button,
     list::{List, ListDelegate},
-    v_flex, ActiveTheme, Disableable, Icon, IconName, IndexPath, Selectable, Sizable, Size,
-    StyleSized, StyledExt,
+    position::{Position, Positioned},
+    v_flex, ActiveTheme, Disableable, Icon, IconName, IndexPath, Placement, Selectable, Sizable,
+    Size, StyleSized, StyledExt,
 };
 
 #[derive(Clone)]
@@ -31,6 +37,10 @@ pub(crate) fn init(cx: &mut App) {
     cx.bind_keys([
         KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
         KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+        KeyBinding::new("pageup", SelectPageUp, Some(CONTEXT)),
+        KeyBinding::new("pagedown", SelectPageDown, Some(CONTEXT)),
         KeyBinding::new("enter", Confirm { secondary: false }, Some(CONTEXT)),
         KeyBinding::new(
             "secondary-enter",
@@ -52,6 +62,10 @@ pub trait DropdownItem: Clone {
         None
     }
     fn value(&self) -> &Self::Value;
+    /// Returns true if the item is disabled, the disabled item can't be selected, default is false.
+    fn disabled(&self) -> bool {
+        false
+    }
     /// Check if the item matches the query for search, default is to match the title.
     fn matches(&self, query: &str) -> bool {
         self.title().to_lowercase().contains(&query.to_lowercase())
@@ -197,6 +211,7 @@ where
         if let Some(item) = self.delegate.item(ix) {
             let list_item = DropdownListItem::new(ix.row)
                 .selected(selected)
+                .disabled(item.disabled())
                 .with_size(size)
                 .child(div().whitespace_nowrap().child(item.title().to_string()));
             Some(list_item)
@@ -205,6 +220,14 @@ where
         }
     }
 
+    fn item_text(&self, ix: IndexPath, _: &App) -> Option<SharedString> {
+        self.delegate.item(ix).map(|item| item.title())
+    }
+
+    fn item_disabled(&self, ix: IndexPath, _: &App) -> bool {
+        self.delegate.item(ix).is_some_and(|item| item.disabled())
+    }
+
     fn cancel(&mut self, window: &mut Window, cx: &mut Context<List<Self>>) {
         let dropdown = self.dropdown.clone();
         cx.defer_in(window, move |_, window, cx| {
@@ -302,6 +325,7 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     menu_width: Length,
     disabled: bool,
     appearance: bool,
+    accessibility: AccessibilityProps,
 }
 
 #[derive(Debug, Clone)]
@@ -621,6 +645,91 @@ where
         cx.propagate();
     }
 
+    /// Open the menu and move the selection of the list.
+    fn open_and_select(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+        f: impl FnOnce(
+            &mut List<DropdownListDelegate<D>>,
+            &mut Window,
+            &mut Context<List<DropdownListDelegate<D>>>,
+        ),
+    ) {
+        self.open = true;
+        self.list.focus_handle(cx).focus(window);
+        self.list.update(cx, |list, cx| f(list, window, cx));
+        cx.notify();
+    }
+
+    fn select_first(&mut self, action: &SelectFirst, window: &mut Window, cx: &mut Context<Self>) {
+        self.open_and_select(window, cx, |list, window, cx| {
+            list.on_action_select_first(action, window, cx)
+        });
+    }
+
+    fn select_last(&mut self, action: &SelectLast, window: &mut Window, cx: &mut Context<Self>) {
+        self.open_and_select(window, cx, |list, window, cx| {
+            list.on_action_select_last(action, window, cx)
+        });
+    }
+
+    fn select_page_up(
+        &mut self,
+        action: &SelectPageUp,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_and_select(window, cx, |list, window, cx| {
+            list.on_action_select_page_up(action, window, cx)
+        });
+    }
+
+    fn select_page_down(
+        &mut self,
+        action: &SelectPageDown,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.open_and_select(window, cx, |list, window, cx| {
+            list.on_action_select_page_down(action, window, cx)
+        });
+    }
+
+    /// Select the item by typing when the menu is closed, like the native select.
+    ///
+    /// When the menu is open, the type-ahead is handled by the list,
+    /// or the query input is used to search if the dropdown is searchable.
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open || self.list.read(cx).query_input().is_some() {
+            return;
+        }
+
+        let selected = self
+            .list
+            .update(cx, |list, cx| list.type_ahead_key_down(event, window, cx));
+        if !selected {
+            return;
+        }
+
+        cx.stop_propagation();
+        self.update_selected_value(window, cx);
+        cx.emit(DropdownEvent::Confirm(self.selected_value.clone()));
+        cx.notify();
+    }
+
+    /// Set whether to select the first item after the last item (and vice versa)
+    /// by the up and down keys, default is true.
+    pub fn set_wrap_around(
+        &mut self,
+        wrap_around: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.list
+            .update(cx, |list, cx| list.set_wrap_around(wrap_around, window, cx));
+    }
+
     fn enter(&mut self, _: &Confirm, window: &mut Window, cx: &mut Context<Self>) {
         // Propagate the event to the parent view, for example to the Modal to support ENTER to confirm.
         cx.propagate();
@@ -695,6 +804,7 @@ where
             menu_width: Length::Auto,
             disabled: false,
             appearance: true,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -830,6 +940,15 @@ where
     }
 }
 
+impl<D> Accessible for Dropdown<D>
+where
+    D: DropdownDelegate,
+{
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl<D> Styled for Dropdown<D>
 where
     D: DropdownDelegate,
@@ -865,6 +984,14 @@ where
         let outline_visible = state.open || is_focused && !self.disabled;
         let popup_radius = cx.theme().radius.min(px(8.));
 
+        let mut props = AccessibilityProps::new(Role::ComboBox)
+            .expanded(state.open)
+            .disabled(self.disabled);
+        props.label = self.placeholder.clone();
+        let props = props.merge(&self.accessibility);
+        Accessibility::declare(&self.id, &props, window, cx);
+        let state = self.state.read(cx);
+
         div()
             .id(self.id.clone())
             .key_context(CONTEXT)
@@ -873,8 +1000,15 @@ where
             })
             .on_action(window.listener_for(&self.state, DropdownState::up))
             .on_action(window.listener_for(&self.state, DropdownState::down))
+            .on_action(window.listener_for(&self.state, DropdownState::select_first))
+            .on_action(window.listener_for(&self.state, DropdownState::select_last))
+            .on_action(window.listener_for(&self.state, DropdownState::select_page_up))
+            .on_action(window.listener_for(&self.state, DropdownState::select_page_down))
             .on_action(window.listener_for(&self.state, DropdownState::enter))
             .on_action(window.listener_for(&self.state, DropdownState::escape))
+            .when(!self.disabled, |this| {
+                this.on_key_down(window.listener_for(&self.state, DropdownState::on_key_down))
+            })
             .size_full()
             .relative()
             .child(
@@ -967,31 +1101,42 @@ where
             .when(state.open, |this| {
                 this.child(
                     deferred(
-                        anchored().snap_to_window_with_margin(px(8.)).child(
-                            div()
-                                .occlude()
-                                .map(|this| match self.menu_width {
-                                    Length::Auto => this.w(bounds.size.width + px(2.)),
-                                    Length::Definite(w) => this.w(w),
-                                })
-                                .child(
-                                    v_flex()
-                                        .occlude()
-                                        .mt_1p5()
-                                        .bg(cx.theme().background)
-                                        .border_1()
-                                        .border_color(cx.theme().border)
-                                        .rounded(popup_radius)
-                                        .shadow_md()
-                                        .child(state.list.clone()),
-                                )
-                                .on_mouse_down_out(window.listener_for(
-                                    &self.state,
-                                    |this, _, window, cx| {
-                                        this.escape(&Cancel, window, cx);
-                                    },
-                                )),
-                        ),
+                        Positioned::new("menu", bounds)
+                            .position(Position::new(Placement::Bottom).gap(px(6.)))
+                            .child(
+                                div()
+                                    .occlude()
+                                    .map(|this| match self.menu_width {
+                                        Length::Auto => this.w(bounds.size.width + px(2.)),
+                                        Length::Definite(w) => this.w(w),
+                                    })
+                                    .child(
+                                        v_flex()
+                                            .occlude()
+                                            .bg(cx.theme().background)
+                                            .border_1()
+                                            .border_color(cx.theme().border)
+                                            .rounded(popup_radius)
+                                            .shadow_md()
+                                            .child(state.list.clone())
+                                            .with_transition(
+                                                "pop",
+                                                Transition::FAST.easing(Easing::Decelerate),
+                                                cx,
+                                                |this, delta| {
+                                                    this.relative()
+                                                        .top(px(-4.) + delta * px(4.))
+                                                        .opacity(delta)
+                                                },
+                                            ),
+                                    )
+                                    .on_mouse_down_out(window.listener_for(
+                                        &self.state,
+                                        |this, _, window, cx| {
+                                            this.escape(&Cancel, window, cx);
+                                        },
+                                    )),
+                            ),
                     )
                     .with_priority(1),
                 )
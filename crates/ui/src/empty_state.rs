@@ -0,0 +1,235 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, App, IntoElement, ParentElement as _,
+    RenderOnce, SharedString, StyleRefinement, Styled, Window,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, v_flex, ActiveTheme as _, Icon, IconName, Sizable, Size, StyledExt};
+
+/// A placeholder to show when there is no data, with an icon or illustration,
+/// title, description and actions.
+///
+/// ```ignore
+/// EmptyState::new()
+///     .icon(IconName::Inbox)
+///     .title("No messages")
+///     .description("New messages will appear here.")
+///     .primary_action(Button::new("compose").primary().label("Compose"))
+/// ```
+#[derive(IntoElement)]
+pub struct EmptyState {
+    style: StyleRefinement,
+    icon: Option<Icon>,
+    illustration: Option<AnyElement>,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    primary_action: Option<AnyElement>,
+    secondary_action: Option<AnyElement>,
+    size: Size,
+    danger: bool,
+}
+
+impl EmptyState {
+    /// Create a new empty state.
+    pub fn new() -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            icon: None,
+            illustration: None,
+            title: None,
+            description: None,
+            primary_action: None,
+            secondary_action: None,
+            size: Size::default(),
+            danger: false,
+        }
+    }
+
+    /// Create the built-in "No data" empty state, used by List and Table by default.
+    pub fn no_data() -> Self {
+        Self::new()
+            .icon(IconName::Inbox)
+            .title(t!("EmptyState.no_data"))
+    }
+
+    /// Set the icon to show above the title.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set an illustration element to show above the title, this will replace the icon.
+    pub fn illustration(mut self, illustration: impl IntoElement) -> Self {
+        self.illustration = Some(illustration.into_any_element());
+        self
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description text below the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the primary action, e.g.: a [`crate::button::Button`].
+    pub fn primary_action(mut self, action: impl IntoElement) -> Self {
+        self.primary_action = Some(action.into_any_element());
+        self
+    }
+
+    /// Set the secondary action, this will be shown after the primary action.
+    pub fn secondary_action(mut self, action: impl IntoElement) -> Self {
+        self.secondary_action = Some(action.into_any_element());
+        self
+    }
+}
+
+impl Sizable for EmptyState {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for EmptyState {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (icon_size, gap) = match self.size {
+            Size::XSmall | Size::Small => (px(32.), px(4.)),
+            Size::Large => (px(64.), px(12.)),
+            _ => (px(48.), px(8.)),
+        };
+        let icon_color = if self.danger {
+            cx.theme().danger
+        } else {
+            cx.theme().muted_foreground.opacity(0.6)
+        };
+        let has_actions = self.primary_action.is_some() || self.secondary_action.is_some();
+
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap(gap)
+            .items_center()
+            .justify_center()
+            .text_center()
+            .refine_style(&self.style)
+            .map(|this| match (self.illustration, self.icon) {
+                (Some(illustration), _) => this.child(illustration),
+                (None, Some(icon)) => this.child(icon.size(icon_size).text_color(icon_color)),
+                (None, None) => this,
+            })
+            .when_some(self.title, |this, title| {
+                this.child(
+                    div()
+                        .font_semibold()
+                        .map(|this| match self.size {
+                            Size::XSmall | Size::Small => this.text_sm(),
+                            Size::Large => this.text_lg(),
+                            _ => this.text_base(),
+                        })
+                        .child(title),
+                )
+            })
+            .when_some(self.description, |this, description| {
+                this.child(
+                    div()
+                        .max_w(px(420.))
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(description),
+                )
+            })
+            .when(has_actions, |this| {
+                this.child(
+                    h_flex()
+                        .mt_2()
+                        .gap_2()
+                        .children(self.primary_action)
+                        .children(self.secondary_action),
+                )
+            })
+    }
+}
+
+/// A placeholder to show when failed to load the data, e.g.: with a "Retry" action.
+#[derive(IntoElement)]
+pub struct ErrorState {
+    base: EmptyState,
+}
+
+impl ErrorState {
+    /// Create a new error state with the default icon and title.
+    pub fn new() -> Self {
+        let mut base = EmptyState::new()
+            .icon(IconName::CircleX)
+            .title(t!("EmptyState.error"));
+        base.danger = true;
+        Self { base }
+    }
+
+    /// Set the icon to show above the title.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.base = self.base.icon(icon);
+        self
+    }
+
+    /// Set an illustration element to show above the title, this will replace the icon.
+    pub fn illustration(mut self, illustration: impl IntoElement) -> Self {
+        self.base = self.base.illustration(illustration);
+        self
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.base = self.base.title(title);
+        self
+    }
+
+    /// Set the description text below the title, e.g.: the error message.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.base = self.base.description(description);
+        self
+    }
+
+    /// Set the primary action, e.g.: a "Retry" button.
+    pub fn primary_action(mut self, action: impl IntoElement) -> Self {
+        self.base = self.base.primary_action(action);
+        self
+    }
+
+    /// Set the secondary action, this will be shown after the primary action.
+    pub fn secondary_action(mut self, action: impl IntoElement) -> Self {
+        self.base = self.base.secondary_action(action);
+        self
+    }
+}
+
+impl Sizable for ErrorState {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.base = self.base.with_size(size);
+        self
+    }
+}
+
+impl Styled for ErrorState {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ErrorState {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        self.base
+    }
+}
@@ -0,0 +1,240 @@
+//! The wrappers of the native open / save file dialogs with the file type filters.
+//!
+//! ```ignore
+//! use gpui_component::file_dialog::{FileFilter, OpenDialog};
+//!
+//! let task = OpenDialog::new()
+//!     .multiple(true)
+//!     .filter(FileFilter::new("Rust", ["rs"]))
+//!     .open(cx);
+//!
+//! cx.spawn(async move |_| {
+//!     if let Ok(Some(paths)) = task.await {
+//!         // ...
+//!     }
+//! })
+//! .detach();
+//! ```
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use gpui::{App, PathPromptOptions, SharedString, Task};
+
+/// A file type filter of the [`OpenDialog`] and [`SaveDialog`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileFilter {
+    pub name: SharedString,
+    /// The extensions without the leading dot, e.g.: `["rs", "toml"]`.
+    pub extensions: Vec<SharedString>,
+}
+
+impl FileFilter {
+    pub fn new(
+        name: impl Into<SharedString>,
+        extensions: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            extensions: extensions.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns true if the extension of the `path` matches the filter (case insensitive).
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+
+        self.extensions
+            .iter()
+            .any(|filter_ext| filter_ext.eq_ignore_ascii_case(ext))
+    }
+}
+
+/// Returns true if the `path` matches any of the `filters`, or the `filters` is empty.
+fn matches_filters(filters: &[FileFilter], path: &Path) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| filter.matches(path))
+}
+
+/// A native dialog to pick the files or directories to open.
+#[derive(Debug, Clone)]
+pub struct OpenDialog {
+    files: bool,
+    directories: bool,
+    multiple: bool,
+    prompt: Option<SharedString>,
+    filters: Vec<FileFilter>,
+}
+
+impl Default for OpenDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenDialog {
+    /// Create a new dialog to pick a single file.
+    pub fn new() -> Self {
+        Self {
+            files: true,
+            directories: false,
+            multiple: false,
+            prompt: None,
+            filters: vec![],
+        }
+    }
+
+    /// Set to allow picking the files, default is true.
+    pub fn files(mut self, files: bool) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Set to allow picking the directories, default is false.
+    pub fn directories(mut self, directories: bool) -> Self {
+        self.directories = directories;
+        self
+    }
+
+    /// Set to allow picking multiple paths, default is false.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the text of the confirm button.
+    pub fn prompt(mut self, prompt: impl Into<SharedString>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Add a file type filter.
+    ///
+    /// The native dialogs of GPUI can not filter the files, so the picked files
+    /// not matching any of the filters are removed from the result.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Show the dialog, the result is None if the dialog is canceled or no path matches the filters.
+    pub fn open(self, cx: &mut App) -> Task<Result<Option<Vec<PathBuf>>>> {
+        let rx = cx.prompt_for_paths(PathPromptOptions {
+            files: self.files,
+            directories: self.directories,
+            multiple: self.multiple,
+            prompt: self.prompt,
+        });
+        let filters = self.filters;
+
+        cx.spawn(async move |_| {
+            let Some(paths) = rx.await?? else {
+                return Ok(None);
+            };
+
+            let paths = paths
+                .into_iter()
+                .filter(|path| path.is_dir() || matches_filters(&filters, path))
+                .collect::<Vec<_>>();
+            Ok((!paths.is_empty()).then_some(paths))
+        })
+    }
+}
+
+/// A native dialog to pick a new path to save the file.
+#[derive(Debug, Clone)]
+pub struct SaveDialog {
+    directory: PathBuf,
+    suggested_name: Option<SharedString>,
+    filters: Vec<FileFilter>,
+}
+
+impl SaveDialog {
+    /// Create a new dialog starting at the `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            suggested_name: None,
+            filters: vec![],
+        }
+    }
+
+    /// Set the suggested file name.
+    pub fn suggested_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.suggested_name = Some(name.into());
+        self
+    }
+
+    /// Add a file type filter.
+    ///
+    /// The first extension of the first filter is appended to the picked path,
+    /// if it does not match any of the filters.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Show the dialog, the result is None if the dialog is canceled.
+    pub fn save(self, cx: &mut App) -> Task<Result<Option<PathBuf>>> {
+        let rx = cx.prompt_for_new_path(
+            &self.directory,
+            self.suggested_name.as_ref().map(|name| name.as_ref()),
+        );
+        let filters = self.filters;
+
+        cx.spawn(async move |_| {
+            let path = rx.await??;
+            Ok(path.map(|path| with_default_extension(&filters, path)))
+        })
+    }
+}
+
+/// Append the default extension of the `filters` to the `path` if it does not match any filter.
+fn with_default_extension(filters: &[FileFilter], mut path: PathBuf) -> PathBuf {
+    if matches_filters(filters, &path) {
+        return path;
+    }
+
+    let Some(ext) = filters.first().and_then(|filter| filter.extensions.first()) else {
+        return path;
+    };
+
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(ext.as_ref());
+    path.set_file_name(file_name);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{matches_filters, with_default_extension, FileFilter};
+
+    #[test]
+    fn test_file_filter() {
+        let filters = vec![
+            FileFilter::new("Rust", ["rs"]),
+            FileFilter::new("Image", ["png", "jpg"]),
+        ];
+        assert!(matches_filters(&filters, Path::new("src/main.rs")));
+        assert!(matches_filters(&filters, Path::new("a/Photo.JPG")));
+        assert!(!matches_filters(&filters, Path::new("README.md")));
+        assert!(!matches_filters(&filters, Path::new("Makefile")));
+        assert!(matches_filters(&[], Path::new("Makefile")));
+
+        assert_eq!(
+            with_default_extension(&filters, PathBuf::from("a/main")),
+            PathBuf::from("a/main.rs")
+        );
+        assert_eq!(
+            with_default_extension(&filters, PathBuf::from("a/logo.png")),
+            PathBuf::from("a/logo.png")
+        );
+        assert_eq!(
+            with_default_extension(&[], PathBuf::from("a/main")),
+            PathBuf::from("a/main")
+        );
+    }
+}
@@ -0,0 +1,858 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, uniform_list, App, AppContext as _, ClickEvent,
+    Context, Entity, EventEmitter, FocusHandle, Focusable, InteractiveElement, IntoElement,
+    KeyBinding, MouseButton, ParentElement, Render, ScrollStrategy, SharedString,
+    StatefulInteractiveElement as _, Styled, Subscription, UniformListScrollHandle, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::{Confirm, SelectLeft, SelectNext, SelectPrev, SelectRight},
+    context_menu::ContextMenuExt as _,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    popup_menu::{PopupMenu, PopupMenuItem},
+    scroll::{Scrollbar, ScrollbarState},
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _,
+};
+
+actions!(file_tree, [Rename, Delete]);
+
+const CONTEXT: &str = "FileTree";
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm { secondary: false }, Some(CONTEXT)),
+        KeyBinding::new("f2", Rename, Some(CONTEXT)),
+        KeyBinding::new("delete", Delete, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-backspace", Delete, Some(CONTEXT)),
+    ]);
+}
+
+/// A file or directory in the [`FileTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    pub path: PathBuf,
+    pub name: SharedString,
+    pub is_dir: bool,
+}
+
+impl FileEntry {
+    pub fn new(path: impl Into<PathBuf>, is_dir: bool) -> Self {
+        let path = path.into();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            name: name.into(),
+            is_dir,
+        }
+    }
+}
+
+/// The events of the [`FileTree`], the tree does not change the file system by itself,
+/// handle the events and call [`FileTree::refresh`] to reload the changed directories.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileTreeEvent {
+    /// The selected paths are changed.
+    Select(Vec<PathBuf>),
+    /// A file is opened by double click or pressing Enter.
+    Open(PathBuf),
+    /// Request to create a new file or directory in the `parent` directory.
+    Create { parent: PathBuf, is_dir: bool },
+    /// Request to rename the `path` to the `new_name` in the same directory.
+    Rename {
+        path: PathBuf,
+        new_name: SharedString,
+    },
+    /// Request to delete the paths.
+    Delete(Vec<PathBuf>),
+}
+
+/// The drag value of the paths dragged out of the [`FileTree`], also used as the drag preview.
+///
+/// Use `on_drop` with this type to accept the paths in the other views.
+#[derive(Debug, Clone)]
+pub struct DraggedPaths {
+    paths: Vec<PathBuf>,
+}
+
+impl DraggedPaths {
+    /// Returns the dragged paths.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+}
+
+impl Render for DraggedPaths {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let label: SharedString = match self.paths.as_slice() {
+            [path] => path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+                .into(),
+            paths => t!("FileTree.items", count = paths.len()).into(),
+        };
+
+        h_flex()
+            .id("dragged-paths")
+            .cursor_grabbing()
+            .gap_1()
+            .py_1()
+            .px_2()
+            .text_sm()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .shadow_md()
+            .bg(cx.theme().background)
+            .text_color(cx.theme().foreground)
+            .child(Icon::new(IconName::File).small())
+            .child(label)
+    }
+}
+
+/// An entry in the flattened visible rows of the tree.
+#[derive(Debug, Clone, PartialEq)]
+struct VisibleEntry {
+    entry: FileEntry,
+    depth: usize,
+}
+
+/// A file tree browser for the project sidebar of the IDE-style apps.
+///
+/// The directories are loaded lazily when expanded, use the `secondary` (`cmd` on macOS,
+/// `ctrl` on others) click to toggle the selection, and `shift` click to select a range.
+/// The selected files can be dragged out as [`DraggedPaths`].
+pub struct FileTree {
+    focus_handle: FocusHandle,
+    root: PathBuf,
+    show_hidden: bool,
+    /// The loaded children of the directories.
+    children: HashMap<PathBuf, Vec<FileEntry>>,
+    expanded: HashSet<PathBuf>,
+    loading: HashSet<PathBuf>,
+    entries: Vec<VisibleEntry>,
+    selected: Vec<PathBuf>,
+    /// The index of the entry to start the range selection, also moved by the keyboard.
+    anchor_ix: Option<usize>,
+    right_clicked_ix: Option<usize>,
+    renaming: Option<(PathBuf, Entity<InputState>)>,
+    scroll_handle: UniformListScrollHandle,
+    scroll_state: ScrollbarState,
+    _rename_subscription: Option<Subscription>,
+}
+
+impl EventEmitter<FileTreeEvent> for FileTree {}
+
+impl Focusable for FileTree {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl FileTree {
+    /// Create a new file tree of the `root` directory.
+    pub fn new(root: impl Into<PathBuf>, _: &mut Window, cx: &mut Context<Self>) -> Self {
+        let root = root.into();
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            root: root.clone(),
+            show_hidden: false,
+            children: HashMap::new(),
+            expanded: HashSet::new(),
+            loading: HashSet::new(),
+            entries: vec![],
+            selected: vec![],
+            anchor_ix: None,
+            right_clicked_ix: None,
+            renaming: None,
+            scroll_handle: UniformListScrollHandle::default(),
+            scroll_state: ScrollbarState::default(),
+            _rename_subscription: None,
+        };
+        this.load_dir(root, cx);
+        this
+    }
+
+    /// Set to show the hidden files (starts with `.`), default is false.
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self.update_entries();
+        self
+    }
+
+    /// Returns the root directory of the tree.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Change the root directory, all the loaded directories are cleared.
+    pub fn set_root(&mut self, root: impl Into<PathBuf>, _: &mut Window, cx: &mut Context<Self>) {
+        self.root = root.into();
+        self.children.clear();
+        self.expanded.clear();
+        self.loading.clear();
+        self.selected.clear();
+        self.anchor_ix = None;
+        self.renaming = None;
+        self.update_entries();
+        self.load_dir(self.root.clone(), cx);
+        cx.notify();
+    }
+
+    /// Returns the selected paths in the selection order.
+    pub fn selected_paths(&self) -> &[PathBuf] {
+        &self.selected
+    }
+
+    /// Reload the children of the `dir`, call this after the directory is changed.
+    pub fn refresh(&mut self, dir: impl AsRef<Path>, cx: &mut Context<Self>) {
+        let dir = dir.as_ref();
+        if dir == self.root || self.children.contains_key(dir) {
+            self.load_dir(dir.to_path_buf(), cx);
+        }
+    }
+
+    /// Expand or collapse the directory, the children are loaded on the first expanding.
+    pub fn set_expanded(&mut self, dir: impl AsRef<Path>, expanded: bool, cx: &mut Context<Self>) {
+        let dir = dir.as_ref();
+        if expanded {
+            if !self.expanded.insert(dir.to_path_buf()) {
+                return;
+            }
+            if !self.children.contains_key(dir) {
+                self.load_dir(dir.to_path_buf(), cx);
+            }
+        } else if !self.expanded.remove(dir) {
+            return;
+        }
+
+        self.update_entries();
+        cx.notify();
+    }
+
+    fn load_dir(&mut self, dir: PathBuf, cx: &mut Context<Self>) {
+        if !self.loading.insert(dir.clone()) {
+            return;
+        }
+
+        let task = cx.background_executor().spawn({
+            let dir = dir.clone();
+            async move { read_entries(&dir) }
+        });
+
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+            _ = this.update(cx, |this, cx| {
+                this.loading.remove(&dir);
+                let entries = result.unwrap_or_else(|err| {
+                    tracing::error!("failed to read dir {:?}: {:?}", dir, err);
+                    vec![]
+                });
+                this.children.insert(dir, entries);
+                this.update_entries();
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn update_entries(&mut self) {
+        let selected_path = self
+            .anchor_ix
+            .and_then(|ix| self.entries.get(ix))
+            .map(|entry| entry.entry.path.clone());
+
+        self.entries =
+            flatten_entries(&self.root, &self.children, &self.expanded, self.show_hidden);
+
+        // Keep the anchor on the same path, and remove the selected paths no longer visible.
+        self.anchor_ix =
+            selected_path.and_then(|path| self.entries.iter().position(|e| e.entry.path == path));
+        let visible = self
+            .entries
+            .iter()
+            .map(|e| &e.entry.path)
+            .collect::<HashSet<_>>();
+        self.selected.retain(|path| visible.contains(path));
+    }
+
+    fn select(&mut self, ix: usize, extend: bool, toggle: bool, cx: &mut Context<Self>) {
+        let Some(entry) = self.entries.get(ix) else {
+            return;
+        };
+        let path = entry.entry.path.clone();
+
+        if extend {
+            let anchor_ix = self.anchor_ix.unwrap_or(ix);
+            self.selected = self.entries[anchor_ix.min(ix)..=anchor_ix.max(ix)]
+                .iter()
+                .map(|e| e.entry.path.clone())
+                .collect();
+        } else if toggle {
+            if let Some(pos) = self.selected.iter().position(|p| p == &path) {
+                self.selected.remove(pos);
+            } else {
+                self.selected.push(path);
+            }
+            self.anchor_ix = Some(ix);
+        } else {
+            self.selected = vec![path];
+            self.anchor_ix = Some(ix);
+        }
+
+        self.scroll_handle
+            .scroll_to_item(ix, ScrollStrategy::Center);
+        cx.emit(FileTreeEvent::Select(self.selected.clone()));
+        cx.notify();
+    }
+
+    fn open(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(entry) = self.entries.get(ix).map(|e| e.entry.clone()) else {
+            return;
+        };
+
+        if entry.is_dir {
+            let expanded = self.expanded.contains(&entry.path);
+            self.set_expanded(&entry.path, !expanded, cx);
+        } else {
+            cx.emit(FileTreeEvent::Open(entry.path));
+        }
+    }
+
+    /// Returns the directory to create the new entry in, the right clicked or selected directory,
+    /// or the parent of the file, default is the root.
+    fn target_dir(&self) -> PathBuf {
+        self.right_clicked_ix
+            .or(self.anchor_ix)
+            .and_then(|ix| self.entries.get(ix))
+            .map(|e| match e.entry.is_dir {
+                true => e.entry.path.clone(),
+                false => e
+                    .entry
+                    .path
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|| self.root.clone()),
+            })
+            .unwrap_or_else(|| self.root.clone())
+    }
+
+    fn create(&mut self, is_dir: bool, cx: &mut Context<Self>) {
+        let parent = self.target_dir();
+        self.set_expanded(&parent, true, cx);
+        cx.emit(FileTreeEvent::Create { parent, is_dir });
+    }
+
+    fn start_rename(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(entry) = self
+            .right_clicked_ix
+            .or(self.anchor_ix)
+            .and_then(|ix| self.entries.get(ix))
+            .map(|e| e.entry.clone())
+        else {
+            return;
+        };
+
+        let input = cx.new(|cx| InputState::new(window, cx).default_value(entry.name.clone()));
+        input.update(cx, |input, cx| input.focus(window, cx));
+        self._rename_subscription = Some(cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &InputEvent, window, cx| match event {
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {
+                    let new_name = input.read(cx).value().trim().to_string();
+                    this.finish_rename(new_name, window, cx);
+                }
+                _ => {}
+            },
+        ));
+        self.renaming = Some((entry.path, input));
+        cx.notify();
+    }
+
+    fn finish_rename(&mut self, new_name: String, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((path, _)) = self.renaming.take() else {
+            return;
+        };
+        self._rename_subscription = None;
+        self.focus_handle.focus(window);
+
+        let changed = path
+            .file_name()
+            .is_some_and(|name| name != new_name.as_str());
+        if !new_name.is_empty() && changed {
+            cx.emit(FileTreeEvent::Rename {
+                path,
+                new_name: new_name.into(),
+            });
+        }
+        cx.notify();
+    }
+
+    fn delete(&mut self, cx: &mut Context<Self>) {
+        let paths = match self.right_clicked_ix.and_then(|ix| self.entries.get(ix)) {
+            Some(e) if !self.selected.contains(&e.entry.path) => vec![e.entry.path.clone()],
+            _ => self.selected.clone(),
+        };
+
+        if !paths.is_empty() {
+            cx.emit(FileTreeEvent::Delete(paths));
+        }
+    }
+
+    fn action_select_prev(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
+        let ix = match self.anchor_ix {
+            Some(ix) => ix.saturating_sub(1),
+            None => self.entries.len().saturating_sub(1),
+        };
+        self.select(ix, false, false, cx);
+    }
+
+    fn action_select_next(&mut self, _: &SelectNext, _: &mut Window, cx: &mut Context<Self>) {
+        let ix = match self.anchor_ix {
+            Some(ix) => (ix + 1).min(self.entries.len().saturating_sub(1)),
+            None => 0,
+        };
+        self.select(ix, false, false, cx);
+    }
+
+    fn action_select_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
+        let rtl = cx.theme().direction.is_rtl();
+        self.expand_or_collapse(rtl, cx);
+    }
+
+    fn action_select_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
+        let rtl = cx.theme().direction.is_rtl();
+        self.expand_or_collapse(!rtl, cx);
+    }
+
+    /// Expand the selected directory, or collapse it (or move to its parent if collapsed).
+    fn expand_or_collapse(&mut self, expand: bool, cx: &mut Context<Self>) {
+        let Some(ix) = self.anchor_ix else {
+            return;
+        };
+        let Some(entry) = self.entries.get(ix).cloned() else {
+            return;
+        };
+
+        let expanded = self.expanded.contains(&entry.entry.path);
+        if entry.entry.is_dir && expanded != expand {
+            self.set_expanded(&entry.entry.path, expand, cx);
+            return;
+        }
+
+        if !expand {
+            if let Some(parent_ix) = self.entries[..ix]
+                .iter()
+                .rposition(|e| e.depth + 1 == entry.depth)
+            {
+                self.select(parent_ix, false, false, cx);
+            }
+        }
+    }
+
+    fn action_confirm(&mut self, _: &Confirm, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(ix) = self.anchor_ix {
+            self.open(ix, cx);
+        }
+    }
+
+    fn action_rename(&mut self, _: &Rename, window: &mut Window, cx: &mut Context<Self>) {
+        self.right_clicked_ix = None;
+        self.start_rename(window, cx);
+    }
+
+    fn action_delete(&mut self, _: &Delete, _: &mut Window, cx: &mut Context<Self>) {
+        self.right_clicked_ix = None;
+        self.delete(cx);
+    }
+
+    fn on_entry_click(
+        &mut self,
+        ix: usize,
+        event: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_handle.focus(window);
+        let modifiers = event.modifiers();
+        self.select(ix, modifiers.shift, modifiers.secondary(), cx);
+
+        // Toggle the directory by single click, open the file by double click.
+        let is_dir = self.entries.get(ix).is_some_and(|e| e.entry.is_dir);
+        let click_count = event.click_count();
+        if !modifiers.modified() && (is_dir == (click_count == 1)) {
+            self.open(ix, cx);
+        }
+    }
+
+    fn render_entry(&self, ix: usize, cx: &mut Context<Self>) -> impl IntoElement {
+        let entry = &self.entries[ix];
+        let path = &entry.entry.path;
+        let selected = self.selected.contains(path);
+        let expanded = self.expanded.contains(path);
+        let direction = cx.theme().direction;
+
+        let icon = if entry.entry.is_dir {
+            match expanded {
+                true => IconName::FolderOpen,
+                false => IconName::FolderClosed,
+            }
+        } else {
+            file_icon(path)
+        };
+
+        let chevron = div()
+            .flex_shrink_0()
+            .size_4()
+            .when(entry.entry.is_dir, |this| {
+                this.child(
+                    Icon::new(match expanded {
+                        true => IconName::ChevronDown,
+                        false => IconName::ChevronRight.mirrored(direction),
+                    })
+                    .small()
+                    .text_color(cx.theme().muted_foreground),
+                )
+            });
+
+        let label = match &self.renaming {
+            Some((renaming_path, input)) if renaming_path == path => {
+                TextInput::new(input).xsmall().into_any_element()
+            }
+            _ => div()
+                .flex_1()
+                .overflow_hidden()
+                .text_ellipsis()
+                .whitespace_nowrap()
+                .child(entry.entry.name.clone())
+                .into_any_element(),
+        };
+
+        let dragged_paths = if selected {
+            self.selected.clone()
+        } else {
+            vec![path.clone()]
+        };
+
+        h_flex()
+            .id(ix)
+            .w_full()
+            .h_7()
+            .gap_1()
+            .pl(px(8.) + px(12.) * entry.depth as f32)
+            .pr_2()
+            .text_sm()
+            .border_1()
+            .border_color(gpui::transparent_black())
+            .rounded(cx.theme().radius)
+            .when(selected, |this| {
+                this.bg(cx.theme().list_active)
+                    .border_color(cx.theme().list_active_border)
+            })
+            .when(!selected, |this| {
+                this.hover(|this| this.bg(cx.theme().list_hover))
+            })
+            .child(chevron)
+            .child(
+                Icon::new(icon)
+                    .small()
+                    .flex_shrink_0()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(label)
+            .on_mouse_down(
+                MouseButton::Right,
+                cx.listener(move |this, _, _, cx| {
+                    this.right_clicked_ix = Some(ix);
+                    cx.notify();
+                }),
+            )
+            .on_click(cx.listener(move |this, event, window, cx| {
+                this.on_entry_click(ix, event, window, cx);
+            }))
+            .on_drag(
+                DraggedPaths {
+                    paths: dragged_paths,
+                },
+                |drag, _, _, cx| {
+                    cx.stop_propagation();
+                    cx.new(|_| drag.clone())
+                },
+            )
+    }
+}
+
+/// Read the children of the `dir`, sorted by [`sort_entries`].
+fn read_entries(dir: &Path) -> io::Result<Vec<FileEntry>> {
+    let mut entries = vec![];
+    for item in std::fs::read_dir(dir)? {
+        let item = item?;
+        // Follow the symlinks to show the linked directories as directories.
+        let is_dir = item.path().is_dir();
+        entries.push(FileEntry::new(item.path(), is_dir));
+    }
+
+    sort_entries(&mut entries);
+    Ok(entries)
+}
+
+/// Sort the directories first, then by the name (case insensitive).
+fn sort_entries(entries: &mut [FileEntry]) {
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+}
+
+/// Flatten the loaded children of the expanded directories under the `root` into visible rows.
+fn flatten_entries(
+    root: &Path,
+    children: &HashMap<PathBuf, Vec<FileEntry>>,
+    expanded: &HashSet<PathBuf>,
+    show_hidden: bool,
+) -> Vec<VisibleEntry> {
+    fn walk(
+        dir: &Path,
+        depth: usize,
+        children: &HashMap<PathBuf, Vec<FileEntry>>,
+        expanded: &HashSet<PathBuf>,
+        show_hidden: bool,
+        entries: &mut Vec<VisibleEntry>,
+    ) {
+        let Some(items) = children.get(dir) else {
+            return;
+        };
+
+        for item in items {
+            if !show_hidden && item.name.starts_with('.') {
+                continue;
+            }
+
+            entries.push(VisibleEntry {
+                entry: item.clone(),
+                depth,
+            });
+            if item.is_dir && expanded.contains(&item.path) {
+                walk(
+                    &item.path,
+                    depth + 1,
+                    children,
+                    expanded,
+                    show_hidden,
+                    entries,
+                );
+            }
+        }
+    }
+
+    let mut entries = vec![];
+    walk(root, 0, children, expanded, show_hidden, &mut entries);
+    entries
+}
+
+/// Returns the icon of the file by the extension.
+fn file_icon(path: &Path) -> IconName {
+    let ext = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "cs" | "go" | "java" | "kt" | "swift" | "py"
+        | "rb" | "php" | "lua" | "zig" | "js" | "jsx" | "ts" | "tsx" | "html" | "css" | "scss"
+        | "json" | "toml" | "yaml" | "yml" | "xml" | "sh" | "sql" => IconName::FileCode,
+        "md" | "mdx" | "txt" | "rst" | "log" | "csv" => IconName::FileText,
+        "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "svg" | "ico" => IconName::FileImage,
+        _ => IconName::File,
+    }
+}
+
+impl Render for FileTree {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity();
+
+        v_flex()
+            .id("file-tree")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::action_select_prev))
+            .on_action(cx.listener(Self::action_select_next))
+            .on_action(cx.listener(Self::action_select_left))
+            .on_action(cx.listener(Self::action_select_right))
+            .on_action(cx.listener(Self::action_confirm))
+            .on_action(cx.listener(Self::action_rename))
+            .on_action(cx.listener(Self::action_delete))
+            .relative()
+            .size_full()
+            .child(
+                uniform_list(
+                    "entries",
+                    self.entries.len(),
+                    cx.processor(move |this, range: Range<usize>, _, cx| {
+                        range
+                            .map(|ix| this.render_entry(ix, cx).into_any_element())
+                            .collect::<Vec<_>>()
+                    }),
+                )
+                .flex_1()
+                .p_1()
+                .track_scroll(self.scroll_handle.clone()),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .right_0()
+                    .bottom_0()
+                    .w(crate::scroll::width(cx))
+                    .child(Scrollbar::uniform_scroll(
+                        &self.scroll_state,
+                        &self.scroll_handle,
+                    )),
+            )
+            // Reset before the entry handles the right click in the bubble phase.
+            .capture_any_mouse_down(cx.listener(|this, _, _, _| {
+                this.right_clicked_ix = None;
+            }))
+            .context_menu(move |menu: PopupMenu, _, cx| {
+                let tree = view.read(cx);
+                let has_target = tree.right_clicked_ix.is_some() || !tree.selected.is_empty();
+
+                let item = |label: SharedString, f: fn(&mut FileTree, &mut Window, &mut Context<FileTree>)| {
+                    let view = view.clone();
+                    PopupMenuItem::new(label).on_click(move |window, cx| {
+                        view.update(cx, |this, cx| f(this, window, cx));
+                    })
+                };
+
+                menu.item(
+                    item(t!("FileTree.new_file").into(), |this, _, cx| {
+                        this.create(false, cx)
+                    })
+                    .icon(IconName::File),
+                )
+                .item(
+                    item(t!("FileTree.new_folder").into(), |this, _, cx| {
+                        this.create(true, cx)
+                    })
+                    .icon(IconName::FolderClosed),
+                )
+                .separator()
+                .item(
+                    item(t!("FileTree.rename").into(), |this, window, cx| {
+                        this.start_rename(window, cx)
+                    })
+                    .disabled(!has_target),
+                )
+                .item(
+                    item(t!("FileTree.delete").into(), |this, _, cx| this.delete(cx))
+                        .danger(true)
+                        .disabled(!has_target),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+    };
+
+    use super::{file_icon, flatten_entries, sort_entries, FileEntry};
+    use crate::IconName;
+
+    #[test]
+    fn test_sort_entries() {
+        let mut entries = vec![
+            FileEntry::new("/a/b.rs", false),
+            FileEntry::new("/a/src", true),
+            FileEntry::new("/a/A.md", false),
+            FileEntry::new("/a/assets", true),
+        ];
+        sort_entries(&mut entries);
+        let names = entries.iter().map(|e| e.name.as_ref()).collect::<Vec<_>>();
+        assert_eq!(names, ["assets", "src", "A.md", "b.rs"]);
+    }
+
+    #[test]
+    fn test_flatten_entries() {
+        let root = PathBuf::from("/a");
+        let mut children = HashMap::new();
+        children.insert(
+            root.clone(),
+            vec![
+                FileEntry::new("/a/src", true),
+                FileEntry::new("/a/tests", true),
+                FileEntry::new("/a/Cargo.toml", false),
+                FileEntry::new("/a/.gitignore", false),
+            ],
+        );
+        children.insert(
+            PathBuf::from("/a/src"),
+            vec![FileEntry::new("/a/src/lib.rs", false)],
+        );
+
+        let mut expanded = HashSet::new();
+        let names = |expanded: &HashSet<PathBuf>| {
+            flatten_entries(&root, &children, expanded, false)
+                .into_iter()
+                .map(|e| (e.entry.name.to_string(), e.depth))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&expanded).len(), 3);
+        assert_eq!(flatten_entries(&root, &children, &expanded, true).len(), 4);
+
+        // The expanded but not loaded directory has no children.
+        expanded.insert(PathBuf::from("/a/src"));
+        expanded.insert(PathBuf::from("/a/tests"));
+        assert_eq!(
+            names(&expanded),
+            [
+                ("src".to_string(), 0),
+                ("lib.rs".to_string(), 1),
+                ("tests".to_string(), 0),
+                ("Cargo.toml".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_icon() {
+        assert!(matches!(
+            file_icon(Path::new("src/main.rs")),
+            IconName::FileCode
+        ));
+        assert!(matches!(
+            file_icon(Path::new("README.MD")),
+            IconName::FileText
+        ));
+        assert!(matches!(
+            file_icon(Path::new("logo.png")),
+            IconName::FileImage
+        ));
+        assert!(matches!(file_icon(Path::new("LICENSE")), IconName::File));
+    }
+}
@@ -0,0 +1,55 @@
+use gpui::{App, FocusHandle, InteractiveElement, Window};
+
+use crate::root::{Tab, TabPrev};
+
+/// The max number of tab stops to try for moving the focus into the trap.
+const MAX_TAB_STOPS: usize = 256;
+
+/// Extension trait to trap the Tab / Shift+Tab focus navigation in an element.
+///
+/// This is used by the [`crate::modal::Modal`], [`crate::drawer::Drawer`] to keep
+/// the focus inside the dialog, the focus is wrapped around at the first and the last tab stop.
+///
+/// The element should use `tab_group` to keep the tab stops in it together.
+pub trait FocusTrapElement: InteractiveElement + Sized {
+    /// Trap the focus navigation in the element, the `focus_handle` must be tracked by this element.
+    fn focus_trap(self, focus_handle: &FocusHandle) -> Self {
+        let next_handle = focus_handle.clone();
+        let prev_handle = focus_handle.clone();
+
+        self.on_action(move |_: &Tab, window, cx| {
+            move_focus_in_trap(&next_handle, true, window, cx);
+        })
+        .on_action(move |_: &TabPrev, window, cx| {
+            move_focus_in_trap(&prev_handle, false, window, cx);
+        })
+    }
+}
+
+impl<T: InteractiveElement> FocusTrapElement for T {}
+
+/// Move the focus to the next (or previous) tab stop inside the `trap`.
+///
+/// If there is no tab stop in the trap, the trap itself will be focused.
+fn move_focus_in_trap(trap: &FocusHandle, forward: bool, window: &mut Window, cx: &mut App) {
+    let start = window.focused(cx);
+
+    for _ in 0..MAX_TAB_STOPS {
+        if forward {
+            window.focus_next();
+        } else {
+            window.focus_prev();
+        }
+
+        let focused = window.focused(cx);
+        if trap.contains_focused(window, cx) && focused.as_ref() != Some(trap) {
+            return;
+        }
+        // Back to the start, there is no other tab stop.
+        if focused.is_none() || focused == start {
+            break;
+        }
+    }
+
+    trap.focus(window);
+}
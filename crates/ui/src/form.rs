@@ -2,11 +2,84 @@ use std::rc::{Rc, Weak};
 
 use gpui::{
     div, prelude::FluentBuilder as _, px, AlignItems, AnyElement, AnyView, App, Axis, Div, Element,
-    ElementId, FocusHandle, InteractiveElement as _, IntoElement, ParentElement, Pixels, Rems,
-    RenderOnce, SharedString, Styled, Window,
+    ElementId, Entity, FocusHandle, InteractiveElement as _, IntoElement, ParentElement, Pixels,
+    Rems, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, Task, Window,
 };
 
-use crate::{h_flex, v_flex, ActiveTheme as _, AxisExt, Sizable, Size, StyledExt};
+use crate::{
+    accessibility::{Accessibility, AccessibilityProps, Politeness, Role},
+    h_flex, v_flex, ActiveTheme as _, AxisExt, Sizable, Size, StyledExt,
+};
+
+/// The change payload of the checkable controls, e.g.: [`crate::checkbox::Checkbox`],
+/// [`crate::switch::Switch`], [`crate::radio::Radio`] and [`crate::radio::RadioGroup`].
+///
+/// The `name` and `value` are the form metadata set on the control, so the same handler
+/// can be used to collect the values of the different controls in a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The name of the control, for the RadioGroup, it is the name of the group.
+    pub name: Option<SharedString>,
+    /// The value of the control, for the RadioGroup, it is the value of the selected radio.
+    pub value: Option<SharedString>,
+    /// The new checked state.
+    pub checked: bool,
+}
+
+/// The guard to confirm the change of the checkable controls before it is applied,
+/// the change is vetoed if the task resolves `false`.
+pub(crate) type BeforeChange = Rc<dyn Fn(&bool, &mut Window, &mut App) -> Task<bool>>;
+
+/// Returns the pending state of the [`BeforeChange`] guard of the control `id`.
+pub(crate) fn before_change_pending(
+    id: &ElementId,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<bool> {
+    window.use_keyed_state(
+        ElementId::NamedChild(Box::new(id.clone()), "pending".into()),
+        cx,
+        |_, _| false,
+    )
+}
+
+/// Apply the `change` if the guard resolves `true`, or apply it immediately without the guard.
+///
+/// The pending state of the guard is true until it is resolved, the controls show the loading
+/// state and ignore the clicks in the meantime.
+pub(crate) fn apply_change(
+    change: FieldChange,
+    guard: Option<(BeforeChange, Entity<bool>)>,
+    apply: impl FnOnce(&FieldChange, &mut Window, &mut App) + 'static,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let Some((before_change, pending)) = guard else {
+        apply(&change, window, cx);
+        return;
+    };
+    if *pending.read(cx) {
+        return;
+    }
+
+    let task = before_change(&change.checked, window, cx);
+    pending.update(cx, |pending, cx| {
+        *pending = true;
+        cx.notify();
+    });
+    window
+        .spawn(cx, async move |cx| {
+            let allowed = task.await;
+            _ = pending.update(cx, |pending, cx| {
+                *pending = false;
+                cx.notify();
+            });
+            if allowed {
+                _ = cx.update(|window, cx| apply(&change, window, cx));
+            }
+        })
+        .detach();
+}
 
 /// Create a new form with a vertical layout.
 pub fn v_form() -> Form {
@@ -151,6 +224,16 @@ impl RenderOnce for FieldBuilder {
     }
 }
 
+impl FieldBuilder {
+    /// Returns the plain string if this is `String`.
+    fn as_str(&self) -> Option<&SharedString> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
 impl From<&'static str> for FieldBuilder {
     fn from(value: &'static str) -> Self {
         Self::String(value.into())
@@ -172,11 +255,16 @@ impl From<SharedString> for FieldBuilder {
 #[derive(IntoElement)]
 pub struct FormField {
     id: ElementId,
+    /// Whether the `id` is set by user, otherwise it is the index in the Form.
+    custom_id: bool,
     form: Weak<Form>,
     label: Option<FieldBuilder>,
     no_label_indent: bool,
     focus_handle: Option<FocusHandle>,
     description: Option<FieldBuilder>,
+    error: Option<FieldBuilder>,
+    labelled_by: Option<ElementId>,
+    described_by: Option<ElementId>,
     /// Used to render the actual form field, e.g.: TextInput, Switch...
     child: Div,
     visible: bool,
@@ -193,9 +281,13 @@ impl FormField {
     pub fn new() -> Self {
         Self {
             id: 0.into(),
+            custom_id: false,
             form: Weak::new(),
             label: None,
             description: None,
+            error: None,
+            labelled_by: None,
+            described_by: None,
             child: div(),
             visible: true,
             required: false,
@@ -209,6 +301,47 @@ impl FormField {
         }
     }
 
+    /// Set the id of the form field, default is the index in the Form.
+    ///
+    /// The ids of the label and description are derived from it, see [`FormField::label_id`].
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self.custom_id = true;
+        self
+    }
+
+    /// Set the element id of the label, so the control can be associated with the label by the id.
+    ///
+    /// Default is derived from the field id.
+    pub fn labelled_by(mut self, id: impl Into<ElementId>) -> Self {
+        self.labelled_by = Some(id.into());
+        self
+    }
+
+    /// Set the element id of the description and error message,
+    /// so the control can be associated with them by the id.
+    ///
+    /// Default is derived from the field id.
+    pub fn described_by(mut self, id: impl Into<ElementId>) -> Self {
+        self.described_by = Some(id.into());
+        self
+    }
+
+    /// Returns the element id of the label.
+    pub fn label_id(&self) -> ElementId {
+        self.labelled_by
+            .clone()
+            .unwrap_or_else(|| ElementId::NamedChild(Box::new(self.id.clone()), "label".into()))
+    }
+
+    /// Returns the element id of the description, the error message is a child of it
+    /// with the `error` name.
+    pub fn description_id(&self) -> ElementId {
+        self.described_by.clone().unwrap_or_else(|| {
+            ElementId::NamedChild(Box::new(self.id.clone()), "description".into())
+        })
+    }
+
     /// Sets the label for the form field.
     pub fn label(mut self, label: impl Into<FieldBuilder>) -> Self {
         self.label = Some(label.into());
@@ -255,6 +388,14 @@ impl FormField {
         self
     }
 
+    /// Sets the error message for the form field, it is rendered below the description.
+    ///
+    /// Use this to show the validation result, e.g.: nothing is selected in a required RadioGroup.
+    pub fn error(mut self, error: impl Into<FieldBuilder>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
     /// Set the visibility of the form field, default is `true`.
     pub fn visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -270,6 +411,8 @@ impl FormField {
     /// Set the focus handle for the form field.
     ///
     /// If not set, the form field will not be focusable.
+    ///
+    /// When set, click on the label will focus the control.
     pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
         self.focus_handle = Some(focus_handle.clone());
         self
@@ -284,7 +427,9 @@ impl FormField {
     ///
     /// This is internal API for sync props from From.
     fn props(mut self, ix: usize, props: FieldProps) -> Self {
-        self.id = ix.into();
+        if !self.custom_id {
+            self.id = ix.into();
+        }
         self.props = props;
         self
     }
@@ -333,6 +478,37 @@ impl ParentElement for FormField {
     }
 }
 
+impl FormField {
+    /// Declare the field as an accessible group labelled by the label and described by
+    /// the description, the error message is announced when it changes.
+    fn declare_accessibility(&self, window: &mut Window, cx: &mut App) {
+        let mut props = AccessibilityProps::new(Role::Group).invalid(self.error.is_some());
+        props.label = self.label.as_ref().and_then(|b| b.as_str().cloned());
+        props.description = self.description.as_ref().and_then(|b| b.as_str().cloned());
+        props.labelled_by = self.label.as_ref().map(|_| self.label_id());
+        props.described_by =
+            (self.description.is_some() || self.error.is_some()).then(|| self.description_id());
+        Accessibility::declare(&self.id, &props, window, cx);
+
+        if !Accessibility::is_enabled(cx) {
+            return;
+        }
+
+        let error = self.error.as_ref().and_then(|b| b.as_str().cloned());
+        let last_error = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "announced-error".into()),
+            cx,
+            |_, _| None::<SharedString>,
+        );
+        if *last_error.read(cx) != error {
+            last_error.update(cx, |this, _| *this = error.clone());
+            if let Some(error) = error {
+                Accessibility::announce(error, Politeness::Assertive, window, cx);
+            }
+        }
+    }
+}
+
 impl RenderOnce for FormField {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let layout = self.props.layout;
@@ -343,6 +519,10 @@ impl RenderOnce for FormField {
             self.props.label_width
         };
         let has_label = !self.no_label_indent;
+        let label_id = self.label_id();
+        let description_id = self.description_id();
+        let focus_handle = self.focus_handle.clone();
+        self.declare_accessibility(window, cx);
 
         #[inline]
         fn wrap_div(layout: Axis) -> Div {
@@ -396,6 +576,7 @@ impl RenderOnce for FormField {
                         // Label
                         this.child(
                             wrap_label(label_width)
+                                .id(label_id)
                                 .text_sm()
                                 .when_some(self.props.label_text_size, |this, size| {
                                     this.text_size(size)
@@ -405,6 +586,11 @@ impl RenderOnce for FormField {
                                 .flex_row()
                                 .gap_1()
                                 .items_center()
+                                .when_some(focus_handle, |this, focus_handle| {
+                                    this.on_click(move |_, window, _| {
+                                        window.focus(&focus_handle);
+                                    })
+                                })
                                 .when_some(self.label, |this, builder| {
                                     this.child(
                                         h_flex()
@@ -441,12 +627,30 @@ impl RenderOnce for FormField {
                             wrap_label(label_width),
                         )
                     })
-                    .when_some(self.description, |this, builder| {
+                    .when(self.description.is_some() || self.error.is_some(), |this| {
                         this.child(
-                            div()
+                            v_flex()
+                                .id(description_id.clone())
+                                .gap_1()
                                 .text_xs()
-                                .text_color(cx.theme().muted_foreground)
-                                .child(builder.render(window, cx)),
+                                .when_some(self.description, |this, builder| {
+                                    this.child(
+                                        div()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(builder.render(window, cx)),
+                                    )
+                                })
+                                .when_some(self.error, |this, builder| {
+                                    this.child(
+                                        div()
+                                            .id(ElementId::NamedChild(
+                                                Box::new(description_id),
+                                                "error".into(),
+                                            ))
+                                            .text_color(cx.theme().danger)
+                                            .child(builder.render(window, cx)),
+                                    )
+                                }),
                         )
                     }),
             )
@@ -0,0 +1,236 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, App, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement, Pixels, RenderOnce, StyleRefinement, Styled, Window,
+};
+
+use crate::{responsive::measure_width, StyledExt as _};
+
+/// The column count of a responsive layout, changed by the width breakpoints.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResponsiveColumns {
+    columns: usize,
+    /// The `(min_width, columns)` pairs, sorted by the `min_width`.
+    breakpoints: Vec<(Pixels, usize)>,
+}
+
+impl ResponsiveColumns {
+    pub(crate) fn new(columns: usize) -> Self {
+        Self {
+            columns: columns.max(1),
+            breakpoints: vec![],
+        }
+    }
+
+    pub(crate) fn set_columns(&mut self, columns: usize) {
+        self.columns = columns.max(1);
+    }
+
+    pub(crate) fn add_breakpoint(&mut self, min_width: Pixels, columns: usize) {
+        self.breakpoints.retain(|(width, _)| *width != min_width);
+        self.breakpoints.push((min_width, columns.max(1)));
+        self.breakpoints.sort_by_key(|(width, _)| *width);
+    }
+
+    /// Returns the column count of the largest breakpoint not greater than the `width`.
+    pub(crate) fn resolve(&self, width: Pixels) -> usize {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|(min_width, _)| width >= *min_width)
+            .map_or(self.columns, |(_, columns)| *columns)
+    }
+}
+
+/// An item of the [`Grid`], spans one column by default.
+#[derive(IntoElement)]
+pub struct GridItem {
+    style: StyleRefinement,
+    span: usize,
+    full: bool,
+    row_span: usize,
+    columns: usize,
+    children: Vec<AnyElement>,
+}
+
+impl GridItem {
+    pub fn new() -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            span: 1,
+            full: false,
+            row_span: 1,
+            columns: 1,
+            children: vec![],
+        }
+    }
+
+    /// Set the number of columns to span, default is 1.
+    ///
+    /// The span is limited to the column count of the grid.
+    pub fn span(mut self, span: usize) -> Self {
+        self.span = span.max(1);
+        self
+    }
+
+    /// Set to span all the columns of the grid.
+    pub fn span_full(mut self) -> Self {
+        self.full = true;
+        self
+    }
+
+    /// Set the number of rows to span, default is 1.
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.row_span = row_span.max(1);
+        self
+    }
+}
+
+impl Default for GridItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for GridItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for GridItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for GridItem {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        let span = if self.full {
+            self.columns
+        } else {
+            self.span.min(self.columns)
+        };
+
+        div()
+            .min_w_0()
+            .col_span(span as u16)
+            .when(self.row_span > 1, |this| {
+                this.row_span(self.row_span as u16)
+            })
+            .refine_style(&self.style)
+            .children(self.children)
+    }
+}
+
+/// A responsive grid layout, the column count is changed by the width of the grid.
+///
+/// Use the `gap` methods of [`Styled`] to control the gap between the items.
+///
+/// ```ignore
+/// Grid::new("gallery")
+///     .columns(1)
+///     .breakpoint(px(600.), 2)
+///     .breakpoint(px(900.), 4)
+///     .gap_4()
+///     .item(GridItem::new().span_full().child("Header"))
+///     .children(cards);
+/// ```
+#[derive(IntoElement)]
+pub struct Grid {
+    id: ElementId,
+    style: StyleRefinement,
+    columns: ResponsiveColumns,
+    items: Vec<GridItem>,
+}
+
+impl Grid {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            columns: ResponsiveColumns::new(1),
+            items: vec![],
+        }
+    }
+
+    /// Set the column count when the width is less than all the breakpoints, default is 1.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns.set_columns(columns);
+        self
+    }
+
+    /// Use the `columns` when the width of the grid is greater than or equal to the `min_width`.
+    pub fn breakpoint(mut self, min_width: impl Into<Pixels>, columns: usize) -> Self {
+        self.columns.add_breakpoint(min_width.into(), columns);
+        self
+    }
+
+    /// Add an item with the span configuration.
+    pub fn item(mut self, item: GridItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add items with the span configuration.
+    pub fn items(mut self, items: impl IntoIterator<Item = GridItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+}
+
+impl ParentElement for Grid {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.items
+            .extend(elements.into_iter().map(|el| GridItem::new().child(el)));
+    }
+}
+
+impl Styled for Grid {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Grid {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (width, measure) = measure_width(&self.id, window, cx);
+        let columns = self.columns.resolve(width);
+
+        div()
+            .id(self.id)
+            .relative()
+            .grid()
+            .grid_cols(columns as u16)
+            .refine_style(&self.style)
+            .child(measure)
+            .children(self.items.into_iter().map(|mut item| {
+                item.columns = columns;
+                item
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::ResponsiveColumns;
+
+    #[test]
+    fn test_responsive_columns() {
+        let mut columns = ResponsiveColumns::new(1);
+        columns.add_breakpoint(px(900.), 4);
+        columns.add_breakpoint(px(600.), 2);
+        assert_eq!(columns.resolve(px(0.)), 1);
+        assert_eq!(columns.resolve(px(599.)), 1);
+        assert_eq!(columns.resolve(px(600.)), 2);
+        assert_eq!(columns.resolve(px(899.)), 2);
+        assert_eq!(columns.resolve(px(1200.)), 4);
+
+        columns.add_breakpoint(px(600.), 3);
+        assert_eq!(columns.resolve(px(700.)), 3);
+
+        columns.set_columns(0);
+        assert_eq!(columns.resolve(px(100.)), 1);
+    }
+}
@@ -9,7 +9,7 @@ use ropey::Rope;
 use sum_tree::{Bias, SeekTarget, SumTree};
 
 use crate::{
-    input::{Position, RopeExt as _},
+    input::{AnchorRange, InputEdit, Position, RopeExt as _},
     ActiveTheme,
 };
 
@@ -259,6 +259,43 @@ impl DiagnosticSet {
         self.clear();
     }
 
+    /// Move the diagnostics by the `edit`, the `text` is the edited text.
+    ///
+    /// The diagnostics are kept without recomputation, except the diagnostic, whose text is deleted by the edit.
+    pub(crate) fn apply_edit(&mut self, text: &Rope, edit: &InputEdit) {
+        self.text = text.clone();
+        if self.diagnostics.is_empty() {
+            return;
+        }
+
+        let range = &edit.range;
+        let entries = self
+            .diagnostics
+            .iter()
+            .filter(|entry| {
+                entry.range.is_empty()
+                    || range.is_empty()
+                    || entry.range.start < range.start
+                    || entry.range.end > range.end
+            })
+            .map(|entry| {
+                if entry.range.end < range.start {
+                    return entry.clone();
+                }
+
+                let mut anchor_range = AnchorRange::new(entry.range.clone());
+                anchor_range.apply_edit(edit);
+                let entry_range = anchor_range.range();
+                let mut entry = entry.clone();
+                entry.diagnostic.range = text.offset_to_position(entry_range.start)
+                    ..text.offset_to_position(entry_range.end);
+                entry.range = entry_range;
+                entry
+            })
+            .collect::<Vec<_>>();
+        self.diagnostics = SumTree::from_iter(entries, &());
+    }
+
     pub fn push(&mut self, diagnostic: impl Into<Diagnostic>) {
         let diagnostic = diagnostic.into();
         let start = self.text.position_to_offset(&diagnostic.range.start);
@@ -392,4 +429,59 @@ mod tests {
         diagnostics.clear();
         assert_eq!(diagnostics.len(), 0);
     }
+
+    #[test]
+    fn test_diagnostic_apply_edit() {
+        use ropey::Rope;
+
+        use super::{Diagnostic, DiagnosticSet};
+        use crate::input::{InputEdit, RopeExt as _};
+
+        let mut text = Rope::from("let a = foo;\nlet b = bar;\n");
+        let mut diagnostics = DiagnosticSet::new(&text);
+        diagnostics.push(Diagnostic::new(
+            Position::new(0, 8)..Position::new(0, 11),
+            "Unknown foo",
+        ));
+        diagnostics.push(Diagnostic::new(
+            Position::new(1, 8)..Position::new(1, 11),
+            "Unknown bar",
+        ));
+
+        let mut apply = |range: std::ops::Range<usize>, new_text: &str| {
+            let edit = InputEdit {
+                range: range.clone(),
+                old_text: text.slice(range.clone()).to_string(),
+                new_text: new_text.to_string(),
+            };
+            text.replace(range, new_text);
+            diagnostics.apply_edit(&text, &edit);
+            diagnostics
+                .iter()
+                .map(|entry| (entry.range.clone(), entry.diagnostic.range.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        // Insert a line above.
+        assert_eq!(
+            apply(0..0, "// a\n"),
+            vec![
+                (13..16, Position::new(1, 8)..Position::new(1, 11)),
+                (26..29, Position::new(2, 8)..Position::new(2, 11)),
+            ]
+        );
+        // Type in the first diagnostic.
+        assert_eq!(
+            apply(14..14, "oo"),
+            vec![
+                (13..18, Position::new(1, 8)..Position::new(1, 13)),
+                (28..31, Position::new(2, 8)..Position::new(2, 11)),
+            ]
+        );
+        // Delete the text of the first diagnostic.
+        assert_eq!(
+            apply(13..18, ""),
+            vec![(23..26, Position::new(2, 8)..Position::new(2, 11))]
+        );
+    }
 }
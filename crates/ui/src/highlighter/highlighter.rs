@@ -1,8 +1,8 @@
-use crate::highlighter::{HighlightTheme, LanguageRegistry};
+use crate::highlighter::{HighlightTheme, Language, LanguageRegistry};
 use crate::input::RopeExt;
 
 use anyhow::{anyhow, Context, Result};
-use gpui::{HighlightStyle, SharedString};
+use gpui::{HighlightStyle, Hsla, SharedString};
 
 use ropey::{ChunkCursor, Rope};
 use std::{
@@ -608,6 +608,135 @@ impl SyntaxHighlighter {
 
         styles
     }
+
+    /// Returns the brackets `()`, `[]` and `{}` in the `range` with the nesting depth (0-based).
+    ///
+    /// The brackets are the tokens in the syntax tree, so the brackets in the strings and comments are excluded.
+    pub(crate) fn brackets(&self, range: &Range<usize>) -> Vec<(Range<usize>, usize)> {
+        let mut brackets = vec![];
+        if let Some(tree) = &self.tree {
+            collect_brackets(tree.root_node(), range, 0, &mut brackets);
+        }
+        brackets
+    }
+
+    /// Returns the start rows (0-based) of the scopes (e.g.: function, impl, class) enclosing the `row`,
+    /// from the outermost to the innermost.
+    ///
+    /// The scopes started or ended at the `row` are excluded.
+    pub(crate) fn enclosing_scopes(&self, row: usize) -> Vec<usize> {
+        let Some(tree) = &self.tree else {
+            return vec![];
+        };
+        if row >= self.text.lines_len() {
+            return vec![];
+        }
+
+        let scope_kinds = Language::from_str(&self.language).scope_kinds();
+        if scope_kinds.is_empty() {
+            return vec![];
+        }
+
+        let offset = self.text.line_start_offset(row);
+        let mut scopes = vec![];
+        let mut node = tree.root_node().descendant_for_byte_range(offset, offset);
+        while let Some(current) = node {
+            let (start_row, end_row) = (current.start_position().row, current.end_position().row);
+            let is_root = current.parent().is_none();
+            if !is_root && start_row < row && end_row > row && scope_kinds.contains(&current.kind())
+            {
+                // The nodes may start at the same row, e.g.: the decorated function in Python.
+                if scopes.last() != Some(&start_row) {
+                    scopes.push(start_row);
+                }
+            }
+            node = current.parent();
+        }
+        scopes.reverse();
+        scopes
+    }
+}
+
+/// Collect the brackets in the children of the `node`, the depth is counted by the siblings,
+/// the children inside a pair of brackets go 1 level deeper.
+fn collect_brackets(
+    node: Node,
+    range: &Range<usize>,
+    mut depth: usize,
+    brackets: &mut Vec<(Range<usize>, usize)>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.start_byte() >= range.end {
+            break;
+        }
+
+        let is_token = !child.is_named() && child.child_count() == 0;
+        let in_range = child.start_byte() >= range.start && !child.byte_range().is_empty();
+        match child.kind() {
+            "(" | "[" | "{" if is_token => {
+                if in_range {
+                    brackets.push((child.byte_range(), depth));
+                }
+                depth += 1;
+            }
+            ")" | "]" | "}" if is_token => {
+                depth = depth.saturating_sub(1);
+                if in_range {
+                    brackets.push((child.byte_range(), depth));
+                }
+            }
+            _ => {
+                if child.end_byte() > range.start && child.child_count() > 0 {
+                    collect_brackets(child, range, depth, brackets);
+                }
+            }
+        }
+    }
+}
+
+/// Override the color of the `styles` in the `colors` ranges, both are sorted without overlapping.
+pub(crate) fn override_colors(
+    styles: Vec<(Range<usize>, HighlightStyle)>,
+    colors: &[(Range<usize>, Hsla)],
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    if colors.is_empty() {
+        return styles;
+    }
+
+    let mut result = Vec::with_capacity(styles.len() + colors.len() * 2);
+    let mut colors = colors.iter().peekable();
+    for (range, style) in styles {
+        while colors
+            .next_if(|(color_range, _)| color_range.end <= range.start)
+            .is_some()
+        {}
+
+        let mut start = range.start;
+        for (color_range, color) in colors.clone() {
+            if color_range.start >= range.end {
+                break;
+            }
+
+            let color_start = color_range.start.max(start);
+            let color_end = color_range.end.min(range.end);
+            if color_start > start {
+                result.push((start..color_start, style));
+            }
+            result.push((
+                color_start..color_end,
+                HighlightStyle {
+                    color: Some(*color),
+                    ..style
+                },
+            ));
+            start = color_end;
+        }
+        if start < range.end {
+            result.push((start..range.end, style));
+        }
+    }
+    result
 }
 
 /// To merge intersection ranges, let the subsequent range cover
@@ -818,4 +947,66 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_override_colors() {
+        let red = color_style(gpui::red());
+        let bold = HighlightStyle {
+            font_weight: Some(gpui::FontWeight::BOLD),
+            ..Default::default()
+        };
+        let blue = HighlightStyle {
+            color: Some(gpui::blue()),
+            ..bold
+        };
+
+        assert_eq!(override_colors(vec![(0..5, red)], &[]), vec![(0..5, red)]);
+        assert_eq!(
+            override_colors(
+                vec![(0..4, red), (4..10, bold)],
+                &[
+                    (0..1, gpui::blue()),
+                    (3..5, gpui::blue()),
+                    (9..10, gpui::blue())
+                ]
+            ),
+            vec![
+                (0..1, color_style(gpui::blue())),
+                (1..3, red),
+                (3..4, color_style(gpui::blue())),
+                (4..5, blue),
+                (5..9, bold),
+                (9..10, blue),
+            ]
+        );
+    }
+
+    #[cfg(feature = "tree-sitter-languages")]
+    #[test]
+    fn test_enclosing_scopes() {
+        let scopes = |lang: &str, text: &str, row: usize| {
+            let mut highlighter = SyntaxHighlighter::new(lang);
+            highlighter.update(None, &Rope::from(text));
+            highlighter.enclosing_scopes(row)
+        };
+
+        let text = "mod foo {\n    impl Foo {\n        fn bar() {\n            call(\n                1,\n            );\n        }\n    }\n}\n";
+        assert_eq!(scopes("rust", text, 4), vec![0, 1, 2]);
+        // The scopes started or ended at the row are excluded.
+        assert_eq!(scopes("rust", text, 2), vec![0, 1]);
+        assert_eq!(scopes("rust", text, 7), vec![0]);
+
+        // The method invocation is not a scope.
+        let text =
+            "class Foo {\n    void bar() {\n        baz(\n            1\n        );\n    }\n}\n";
+        assert_eq!(scopes("java", text, 3), vec![0, 1]);
+
+        // The implements clause is not a scope.
+        let text = "class Foo\n    implements\n        Bar,\n        Baz {\n    run() {\n        return 1;\n    }\n}\n";
+        assert_eq!(scopes("typescript", text, 2), vec![0]);
+        assert_eq!(scopes("typescript", text, 5), vec![0, 4]);
+
+        // No scopes of the languages without the scope kinds.
+        assert!(scopes("json", "{\n  \"a\": {\n    \"b\": 1\n  }\n}\n", 2).is_empty());
+    }
 }
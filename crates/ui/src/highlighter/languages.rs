@@ -172,6 +172,109 @@ impl Language {
         .collect()
     }
 
+    /// Returns the node kinds of the scopes (e.g.: function, impl, class) to show in the sticky scroll.
+    pub(super) fn scope_kinds(&self) -> &'static [&'static str] {
+        #[cfg(not(feature = "tree-sitter-languages"))]
+        return &[];
+
+        #[cfg(feature = "tree-sitter-languages")]
+        match self {
+            Self::Bash => &["function_definition"],
+            Self::C => &[
+                "function_definition",
+                "struct_specifier",
+                "union_specifier",
+                "enum_specifier",
+            ],
+            Self::Cpp => &[
+                "function_definition",
+                "class_specifier",
+                "struct_specifier",
+                "union_specifier",
+                "enum_specifier",
+                "namespace_definition",
+            ],
+            Self::CSharp => &[
+                "namespace_declaration",
+                "class_declaration",
+                "struct_declaration",
+                "interface_declaration",
+                "enum_declaration",
+                "record_declaration",
+                "method_declaration",
+                "constructor_declaration",
+            ],
+            Self::Go => &[
+                "function_declaration",
+                "method_declaration",
+                "type_declaration",
+                "func_literal",
+            ],
+            Self::Java => &[
+                "class_declaration",
+                "interface_declaration",
+                "enum_declaration",
+                "record_declaration",
+                "method_declaration",
+                "constructor_declaration",
+            ],
+            Self::JavaScript => &[
+                "class_declaration",
+                "class",
+                "function_declaration",
+                "generator_function_declaration",
+                "function_expression",
+                "arrow_function",
+                "method_definition",
+            ],
+            Self::TypeScript | Self::Tsx => &[
+                "class_declaration",
+                "abstract_class_declaration",
+                "class",
+                "interface_declaration",
+                "enum_declaration",
+                "internal_module",
+                "module",
+                "function_declaration",
+                "generator_function_declaration",
+                "function_expression",
+                "arrow_function",
+                "method_definition",
+            ],
+            Self::Proto => &["message", "enum", "service"],
+            Self::Python => &["class_definition", "function_definition"],
+            Self::Ruby => &[
+                "module",
+                "class",
+                "singleton_class",
+                "method",
+                "singleton_method",
+            ],
+            Self::Rust => &[
+                "mod_item",
+                "impl_item",
+                "trait_item",
+                "struct_item",
+                "enum_item",
+                "union_item",
+                "function_item",
+            ],
+            Self::Scala => &[
+                "object_definition",
+                "class_definition",
+                "trait_definition",
+                "function_definition",
+            ],
+            Self::Swift => &[
+                "class_declaration",
+                "protocol_declaration",
+                "function_declaration",
+                "init_declaration",
+            ],
+            _ => &[],
+        }
+    }
+
     /// Return the language info for the language.
     ///
     /// (language, query, injection, locals)
@@ -421,6 +421,14 @@ pub struct HighlightThemeStyle {
     pub editor_line_number: Option<Hsla>,
     #[serde(rename = "editor.active_line_number")]
     pub editor_active_line_number: Option<Hsla>,
+    #[serde(rename = "editor.invisible")]
+    pub editor_invisible: Option<Hsla>,
+    #[serde(rename = "editor.trailing_whitespace.background")]
+    pub editor_trailing_whitespace: Option<Hsla>,
+    #[serde(rename = "editor.indent_guide")]
+    pub editor_indent_guide: Option<Hsla>,
+    #[serde(rename = "editor.indent_guide.active")]
+    pub editor_indent_guide_active: Option<Hsla>,
     #[serde(flatten)]
     pub status: StatusColors,
     #[serde(rename = "syntax")]
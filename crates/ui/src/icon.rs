@@ -1,10 +1,19 @@
-use crate::{ActiveTheme, Sizable, Size};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::{ActiveTheme, Direction, Sizable, Size};
 use gpui::{
-    prelude::FluentBuilder as _, svg, AnyElement, App, AppContext, Context, Entity, Hsla,
-    IntoElement, Radians, Render, RenderOnce, SharedString, StyleRefinement, Styled, Svg,
+    prelude::FluentBuilder as _, svg, AnyElement, App, AppContext, AssetSource, Context, Entity,
+    Hsla, IntoElement, Radians, Render, RenderOnce, SharedString, StyleRefinement, Styled, Svg,
     Transformation, Window,
 };
 
+/// The asset path prefix of the icons registered by [`IconRegistry::register_bytes`].
+const REGISTRY_PATH_PREFIX: &str = "icons/registry/";
+
 #[derive(IntoElement, Clone)]
 pub enum IconName {
     ALargeSmall,
@@ -15,6 +24,7 @@ pub enum IconName {
     Asterisk,
     Bell,
     BookOpen,
+    Bookmark,
     Bot,
     Building2,
     Calendar,
@@ -33,11 +43,16 @@ pub enum IconName {
     Copy,
     Dash,
     Delete,
+    Dot,
     Ellipsis,
     EllipsisVertical,
     ExternalLink,
     Eye,
     EyeOff,
+    File,
+    FileCode,
+    FileImage,
+    FileText,
     Folder,
     FolderClosed,
     FolderOpen,
@@ -68,6 +83,9 @@ pub enum IconName {
     PanelRight,
     PanelRightClose,
     PanelRightOpen,
+    Pause,
+    Pin,
+    Play,
     Plus,
     Replace,
     ResizeCorner,
@@ -88,11 +106,42 @@ pub enum IconName {
     WindowMaximize,
     WindowMinimize,
     WindowRestore,
+    /// A custom icon registered in the [`IconRegistry`] by name.
+    Custom(SharedString),
 }
 
 impl IconName {
+    /// Create a custom icon by the name registered in the [`IconRegistry`].
+    pub fn custom(name: impl Into<SharedString>) -> Self {
+        Self::Custom(name.into())
+    }
+
+    /// Returns the mirrored icon for the `direction`, e.g.: `ChevronRight` is `ChevronLeft` in `Rtl`.
+    ///
+    /// The icons that are not directional are returned as is.
+    pub fn mirrored(self, direction: Direction) -> Self {
+        if !direction.is_rtl() {
+            return self;
+        }
+
+        match self {
+            Self::ArrowLeft => Self::ArrowRight,
+            Self::ArrowRight => Self::ArrowLeft,
+            Self::ChevronLeft => Self::ChevronRight,
+            Self::ChevronRight => Self::ChevronLeft,
+            Self::PanelLeft => Self::PanelRight,
+            Self::PanelLeftClose => Self::PanelRightClose,
+            Self::PanelLeftOpen => Self::PanelRightOpen,
+            Self::PanelRight => Self::PanelLeft,
+            Self::PanelRightClose => Self::PanelLeftClose,
+            Self::PanelRightOpen => Self::PanelLeftOpen,
+            other => other,
+        }
+    }
+
     pub fn path(self) -> SharedString {
         match self {
+            Self::Custom(name) => return IconRegistry::singleton().path(&name),
             Self::ALargeSmall => "icons/a-large-small.svg",
             Self::ArrowDown => "icons/arrow-down.svg",
             Self::ArrowLeft => "icons/arrow-left.svg",
@@ -101,6 +150,7 @@ impl IconName {
             Self::Asterisk => "icons/asterisk.svg",
             Self::Bell => "icons/bell.svg",
             Self::BookOpen => "icons/book-open.svg",
+            Self::Bookmark => "icons/bookmark.svg",
             Self::Bot => "icons/bot.svg",
             Self::Building2 => "icons/building-2.svg",
             Self::Calendar => "icons/calendar.svg",
@@ -119,11 +169,16 @@ impl IconName {
             Self::Copy => "icons/copy.svg",
             Self::Dash => "icons/dash.svg",
             Self::Delete => "icons/delete.svg",
+            Self::Dot => "icons/dot.svg",
             Self::Ellipsis => "icons/ellipsis.svg",
             Self::EllipsisVertical => "icons/ellipsis-vertical.svg",
             Self::ExternalLink => "icons/external-link.svg",
             Self::Eye => "icons/eye.svg",
             Self::EyeOff => "icons/eye-off.svg",
+            Self::File => "icons/file.svg",
+            Self::FileCode => "icons/file-code.svg",
+            Self::FileImage => "icons/file-image.svg",
+            Self::FileText => "icons/file-text.svg",
             Self::Folder => "icons/folder.svg",
             Self::FolderClosed => "icons/folder-closed.svg",
             Self::FolderOpen => "icons/folder-open.svg",
@@ -154,6 +209,9 @@ impl IconName {
             Self::PanelRight => "icons/panel-right.svg",
             Self::PanelRightClose => "icons/panel-right-close.svg",
             Self::PanelRightOpen => "icons/panel-right-open.svg",
+            Self::Pause => "icons/pause.svg",
+            Self::Pin => "icons/pin.svg",
+            Self::Play => "icons/play.svg",
             Self::Plus => "icons/plus.svg",
             Self::Replace => "icons/replace.svg",
             Self::ResizeCorner => "icons/resize-corner.svg",
@@ -240,6 +298,149 @@ pub trait IconNamed {
     fn path(&self) -> SharedString;
 }
 
+enum IconSource {
+    Path(SharedString),
+    Bytes(Cow<'static, [u8]>),
+}
+
+/// Registry for the custom icons, use them by [`IconName::Custom`].
+///
+/// The icon from bytes is served by [`IconAssets`], so the application's
+/// [`AssetSource`] must be wrapped by it.
+///
+/// ```ignore
+/// Application::new().with_assets(IconAssets::new(Assets));
+///
+/// IconRegistry::singleton().register("logo", "icons/logo.svg");
+/// IconRegistry::singleton().register_bytes("heart", include_bytes!("heart.svg").as_slice());
+///
+/// Icon::new(IconName::custom("heart")).small();
+/// ```
+pub struct IconRegistry {
+    icons: Mutex<HashMap<SharedString, IconSource>>,
+}
+
+impl IconRegistry {
+    /// Returns the singleton instance of the `IconRegistry`.
+    pub fn singleton() -> &'static LazyLock<IconRegistry> {
+        static INSTANCE: LazyLock<IconRegistry> = LazyLock::new(|| IconRegistry {
+            icons: Mutex::new(HashMap::new()),
+        });
+        &INSTANCE
+    }
+
+    /// Registers an icon by the SVG path of the Assets bundle, e.g.: `icons/foo.svg`.
+    pub fn register(&self, name: impl Into<SharedString>, path: impl Into<SharedString>) {
+        self.icons
+            .lock()
+            .unwrap()
+            .insert(name.into(), IconSource::Path(path.into()));
+    }
+
+    /// Registers an icon by the SVG content.
+    pub fn register_bytes(
+        &self,
+        name: impl Into<SharedString>,
+        bytes: impl Into<Cow<'static, [u8]>>,
+    ) {
+        self.icons
+            .lock()
+            .unwrap()
+            .insert(name.into(), IconSource::Bytes(bytes.into()));
+    }
+
+    /// Returns true if the icon is registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.lock().unwrap().contains_key(name)
+    }
+
+    /// Returns the asset path of the icon, fallback to `icons/{name}.svg` if not registered.
+    fn path(&self, name: &str) -> SharedString {
+        match self.icons.lock().unwrap().get(name) {
+            Some(IconSource::Path(path)) => path.clone(),
+            Some(IconSource::Bytes(_)) => format!("{REGISTRY_PATH_PREFIX}{name}.svg").into(),
+            None => format!("icons/{name}.svg").into(),
+        }
+    }
+
+    fn load(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        let name = path
+            .strip_prefix(REGISTRY_PATH_PREFIX)?
+            .strip_suffix(".svg")?;
+        match self.icons.lock().unwrap().get(name) {
+            Some(IconSource::Bytes(bytes)) => Some(bytes.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// An [`AssetSource`] wrapper to serve the icons registered by [`IconRegistry::register_bytes`].
+pub struct IconAssets<A> {
+    inner: A,
+}
+
+impl<A: AssetSource> IconAssets<A> {
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A: AssetSource> AssetSource for IconAssets<A> {
+    fn load(&self, path: &str) -> gpui::Result<Option<Cow<'static, [u8]>>> {
+        if let Some(bytes) = IconRegistry::singleton().load(path) {
+            return Ok(Some(bytes));
+        }
+
+        self.inner.load(path)
+    }
+
+    fn list(&self, path: &str) -> gpui::Result<Vec<SharedString>> {
+        self.inner.list(path)
+    }
+}
+
+/// Generate a typed enum of the application icons, it can be used as an [`Icon`].
+///
+/// ```ignore
+/// gpui_component::icon_names! {
+///     pub enum AppIcon {
+///         Logo => "icons/logo.svg",
+///         Heart => "icons/heart.svg",
+///     }
+/// }
+///
+/// Button::new("like").icon(AppIcon::Heart);
+/// ```
+#[macro_export]
+macro_rules! icon_names {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $path:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl $crate::IconNamed for $name {
+            fn path(&self) -> ::gpui::SharedString {
+                match self {
+                    $(Self::$variant => ::gpui::SharedString::new_static($path)),*
+                }
+            }
+        }
+
+        impl From<$name> for $crate::Icon {
+            fn from(val: $name) -> Self {
+                $crate::Icon::empty().path($crate::IconNamed::path(&val))
+            }
+        }
+    };
+}
+
 impl Icon {
     pub fn new(icon: impl Into<Icon>) -> Self {
         icon.into()
@@ -352,3 +553,47 @@ impl Render for Icon {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::AssetSource as _;
+
+    use super::{IconAssets, IconName, IconNamed as _, IconRegistry};
+
+    crate::icon_names! {
+        enum TestIcon {
+            Logo => "icons/logo.svg",
+        }
+    }
+
+    #[test]
+    fn test_icon_registry() {
+        let registry = IconRegistry::singleton();
+        registry.register("test-path", "icons/foo.svg");
+        registry.register_bytes("test-bytes", b"<svg></svg>".as_slice());
+
+        assert!(registry.contains("test-path"));
+        assert!(!registry.contains("test-unknown"));
+        assert_eq!(IconName::custom("test-path").path(), "icons/foo.svg");
+        assert_eq!(
+            IconName::custom("test-bytes").path(),
+            "icons/registry/test-bytes.svg"
+        );
+        assert_eq!(
+            IconName::custom("test-unknown").path(),
+            "icons/test-unknown.svg"
+        );
+
+        let assets = IconAssets::new(());
+        assert_eq!(
+            assets
+                .load("icons/registry/test-bytes.svg")
+                .unwrap()
+                .as_deref(),
+            Some(b"<svg></svg>".as_slice())
+        );
+        assert!(assets.load("icons/foo.svg").unwrap().is_none());
+
+        assert_eq!(TestIcon::Logo.path(), "icons/logo.svg");
+    }
+}
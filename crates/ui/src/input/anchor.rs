@@ -0,0 +1,215 @@
+use std::ops::Range;
+
+use sum_tree::Bias;
+
+use crate::input::{ChangeSet, InputEdit};
+
+/// A position in the text that tracks the edits, e.g.: a bookmark, or an annotation of the host.
+///
+/// The anchor is moved by the edits, update it with the edits of the [`super::InputEvent::Change`]:
+///
+/// ```ignore
+/// cx.subscribe(&input, |this, _, event: &InputEvent, _| {
+///     if let InputEvent::Change(changes) = event {
+///         this.anchor.apply_changes(changes);
+///     }
+/// })
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Anchor {
+    offset: usize,
+    bias: Bias,
+}
+
+impl Anchor {
+    /// Create an anchor at the byte `offset`.
+    ///
+    /// The `bias` is the side of the anchor when the text is inserted at the offset:
+    ///
+    /// - [`Bias::Left`]: stay before the inserted text.
+    /// - [`Bias::Right`]: move after the inserted text.
+    pub fn new(offset: usize, bias: Bias) -> Self {
+        Self { offset, bias }
+    }
+
+    /// Returns the byte offset of the anchor in the current text.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn bias(&self) -> Bias {
+        self.bias
+    }
+
+    /// Move the anchor by the `edit`.
+    ///
+    /// If the text around the anchor is replaced, the anchor is moved to the start (or the end by
+    /// [`Bias::Right`]) of the inserted text.
+    pub fn apply_edit(&mut self, edit: &InputEdit) {
+        let range = &edit.range;
+        let new_len = edit.new_text.len();
+        if self.offset < range.start {
+            return;
+        }
+
+        self.offset = if self.offset >= range.end && !range.is_empty() || self.offset > range.end {
+            self.offset - range.len() + new_len
+        } else {
+            match self.bias {
+                Bias::Left => range.start,
+                Bias::Right => range.start + new_len,
+            }
+        };
+    }
+
+    /// Move the anchor by the edits in order.
+    pub fn apply_changes(&mut self, changes: &ChangeSet) {
+        for edit in changes.edits() {
+            self.apply_edit(edit);
+        }
+    }
+}
+
+/// A range of the [`Anchor`]s, it is not expanded by the text inserted at the edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnchorRange {
+    pub start: Anchor,
+    pub end: Anchor,
+}
+
+impl AnchorRange {
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            start: Anchor::new(range.start, Bias::Right),
+            end: Anchor::new(range.end, Bias::Left),
+        }
+    }
+
+    /// Returns the byte range in the current text, the end is never before the start.
+    pub fn range(&self) -> Range<usize> {
+        let start = self.start.offset();
+        start..self.end.offset().max(start)
+    }
+
+    /// Move the range by the `edit`.
+    pub fn apply_edit(&mut self, edit: &InputEdit) {
+        self.start.apply_edit(edit);
+        self.end.apply_edit(edit);
+    }
+
+    /// Move the range by the edits in order.
+    pub fn apply_changes(&mut self, changes: &ChangeSet) {
+        for edit in changes.edits() {
+            self.apply_edit(edit);
+        }
+    }
+}
+
+impl From<Range<usize>> for AnchorRange {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range)
+    }
+}
+
+/// Move the `anchor` at the start of a line by the `edit`, returns false if the line is deleted.
+///
+/// The anchor is kept at the first line, if the whole lines from the anchor are replaced.
+pub(super) fn apply_line_edit(anchor: &mut Anchor, edit: &InputEdit) -> bool {
+    let range = &edit.range;
+    let offset = anchor.offset();
+    if range.start < offset && offset < range.end {
+        return false;
+    }
+    if range.start == offset && edit.old_text.ends_with('\n') {
+        return !edit.new_text.is_empty();
+    }
+
+    anchor.apply_edit(edit);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use sum_tree::Bias;
+
+    use super::{apply_line_edit, Anchor, AnchorRange};
+    use crate::input::{ChangeSet, InputEdit};
+
+    fn edit(range: std::ops::Range<usize>, old_text: &str, new_text: &str) -> InputEdit {
+        InputEdit {
+            range,
+            old_text: old_text.to_string(),
+            new_text: new_text.to_string(),
+        }
+    }
+
+    fn apply(offset: usize, bias: Bias, edit: &InputEdit) -> usize {
+        let mut anchor = Anchor::new(offset, bias);
+        anchor.apply_edit(edit);
+        anchor.offset()
+    }
+
+    #[test]
+    fn test_anchor_apply_edit() {
+        // Insert `abc` at 5.
+        let insert = edit(5..5, "", "abc");
+        assert_eq!(apply(3, Bias::Left, &insert), 3);
+        assert_eq!(apply(5, Bias::Left, &insert), 5);
+        assert_eq!(apply(5, Bias::Right, &insert), 8);
+        assert_eq!(apply(6, Bias::Left, &insert), 9);
+
+        // Replace `hello` in 5..10 with `hi`.
+        let replace = edit(5..10, "hello", "hi");
+        assert_eq!(apply(5, Bias::Left, &replace), 5);
+        assert_eq!(apply(5, Bias::Right, &replace), 7);
+        assert_eq!(apply(8, Bias::Left, &replace), 5);
+        assert_eq!(apply(8, Bias::Right, &replace), 7);
+        assert_eq!(apply(10, Bias::Left, &replace), 7);
+        assert_eq!(apply(12, Bias::Right, &replace), 9);
+
+        // Delete `hello` in 5..10.
+        let delete = edit(5..10, "hello", "");
+        assert_eq!(apply(7, Bias::Right, &delete), 5);
+        assert_eq!(apply(10, Bias::Left, &delete), 5);
+    }
+
+    #[test]
+    fn test_anchor_range() {
+        let mut range = AnchorRange::new(5..10);
+        range.apply_edit(&edit(5..5, "", "ab"));
+        assert_eq!(range.range(), 7..12);
+        range.apply_edit(&edit(12..12, "", "cd"));
+        assert_eq!(range.range(), 7..12);
+        range.apply_edit(&edit(8..9, "x", "xyz"));
+        assert_eq!(range.range(), 7..14);
+        range.apply_edit(&edit(0..20, "", ""));
+        assert_eq!(range.range(), 0..0);
+
+        // The empty range is kept after the inserted text.
+        let mut range = AnchorRange::new(3..3);
+        range.apply_changes(&ChangeSet::from(edit(3..3, "", "a")));
+        assert_eq!(range.range(), 4..4);
+    }
+
+    #[test]
+    fn test_apply_line_edit() {
+        let apply = |offset: usize, edit: &InputEdit| {
+            let mut anchor = Anchor::new(offset, Bias::Right);
+            apply_line_edit(&mut anchor, edit).then(|| anchor.offset())
+        };
+
+        // Type at the line start.
+        assert_eq!(apply(4, &edit(4..4, "", "a")), Some(5));
+        // Insert a newline at the line start, the line is moved down.
+        assert_eq!(apply(4, &edit(4..4, "", "\n")), Some(5));
+        // Join the line into the previous line.
+        assert_eq!(apply(4, &edit(3..4, "\n", "")), Some(3));
+        // Delete the line from the start.
+        assert_eq!(apply(4, &edit(4..8, "foo\n", "")), None);
+        assert_eq!(apply(8, &edit(4..8, "foo\n", "")), Some(4));
+        // Replace the line.
+        assert_eq!(apply(4, &edit(4..8, "foo\n", "bar\n")), Some(4));
+        // Delete the lines across the anchor.
+        assert_eq!(apply(4, &edit(2..6, "a\nfo", "")), None);
+    }
+}
@@ -0,0 +1,64 @@
+//! The caret movement by the Left / Right keys in the right-to-left text.
+//!
+//! This is not the Unicode Bidirectional Algorithm, the text is not reordered by this crate: the
+//! runs of a line are shaped and painted in the logical order. So the keys are mapped by the x
+//! positions of the painted text, instead of swapped by the direction of the line, then the
+//! Left key always moves the caret to the left on the screen.
+
+use gpui::Pixels;
+
+/// Returns true if the step to the screen left (or right by `left = false`) is forward in the
+/// logical order.
+///
+/// The `cursor`, `prev` and `next` are the x positions of the cursor, the previous and the next
+/// boundaries, None if the boundary is not on the same visual line (or not laid out).
+pub(super) fn is_visual_step_forward(
+    left: bool,
+    cursor: Pixels,
+    prev: Option<Pixels>,
+    next: Option<Pixels>,
+) -> bool {
+    let toward = |x: &Pixels| if left { *x < cursor } else { *x > cursor };
+    match (prev.filter(toward), next.filter(toward)) {
+        (Some(_), None) => false,
+        (None, Some(_)) => true,
+        // At the edges of the reordered runs, step to the closer one.
+        (Some(prev), Some(next)) => (next - cursor).abs() < (prev - cursor).abs(),
+        // At the line edges, step to the previous or the next line.
+        (None, None) => !left,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::is_visual_step_forward;
+
+    #[test]
+    fn test_is_visual_step_forward() {
+        let forward = |left: bool, cursor: f32, prev: Option<f32>, next: Option<f32>| {
+            is_visual_step_forward(left, px(cursor), prev.map(px), next.map(px))
+        };
+
+        // The text is painted in the logical order, in both "abc" and "שלום", the x increases with
+        // the offset, so the Left key moves backward.
+        assert!(!forward(true, 10., Some(5.), Some(15.)));
+        assert!(forward(false, 10., Some(5.), Some(15.)));
+
+        // If the runs are reordered, the x decreases with the offset, the keys follow the screen.
+        assert!(forward(true, 10., Some(15.), Some(5.)));
+        assert!(!forward(false, 10., Some(15.), Some(5.)));
+
+        // At the edge of a reordered run, both are at the left, step to the closer one.
+        assert!(forward(true, 10., Some(2.), Some(8.)));
+
+        // At the line start (the previous boundary is on the previous line) and the line end.
+        assert!(!forward(true, 0., None, Some(5.)));
+        assert!(forward(false, 20., Some(15.), None));
+
+        // Not laid out, fall back to the logical order.
+        assert!(!forward(true, 0., None, None));
+        assert!(forward(false, 0., None, None));
+    }
+}
@@ -0,0 +1,284 @@
+use gpui::{Context, Window};
+use ropey::Rope;
+use rust_i18n::t;
+use serde::{Deserialize, Serialize};
+use sum_tree::Bias;
+
+use crate::{
+    input::{
+        anchor::apply_line_edit, popovers::ContextMenu, Anchor, InputEdit, InputState,
+        NextBookmark, Position, PrevBookmark, RopeExt as _, ShowBookmarks, ToggleBookmark,
+    },
+    popup_menu::PopupMenuItem,
+};
+
+/// The max chars of the line preview in the bookmarks list.
+const MAX_PREVIEW_CHARS: usize = 60;
+
+/// A bookmark saved by [`InputState::bookmark_anchors`], to restore by [`InputState::restore_bookmarks`].
+///
+/// The line text is kept to relocate the bookmark, when the text is changed out of the editor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkAnchor {
+    /// The row (0-based) of the bookmark when saved.
+    pub row: usize,
+    /// The text of the line when saved, without the line ending.
+    pub text: String,
+}
+
+/// Returns the text of the `row` without the line ending.
+fn line_text(text: &Rope, row: usize) -> String {
+    text.slice_line(row)
+        .to_string()
+        .trim_end_matches(['\r', '\n'])
+        .to_string()
+}
+
+/// Returns the row of the `anchor` in the `text`.
+///
+/// It is the nearest line with the same text, or the saved row if the line is not found.
+fn relocate(text: &Rope, anchor: &BookmarkAnchor) -> usize {
+    let lines_len = text.lines_len();
+    let row = anchor.row.min(lines_len.saturating_sub(1));
+    let is_same = |row: usize| line_text(text, row) == anchor.text;
+    if anchor.text.trim().is_empty() || is_same(row) {
+        return row;
+    }
+
+    (1..lines_len)
+        .flat_map(|distance| [row.checked_sub(distance), Some(row + distance)])
+        .flatten()
+        .filter(|row| *row < lines_len)
+        .find(|row| is_same(*row))
+        .unwrap_or(row)
+}
+
+/// Returns the next (or previous) bookmark of the `row`, wrap around at the end.
+fn next_bookmark(bookmarks: &[usize], row: usize, reverse: bool) -> Option<usize> {
+    if reverse {
+        bookmarks
+            .iter()
+            .rev()
+            .find(|bookmark| **bookmark < row)
+            .or(bookmarks.last())
+            .copied()
+    } else {
+        bookmarks
+            .iter()
+            .find(|bookmark| **bookmark > row)
+            .or(bookmarks.first())
+            .copied()
+    }
+}
+
+impl InputState {
+    pub(super) fn on_action_toggle_bookmark(
+        &mut self,
+        _: &ToggleBookmark,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let row = self.cursor_position().line as usize;
+        self.toggle_bookmark(row, window, cx);
+    }
+
+    pub(super) fn on_action_next_bookmark(
+        &mut self,
+        _: &NextBookmark,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let row = self.cursor_position().line as usize;
+        if let Some(row) = next_bookmark(&self.bookmarks(), row, false) {
+            self.go_to_bookmark(row, window, cx);
+        }
+    }
+
+    pub(super) fn on_action_prev_bookmark(
+        &mut self,
+        _: &PrevBookmark,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let row = self.cursor_position().line as usize;
+        if let Some(row) = next_bookmark(&self.bookmarks(), row, true) {
+            self.go_to_bookmark(row, window, cx);
+        }
+    }
+
+    pub(super) fn on_action_show_bookmarks(
+        &mut self,
+        _: &ShowBookmarks,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_bookmarks(window, cx);
+    }
+
+    /// Returns the rows (0-based) of the bookmarks in order.
+    pub fn bookmarks(&self) -> Vec<usize> {
+        let mut rows = self
+            .bookmarks
+            .iter()
+            .map(|anchor| self.text.offset_to_point(anchor.offset()).row)
+            .collect::<Vec<_>>();
+        rows.dedup();
+        rows
+    }
+
+    /// Add or remove the bookmark of the `row` (0-based), only for the multi-line modes.
+    pub fn toggle_bookmark(&mut self, row: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.mode.is_multi_line() || row >= self.text.lines_len() {
+            return;
+        }
+
+        let anchor = Anchor::new(self.text.line_start_offset(row), Bias::Right);
+        let len = self.bookmarks.len();
+        // The bookmarks of the joined lines are in the same row.
+        self.bookmarks
+            .retain(|bookmark| self.text.offset_to_point(bookmark.offset()).row != row);
+        if self.bookmarks.len() == len {
+            let ix = self
+                .bookmarks
+                .partition_point(|bookmark| bookmark.offset() < anchor.offset());
+            self.bookmarks.insert(ix, anchor);
+        }
+        cx.notify();
+    }
+
+    /// Remove all the bookmarks.
+    pub fn clear_bookmarks(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.bookmarks.clear();
+        cx.notify();
+    }
+
+    /// Returns the bookmarks with the line text, to save them.
+    pub fn bookmark_anchors(&self) -> Vec<BookmarkAnchor> {
+        self.bookmarks()
+            .into_iter()
+            .map(|row| BookmarkAnchor {
+                row,
+                text: line_text(&self.text, row),
+            })
+            .collect()
+    }
+
+    /// Restore the bookmarks saved by [`InputState::bookmark_anchors`], the existing bookmarks are replaced.
+    ///
+    /// If the line of a bookmark is moved, e.g.: the text is edited by another editor,
+    /// the bookmark is restored at the nearest line with the same text.
+    pub fn restore_bookmarks(
+        &mut self,
+        anchors: &[BookmarkAnchor],
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut bookmarks = anchors
+            .iter()
+            .map(|anchor| relocate(&self.text, anchor))
+            .collect::<Vec<_>>();
+        bookmarks.sort_unstable();
+        bookmarks.dedup();
+        self.bookmarks = bookmarks
+            .into_iter()
+            .map(|row| Anchor::new(self.text.line_start_offset(row), Bias::Right))
+            .collect();
+        cx.notify();
+    }
+
+    /// Show the list of the bookmarks with the line preview at the cursor, click to go to the bookmark.
+    pub fn show_bookmarks(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.mode.is_multi_line() {
+            return;
+        }
+
+        let editor = cx.entity();
+        let mut items = self
+            .bookmarks()
+            .into_iter()
+            .map(|row| {
+                let text = line_text(&self.text, row);
+                let mut preview = text
+                    .trim()
+                    .chars()
+                    .take(MAX_PREVIEW_CHARS)
+                    .collect::<String>();
+                if text.trim().chars().count() > MAX_PREVIEW_CHARS {
+                    preview.push('…');
+                }
+
+                let editor = editor.clone();
+                PopupMenuItem::new(preview)
+                    .description(format!("{}", row + 1))
+                    .on_click(move |window, cx| {
+                        editor.update(cx, |editor, cx| editor.go_to_bookmark(row, window, cx));
+                    })
+            })
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            items.push(PopupMenuItem::new(t!("Input.No Bookmarks")).disabled(true));
+        }
+
+        let cursor = self.cursor();
+        let position = self
+            .range_to_bounds(&(cursor..cursor))
+            .map_or(self.input_bounds.origin, |bounds| bounds.bottom_left());
+        let action_context = self.focus_handle.clone();
+        self.context_menu = Some(ContextMenu::MouseContext(self.mouse_context_menu.clone()));
+        self.mouse_context_menu.update(cx, |menu, cx| {
+            menu.open_menu(position, items, action_context, cx);
+        });
+        cx.notify();
+    }
+
+    /// Move the cursor to the start of the bookmark at the `row`.
+    fn go_to_bookmark(&mut self, row: usize, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_cursor_position(Position::new(row as u32, 0), window, cx);
+    }
+
+    /// Move the bookmarks by the `edit`, the text is already edited.
+    pub(super) fn update_bookmarks(&mut self, edit: &InputEdit) {
+        // The anchors are still in order after the edit.
+        self.bookmarks.retain_mut(|anchor| {
+            anchor.offset() < edit.range.start || apply_line_edit(anchor, edit)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+
+    use super::{next_bookmark, relocate, BookmarkAnchor};
+
+    #[test]
+    fn test_relocate() {
+        let text = Rope::from("fn a() {}\n\nfn b() {}\nfn c() {}\n");
+        let anchor = |row: usize, text: &str| BookmarkAnchor {
+            row,
+            text: text.to_string(),
+        };
+
+        assert_eq!(relocate(&text, &anchor(2, "fn b() {}")), 2);
+        // Lines inserted above.
+        assert_eq!(relocate(&text, &anchor(0, "fn c() {}")), 3);
+        // Lines removed above.
+        assert_eq!(relocate(&text, &anchor(6, "fn a() {}")), 0);
+        // The line is changed.
+        assert_eq!(relocate(&text, &anchor(3, "fn d() {}")), 3);
+        assert_eq!(relocate(&text, &anchor(10, "fn d() {}")), 4);
+        // The blank line is not relocated.
+        assert_eq!(relocate(&text, &anchor(3, "")), 3);
+    }
+
+    #[test]
+    fn test_next_bookmark() {
+        let bookmarks = [2, 5, 9];
+        assert_eq!(next_bookmark(&bookmarks, 0, false), Some(2));
+        assert_eq!(next_bookmark(&bookmarks, 5, false), Some(9));
+        assert_eq!(next_bookmark(&bookmarks, 9, false), Some(2));
+        assert_eq!(next_bookmark(&bookmarks, 5, true), Some(2));
+        assert_eq!(next_bookmark(&bookmarks, 2, true), Some(9));
+        assert_eq!(next_bookmark(&[], 2, true), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Range};
 
 use crate::{history::HistoryItem, input::Selection};
 
@@ -37,3 +37,100 @@ impl HistoryItem for Change {
         self.version = version;
     }
 }
+
+/// An edit of the text in the [`super::InputState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputEdit {
+    /// The byte range of the replaced text, in the text before this edit.
+    pub range: Range<usize>,
+    /// The text replaced by this edit.
+    pub old_text: String,
+    /// The text inserted by this edit.
+    pub new_text: String,
+}
+
+impl InputEdit {
+    /// Returns the byte range of the inserted text, in the text after this edit.
+    pub fn new_range(&self) -> Range<usize> {
+        self.range.start..self.range.start + self.new_text.len()
+    }
+}
+
+/// The edits of the text emitted by the [`super::InputEvent::Change`].
+///
+/// A single operation may apply multiple edits (e.g. indent the selected lines, undo, apply LSP edits),
+/// they are batched in one change set in the applied order, so each edit's range is relative to
+/// the text after the previous edits.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChangeSet {
+    edits: Vec<InputEdit>,
+}
+
+impl ChangeSet {
+    /// Returns the edits in the applied order.
+    pub fn edits(&self) -> &[InputEdit] {
+        &self.edits
+    }
+
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, edit: InputEdit) {
+        self.edits.push(edit);
+    }
+
+    /// Apply the edits to the `text`, to keep a copy of the text in sync.
+    ///
+    /// The `text` must be the same as the input text before the edits.
+    pub fn apply(&self, text: &mut String) {
+        for edit in self.edits.iter() {
+            text.replace_range(edit.range.clone(), &edit.new_text);
+        }
+    }
+}
+
+impl From<InputEdit> for ChangeSet {
+    fn from(edit: InputEdit) -> Self {
+        Self { edits: vec![edit] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChangeSet, InputEdit};
+
+    #[test]
+    fn test_change_set_apply() {
+        let mut changes = ChangeSet::default();
+        assert!(changes.is_empty());
+
+        // Indent two lines.
+        changes.push(InputEdit {
+            range: 0..0,
+            old_text: "".into(),
+            new_text: "  ".into(),
+        });
+        changes.push(InputEdit {
+            range: 8..8,
+            old_text: "".into(),
+            new_text: "  ".into(),
+        });
+        // Replace "bar" with "baz".
+        changes.push(InputEdit {
+            range: 10..13,
+            old_text: "bar".into(),
+            new_text: "baz".into(),
+        });
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes.edits()[2].new_range(), 10..13);
+
+        let mut text = String::from("foo()\nbar");
+        changes.apply(&mut text);
+        assert_eq!(text, "  foo()\n  baz");
+    }
+}
@@ -0,0 +1,337 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use gpui::{
+    fill, point, px, size, AnyElement, App, AvailableSpace, Bounds, Context, Hsla, IntoElement,
+    Pixels, Point, ShapedLine, SharedString, Styled as _, TextRun, Window,
+};
+
+use sum_tree::Bias;
+
+use crate::{
+    input::{
+        anchor::apply_line_edit, element::TextElement, Anchor, InputEdit, InputState, LastLayout,
+        RopeExt as _,
+    },
+    ActiveTheme as _, Icon, IconName,
+};
+
+/// The gap between the end of the line and the widget after it.
+const AFTER_LINE_GAP: Pixels = px(16.);
+
+/// The decorations are keyed by the string, in a sorted map to paint in a stable order.
+pub(super) type Decorations = BTreeMap<SharedString, LineDecoration>;
+
+/// The decoration of a line, added by [`InputState::set_decoration`] for the multi-line modes.
+///
+/// The decoration is anchored at the start of the line, it moves with the line when the text is edited,
+/// and is removed if the line is deleted.
+///
+/// ```ignore
+/// state.set_decoration(
+///     "test-result",
+///     LineDecoration::new(12)
+///         .background(cx.theme().success.opacity(0.1))
+///         .gutter_icon(Icon::new(IconName::CircleCheck).text_color(cx.theme().success))
+///         .after_line(|_, cx| Label::new("passed in 12ms").text_color(cx.theme().muted_foreground))
+///         .margin_text("3 days ago"),
+///     window,
+///     cx,
+/// );
+/// ```
+#[derive(Clone)]
+pub struct LineDecoration {
+    row: usize,
+    /// The anchor of the line start, set by [`InputState::set_decoration`].
+    anchor: Anchor,
+    background: Option<Hsla>,
+    gutter_icon: Option<Icon>,
+    after_line: Option<Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>>,
+    margin_text: Option<SharedString>,
+}
+
+impl LineDecoration {
+    /// Create a decoration of the `row` (0-based).
+    pub fn new(row: usize) -> Self {
+        Self {
+            row,
+            anchor: Anchor::default(),
+            background: None,
+            gutter_icon: None,
+            after_line: None,
+            margin_text: None,
+        }
+    }
+
+    /// Returns the row (0-based) of the decoration.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the anchor of the line start, it tracks the line after the decoration is set.
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+
+    /// Set the background color of the entire line.
+    pub fn background(mut self, color: impl Into<Hsla>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Set the icon in the gutter, only shown when the line number is enabled.
+    pub fn gutter_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.gutter_icon = Some(icon.into());
+        self
+    }
+
+    /// Set the widget after the end of the line, e.g.: the test result or the blame info.
+    ///
+    /// The widget is laid out in one line height.
+    pub fn after_line<E>(mut self, builder: impl Fn(&mut Window, &mut App) -> E + 'static) -> Self
+    where
+        E: IntoElement,
+    {
+        self.after_line = Some(Rc::new(move |window, cx| {
+            builder(window, cx).into_any_element()
+        }));
+        self
+    }
+
+    /// Set the text at the right margin of the viewport.
+    pub fn margin_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.margin_text = Some(text.into());
+        self
+    }
+}
+
+/// The decorations of the visible lines.
+#[derive(Default)]
+pub(super) struct DecorationsLayout {
+    backgrounds: Vec<(Bounds<Pixels>, Hsla)>,
+    gutter_icons: Vec<AnyElement>,
+    after_lines: Vec<AnyElement>,
+    margin_texts: Vec<(Point<Pixels>, ShapedLine)>,
+}
+
+impl DecorationsLayout {
+    /// Paint the line backgrounds, before the text.
+    pub(super) fn paint_background(&self, window: &mut Window) {
+        for (bounds, color) in self.backgrounds.iter() {
+            window.paint_quad(fill(*bounds, *color));
+        }
+    }
+
+    /// Paint the widgets after the lines and the margin texts, after the text.
+    pub(super) fn paint(&mut self, line_height: Pixels, window: &mut Window, cx: &mut App) {
+        for element in self.after_lines.iter_mut() {
+            element.paint(window, cx);
+        }
+        for (origin, line) in self.margin_texts.iter() {
+            _ = line.paint(*origin, line_height, window, cx);
+        }
+    }
+
+    /// Paint the gutter icons, after the line numbers.
+    pub(super) fn paint_gutter(&mut self, window: &mut Window, cx: &mut App) {
+        for element in self.gutter_icons.iter_mut() {
+            element.paint(window, cx);
+        }
+    }
+}
+
+impl TextElement {
+    /// Layout the decorations of the visible lines.
+    ///
+    /// The `input_bounds` is the bounds of the element, without the scroll offset.
+    pub(super) fn layout_decorations(
+        &self,
+        last_layout: &LastLayout,
+        bounds: &Bounds<Pixels>,
+        input_bounds: &Bounds<Pixels>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> DecorationsLayout {
+        let state = self.state.read(cx);
+        let mut layout = DecorationsLayout::default();
+        if !state.mode.is_multi_line()
+            || (state.decorations.is_empty() && state.bookmarks.is_empty())
+        {
+            return layout;
+        }
+
+        let visible_range = last_layout.visible_range.clone();
+        let bookmark_icon = Icon::new(IconName::Bookmark).text_color(cx.theme().blue);
+        let mut decorations = state
+            .bookmarks()
+            .into_iter()
+            .map(|row| LineDecoration::new(row).gutter_icon(bookmark_icon.clone()))
+            .chain(state.decorations.values().cloned())
+            .filter(|decoration| visible_range.contains(&decoration.row))
+            .collect::<Vec<_>>();
+        decorations.sort_by_key(|decoration| decoration.row);
+        let show_gutter = state.mode.line_number();
+
+        // The top of the visible lines.
+        let line_height = last_layout.line_height;
+        let mut tops = Vec::with_capacity(last_layout.lines.len());
+        let mut offset_y = last_layout.visible_top;
+        for line in last_layout.lines.iter() {
+            tops.push(offset_y);
+            offset_y += line.size(line_height).height;
+        }
+
+        let text_style = window.text_style();
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let icon_size = (line_height * 0.75).round();
+        let text_origin = bounds.origin + point(last_layout.line_number_width, px(0.));
+        let mut after_line_x: Option<(usize, Pixels)> = None;
+        for decoration in decorations {
+            let ix = decoration.row - visible_range.start;
+            let (Some(line), Some(top)) = (last_layout.lines.get(ix), tops.get(ix).copied()) else {
+                continue;
+            };
+
+            if let Some(color) = decoration.background {
+                layout.backgrounds.push((
+                    Bounds::new(
+                        point(input_bounds.origin.x, bounds.origin.y + top),
+                        size(input_bounds.size.width, line.size(line_height).height),
+                    ),
+                    color,
+                ));
+            }
+
+            if let Some(icon) = decoration.gutter_icon.filter(|_| show_gutter) {
+                let mut element = icon.size(icon_size).into_any_element();
+                element.prepaint_as_root(
+                    point(
+                        input_bounds.origin.x + px(4.),
+                        bounds.origin.y + top + (line_height - icon_size) / 2.,
+                    ),
+                    size(icon_size, icon_size).map(AvailableSpace::Definite),
+                    window,
+                    cx,
+                );
+                layout.gutter_icons.push(element);
+            }
+
+            if let Some(builder) = decoration.after_line {
+                let last_line_top =
+                    top + line_height * line.wrapped_lines.len().saturating_sub(1) as f32;
+                // Multiple widgets of the same line are placed one after another.
+                let x = match after_line_x {
+                    Some((row, x)) if row == decoration.row => x,
+                    _ => {
+                        line.wrapped_lines.last().map_or(px(0.), |line| line.width) + AFTER_LINE_GAP
+                    }
+                };
+                let mut element = builder(window, cx);
+                let element_size = element.layout_as_root(
+                    size(
+                        AvailableSpace::MinContent,
+                        AvailableSpace::Definite(line_height),
+                    ),
+                    window,
+                    cx,
+                );
+                element.prepaint_at(text_origin + point(x, last_line_top), window, cx);
+                after_line_x = Some((decoration.row, x + element_size.width + AFTER_LINE_GAP));
+                layout.after_lines.push(element);
+            }
+
+            if let Some(text) = decoration.margin_text {
+                let len = text.len();
+                let line = window.text_system().shape_line(
+                    text,
+                    font_size,
+                    &[TextRun {
+                        len,
+                        font: text_style.font(),
+                        color: cx.theme().muted_foreground,
+                        background_color: None,
+                        underline: None,
+                        strikethrough: None,
+                    }],
+                    None,
+                );
+                let x = input_bounds.right() - line.width - AFTER_LINE_GAP;
+                layout
+                    .margin_texts
+                    .push((point(x, bounds.origin.y + top), line));
+            }
+        }
+
+        layout
+    }
+}
+
+impl InputState {
+    /// Add or replace the decoration by the `key`, only for the multi-line modes.
+    ///
+    /// The `key` is used to update or remove the decoration later, e.g.: `test:12`, `blame:12`.
+    pub fn set_decoration(
+        &mut self,
+        key: impl Into<SharedString>,
+        mut decoration: LineDecoration,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        debug_assert!(self.mode.is_multi_line());
+        decoration.anchor = Anchor::new(self.text.line_start_offset(decoration.row), Bias::Right);
+        self.decorations.insert(key.into(), decoration);
+        cx.notify();
+    }
+
+    /// Remove the decoration by the `key`, returns the removed decoration.
+    pub fn remove_decoration(
+        &mut self,
+        key: &str,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<LineDecoration> {
+        let decoration = self.decorations.remove(key);
+        if decoration.is_some() {
+            cx.notify();
+        }
+        decoration
+    }
+
+    /// Remove all the decorations, or only the decorations of the keys starting with the `prefix`.
+    pub fn clear_decorations(
+        &mut self,
+        prefix: Option<&str>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match prefix {
+            Some(prefix) => self.decorations.retain(|key, _| !key.starts_with(prefix)),
+            None => self.decorations.clear(),
+        }
+        cx.notify();
+    }
+
+    /// Returns the decoration by the `key`.
+    pub fn decoration(&self, key: &str) -> Option<&LineDecoration> {
+        self.decorations.get(key)
+    }
+
+    /// Returns all the decorations with the keys, in the order of the keys.
+    pub fn decorations(&self) -> impl Iterator<Item = (&SharedString, &LineDecoration)> {
+        self.decorations.iter()
+    }
+
+    /// Move the decorations by the `edit`, the text is already edited.
+    pub(super) fn update_decorations(&mut self, edit: &InputEdit) {
+        let text = &self.text;
+        self.decorations.retain(|_, decoration| {
+            if decoration.anchor.offset() < edit.range.start {
+                return true;
+            }
+            if !apply_line_edit(&mut decoration.anchor, edit) {
+                return false;
+            }
+            decoration.row = text.offset_to_point(decoration.anchor.offset()).row;
+            true
+        });
+    }
+}
@@ -10,11 +10,16 @@ use ropey::Rope;
 use smallvec::SmallVec;
 
 use crate::{
+    debug_overlay::DebugOverlay,
+    highlighter::override_colors,
     input::{blink_cursor::CURSOR_WIDTH, text_wrapper::LineLayout, RopeExt as _},
     ActiveTheme as _, Colorize, PixelsExt, Root,
 };
 
-use super::{mode::InputMode, InputState, LastLayout};
+use super::{
+    decorations::DecorationsLayout, mode::InputMode, sticky_scroll::StickyScrollLayout,
+    whitespace::InvisiblesLayout, InputState, LastLayout,
+};
 
 const BOTTOM_MARGIN_ROWS: usize = 3;
 pub(super) const RIGHT_MARGIN: Pixels = px(10.);
@@ -628,6 +633,33 @@ impl TextElement {
         lines
     }
 
+    /// The extra text styles in the visible range, the gaps are filled with the default style.
+    fn text_styles(
+        state: &InputState,
+        visible_byte_range: Range<usize>,
+        link_styles: Vec<(Range<usize>, HighlightStyle)>,
+    ) -> Option<Vec<(Range<usize>, HighlightStyle)>> {
+        if state.text_styles.is_empty() && link_styles.is_empty() {
+            return None;
+        }
+
+        let styles = state
+            .text_styles
+            .iter()
+            .filter_map(|(range, style)| {
+                let start = range.start.max(visible_byte_range.start);
+                let end = range.end.min(visible_byte_range.end);
+                (start < end).then(|| (start..end, *style))
+            })
+            .chain(link_styles)
+            .collect::<Vec<_>>();
+
+        Some(
+            gpui::combine_highlights([(visible_byte_range, HighlightStyle::default())], styles)
+                .collect(),
+        )
+    }
+
     /// First usize is the offset of skipped.
     fn highlight_lines(
         &mut self,
@@ -636,6 +668,7 @@ impl TextElement {
         visible_byte_range: Range<usize>,
         cx: &mut App,
     ) -> Option<Vec<(Range<usize>, HighlightStyle)>> {
+        let link_styles = self.layout_links(visible_range, cx);
         let state = self.state.read(cx);
         let text = &state.text;
 
@@ -645,7 +678,7 @@ impl TextElement {
                 diagnostics,
                 ..
             } => (highlighter.borrow(), diagnostics),
-            _ => return None,
+            _ => return Self::text_styles(state, visible_byte_range, link_styles),
         };
         let highlighter = highlighter.as_ref()?;
 
@@ -666,12 +699,29 @@ impl TextElement {
             offset = range.end;
         }
 
+        if state.bracket_colorization {
+            let colors = [cx.theme().yellow, cx.theme().magenta, cx.theme().blue];
+            let brackets = highlighter
+                .brackets(&visible_byte_range)
+                .into_iter()
+                .map(|(range, depth)| (range, colors[depth % colors.len()]))
+                .collect::<Vec<_>>();
+            // Fill the gaps without the syntax styles, to override the color of all brackets.
+            styles = gpui::combine_highlights(
+                [(visible_byte_range.clone(), HighlightStyle::default())],
+                styles,
+            )
+            .collect();
+            styles = override_colors(styles, &brackets);
+        }
+
         let diagnostic_styles = diagnostics.styles_for_range(&visible_byte_range, cx);
 
         // hover definition style
         if let Some(hover_style) = self.layout_hover_definition(cx) {
             styles.push(hover_style);
         }
+        styles.extend(link_styles);
 
         // Combine marker styles
         styles = gpui::combine_highlights(diagnostic_styles, styles).collect();
@@ -697,7 +747,12 @@ pub(super) struct PrepaintState {
     hover_highlight_path: Option<Path<Pixels>>,
     search_match_paths: Vec<(Path<Pixels>, bool)>,
     document_color_paths: Vec<(Path<Pixels>, Hsla)>,
+    invisibles: InvisiblesLayout,
+    indent_guides: Vec<(Bounds<Pixels>, bool)>,
+    sticky_scroll: StickyScrollLayout,
+    decorations: DecorationsLayout,
     hover_definition_hitbox: Option<Hitbox>,
+    hover_link_hitbox: Option<Hitbox>,
     bounds: Bounds<Pixels>,
 }
 
@@ -760,6 +815,7 @@ impl Element for TextElement {
         window: &mut Window,
         cx: &mut App,
     ) -> (LayoutId, Self::RequestLayoutState) {
+        let _span = tracing::trace_span!("request_layout", component = "Input").entered();
         let state = self.state.read(cx);
         let line_height = window.line_height();
 
@@ -792,6 +848,7 @@ impl Element for TextElement {
         window: &mut Window,
         cx: &mut App,
     ) -> Self::PrepaintState {
+        let _span = tracing::trace_span!("prepaint", component = "Input").entered();
         let state = self.state.read(cx);
         let line_height = window.line_height();
 
@@ -816,6 +873,7 @@ impl Element for TextElement {
         let placeholder = self.placeholder.clone();
         let style = window.text_style();
         let font_size = style.font_size.to_pixels(window.rem_size());
+        let input_bounds = bounds;
         let mut bounds = bounds;
 
         let (display_text, text_color) = if is_empty {
@@ -1015,6 +1073,11 @@ impl Element for TextElement {
         let hover_highlight_path = self.layout_hover_highlight(&last_layout, &mut bounds, cx);
         let document_color_paths =
             self.layout_document_colors(&document_colors, &last_layout, &bounds);
+        let invisibles = self.layout_invisibles(&last_layout, &bounds, cx);
+        let indent_guides = self.layout_indent_guides(&last_layout, &bounds, window, cx);
+        let sticky_scroll =
+            self.layout_sticky_scroll(&last_layout, &input_bounds, line_number_len, window, cx);
+        let decorations = self.layout_decorations(&last_layout, &bounds, &input_bounds, window, cx);
 
         let state = self.state.read(cx);
         let line_numbers = if state.mode.line_number() {
@@ -1064,6 +1127,7 @@ impl Element for TextElement {
         };
 
         let hover_definition_hitbox = self.layout_hover_definition_hitbox(state, window, cx);
+        let hover_link_hitbox = self.layout_hover_link_hitbox(state, window);
 
         PrepaintState {
             bounds,
@@ -1077,7 +1141,12 @@ impl Element for TextElement {
             search_match_paths,
             hover_highlight_path,
             hover_definition_hitbox,
+            hover_link_hitbox,
             document_color_paths,
+            invisibles,
+            indent_guides,
+            sticky_scroll,
+            decorations,
         }
     }
 
@@ -1091,7 +1160,9 @@ impl Element for TextElement {
         window: &mut Window,
         cx: &mut App,
     ) {
+        let _span = tracing::trace_span!("paint", component = "Input").entered();
         let focus_handle = self.state.read(cx).focus_handle.clone();
+        DebugOverlay::record("Input", input_bounds, Some(&focus_handle), window, cx);
         let show_cursor = self.state.read(cx).show_cursor(window, cx);
         let focused = focus_handle.is_focused(window);
         let bounds = prepaint.bounds;
@@ -1170,6 +1241,10 @@ impl Element for TextElement {
             }
         }
 
+        prepaint.decorations.paint_background(window);
+        Self::paint_indent_guides(&prepaint.indent_guides, window, cx);
+        prepaint.invisibles.paint_background(window, cx);
+
         // Paint selections
         if window.is_window_active() {
             let secondary_selection = cx.theme().selection.saturation(0.1);
@@ -1206,6 +1281,8 @@ impl Element for TextElement {
             _ = line.paint(p, line_height, window, cx);
             offset_y += line.size(line_height).height;
         }
+        prepaint.invisibles.paint(window, cx);
+        prepaint.decorations.paint(line_height, window, cx);
 
         // Paint blinking cursor
         if focused && show_cursor {
@@ -1257,6 +1334,16 @@ impl Element for TextElement {
             }
         }
 
+        prepaint.decorations.paint_gutter(window, cx);
+
+        self.paint_sticky_scroll(
+            &prepaint.sticky_scroll,
+            &prepaint.last_layout,
+            bounds.origin.x - input_bounds.origin.x,
+            window,
+            cx,
+        );
+
         self.state.update(cx, |state, cx| {
             state.last_layout = Some(prepaint.last_layout.clone());
             state.last_bounds = Some(bounds);
@@ -1275,6 +1362,9 @@ impl Element for TextElement {
         if let Some(hitbox) = prepaint.hover_definition_hitbox.as_ref() {
             window.set_cursor_style(gpui::CursorStyle::PointingHand, &hitbox);
         }
+        if let Some(hitbox) = prepaint.hover_link_hitbox.as_ref() {
+            window.set_cursor_style(gpui::CursorStyle::PointingHand, hitbox);
+        }
 
         self.paint_mouse_listeners(window, cx);
     }
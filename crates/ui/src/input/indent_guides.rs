@@ -0,0 +1,248 @@
+use std::ops::Range;
+
+use gpui::{fill, point, px, size, App, Bounds, Pixels, TextRun, Window};
+
+use crate::{
+    input::{element::TextElement, LastLayout, RopeExt as _},
+    ActiveTheme as _,
+};
+
+/// The max rows to scan out of the visible range, to resolve the blank lines and the active block.
+const MAX_SCAN_ROWS: usize = 200;
+
+/// Returns the indent of the line in columns, or None if the line is blank.
+fn line_indent(line: impl IntoIterator<Item = char>, tab_size: usize) -> Option<usize> {
+    let mut columns = 0;
+    for c in line {
+        match c {
+            ' ' => columns += 1,
+            '\t' => columns += tab_size - columns % tab_size,
+            '\r' | '\n' => return None,
+            _ => return Some(columns),
+        }
+    }
+    None
+}
+
+/// Resolve the indent of the blank lines by the nearest non-blank lines above and below,
+/// to keep the guides continuous in the blocks.
+fn resolve_indents(indents: &[Option<usize>], tab_size: usize) -> Vec<usize> {
+    let mut below = vec![None; indents.len()];
+    let mut next = None;
+    for (ix, indent) in indents.iter().enumerate().rev() {
+        below[ix] = next;
+        if indent.is_some() {
+            next = *indent;
+        }
+    }
+
+    let mut above = None;
+    indents
+        .iter()
+        .zip(below)
+        .map(|(indent, below)| match (*indent, above, below) {
+            (Some(indent), _, _) => {
+                above = Some(indent);
+                indent
+            }
+            (None, Some(above), Some(below)) if above == below => above,
+            // Like the closing line of a block, one level deeper than the outer lines.
+            (None, Some(above), Some(below)) => (above.min(below) + tab_size).min(above.max(below)),
+            _ => 0,
+        })
+        .collect()
+}
+
+/// Returns the column and rows of the guide of the innermost block containing the `row`.
+///
+/// If the `row` is the header of a block (the next line is indented deeper), it is the block below.
+fn active_guide(
+    indents: &[Option<usize>],
+    resolved: &[usize],
+    row: usize,
+    tab_size: usize,
+) -> Option<(usize, Range<usize>)> {
+    let indent = *resolved.get(row)?;
+    let row = match resolved.get(row + 1) {
+        Some(next) if indents[row].is_some() && *next > indent => row + 1,
+        _ => row,
+    };
+    let indent = resolved[row];
+    if indent == 0 {
+        return None;
+    }
+
+    let column = (indent - 1) / tab_size * tab_size;
+    let start = (0..row)
+        .rev()
+        .take_while(|ix| resolved[*ix] > column)
+        .last()
+        .unwrap_or(row);
+    let end = (row + 1..resolved.len())
+        .take_while(|ix| resolved[*ix] > column)
+        .last()
+        .unwrap_or(row)
+        + 1;
+    Some((column, start..end))
+}
+
+impl TextElement {
+    /// Layout the indent guides of the visible lines, only for the code editor mode.
+    ///
+    /// Returns the bounds of the guides, and true if it is the active guide.
+    pub(super) fn layout_indent_guides(
+        &self,
+        last_layout: &LastLayout,
+        bounds: &Bounds<Pixels>,
+        window: &mut Window,
+        cx: &App,
+    ) -> Vec<(Bounds<Pixels>, bool)> {
+        let state = self.state.read(cx);
+        if !state.mode.is_code_editor() || !state.indent_guides || state.text.len() == 0 {
+            return vec![];
+        }
+
+        let tab_size = state.mode.tab_size().map_or(1, |tab| tab.tab_size.max(1));
+        let text = &state.text;
+        let visible_range = &last_layout.visible_range;
+        let scan_range = visible_range.start.saturating_sub(MAX_SCAN_ROWS)
+            ..(visible_range.end + MAX_SCAN_ROWS).min(text.lines_len());
+        let indents: Vec<Option<usize>> = scan_range
+            .clone()
+            .map(|row| line_indent(text.slice_line(row).chars(), tab_size))
+            .collect();
+        let resolved = resolve_indents(&indents, tab_size);
+
+        let cursor_row = text.offset_to_point(state.cursor()).row;
+        let active = cursor_row
+            .checked_sub(scan_range.start)
+            .and_then(|row| active_guide(&indents, &resolved, row, tab_size))
+            .map(|(column, rows)| {
+                (
+                    column,
+                    rows.start + scan_range.start..rows.end + scan_range.start,
+                )
+            });
+
+        let style = window.text_style();
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        let space_width = window
+            .text_system()
+            .shape_line(
+                " ".into(),
+                font_size,
+                &[TextRun {
+                    len: 1,
+                    font: style.font(),
+                    color: gpui::black(),
+                    background_color: None,
+                    underline: None,
+                    strikethrough: None,
+                }],
+                None,
+            )
+            .width;
+
+        let line_height = last_layout.line_height;
+        let origin = bounds.origin + point(last_layout.line_number_width, px(0.));
+        let mut guides = vec![];
+        let mut offset_y = last_layout.visible_top;
+        for (ix, line) in last_layout.lines.iter().enumerate() {
+            let row = visible_range.start + ix;
+            let height = line.size(line_height).height;
+            let indent = resolved.get(row - scan_range.start).copied().unwrap_or(0);
+            for column in (0..indent).step_by(tab_size) {
+                let is_active = active
+                    .as_ref()
+                    .is_some_and(|(c, rows)| *c == column && rows.contains(&row));
+                guides.push((
+                    Bounds::new(
+                        origin + point(space_width * column as f32, offset_y),
+                        size(px(1.), height),
+                    ),
+                    is_active,
+                ));
+            }
+            offset_y += height;
+        }
+
+        guides
+    }
+
+    pub(super) fn paint_indent_guides(
+        guides: &[(Bounds<Pixels>, bool)],
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let style = &cx.theme().highlight_theme.style;
+        let color = style.editor_indent_guide.unwrap_or(cx.theme().border);
+        let active_color = style
+            .editor_indent_guide_active
+            .unwrap_or(cx.theme().muted_foreground.opacity(0.5));
+        for (bounds, is_active) in guides {
+            window.paint_quad(fill(*bounds, if *is_active { active_color } else { color }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{active_guide, line_indent, resolve_indents};
+
+    #[test]
+    fn test_line_indent() {
+        assert_eq!(line_indent("foo".chars(), 4), Some(0));
+        assert_eq!(line_indent("    foo".chars(), 4), Some(4));
+        assert_eq!(line_indent("\t  foo".chars(), 4), Some(6));
+        assert_eq!(line_indent("  \tfoo".chars(), 4), Some(4));
+        assert_eq!(line_indent("    ".chars(), 4), None);
+        assert_eq!(line_indent("  \r".chars(), 4), None);
+    }
+
+    #[test]
+    fn test_resolve_indents() {
+        // fn a() {
+        //
+        //     foo();
+        //
+        //         bar();
+        //
+        // }
+        //
+        // fn b() {}
+        let indents = [
+            Some(0),
+            None,
+            Some(4),
+            None,
+            Some(8),
+            None,
+            Some(0),
+            None,
+            Some(0),
+        ];
+        assert_eq!(
+            resolve_indents(&indents, 4),
+            vec![0, 4, 4, 8, 8, 4, 0, 0, 0]
+        );
+        assert_eq!(resolve_indents(&[None, Some(4), None], 4), vec![0, 4, 0]);
+    }
+
+    #[test]
+    fn test_active_guide() {
+        // 0 fn a() {
+        // 1     if b {
+        // 2         c();
+        // 3     }
+        // 4     d();
+        // 5 }
+        let indents = [Some(0), Some(4), Some(8), Some(4), Some(4), Some(0)];
+        let resolved = resolve_indents(&indents, 4);
+        assert_eq!(active_guide(&indents, &resolved, 0, 4), Some((0, 1..5)));
+        assert_eq!(active_guide(&indents, &resolved, 1, 4), Some((4, 2..3)));
+        assert_eq!(active_guide(&indents, &resolved, 2, 4), Some((4, 2..3)));
+        assert_eq!(active_guide(&indents, &resolved, 4, 4), Some((0, 1..5)));
+        assert_eq!(active_guide(&indents, &resolved, 5, 4), None);
+        assert_eq!(active_guide(&indents, &resolved, 6, 4), None);
+    }
+}
@@ -0,0 +1,311 @@
+use std::{ops::Range, rc::Rc, sync::LazyLock};
+
+use gpui::{
+    px, App, Context, HighlightStyle, Hitbox, HitboxBehavior, MouseButton, MouseDownEvent,
+    SharedString, UnderlineStyle, Window,
+};
+use regex::Regex;
+use rust_i18n::t;
+
+use crate::{
+    input::{element::TextElement, popovers::HoverPopover, InputState, RopeExt as _},
+    ActiveTheme as _,
+};
+
+static URL_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?:https?://|mailto:)[^\s<>"'`]+"#).unwrap());
+
+/// A link detected in a line of the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The byte range of the link in the line.
+    pub range: Range<usize>,
+    /// The target to open, e.g.: the URL.
+    pub target: SharedString,
+}
+
+/// The provider to detect the links in the multi-line input.
+///
+/// The links are underlined, and opened by `Cmd + Click` (`Ctrl + Click` on Windows and Linux).
+pub trait LinkProvider {
+    /// Returns the links in the `line`, the line is without the ending `\n`.
+    fn links(&self, line: &str) -> Vec<Link>;
+
+    /// Open the link, default is to open the target as an URL.
+    fn open(&self, link: &Link, _window: &mut Window, cx: &mut App) {
+        cx.open_url(&link.target);
+    }
+}
+
+/// The default [`LinkProvider`] to detect the `http://`, `https://` and `mailto:` URLs.
+pub struct UrlLinkProvider;
+
+/// Returns the byte ranges of the URLs in the `line`.
+///
+/// The trailing punctuations and the unbalanced closing brackets are excluded,
+/// e.g.: `(see https://example.com).` is `https://example.com`.
+fn detect_urls(line: &str) -> Vec<Range<usize>> {
+    URL_PATTERN
+        .find_iter(line)
+        .filter_map(|m| {
+            let url = m.as_str();
+            let scheme_len = url.find("://").map_or("mailto:".len(), |ix| ix + 3);
+            let end = trim_url_end(url);
+            (end > scheme_len).then(|| m.start()..m.start() + end)
+        })
+        .collect()
+}
+
+/// Returns the end of the `url` without the trailing punctuations.
+fn trim_url_end(url: &str) -> usize {
+    let mut end = url.len();
+    while let Some(c) = url[..end].chars().last() {
+        let url = &url[..end];
+        let trim = match c {
+            '.' | ',' | ';' | ':' | '!' | '?' | '。' | '，' | '；' | '：' | '！' | '？' => {
+                true
+            }
+            ')' => url.matches('(').count() < url.matches(')').count(),
+            ']' => url.matches('[').count() < url.matches(']').count(),
+            _ => false,
+        };
+        if !trim {
+            break;
+        }
+        end -= c.len_utf8();
+    }
+    end
+}
+
+impl LinkProvider for UrlLinkProvider {
+    fn links(&self, line: &str) -> Vec<Link> {
+        detect_urls(line)
+            .into_iter()
+            .map(|range| Link {
+                target: line[range.clone()].to_string().into(),
+                range,
+            })
+            .collect()
+    }
+}
+
+/// A [`LinkProvider`] to linkify the matches of a regex, e.g.: the issue IDs or the file paths.
+///
+/// ```ignore
+/// PatternLinkProvider::new(Regex::new(r"#\d+").unwrap(), |link, _, cx| {
+///     cx.open_url(&format!("https://github.com/owner/repo/issues/{}", &link.target[1..]));
+/// })
+/// ```
+pub struct PatternLinkProvider {
+    pattern: Regex,
+    on_open: Box<dyn Fn(&Link, &mut Window, &mut App)>,
+}
+
+impl PatternLinkProvider {
+    /// Create a provider of the `pattern`, the `on_open` is called with the matched text as the target.
+    pub fn new(pattern: Regex, on_open: impl Fn(&Link, &mut Window, &mut App) + 'static) -> Self {
+        Self {
+            pattern,
+            on_open: Box::new(on_open),
+        }
+    }
+}
+
+impl LinkProvider for PatternLinkProvider {
+    fn links(&self, line: &str) -> Vec<Link> {
+        self.pattern
+            .find_iter(line)
+            .filter(|m| !m.is_empty())
+            .map(|m| Link {
+                range: m.range(),
+                target: m.as_str().to_string().into(),
+            })
+            .collect()
+    }
+
+    fn open(&self, link: &Link, window: &mut Window, cx: &mut App) {
+        (self.on_open)(link, window, cx);
+    }
+}
+
+impl InputState {
+    /// Returns the links in the `row`, with the provider and the byte range in the text.
+    fn links_in_row(&self, row: usize) -> Vec<(Rc<dyn LinkProvider>, Link, Range<usize>)> {
+        let line_start = self.text.line_start_offset(row);
+        let line = self.text.slice_line(row).to_string();
+        let line = line.trim_end_matches(['\r', '\n']);
+        self.link_providers
+            .iter()
+            .flat_map(|provider| {
+                provider.links(line).into_iter().map(|link| {
+                    let range = line_start + link.range.start..line_start + link.range.end;
+                    (provider.clone(), link, range)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the link at the `offset`, the first provider has the priority.
+    fn link_at(&self, offset: usize) -> Option<(Rc<dyn LinkProvider>, Link, Range<usize>)> {
+        if !self.mode.is_multi_line() || self.link_providers.is_empty() {
+            return None;
+        }
+
+        let row = self.text.offset_to_point(offset).row;
+        self.links_in_row(row)
+            .into_iter()
+            .find(|(_, _, range)| range.contains(&offset))
+    }
+
+    /// Show the hint of the link under the mouse, return true if hovered a link.
+    pub(super) fn handle_hover_link(&mut self, offset: usize, cx: &mut Context<Self>) -> bool {
+        let link = if self.selecting {
+            None
+        } else {
+            self.link_at(offset)
+        };
+        let Some((_, _, range)) = link else {
+            if let Some(range) = self.hover_link.take() {
+                if self
+                    .hover_popover
+                    .as_ref()
+                    .is_some_and(|popover| popover.read(cx).symbol_range == range)
+                {
+                    self.hover_popover = None;
+                }
+                cx.notify();
+            }
+            return false;
+        };
+
+        if self.hover_link.as_ref() != Some(&range) {
+            let keys = if cfg!(target_os = "macos") {
+                "⌘ + click"
+            } else {
+                "Ctrl + click"
+            };
+            let hover = lsp_types::Hover {
+                contents: lsp_types::HoverContents::Scalar(lsp_types::MarkedString::String(
+                    t!("Input.Follow Link", keys = keys).to_string(),
+                )),
+                range: None,
+            };
+            self.hover_definition.clear();
+            self.hover_popover = Some(HoverPopover::new(cx.entity(), range.clone(), &hover, cx));
+            self.hover_link = Some(range);
+        }
+        cx.notify();
+        true
+    }
+
+    /// Open the link by `Cmd + Click`, return true if handled.
+    pub(super) fn handle_click_link(
+        &mut self,
+        event: &MouseDownEvent,
+        offset: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if !event.modifiers.secondary() || event.button != MouseButton::Left {
+            return false;
+        }
+
+        let Some((provider, link, _)) = self.link_at(offset) else {
+            return false;
+        };
+        self.selecting = false;
+        provider.open(&link, window, cx);
+        true
+    }
+}
+
+impl TextElement {
+    /// Layout the underline styles of the links in the visible lines, the hovered link is colored.
+    pub(super) fn layout_links(
+        &self,
+        visible_range: &Range<usize>,
+        cx: &App,
+    ) -> Vec<(Range<usize>, HighlightStyle)> {
+        let state = self.state.read(cx);
+        if !state.mode.is_multi_line() || state.link_providers.is_empty() {
+            return vec![];
+        }
+
+        let underline = UnderlineStyle {
+            thickness: px(1.),
+            ..UnderlineStyle::default()
+        };
+        let mut styles = visible_range
+            .clone()
+            .filter(|row| *row < state.text.lines_len())
+            .flat_map(|row| state.links_in_row(row))
+            .map(|(_, _, range)| {
+                let style = HighlightStyle {
+                    underline: Some(underline),
+                    color: (state.hover_link.as_ref() == Some(&range)).then(|| cx.theme().link),
+                    ..Default::default()
+                };
+                (range, style)
+            })
+            .collect::<Vec<_>>();
+        styles.sort_by_key(|(range, _)| range.start);
+        styles
+    }
+
+    /// The hitbox of the hovered link to show the pointer, when the `Cmd` is pressed.
+    pub(super) fn layout_hover_link_hitbox(
+        &self,
+        state: &InputState,
+        window: &mut Window,
+    ) -> Option<Hitbox> {
+        if !window.modifiers().secondary() {
+            return None;
+        }
+
+        let range = state.hover_link.as_ref()?;
+        if range.end > state.text.len() {
+            return None;
+        }
+        let bounds = state.range_to_bounds(range)?;
+        Some(window.insert_hitbox(bounds, HitboxBehavior::Normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::Regex;
+
+    use super::{detect_urls, LinkProvider as _, PatternLinkProvider, UrlLinkProvider};
+
+    #[test]
+    fn test_detect_urls() {
+        assert_eq!(detect_urls("see https://example.com"), vec![4..23]);
+        assert_eq!(
+            detect_urls("(see https://example.com/a_(b)?q=1)."),
+            vec![5..34]
+        );
+        assert_eq!(
+            detect_urls("http://a.com, mailto:foo@bar.com!"),
+            vec![0..12, 14..32]
+        );
+        assert_eq!(detect_urls("<https://a.com/x>"), vec![1..16]);
+        assert!(detect_urls("https:// or mailto: or example.com").is_empty());
+
+        let links = UrlLinkProvider.links("链接 https://example.com/路径。");
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].target.as_ref(), "https://example.com/路径");
+    }
+
+    #[test]
+    fn test_pattern_link_provider() {
+        let provider = PatternLinkProvider::new(Regex::new(r"#\d+").unwrap(), |_, _, _| {});
+        let links = provider.links("Fix #12 and #345, not #");
+        assert_eq!(
+            links
+                .iter()
+                .map(|link| (link.range.clone(), link.target.as_ref()))
+                .collect::<Vec<_>>(),
+            vec![(4..7, "#12"), (12..16, "#345")]
+        );
+    }
+}
@@ -125,13 +125,15 @@ impl InputState {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        for edit in text_edits {
-            let start = self.text.position_to_offset(&edit.range.start);
-            let end = self.text.position_to_offset(&edit.range.end);
+        self.batch_changes(cx, |this, cx| {
+            for edit in text_edits {
+                let start = this.text.position_to_offset(&edit.range.start);
+                let end = this.text.position_to_offset(&edit.range.end);
 
-            let range_utf16 = self.range_to_utf16(&(start..end));
-            self.replace_text_in_range_silent(Some(range_utf16), &edit.new_text, window, cx);
-        }
+                let range_utf16 = this.range_to_utf16(&(start..end));
+                this.replace_text_in_range_silent(Some(range_utf16), &edit.new_text, window, cx);
+            }
+        });
     }
 
     pub(super) fn handle_mouse_move(
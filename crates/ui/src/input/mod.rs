@@ -1,8 +1,14 @@
+mod anchor;
+mod bidi;
 mod blink_cursor;
+mod bookmarks;
 mod change;
 mod clear_button;
 mod cursor;
+mod decorations;
 mod element;
+mod indent_guides;
+mod links;
 mod lsp;
 mod mask_pattern;
 mod mode;
@@ -13,11 +19,18 @@ pub(crate) mod popovers;
 mod rope_ext;
 mod search;
 mod state;
+mod sticky_scroll;
 mod text_input;
 mod text_wrapper;
+mod whitespace;
 
+pub use anchor::{Anchor, AnchorRange};
+pub use bookmarks::BookmarkAnchor;
+pub use change::{ChangeSet, InputEdit};
 pub(crate) use clear_button::*;
 pub use cursor::*;
+pub use decorations::LineDecoration;
+pub use links::*;
 pub use lsp::*;
 pub use mask_pattern::MaskPattern;
 pub use mode::TabSize;
@@ -29,3 +42,4 @@ pub use text_input::*;
 pub use lsp_types::Position;
 pub use rope_ext::*;
 pub use ropey::Rope;
+pub use sum_tree::Bias;
@@ -36,7 +36,7 @@ impl InputState {
     pub(crate) fn move_to(&mut self, offset: usize, cx: &mut Context<Self>) {
         let offset = offset.clamp(0, self.text.len());
         self.selected_range = (offset..offset).into();
-        self.scroll_to(offset, cx);
+        self.scroll_into_view(offset, cx);
         self.pause_blink_cursor(cx);
         self.update_preferred_column();
         self.hide_context_menu(cx);
@@ -100,6 +100,23 @@ impl InputState {
     }
 
     pub(super) fn left(&mut self, _: &MoveLeft, _: &mut Window, cx: &mut Context<Self>) {
+        if self.is_visual_step_forward(true) {
+            self.move_forward(cx);
+        } else {
+            self.move_backward(cx);
+        }
+    }
+
+    pub(super) fn right(&mut self, _: &MoveRight, _: &mut Window, cx: &mut Context<Self>) {
+        if self.is_visual_step_forward(false) {
+            self.move_forward(cx);
+        } else {
+            self.move_backward(cx);
+        }
+    }
+
+    /// Move the cursor to the previous char in the logical order.
+    fn move_backward(&mut self, cx: &mut Context<Self>) {
         self.pause_blink_cursor(cx);
         if self.selected_range.is_empty() {
             self.move_to(self.previous_boundary(self.cursor()), cx);
@@ -108,7 +125,8 @@ impl InputState {
         }
     }
 
-    pub(super) fn right(&mut self, _: &MoveRight, _: &mut Window, cx: &mut Context<Self>) {
+    /// Move the cursor to the next char in the logical order.
+    fn move_forward(&mut self, cx: &mut Context<Self>) {
         self.pause_blink_cursor(cx);
         if self.selected_range.is_empty() {
             self.move_to(self.next_boundary(self.selected_range.end), cx);
@@ -5,7 +5,7 @@ use gpui::{
     Subscription, Window,
 };
 
-use super::{blink_cursor::BlinkCursor, InputEvent};
+use super::{blink_cursor::BlinkCursor, InputEdit, InputEvent};
 use crate::{h_flex, v_flex, ActiveTheme, Disableable, Icon, IconName, Sizable, Size};
 
 pub struct OtpState {
@@ -98,6 +98,7 @@ impl OtpState {
     }
 
     fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let old_value = self.value.clone();
         let mut chars: Vec<char> = self.value.chars().collect();
         let ix = chars.len();
 
@@ -133,7 +134,14 @@ impl OtpState {
         self.value = SharedString::from(chars.iter().collect::<String>());
 
         if self.value.chars().count() == self.length {
-            cx.emit(InputEvent::Change);
+            cx.emit(InputEvent::Change(
+                InputEdit {
+                    range: 0..old_value.len(),
+                    old_text: old_value.to_string(),
+                    new_text: self.value.to_string(),
+                }
+                .into(),
+            ));
         }
         cx.notify()
     }
@@ -1,10 +1,10 @@
 use std::rc::Rc;
 
 use gpui::{
-    canvas, deferred, div, prelude::FluentBuilder, px, relative, Action, AnyElement, App,
-    AppContext, Bounds, Context, DismissEvent, Empty, Entity, EventEmitter,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, Point, Render, RenderOnce,
-    SharedString, Styled, StyledText, Subscription, Window,
+    canvas, deferred, div, point, prelude::FluentBuilder, px, relative, size, Action, AnyElement,
+    App, AppContext, Bounds, Context, DismissEvent, Empty, Entity, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render, RenderOnce, SharedString,
+    Styled, StyledText, Subscription, Window,
 };
 use lsp_types::CodeAction;
 
@@ -15,7 +15,8 @@ use crate::{
     actions, h_flex,
     input::{self, popovers::editor_popover, InputState},
     list::{List, ListDelegate, ListEvent},
-    ActiveTheme, IndexPath, Selectable,
+    position::{Position, Positioned},
+    ActiveTheme, IndexPath, Placement, Selectable,
 };
 
 #[derive(Debug, Clone)]
@@ -292,26 +293,27 @@ impl CodeActionMenu {
         cx.notify();
     }
 
-    fn origin(&self, cx: &App) -> Option<Point<Pixels>> {
+    /// The bounds of the cursor in window coordinates, the menu is positioned below it.
+    fn trigger_bounds(&self, cx: &App) -> Option<Bounds<Pixels>> {
         let state = self.state.read(cx);
         let Some(last_layout) = state.last_layout.as_ref() else {
             return None;
         };
-        let Some(cursor_origin) = last_layout.cursor_bounds.map(|b| b.origin) else {
+        let Some(cursor_bounds) = last_layout.cursor_bounds else {
             return None;
         };
 
-        let scroll_origin = self.state.read(cx).scroll_handle.offset();
+        let scroll_origin = state.scroll_handle.offset();
 
-        Some(
-            scroll_origin + cursor_origin - state.input_bounds.origin
-                + Point::new(-px(4.), last_layout.line_height + px(4.)),
-        )
+        Some(Bounds {
+            origin: scroll_origin + cursor_bounds.origin - point(px(4.), px(0.)),
+            size: size(cursor_bounds.size.width, last_layout.line_height),
+        })
     }
 }
 
 impl Render for CodeActionMenu {
-    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         if !self.open {
             return Empty.into_any_element();
         }
@@ -323,31 +325,30 @@ impl Render for CodeActionMenu {
 
         let view = cx.entity();
 
-        let Some(pos) = self.origin(cx) else {
+        let Some(trigger_bounds) = self.trigger_bounds(cx) else {
             return Empty.into_any_element();
         };
 
-        let max_width = MAX_MENU_WIDTH.min(window.bounds().size.width - pos.x);
-
         deferred(
-            editor_popover("code-action-menu", cx)
-                .absolute()
-                .left(pos.x)
-                .top(pos.y)
-                .max_w(max_width)
-                .min_w(px(120.))
-                .child(self.list.clone())
+            Positioned::new("code-action-menu-position", trigger_bounds)
+                .position(Position::new(Placement::Bottom))
                 .child(
-                    canvas(
-                        move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
-                        |_, _, _, _| {},
-                    )
-                    .absolute()
-                    .size_full(),
-                )
-                .on_mouse_down_out(cx.listener(|this, _, _, cx| {
-                    this.hide(cx);
-                })),
+                    editor_popover("code-action-menu", cx)
+                        .max_w(MAX_MENU_WIDTH)
+                        .min_w(px(120.))
+                        .child(self.list.clone())
+                        .child(
+                            canvas(
+                                move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                                |_, _, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        )
+                        .on_mouse_down_out(cx.listener(|this, _, _, cx| {
+                            this.hide(cx);
+                        })),
+                ),
         )
         .into_any_element()
     }
@@ -1,10 +1,10 @@
 use std::rc::Rc;
 
 use gpui::{
-    canvas, deferred, div, prelude::FluentBuilder, px, relative, Action, AnyElement, App,
-    AppContext, Bounds, Context, DismissEvent, Empty, Entity, EventEmitter, HighlightStyle,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, Point, Render, RenderOnce,
-    SharedString, Styled, StyledText, Subscription, Window,
+    canvas, deferred, div, point, prelude::FluentBuilder, px, relative, size, Action, AnyElement,
+    App, AppContext, Bounds, Context, DismissEvent, Empty, Entity, EventEmitter, HighlightStyle,
+    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render, RenderOnce, SharedString,
+    Styled, StyledText, Subscription, Window,
 };
 use lsp_types::{CompletionItem, CompletionTextEdit};
 
@@ -21,7 +21,8 @@ use crate::{
     },
     label::Label,
     list::{List, ListDelegate, ListEvent},
-    ActiveTheme, IndexPath, Selectable,
+    position::{Position, Positioned},
+    ActiveTheme, IndexPath, Placement, Selectable,
 };
 
 struct ContextMenuDelegate {
@@ -375,21 +376,22 @@ impl CompletionMenu {
         cx.notify();
     }
 
-    fn origin(&self, cx: &App) -> Option<Point<Pixels>> {
+    /// The bounds of the cursor in window coordinates, the menu is positioned below it.
+    fn trigger_bounds(&self, cx: &App) -> Option<Bounds<Pixels>> {
         let editor = self.editor.read(cx);
         let Some(last_layout) = editor.last_layout.as_ref() else {
             return None;
         };
-        let Some(cursor_origin) = last_layout.cursor_bounds.map(|b| b.origin) else {
+        let Some(cursor_bounds) = last_layout.cursor_bounds else {
             return None;
         };
 
-        let scroll_origin = self.editor.read(cx).scroll_handle.offset();
+        let scroll_origin = editor.scroll_handle.offset();
 
-        Some(
-            scroll_origin + cursor_origin - editor.input_bounds.origin
-                + Point::new(-px(4.), last_layout.line_height + px(4.)),
-        )
+        Some(Bounds {
+            origin: scroll_origin + cursor_bounds.origin - point(px(4.), px(0.)),
+            size: size(cursor_bounds.size.width, last_layout.line_height),
+        })
     }
 }
 
@@ -406,7 +408,7 @@ impl Render for CompletionMenu {
 
         let view = cx.entity();
 
-        let Some(pos) = self.origin(cx) else {
+        let Some(trigger_bounds) = self.trigger_bounds(cx) else {
             return Empty.into_any_element();
         };
 
@@ -417,57 +419,58 @@ impl Render for CompletionMenu {
             .selected_item()
             .and_then(|item| item.documentation.clone());
 
-        let max_width = MAX_MENU_WIDTH.min(window.bounds().size.width - pos.x);
-        let abs_pos = self.editor.read(cx).input_bounds.origin + pos;
         let vertical_layout =
-            abs_pos.x + MAX_MENU_WIDTH + POPOVER_GAP + MAX_MENU_WIDTH + POPOVER_GAP
+            trigger_bounds.left() + MAX_MENU_WIDTH + POPOVER_GAP + MAX_MENU_WIDTH + POPOVER_GAP
                 > window.bounds().size.width;
 
         deferred(
-            div()
-                .absolute()
-                .left(pos.x)
-                .top(pos.y)
-                .flex()
-                .flex_row()
-                .gap(POPOVER_GAP)
-                .items_start()
-                .when(vertical_layout, |this| this.flex_col())
+            Positioned::new("completion-menu-position", trigger_bounds)
+                .position(Position::new(Placement::Bottom).gap(POPOVER_GAP))
                 .child(
-                    editor_popover("completion-menu", cx)
-                        .max_w(max_width)
-                        .min_w(px(120.))
-                        .child(self.list.clone())
+                    div()
+                        .flex()
+                        .flex_row()
+                        .gap(POPOVER_GAP)
+                        .items_start()
+                        .when(vertical_layout, |this| this.flex_col())
                         .child(
-                            canvas(
-                                move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
-                                |_, _, _, _| {},
-                            )
-                            .absolute()
-                            .size_full(),
-                        ),
-                )
-                .when_some(selected_documentation, |this, documentation| {
-                    let mut doc = match documentation {
-                        lsp_types::Documentation::String(s) => s.clone(),
-                        lsp_types::Documentation::MarkupContent(mc) => mc.value.clone(),
-                    };
-                    if vertical_layout {
-                        doc = doc.split("\n").next().unwrap_or_default().to_string();
-                    }
-
-                    this.child(
-                        div().child(
                             editor_popover("completion-menu", cx)
-                                .w(MAX_MENU_WIDTH)
-                                .px_2()
-                                .child(render_markdown("doc", doc, window, cx)),
-                        ),
-                    )
-                })
-                .on_mouse_down_out(cx.listener(|this, _, _, cx| {
-                    this.hide(cx);
-                })),
+                                .max_w(MAX_MENU_WIDTH)
+                                .min_w(px(120.))
+                                .child(self.list.clone())
+                                .child(
+                                    canvas(
+                                        move |bounds, _, cx| {
+                                            view.update(cx, |r, _| r.bounds = bounds)
+                                        },
+                                        |_, _, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full(),
+                                ),
+                        )
+                        .when_some(selected_documentation, |this, documentation| {
+                            let mut doc = match documentation {
+                                lsp_types::Documentation::String(s) => s.clone(),
+                                lsp_types::Documentation::MarkupContent(mc) => mc.value.clone(),
+                            };
+                            if vertical_layout {
+                                doc = doc.split("\n").next().unwrap_or_default().to_string();
+                            }
+
+                            this.child(
+                                div().child(
+                                    editor_popover("completion-menu", cx)
+                                        .w(MAX_MENU_WIDTH)
+                                        .px_2()
+                                        .child(render_markdown("doc", doc, window, cx)),
+                                ),
+                            )
+                        })
+                        .on_mouse_down_out(cx.listener(|this, _, _, cx| {
+                            this.hide(cx);
+                        })),
+                ),
         )
         .into_any_element()
     }
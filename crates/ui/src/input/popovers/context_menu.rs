@@ -1,13 +1,13 @@
 use gpui::{
     anchored, deferred, div, prelude::FluentBuilder as _, px, App, AppContext as _, Context,
-    Corner, DismissEvent, Entity, IntoElement, MouseDownEvent, ParentElement as _, Pixels, Point,
-    Render, Styled, Subscription, Window,
+    Corner, DismissEvent, Entity, FocusHandle, IntoElement, MouseDownEvent, ParentElement as _,
+    Pixels, Point, Render, Styled, Subscription, Window,
 };
 use rust_i18n::t;
 
 use crate::{
     input::{self, popovers::ContextMenu, InputState},
-    popup_menu::PopupMenu,
+    popup_menu::{PopupMenu, PopupMenuItem},
 };
 
 /// Context menu for mouse right clicks.
@@ -44,7 +44,11 @@ impl InputState {
         let has_goto_definition = is_enable && self.lsp.definition_provider.is_some();
         let has_code_action = is_enable && !self.lsp.code_action_providers.is_empty();
         let is_selected = !self.selected_range.is_empty();
+        // Do not allow to copy the masked text (e.g.: password).
+        let can_copy = is_selected && !self.masked;
         let has_paste = is_enable && cx.read_from_clipboard().is_some();
+        let has_undo = !self.history.undos().is_empty();
+        let has_redo = !self.history.redos().is_empty();
 
         let action_context = self.focus_handle.clone();
         self.mouse_context_menu.update(cx, |this, cx| {
@@ -64,12 +68,13 @@ impl InputState {
                         )
                         .separator()
                     })
-                    .menu_with_enable(
-                        t!("Input.Cut"),
-                        Box::new(input::Cut),
-                        is_enable && is_selected,
-                    )
-                    .menu_with_enable(t!("Input.Copy"), Box::new(input::Copy), is_selected)
+                    .when(is_enable, |m| {
+                        m.menu_with_enable(t!("Input.Undo"), Box::new(input::Undo), has_undo)
+                            .menu_with_enable(t!("Input.Redo"), Box::new(input::Redo), has_redo)
+                            .separator()
+                    })
+                    .menu_with_enable(t!("Input.Cut"), Box::new(input::Cut), is_enable && can_copy)
+                    .menu_with_enable(t!("Input.Copy"), Box::new(input::Copy), can_copy)
                     .menu_with_enable(t!("Input.Paste"), Box::new(input::Paste), has_paste)
                     .separator()
                     .menu(t!("Input.Select All"), Box::new(input::SelectAll));
@@ -110,6 +115,24 @@ impl MouseContextMenu {
         })
     }
 
+    /// Open the menu of the `items` at the `position`, e.g.: the bookmarks list.
+    pub(crate) fn open_menu(
+        &mut self,
+        position: Point<Pixels>,
+        items: Vec<PopupMenuItem>,
+        action_context: FocusHandle,
+        cx: &mut Context<Self>,
+    ) {
+        self.mouse_position = position;
+        self.menu.update(cx, |menu, cx| {
+            menu.menu_items = items;
+            menu.action_context = Some(action_context);
+            cx.notify();
+        });
+        self.open = true;
+        cx.notify();
+    }
+
     #[inline]
     pub(crate) fn is_open(&self) -> bool {
         self.open
@@ -3,12 +3,14 @@ use std::{ops::Range, rc::Rc};
 use gpui::{
     deferred, div, point, prelude::FluentBuilder as _, px, AnyElement, App, AppContext as _,
     AvailableSpace, Bounds, Element, ElementId, Entity, InteractiveElement, IntoElement,
-    MouseDownEvent, ParentElement as _, Pixels, Render, StyleRefinement, Styled, Window,
+    MouseDownEvent, ParentElement as _, Pixels, Render, StatefulInteractiveElement as _,
+    StyleRefinement, Styled, Window,
 };
 
 use crate::{
     input::{popovers::render_markdown, InputState},
-    StyledExt,
+    position::Position,
+    Placement, StyledExt,
 };
 
 pub struct HoverPopover {
@@ -179,16 +181,19 @@ impl Element for Popover {
             }
         };
 
-        let max_width = self
-            .width_limit
-            .end
-            .min(window.bounds().size.width - SNAP_TO_EDGE * 2)
-            .max(px(200.));
+        const SNAP_TO_EDGE: Pixels = px(8.);
+        let position = Position::new(Placement::Top)
+            .gap(px(0.))
+            .margin(SNAP_TO_EDGE);
+        let viewport = window.viewport_size();
+        let max_size = position.max_size(trigger_bounds, viewport);
+        let max_width = self.width_limit.end.min(max_size.width).max(px(200.));
 
         let is_open = *open_state.read(cx);
 
         let mut popover = deferred(
             div()
+                .id("popover")
                 .when(!is_open, |s| s.invisible())
                 .flex_none()
                 .occlude()
@@ -197,26 +202,18 @@ impl Element for Popover {
                 .popover_style(cx)
                 .shadow_md()
                 .max_w(max_width)
+                .max_h(max_size.height)
+                .overflow_y_scroll()
                 .refine_style(&self.style)
                 .child((self.content_builder)(window, cx)),
         )
         .into_any_element();
 
         let popover_size = popover.layout_as_root(AvailableSpace::min_size(), window, cx);
-        const SNAP_TO_EDGE: Pixels = px(8.);
-        let top_space = trigger_bounds.top() - SNAP_TO_EDGE;
-        let right_space = window.bounds().size.width - trigger_bounds.left() - SNAP_TO_EDGE;
-
-        let mut pos = point(
-            trigger_bounds.left(),
-            trigger_bounds.top() - popover_size.height,
-        );
-        if popover_size.height > top_space {
-            pos.y = trigger_bounds.bottom();
-        }
-        if popover_size.width > right_space {
-            pos.x = trigger_bounds.right() - popover_size.width;
-        }
+        let pos = position
+            .resolve(trigger_bounds, popover_size, viewport)
+            .bounds
+            .origin;
 
         let mut empty = div().into_any_element();
         let layout_id = empty.request_layout(window, cx);
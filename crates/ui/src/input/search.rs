@@ -225,7 +225,7 @@ impl SearchPanel {
                     cx.subscribe(&search_input, |this: &mut Self, _, ev: &InputEvent, cx| {
                         // Handle search input changes
                         match ev {
-                            InputEvent::Change => {
+                            InputEvent::Change(_) => {
                                 this.update_search_query(cx);
                             }
                             _ => {}
@@ -309,7 +309,7 @@ impl SearchPanel {
     fn prev(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         if let Some(range) = self.matcher.next_back() {
             self.editor.update(cx, |state, cx| {
-                state.scroll_to(range.start, cx);
+                state.scroll_into_view(range.start, cx);
             });
         }
     }
@@ -317,7 +317,7 @@ impl SearchPanel {
     fn next(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         if let Some(range) = self.matcher.next() {
             self.editor.update(cx, |state, cx| {
-                state.scroll_to(range.end, cx);
+                state.scroll_into_view(range.end, cx);
             });
         }
     }
@@ -346,7 +346,7 @@ impl SearchPanel {
                 cx.update(|window, cx| {
                     text_state.update(cx, |state, cx| {
                         let range_utf16 = state.range_to_utf16(&range);
-                        state.scroll_to(next_range.end, cx);
+                        state.scroll_into_view(next_range.end, cx);
                         state.replace_text_in_range_silent(
                             Some(range_utf16),
                             new_text.as_str(),
@@ -383,7 +383,7 @@ impl SearchPanel {
                         window,
                         cx,
                     );
-                    state.scroll_to(0, cx);
+                    state.scroll_into_view(0, cx);
                 });
             })
         })
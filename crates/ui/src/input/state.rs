@@ -5,22 +5,26 @@
 use anyhow::Result;
 use gpui::{
     actions, div, point, prelude::FluentBuilder as _, px, Action, App, AppContext, Bounds,
-    ClipboardItem, Context, Entity, EntityInputHandler, EventEmitter, FocusHandle, Focusable,
-    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton, MouseDownEvent,
-    MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels, Point, Render, ScrollHandle,
-    ScrollWheelEvent, SharedString, Styled as _, Subscription, Task, UTF16Selection, Window,
+    ClipboardEntry, Context, Entity, EntityInputHandler, EventEmitter, ExternalPaths, FocusHandle,
+    Focusable, HighlightStyle, Image, ImageFormat, InteractiveElement as _, IntoElement,
+    KeyBinding, KeyDownEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
+    ParentElement as _, Pixels, Point, Render, ScrollHandle, ScrollWheelEvent, SharedString, Size,
+    Styled as _, Subscription, Task, UTF16Selection, Window,
 };
 use ropey::{Rope, RopeSlice};
 use serde::Deserialize;
 use std::cell::RefCell;
 use std::ops::Range;
+use std::path::Path;
 use std::rc::Rc;
+use std::sync::Arc;
 use sum_tree::Bias;
 use unicode_segmentation::*;
 
 use super::{
+    bidi,
     blink_cursor::BlinkCursor,
-    change::Change,
+    change::{Change, ChangeSet, InputEdit},
     element::TextElement,
     mask_pattern::MaskPattern,
     mode::{InputMode, TabSize},
@@ -28,15 +32,23 @@ use super::{
     text_wrapper::TextWrapper,
 };
 use crate::input::{
+    anchor::Anchor,
+    decorations::Decorations,
     element::RIGHT_MARGIN,
     popovers::{ContextMenu, DiagnosticPopover, HoverPopover, MouseContextMenu},
     search::{self, SearchPanel},
     text_wrapper::LineLayout,
-    HoverDefinition, Lsp, Position,
+    HoverDefinition, LinkProvider, Lsp, Position, UrlLinkProvider,
 };
 use crate::input::{RopeExt as _, Selection};
+use crate::text::SelectionToolbar;
+use crate::{
+    clipboard::{highlighted_html, ClipboardContent},
+    history::History,
+    scroll::{self, ScrollSyncPane, ScrollTarget, ScrollbarState},
+    ActiveTheme as _, Root, ScaleFactorObserver as _,
+};
 use crate::{highlighter::DiagnosticSet, input::text_wrapper::LineItem};
-use crate::{history::History, scroll::ScrollbarState, Root};
 
 #[derive(Action, Clone, PartialEq, Eq, Deserialize)]
 #[action(namespace = input, no_json)]
@@ -93,15 +105,29 @@ actions!(
         ToggleCodeActions,
         Search,
         GoToDefinition,
+        TrimTrailingWhitespace,
+        ToggleBookmark,
+        NextBookmark,
+        PrevBookmark,
+        ShowBookmarks,
     ]
 );
 
 #[derive(Clone)]
 pub enum InputEvent {
-    Change,
-    PressEnter { secondary: bool },
+    /// The text is changed, with the edits applied.
+    Change(ChangeSet),
+    PressEnter {
+        secondary: bool,
+    },
     Focus,
     Blur,
+    /// An image is pasted or dropped into the input, see [`InputState::accept_media`].
+    MediaAdded {
+        image: Arc<Image>,
+        /// The placeholder token inserted at the cursor, replace it after the upload.
+        token: SharedString,
+    },
 }
 
 pub(super) const CONTEXT: &str = "Input";
@@ -223,6 +249,18 @@ pub(crate) fn init(cx: &mut App) {
         KeyBinding::new("cmd-f", Search, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-f", Search, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-k", ToggleBookmark, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-k", ToggleBookmark, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-l", NextBookmark, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-l", NextBookmark, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-j", PrevBookmark, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-alt-j", PrevBookmark, Some(CONTEXT)),
     ]);
 
     search::init(cx);
@@ -297,8 +335,23 @@ pub struct InputState {
     pub(super) masked: bool,
     pub(super) clean_on_escape: bool,
     pub(super) soft_wrap: bool,
+    pub(super) show_invisibles: bool,
+    pub(super) highlight_trailing_whitespace: bool,
+    pub(super) indent_guides: bool,
+    pub(super) bracket_colorization: bool,
+    pub(super) sticky_scroll: bool,
+    pub(super) link_providers: Vec<Rc<dyn LinkProvider>>,
+    /// The byte range of the link under the mouse.
+    pub(super) hover_link: Option<Range<usize>>,
+    pub(super) decorations: Decorations,
+    /// The anchors of the bookmarks at the line start, in order.
+    pub(super) bookmarks: Vec<Anchor>,
     pub(super) pattern: Option<regex::Regex>,
     pub(super) validate: Option<Box<dyn Fn(&str, &mut Context<Self>) -> bool + 'static>>,
+    pub(super) accept_media: Option<Box<dyn Fn(&Image, &mut Context<Self>) -> bool + 'static>>,
+    /// The count of the accepted media, used to generate the placeholder tokens.
+    media_count: usize,
+    selection_toolbar: Option<SelectionToolbar>,
     pub(crate) scroll_handle: ScrollHandle,
     /// The deferred scroll offset to apply on next layout.
     pub(crate) deferred_scroll_offset: Option<Point<Pixels>>,
@@ -309,6 +362,8 @@ pub struct InputState {
     /// The mask pattern for formatting the input text
     pub(crate) mask_pattern: MaskPattern,
     pub(super) placeholder: SharedString,
+    /// The extra text styles by the byte ranges, used by the rich text editor.
+    pub(super) text_styles: Vec<(Range<usize>, HighlightStyle)>,
 
     /// Popover
     diagnostic_popover: Option<Entity<DiagnosticPopover>>,
@@ -329,6 +384,8 @@ pub struct InputState {
     _pending_update: bool,
     /// A flag to indicate if we should ignore the next completion event.
     pub(super) silent_replace_text: bool,
+    /// The edits are batched in one [`InputEvent::Change`] if this is Some, see [`Self::batch_changes`].
+    pending_changes: Option<ChangeSet>,
 
     /// To remember the horizontal column (x-coordinate) of the cursor position for keep column for move up/down.
     ///
@@ -338,6 +395,7 @@ pub struct InputState {
     _subscriptions: Vec<Subscription>,
 
     pub(super) _context_menu_task: Task<Result<()>>,
+    _scroll_task: Task<()>,
 }
 
 impl EventEmitter<InputEvent> for InputState {}
@@ -367,6 +425,11 @@ impl InputState {
             }),
             cx.on_focus(&focus_handle, window, Self::on_focus),
             cx.on_blur(&focus_handle, window, Self::on_blur),
+            // Re-wrap the text when the window is moved to a monitor with a different DPI.
+            cx.observe_scale_factor(window, |input, _, _, cx| {
+                input.text_wrapper.refresh(cx);
+                cx.notify();
+            }),
         ];
 
         let text_style = window.text_style();
@@ -394,9 +457,21 @@ impl InputState {
             masked: false,
             clean_on_escape: false,
             soft_wrap: true,
+            show_invisibles: false,
+            highlight_trailing_whitespace: false,
+            indent_guides: false,
+            bracket_colorization: false,
+            sticky_scroll: false,
+            link_providers: vec![Rc::new(UrlLinkProvider)],
+            hover_link: None,
+            decorations: Decorations::default(),
+            bookmarks: vec![],
             loading: false,
             pattern: None,
             validate: None,
+            accept_media: None,
+            media_count: 0,
+            selection_toolbar: None,
             mode: InputMode::SingleLine,
             last_layout: None,
             last_bounds: None,
@@ -408,6 +483,7 @@ impl InputState {
             deferred_scroll_offset: None,
             preferred_column: None,
             placeholder: SharedString::default(),
+            text_styles: vec![],
             mask_pattern: MaskPattern::default(),
             lsp: Lsp::default(),
             diagnostic_popover: None,
@@ -417,8 +493,10 @@ impl InputState {
             hover_popover: None,
             hover_definition: HoverDefinition::default(),
             silent_replace_text: false,
+            pending_changes: None,
             _subscriptions,
             _context_menu_task: Task::ready(Ok(())),
+            _scroll_task: Task::ready(()),
             _pending_update: false,
         }
     }
@@ -508,6 +586,123 @@ impl InputState {
         cx.notify();
     }
 
+    /// Set true to render the spaces and tabs with faint glyphs, only for [`InputMode::CodeEditor`] mode.
+    pub fn show_invisibles(mut self, show: bool) -> Self {
+        debug_assert!(self.mode.is_code_editor());
+        self.show_invisibles = show;
+        self
+    }
+
+    /// Set show invisibles, only for [`InputMode::CodeEditor`] mode.
+    pub fn set_show_invisibles(&mut self, show: bool, _: &mut Window, cx: &mut Context<Self>) {
+        debug_assert!(self.mode.is_code_editor());
+        self.show_invisibles = show;
+        cx.notify();
+    }
+
+    /// Set true to highlight the trailing whitespace of the lines, only for [`InputMode::CodeEditor`] mode.
+    pub fn highlight_trailing_whitespace(mut self, highlight: bool) -> Self {
+        debug_assert!(self.mode.is_code_editor());
+        self.highlight_trailing_whitespace = highlight;
+        self
+    }
+
+    /// Set highlight trailing whitespace, only for [`InputMode::CodeEditor`] mode.
+    pub fn set_highlight_trailing_whitespace(
+        &mut self,
+        highlight: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        debug_assert!(self.mode.is_code_editor());
+        self.highlight_trailing_whitespace = highlight;
+        cx.notify();
+    }
+
+    /// Set true to show the indent guides, only for [`InputMode::CodeEditor`] mode.
+    ///
+    /// The guide of the block containing the cursor is emphasized.
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        debug_assert!(self.mode.is_code_editor());
+        self.indent_guides = indent_guides;
+        self
+    }
+
+    /// Set indent guides, only for [`InputMode::CodeEditor`] mode.
+    pub fn set_indent_guides(
+        &mut self,
+        indent_guides: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        debug_assert!(self.mode.is_code_editor());
+        self.indent_guides = indent_guides;
+        cx.notify();
+    }
+
+    /// Set true to colorize the matching brackets by the nesting depth,
+    /// only for [`InputMode::CodeEditor`] mode.
+    pub fn bracket_colorization(mut self, colorize: bool) -> Self {
+        debug_assert!(self.mode.is_code_editor());
+        self.bracket_colorization = colorize;
+        self
+    }
+
+    /// Set bracket colorization, only for [`InputMode::CodeEditor`] mode.
+    pub fn set_bracket_colorization(
+        &mut self,
+        colorize: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        debug_assert!(self.mode.is_code_editor());
+        self.bracket_colorization = colorize;
+        cx.notify();
+    }
+
+    /// Set true to show the signatures of the scopes enclosing the first visible line
+    /// at the top of the editor, only for [`InputMode::CodeEditor`] mode.
+    ///
+    /// Click a sticky line to move the cursor to the scope.
+    pub fn sticky_scroll(mut self, sticky_scroll: bool) -> Self {
+        debug_assert!(self.mode.is_code_editor());
+        self.sticky_scroll = sticky_scroll;
+        self
+    }
+
+    /// Set sticky scroll, only for [`InputMode::CodeEditor`] mode.
+    pub fn set_sticky_scroll(
+        &mut self,
+        sticky_scroll: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        debug_assert!(self.mode.is_code_editor());
+        self.sticky_scroll = sticky_scroll;
+        cx.notify();
+    }
+
+    /// Set the providers to detect the links in the text, default is [`UrlLinkProvider`],
+    /// set empty to disable, only for the multi-line modes.
+    ///
+    /// The links are underlined, and opened by `Cmd + Click` (`Ctrl + Click` on Windows and Linux).
+    pub fn link_providers(mut self, providers: Vec<Rc<dyn LinkProvider>>) -> Self {
+        self.link_providers = providers;
+        self
+    }
+
+    /// Set the link providers, only for the multi-line modes.
+    pub fn set_link_providers(
+        &mut self,
+        providers: Vec<Rc<dyn LinkProvider>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.link_providers = providers;
+        self.hover_link = None;
+        cx.notify();
+    }
+
     /// Set the tab size for the input.
     ///
     /// Only for [`InputMode::MultiLine`] and [`InputMode::CodeEditor`] mode.
@@ -790,6 +985,29 @@ impl InputState {
         self
     }
 
+    /// Accept the images pasted or dropped into the input, the `f` returns false to reject the image.
+    ///
+    /// The accepted image inserts a placeholder token (e.g.: `[Image #1]`) at the cursor
+    /// and emits [`InputEvent::MediaAdded`], so you can upload it and replace the token.
+    ///
+    /// Only for [`InputMode::MultiLine`] mode.
+    pub fn accept_media(
+        mut self,
+        f: impl Fn(&Image, &mut Context<Self>) -> bool + 'static,
+    ) -> Self {
+        debug_assert!(self.mode.is_multi_line());
+        self.accept_media = Some(Box::new(f));
+        self
+    }
+
+    /// Set the [`SelectionToolbar`] to show above the selected text.
+    ///
+    /// The toolbar is not shown when the input is masked.
+    pub fn selection_toolbar(mut self, toolbar: SelectionToolbar) -> Self {
+        self.selection_toolbar = Some(toolbar);
+        self
+    }
+
     /// Set true to show indicator at the input right.
     ///
     /// Only for [`InputMode::SingleLine`] mode.
@@ -858,11 +1076,21 @@ impl InputState {
     }
 
     pub(super) fn select_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
-        self.select_to(self.previous_boundary(self.cursor()), cx);
+        let offset = if self.is_visual_step_forward(true) {
+            self.next_boundary(self.cursor())
+        } else {
+            self.previous_boundary(self.cursor())
+        };
+        self.select_to(offset, cx);
     }
 
     pub(super) fn select_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
-        self.select_to(self.next_boundary(self.cursor()), cx);
+        let offset = if self.is_visual_step_forward(false) {
+            self.next_boundary(self.cursor())
+        } else {
+            self.previous_boundary(self.cursor())
+        };
+        self.select_to(offset, cx);
     }
 
     pub(super) fn select_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
@@ -953,8 +1181,7 @@ impl InputState {
         let left_part = self.text.slice(0..offset).to_string();
 
         UnicodeSegmentation::split_word_bound_indices(left_part.as_str())
-            .filter(|(_, s)| !s.trim_start().is_empty())
-            .next_back()
+            .rfind(|(_, s)| !s.trim_start().is_empty())
             .map(|(i, _)| i)
             .unwrap_or(0)
     }
@@ -1199,6 +1426,10 @@ impl InputState {
     }
 
     pub(super) fn indent(&mut self, block: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.batch_changes(cx, |this, cx| this.indent_lines(block, window, cx));
+    }
+
+    fn indent_lines(&mut self, block: bool, window: &mut Window, cx: &mut Context<Self>) {
         let Some(tab_size) = self.mode.tab_size() else {
             cx.propagate();
             return;
@@ -1257,6 +1488,10 @@ impl InputState {
     }
 
     pub(super) fn outdent(&mut self, block: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.batch_changes(cx, |this, cx| this.outdent_lines(block, window, cx));
+    }
+
+    fn outdent_lines(&mut self, block: bool, window: &mut Window, cx: &mut Context<Self>) {
         let Some(tab_size) = self.mode.tab_size() else {
             cx.propagate();
             return;
@@ -1333,7 +1568,7 @@ impl InputState {
     pub(super) fn clean(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         self.replace_text("", window, cx);
         self.selected_range = (0..0).into();
-        self.scroll_to(0, cx);
+        self.scroll_into_view(0, cx);
     }
 
     pub(super) fn escape(&mut self, action: &Escape, window: &mut Window, cx: &mut Context<Self>) {
@@ -1369,6 +1604,9 @@ impl InputState {
         self.selecting = true;
         let offset = self.index_for_mouse_position(event.position);
 
+        if self.handle_click_link(event, offset, window, cx) {
+            return;
+        }
         if self.handle_click_hover_definition(event, offset, window, cx) {
             return;
         }
@@ -1410,7 +1648,9 @@ impl InputState {
     ) {
         // Show diagnostic popover on mouse move
         let offset = self.index_for_mouse_position(event.position);
-        self.handle_mouse_move(offset, event, window, cx);
+        if !self.handle_hover_link(offset, cx) {
+            self.handle_mouse_move(offset, event, window, cx);
+        }
 
         if self.mode.is_code_editor() {
             if let Some(diagnostic) = self
@@ -1457,8 +1697,12 @@ impl InputState {
     }
 
     fn update_scroll_offset(&mut self, offset: Option<Point<Pixels>>, cx: &mut Context<Self>) {
-        let mut offset = offset.unwrap_or(self.scroll_handle.offset());
+        let offset = self.clamp_scroll_offset(offset.unwrap_or(self.scroll_handle.offset()));
+        self.scroll_handle.set_offset(offset);
+        cx.notify();
+    }
 
+    fn clamp_scroll_offset(&self, mut offset: Point<Pixels>) -> Point<Pixels> {
         let safe_y_range =
             (-self.scroll_size.height + self.input_bounds.size.height).min(px(0.0))..px(0.);
         let safe_x_range =
@@ -1470,11 +1714,43 @@ impl InputState {
             offset.y.clamp(safe_y_range.start, safe_y_range.end)
         };
         offset.x = offset.x.clamp(safe_x_range.start, safe_x_range.end);
-        self.scroll_handle.set_offset(offset);
-        cx.notify();
+        offset
     }
 
-    pub(crate) fn scroll_to(&mut self, offset: usize, cx: &mut Context<Self>) {
+    /// Scroll to the offset or the line, animate the scrolling if `smooth` is true.
+    ///
+    /// The cursor is not moved, the [`ScrollTarget::Row`] is the line index (0-based).
+    pub fn scroll_to(
+        &mut self,
+        target: impl Into<ScrollTarget>,
+        smooth: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let offset = match target.into() {
+            ScrollTarget::Offset(offset) => offset,
+            ScrollTarget::Row(row) => {
+                let line_height = self
+                    .last_layout
+                    .as_ref()
+                    .map(|layout| layout.line_height)
+                    .unwrap_or(window.line_height());
+                let y = self
+                    .text_wrapper
+                    .lines
+                    .iter()
+                    .take(row)
+                    .fold(px(0.), |y, line| y + line.height(line_height));
+                point(self.scroll_handle.offset().x, -y)
+            }
+        };
+
+        let offset = self.clamp_scroll_offset(offset);
+        self._scroll_task =
+            scroll::scroll_to_offset(&self.scroll_handle, offset, smooth, window, cx);
+    }
+
+    pub(crate) fn scroll_into_view(&mut self, offset: usize, cx: &mut Context<Self>) {
         let Some(last_layout) = self.last_layout.as_ref() else {
             return;
         };
@@ -1540,13 +1816,43 @@ impl InputState {
         window.show_character_palette();
     }
 
-    pub(super) fn copy(&mut self, _: &Copy, _: &mut Window, cx: &mut Context<Self>) {
+    /// Write the selected text into the clipboard,
+    /// with the syntax highlighted HTML in [`InputMode::CodeEditor`] mode.
+    fn write_selection_to_clipboard(&self, window: &Window, cx: &mut App) {
+        let range: Range<usize> = self.selected_range.into();
+        let selected_text = self.text.slice(range.clone()).to_string();
+        let mut content = ClipboardContent::new(selected_text.clone());
+
+        if let InputMode::CodeEditor { highlighter, .. } = &self.mode {
+            if let Some(highlighter) = highlighter.borrow().as_ref() {
+                let styles = highlighter
+                    .styles(&range, &cx.theme().highlight_theme)
+                    .into_iter()
+                    .map(|(style_range, style)| {
+                        (
+                            style_range.start.saturating_sub(range.start)
+                                ..style_range.end.saturating_sub(range.start),
+                            style,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                content = content.html(highlighted_html(
+                    &selected_text,
+                    &styles,
+                    &window.text_style().font_family,
+                ));
+            }
+        }
+
+        content.write(cx);
+    }
+
+    pub(super) fn copy(&mut self, _: &Copy, window: &mut Window, cx: &mut Context<Self>) {
         if self.selected_range.is_empty() {
             return;
         }
 
-        let selected_text = self.text.slice(self.selected_range).to_string();
-        cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
+        self.write_selection_to_clipboard(window, cx);
     }
 
     pub(super) fn cut(&mut self, _: &Cut, window: &mut Window, cx: &mut Context<Self>) {
@@ -1554,22 +1860,86 @@ impl InputState {
             return;
         }
 
-        let selected_text = self.text.slice(self.selected_range).to_string();
-        cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
+        self.write_selection_to_clipboard(window, cx);
 
         self.replace_text_in_range_silent(None, "", window, cx);
     }
 
     pub(super) fn paste(&mut self, _: &Paste, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
+            let mut has_media = false;
+            for entry in clipboard.entries() {
+                if let ClipboardEntry::Image(image) = entry {
+                    has_media |= self.insert_media(image.clone(), window, cx);
+                }
+            }
+
             let mut new_text = clipboard.text().unwrap_or_default();
             if !self.mode.is_multi_line() {
                 new_text = new_text.replace('\n', "");
             }
 
-            self.replace_text_in_range_silent(None, &new_text, window, cx);
-            self.scroll_to(self.cursor(), cx);
+            if !has_media || !new_text.is_empty() {
+                self.replace_text_in_range_silent(None, &new_text, window, cx);
+            }
+            self.scroll_into_view(self.cursor(), cx);
+        }
+    }
+
+    /// Insert the placeholder token of the image at the cursor and emit [`InputEvent::MediaAdded`].
+    ///
+    /// Returns false if the media is not accepted.
+    fn insert_media(&mut self, image: Image, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(accept_media) = self.accept_media.as_ref() else {
+            return false;
+        };
+        if !accept_media(&image, cx) {
+            return false;
+        }
+
+        self.media_count += 1;
+        let token = SharedString::from(format!("[Image #{}]", self.media_count));
+        self.replace_text_in_range_silent(None, &token, window, cx);
+        cx.emit(InputEvent::MediaAdded {
+            image: Arc::new(image),
+            token,
+        });
+        true
+    }
+
+    fn on_drop_paths(
+        &mut self,
+        paths: &ExternalPaths,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.disabled {
+            return;
         }
+
+        let paths = paths.paths().to_vec();
+        cx.spawn_in(window, async move |this, cx| {
+            let images = cx
+                .background_spawn(async move {
+                    paths
+                        .iter()
+                        .filter_map(|path| {
+                            let format = image_format_for_path(path)?;
+                            let bytes = std::fs::read(path).ok()?;
+                            Some(Image::from_bytes(format, bytes))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .await;
+
+            _ = this.update_in(cx, |this, window, cx| {
+                for image in images {
+                    this.insert_media(image, window, cx);
+                }
+                this.scroll_into_view(this.cursor(), cx);
+            });
+        })
+        .detach();
     }
 
     fn push_history(&mut self, text: &Rope, range: &Range<usize>, new_text: &str) {
@@ -1588,10 +1958,17 @@ impl InputState {
     pub(super) fn undo(&mut self, _: &Undo, window: &mut Window, cx: &mut Context<Self>) {
         self.history.ignore = true;
         if let Some(changes) = self.history.undo() {
-            for change in changes {
-                let range_utf16 = self.range_to_utf16(&change.new_range.into());
-                self.replace_text_in_range_silent(Some(range_utf16), &change.old_text, window, cx);
-            }
+            self.batch_changes(cx, |this, cx| {
+                for change in changes {
+                    let range_utf16 = this.range_to_utf16(&change.new_range.into());
+                    this.replace_text_in_range_silent(
+                        Some(range_utf16),
+                        &change.old_text,
+                        window,
+                        cx,
+                    );
+                }
+            });
         }
         self.history.ignore = false;
     }
@@ -1599,14 +1976,56 @@ impl InputState {
     pub(super) fn redo(&mut self, _: &Redo, window: &mut Window, cx: &mut Context<Self>) {
         self.history.ignore = true;
         if let Some(changes) = self.history.redo() {
-            for change in changes {
-                let range_utf16 = self.range_to_utf16(&change.old_range.into());
-                self.replace_text_in_range_silent(Some(range_utf16), &change.new_text, window, cx);
-            }
+            self.batch_changes(cx, |this, cx| {
+                for change in changes {
+                    let range_utf16 = this.range_to_utf16(&change.old_range.into());
+                    this.replace_text_in_range_silent(
+                        Some(range_utf16),
+                        &change.new_text,
+                        window,
+                        cx,
+                    );
+                }
+            });
         }
         self.history.ignore = false;
     }
 
+    /// Returns the selected byte range.
+    pub(crate) fn selected_range(&self) -> Range<usize> {
+        self.selected_range.into()
+    }
+
+    /// Replace the text in the byte `range`, and move the cursor to the end of the inserted text.
+    pub(crate) fn replace_range(
+        &mut self,
+        range: Range<usize>,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range_utf16 = self.range_to_utf16(&range);
+        self.replace_text_in_range_silent(Some(range_utf16), new_text, window, cx);
+    }
+
+    /// Clear the undo history, used when the text is changed out of the input.
+    pub(crate) fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Set the extra text styles by the byte ranges, the styles are combined with the text style.
+    ///
+    /// This does not notify, so it can be called in the render of the parent view.
+    pub(crate) fn set_text_styles(&mut self, styles: Vec<(Range<usize>, HighlightStyle)>) {
+        self.text_styles = styles;
+    }
+
+    /// Returns the display row (with soft wrap) of the cursor and the count of the display rows.
+    pub(crate) fn cursor_display_row(&self) -> (usize, usize) {
+        let row = self.text_wrapper.offset_to_display_point(self.cursor()).row;
+        (row, self.text_wrapper.len())
+    }
+
     /// Get byte offset of the cursor.
     ///
     /// The offset is the UTF-8 offset.
@@ -1850,6 +2269,28 @@ impl InputState {
         offset
     }
 
+    /// Returns true if the Left (or Right by `left = false`) key moves the cursor forward,
+    /// by the x positions in the last layout, see [`bidi`] for the limits.
+    pub(super) fn is_visual_step_forward(&self, left: bool) -> bool {
+        let cursor = self.cursor();
+        let (line_ix, sub_line_ix, Some(pos)) = self.line_and_position_for_offset(cursor) else {
+            return !left;
+        };
+        let x_on_line = |offset: usize| match self.line_and_position_for_offset(offset) {
+            (l, s, Some(pos)) if offset != cursor && (l, s) == (line_ix, sub_line_ix) => {
+                Some(pos.x)
+            }
+            _ => None,
+        };
+
+        bidi::is_visual_step_forward(
+            left,
+            pos.x,
+            x_on_line(self.previous_boundary(cursor)),
+            x_on_line(self.next_boundary(cursor)),
+        )
+    }
+
     /// Returns the true to let InputElement to render cursor, when Input is focused and current BlinkCursor is visible.
     pub(crate) fn show_cursor(&self, window: &Window, cx: &App) -> bool {
         (self.focus_handle.is_focused(window) || self.is_context_menu_open(cx))
@@ -2046,6 +2487,41 @@ impl InputState {
         );
     }
 
+    /// Batch the edits applied in `f` into one [`InputEvent::Change`].
+    ///
+    /// The nested batches are merged into the outermost one.
+    pub(crate) fn batch_changes<R>(
+        &mut self,
+        cx: &mut Context<Self>,
+        f: impl FnOnce(&mut Self, &mut Context<Self>) -> R,
+    ) -> R {
+        let is_outermost = self.pending_changes.is_none();
+        if is_outermost {
+            self.pending_changes = Some(ChangeSet::default());
+        }
+
+        let result = f(self, cx);
+
+        if is_outermost {
+            if let Some(changes) = self.pending_changes.take() {
+                if !changes.is_empty() {
+                    cx.emit(InputEvent::Change(changes));
+                }
+            }
+        }
+        result
+    }
+
+    /// Emit the edit in a [`InputEvent::Change`], or push it into the pending batch.
+    fn emit_change(&mut self, edit: InputEdit, cx: &mut Context<Self>) {
+        self.update_decorations(&edit);
+        self.update_bookmarks(&edit);
+        match self.pending_changes.as_mut() {
+            Some(changes) => changes.push(edit),
+            None => cx.emit(InputEvent::Change(edit.into())),
+        }
+    }
+
     /// Replace text in range in silent.
     ///
     /// This will not trigger any UI interaction, such as auto-completion.
@@ -2130,6 +2606,11 @@ impl EntityInputHandler for InputState {
         self.text.replace(range.clone(), new_text);
 
         let mut new_offset = (range.start + new_text.len()).min(self.text.len());
+        let mut edit = InputEdit {
+            range: range.clone(),
+            old_text: old_text.slice(range.clone()).to_string(),
+            new_text: new_text.to_string(),
+        };
 
         if self.mode.is_single_line() {
             let pending_text = self.text.to_string();
@@ -2145,12 +2626,19 @@ impl EntityInputHandler for InputState {
                 let new_text_len =
                     (new_text.len() + mask_text.len()).saturating_sub(pending_text.len());
                 new_offset = (range.start + new_text_len).min(mask_text.len());
+
+                // The mask may change the whole text.
+                edit = InputEdit {
+                    range: 0..old_text.len(),
+                    old_text: old_text.to_string(),
+                    new_text: mask_text.to_string(),
+                };
             }
         }
 
         self.push_history(&old_text, &range, &new_text);
         if let Some(diagnostics) = self.mode.diagnostics_mut() {
-            diagnostics.reset(&self.text)
+            diagnostics.apply_edit(&self.text, &edit);
         }
         self.text_wrapper
             .update(&self.text, &range, &Rope::from(new_text), cx);
@@ -2165,7 +2653,7 @@ impl EntityInputHandler for InputState {
         if !self.silent_replace_text {
             self.handle_completion_trigger(&range, &new_text, window, cx);
         }
-        cx.emit(InputEvent::Change);
+        self.emit_change(edit, cx);
         cx.notify();
     }
 
@@ -2203,10 +2691,15 @@ impl EntityInputHandler for InputState {
                 return;
             }
         }
+        let edit = InputEdit {
+            range: range.clone(),
+            old_text: old_text.slice(range.clone()).to_string(),
+            new_text: new_text.to_string(),
+        };
 
         self.push_history(&old_text, &range, new_text);
         if let Some(diagnostics) = self.mode.diagnostics_mut() {
-            diagnostics.reset(&self.text)
+            diagnostics.apply_edit(&self.text, &edit);
         }
         self.text_wrapper
             .update(&self.text, &range, &Rope::from(new_text), cx);
@@ -2227,7 +2720,7 @@ impl EntityInputHandler for InputState {
                 .into();
         }
         self.mode.update_auto_grow(&self.text_wrapper);
-        cx.emit(InputEvent::Change);
+        self.emit_change(edit, cx);
         cx.notify();
     }
 
@@ -2314,6 +2807,21 @@ impl Focusable for InputState {
     }
 }
 
+impl ScrollSyncPane for Entity<InputState> {
+    fn offset(&self, cx: &App) -> Point<Pixels> {
+        self.read(cx).scroll_handle.offset()
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>, cx: &mut App) {
+        self.update(cx, |state, cx| state.update_scroll_offset(Some(offset), cx));
+    }
+
+    fn max_offset(&self, cx: &App) -> Size<Pixels> {
+        let state = self.read(cx);
+        (state.scroll_size - state.input_bounds.size).max(&Size::default())
+    }
+}
+
 impl Render for InputState {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         if self._pending_update {
@@ -2329,9 +2837,59 @@ impl Render for InputState {
             .when(self.mode.is_multi_line(), |this| this.h_full())
             .flex_grow()
             .overflow_x_hidden()
+            .when(self.accept_media.is_some(), |this| {
+                this.on_drop(cx.listener(Self::on_drop_paths))
+            })
             .child(TextElement::new(cx.entity().clone()).placeholder(self.placeholder.clone()))
             .children(self.diagnostic_popover.clone())
             .children(self.context_menu.as_ref().map(|menu| menu.render()))
             .children(self.hover_popover.clone())
+            .children(self.render_selection_toolbar(window, cx))
+    }
+}
+
+impl InputState {
+    fn render_selection_toolbar(
+        &self,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) -> Option<impl IntoElement> {
+        let toolbar = self.selection_toolbar.as_ref()?;
+        if toolbar.is_empty()
+            || self.masked
+            || self.selecting
+            || self.selected_range.is_empty()
+            || !self.focus_handle.is_focused(window)
+            || self
+                .context_menu
+                .as_ref()
+                .map_or(false, |menu| menu.is_open(cx))
+        {
+            return None;
+        }
+
+        let bounds = self.range_to_bounds(&self.selected_range.into())?;
+        let state = cx.entity();
+        Some(toolbar.render(
+            "selection-toolbar",
+            bounds,
+            move |cx| Some(state.read(cx).selected_text().to_string().into()),
+            |_, _| {},
+            cx,
+        ))
+    }
+}
+
+fn image_format_for_path(path: &Path) -> Option<ImageFormat> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        "png" => Some(ImageFormat::Png),
+        "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
+        "webp" => Some(ImageFormat::Webp),
+        "gif" => Some(ImageFormat::Gif),
+        "svg" => Some(ImageFormat::Svg),
+        "bmp" => Some(ImageFormat::Bmp),
+        "tif" | "tiff" => Some(ImageFormat::Tiff),
+        _ => None,
     }
 }
@@ -0,0 +1,268 @@
+use gpui::{
+    fill, point, px, size, App, Bounds, ContentMask, Context, CursorStyle, Hitbox, HitboxBehavior,
+    MouseButton, MouseDownEvent, Pixels, ShapedLine, TextRun, Window,
+};
+
+use crate::{
+    highlighter::SyntaxHighlighter,
+    input::{
+        element::TextElement, mode::InputMode, InputState, LastLayout, Position, RopeExt as _,
+    },
+    ActiveTheme as _,
+};
+
+/// The max lines of the sticky scroll, the innermost scopes are hidden if there are more.
+const MAX_STICKY_LINES: usize = 5;
+
+struct StickyLine {
+    row: usize,
+    line: ShapedLine,
+    line_number: Option<ShapedLine>,
+}
+
+/// The signatures of the scopes enclosing the first visible line, sticky at the top of the editor.
+#[derive(Default)]
+pub(super) struct StickyScrollLayout {
+    lines: Vec<StickyLine>,
+    bounds: Bounds<Pixels>,
+    hitbox: Option<Hitbox>,
+}
+
+/// Returns the start rows of the scopes enclosing the `row` to show in the sticky scroll,
+/// from the outermost, at most [`MAX_STICKY_LINES`].
+fn sticky_rows(highlighter: &SyntaxHighlighter, row: usize) -> Vec<usize> {
+    let mut rows = highlighter.enclosing_scopes(row);
+    rows.truncate(MAX_STICKY_LINES);
+    rows
+}
+
+/// Returns the column (in chars) of the first non-whitespace char of the line.
+fn indent_column(chars: impl IntoIterator<Item = char>) -> usize {
+    chars.into_iter().take_while(|c| c.is_whitespace()).count()
+}
+
+impl TextElement {
+    /// Layout the sticky scroll, only for the code editor mode.
+    ///
+    /// The `input_bounds` is the bounds of the element, without the scroll offset.
+    pub(super) fn layout_sticky_scroll(
+        &self,
+        last_layout: &LastLayout,
+        input_bounds: &Bounds<Pixels>,
+        line_number_len: usize,
+        window: &mut Window,
+        cx: &App,
+    ) -> StickyScrollLayout {
+        let state = self.state.read(cx);
+        let InputMode::CodeEditor { highlighter, .. } = &state.mode else {
+            return StickyScrollLayout::default();
+        };
+        if !state.sticky_scroll {
+            return StickyScrollLayout::default();
+        }
+        let highlighter = highlighter.borrow();
+        let Some(highlighter) = highlighter.as_ref() else {
+            return StickyScrollLayout::default();
+        };
+
+        let rows = sticky_rows(highlighter, last_layout.visible_range.start);
+        if rows.is_empty() {
+            return StickyScrollLayout::default();
+        }
+
+        let text = &state.text;
+        let text_style = window.text_style();
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let highlight_theme = &cx.theme().highlight_theme;
+        let lines = rows
+            .into_iter()
+            .map(|row| {
+                let line_start = text.line_start_offset(row);
+                let line_text = text.slice_line(row).to_string();
+                let line_text = line_text.trim_end_matches('\r');
+                let line_end = line_start + line_text.len();
+
+                let mut runs = vec![];
+                let mut offset = line_start;
+                for (range, style) in highlighter.styles(&(line_start..line_end), highlight_theme) {
+                    let end = range.end.min(line_end);
+                    if end > offset {
+                        runs.push(text_style.clone().highlight(style).to_run(end - offset));
+                        offset = end;
+                    }
+                }
+                if offset < line_end {
+                    runs.push(text_style.to_run(line_end - offset));
+                }
+                let line = window.text_system().shape_line(
+                    line_text.to_string().into(),
+                    font_size,
+                    &runs,
+                    None,
+                );
+
+                let line_number = state.mode.line_number().then(|| {
+                    window.text_system().shape_line(
+                        format!("{:>width$}", row + 1, width = line_number_len).into(),
+                        font_size,
+                        &[TextRun {
+                            len: line_number_len,
+                            font: text_style.font(),
+                            color: cx.theme().muted_foreground,
+                            background_color: None,
+                            underline: None,
+                            strikethrough: None,
+                        }],
+                        None,
+                    )
+                });
+
+                StickyLine {
+                    row,
+                    line,
+                    line_number,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let bounds = Bounds::new(
+            input_bounds.origin,
+            size(
+                input_bounds.size.width,
+                last_layout.line_height * lines.len() as f32,
+            ),
+        );
+        StickyScrollLayout {
+            lines,
+            bounds,
+            hitbox: Some(window.insert_hitbox(bounds, HitboxBehavior::Normal)),
+        }
+    }
+
+    /// Paint the sticky scroll above the text and line numbers.
+    ///
+    /// The `scroll_x` is the horizontal scroll offset, to keep the sticky lines aligned with the text.
+    pub(super) fn paint_sticky_scroll(
+        &self,
+        layout: &StickyScrollLayout,
+        last_layout: &LastLayout,
+        scroll_x: Pixels,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(hitbox) = layout.hitbox.as_ref() else {
+            return;
+        };
+
+        let bounds = layout.bounds;
+        let line_height = last_layout.line_height;
+        let line_number_width = last_layout.line_number_width;
+        window.paint_quad(fill(bounds, cx.theme().editor_background()));
+
+        let text_bounds = Bounds::from_corners(
+            point(bounds.origin.x + line_number_width, bounds.origin.y),
+            bounds.bottom_right(),
+        );
+        window.with_content_mask(
+            Some(ContentMask {
+                bounds: text_bounds,
+            }),
+            |window| {
+                for (ix, line) in layout.lines.iter().enumerate() {
+                    let p = point(
+                        text_bounds.origin.x + scroll_x,
+                        bounds.origin.y + line_height * ix as f32,
+                    );
+                    _ = line.line.paint(p, line_height, window, cx);
+                }
+            },
+        );
+
+        for (ix, line) in layout.lines.iter().enumerate() {
+            if let Some(line_number) = line.line_number.as_ref() {
+                let p = point(bounds.origin.x, bounds.origin.y + line_height * ix as f32);
+                _ = line_number.paint(p, line_height, window, cx);
+            }
+        }
+
+        window.paint_quad(fill(
+            Bounds::new(
+                point(bounds.origin.x, bounds.bottom() - px(1.)),
+                size(bounds.size.width, px(1.)),
+            ),
+            cx.theme().border,
+        ));
+
+        window.set_cursor_style(CursorStyle::PointingHand, hitbox);
+        window.on_mouse_event({
+            let state = self.state.clone();
+            let hitbox = hitbox.clone();
+            let rows: Vec<usize> = layout.lines.iter().map(|line| line.row).collect();
+            move |event: &MouseDownEvent, phase, window, cx| {
+                if !phase.bubble()
+                    || event.button != MouseButton::Left
+                    || !hitbox.is_hovered(window)
+                {
+                    return;
+                }
+
+                let ix = ((event.position.y - bounds.origin.y) / line_height) as usize;
+                if let Some(row) = rows.get(ix).copied() {
+                    cx.stop_propagation();
+                    state.update(cx, |state, cx| state.go_to_scope(row, window, cx));
+                }
+            }
+        });
+    }
+}
+
+impl InputState {
+    /// Move the cursor to the start of the scope at the `row`, by clicking the sticky scroll.
+    fn go_to_scope(&mut self, row: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let column = indent_column(self.text.slice_line(row).chars());
+        self.set_cursor_position(Position::new(row as u32, column as u32), window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::indent_column;
+
+    #[test]
+    fn test_indent_column() {
+        assert_eq!(indent_column("fn foo() {".chars()), 0);
+        assert_eq!(indent_column("    fn foo() {".chars()), 4);
+        assert_eq!(indent_column("\t\tdef foo():".chars()), 2);
+        assert_eq!(indent_column("   ".chars()), 3);
+        assert_eq!(indent_column("".chars()), 0);
+    }
+
+    #[cfg(feature = "tree-sitter-languages")]
+    #[test]
+    fn test_sticky_rows() {
+        use ropey::Rope;
+
+        use super::{sticky_rows, MAX_STICKY_LINES};
+        use crate::highlighter::SyntaxHighlighter;
+
+        // The nested modules deeper than the max lines, the innermost scopes are hidden.
+        let depth = MAX_STICKY_LINES + 2;
+        let mut text = String::new();
+        for i in 0..depth {
+            text.push_str(&format!("{}mod m{} {{\n", "    ".repeat(i), i));
+        }
+        text.push_str(&format!("{}const A: u8 = 1;\n", "    ".repeat(depth)));
+        for i in (0..depth).rev() {
+            text.push_str(&format!("{}}}\n", "    ".repeat(i)));
+        }
+
+        let mut highlighter = SyntaxHighlighter::new("rust");
+        highlighter.update(None, &Rope::from(text.as_str()));
+        assert_eq!(
+            sticky_rows(&highlighter, depth),
+            (0..MAX_STICKY_LINES).collect::<Vec<_>>()
+        );
+        assert_eq!(sticky_rows(&highlighter, 2), vec![0, 1]);
+        assert!(sticky_rows(&highlighter, 0).is_empty());
+    }
+}
@@ -5,12 +5,13 @@ use gpui::{
     RenderOnce, StyleRefinement, Styled, Window,
 };
 
+use crate::accessibility::{Accessibility, AccessibilityProps, Accessible, Role};
 use crate::button::{Button, ButtonVariants as _};
 use crate::indicator::Indicator;
 use crate::input::clear_button;
 use crate::input::element::{LINE_NUMBER_RIGHT_MARGIN, RIGHT_MARGIN};
 use crate::scroll::Scrollbar;
-use crate::{h_flex, Selectable, StyledExt};
+use crate::{h_flex, DensityExt as _, DirectionExt as _, Selectable, StyleOverrides, StyledExt};
 use crate::{v_flex, ActiveTheme};
 use crate::{IconName, Size};
 use crate::{Sizable, StyleSized};
@@ -33,6 +34,7 @@ pub struct TextInput {
     focus_bordered: bool,
     tab_index: isize,
     selected: bool,
+    accessibility: AccessibilityProps,
 }
 
 impl Sizable for TextInput {
@@ -42,6 +44,12 @@ impl Sizable for TextInput {
     }
 }
 
+impl Accessible for TextInput {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Selectable for TextInput {
     fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -71,6 +79,7 @@ impl TextInput {
             focus_bordered: true,
             tab_index: 0,
             selected: false,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -250,6 +259,15 @@ impl RenderOnce for TextInput {
             state.disabled = self.disabled;
         });
 
+        let mut props = AccessibilityProps::new(Role::TextInput).disabled(self.disabled);
+        props.label = Some(self.state.read(cx).placeholder.clone()).filter(|s| !s.is_empty());
+        Accessibility::declare(
+            &("input", self.state.entity_id()).into(),
+            &props.merge(&self.accessibility),
+            window,
+            cx,
+        );
+
         let state = self.state.read(cx);
         let focused = state.focus_handle.is_focused(window);
         let gap_x = match self.size {
@@ -300,6 +318,30 @@ impl RenderOnce for TextInput {
                             .on_action(window.listener_for(&self.state, InputState::outdent_inline))
                             .on_action(window.listener_for(&self.state, InputState::indent_block))
                             .on_action(window.listener_for(&self.state, InputState::outdent_block))
+                            .on_action(window.listener_for(
+                                &self.state,
+                                InputState::on_action_trim_trailing_whitespace,
+                            ))
+                            .on_action(
+                                window.listener_for(
+                                    &self.state,
+                                    InputState::on_action_toggle_bookmark,
+                                ),
+                            )
+                            .on_action(
+                                window
+                                    .listener_for(&self.state, InputState::on_action_next_bookmark),
+                            )
+                            .on_action(
+                                window
+                                    .listener_for(&self.state, InputState::on_action_prev_bookmark),
+                            )
+                            .on_action(
+                                window.listener_for(
+                                    &self.state,
+                                    InputState::on_action_show_bookmarks,
+                                ),
+                            )
                     })
                     .on_action(
                         window.listener_for(&self.state, InputState::on_action_toggle_code_actions),
@@ -381,6 +423,9 @@ impl RenderOnce for TextInput {
             })
             .items_center()
             .gap(gap_x)
+            .density(cx.theme().density)
+            .direction(cx.theme().direction)
+            .refine_style(&StyleOverrides::style_for::<Self>(cx))
             .refine_style(&self.style)
             .children(prefix)
             .when(state.mode.is_multi_line(), |mut this| {
@@ -225,6 +225,11 @@ impl TextWrapper {
         }
     }
 
+    /// Recalculate all wrapped lines of the current text.
+    pub(super) fn refresh(&mut self, cx: &mut App) {
+        self.update_all(&self.text.clone(), cx);
+    }
+
     /// Update the text wrapper and recalculate the wrapped lines.
     ///
     /// If the `text` is the same as the current text, do nothing.
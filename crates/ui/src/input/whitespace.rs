@@ -0,0 +1,245 @@
+use std::ops::Range;
+
+use gpui::{
+    fill, point, px, size, App, Bounds, Context, Hsla, Path, PathBuilder, Pixels, Point, Window,
+};
+use ropey::Rope;
+
+use crate::{
+    input::{element::TextElement, InputState, LastLayout, RopeExt as _, TrimTrailingWhitespace},
+    ActiveTheme as _,
+};
+
+/// Returns the byte range of the trailing spaces and tabs in the `line`, the ending `\r` is excluded.
+fn trailing_whitespace(line: &str) -> Option<Range<usize>> {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let start = line.trim_end_matches([' ', '\t']).len();
+    (start < line.len()).then_some(start..line.len())
+}
+
+/// Returns the byte ranges of the trailing whitespace of the `rows` in the `text`.
+fn trailing_whitespace_ranges(text: &Rope, rows: Range<usize>) -> Vec<Range<usize>> {
+    let rows = rows.start..rows.end.min(text.lines_len());
+    rows.filter_map(|row| {
+        let line_start = text.line_start_offset(row);
+        trailing_whitespace(&text.slice_line(row).to_string())
+            .map(|range| line_start + range.start..line_start + range.end)
+    })
+    .collect()
+}
+
+/// Returns the `offset` after the `removed` ranges (sorted) are deleted.
+fn offset_after_removed(offset: usize, removed: &[Range<usize>]) -> usize {
+    let removed_len: usize = removed
+        .iter()
+        .map(|range| range.end.min(offset).saturating_sub(range.start))
+        .sum();
+    offset - removed_len
+}
+
+/// The invisible characters and trailing whitespace in the visible lines.
+#[derive(Default)]
+pub(super) struct InvisiblesLayout {
+    /// The center of the spaces.
+    spaces: Vec<Point<Pixels>>,
+    /// The arrows of the tabs.
+    tabs: Option<Path<Pixels>>,
+    trailing_paths: Vec<Path<Pixels>>,
+}
+
+impl InvisiblesLayout {
+    /// Paint the trailing whitespace background, before the text.
+    pub(super) fn paint_background(&self, window: &mut Window, cx: &mut App) {
+        if self.trailing_paths.is_empty() {
+            return;
+        }
+
+        let color = cx
+            .theme()
+            .highlight_theme
+            .style
+            .editor_trailing_whitespace
+            .unwrap_or(cx.theme().danger.opacity(0.2));
+        for path in self.trailing_paths.iter() {
+            window.paint_path(path.clone(), color);
+        }
+    }
+
+    /// Paint the glyphs of the spaces and tabs, after the text.
+    pub(super) fn paint(&self, window: &mut Window, cx: &mut App) {
+        let color: Hsla = cx
+            .theme()
+            .highlight_theme
+            .style
+            .editor_invisible
+            .unwrap_or(cx.theme().muted_foreground.opacity(0.4));
+        let dot = px(2.);
+        for center in self.spaces.iter() {
+            window.paint_quad(
+                fill(
+                    Bounds::new(*center - point(dot / 2., dot / 2.), size(dot, dot)),
+                    color,
+                )
+                .corner_radii(dot / 2.),
+            );
+        }
+        if let Some(path) = self.tabs.clone() {
+            window.paint_path(path, color);
+        }
+    }
+}
+
+impl TextElement {
+    /// Layout the invisible characters and the trailing whitespace of the visible lines,
+    /// only for the code editor mode.
+    pub(super) fn layout_invisibles(
+        &self,
+        last_layout: &LastLayout,
+        bounds: &Bounds<Pixels>,
+        cx: &App,
+    ) -> InvisiblesLayout {
+        let state = self.state.read(cx);
+        let mut layout = InvisiblesLayout::default();
+        if !state.mode.is_code_editor()
+            || state.text.len() == 0
+            || (!state.show_invisibles && !state.highlight_trailing_whitespace)
+        {
+            return layout;
+        }
+
+        if state.highlight_trailing_whitespace {
+            let cursor = state.cursor();
+            for range in trailing_whitespace_ranges(&state.text, last_layout.visible_range.clone())
+            {
+                // Skip the whitespace being typed at the cursor.
+                if (range.start..=range.end).contains(&cursor) {
+                    continue;
+                }
+                if let Some(path) = Self::layout_match_range(range, last_layout, bounds) {
+                    layout.trailing_paths.push(path);
+                }
+            }
+        }
+
+        if !state.show_invisibles {
+            return layout;
+        }
+
+        let line_height = last_layout.line_height;
+        let arrow = px(3.);
+        let mut tabs = PathBuilder::stroke(px(1.));
+        let mut has_tabs = false;
+        let mut offset_y = last_layout.visible_top;
+        for line in last_layout.lines.iter() {
+            for wrapped in line.wrapped_lines.iter() {
+                let origin = bounds.origin + point(last_layout.line_number_width, offset_y);
+                offset_y += line_height;
+
+                // The glyph index is in order, same as `ShapedLine::x_for_index` but faster for long lines.
+                let glyphs: Vec<(usize, Pixels)> = wrapped
+                    .runs
+                    .iter()
+                    .flat_map(|run| run.glyphs.iter().map(|g| (g.index, g.position.x)))
+                    .collect();
+                let x_for_index = |index: usize| {
+                    glyphs
+                        .get(glyphs.partition_point(|(ix, _)| *ix < index))
+                        .map_or(wrapped.width, |(_, x)| *x)
+                };
+
+                let center_y = origin.y + line_height / 2.;
+                for (ix, c) in wrapped.text.char_indices() {
+                    if c != ' ' && c != '\t' {
+                        continue;
+                    }
+
+                    let start_x = origin.x + x_for_index(ix);
+                    let end_x = origin.x + x_for_index(ix + 1);
+                    if c == ' ' {
+                        layout.spaces.push(point((start_x + end_x) / 2., center_y));
+                    } else if end_x - start_x > arrow * 2. {
+                        let (start_x, end_x) = (start_x + px(2.), end_x - px(2.));
+                        tabs.move_to(point(start_x, center_y));
+                        tabs.line_to(point(end_x, center_y));
+                        tabs.move_to(point(end_x - arrow, center_y - arrow));
+                        tabs.line_to(point(end_x, center_y));
+                        tabs.line_to(point(end_x - arrow, center_y + arrow));
+                        has_tabs = true;
+                    }
+                }
+            }
+        }
+
+        if has_tabs {
+            layout.tabs = tabs.build().ok();
+        }
+        layout
+    }
+}
+
+impl InputState {
+    pub(super) fn on_action_trim_trailing_whitespace(
+        &mut self,
+        _: &TrimTrailingWhitespace,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.trim_trailing_whitespace(window, cx);
+    }
+
+    /// Remove the trailing spaces and tabs of all lines, can be undone in one step.
+    pub fn trim_trailing_whitespace(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled || !self.mode.is_multi_line() {
+            return;
+        }
+
+        let ranges = trailing_whitespace_ranges(&self.text, 0..self.text.lines_len());
+        if ranges.is_empty() {
+            return;
+        }
+
+        let selected_range = self.selected_range;
+        self.batch_changes(cx, |this, cx| {
+            // Remove from the end to keep the offsets of the previous ranges.
+            for range in ranges.iter().rev() {
+                this.replace_text_in_range_silent(Some(this.range_to_utf16(range)), "", window, cx);
+            }
+        });
+
+        self.selected_range = (offset_after_removed(selected_range.start, &ranges)
+            ..offset_after_removed(selected_range.end, &ranges))
+            .into();
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+
+    use super::{offset_after_removed, trailing_whitespace, trailing_whitespace_ranges};
+
+    #[test]
+    fn test_trailing_whitespace() {
+        assert_eq!(trailing_whitespace("let a = 1;"), None);
+        assert_eq!(trailing_whitespace("let a = 1;  "), Some(10..12));
+        assert_eq!(trailing_whitespace("foo \t\r"), Some(3..5));
+        assert_eq!(trailing_whitespace("    "), Some(0..4));
+        assert_eq!(trailing_whitespace(""), None);
+        assert_eq!(trailing_whitespace("\r"), None);
+
+        let text = Rope::from("a  \n  b\n\t\r\nc");
+        assert_eq!(trailing_whitespace_ranges(&text, 0..10), vec![1..3, 8..9]);
+        assert!(trailing_whitespace_ranges(&text, 1..2).is_empty());
+    }
+
+    #[test]
+    fn test_offset_after_removed() {
+        let removed = [1..3, 8..9];
+        assert_eq!(offset_after_removed(0, &removed), 0);
+        assert_eq!(offset_after_removed(2, &removed), 1);
+        assert_eq!(offset_after_removed(3, &removed), 1);
+        assert_eq!(offset_after_removed(7, &removed), 5);
+        assert_eq!(offset_after_removed(12, &removed), 9);
+    }
+}
@@ -108,7 +108,7 @@ impl DivInspector {
                 &json_input_state,
                 window,
                 |this: &mut DivInspector, state, event: &InputEvent, window, cx| match event {
-                    InputEvent::Change => {
+                    InputEvent::Change(_) => {
                         let new_style = state.read(cx).value();
                         this.edit_json(new_style.as_str(), window, cx);
                     }
@@ -119,7 +119,7 @@ impl DivInspector {
                 &rust_input_state,
                 window,
                 |this: &mut DivInspector, state, event: &InputEvent, window, cx| match event {
-                    InputEvent::Change => {
+                    InputEvent::Change(_) => {
                         let new_style = state.read(cx).value();
                         this.edit_rust(new_style.as_str(), window, cx);
                     }
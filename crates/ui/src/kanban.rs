@@ -0,0 +1,747 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, uniform_list, AnyElement, App, AppContext, Context,
+    DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, ParentElement, Pixels, Render, SharedString, StatefulInteractiveElement as _,
+    Styled, Subscription, UniformListScrollHandle, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    dnd::auto_scroll,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    label::Label,
+    v_flex, ActiveTheme as _, IconName, InteractiveElementExt as _, Sizable as _, StyledExt as _,
+};
+
+/// A card in the [`KanbanColumn`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanCard {
+    pub id: SharedString,
+    pub title: SharedString,
+    pub description: Option<SharedString>,
+}
+
+impl KanbanCard {
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A column of the [`KanbanBoard`].
+#[derive(Clone)]
+pub struct KanbanColumn {
+    pub id: SharedString,
+    pub title: SharedString,
+    pub cards: Vec<KanbanCard>,
+    pub collapsed: bool,
+    scroll_handle: UniformListScrollHandle,
+}
+
+impl KanbanColumn {
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            cards: vec![],
+            collapsed: false,
+            scroll_handle: UniformListScrollHandle::default(),
+        }
+    }
+
+    pub fn cards(mut self, cards: impl IntoIterator<Item = KanbanCard>) -> Self {
+        self.cards = cards.into_iter().collect();
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+}
+
+pub enum KanbanEvent {
+    /// A card is moved by drag and drop.
+    ///
+    /// The `to_ix` is the index of the card in the target column after the move.
+    MoveCard {
+        card_id: SharedString,
+        from_column: usize,
+        from_ix: usize,
+        to_column: usize,
+        to_ix: usize,
+    },
+    /// A new column is added at the index.
+    AddColumn(usize),
+    /// The column at the index is renamed.
+    RenameColumn(usize),
+    /// The column at the index is collapsed or expanded.
+    CollapseColumn(usize, bool),
+}
+
+/// The drag value of a card, also used as the drag preview.
+#[derive(Clone)]
+struct DragCard {
+    board_id: EntityId,
+    column_ix: usize,
+    card_ix: usize,
+    card: KanbanCard,
+    width: Pixels,
+}
+
+impl Render for DragCard {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .id("drag-card")
+            .cursor_grabbing()
+            .w(self.width)
+            .py_2()
+            .px_3()
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .shadow_md()
+            .bg(cx.theme().background)
+            .text_color(cx.theme().foreground)
+            .child(self.card.title.clone())
+    }
+}
+
+type CardRenderer = Rc<dyn Fn(&KanbanCard, &mut Window, &mut App) -> AnyElement>;
+
+/// A Kanban board with horizontal columns and draggable cards.
+///
+/// The cards in each column are virtualized with a fixed card height,
+/// drag the cards to reorder them in the column or move them to another column.
+pub struct KanbanBoard {
+    focus_handle: FocusHandle,
+    columns: Vec<KanbanColumn>,
+    column_width: Pixels,
+    card_height: Pixels,
+    card_renderer: Option<CardRenderer>,
+    /// The (column_ix, insert_ix) placeholder of the dragging card.
+    drop_target: Option<(usize, usize)>,
+    /// The (column_ix, card_ix) of the dragging card.
+    dragging: Option<(usize, usize)>,
+    renaming: Option<(usize, Entity<InputState>)>,
+    _rename_subscription: Option<Subscription>,
+}
+
+impl EventEmitter<KanbanEvent> for KanbanBoard {}
+
+impl Focusable for KanbanBoard {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl KanbanBoard {
+    pub fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            columns: vec![],
+            column_width: px(272.),
+            card_height: px(72.),
+            card_renderer: None,
+            drop_target: None,
+            dragging: None,
+            renaming: None,
+            _rename_subscription: None,
+        }
+    }
+
+    /// Set the columns of the board.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = KanbanColumn>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    /// Set the width of the expanded columns, default is 272px.
+    pub fn column_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.column_width = width.into();
+        self
+    }
+
+    /// Set the height of the cards (including the gap between cards), default is 72px.
+    ///
+    /// All cards have the same height to be virtualized.
+    pub fn card_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.card_height = height.into();
+        self
+    }
+
+    /// Set a custom renderer of the card content.
+    pub fn card_renderer<F, E>(mut self, f: F) -> Self
+    where
+        F: Fn(&KanbanCard, &mut Window, &mut App) -> E + 'static,
+        E: IntoElement,
+    {
+        self.card_renderer = Some(Rc::new(move |card, window, cx| {
+            f(card, window, cx).into_any_element()
+        }));
+        self
+    }
+
+    pub fn set_columns(
+        &mut self,
+        columns: impl IntoIterator<Item = KanbanColumn>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.columns = columns.into_iter().collect();
+        self.renaming = None;
+        self.drop_target = None;
+        cx.notify();
+    }
+
+    /// Returns the columns of the board.
+    pub fn get_columns(&self) -> &[KanbanColumn] {
+        &self.columns
+    }
+
+    /// Add a column to the end of the board.
+    pub fn add_column(&mut self, column: KanbanColumn, _: &mut Window, cx: &mut Context<Self>) {
+        self.columns.push(column);
+        cx.emit(KanbanEvent::AddColumn(self.columns.len() - 1));
+        cx.notify();
+    }
+
+    /// Rename the column at the index.
+    pub fn rename_column(
+        &mut self,
+        column_ix: usize,
+        title: impl Into<SharedString>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(column) = self.columns.get_mut(column_ix) else {
+            return;
+        };
+
+        let title = title.into();
+        if column.title == title {
+            return;
+        }
+
+        column.title = title;
+        cx.emit(KanbanEvent::RenameColumn(column_ix));
+        cx.notify();
+    }
+
+    /// Collapse or expand the column at the index.
+    pub fn set_column_collapsed(
+        &mut self,
+        column_ix: usize,
+        collapsed: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(column) = self.columns.get_mut(column_ix) else {
+            return;
+        };
+
+        if column.collapsed == collapsed {
+            return;
+        }
+
+        column.collapsed = collapsed;
+        cx.emit(KanbanEvent::CollapseColumn(column_ix, collapsed));
+        cx.notify();
+    }
+
+    /// Move the card to the `to_ix` of the `to_column`, the `to_ix` is the insert index before the move.
+    pub fn move_card(
+        &mut self,
+        from: (usize, usize),
+        to: (usize, usize),
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(to) = move_card(&mut self.columns, from, to) else {
+            return;
+        };
+
+        let card_id = self.columns[to.0].cards[to.1].id.clone();
+        cx.emit(KanbanEvent::MoveCard {
+            card_id,
+            from_column: from.0,
+            from_ix: from.1,
+            to_column: to.0,
+            to_ix: to.1,
+        });
+        cx.notify();
+    }
+
+    fn start_rename(&mut self, column_ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(column) = self.columns.get(column_ix) else {
+            return;
+        };
+
+        let title = column.title.clone();
+        let input = cx.new(|cx| InputState::new(window, cx).default_value(title));
+        input.update(cx, |input, cx| input.focus(window, cx));
+        self._rename_subscription = Some(cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &InputEvent, window, cx| match event {
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {
+                    let title = input.read(cx).value().trim().to_string();
+                    this.finish_rename(title, window, cx);
+                }
+                _ => {}
+            },
+        ));
+        self.renaming = Some((column_ix, input));
+        cx.notify();
+    }
+
+    fn finish_rename(&mut self, title: String, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((column_ix, _)) = self.renaming.take() else {
+            return;
+        };
+        self._rename_subscription = None;
+
+        if !title.is_empty() {
+            self.rename_column(column_ix, title, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn on_add_column(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let id = format!("column-{}", self.columns.len());
+        self.add_column(KanbanColumn::new(id, t!("Kanban.new_column")), window, cx);
+        self.start_rename(self.columns.len() - 1, window, cx);
+    }
+
+    fn on_card_drag_move(
+        &mut self,
+        column_ix: usize,
+        event: &DragMoveEvent<DragCard>,
+        cx: &mut Context<Self>,
+    ) {
+        let drag = event.drag(cx);
+        if drag.board_id != cx.entity_id() {
+            return;
+        }
+        self.dragging = Some((drag.column_ix, drag.card_ix));
+
+        let bounds = event.bounds;
+        let position = event.event.position;
+        if !bounds.contains(&position) {
+            // Clear the placeholder only when leaving this column, another column may have set it.
+            if self.drop_target.map(|(ix, _)| ix) == Some(column_ix) {
+                self.drop_target = None;
+                cx.notify();
+            }
+            return;
+        }
+
+        let Some(column) = self.columns.get(column_ix) else {
+            return;
+        };
+        let scroll_y = column.scroll_handle.0.borrow().base_handle.offset().y;
+        let insert_ix = insert_index(
+            position.y - bounds.top() - scroll_y,
+            self.card_height,
+            column.cards.len(),
+        );
+
+        if self.drop_target != Some((column_ix, insert_ix)) {
+            self.drop_target = Some((column_ix, insert_ix));
+            cx.notify();
+        }
+    }
+
+    fn on_card_drop(
+        &mut self,
+        column_ix: usize,
+        drag: &DragCard,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let drop_target = self.drop_target.take();
+        self.dragging = None;
+        if drag.board_id != cx.entity_id() {
+            return;
+        }
+
+        let insert_ix = match drop_target {
+            Some((ix, insert_ix)) if ix == column_ix => insert_ix,
+            _ => self
+                .columns
+                .get(column_ix)
+                .map(|column| column.cards.len())
+                .unwrap_or_default(),
+        };
+
+        self.move_card(
+            (drag.column_ix, drag.card_ix),
+            (column_ix, insert_ix),
+            window,
+            cx,
+        );
+        cx.notify();
+    }
+
+    fn render_card(
+        &self,
+        column_ix: usize,
+        card_ix: usize,
+        card: &KanbanCard,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let dragging = self.dragging == Some((column_ix, card_ix));
+        let content = match self.card_renderer.clone() {
+            Some(renderer) => renderer(card, window, cx),
+            None => v_flex()
+                .gap_1()
+                .child(Label::new(card.title.clone()).text_sm().font_medium())
+                .when_some(card.description.clone(), |this, description| {
+                    this.child(
+                        Label::new(description)
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground),
+                    )
+                })
+                .into_any_element(),
+        };
+
+        div().h(self.card_height).pb_2().child(
+            div()
+                .id(SharedString::from(format!("card-{}", card.id)))
+                .size_full()
+                .overflow_hidden()
+                .py_2()
+                .px_3()
+                .border_1()
+                .border_color(cx.theme().border)
+                .rounded(cx.theme().radius)
+                .bg(cx.theme().background)
+                .cursor_grab()
+                .when(dragging, |this| this.opacity(0.5))
+                .child(content)
+                .on_drag(
+                    DragCard {
+                        board_id: cx.entity_id(),
+                        column_ix,
+                        card_ix,
+                        card: card.clone(),
+                        width: self.column_width - px(16.),
+                    },
+                    |drag, _, _, cx| {
+                        cx.stop_propagation();
+                        cx.new(|_| drag.clone())
+                    },
+                ),
+        )
+    }
+
+    fn render_placeholder(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div().h(self.card_height).pb_2().child(
+            div()
+                .size_full()
+                .border_1()
+                .border_dashed()
+                .border_color(cx.theme().drag_border)
+                .rounded(cx.theme().radius)
+                .bg(cx.theme().drop_target),
+        )
+    }
+
+    fn render_column_title(
+        &self,
+        column_ix: usize,
+        column: &KanbanColumn,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        match &self.renaming {
+            Some((ix, input)) if *ix == column_ix => {
+                TextInput::new(input).xsmall().into_any_element()
+            }
+            _ => h_flex()
+                .id("title")
+                .flex_1()
+                .gap_2()
+                .overflow_hidden()
+                .child(
+                    Label::new(column.title.clone())
+                        .text_sm()
+                        .font_semibold()
+                        .whitespace_nowrap(),
+                )
+                .child(
+                    Label::new(column.cards.len().to_string())
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .on_double_click(cx.listener(move |this, _, window, cx| {
+                    this.start_rename(column_ix, window, cx);
+                }))
+                .into_any_element(),
+        }
+    }
+
+    fn render_column(
+        &self,
+        column_ix: usize,
+        column: &KanbanColumn,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let collapsed = column.collapsed;
+        let toggle_button = Button::new("collapse")
+            .ghost()
+            .xsmall()
+            .icon(if collapsed {
+                IconName::ChevronRight
+            } else {
+                IconName::ChevronLeft
+            })
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.set_column_collapsed(column_ix, !collapsed, window, cx);
+            }));
+
+        if collapsed {
+            return v_flex()
+                .id(("kanban-column", column_ix))
+                .flex_shrink_0()
+                .h_full()
+                .w_10()
+                .py_2()
+                .gap_2()
+                .items_center()
+                .rounded(cx.theme().radius)
+                .bg(cx.theme().secondary)
+                .child(toggle_button)
+                .child(
+                    Label::new(column.title.clone())
+                        .text_sm()
+                        .font_semibold()
+                        .whitespace_nowrap(),
+                )
+                .child(
+                    Label::new(column.cards.len().to_string())
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .on_drop(cx.listener(move |this, drag: &DragCard, window, cx| {
+                    this.on_card_drop(column_ix, drag, window, cx);
+                }))
+                .into_any_element();
+        }
+
+        let cards_count = column.cards.len();
+        let placeholder_ix = self
+            .drop_target
+            .and_then(|(ix, insert_ix)| (ix == column_ix).then_some(insert_ix));
+        let items_count = cards_count + placeholder_ix.map_or(0, |_| 1);
+
+        v_flex()
+            .id(("kanban-column", column_ix))
+            .flex_shrink_0()
+            .h_full()
+            .w(self.column_width)
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().secondary)
+            .child(
+                h_flex()
+                    .h_10()
+                    .px_2()
+                    .gap_1()
+                    .child(self.render_column_title(column_ix, column, cx))
+                    .child(toggle_button),
+            )
+            .child(
+                div()
+                    .id("cards")
+                    .relative()
+                    .flex_1()
+                    .px_2()
+                    .child(auto_scroll(&column.scroll_handle.0.borrow().base_handle))
+                    .child(
+                        uniform_list(
+                            ("kanban-cards", column_ix),
+                            items_count,
+                            cx.processor(move |this, range, window, cx| {
+                                let mut items = Vec::with_capacity(16);
+                                for ix in range {
+                                    let card_ix = match placeholder_ix {
+                                        Some(placeholder_ix) if ix == placeholder_ix => {
+                                            items.push(
+                                                this.render_placeholder(cx).into_any_element(),
+                                            );
+                                            continue;
+                                        }
+                                        Some(placeholder_ix) if ix > placeholder_ix => ix - 1,
+                                        _ => ix,
+                                    };
+
+                                    let Some(card) = this
+                                        .columns
+                                        .get(column_ix)
+                                        .and_then(|column| column.cards.get(card_ix).cloned())
+                                    else {
+                                        continue;
+                                    };
+
+                                    items.push(
+                                        this.render_card(column_ix, card_ix, &card, window, cx)
+                                            .into_any_element(),
+                                    );
+                                }
+                                items
+                            }),
+                        )
+                        .size_full()
+                        .track_scroll(column.scroll_handle.clone()),
+                    )
+                    .on_drag_move(cx.listener(move |this, event, _, cx| {
+                        this.on_card_drag_move(column_ix, event, cx);
+                    }))
+                    .on_drop(cx.listener(move |this, drag: &DragCard, window, cx| {
+                        this.on_card_drop(column_ix, drag, window, cx);
+                    })),
+            )
+            .into_any_element()
+    }
+}
+
+/// Returns the insert index of the dragging card by the offset `y` to the top of the list content.
+fn insert_index(y: Pixels, card_height: Pixels, cards_count: usize) -> usize {
+    if card_height <= px(0.) || y <= px(0.) {
+        return 0;
+    }
+
+    ((y / card_height).round() as usize).min(cards_count)
+}
+
+/// Move the card `from` (column_ix, card_ix) to insert at `to` (column_ix, insert_ix).
+///
+/// The `insert_ix` is the index before the card is removed, same as the drop placeholder.
+/// Returns the final (column_ix, card_ix) of the card, or None if nothing is changed.
+fn move_card(
+    columns: &mut [KanbanColumn],
+    from: (usize, usize),
+    to: (usize, usize),
+) -> Option<(usize, usize)> {
+    let (from_column, from_ix) = from;
+    let (to_column, mut to_ix) = to;
+    if from_ix >= columns.get(from_column)?.cards.len() {
+        return None;
+    }
+    to_ix = to_ix.min(columns.get(to_column)?.cards.len());
+
+    if from_column == to_column {
+        if to_ix > from_ix {
+            to_ix -= 1;
+        }
+        if to_ix == from_ix {
+            return None;
+        }
+    }
+
+    let card = columns[from_column].cards.remove(from_ix);
+    columns[to_column].cards.insert(to_ix, card);
+    Some((to_column, to_ix))
+}
+
+impl Render for KanbanBoard {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // The drag may be canceled outside the columns, so no drop event to clear the placeholder.
+        if !cx.has_active_drag() {
+            self.drop_target = None;
+            self.dragging = None;
+        }
+
+        h_flex()
+            .id("kanban-board")
+            .key_context("KanbanBoard")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .items_start()
+            .gap_3()
+            .overflow_x_scroll()
+            .children(
+                self.columns
+                    .iter()
+                    .enumerate()
+                    .map(|(column_ix, column)| self.render_column(column_ix, column, cx)),
+            )
+            .child(
+                Button::new("add-column")
+                    .ghost()
+                    .small()
+                    .flex_shrink_0()
+                    .icon(IconName::Plus)
+                    .label(t!("Kanban.add_column"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.on_add_column(window, cx);
+                    })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{insert_index, move_card, KanbanCard, KanbanColumn};
+
+    fn columns() -> Vec<KanbanColumn> {
+        vec![
+            KanbanColumn::new("todo", "Todo").cards([
+                KanbanCard::new("a", "A"),
+                KanbanCard::new("b", "B"),
+                KanbanCard::new("c", "C"),
+            ]),
+            KanbanColumn::new("done", "Done").cards([KanbanCard::new("d", "D")]),
+        ]
+    }
+
+    fn ids(column: &KanbanColumn) -> Vec<&str> {
+        column.cards.iter().map(|card| card.id.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_move_card() {
+        let mut columns = columns();
+        assert_eq!(move_card(&mut columns, (0, 0), (0, 2)), Some((0, 1)));
+        assert_eq!(ids(&columns[0]), ["b", "a", "c"]);
+
+        // Drop at the same position.
+        assert_eq!(move_card(&mut columns, (0, 1), (0, 1)), None);
+        assert_eq!(move_card(&mut columns, (0, 1), (0, 2)), None);
+
+        assert_eq!(move_card(&mut columns, (0, 2), (1, 0)), Some((1, 0)));
+        assert_eq!(ids(&columns[0]), ["b", "a"]);
+        assert_eq!(ids(&columns[1]), ["c", "d"]);
+
+        assert_eq!(move_card(&mut columns, (1, 0), (0, 10)), Some((0, 2)));
+        assert_eq!(ids(&columns[0]), ["b", "a", "c"]);
+
+        assert_eq!(move_card(&mut columns, (0, 5), (1, 0)), None);
+        assert_eq!(move_card(&mut columns, (0, 0), (2, 0)), None);
+    }
+
+    #[test]
+    fn test_insert_index() {
+        assert_eq!(insert_index(px(-10.), px(72.), 3), 0);
+        assert_eq!(insert_index(px(30.), px(72.), 3), 0);
+        assert_eq!(insert_index(px(40.), px(72.), 3), 1);
+        assert_eq!(insert_index(px(1000.), px(72.), 3), 3);
+    }
+}
@@ -5,7 +5,7 @@ use gpui::{
     SharedString, StyleRefinement, Styled, StyledText, Window,
 };
 
-use crate::{ActiveTheme, StyledExt};
+use crate::{ActiveTheme, CachedText, StyledExt};
 
 const MASKED: &'static str = "•";
 
@@ -201,9 +201,10 @@ impl RenderOnce for Label {
             .line_height(rems(1.25))
             .text_color(cx.theme().foreground)
             .refine_style(&self.style)
-            .child(
-                StyledText::new(&text).when_some(highlights, |this, hl| this.with_highlights(hl)),
-            )
+            .map(|this| match highlights {
+                Some(highlights) => this.child(StyledText::new(&text).with_highlights(highlights)),
+                None => this.child(CachedText::new(text)),
+            })
     }
 }
 
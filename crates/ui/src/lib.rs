@@ -1,4 +1,5 @@
 mod event;
+mod focus_trap;
 mod global_state;
 mod icon;
 mod index_path;
@@ -6,7 +7,10 @@ mod index_path;
 mod inspector;
 mod kbd;
 mod root;
+mod scale;
+mod style_override;
 mod styled;
+mod text_cache;
 mod time;
 mod title_bar;
 mod virtual_list;
@@ -14,6 +18,7 @@ mod window_border;
 
 pub(crate) mod actions;
 
+pub mod accessibility;
 pub mod accordion;
 pub mod alert;
 pub mod animation;
@@ -21,33 +26,53 @@ pub mod avatar;
 pub mod badge;
 pub mod breadcrumb;
 pub mod button;
+pub mod carousel;
 pub mod chart;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
+pub mod debug_overlay;
 pub mod description_list;
 pub mod divider;
+pub mod dnd;
 pub mod dock;
 pub mod drawer;
 pub mod dropdown;
+pub mod empty_state;
+pub mod file_dialog;
+pub mod file_tree;
 pub mod form;
+pub mod grid;
 pub mod group_box;
 pub mod highlighter;
 pub mod history;
 pub mod indicator;
 pub mod input;
+pub mod kanban;
 pub mod label;
 pub mod link;
 pub mod list;
+pub mod loading_bar;
+pub mod locale;
+pub mod log_view;
+pub mod masonry;
+pub mod master_detail;
 pub mod menu;
 pub mod modal;
 pub mod notification;
+pub mod overlay;
+pub mod pagination;
 pub mod plot;
 pub mod popover;
+pub mod position;
+pub mod prelude;
 pub mod progress;
 pub mod radio;
 pub mod resizable;
+pub mod responsive;
+pub mod rich_text;
 pub mod scroll;
+pub mod shortcut_help;
 pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
@@ -58,6 +83,9 @@ pub mod tag;
 pub mod text;
 pub mod theme;
 pub mod tooltip;
+pub mod tour;
+pub mod virtual_keyboard;
+pub mod whiteboard;
 
 #[cfg(feature = "webview")]
 pub mod webview;
@@ -69,12 +97,16 @@ pub use wry;
 
 pub use crate::Disableable;
 pub use event::InteractiveElementExt;
+pub use focus_trap::*;
 pub use index_path::IndexPath;
 #[cfg(any(feature = "inspector", debug_assertions))]
 pub use inspector::*;
 pub use menu::{context_menu, popup_menu};
 pub use root::{ContextModal, Root};
+pub use scale::*;
+pub use style_override::*;
 pub use styled::*;
+pub use text_cache::*;
 pub use time::*;
 pub use title_bar::*;
 pub use virtual_list::{h_virtual_list, v_virtual_list, VirtualList, VirtualListScrollHandle};
@@ -94,21 +126,36 @@ rust_i18n::i18n!("locales", fallback = "en");
 pub fn init(cx: &mut App) {
     theme::init(cx);
     global_state::init(cx);
+    style_override::init(cx);
+    text_cache::init(cx);
+    debug_overlay::init(cx);
     #[cfg(any(feature = "inspector", debug_assertions))]
     inspector::init(cx);
     root::init(cx);
+    calendar::init(cx);
     date_picker::init(cx);
     color_picker::init(cx);
     dock::init(cx);
     drawer::init(cx);
     dropdown::init(cx);
+    file_tree::init(cx);
     input::init(cx);
     list::init(cx);
+    log_view::init(cx);
+    master_detail::init(cx);
     modal::init(cx);
     popover::init(cx);
+    rich_text::init(cx);
     menu::init(cx);
+    shortcut_help::init(cx);
+    tab::init(cx);
     table::init(cx);
+    tour::init(cx);
     text::init(cx);
+    tooltip::init(cx);
+    #[cfg(feature = "webview")]
+    webview::init(cx);
+    whiteboard::init(cx);
 }
 
 #[inline]
@@ -116,6 +163,9 @@ pub fn locale() -> impl Deref<Target = str> {
     rust_i18n::locale()
 }
 
+/// Set the locale of the built-in translations.
+///
+/// Use [`locale::Locale::set`] to refresh the windows and notify the locale changes.
 #[inline]
 pub fn set_locale(locale: &str) {
     rust_i18n::set_locale(locale)
@@ -94,55 +94,6 @@ impl RowsCache {
             .position(|p| p.is_entry() && p.eq_index_path(path))
     }
 
-    /// Returns the sections count in the cache.
-    pub(crate) fn sections_count(&self) -> usize {
-        self.sections.len()
-    }
-
-    /// Returns the rows count in the given section, if the section does not exist, returns 0.
-    pub(crate) fn rows_count(&self, section: usize) -> usize {
-        self.sections.get(section).cloned().unwrap_or(0)
-    }
-
-    /// Return prev row, if the row is the first in the first section, goes to the last row.
-    pub(crate) fn prev(&self, path: IndexPath) -> IndexPath {
-        let mut path = path;
-        if path.section == 0 && path.row == 0 {
-            path.section = self.sections_count().saturating_sub(1);
-            path.row = self.rows_count(path.section).saturating_sub(1);
-            return path;
-        }
-
-        if path.row > 0 {
-            path.row -= 1;
-        } else if path.section > 0 {
-            path.section -= 1;
-            path.row = self.rows_count(path.section).saturating_sub(1);
-        }
-        path
-    }
-
-    /// Returns the next row, if the row is the last in the last section, goes to the first row.
-    pub(crate) fn next(&self, path: IndexPath) -> IndexPath {
-        let mut path = path;
-        if path.section + 1 == self.sections_count()
-            && path.row + 1 == self.rows_count(path.section)
-        {
-            path.section = 0;
-            path.row = 0;
-            return path;
-        }
-
-        if path.row + 1 < self.rows_count(path.section) {
-            path.row += 1;
-        } else if path.section + 1 < self.sections_count() {
-            path.section += 1;
-            path.row = 0;
-        }
-
-        path
-    }
-
     pub(crate) fn measured_size(&self) -> MeasuredEntrySize {
         self.measured_size
     }
@@ -198,82 +149,3 @@ impl RowsCache {
         self.items_count = total_items_count;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use std::rc::Rc;
-
-    use crate::{list::cache::RowsCache, IndexPath};
-
-    #[test]
-    fn test_prev_next() {
-        let mut row_cache = RowsCache::default();
-        // section 0
-        //  row 0
-        //  row 1
-        // section 1
-        //  row 0
-        //  row 1
-        //  row 2
-        //  row 3
-        // section 2
-        //  row 0
-        //  row 1
-        //  row 2
-        row_cache.sections = Rc::new(vec![2, 4, 3]);
-
-        assert_eq!(
-            row_cache.next(IndexPath::new(0).section(0)),
-            IndexPath::new(1).section(0)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(1).section(0)),
-            IndexPath::new(0).section(1)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(0).section(1)),
-            IndexPath::new(1).section(1)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(3).section(1)),
-            IndexPath::new(0).section(2)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(0).section(2)),
-            IndexPath::new(1).section(2)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(1).section(2)),
-            IndexPath::new(2).section(2)
-        );
-        assert_eq!(
-            row_cache.next(IndexPath::new(2).section(2)),
-            IndexPath::new(0).section(0)
-        );
-
-        assert_eq!(
-            row_cache.prev(IndexPath::new(0).section(0)),
-            IndexPath::new(2).section(2)
-        );
-        assert_eq!(
-            row_cache.prev(IndexPath::new(1).section(0)),
-            IndexPath::new(0).section(0)
-        );
-        assert_eq!(
-            row_cache.prev(IndexPath::new(0).section(1)),
-            IndexPath::new(1).section(0)
-        );
-        assert_eq!(
-            row_cache.prev(IndexPath::new(1).section(1)),
-            IndexPath::new(0).section(1)
-        );
-        assert_eq!(
-            row_cache.prev(IndexPath::new(3).section(1)),
-            IndexPath::new(2).section(1)
-        );
-        assert_eq!(
-            row_cache.prev(IndexPath::new(0).section(2)),
-            IndexPath::new(3).section(1)
-        );
-    }
-}
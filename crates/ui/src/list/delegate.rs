@@ -1,9 +1,9 @@
-use gpui::{AnyElement, App, Context, IntoElement, ParentElement as _, Styled as _, Task, Window};
+use gpui::{AnyElement, App, Context, IntoElement, SharedString, Task, Window};
 
 use crate::{
-    h_flex,
+    empty_state::{EmptyState, ErrorState},
     list::{loading::Loading, List},
-    ActiveTheme as _, Icon, IconName, IndexPath, Selectable,
+    IndexPath, Selectable, Sizable as _,
 };
 
 /// A delegate for the List.
@@ -42,6 +42,19 @@ pub trait ListDelegate: Sized + 'static {
         cx: &mut Context<List<Self>>,
     ) -> Option<Self::Item>;
 
+    /// Returns the text of the item at the given index for the type-ahead selection,
+    /// e.g.: typing `ca` to select `Calendar`, default is None.
+    fn item_text(&self, ix: IndexPath, cx: &App) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns true if the item at the given index is disabled, default is false.
+    ///
+    /// The disabled items are skipped by the keyboard navigation and can't be confirmed.
+    fn item_disabled(&self, ix: IndexPath, cx: &App) -> bool {
+        false
+    }
+
     /// Render the section header at the given index, default is None.
     ///
     /// NOTE: Every header should have same height.
@@ -66,14 +79,26 @@ pub trait ListDelegate: Sized + 'static {
         None::<AnyElement>
     }
 
-    /// Return a Element to show when list is empty.
+    /// Return a Element to show when list is empty, default is [`EmptyState::no_data`].
     fn render_empty(&self, window: &mut Window, cx: &mut Context<List<Self>>) -> impl IntoElement {
-        h_flex()
-            .size_full()
-            .justify_center()
-            .text_color(cx.theme().muted_foreground.opacity(0.6))
-            .child(Icon::new(IconName::Inbox).size_12())
-            .into_any_element()
+        EmptyState::no_data().small()
+    }
+
+    /// Returns the error message if failed to load the items, default is None.
+    ///
+    /// When this returns Some, the [`ListDelegate::render_error`] will be rendered instead of the items.
+    fn load_error(&self, cx: &App) -> Option<SharedString> {
+        None
+    }
+
+    /// Return a Element to show when failed to load the items, default is [`ErrorState`].
+    fn render_error(
+        &self,
+        error: SharedString,
+        window: &mut Window,
+        cx: &mut Context<List<Self>>,
+    ) -> impl IntoElement {
+        ErrorState::new().small().description(error)
     }
 
     /// Returns Some(AnyElement) to render the initial state of the list.
@@ -1,13 +1,16 @@
 use std::ops::Range;
 use std::time::Duration;
 
-use crate::actions::{Cancel, Confirm, SelectNext, SelectPrev};
+use crate::actions::{
+    Cancel, Confirm, SelectFirst, SelectLast, SelectNext, SelectPageDown, SelectPageUp, SelectPrev,
+};
 use crate::input::InputState;
 use crate::list::cache::{MeasuredEntrySize, RowEntry, RowsCache};
+use crate::list::navigation::{step_index, TypeAhead};
 use crate::list::ListDelegate;
 use crate::{
     input::{InputEvent, TextInput},
-    scroll::{Scrollbar, ScrollbarState},
+    scroll::{self, ScrollTarget, Scrollbar, ScrollbarState},
     v_flex, ActiveTheme, IconName, Size,
 };
 use crate::{
@@ -18,8 +21,8 @@ use gpui::{
     IntoElement, KeyBinding, Length, MouseButton, ParentElement, Render, Styled, Task, Window,
 };
 use gpui::{
-    px, size, App, AvailableSpace, Context, Edges, EventEmitter, ListSizingBehavior,
-    MouseDownEvent, Pixels, ScrollStrategy, Subscription,
+    point, px, size, App, AvailableSpace, Context, Edges, EventEmitter, KeyDownEvent,
+    ListSizingBehavior, MouseDownEvent, Pixels, ScrollStrategy, Subscription,
 };
 use rust_i18n::t;
 use smol::Timer;
@@ -32,6 +35,10 @@ pub(crate) fn init(cx: &mut App) {
         KeyBinding::new("secondary-enter", Confirm { secondary: true }, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("pageup", SelectPageUp, context),
+        KeyBinding::new("pagedown", SelectPageDown, context),
     ]);
 }
 
@@ -64,8 +71,11 @@ pub struct List<D: ListDelegate> {
     deferred_scroll_to_index: Option<(IndexPath, ScrollStrategy)>,
     mouse_right_clicked_index: Option<IndexPath>,
     reset_on_cancel: bool,
+    wrap_around: bool,
+    type_ahead: TypeAhead,
     _search_task: Task<()>,
     _load_more_task: Task<()>,
+    _scroll_task: Task<()>,
     _query_input_subscription: Subscription,
 }
 
@@ -98,9 +108,12 @@ where
             querying: false,
             size: Size::default(),
             reset_on_cancel: true,
+            wrap_around: true,
+            type_ahead: TypeAhead::default(),
             paddings: Edges::default(),
             _search_task: Task::ready(()),
             _load_more_task: Task::ready(()),
+            _scroll_task: Task::ready(()),
             _query_input_subscription,
         }
     }
@@ -132,6 +145,18 @@ where
         self
     }
 
+    /// Sets whether to select the first item after the last item (and vice versa)
+    /// by the up and down keys, default is true.
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Set whether to select the first item after the last item (and vice versa).
+    pub fn set_wrap_around(&mut self, wrap_around: bool, _: &mut Window, _: &mut Context<Self>) {
+        self.wrap_around = wrap_around;
+    }
+
     pub fn set_query_input(
         &mut self,
         query_input: Entity<InputState>,
@@ -231,6 +256,47 @@ where
         cx.notify();
     }
 
+    /// Scroll to the offset or the item, animate the scrolling if `smooth` is true.
+    ///
+    /// The [`ScrollTarget::Row`] is the index of the items across the sections.
+    pub fn scroll_to(
+        &mut self,
+        target: impl Into<ScrollTarget>,
+        smooth: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let scroll_handle = self.scroll_handle.base_handle().clone();
+        let offset = match target.into() {
+            ScrollTarget::Offset(offset) => offset,
+            ScrollTarget::Row(ix) => {
+                let Some(pos) = self
+                    .rows_cache
+                    .entities
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| entry.is_entry())
+                    .nth(ix)
+                    .map(|(pos, _)| pos)
+                else {
+                    return;
+                };
+
+                let y = self.rows_cache.entries_sizes[..pos]
+                    .iter()
+                    .fold(self.paddings.top, |y, size| y + size.height);
+                point(scroll_handle.offset().x, -y)
+            }
+        };
+
+        let max_offset = scroll_handle.max_offset();
+        let offset = point(
+            offset.x.clamp(-max_offset.width, px(0.)),
+            offset.y.clamp(-max_offset.height, px(0.)),
+        );
+        self._scroll_task = scroll::scroll_to_offset(&scroll_handle, offset, smooth, window, cx);
+    }
+
     /// Get scroll handle
     pub fn scroll_handle(&self) -> &VirtualListScrollHandle {
         &self.scroll_handle
@@ -257,7 +323,7 @@ where
         cx: &mut Context<Self>,
     ) {
         match event {
-            InputEvent::Change => {
+            InputEvent::Change(_) => {
                 let text = state.read(cx).value();
                 let text = text.trim().to_string();
                 if Some(&text) == self.last_query.as_ref() {
@@ -339,6 +405,10 @@ where
         self
     }
 
+    pub(crate) fn set_reset_on_cancel(&mut self, reset: bool) {
+        self.reset_on_cancel = reset;
+    }
+
     fn on_action_cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
         cx.propagate();
         if self.reset_on_cancel {
@@ -363,6 +433,9 @@ where
         let Some(ix) = self.selected_index else {
             return;
         };
+        if self.delegate.item_disabled(ix, cx) {
+            return;
+        }
 
         self.delegate
             .set_selected_index(self.selected_index, window, cx);
@@ -379,20 +452,68 @@ where
         cx.notify();
     }
 
-    pub(crate) fn on_action_select_prev(
+    /// Returns the index path of the item at the flattened `ix`, `None` for the section header and footer.
+    fn item_path_at(&self, ix: usize) -> Option<IndexPath> {
+        self.rows_cache
+            .entities
+            .get(ix)
+            .filter(|entry| entry.is_entry())
+            .map(|entry| entry.index())
+    }
+
+    /// Returns the flattened index of the item at the index path.
+    fn flatten_ix_of(&self, ix: IndexPath) -> Option<usize> {
+        self.rows_cache
+            .entities
+            .iter()
+            .position(|entry| entry.is_entry() && entry.index().eq_row(ix))
+    }
+
+    /// Select the item moved by the `step` from the selected item, skipping the disabled items.
+    fn select_by_step(
         &mut self,
-        _: &SelectPrev,
+        current: Option<IndexPath>,
+        step: isize,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.rows_cache.len() == 0 {
+        // Step through the flattened rows, the section headers and footers are skipped as disabled.
+        let current = current.and_then(|ix| self.flatten_ix_of(ix));
+        let Some(path) = step_index(
+            self.rows_cache.len(),
+            current,
+            step,
+            self.wrap_around,
+            |ix| {
+                self.item_path_at(ix)
+                    .is_none_or(|path| self.delegate.item_disabled(path, cx))
+            },
+        )
+        .and_then(|ix| self.item_path_at(ix)) else {
             return;
+        };
+
+        self.select_item(path, window, cx);
+    }
+
+    /// Returns the number of the items in the visible area.
+    fn page_size(&self) -> usize {
+        let item_height = self.rows_cache.measured_size().item_size.height;
+        let viewport_height = self.scroll_handle.base_handle().bounds().size.height;
+        if item_height <= px(0.) {
+            return 1;
         }
 
-        let prev_ix = self
-            .rows_cache
-            .prev(self.selected_index.unwrap_or(IndexPath::default()));
-        self.select_item(prev_ix, window, cx);
+        ((viewport_height / item_height).floor() as usize).max(1)
+    }
+
+    pub(crate) fn on_action_select_prev(
+        &mut self,
+        _: &SelectPrev,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select_by_step(self.selected_index, -1, window, cx);
     }
 
     pub(crate) fn on_action_select_next(
@@ -401,14 +522,98 @@ where
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.rows_cache.len() == 0 {
-            return;
+        self.select_by_step(self.selected_index, 1, window, cx);
+    }
+
+    pub(crate) fn on_action_select_first(
+        &mut self,
+        _: &SelectFirst,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select_by_step(None, 1, window, cx);
+    }
+
+    pub(crate) fn on_action_select_last(
+        &mut self,
+        _: &SelectLast,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select_by_step(None, -1, window, cx);
+    }
+
+    pub(crate) fn on_action_select_page_up(
+        &mut self,
+        _: &SelectPageUp,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let step = self.page_size() as isize;
+        self.select_by_step(self.selected_index, -step, window, cx);
+    }
+
+    pub(crate) fn on_action_select_page_down(
+        &mut self,
+        _: &SelectPageDown,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let step = self.page_size() as isize;
+        self.select_by_step(self.selected_index, step, window, cx);
+    }
+
+    /// Select the item by the typed `text` with the [`ListDelegate::item_text`],
+    /// returns true if an item is selected.
+    pub(crate) fn type_ahead(
+        &mut self,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let current = self.selected_index.and_then(|ix| self.flatten_ix_of(ix));
+        let query = self.type_ahead.push(text).to_string();
+        let Some(path) = TypeAhead::find(&query, self.rows_cache.len(), current, |ix| {
+            let path = self.item_path_at(ix)?;
+            if self.delegate.item_disabled(path, cx) {
+                return None;
+            }
+            self.delegate
+                .item_text(path, cx)
+                .map(|text| text.to_string())
+        })
+        .and_then(|ix| self.item_path_at(ix)) else {
+            return false;
+        };
+
+        self.select_item(path, window, cx);
+        true
+    }
+
+    /// Handle the key down event for the type-ahead, returns true if an item is selected.
+    pub(crate) fn type_ahead_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        match self.type_ahead.typed_text(event) {
+            Some(text) => self.type_ahead(text, window, cx),
+            None => false,
         }
+    }
 
-        let next_ix = self
-            .rows_cache
-            .next(self.selected_index.unwrap_or_default());
-        self.select_item(next_ix, window, cx);
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        // The query input is used to filter the items.
+        if let Some(input) = &self.query_input {
+            if input.focus_handle(cx).is_focused(window) {
+                return;
+            }
+        }
+
+        if self.type_ahead_key_down(event, window, cx) {
+            cx.stop_propagation();
+        }
     }
 
     fn render_list_item(
@@ -436,6 +641,9 @@ where
                     MouseButton::Left,
                     cx.listener(move |this, ev: &MouseDownEvent, window, cx| {
                         this.mouse_right_clicked_index = None;
+                        if this.delegate.item_disabled(ix, cx) {
+                            return;
+                        }
                         this.selected_index = Some(ix);
                         this.on_action_confirm(
                             &Confirm {
@@ -464,6 +672,13 @@ where
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let measured_size = self.rows_cache.measured_size();
+        let load_error = self.delegate().load_error(cx);
+        let has_error = load_error.is_some();
+        let mut paddings = self.paddings;
+        // Reserve the space for the scrollbar in the gutter layout.
+        if self.scrollbar_visible && cx.theme().scrollbar_layout.is_gutter() {
+            paddings.right += scroll::width(cx);
+        }
 
         v_flex()
             .flex_grow()
@@ -472,10 +687,12 @@ where
             .min_w(measured_size.item_size.width)
             .when_some(self.max_height, |this, h| this.max_h(h))
             .overflow_hidden()
-            .when(items_count == 0, |this| {
-                this.child(self.delegate().render_empty(window, cx))
+            .map(|this| match load_error {
+                Some(error) => this.child(self.delegate().render_error(error, window, cx)),
+                None if items_count == 0 => this.child(self.delegate().render_empty(window, cx)),
+                None => this,
             })
-            .when(items_count > 0, {
+            .when(!has_error && items_count > 0, {
                 let rows_cache = self.rows_cache.clone();
                 |this| {
                     this.child(
@@ -519,7 +736,7 @@ where
                                     .collect::<Vec<_>>()
                             },
                         )
-                        .paddings(self.paddings)
+                        .paddings(paddings)
                         .when(self.max_height.is_some(), |this| {
                             this.with_sizing_behavior(ListSizingBehavior::Infer)
                         })
@@ -609,7 +826,12 @@ where
         v_flex()
             .key_context("List")
             .id("list")
-            .track_focus(&self.focus_handle)
+            .track_focus(
+                &self
+                    .focus_handle
+                    .clone()
+                    .tab_stop(self.query_input.is_none()),
+            )
             .size_full()
             .relative()
             .overflow_hidden()
@@ -643,6 +865,11 @@ where
                     .on_action(cx.listener(Self::on_action_confirm))
                     .on_action(cx.listener(Self::on_action_select_next))
                     .on_action(cx.listener(Self::on_action_select_prev))
+                    .on_action(cx.listener(Self::on_action_select_first))
+                    .on_action(cx.listener(Self::on_action_select_last))
+                    .on_action(cx.listener(Self::on_action_select_page_up))
+                    .on_action(cx.listener(Self::on_action_select_page_down))
+                    .on_key_down(cx.listener(Self::on_key_down))
                     .map(|this| {
                         if let Some(view) = initial_view {
                             this.child(view)
@@ -1,4 +1,7 @@
-use crate::{h_flex, ActiveTheme, Disableable, Icon, Selectable, Sizable as _, StyledExt};
+use crate::{
+    h_flex, ActiveTheme, DensityExt as _, DirectionExt as _, Disableable, Icon, Selectable,
+    Sizable as _, StyledExt,
+};
 use gpui::{
     div, prelude::FluentBuilder as _, AnyElement, App, ClickEvent, Div, ElementId,
     InteractiveElement, IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce,
@@ -169,6 +172,8 @@ impl RenderOnce for ListItem {
             .relative()
             .items_center()
             .justify_between()
+            .density(cx.theme().density)
+            .direction(cx.theme().direction)
             .refine_style(&self.style)
             .when(is_selectable, |this| {
                 this.when_some(self.on_click, |this, on_click| {
@@ -203,7 +208,8 @@ impl RenderOnce for ListItem {
                                 },
                             ),
                         )
-                    }),
+                    })
+                    .direction(cx.theme().direction),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix(window, cx)))
             .map(|this| {
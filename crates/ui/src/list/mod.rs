@@ -3,6 +3,7 @@ mod delegate;
 mod list;
 mod list_item;
 mod loading;
+pub(crate) mod navigation;
 mod separator_item;
 
 pub use delegate::*;
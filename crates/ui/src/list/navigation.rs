@@ -0,0 +1,209 @@
+use std::time::{Duration, Instant};
+
+use gpui::KeyDownEvent;
+
+/// The query of the [`TypeAhead`] is reset if no key is typed in this duration.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Returns the index of the item moved by the `step` from the `current` in the `count` items,
+/// skipping the disabled items, or None if all items are disabled.
+///
+/// - No `current`: a positive step starts before the first item, a negative step after the last.
+/// - `wrap`: a single step from the last item goes to the first, and vice versa,
+///   otherwise the index is clamped, e.g.: the page up and down.
+pub(crate) fn step_index(
+    count: usize,
+    current: Option<usize>,
+    step: isize,
+    wrap: bool,
+    disabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let count_i = count as isize;
+    let start = match current {
+        Some(ix) => ix.min(count - 1) as isize,
+        None if step >= 0 => -1,
+        None => count_i,
+    };
+    let target = start + step;
+    let wrap = wrap && step.abs() == 1;
+    let target = if wrap {
+        target.rem_euclid(count_i)
+    } else {
+        target.clamp(0, count_i - 1)
+    };
+
+    // Move in the direction of the step to skip the disabled items,
+    // turn back if reached the end without wrapping.
+    let direction = if step < 0 { -1 } else { 1 };
+    for direction in [direction, -direction] {
+        let mut ix = target;
+        for _ in 0..count {
+            if !disabled(ix as usize) {
+                return Some(ix as usize);
+            }
+            ix += direction;
+            if wrap {
+                ix = ix.rem_euclid(count_i);
+            } else if ix < 0 || ix >= count_i {
+                break;
+            }
+        }
+        if wrap {
+            break;
+        }
+    }
+    None
+}
+
+/// The typed characters to select the item by the label prefix, e.g.: typing `ca` to select `Calendar`.
+#[derive(Debug, Default)]
+pub(crate) struct TypeAhead {
+    query: String,
+    last_typed_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Returns the typed text of the key down event for the type-ahead,
+    /// the space is only used when the query is not empty.
+    pub(crate) fn typed_text<'a>(&self, event: &'a KeyDownEvent) -> Option<&'a str> {
+        let modifiers = &event.keystroke.modifiers;
+        if modifiers.control || modifiers.platform || modifiers.function || modifiers.alt {
+            return None;
+        }
+
+        let text = event.keystroke.key_char.as_deref()?;
+        if text.is_empty() || text.chars().any(|c| c.is_control()) {
+            return None;
+        }
+        if text.trim().is_empty() && !self.is_active() {
+            return None;
+        }
+        Some(text)
+    }
+
+    fn is_active(&self) -> bool {
+        self.last_typed_at
+            .is_some_and(|at| at.elapsed() < TYPE_AHEAD_TIMEOUT)
+    }
+
+    /// Append the typed `text` to the query, the query is reset after the timeout.
+    pub(crate) fn push(&mut self, text: &str) -> &str {
+        self.push_at(text, Instant::now())
+    }
+
+    fn push_at(&mut self, text: &str, now: Instant) -> &str {
+        if self
+            .last_typed_at
+            .is_none_or(|at| now.duration_since(at) >= TYPE_AHEAD_TIMEOUT)
+        {
+            self.query.clear();
+        }
+        self.query.push_str(&text.to_lowercase());
+        self.last_typed_at = Some(now);
+        &self.query
+    }
+
+    /// Returns the index of the item to select by the `query`, the label of the item
+    /// must starts with the query (case insensitive).
+    ///
+    /// The search starts from the `current` item, or the next item if the query is a single
+    /// repeated character (e.g.: `c`, `cc`) to cycle the items starting with it.
+    pub(crate) fn find(
+        query: &str,
+        count: usize,
+        current: Option<usize>,
+        label: impl Fn(usize) -> Option<String>,
+    ) -> Option<usize> {
+        let mut chars = query.chars();
+        let first = chars.next()?;
+        let repeated = chars.all(|c| c == first);
+        let offset = match current {
+            Some(ix) if repeated => ix + 1,
+            Some(ix) => ix,
+            None => 0,
+        };
+
+        let matches = |ix: usize, prefix: &str| {
+            label(ix).is_some_and(|label| label.to_lowercase().starts_with(prefix))
+        };
+        let first = first.to_string();
+        (0..count)
+            .map(|step| (offset + step) % count)
+            .find(|ix| matches(*ix, query))
+            .or_else(|| {
+                repeated
+                    .then(|| {
+                        (0..count)
+                            .map(|step| (offset + step) % count)
+                            .find(|ix| matches(*ix, &first))
+                    })
+                    .flatten()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{step_index, TypeAhead};
+
+    #[test]
+    fn test_step_index() {
+        let none = |_| false;
+        assert_eq!(step_index(0, None, 1, true, none), None);
+        assert_eq!(step_index(5, None, 1, true, none), Some(0));
+        assert_eq!(step_index(5, None, -1, true, none), Some(4));
+        assert_eq!(step_index(5, Some(4), 1, true, none), Some(0));
+        assert_eq!(step_index(5, Some(4), 1, false, none), Some(4));
+        assert_eq!(step_index(5, Some(0), -1, false, none), Some(0));
+        assert_eq!(step_index(5, Some(1), 10, true, none), Some(4));
+        assert_eq!(step_index(5, Some(3), -10, true, none), Some(0));
+
+        let disabled = |ix| ix == 0 || ix == 2;
+        assert_eq!(step_index(5, Some(1), 1, true, disabled), Some(3));
+        assert_eq!(step_index(5, Some(1), -1, true, disabled), Some(4));
+        assert_eq!(step_index(5, Some(1), -1, false, disabled), Some(1));
+        assert_eq!(step_index(5, None, 1, false, disabled), Some(1));
+        assert_eq!(step_index(3, Some(1), -10, false, disabled), Some(1));
+        assert_eq!(step_index(3, None, 1, true, |_| true), None);
+    }
+
+    #[test]
+    fn test_step_index_in_sections() {
+        // The flattened rows of the sections: header, item, item, footer, header, item.
+        let is_item = [false, true, true, false, false, true];
+        let not_item = |ix: usize| !is_item[ix];
+        assert_eq!(step_index(6, None, 1, true, not_item), Some(1));
+        assert_eq!(step_index(6, None, -1, true, not_item), Some(5));
+        assert_eq!(step_index(6, Some(2), 1, true, not_item), Some(5));
+        assert_eq!(step_index(6, Some(5), 1, true, not_item), Some(1));
+        assert_eq!(step_index(6, Some(1), -1, false, not_item), Some(1));
+        assert_eq!(step_index(6, Some(1), 3, false, not_item), Some(5));
+        assert_eq!(step_index(6, Some(5), -3, false, not_item), Some(2));
+    }
+
+    #[test]
+    fn test_type_ahead() {
+        let labels = ["Apple", "Banana", "Calendar", "Camera", "Cat"];
+        let label = |ix: usize| labels.get(ix).map(|s| s.to_string());
+        assert_eq!(TypeAhead::find("ca", 5, None, label), Some(2));
+        assert_eq!(TypeAhead::find("cam", 5, Some(2), label), Some(3));
+        assert_eq!(TypeAhead::find("c", 5, Some(2), label), Some(3));
+        assert_eq!(TypeAhead::find("cc", 5, Some(4), label), Some(2));
+        assert_eq!(TypeAhead::find("x", 5, None, label), None);
+
+        let mut type_ahead = TypeAhead::default();
+        let now = Instant::now();
+        assert_eq!(type_ahead.push_at("C", now), "c");
+        assert_eq!(
+            type_ahead.push_at("a", now + Duration::from_millis(300)),
+            "ca"
+        );
+        assert_eq!(type_ahead.push_at("b", now + Duration::from_secs(2)), "b");
+    }
+}
@@ -0,0 +1,259 @@
+//! A thin progress bar on the top of the window to show the app is busy, like NProgress.
+//!
+//! ```ignore
+//! use gpui_component::ContextModal as _;
+//!
+//! let id = window.start_loading(cx);
+//! // Optional, the bar trickles by itself without the progress.
+//! window.set_loading_progress(id, 0.5, cx);
+//! window.finish_loading(id, cx);
+//! ```
+//!
+//! The progress of the concurrent tasks are aggregated into one bar, the bar is completed
+//! when all the tasks are finished. It is rendered by [`crate::Root`] above all layers.
+use std::time::{Duration, Instant};
+
+use gpui::{
+    deferred, div, prelude::FluentBuilder as _, px, relative, Context, IntoElement,
+    ParentElement as _, Render, Styled as _, Task, Window,
+};
+use smol::Timer;
+
+use crate::ActiveTheme as _;
+
+/// The deferred priority of the loading bar, above the overlays.
+const LOADING_BAR_PRIORITY: usize = 100;
+/// The interval to repaint the bar when it is animating.
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+/// The progress of a task without the progress starts from, and trickles towards.
+const TRICKLE_RANGE: (f32, f32) = (0.08, 0.9);
+/// The time constant of the trickle, the progress reaches 63% of the range in this duration.
+const TRICKLE_DURATION: Duration = Duration::from_secs(2);
+/// The duration to fade out the completed bar.
+const FADE_DURATION: Duration = Duration::from_millis(400);
+
+/// The id of a loading task started by [`crate::ContextModal::start_loading`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LoadingId(pub(crate) usize);
+
+struct LoadingTask {
+    id: LoadingId,
+    progress: Option<f32>,
+    started_at: Instant,
+}
+
+/// Returns the progress of a task without the progress after the `elapsed`.
+fn trickle(elapsed: Duration) -> f32 {
+    let (from, to) = TRICKLE_RANGE;
+    to - (to - from) * (-elapsed.as_secs_f32() / TRICKLE_DURATION.as_secs_f32()).exp()
+}
+
+/// The tasks and the displayed value of the loading bar.
+#[derive(Default)]
+struct LoadingState {
+    tasks: Vec<LoadingTask>,
+    next_id: usize,
+    /// The displayed value, it never goes back until all the tasks are finished.
+    value: f32,
+    finished_at: Option<Instant>,
+}
+
+impl LoadingState {
+    fn start(&mut self, now: Instant) -> LoadingId {
+        if self.tasks.is_empty() {
+            self.value = 0.;
+            self.finished_at = None;
+        }
+
+        let id = LoadingId(self.next_id);
+        self.next_id += 1;
+        self.tasks.push(LoadingTask {
+            id,
+            progress: None,
+            started_at: now,
+        });
+        id
+    }
+
+    fn set(&mut self, id: LoadingId, progress: f32) {
+        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
+            task.progress = Some(progress.clamp(0., 1.));
+        }
+    }
+
+    fn finish(&mut self, id: LoadingId, now: Instant) {
+        let len = self.tasks.len();
+        self.tasks.retain(|task| task.id != id);
+        if len > 0 && self.tasks.is_empty() {
+            self.finished_at = Some(now);
+        }
+    }
+
+    fn is_loading(&self) -> bool {
+        !self.tasks.is_empty()
+    }
+
+    fn is_visible(&self, now: Instant) -> bool {
+        self.is_loading()
+            || self
+                .finished_at
+                .is_some_and(|at| now.duration_since(at) < FADE_DURATION)
+    }
+
+    /// Returns the value and the opacity of the bar at `now`, or None if the bar is hidden.
+    fn frame(&mut self, now: Instant) -> Option<(f32, f32)> {
+        if self.tasks.is_empty() {
+            let elapsed = now.duration_since(self.finished_at?);
+            if elapsed >= FADE_DURATION {
+                self.finished_at = None;
+                return None;
+            }
+            return Some((1., 1. - elapsed.as_secs_f32() / FADE_DURATION.as_secs_f32()));
+        }
+
+        let total = self
+            .tasks
+            .iter()
+            .map(|task| {
+                task.progress
+                    .unwrap_or_else(|| trickle(now.duration_since(task.started_at)))
+            })
+            .sum::<f32>();
+        // Never show completed until all the tasks are finished.
+        let value = (total / self.tasks.len() as f32).min(0.99);
+        self.value = self.value.max(value);
+        Some((self.value, 1.))
+    }
+}
+
+/// The loading bar of the window, use the [`crate::ContextModal`] methods to control it.
+pub struct LoadingBar {
+    state: LoadingState,
+    _ticker: Option<Task<()>>,
+}
+
+impl LoadingBar {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: LoadingState::default(),
+            _ticker: None,
+        }
+    }
+
+    pub(crate) fn start(&mut self, cx: &mut Context<Self>) -> LoadingId {
+        let id = self.state.start(Instant::now());
+        self.animate(cx);
+        id
+    }
+
+    pub(crate) fn set(&mut self, id: LoadingId, progress: f32, cx: &mut Context<Self>) {
+        self.state.set(id, progress);
+        cx.notify();
+    }
+
+    pub(crate) fn finish(&mut self, id: LoadingId, cx: &mut Context<Self>) {
+        self.state.finish(id, Instant::now());
+        if cx.theme().reduce_motion {
+            self.state.finished_at = None;
+        }
+        cx.notify();
+    }
+
+    /// Returns true if any task is not finished.
+    pub fn is_loading(&self) -> bool {
+        self.state.is_loading()
+    }
+
+    /// Repaint the bar in every frame until it is hidden.
+    fn animate(&mut self, cx: &mut Context<Self>) {
+        cx.notify();
+        if self._ticker.is_some() || cx.theme().reduce_motion {
+            return;
+        }
+
+        self._ticker = Some(cx.spawn(async move |this, cx| loop {
+            Timer::after(FRAME_INTERVAL).await;
+            let visible = this.update(cx, |this, cx| {
+                cx.notify();
+                let visible = this.state.is_visible(Instant::now());
+                if !visible {
+                    this._ticker = None;
+                }
+                visible
+            });
+            if !matches!(visible, Ok(true)) {
+                break;
+            }
+        }));
+    }
+}
+
+impl Render for LoadingBar {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let frame = self.state.frame(Instant::now());
+
+        div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .w_full()
+            .when_some(frame, |this, (value, opacity)| {
+                this.child(
+                    deferred(
+                        div()
+                            .h(px(2.))
+                            .w(relative(value))
+                            .bg(cx.theme().progress_bar)
+                            .opacity(opacity),
+                    )
+                    .with_priority(LOADING_BAR_PRIORITY),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{trickle, LoadingState, FADE_DURATION};
+
+    #[test]
+    fn test_trickle() {
+        assert!((trickle(Duration::ZERO) - 0.08).abs() < 1e-6);
+        assert!(trickle(Duration::from_secs(1)) < trickle(Duration::from_secs(2)));
+        assert!(trickle(Duration::from_secs(60)) <= 0.9);
+    }
+
+    #[test]
+    fn test_loading_state() {
+        let now = Instant::now();
+        let mut state = LoadingState::default();
+        assert_eq!(state.frame(now), None);
+
+        let a = state.start(now);
+        let b = state.start(now);
+        state.set(a, 0.6);
+        state.set(b, 0.2);
+        assert_eq!(state.frame(now), Some((0.4, 1.)));
+
+        // Keep the value when a task is finished with a lower average.
+        state.finish(a, now);
+        assert_eq!(state.frame(now), Some((0.4, 1.)));
+        assert!(state.is_loading());
+
+        state.set(b, 1.);
+        assert_eq!(state.frame(now), Some((0.99, 1.)));
+
+        state.finish(b, now);
+        assert!(!state.is_loading());
+        assert_eq!(state.frame(now + FADE_DURATION / 2), Some((1., 0.5)));
+        assert!(state.is_visible(now + FADE_DURATION / 2));
+        assert_eq!(state.frame(now + FADE_DURATION), None);
+
+        // Restart from zero.
+        let c = state.start(now);
+        state.set(c, 0.1);
+        assert_eq!(state.frame(now), Some((0.1, 1.)));
+    }
+}
@@ -0,0 +1,258 @@
+//! The locale of the components, and the locale-aware formatters of numbers, dates and
+//! relative times.
+//!
+//! The built-in strings are translated by the current locale, use [`Locale::set`] to switch
+//! the locale at runtime, the windows will be refreshed and the [`Locale::on_change`]
+//! listeners will be notified.
+//!
+//! ```ignore
+//! use gpui_component::locale::{self, DateStyle, Locale};
+//!
+//! Locale::set("zh-CN", cx);
+//! assert_eq!(locale::format_number(1234.5, 2), "1,234.50");
+//! assert_eq!(locale::format_date(&date, DateStyle::Long), "2024年8月3日");
+//! ```
+use std::borrow::Cow;
+
+use chrono::{Datelike as _, NaiveDate, TimeDelta};
+use gpui::{App, Global, SharedString, Subscription};
+use rust_i18n::t;
+
+/// The global locale state, used to notify the locale changes.
+struct LocaleState {
+    locale: SharedString,
+}
+
+impl Global for LocaleState {}
+
+/// The locale change event, see [`Locale::on_change`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocaleChangeEvent {
+    /// The new locale, e.g.: `en`, `zh-CN`.
+    pub locale: SharedString,
+}
+
+/// The locale of the components.
+pub struct Locale;
+
+impl Locale {
+    /// Returns the current locale, e.g.: `en`, `zh-CN`.
+    pub fn current() -> SharedString {
+        SharedString::from(rust_i18n::locale().to_string())
+    }
+
+    /// Returns the available locales of the built-in translations.
+    pub fn available() -> Vec<SharedString> {
+        rust_i18n::available_locales!()
+            .into_iter()
+            .map(|locale| SharedString::from(locale.to_string()))
+            .collect()
+    }
+
+    /// Set the locale and refresh the windows to apply the translations.
+    ///
+    /// Unlike [`crate::set_locale`], this will notify the [`Locale::on_change`] listeners.
+    pub fn set(locale: impl Into<SharedString>, cx: &mut App) {
+        let locale: SharedString = locale.into();
+        rust_i18n::set_locale(&locale);
+        cx.set_global(LocaleState { locale });
+        cx.refresh_windows();
+    }
+
+    /// Listen the locale changes by [`Locale::set`].
+    pub fn on_change(
+        cx: &mut App,
+        f: impl Fn(&LocaleChangeEvent, &mut App) + 'static,
+    ) -> Subscription {
+        cx.observe_global::<LocaleState>(move |cx| {
+            let event = LocaleChangeEvent {
+                locale: cx.global::<LocaleState>().locale.clone(),
+            };
+            f(&event, cx);
+        })
+    }
+}
+
+/// Format the number with the grouping and decimal separators of the current locale.
+///
+/// The `precision` is the number of the digits after the decimal separator.
+pub fn format_number(value: f64, precision: usize) -> String {
+    let group = t!("Locale.number.group");
+    let decimal = t!("Locale.number.decimal");
+    group_number(value, precision, &group, &decimal)
+}
+
+/// Format the integer with the grouping separator of the current locale.
+pub fn format_integer(value: impl Into<i64>) -> String {
+    format_number(value.into() as f64, 0)
+}
+
+fn group_number(value: f64, precision: usize, group: &str, decimal: &str) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let formatted = format!("{:.*}", precision, value.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut result = String::with_capacity(formatted.len() + int_part.len() / 3 + 1);
+    if value < 0. && formatted.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        result.push('-');
+    }
+    for (ix, c) in int_part.chars().enumerate() {
+        if ix > 0 && (int_part.len() - ix) % 3 == 0 {
+            result.push_str(group);
+        }
+        result.push(c);
+    }
+    if let Some(frac_part) = frac_part {
+        result.push_str(decimal);
+        result.push_str(frac_part);
+    }
+    result
+}
+
+/// The style of the formatted date, see [`format_date`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// The numeric date, e.g.: `08/03/2024`, `2024/08/03`.
+    #[default]
+    Short,
+    /// The date with the month name, e.g.: `August 3, 2024`, `2024年8月3日`.
+    Long,
+}
+
+/// Returns the [`chrono`] format of the [`DateStyle::Short`] date of the current locale,
+/// e.g.: `%m/%d/%Y`.
+pub fn date_format() -> SharedString {
+    t!("Locale.date.short").into()
+}
+
+/// Format the date by the current locale.
+pub fn format_date(date: &NaiveDate, style: DateStyle) -> String {
+    match style {
+        DateStyle::Short => date.format(&date_format()).to_string(),
+        DateStyle::Long => t!(
+            "Locale.date.long",
+            year = date.year(),
+            month = month_name(date.month()),
+            m = date.month(),
+            day = date.day()
+        )
+        .into_owned(),
+    }
+}
+
+/// Returns the name of the month (1-12) in the current locale.
+pub fn month_name(month: u32) -> SharedString {
+    match month {
+        1 => t!("Calendar.month.January"),
+        2 => t!("Calendar.month.February"),
+        3 => t!("Calendar.month.March"),
+        4 => t!("Calendar.month.April"),
+        5 => t!("Calendar.month.May"),
+        6 => t!("Calendar.month.June"),
+        7 => t!("Calendar.month.July"),
+        8 => t!("Calendar.month.August"),
+        9 => t!("Calendar.month.September"),
+        10 => t!("Calendar.month.October"),
+        11 => t!("Calendar.month.November"),
+        12 => t!("Calendar.month.December"),
+        _ => Cow::Borrowed(""),
+    }
+    .into()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeUnit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+/// Returns the largest unit and the count of the `seconds`, None if less than a minute.
+fn relative_unit(seconds: i64) -> Option<(TimeUnit, i64)> {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const MONTH: i64 = 30 * DAY;
+    const YEAR: i64 = 365 * DAY;
+
+    let seconds = seconds.abs();
+    let unit = match seconds {
+        s if s < MINUTE => return None,
+        s if s < HOUR => (TimeUnit::Minute, s / MINUTE),
+        s if s < DAY => (TimeUnit::Hour, s / HOUR),
+        s if s < MONTH => (TimeUnit::Day, s / DAY),
+        s if s < YEAR => (TimeUnit::Month, s / MONTH),
+        s => (TimeUnit::Year, s / YEAR),
+    };
+    Some(unit)
+}
+
+/// Format the time delta as a relative time in the current locale,
+/// e.g.: `3 minutes ago` for a negative delta, `in 2 days` for a positive delta.
+pub fn format_relative_time(delta: TimeDelta) -> String {
+    let seconds = delta.num_seconds();
+    let Some((unit, count)) = relative_unit(seconds) else {
+        return t!("Locale.relative.now").into_owned();
+    };
+
+    let count = format_integer(count);
+    let one = count == "1";
+    let future = seconds > 0;
+    match (unit, one, future) {
+        (TimeUnit::Minute, true, false) => t!("Locale.relative.minute_ago"),
+        (TimeUnit::Minute, false, false) => t!("Locale.relative.minutes_ago", count = count),
+        (TimeUnit::Minute, true, true) => t!("Locale.relative.in_minute"),
+        (TimeUnit::Minute, false, true) => t!("Locale.relative.in_minutes", count = count),
+        (TimeUnit::Hour, true, false) => t!("Locale.relative.hour_ago"),
+        (TimeUnit::Hour, false, false) => t!("Locale.relative.hours_ago", count = count),
+        (TimeUnit::Hour, true, true) => t!("Locale.relative.in_hour"),
+        (TimeUnit::Hour, false, true) => t!("Locale.relative.in_hours", count = count),
+        (TimeUnit::Day, true, false) => t!("Locale.relative.day_ago"),
+        (TimeUnit::Day, false, false) => t!("Locale.relative.days_ago", count = count),
+        (TimeUnit::Day, true, true) => t!("Locale.relative.in_day"),
+        (TimeUnit::Day, false, true) => t!("Locale.relative.in_days", count = count),
+        (TimeUnit::Month, true, false) => t!("Locale.relative.month_ago"),
+        (TimeUnit::Month, false, false) => t!("Locale.relative.months_ago", count = count),
+        (TimeUnit::Month, true, true) => t!("Locale.relative.in_month"),
+        (TimeUnit::Month, false, true) => t!("Locale.relative.in_months", count = count),
+        (TimeUnit::Year, true, false) => t!("Locale.relative.year_ago"),
+        (TimeUnit::Year, false, false) => t!("Locale.relative.years_ago", count = count),
+        (TimeUnit::Year, true, true) => t!("Locale.relative.in_year"),
+        (TimeUnit::Year, false, true) => t!("Locale.relative.in_years", count = count),
+    }
+    .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_number, relative_unit, TimeUnit};
+
+    #[test]
+    fn test_group_number() {
+        assert_eq!(group_number(0., 0, ",", "."), "0");
+        assert_eq!(group_number(999., 0, ",", "."), "999");
+        assert_eq!(group_number(1234., 0, ",", "."), "1,234");
+        assert_eq!(group_number(1234567.891, 2, ",", "."), "1,234,567.89");
+        assert_eq!(group_number(-1234.5, 1, ".", ","), "-1.234,5");
+        assert_eq!(group_number(-0.001, 2, ",", "."), "0.00");
+        assert_eq!(group_number(f64::NAN, 2, ",", "."), "NaN");
+    }
+
+    #[test]
+    fn test_relative_unit() {
+        assert_eq!(relative_unit(30), None);
+        assert_eq!(relative_unit(-90), Some((TimeUnit::Minute, 1)));
+        assert_eq!(relative_unit(3 * 3600), Some((TimeUnit::Hour, 3)));
+        assert_eq!(relative_unit(-2 * 86400), Some((TimeUnit::Day, 2)));
+        assert_eq!(relative_unit(65 * 86400), Some((TimeUnit::Month, 2)));
+        assert_eq!(relative_unit(800 * 86400), Some((TimeUnit::Year, 2)));
+    }
+}
@@ -0,0 +1,768 @@
+use std::{collections::VecDeque, fmt, ops::Range};
+
+use chrono::{DateTime, Local};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, uniform_list, App, AppContext as _, ClipboardItem,
+    Context, Entity, EventEmitter, FocusHandle, Focusable, Hsla, InteractiveElement, IntoElement,
+    KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent, ParentElement, Pixels, Render,
+    ScrollStrategy, SharedString, Styled, Subscription, UniformListScrollHandle, Window,
+};
+use regex::Regex;
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    input::{self, InputEvent, InputState, TextInput},
+    label::Label,
+    scroll::{Scrollbar, ScrollbarState},
+    v_flex, ActiveTheme as _, IconName, Selectable as _, Sizable as _,
+};
+
+const CONTEXT: &str = "LogView";
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", input::Copy, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", input::Copy, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", input::SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", input::SelectAll, Some(CONTEXT)),
+    ]);
+}
+
+/// The severity level of a [`LogLine`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Detect the level by the first level keyword (e.g.: `ERROR`, `warn`) in the text,
+    /// default is [`LogLevel::Info`].
+    pub fn detect(text: &str) -> Self {
+        text.split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty())
+            .take(8)
+            .find_map(|word| match word.to_ascii_uppercase().as_str() {
+                "TRACE" => Some(Self::Trace),
+                "DEBUG" => Some(Self::Debug),
+                "INFO" => Some(Self::Info),
+                "WARN" | "WARNING" => Some(Self::Warn),
+                "ERROR" | "FATAL" => Some(Self::Error),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    pub(crate) fn color(&self, cx: &App) -> Hsla {
+        match self {
+            Self::Trace | Self::Debug => cx.theme().muted_foreground,
+            Self::Info => cx.theme().info,
+            Self::Warn => cx.theme().warning,
+            Self::Error => cx.theme().danger,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A line of the [`LogView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub level: LogLevel,
+    pub timestamp: Option<DateTime<Local>>,
+    pub message: SharedString,
+}
+
+impl LogLine {
+    pub fn new(level: LogLevel, message: impl Into<SharedString>) -> Self {
+        Self {
+            level,
+            timestamp: None,
+            message: message.into(),
+        }
+    }
+
+    /// Create a line from the plain text, the level is detected by [`LogLevel::detect`].
+    pub fn plain(message: impl Into<SharedString>) -> Self {
+        let message = message.into();
+        Self::new(LogLevel::detect(&message), message)
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Local>) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+}
+
+impl fmt::Display for LogLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(timestamp) = &self.timestamp {
+            write!(f, "{} ", timestamp.format(TIMESTAMP_FORMAT))?;
+        }
+        write!(f, "{:<5} {}", self.level.as_str(), self.message)
+    }
+}
+
+const TIMESTAMP_FORMAT: &str = "%H:%M:%S%.3f";
+
+/// The filter of the [`LogView`].
+#[derive(Debug, Clone)]
+pub enum LogFilter {
+    /// Match the lines containing the text, case insensitive.
+    Substring(String),
+    /// Match the lines by the regex.
+    Regex(Regex),
+}
+
+impl LogFilter {
+    pub fn matches(&self, line: &LogLine) -> bool {
+        match self {
+            Self::Substring(text) => contains_ignore_case(&line.message, text),
+            Self::Regex(regex) => regex.is_match(&line.message),
+        }
+    }
+}
+
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    if needle.is_ascii() {
+        let needle = needle.as_bytes();
+        needle.is_empty()
+            || haystack
+                .as_bytes()
+                .windows(needle.len())
+                .any(|window| window.eq_ignore_ascii_case(needle))
+    } else {
+        haystack.to_lowercase().contains(&needle.to_lowercase())
+    }
+}
+
+/// The lines storage with a fixed capacity, the oldest lines are dropped when full.
+///
+/// The lines are addressed by the sequence number that is never reused,
+/// so the filtered results and the selection are kept after the old lines are dropped.
+struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    /// The sequence number of the first line in `lines`.
+    first_seq: usize,
+}
+
+impl LogBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity: capacity.max(1),
+            first_seq: 0,
+        }
+    }
+
+    /// Returns the sequence number range of the stored lines.
+    fn seqs(&self) -> Range<usize> {
+        self.first_seq..self.first_seq + self.lines.len()
+    }
+
+    fn get(&self, seq: usize) -> Option<&LogLine> {
+        self.lines.get(seq.checked_sub(self.first_seq)?)
+    }
+
+    /// Push the line, returns the sequence number of it.
+    fn push(&mut self, line: LogLine) -> usize {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+            self.first_seq += 1;
+        }
+        self.lines.push_back(line);
+        self.first_seq + self.lines.len() - 1
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.lines.len() > self.capacity {
+            self.lines.pop_front();
+            self.first_seq += 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.first_seq += self.lines.len();
+        self.lines.clear();
+    }
+}
+
+pub enum LogViewEvent {
+    /// The follow tail mode is changed, it is paused when the user scrolls up.
+    Follow(bool),
+}
+
+/// A virtualized log viewer for a large amount of appended lines.
+///
+/// - The lines are stored in a ring buffer, the oldest lines are dropped over the `capacity`.
+/// - Follow the tail when new lines are appended, paused when the user scrolls up,
+///   and resumed after scrolling back to the bottom.
+/// - Filter the lines by the substring or regex, and the minimum level.
+/// - Select the lines by click, `shift` click or drag, copy or [`LogView::export`] them.
+pub struct LogView {
+    focus_handle: FocusHandle,
+    buffer: LogBuffer,
+    filter: Option<LogFilter>,
+    min_level: LogLevel,
+    /// The sequence numbers of the matched lines, None if no filter.
+    matched: Option<VecDeque<usize>>,
+    follow: bool,
+    show_timestamps: bool,
+    line_height: Pixels,
+    /// The (anchor, head) sequence numbers of the selected lines.
+    selection: Option<(usize, usize)>,
+    selecting: bool,
+    filter_input: Entity<InputState>,
+    use_regex: bool,
+    invalid_regex: bool,
+    scroll_handle: UniformListScrollHandle,
+    scroll_state: ScrollbarState,
+    last_scroll_y: Pixels,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<LogViewEvent> for LogView {}
+
+impl Focusable for LogView {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl LogView {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let filter_input =
+            cx.new(|cx| InputState::new(window, cx).placeholder(t!("LogView.filter_placeholder")));
+        let _subscriptions = vec![cx.subscribe_in(
+            &filter_input,
+            window,
+            |this, _, event: &InputEvent, _, cx| {
+                if let InputEvent::Change(_) = event {
+                    this.update_filter_from_input(cx);
+                }
+            },
+        )];
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            buffer: LogBuffer::new(100_000),
+            filter: None,
+            min_level: LogLevel::Trace,
+            matched: None,
+            follow: true,
+            show_timestamps: true,
+            line_height: px(20.),
+            selection: None,
+            selecting: false,
+            filter_input,
+            use_regex: false,
+            invalid_regex: false,
+            scroll_handle: UniformListScrollHandle::default(),
+            scroll_state: ScrollbarState::default(),
+            last_scroll_y: px(0.),
+            _subscriptions,
+        }
+    }
+
+    /// Set the maximum number of the lines to keep, default is 100,000.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.buffer.set_capacity(capacity);
+        self
+    }
+
+    /// Set to show the timestamps, default is true.
+    pub fn show_timestamps(mut self, show_timestamps: bool) -> Self {
+        self.show_timestamps = show_timestamps;
+        self
+    }
+
+    /// Set the height of the lines, default is 20px.
+    pub fn line_height(mut self, line_height: impl Into<Pixels>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Append a line.
+    pub fn push(&mut self, line: LogLine, cx: &mut Context<Self>) {
+        self.push_line(line);
+        self.after_append(cx);
+    }
+
+    /// Append the lines.
+    pub fn extend(&mut self, lines: impl IntoIterator<Item = LogLine>, cx: &mut Context<Self>) {
+        for line in lines {
+            self.push_line(line);
+        }
+        self.after_append(cx);
+    }
+
+    /// Remove all the lines.
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.buffer.clear();
+        if let Some(matched) = &mut self.matched {
+            matched.clear();
+        }
+        self.selection = None;
+        cx.notify();
+    }
+
+    /// Returns the number of the stored lines.
+    pub fn len(&self) -> usize {
+        self.buffer.lines.len()
+    }
+
+    /// Returns true if there is no line.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.lines.is_empty()
+    }
+
+    /// Set the filter, None to show all the lines with the minimum level.
+    ///
+    /// This is also set by the filter input of the toolbar.
+    pub fn set_filter(&mut self, filter: Option<LogFilter>, cx: &mut Context<Self>) {
+        self.filter = filter;
+        self.rebuild_matched();
+        cx.notify();
+    }
+
+    /// Set the minimum level of the lines to show, default is [`LogLevel::Trace`] to show all.
+    pub fn set_min_level(&mut self, level: LogLevel, cx: &mut Context<Self>) {
+        self.min_level = level;
+        self.rebuild_matched();
+        cx.notify();
+    }
+
+    /// Returns the number of the lines matched the filter, or all the lines if no filter.
+    pub fn match_count(&self) -> usize {
+        match &self.matched {
+            Some(matched) => matched.len(),
+            None => self.buffer.lines.len(),
+        }
+    }
+
+    /// Returns true if following the tail.
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Set to follow the tail, scroll to the bottom if true.
+    pub fn set_follow(&mut self, follow: bool, cx: &mut Context<Self>) {
+        if self.follow != follow {
+            self.follow = follow;
+            cx.emit(LogViewEvent::Follow(follow));
+        }
+        if follow {
+            self.scroll_to_bottom();
+        }
+        cx.notify();
+    }
+
+    /// Returns the text of the selected lines.
+    pub fn selected_text(&self) -> Option<String> {
+        let (anchor, head) = self.selection?;
+        let range = anchor.min(head)..anchor.max(head) + 1;
+        Some(self.lines_text(self.visible_seqs().filter(|seq| range.contains(seq))))
+    }
+
+    /// Export the visible (filtered) lines as the text.
+    pub fn export(&self) -> String {
+        self.lines_text(self.visible_seqs())
+    }
+
+    fn lines_text(&self, seqs: impl Iterator<Item = usize>) -> String {
+        let mut text = String::new();
+        for line in seqs.filter_map(|seq| self.buffer.get(seq)) {
+            use std::fmt::Write as _;
+            _ = writeln!(text, "{}", line);
+        }
+        text
+    }
+
+    fn visible_seqs(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+        match &self.matched {
+            Some(matched) => Box::new(matched.iter().copied()),
+            None => Box::new(self.buffer.seqs()),
+        }
+    }
+
+    /// Returns the sequence number of the visible line at the `ix`.
+    fn seq_at(&self, ix: usize) -> Option<usize> {
+        match &self.matched {
+            Some(matched) => matched.get(ix).copied(),
+            None => {
+                let seqs = self.buffer.seqs();
+                (ix < seqs.len()).then(|| seqs.start + ix)
+            }
+        }
+    }
+
+    fn is_match(&self, line: &LogLine) -> bool {
+        line.level >= self.min_level
+            && self
+                .filter
+                .as_ref()
+                .map_or(true, |filter| filter.matches(line))
+    }
+
+    fn has_filter(&self) -> bool {
+        self.filter.is_some() || self.min_level > LogLevel::Trace
+    }
+
+    fn push_line(&mut self, line: LogLine) {
+        let matched = self.has_filter() && self.is_match(&line);
+        let seq = self.buffer.push(line);
+
+        if let Some(matches) = &mut self.matched {
+            let first_seq = self.buffer.first_seq;
+            while matches.front().is_some_and(|seq| *seq < first_seq) {
+                matches.pop_front();
+            }
+            if matched {
+                matches.push_back(seq);
+            }
+        }
+    }
+
+    fn rebuild_matched(&mut self) {
+        self.matched = self.has_filter().then(|| {
+            self.buffer
+                .seqs()
+                .filter(|seq| {
+                    self.buffer
+                        .get(*seq)
+                        .is_some_and(|line| self.is_match(line))
+                })
+                .collect()
+        });
+        if self.follow {
+            self.scroll_to_bottom();
+        }
+    }
+
+    fn after_append(&mut self, cx: &mut Context<Self>) {
+        if self.follow {
+            self.scroll_to_bottom();
+        }
+        cx.notify();
+    }
+
+    fn scroll_to_bottom(&self) {
+        let count = self.match_count();
+        if count > 0 {
+            self.scroll_handle
+                .scroll_to_item(count - 1, ScrollStrategy::Bottom);
+        }
+    }
+
+    fn update_filter_from_input(&mut self, cx: &mut Context<Self>) {
+        let text = self.filter_input.read(cx).value().to_string();
+        self.invalid_regex = false;
+
+        let filter = if text.is_empty() {
+            None
+        } else if self.use_regex {
+            match Regex::new(&text) {
+                Ok(regex) => Some(LogFilter::Regex(regex)),
+                Err(_) => {
+                    self.invalid_regex = true;
+                    None
+                }
+            }
+        } else {
+            Some(LogFilter::Substring(text))
+        };
+        self.set_filter(filter, cx);
+    }
+
+    /// Pause the follow mode if the user scrolled up, resume it when scrolled to the bottom.
+    fn update_follow_by_scroll(&mut self, cx: &mut Context<Self>) {
+        let (offset_y, max_y) = {
+            let state = self.scroll_handle.0.borrow();
+            (
+                state.base_handle.offset().y,
+                state.base_handle.max_offset().height,
+            )
+        };
+        if offset_y == self.last_scroll_y {
+            return;
+        }
+
+        // The offset is changed by the user or the deferred scroll to the bottom.
+        self.last_scroll_y = offset_y;
+        let at_bottom = -offset_y >= max_y - px(1.);
+        if self.follow != at_bottom {
+            self.follow = at_bottom;
+            cx.emit(LogViewEvent::Follow(at_bottom));
+        }
+    }
+
+    fn on_line_mouse_down(&mut self, seq: usize, event: &MouseDownEvent, window: &mut Window) {
+        self.focus_handle.focus(window);
+        self.selecting = true;
+        self.selection = match self.selection {
+            Some((anchor, _)) if event.modifiers.shift => Some((anchor, seq)),
+            _ => Some((seq, seq)),
+        };
+    }
+
+    fn on_line_mouse_move(&mut self, seq: usize, event: &MouseMoveEvent, cx: &mut Context<Self>) {
+        if !self.selecting || event.pressed_button != Some(MouseButton::Left) {
+            self.selecting = false;
+            return;
+        }
+
+        if let Some((anchor, head)) = self.selection {
+            if head != seq {
+                self.selection = Some((anchor, seq));
+                cx.notify();
+            }
+        }
+    }
+
+    fn action_copy(&mut self, _: &input::Copy, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(text) = self.selected_text() {
+            cx.write_to_clipboard(ClipboardItem::new_string(text));
+        }
+    }
+
+    fn action_select_all(&mut self, _: &input::SelectAll, _: &mut Window, cx: &mut Context<Self>) {
+        let count = self.match_count();
+        if let (Some(first), Some(last)) = (self.seq_at(0), self.seq_at(count.saturating_sub(1))) {
+            self.selection = Some((first, last));
+            cx.notify();
+        }
+    }
+
+    fn render_line(&self, ix: usize, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let seq = self.seq_at(ix)?;
+        let line = self.buffer.get(seq)?;
+        let selected = self
+            .selection
+            .is_some_and(|(anchor, head)| (anchor.min(head)..=anchor.max(head)).contains(&seq));
+
+        Some(
+            h_flex()
+                .id(ix)
+                .h(self.line_height)
+                .px_2()
+                .gap_2()
+                .whitespace_nowrap()
+                .when(selected, |this| this.bg(cx.theme().selection))
+                .when(self.show_timestamps, |this| {
+                    this.child(
+                        div()
+                            .flex_shrink_0()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                line.timestamp
+                                    .map(|timestamp| timestamp.format(TIMESTAMP_FORMAT).to_string())
+                                    .unwrap_or_default(),
+                            ),
+                    )
+                })
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .w(px(44.))
+                        .text_color(line.level.color(cx))
+                        .child(line.level.as_str()),
+                )
+                .child(
+                    div()
+                        .when(line.level == LogLevel::Error, |this| {
+                            this.text_color(cx.theme().danger)
+                        })
+                        .child(line.message.clone()),
+                )
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, event, window, cx| {
+                        this.on_line_mouse_down(seq, event, window);
+                        cx.notify();
+                    }),
+                )
+                .on_mouse_move(cx.listener(move |this, event, _, cx| {
+                    this.on_line_mouse_move(seq, event, cx);
+                })),
+        )
+    }
+
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let count_label = if self.invalid_regex {
+            Label::new(t!("LogView.invalid_regex")).text_color(cx.theme().danger)
+        } else {
+            Label::new(t!("LogView.matches", count = self.match_count()))
+                .text_color(cx.theme().muted_foreground)
+        };
+
+        h_flex()
+            .p_1()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    TextInput::new(&self.filter_input)
+                        .small()
+                        .cleanable()
+                        .suffix(
+                            Button::new("regex")
+                                .selected(self.use_regex)
+                                .xsmall()
+                                .compact()
+                                .ghost()
+                                .label(".*")
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.use_regex = !this.use_regex;
+                                    this.update_filter_from_input(cx);
+                                })),
+                        ),
+                ),
+            )
+            .child(count_label.text_xs().whitespace_nowrap())
+            .child(
+                Button::new("follow")
+                    .selected(self.follow)
+                    .small()
+                    .ghost()
+                    .icon(IconName::ArrowDown)
+                    .tooltip(t!("LogView.follow"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.set_follow(!this.follow, cx);
+                    })),
+            )
+    }
+}
+
+impl Render for LogView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.update_follow_by_scroll(cx);
+
+        v_flex()
+            .id("log-view")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::action_copy))
+            .on_action(cx.listener(Self::action_select_all))
+            .size_full()
+            .child(self.render_toolbar(cx))
+            .child(
+                div()
+                    .relative()
+                    .flex_1()
+                    .font_family("Menlo, Monaco, Consolas, monospace")
+                    .text_xs()
+                    .child(
+                        uniform_list(
+                            "lines",
+                            self.match_count(),
+                            cx.processor(move |this, range: Range<usize>, _, cx| {
+                                range
+                                    .filter_map(|ix| {
+                                        this.render_line(ix, cx).map(|el| el.into_any_element())
+                                    })
+                                    .collect::<Vec<_>>()
+                            }),
+                        )
+                        .size_full()
+                        .track_scroll(self.scroll_handle.clone()),
+                    )
+                    .child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .right_0()
+                            .bottom_0()
+                            .w(crate::scroll::width(cx))
+                            .child(Scrollbar::uniform_scroll(
+                                &self.scroll_state,
+                                &self.scroll_handle,
+                            )),
+                    )
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        cx.listener(|this, _, _, _| {
+                            this.selecting = false;
+                        }),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_ignore_case, LogBuffer, LogLevel, LogLine};
+
+    #[test]
+    fn test_log_level_detect() {
+        assert_eq!(
+            LogLevel::detect("[ERROR] failed to connect"),
+            LogLevel::Error
+        );
+        assert_eq!(
+            LogLevel::detect("2024-01-01 warn: disk full"),
+            LogLevel::Warn
+        );
+        assert_eq!(LogLevel::detect("DEBUG main.rs:10"), LogLevel::Debug);
+        assert_eq!(LogLevel::detect("Server started"), LogLevel::Info);
+        assert!(LogLevel::Error > LogLevel::Warn);
+    }
+
+    #[test]
+    fn test_log_buffer() {
+        let mut buffer = LogBuffer::new(3);
+        for i in 0..5 {
+            assert_eq!(buffer.push(LogLine::plain(format!("line {i}"))), i);
+        }
+        assert_eq!(buffer.seqs(), 2..5);
+        assert!(buffer.get(1).is_none());
+        assert_eq!(buffer.get(2).unwrap().message, "line 2");
+
+        buffer.set_capacity(2);
+        assert_eq!(buffer.seqs(), 3..5);
+
+        buffer.clear();
+        assert_eq!(buffer.seqs(), 5..5);
+        assert_eq!(buffer.push(LogLine::plain("line 5")), 5);
+    }
+
+    #[test]
+    fn test_contains_ignore_case() {
+        assert!(contains_ignore_case("Connection Refused", "refused"));
+        assert!(contains_ignore_case("anything", ""));
+        assert!(!contains_ignore_case("ok", "error"));
+        assert!(contains_ignore_case("Ünïcode", "üNÏ"));
+    }
+
+    #[test]
+    fn test_log_line_display() {
+        let line = LogLine::new(LogLevel::Warn, "low memory");
+        assert_eq!(line.to_string(), "WARN  low memory");
+    }
+}
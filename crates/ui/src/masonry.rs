@@ -0,0 +1,395 @@
+//! A Pinterest-style layout to place the items into the shortest column.
+//!
+//! Only the items intersecting the viewport are rendered, so it can be used for a large gallery.
+use std::{ops::Range, rc::Rc};
+
+use gpui::{
+    div, point, px, size, AnyElement, App, AvailableSpace, Bounds, ContentMask, Context, Div,
+    Element, ElementId, Entity, GlobalElementId, Hitbox, InteractiveElement, IntoElement, Pixels,
+    Render, ScrollHandle, Size, Stateful, StatefulInteractiveElement, StyleRefinement, Styled,
+    Window,
+};
+use smallvec::SmallVec;
+
+use crate::{debug_overlay::DebugOverlay, grid::ResponsiveColumns};
+
+/// Create a [`Masonry`] with the natural size of each item.
+///
+/// Each item is scaled to the column width by keeping the aspect ratio of the `item_sizes`,
+/// use a zero width to keep the height of an item, e.g.: a text card.
+///
+/// The `f` is called with the range of the items to render.
+///
+/// ```ignore
+/// masonry(cx.entity(), "photos", self.photo_sizes.clone(), |this, range, _, cx| {
+///     range.map(|ix| this.render_photo(ix, cx)).collect()
+/// })
+/// .columns(2)
+/// .breakpoint(px(800.), 4)
+/// .gap_2()
+/// ```
+pub fn masonry<R, V>(
+    view: Entity<V>,
+    id: impl Into<ElementId>,
+    item_sizes: Rc<Vec<Size<Pixels>>>,
+    f: impl 'static + Fn(&mut V, Range<usize>, &mut Window, &mut Context<V>) -> Vec<R>,
+) -> Masonry
+where
+    R: IntoElement,
+    V: Render,
+{
+    let id: ElementId = id.into();
+    let scroll_handle = ScrollHandle::new();
+    let render_range = move |visible_range, window: &mut Window, cx: &mut App| {
+        view.update(cx, |this, cx| {
+            f(this, visible_range, window, cx)
+                .into_iter()
+                .map(|component| component.into_any_element())
+                .collect()
+        })
+    };
+
+    Masonry {
+        id: id.clone(),
+        base: div()
+            .id(id)
+            .size_full()
+            .overflow_y_scroll()
+            .track_scroll(&scroll_handle),
+        scroll_handle,
+        item_sizes,
+        columns: ResponsiveColumns::new(2),
+        render_items: Box::new(render_range),
+    }
+}
+
+/// A virtualized masonry layout, see [`masonry`].
+///
+/// Use the `gap` methods of [`Styled`] to control the gap between the columns and the items.
+pub struct Masonry {
+    id: ElementId,
+    base: Stateful<Div>,
+    scroll_handle: ScrollHandle,
+    item_sizes: Rc<Vec<Size<Pixels>>>,
+    columns: ResponsiveColumns,
+    render_items: Box<
+        dyn for<'a> Fn(Range<usize>, &'a mut Window, &'a mut App) -> SmallVec<[AnyElement; 64]>,
+    >,
+}
+
+impl Masonry {
+    pub fn track_scroll(mut self, scroll_handle: &ScrollHandle) -> Self {
+        self.base = self.base.track_scroll(scroll_handle);
+        self.scroll_handle = scroll_handle.clone();
+        self
+    }
+
+    /// Set the column count when the width is less than all the breakpoints, default is 2.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns.set_columns(columns);
+        self
+    }
+
+    /// Use the `columns` when the width is greater than or equal to the `min_width`.
+    pub fn breakpoint(mut self, min_width: impl Into<Pixels>, columns: usize) -> Self {
+        self.columns.add_breakpoint(min_width.into(), columns);
+        self
+    }
+}
+
+impl Styled for Masonry {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The placement of the items, relative to the content origin.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MasonryLayout {
+    item_sizes: Rc<Vec<Size<Pixels>>>,
+    width: Pixels,
+    columns: usize,
+    gap: Size<Pixels>,
+    bounds: Vec<Bounds<Pixels>>,
+    content_height: Pixels,
+}
+
+impl MasonryLayout {
+    fn new(
+        item_sizes: Rc<Vec<Size<Pixels>>>,
+        width: Pixels,
+        columns: usize,
+        gap: Size<Pixels>,
+    ) -> Self {
+        let columns = columns.max(1);
+        let column_width =
+            ((width - gap.width * (columns - 1) as f32) / columns as f32).max(px(0.));
+        let mut column_heights = vec![px(0.); columns];
+
+        let bounds = item_sizes
+            .iter()
+            .map(|item_size| {
+                let height = if item_size.width > px(0.) {
+                    item_size.height * (column_width / item_size.width)
+                } else {
+                    item_size.height
+                };
+
+                let (column, top) = column_heights
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by_key(|(_, height)| *height)
+                    .unwrap_or_default();
+                column_heights[column] = top + height + gap.height;
+
+                Bounds {
+                    origin: point((column_width + gap.width) * column as f32, top),
+                    size: size(column_width, height),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let content_height = if bounds.is_empty() {
+            px(0.)
+        } else {
+            column_heights.into_iter().max().unwrap_or_default() - gap.height
+        };
+
+        Self {
+            item_sizes,
+            width,
+            columns,
+            gap,
+            bounds,
+            content_height,
+        }
+    }
+
+    /// Returns the range of the items intersecting the vertical range from `top` to `bottom`.
+    fn visible_range(&self, top: Pixels, bottom: Pixels) -> Range<usize> {
+        let is_visible = |bounds: &Bounds<Pixels>| bounds.bottom() >= top && bounds.top() <= bottom;
+        let Some(start) = self.bounds.iter().position(is_visible) else {
+            return 0..0;
+        };
+        let end = self.bounds.iter().rposition(is_visible).unwrap_or(start);
+        start..end + 1
+    }
+}
+
+impl IntoElement for Masonry {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Masonry {
+    type RequestLayoutState = SmallVec<[AnyElement; 32]>;
+    type PrepaintState = Option<Hitbox>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&gpui::InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        let layout_id = self.base.interactivity().request_layout(
+            global_id,
+            inspector_id,
+            window,
+            cx,
+            |style, window, cx| {
+                window.with_text_style(style.text_style().cloned(), |window| {
+                    window.request_layout(style, None, cx)
+                })
+            },
+        );
+
+        (layout_id, SmallVec::new())
+    }
+
+    fn prepaint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&gpui::InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        items: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let style = self
+            .base
+            .interactivity()
+            .compute_style(global_id, None, window, cx);
+        let rem_size = window.rem_size();
+        let border_widths = style.border_widths.to_pixels(rem_size);
+        let paddings = style.padding.to_pixels(bounds.size.into(), rem_size);
+        let gap = size(
+            style
+                .gap
+                .width
+                .to_pixels(bounds.size.width.into(), rem_size),
+            style
+                .gap
+                .height
+                .to_pixels(bounds.size.height.into(), rem_size),
+        );
+
+        let content_bounds = Bounds::from_corners(
+            bounds.origin
+                + point(
+                    border_widths.left + paddings.left,
+                    border_widths.top + paddings.top,
+                ),
+            bounds.bottom_right()
+                - point(
+                    border_widths.right + paddings.right,
+                    border_widths.bottom + paddings.bottom,
+                ),
+        );
+        let width = content_bounds.size.width;
+        let columns = self.columns.resolve(width);
+
+        let layout =
+            window.with_element_state(global_id.unwrap(), |state: Option<Rc<MasonryLayout>>, _| {
+                let layout = match state {
+                    Some(layout)
+                        if Rc::ptr_eq(&layout.item_sizes, &self.item_sizes)
+                            && layout.width == width
+                            && layout.columns == columns
+                            && layout.gap == gap =>
+                    {
+                        layout
+                    }
+                    _ => Rc::new(MasonryLayout::new(
+                        self.item_sizes.clone(),
+                        width,
+                        columns,
+                        gap,
+                    )),
+                };
+                (layout.clone(), layout)
+            });
+
+        self.base.interactivity().prepaint(
+            global_id,
+            inspector_id,
+            bounds,
+            size(width, layout.content_height),
+            window,
+            cx,
+            |_, scroll_offset, hitbox, window, cx| {
+                let top = -scroll_offset.y - paddings.top;
+                let visible_range = layout.visible_range(top, top + bounds.size.height);
+                if visible_range.is_empty() {
+                    return hitbox;
+                }
+
+                let rendered = (self.render_items)(visible_range.clone(), window, cx);
+                window.with_content_mask(Some(ContentMask { bounds }), |window| {
+                    for (mut item, ix) in rendered.into_iter().zip(visible_range) {
+                        let item_bounds = layout.bounds[ix];
+                        if item_bounds.bottom() < top
+                            || item_bounds.top() > top + bounds.size.height
+                        {
+                            continue;
+                        }
+
+                        item.layout_as_root(
+                            size(
+                                AvailableSpace::Definite(item_bounds.size.width),
+                                AvailableSpace::Definite(item_bounds.size.height),
+                            ),
+                            window,
+                            cx,
+                        );
+                        item.prepaint_at(
+                            content_bounds.origin + item_bounds.origin + scroll_offset,
+                            window,
+                            cx,
+                        );
+                        items.push(item);
+                    }
+                });
+
+                hitbox
+            },
+        )
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&gpui::InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        items: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        DebugOverlay::record("Masonry", bounds, None, window, cx);
+        self.base.interactivity().paint(
+            global_id,
+            inspector_id,
+            bounds,
+            hitbox.as_ref(),
+            window,
+            cx,
+            |_, window, cx| {
+                for item in items {
+                    item.paint(window, cx);
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use gpui::{point, px, size, Bounds};
+
+    use super::MasonryLayout;
+
+    #[test]
+    fn test_masonry_layout() {
+        let item_sizes = Rc::new(vec![
+            size(px(100.), px(200.)),
+            size(px(100.), px(100.)),
+            size(px(0.), px(30.)),
+            size(px(50.), px(50.)),
+        ]);
+        let layout = MasonryLayout::new(item_sizes, px(210.), 2, size(px(10.), px(10.)));
+        assert_eq!(
+            layout.bounds,
+            vec![
+                Bounds::new(point(px(0.), px(0.)), size(px(100.), px(200.))),
+                Bounds::new(point(px(110.), px(0.)), size(px(100.), px(100.))),
+                Bounds::new(point(px(110.), px(110.)), size(px(100.), px(30.))),
+                Bounds::new(point(px(110.), px(150.)), size(px(100.), px(100.))),
+            ]
+        );
+        assert_eq!(layout.content_height, px(250.));
+
+        assert_eq!(layout.visible_range(px(0.), px(50.)), 0..2);
+        assert_eq!(layout.visible_range(px(120.), px(130.)), 0..3);
+        assert_eq!(layout.visible_range(px(210.), px(240.)), 3..4);
+        assert_eq!(layout.visible_range(px(300.), px(400.)), 0..0);
+
+        let layout = MasonryLayout::new(Rc::new(vec![]), px(210.), 0, size(px(10.), px(10.)));
+        assert!(layout.bounds.is_empty());
+        assert_eq!(layout.content_height, px(0.));
+    }
+}
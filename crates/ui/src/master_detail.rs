@@ -0,0 +1,444 @@
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, AnyView, App, Bounds, Context, Entity,
+    EventEmitter, FocusHandle, Focusable, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement, Pixels, Render, SharedString, Styled, Subscription, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::Cancel,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    list::{List, ListDelegate, ListEvent},
+    resizable::{h_resizable, resizable_panel, ResizableState},
+    table::{Table, TableDelegate, TableEvent},
+    v_flex, ActiveTheme, IconName, Sizable as _, StyledExt as _,
+};
+
+actions!(master_detail, [FocusMaster, FocusDetail]);
+
+const CONTEXT: &str = "MasterDetail";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("alt-left", FocusMaster, Some(CONTEXT)),
+        KeyBinding::new("alt-right", FocusDetail, Some(CONTEXT)),
+        KeyBinding::new("enter", FocusDetail, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+    ]);
+}
+
+/// Events emitted by the [`MasterDetail`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MasterDetailEvent {
+    /// The detail pane is shown in collapsed mode.
+    ShowDetail,
+    /// Back to the master pane in collapsed mode.
+    Back,
+}
+
+/// The selection of the master view and the shown pane of the [`MasterDetail`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct PaneState {
+    /// Whether the master view has a selection.
+    selected: bool,
+    /// Whether to show the detail pane in collapsed mode.
+    showing_detail: bool,
+}
+
+impl PaneState {
+    /// Returns true if the detail pane is visible.
+    fn is_detail_visible(&self, collapsed: bool) -> bool {
+        !collapsed || self.showing_detail
+    }
+
+    /// Show the detail pane, returns the event to emit in collapsed mode.
+    fn show_detail(&mut self, collapsed: bool) -> Option<MasterDetailEvent> {
+        if self.showing_detail {
+            return None;
+        }
+        self.showing_detail = true;
+        collapsed.then_some(MasterDetailEvent::ShowDetail)
+    }
+
+    /// Returns true if the `Cancel` backs to the master pane, otherwise it is propagated.
+    fn should_cancel_detail(&self, collapsed: bool) -> bool {
+        collapsed && self.showing_detail
+    }
+
+    /// Back to the master pane, returns the event to emit in collapsed mode.
+    fn show_master(&mut self, collapsed: bool) -> Option<MasterDetailEvent> {
+        if !self.showing_detail {
+            return None;
+        }
+        self.showing_detail = false;
+        collapsed.then_some(MasterDetailEvent::Back)
+    }
+}
+
+/// Returns true if only one pane is shown in the `width`.
+fn is_collapsed(width: Pixels, breakpoint: Pixels) -> bool {
+    width > px(0.) && width < breakpoint
+}
+
+/// A split view that pairs a master view (e.g. a [`List`] or [`Table`]) with a detail pane.
+///
+/// When the width is less than the `breakpoint`, only one pane will be shown,
+/// and the detail pane has a back button to return to the master pane.
+///
+/// The `escape` backs to the master pane in collapsed mode, and the [`List`] master view keeps
+/// the selection on `escape`.
+pub struct MasterDetail {
+    master: AnyView,
+    master_focus: FocusHandle,
+    detail: AnyView,
+    detail_focus: FocusHandle,
+    detail_title: Option<SharedString>,
+    resizable_state: Entity<ResizableState>,
+    master_size: Pixels,
+    breakpoint: Pixels,
+    bounds: Bounds<Pixels>,
+    state: PaneState,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl MasterDetail {
+    /// Create a new MasterDetail with a master view and its focus handle.
+    ///
+    /// Use [`MasterDetail::set_selected`] to sync the selection state of the master view,
+    /// or create by [`MasterDetail::table`], [`MasterDetail::list`] to sync automatically.
+    pub fn new(
+        master: impl Into<AnyView>,
+        master_focus: FocusHandle,
+        detail: impl Into<AnyView>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        Self {
+            master: master.into(),
+            master_focus,
+            detail: detail.into(),
+            detail_focus: cx.focus_handle(),
+            detail_title: None,
+            resizable_state: ResizableState::new(cx),
+            master_size: px(300.),
+            breakpoint: px(600.),
+            bounds: Bounds::default(),
+            state: PaneState::default(),
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Create a MasterDetail with a [`Table`] as the master view.
+    ///
+    /// Select a row to sync the selection, double click a row or press `enter` to show the detail.
+    pub fn table<D: TableDelegate>(
+        table: &Entity<Table<D>>,
+        detail: impl Into<AnyView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let master_focus = table.focus_handle(cx);
+        let mut this = Self::new(table.clone(), master_focus, detail, window, cx);
+        this.state.selected = table.read(cx).selected_row().is_some();
+        this._subscriptions = vec![cx.subscribe_in(
+            table,
+            window,
+            |this, _, ev: &TableEvent, window, cx| match ev {
+                TableEvent::SelectRow(_) => this.set_selected(true, cx),
+                TableEvent::DoubleClickedRow(_) => {
+                    this.set_selected(true, cx);
+                    this.focus_detail(window, cx);
+                }
+                _ => {}
+            },
+        )];
+        this
+    }
+
+    /// Create a MasterDetail with a [`List`] as the master view.
+    ///
+    /// Select an item to sync the selection, confirm an item to show the detail,
+    /// and cancel the selection to back to the master.
+    pub fn list<D: ListDelegate>(
+        list: &Entity<List<D>>,
+        detail: impl Into<AnyView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let master_focus = list.focus_handle(cx);
+        // Keep the selection on cancel, otherwise the detail is hidden.
+        list.update(cx, |list, _| list.set_reset_on_cancel(false));
+        let mut this = Self::new(list.clone(), master_focus, detail, window, cx);
+        this.state.selected = list.read(cx).selected_index().is_some();
+        this._subscriptions = vec![cx.subscribe_in(
+            list,
+            window,
+            |this, list, ev: &ListEvent, window, cx| match ev {
+                ListEvent::Select(_) => this.set_selected(true, cx),
+                ListEvent::Confirm(_) => {
+                    this.set_selected(true, cx);
+                    this.focus_detail(window, cx);
+                }
+                ListEvent::Cancel => {
+                    this.set_selected(list.read(cx).selected_index().is_some(), cx);
+                    this.focus_master(window, cx);
+                }
+            },
+        )];
+        this
+    }
+
+    /// Set the title of the detail pane, it will be shown beside the back button in collapsed mode.
+    pub fn detail_title(mut self, title: impl Into<SharedString>) -> Self {
+        self.detail_title = Some(title.into());
+        self
+    }
+
+    pub fn set_detail_title(&mut self, title: impl Into<SharedString>, cx: &mut Context<Self>) {
+        self.detail_title = Some(title.into());
+        cx.notify();
+    }
+
+    /// Set the initial width of the master pane, default is 300px.
+    pub fn master_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.master_size = size.into();
+        self
+    }
+
+    /// Set the width breakpoint to collapse to single pane, default is 600px.
+    pub fn breakpoint(mut self, breakpoint: impl Into<Pixels>) -> Self {
+        self.breakpoint = breakpoint.into();
+        self
+    }
+
+    /// Set whether the master view has a selection.
+    ///
+    /// When there is no selection, the detail pane will show a placeholder.
+    pub fn set_selected(&mut self, selected: bool, cx: &mut Context<Self>) {
+        self.state.selected = selected;
+        cx.notify();
+    }
+
+    /// Returns true if only one pane is shown.
+    pub fn is_collapsed(&self) -> bool {
+        is_collapsed(self.bounds.size.width, self.breakpoint)
+    }
+
+    /// Returns true if the detail pane is visible.
+    pub fn is_detail_visible(&self) -> bool {
+        self.state.is_detail_visible(self.is_collapsed())
+    }
+
+    /// Focus the master view, in collapsed mode this will back to the master pane.
+    pub fn focus_master(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(event) = self.state.show_master(self.is_collapsed()) {
+            cx.emit(event);
+        }
+        self.master_focus.focus(window);
+        cx.notify();
+    }
+
+    /// Focus the detail pane, in collapsed mode this will show the detail pane.
+    pub fn focus_detail(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(event) = self.state.show_detail(self.is_collapsed()) {
+            cx.emit(event);
+        }
+        self.detail_focus.focus(window);
+        cx.notify();
+    }
+
+    fn on_action_focus_master(
+        &mut self,
+        _: &FocusMaster,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focus_master(window, cx);
+    }
+
+    fn on_action_focus_detail(
+        &mut self,
+        _: &FocusDetail,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.detail_focus.contains_focused(window, cx) {
+            cx.propagate();
+            return;
+        }
+
+        self.focus_detail(window, cx);
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.state.should_cancel_detail(self.is_collapsed()) {
+            cx.propagate();
+            return;
+        }
+
+        self.focus_master(window, cx);
+    }
+
+    fn render_detail(&self, collapsed: bool, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .id("detail")
+            .track_focus(&self.detail_focus)
+            .size_full()
+            .when(collapsed, |this| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .px_2()
+                        .py_1()
+                        .border_b_1()
+                        .border_color(cx.theme().border)
+                        .child(
+                            Button::new("back")
+                                .ghost()
+                                .small()
+                                .icon(IconName::ArrowLeft.mirrored(cx.theme().direction))
+                                .label(t!("MasterDetail.Back"))
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.focus_master(window, cx);
+                                })),
+                        )
+                        .when_some(self.detail_title.clone(), |this, title| {
+                            this.child(div().flex_1().font_semibold().text_ellipsis().child(title))
+                        }),
+                )
+            })
+            .child(div().flex_1().size_full().overflow_hidden().map(|this| {
+                if self.state.selected {
+                    this.child(self.detail.clone())
+                } else {
+                    this.flex()
+                        .items_center()
+                        .justify_center()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(SharedString::from(t!("MasterDetail.No Selection")))
+                }
+            }))
+    }
+}
+
+impl EventEmitter<MasterDetailEvent> for MasterDetail {}
+
+impl Focusable for MasterDetail {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.master_focus.clone()
+    }
+}
+
+impl Render for MasterDetail {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity().clone();
+        let collapsed = self.is_collapsed();
+
+        div()
+            .id("master-detail")
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::on_action_focus_master))
+            .on_action(cx.listener(Self::on_action_focus_detail))
+            .on_action(cx.listener(Self::on_action_cancel))
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .child(
+                canvas(
+                    move |bounds, _, cx| {
+                        view.update(cx, |this, cx| {
+                            if this.bounds == bounds {
+                                return;
+                            }
+
+                            let collapsed = this.is_collapsed();
+                            this.bounds = bounds;
+                            if collapsed != this.is_collapsed() {
+                                cx.notify();
+                            }
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .map(|this| {
+                if collapsed {
+                    if self.state.showing_detail {
+                        this.child(self.render_detail(true, cx))
+                    } else {
+                        this.child(div().size_full().child(self.master.clone()))
+                    }
+                } else {
+                    this.child(
+                        h_resizable("master-detail", self.resizable_state.clone())
+                            .child(
+                                resizable_panel()
+                                    .size(self.master_size)
+                                    .child(div().size_full().child(self.master.clone())),
+                            )
+                            .child(resizable_panel().child(self.render_detail(false, cx))),
+                    )
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{is_collapsed, MasterDetailEvent, PaneState};
+
+    #[test]
+    fn test_is_collapsed() {
+        // Not collapsed before the layout.
+        assert!(!is_collapsed(px(0.), px(600.)));
+        assert!(is_collapsed(px(599.), px(600.)));
+        assert!(!is_collapsed(px(600.), px(600.)));
+    }
+
+    #[test]
+    fn test_pane_state() {
+        let mut state = PaneState::default();
+        assert!(state.is_detail_visible(false));
+        assert!(!state.is_detail_visible(true));
+
+        // Switch the panes in collapsed mode.
+        assert_eq!(state.show_detail(true), Some(MasterDetailEvent::ShowDetail));
+        assert!(state.is_detail_visible(true));
+        assert_eq!(state.show_detail(true), None);
+        assert_eq!(state.show_master(true), Some(MasterDetailEvent::Back));
+        assert!(!state.is_detail_visible(true));
+        assert_eq!(state.show_master(true), None);
+
+        // No events when both panes are shown.
+        assert_eq!(state.show_detail(false), None);
+        assert!(state.showing_detail);
+        assert_eq!(state.show_master(false), None);
+        assert!(!state.showing_detail);
+
+        // The selection is kept when switching the panes.
+        state.selected = true;
+        state.show_detail(true);
+        state.show_master(true);
+        assert!(state.selected);
+    }
+
+    #[test]
+    fn test_should_cancel_detail() {
+        let mut state = PaneState::default();
+        // Both panes are shown, the `Cancel` is propagated to the outer view, e.g. a Modal.
+        assert!(!state.should_cancel_detail(false));
+        state.show_detail(false);
+        assert!(!state.should_cancel_detail(false));
+
+        let mut state = PaneState::default();
+        assert!(!state.should_cancel_detail(true));
+        state.show_detail(true);
+        assert!(state.should_cancel_detail(true));
+        state.show_master(true);
+        assert!(!state.should_cancel_detail(true));
+    }
+}
@@ -7,7 +7,7 @@ use gpui::{
     ParentElement, Pixels, Point, Position, Stateful, Style, Subscription, Window,
 };
 
-use crate::menu::popup_menu::PopupMenu;
+use crate::{menu::popup_menu::PopupMenu, tab::Tab};
 
 pub trait ContextMenuExt: ParentElement + Sized {
     fn context_menu(
@@ -19,6 +19,7 @@ pub trait ContextMenuExt: ParentElement + Sized {
 }
 
 impl<E> ContextMenuExt for Stateful<E> where E: ParentElement {}
+impl ContextMenuExt for Tab {}
 
 /// A context menu that can be shown on right-click.
 pub struct ContextMenu {
@@ -0,0 +1,411 @@
+use std::rc::Rc;
+
+use crate::{
+    actions::{Cancel, SelectLeft, SelectRight},
+    button::{Button, ButtonVariants},
+    h_flex,
+    popup_menu::PopupMenu,
+    Selectable, Sizable,
+};
+use gpui::{
+    anchored, canvas, deferred, div, prelude::FluentBuilder, px, App, AppContext as _, ClickEvent,
+    Context, DismissEvent, Entity, Focusable, HighlightStyle, InteractiveElement as _, IntoElement,
+    KeyBinding, KeyDownEvent, ParentElement, Render, SharedString, StatefulInteractiveElement,
+    Styled, StyledText, Subscription, UnderlineStyle, Window,
+};
+
+const CONTEXT: &str = "MenuBar";
+pub(super) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+    ]);
+}
+
+type MenuBuilder = Rc<dyn Fn(PopupMenu, &mut Window, &mut Context<PopupMenu>) -> PopupMenu>;
+
+/// A top level menu of the [`MenuBar`], e.g.: `File`, `Edit`, `View`.
+#[derive(Clone)]
+pub struct MenuBarMenu {
+    name: SharedString,
+    builder: MenuBuilder,
+}
+
+impl MenuBarMenu {
+    /// Create a menu with the `name` and the `builder` to build the popup menu when opened.
+    ///
+    /// Use `&` in the name to define the mnemonic, e.g.: `&File` to open the menu by `alt-f`,
+    /// use `&&` for a literal `&`.
+    pub fn new(
+        name: impl Into<SharedString>,
+        builder: impl Fn(PopupMenu, &mut Window, &mut Context<PopupMenu>) -> PopupMenu + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            builder: Rc::new(builder),
+        }
+    }
+}
+
+/// An application menu bar built on [`PopupMenu`].
+///
+/// - Click a menu to open it, then hover or press `left` / `right` to open the adjacent menus.
+/// - Press `alt` with the mnemonic key to open a menu.
+/// - The menu items can be actions, checkbox or radio items and nested submenus,
+///   see [`PopupMenu`].
+pub struct MenuBar {
+    menus: Vec<Entity<MenuBarItem>>,
+    selected_ix: Option<usize>,
+}
+
+impl MenuBar {
+    /// Create a new menu bar with the menus.
+    pub fn new(menus: Vec<MenuBarMenu>, window: &mut Window, cx: &mut App) -> Entity<Self> {
+        cx.new(|cx| {
+            let mut this = Self {
+                menus: vec![],
+                selected_ix: None,
+            };
+            this.set_menus(menus, window, cx);
+            this
+        })
+    }
+
+    /// Replace the menus, this will close the opened menu.
+    pub fn set_menus(
+        &mut self,
+        menus: Vec<MenuBarMenu>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let menu_bar = cx.entity();
+        self.selected_ix = None;
+        self.menus = menus
+            .into_iter()
+            .enumerate()
+            .map(|(ix, menu)| MenuBarItem::new(ix, menu, menu_bar.clone(), window, cx))
+            .collect();
+        cx.notify();
+    }
+
+    fn move_left(&mut self, _: &SelectLeft, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(selected_ix) = self.selected_ix else {
+            return;
+        };
+
+        let new_ix = if selected_ix == 0 {
+            self.menus.len().saturating_sub(1)
+        } else {
+            selected_ix.saturating_sub(1)
+        };
+        self.set_selected_ix(Some(new_ix), window, cx);
+    }
+
+    fn move_right(&mut self, _: &SelectRight, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(selected_ix) = self.selected_ix else {
+            return;
+        };
+
+        let new_ix = if selected_ix + 1 >= self.menus.len() {
+            0
+        } else {
+            selected_ix + 1
+        };
+        self.set_selected_ix(Some(new_ix), window, cx);
+    }
+
+    fn cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_selected_ix(None, window, cx);
+    }
+
+    fn set_selected_ix(&mut self, ix: Option<usize>, _: &mut Window, cx: &mut Context<Self>) {
+        self.selected_ix = ix;
+        cx.notify();
+    }
+
+    #[inline]
+    fn has_activated_menu(&self) -> bool {
+        self.selected_ix.is_some()
+    }
+
+    /// Open the menu by the mnemonic key, returns true if a menu is opened.
+    fn open_by_mnemonic(&mut self, key: char, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(ix) = self
+            .menus
+            .iter()
+            .position(|menu| menu.read(cx).mnemonic == Some(key))
+        else {
+            return false;
+        };
+
+        self.set_selected_ix(Some(ix), window, cx);
+        true
+    }
+}
+
+impl Render for MenuBar {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity();
+
+        h_flex()
+            .id("menu-bar")
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::move_left))
+            .on_action(cx.listener(Self::move_right))
+            .on_action(cx.listener(Self::cancel))
+            .size_full()
+            .gap_x_1()
+            .overflow_x_scroll()
+            .children(self.menus.clone())
+            .child(
+                // Listen the mnemonic keys in the window, the menu bar is not focused in most cases.
+                canvas(
+                    |_, _, _| {},
+                    move |_, _, window, _| {
+                        let view = view.clone();
+                        window.on_key_event(move |event: &KeyDownEvent, phase, window, cx| {
+                            if !phase.bubble() {
+                                return;
+                            }
+
+                            let modifiers = event.keystroke.modifiers;
+                            if !modifiers.alt
+                                || modifiers.control
+                                || modifiers.platform
+                                || modifiers.shift
+                            {
+                                return;
+                            }
+
+                            let mut chars = event.keystroke.key.chars();
+                            let (Some(key), None) = (chars.next(), chars.next()) else {
+                                return;
+                            };
+
+                            if view.update(cx, |this, cx| this.open_by_mnemonic(key, window, cx)) {
+                                cx.stop_propagation();
+                            }
+                        });
+                    },
+                )
+                .size_0(),
+            )
+    }
+}
+
+/// A menu in the menu bar.
+pub(super) struct MenuBarItem {
+    menu_bar: Entity<MenuBar>,
+    ix: usize,
+    name: SharedString,
+    /// The byte index of the mnemonic char in the `name`.
+    mnemonic_ix: Option<usize>,
+    mnemonic: Option<char>,
+    builder: MenuBuilder,
+    popup_menu: Option<Entity<PopupMenu>>,
+
+    _subscription: Option<Subscription>,
+}
+
+impl MenuBarItem {
+    fn new(
+        ix: usize,
+        menu: MenuBarMenu,
+        menu_bar: Entity<MenuBar>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Entity<Self> {
+        let (name, mnemonic_ix) = parse_mnemonic(&menu.name);
+        let mnemonic = mnemonic_ix
+            .and_then(|ix| name[ix..].chars().next())
+            .map(|c| c.to_ascii_lowercase());
+
+        cx.new(|_| Self {
+            ix,
+            menu_bar,
+            name,
+            mnemonic_ix,
+            mnemonic,
+            builder: menu.builder,
+            popup_menu: None,
+            _subscription: None,
+        })
+    }
+
+    fn build_popup_menu(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<PopupMenu> {
+        let popup_menu = match self.popup_menu.as_ref() {
+            None => {
+                let builder = self.builder.clone();
+                let popup_menu =
+                    PopupMenu::build(window, cx, |menu, window, cx| builder(menu, window, cx));
+                popup_menu.read(cx).focus_handle(cx).focus(window);
+                self._subscription =
+                    Some(cx.subscribe_in(&popup_menu, window, Self::handle_dismiss));
+                self.popup_menu = Some(popup_menu.clone());
+
+                popup_menu
+            }
+            Some(menu) => menu.clone(),
+        };
+
+        let focus_handle = popup_menu.read(cx).focus_handle(cx);
+        if !focus_handle.contains_focused(window, cx) {
+            focus_handle.focus(window);
+        }
+
+        popup_menu
+    }
+
+    fn handle_dismiss(
+        &mut self,
+        _: &Entity<PopupMenu>,
+        _: &DismissEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self._subscription.take();
+        self.popup_menu.take();
+        self.menu_bar.update(cx, |state, cx| {
+            state.cancel(&Cancel, window, cx);
+        });
+    }
+
+    fn handle_trigger_click(
+        &mut self,
+        _: &ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let is_selected = self.menu_bar.read(cx).selected_ix == Some(self.ix);
+
+        _ = self.menu_bar.update(cx, |state, cx| {
+            let new_ix = if is_selected { None } else { Some(self.ix) };
+            state.set_selected_ix(new_ix, window, cx);
+        });
+    }
+
+    fn handle_hover(&mut self, hovered: &bool, window: &mut Window, cx: &mut Context<Self>) {
+        if !*hovered {
+            return;
+        }
+
+        let has_activated_menu = self.menu_bar.read(cx).has_activated_menu();
+        if !has_activated_menu {
+            return;
+        }
+
+        _ = self.menu_bar.update(cx, |state, cx| {
+            state.set_selected_ix(Some(self.ix), window, cx);
+        });
+    }
+}
+
+impl Render for MenuBarItem {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let menu_bar = self.menu_bar.read(cx);
+        let is_selected = menu_bar.selected_ix == Some(self.ix);
+        if !is_selected {
+            // Rebuild the popup menu in next open, to update the checked states.
+            self.popup_menu.take();
+            self._subscription.take();
+        }
+
+        let mnemonic_range = self.mnemonic_ix.and_then(|ix| {
+            let len = self.name[ix..].chars().next()?.len_utf8();
+            Some(ix..ix + len)
+        });
+
+        div()
+            .id(self.ix)
+            .relative()
+            .child(
+                Button::new("menu")
+                    .small()
+                    .py_0p5()
+                    .compact()
+                    .ghost()
+                    .child(
+                        StyledText::new(self.name.clone()).with_highlights(mnemonic_range.map(
+                            |range| {
+                                (
+                                    range,
+                                    HighlightStyle {
+                                        underline: Some(UnderlineStyle {
+                                            thickness: px(1.),
+                                            ..Default::default()
+                                        }),
+                                        ..Default::default()
+                                    },
+                                )
+                            },
+                        )),
+                    )
+                    .selected(is_selected)
+                    .on_click(cx.listener(Self::handle_trigger_click)),
+            )
+            .on_hover(cx.listener(Self::handle_hover))
+            .when(is_selected, |this| {
+                this.child(deferred(
+                    anchored()
+                        .anchor(gpui::Corner::TopLeft)
+                        .snap_to_window_with_margin(px(8.))
+                        .child(
+                            div()
+                                .size_full()
+                                .occlude()
+                                .top_1()
+                                .child(self.build_popup_menu(window, cx)),
+                        ),
+                ))
+            })
+    }
+}
+
+/// Parse the mnemonic from the menu name, returns the name without `&` and the byte index
+/// of the mnemonic char.
+fn parse_mnemonic(name: &str) -> (SharedString, Option<usize>) {
+    let mut label = String::with_capacity(name.len());
+    let mut mnemonic_ix = None;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.peek() {
+                Some('&') => {
+                    chars.next();
+                }
+                Some(_) => {
+                    if mnemonic_ix.is_none() {
+                        mnemonic_ix = Some(label.len());
+                    }
+                    continue;
+                }
+                None => {}
+            }
+        }
+        label.push(c);
+    }
+
+    (label.into(), mnemonic_ix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_mnemonic;
+
+    #[test]
+    fn test_parse_mnemonic() {
+        assert_eq!(parse_mnemonic("&File"), ("File".into(), Some(0)));
+        assert_eq!(parse_mnemonic("E&dit"), ("Edit".into(), Some(1)));
+        assert_eq!(parse_mnemonic("View"), ("View".into(), None));
+        assert_eq!(
+            parse_mnemonic("Fish && &Chips"),
+            ("Fish & Chips".into(), Some(7))
+        );
+        assert_eq!(parse_mnemonic("Help&"), ("Help&".into(), None));
+    }
+}
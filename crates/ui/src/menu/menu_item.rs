@@ -1,4 +1,4 @@
-use crate::{h_flex, ActiveTheme, Disableable, StyledExt};
+use crate::{h_flex, ActiveTheme, DensityExt as _, DirectionExt as _, Disableable, StyledExt};
 use gpui::{
     prelude::FluentBuilder as _, AnyElement, App, ClickEvent, ElementId, InteractiveElement,
     IntoElement, MouseButton, ParentElement, RenderOnce, SharedString,
@@ -92,6 +92,8 @@ impl RenderOnce for MenuItemElement {
             .relative()
             .items_center()
             .justify_between()
+            .density(cx.theme().density)
+            .direction(cx.theme().direction)
             .refine_style(&self.style)
             .when_some(self.on_hover, |this, on_hover| {
                 this.on_hover(move |hovered, window, cx| (on_hover)(hovered, window, cx))
@@ -1,14 +1,16 @@
 use gpui::App;
 
 mod app_menu_bar;
+mod menu_bar;
 mod menu_item;
 
 pub mod context_menu;
 pub mod popup_menu;
 
 pub use app_menu_bar::AppMenuBar;
+pub use menu_bar::{MenuBar, MenuBarMenu};
 
 pub(crate) fn init(cx: &mut App) {
-    app_menu_bar::init(cx);
+    menu_bar::init(cx);
     popup_menu::init(cx);
 }
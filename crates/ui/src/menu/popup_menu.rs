@@ -1,19 +1,24 @@
-use crate::actions::{Cancel, Confirm, SelectNext, SelectPrev};
-use crate::input::{SelectLeft, SelectRight};
+use crate::actions::{
+    Cancel, Confirm, SelectFirst, SelectLast, SelectNext, SelectPageDown, SelectPageUp, SelectPrev,
+};
+use crate::input::{InputEvent, InputState, MoveDown, MoveUp, SelectLeft, SelectRight, TextInput};
+use crate::list::navigation::{step_index, TypeAhead};
 use crate::menu::menu_item::MenuItemElement;
+use crate::position::Position;
 use crate::scroll::{Scrollbar, ScrollbarState};
 use crate::{
-    button::Button, h_flex, popover::Popover, v_flex, ActiveTheme, Icon, IconName, Selectable,
-    Sizable as _,
+    button::Button, h_flex, popover::Popover, v_flex, ActiveTheme, CachedText, Icon, IconName,
+    Selectable, Sizable as _,
 };
-use crate::{Kbd, Side, Size, StyledExt};
+use crate::{Kbd, Placement, Side, Size, StyledExt};
 use gpui::{
-    anchored, canvas, div, prelude::FluentBuilder, px, rems, Action, AnyElement, App, AppContext,
-    Bounds, Context, Corner, DismissEvent, Edges, Entity, EventEmitter, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement, Styled, WeakEntity, Window,
+    anchored, canvas, div, prelude::FluentBuilder, px, rems, size, Action, AnyElement, App,
+    AppContext, Bounds, Context, Corner, DismissEvent, Edges, Entity, EventEmitter, FocusHandle,
+    Focusable, InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render,
+    ScrollHandle, SharedString, StatefulInteractiveElement, Styled, WeakEntity, Window,
 };
-use gpui::{Half, MouseDownEvent, OwnedMenuItem, Subscription};
+use gpui::{Half, KeyDownEvent, MouseDownEvent, OwnedMenuItem, Subscription};
+use rust_i18n::t;
 use std::rc::Rc;
 
 const CONTEXT: &str = "PopupMenu";
@@ -24,6 +29,10 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("escape", Cancel, Some(CONTEXT)),
         KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
         KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirst, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLast, Some(CONTEXT)),
+        KeyBinding::new("pageup", SelectPageUp, Some(CONTEXT)),
+        KeyBinding::new("pagedown", SelectPageDown, Some(CONTEXT)),
         KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
         KeyBinding::new("right", SelectRight, Some(CONTEXT)),
     ]);
@@ -59,13 +68,20 @@ pub trait PopupMenuExt: Styled + Selectable + InteractiveElement + IntoElement +
 }
 impl PopupMenuExt for Button {}
 
-pub(crate) enum PopupMenuItem {
+/// An item of the [`PopupMenu`].
+///
+/// Most of the items are added by the [`PopupMenu`] builders, use [`PopupMenuItem::new`]
+/// with [`PopupMenu::item`] to build an item with description or danger style.
+pub enum PopupMenuItem {
     Separator,
     Label(SharedString),
     Item {
         icon: Option<Icon>,
         label: SharedString,
+        /// The secondary text shown below the label.
+        description: Option<SharedString>,
         disabled: bool,
+        danger: bool,
         is_link: bool,
         action: Option<Box<dyn Action>>,
         // For link item
@@ -87,6 +103,108 @@ pub(crate) enum PopupMenuItem {
 }
 
 impl PopupMenuItem {
+    /// Create a new menu item with the label.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        PopupMenuItem::Item {
+            icon: None,
+            label: label.into(),
+            description: None,
+            disabled: false,
+            danger: false,
+            is_link: false,
+            action: None,
+            handler: None,
+        }
+    }
+
+    /// Set the leading icon of the item.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        if let PopupMenuItem::Item { icon: this, .. } = &mut self {
+            *this = Some(icon.into());
+        }
+        self
+    }
+
+    /// Set the secondary description text of the item.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        if let PopupMenuItem::Item {
+            description: this, ..
+        } = &mut self
+        {
+            *this = Some(description.into());
+        }
+        self
+    }
+
+    /// Set the action to dispatch when the item is clicked.
+    pub fn action(mut self, action: Box<dyn Action>) -> Self {
+        if let PopupMenuItem::Item { action: this, .. } = &mut self {
+            *this = Some(action);
+        }
+        self
+    }
+
+    /// Set the handler to call when the item is clicked, instead of dispatching the action.
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        if let PopupMenuItem::Item { handler: this, .. } = &mut self {
+            *this = Some(Rc::new(handler));
+        }
+        self
+    }
+
+    /// Set the item to use danger style, e.g.: for delete actions.
+    pub fn danger(mut self, danger: bool) -> Self {
+        if let PopupMenuItem::Item { danger: this, .. } = &mut self {
+            *this = danger;
+        }
+        self
+    }
+
+    /// Set the disabled state of the item.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        match &mut self {
+            PopupMenuItem::Item { disabled: this, .. }
+            | PopupMenuItem::ElementItem { disabled: this, .. }
+            | PopupMenuItem::Submenu { disabled: this, .. } => *this = disabled,
+            _ => {}
+        }
+        self
+    }
+
+    #[inline]
+    fn has_icon(&self) -> bool {
+        matches!(
+            self,
+            PopupMenuItem::Item { icon: Some(_), .. }
+                | PopupMenuItem::ElementItem { icon: Some(_), .. }
+                | PopupMenuItem::Submenu { icon: Some(_), .. }
+        )
+    }
+
+    /// Returns true if the item matches the lowercase `query` of the searchable menu.
+    fn matches(&self, query: &str) -> bool {
+        match self {
+            PopupMenuItem::Item {
+                label, description, ..
+            } => {
+                label.to_lowercase().contains(query)
+                    || description
+                        .as_ref()
+                        .map_or(false, |d| d.to_lowercase().contains(query))
+            }
+            PopupMenuItem::Submenu { label, .. } => label.to_lowercase().contains(query),
+            _ => false,
+        }
+    }
+
+    /// Returns the label of the item for the type-ahead selection.
+    fn label(&self) -> Option<&SharedString> {
+        match self {
+            PopupMenuItem::Item { label, .. } | PopupMenuItem::Submenu { label, .. } => Some(label),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn is_clickable(&self) -> bool {
         !matches!(self, PopupMenuItem::Separator)
@@ -128,8 +246,14 @@ pub struct PopupMenu {
     parent_menu: Option<WeakEntity<Self>>,
     scrollable: bool,
     external_link_icon: bool,
+    /// The filter input of the searchable menu.
+    search_input: Option<Entity<InputState>>,
+    /// The lowercase query to filter the items.
+    query: SharedString,
     scroll_handle: ScrollHandle,
     scroll_state: ScrollbarState,
+    wrap_around: bool,
+    type_ahead: TypeAhead,
     // This will update on render
     submenu_anchor: (Corner, Pixels),
 
@@ -152,7 +276,11 @@ impl PopupMenu {
             scrollable: false,
             scroll_handle: ScrollHandle::default(),
             scroll_state: ScrollbarState::default(),
+            wrap_around: true,
+            type_ahead: TypeAhead::default(),
             external_link_icon: true,
+            search_input: None,
+            query: SharedString::default(),
             size: Size::default(),
             submenu_anchor: (Corner::TopLeft, Pixels::ZERO),
             _subscriptions: vec![],
@@ -207,6 +335,25 @@ impl PopupMenu {
         self
     }
 
+    /// Show a filter input at the top of the menu, type to filter the items by label.
+    ///
+    /// This is useful for the long menus, the separators and labels are hidden when filtering.
+    pub fn searchable(mut self, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx
+            .new(|cx| InputState::new(window, cx).placeholder(t!("PopupMenu.search_placeholder")));
+        self._subscriptions
+            .push(cx.subscribe_in(&input, window, Self::on_search_input_event));
+        self.search_input = Some(input);
+        self
+    }
+
+    /// Set whether to select the first item after the last item (and vice versa)
+    /// by the up and down keys, default is true.
+    pub fn wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
     /// Set the menu to show external link icon, default is true.
     pub fn external_link_icon(mut self, visible: bool) -> Self {
         self.external_link_icon = visible;
@@ -240,6 +387,15 @@ impl PopupMenu {
         self
     }
 
+    /// Add a Menu Item built by [`PopupMenuItem::new`].
+    pub fn item(mut self, item: PopupMenuItem) -> Self {
+        if item.has_icon() {
+            self.has_icon = true;
+        }
+        self.menu_items.push(item);
+        self
+    }
+
     /// Add label
     pub fn label(mut self, label: impl Into<SharedString>) -> Self {
         self.menu_items.push(PopupMenuItem::Label(label.into()));
@@ -262,7 +418,9 @@ impl PopupMenu {
         self.menu_items.push(PopupMenuItem::Item {
             icon: None,
             label: label.into(),
+            description: None,
             disabled,
+            danger: false,
             action: None,
             is_link: true,
             handler: Some(Rc::new(move |_, cx| cx.open_url(&href))),
@@ -292,7 +450,9 @@ impl PopupMenu {
         self.menu_items.push(PopupMenuItem::Item {
             icon: Some(icon.into()),
             label: label.into(),
+            description: None,
             disabled,
+            danger: false,
             action: None,
             is_link: true,
             handler: Some(Rc::new(move |_, cx| cx.open_url(&href))),
@@ -349,6 +509,33 @@ impl PopupMenu {
         self
     }
 
+    /// Add Menu Item with radio icon, use it for a group of mutually exclusive items.
+    pub fn menu_with_radio(
+        self,
+        label: impl Into<SharedString>,
+        checked: bool,
+        action: Box<dyn Action>,
+    ) -> Self {
+        self.menu_with_radio_and_disabled(label, checked, action, false)
+    }
+
+    /// Add Menu Item with radio icon and disabled state
+    pub fn menu_with_radio_and_disabled(
+        mut self,
+        label: impl Into<SharedString>,
+        checked: bool,
+        action: Box<dyn Action>,
+        disabled: bool,
+    ) -> Self {
+        if checked {
+            self.add_menu_item(label, Some(IconName::Dot.into()), action, disabled);
+        } else {
+            self.add_menu_item(label, None, action, disabled);
+        }
+
+        self
+    }
+
     /// Add Menu Item with custom element render.
     pub fn menu_element<F, E>(self, action: Box<dyn Action>, builder: F) -> Self
     where
@@ -550,7 +737,9 @@ impl PopupMenu {
         self.menu_items.push(PopupMenuItem::Item {
             icon,
             label: label.into(),
+            description: None,
             disabled,
+            danger: false,
             action: Some(action.boxed_clone()),
             is_link: false,
             handler: None,
@@ -612,7 +801,35 @@ impl PopupMenu {
         self.menu_items
             .iter()
             .enumerate()
-            .filter(|(_, item)| item.is_clickable())
+            .filter(|(_, item)| item.is_clickable() && self.is_visible(item))
+    }
+
+    /// Returns false if the item is filtered out by the search query.
+    #[inline]
+    fn is_visible(&self, item: &PopupMenuItem) -> bool {
+        self.query.is_empty() || item.matches(&self.query)
+    }
+
+    fn on_search_input_event(
+        &mut self,
+        state: &Entity<InputState>,
+        event: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::Change(_) = event {
+            let query = state.read(cx).value().trim().to_lowercase();
+            if query == self.query.as_ref() {
+                return;
+            }
+
+            self.query = query.into();
+            let first_ix = self.clickable_menu_items().next().map(|(ix, _)| ix);
+            self.selected_index = first_ix;
+            self.scroll_handle
+                .scroll_to_item(self.selected_index.unwrap_or(0));
+            cx.notify();
+        }
     }
 
     fn on_click(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
@@ -678,43 +895,87 @@ impl PopupMenu {
         }
     }
 
-    fn select_up(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
-        cx.stop_propagation();
-        let ix = self.selected_index.unwrap_or(0);
+    /// Select the item moved by the `step` from the `current`, skipping the unclickable items.
+    fn select_by_step(&mut self, current: Option<usize>, step: isize, cx: &mut Context<Self>) {
+        let ix = step_index(
+            self.menu_items.len(),
+            current,
+            step,
+            self.wrap_around,
+            |ix| {
+                let item = &self.menu_items[ix];
+                !item.is_clickable() || !self.is_visible(item)
+            },
+        );
+        if let Some(ix) = ix {
+            self.set_selected_index(ix, cx);
+        }
+    }
 
-        if let Some((prev_ix, _)) = self
-            .menu_items
-            .iter()
-            .enumerate()
-            .rev()
-            .find(|(i, item)| *i < ix && item.is_clickable())
-        {
-            self.set_selected_index(prev_ix, cx);
-            return;
+    /// Returns the number of the items in the visible area.
+    fn page_size(&self) -> usize {
+        let viewport_height = self.scroll_handle.bounds().size.height;
+        let item_height = self
+            .scroll_handle
+            .bounds_for_item(self.selected_index.unwrap_or(0))
+            .map_or(px(0.), |bounds| bounds.size.height);
+        if item_height <= px(0.) {
+            return 10;
         }
 
-        let last_clickable_ix = self.clickable_menu_items().last().map(|(ix, _)| ix);
-        self.set_selected_index(last_clickable_ix.unwrap_or(0), cx);
+        ((viewport_height / item_height).floor() as usize).max(1)
+    }
+
+    fn select_up(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        self.select_by_step(self.selected_index, -1, cx);
     }
 
     fn select_down(&mut self, _: &SelectNext, _: &mut Window, cx: &mut Context<Self>) {
         cx.stop_propagation();
-        let Some(ix) = self.selected_index else {
-            self.set_selected_index(0, cx);
+        self.select_by_step(self.selected_index, 1, cx);
+    }
+
+    fn select_first(&mut self, _: &SelectFirst, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        self.select_by_step(None, 1, cx);
+    }
+
+    fn select_last(&mut self, _: &SelectLast, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        self.select_by_step(None, -1, cx);
+    }
+
+    fn select_page_up(&mut self, _: &SelectPageUp, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        let step = self.page_size() as isize;
+        self.select_by_step(self.selected_index, -step, cx);
+    }
+
+    fn select_page_down(&mut self, _: &SelectPageDown, _: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        let step = self.page_size() as isize;
+        self.select_by_step(self.selected_index, step, cx);
+    }
+
+    /// Select the item by typing the prefix of the label, the searchable menu uses the input instead.
+    fn on_key_down(&mut self, event: &KeyDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(text) = self.type_ahead.typed_text(event) else {
             return;
         };
 
-        if let Some((next_ix, _)) = self
-            .menu_items
-            .iter()
-            .enumerate()
-            .find(|(i, item)| *i > ix && item.is_clickable())
-        {
-            self.set_selected_index(next_ix, cx);
-            return;
+        let query = self.type_ahead.push(text).to_string();
+        let ix = TypeAhead::find(&query, self.menu_items.len(), self.selected_index, |ix| {
+            let item = &self.menu_items[ix];
+            if !item.is_clickable() {
+                return None;
+            }
+            item.label().map(|label| label.to_string())
+        });
+        if let Some(ix) = ix {
+            cx.stop_propagation();
+            self.set_selected_index(ix, cx);
         }
-
-        self.set_selected_index(0, cx);
     }
 
     fn select_left(&mut self, _: &SelectLeft, window: &mut Window, cx: &mut Context<Self>) {
@@ -732,7 +993,7 @@ impl PopupMenu {
             return;
         }
 
-        // For parent AppMenuBar to handle.
+        // For parent MenuBar to handle.
         if self.parent_menu.is_none() {
             cx.propagate();
         }
@@ -753,7 +1014,7 @@ impl PopupMenu {
             return;
         }
 
-        // For parent AppMenuBar to handle.
+        // For parent MenuBar to handle.
         if self.parent_menu.is_none() {
             cx.propagate();
         }
@@ -897,13 +1158,20 @@ impl PopupMenu {
     }
 
     /// Calculate the anchor corner and left offset for child submenu
-    fn update_submenu_menu_anchor(&mut self, window: &Window) {
+    fn update_submenu_menu_anchor(&mut self, window: &Window, cx: &App) {
         let bounds = self.bounds;
         let max_width = self.max_width();
-        let (anchor, left) = if max_width + bounds.origin.x > window.bounds().size.width {
-            (Corner::TopRight, -px(16.))
-        } else {
-            (Corner::TopLeft, bounds.size.width - px(8.))
+        let resolved = Position::new(cx.theme().direction.placement(Placement::Right))
+            .gap(px(0.))
+            .margin(px(0.))
+            .resolve(
+                bounds,
+                size(max_width, bounds.size.height),
+                window.viewport_size(),
+            );
+        let (anchor, left) = match resolved.placement {
+            Placement::Left => (Corner::TopRight, -px(16.)),
+            _ => (Corner::TopLeft, bounds.size.width - px(8.)),
         };
 
         let is_bottom_pos = bounds.origin.y + bounds.size.height > window.bounds().size.height;
@@ -934,6 +1202,7 @@ impl PopupMenu {
             Size::Small => (px(20.), state.radius.half()),
             _ => (px(26.), state.radius),
         };
+        let item_height = (item_height * cx.theme().density.scale()).round();
 
         let this = MenuItemElement::new(ix, &group_name)
             .relative()
@@ -995,8 +1264,10 @@ impl PopupMenu {
             PopupMenuItem::Item {
                 icon,
                 label,
+                description,
                 action,
                 disabled,
+                danger,
                 is_link,
                 ..
             } => {
@@ -1010,7 +1281,13 @@ impl PopupMenu {
                     )
                 })
                 .disabled(*disabled)
-                .h(item_height)
+                .when(*danger && !*disabled, |this| {
+                    this.text_color(cx.theme().danger)
+                })
+                .map(|this| match description {
+                    Some(_) => this.min_h(item_height).py_1(),
+                    None => this.h(item_height),
+                })
                 .children(Self::render_icon(has_icon, icon.clone(), window, cx))
                 .child(
                     h_flex()
@@ -1018,14 +1295,25 @@ impl PopupMenu {
                         .gap_2()
                         .items_center()
                         .justify_between()
-                        .when(!show_link_icon, |this| this.child(label.clone()))
+                        .whitespace_nowrap()
+                        .when(!show_link_icon, |this| match description {
+                            Some(description) => this.child(
+                                v_flex().child(CachedText::new(label.clone())).child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(description.clone()),
+                                ),
+                            ),
+                            None => this.child(CachedText::new(label.clone())),
+                        })
                         .when(show_link_icon, |this| {
                             this.child(
                                 h_flex()
                                     .w_full()
                                     .justify_between()
                                     .gap_1p5()
-                                    .child(label.clone())
+                                    .child(CachedText::new(label.clone()))
                                     .child(
                                         Icon::new(IconName::ExternalLink)
                                             .xsmall()
@@ -1059,7 +1347,7 @@ impl PopupMenu {
                                 .items_center()
                                 .justify_between()
                                 .child(label.clone())
-                                .child(IconName::ChevronRight),
+                                .child(IconName::ChevronRight.mirrored(cx.theme().direction)),
                         ),
                 )
                 .when(selected, |this| {
@@ -1100,20 +1388,26 @@ struct ItemState {
 
 impl Render for PopupMenu {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        self.update_submenu_menu_anchor(window);
+        self.update_submenu_menu_anchor(window, cx);
+
+        // Move the focus to the search input to type the query.
+        if let Some(input) = self.search_input.as_ref() {
+            if self.focus_handle.is_focused(window) {
+                input.read(cx).focus_handle(cx).focus(window);
+            }
+        }
 
         let view = cx.entity().clone();
         let items_count = self.menu_items.len();
 
-        let max_height = self.max_height.map_or_else(
-            || {
-                let window_half_height = window.window_bounds().get_bounds().size.height * 0.5;
-                window_half_height.min(px(450.))
-            },
-            |height| height,
-        );
+        let max_height = self.max_height.unwrap_or_else(|| {
+            let window_half_height = window.window_bounds().get_bounds().size.height * 0.5;
+            window_half_height.min(px(450.))
+        });
 
         let max_width = self.max_width();
+        let has_visible_items =
+            self.query.is_empty() || self.clickable_menu_items().next().is_some();
         let item_state = ItemState {
             radius: cx.theme().radius.min(px(8.)),
         };
@@ -1124,10 +1418,26 @@ impl Render for PopupMenu {
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::select_up))
             .on_action(cx.listener(Self::select_down))
+            .on_action(cx.listener(Self::select_first))
+            .on_action(cx.listener(Self::select_last))
+            .on_action(cx.listener(Self::select_page_up))
+            .on_action(cx.listener(Self::select_page_down))
             .on_action(cx.listener(Self::select_left))
             .on_action(cx.listener(Self::select_right))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::dismiss))
+            .when(self.search_input.is_none(), |this| {
+                this.on_key_down(cx.listener(Self::on_key_down))
+            })
+            .when(self.search_input.is_some(), |this| {
+                // The search input takes the up and down keys, use them to select items instead.
+                this.capture_action(cx.listener(|this, _: &MoveUp, window, cx| {
+                    this.select_up(&SelectPrev, window, cx)
+                }))
+                .capture_action(cx.listener(|this, _: &MoveDown, window, cx| {
+                    this.select_down(&SelectNext, window, cx)
+                }))
+            })
             .on_mouse_down_out(cx.listener(|this, ev: &MouseDownEvent, window, cx| {
                 // Do not dismiss, if click inside the parent menu
                 if let Some(parent) = this.parent_menu.as_ref() {
@@ -1143,6 +1453,24 @@ impl Render for PopupMenu {
             .popover_style(cx)
             .text_color(cx.theme().popover_foreground)
             .relative()
+            .when_some(self.search_input.as_ref(), |this, input| {
+                this.child(
+                    div()
+                        .px_1()
+                        .pt_1()
+                        .child(TextInput::new(input).small().appearance(false).cleanable()),
+                )
+                .child(div().mt_1().h(px(1.)).bg(cx.theme().border))
+            })
+            .when(!has_visible_items, |this| {
+                this.child(
+                    div()
+                        .p_2()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(SharedString::from(t!("PopupMenu.no_results"))),
+                )
+            })
             .child(
                 v_flex()
                     .id("items")
@@ -1156,12 +1484,14 @@ impl Render for PopupMenu {
                             .overflow_y_scroll()
                             .track_scroll(&self.scroll_handle)
                     })
+                    .when(!has_visible_items, |this| this.hidden())
                     .children(
                         self.menu_items
                             .iter()
                             .enumerate()
                             // Ignore last separator
                             .filter(|(ix, item)| !(*ix + 1 == items_count && item.is_separator()))
+                            .filter(|(_, item)| self.is_visible(item))
                             .map(|(ix, item)| self.render_item(ix, item, item_state, window, cx)),
                     )
                     .child({
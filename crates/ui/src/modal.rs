@@ -1,18 +1,20 @@
-use std::{rc::Rc, time::Duration};
+use std::rc::Rc;
 
+use anyhow::Result;
 use gpui::{
-    anchored, div, hsla, point, prelude::FluentBuilder, px, relative, Animation, AnimationExt as _,
-    AnyElement, App, Axis, Bounds, BoxShadow, ClickEvent, Div, Edges, FocusHandle, Hsla,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    RenderOnce, SharedString, StyleRefinement, Styled, Window,
+    anchored, div, hsla, point, prelude::FluentBuilder, px, relative, AnyElement, App, Axis,
+    Bounds, BoxShadow, ClickEvent, Div, Edges, FocusHandle, Hsla, InteractiveElement, IntoElement,
+    KeyBinding, MouseButton, ParentElement, Pixels, Point, RenderOnce, SharedString,
+    StyleRefinement, Styled, Task, Window,
 };
 use rust_i18n::t;
 
 use crate::{
     actions::{Cancel, Confirm},
-    animation::cubic_bezier,
+    animation::{lerp, Transition, TransitionExt as _},
     button::{Button, ButtonVariant, ButtonVariants as _},
-    h_flex, v_flex, ActiveTheme as _, ContextModal, IconName, Root, Sizable as _, StyledExt,
+    h_flex, v_flex, ActiveTheme as _, ContextModal, FocusTrapElement as _, IconName, Root, Sizable,
+    Size, StyledExt,
 };
 
 const CONTEXT: &str = "Modal";
@@ -26,6 +28,7 @@ pub(crate) fn init(cx: &mut App) {
 type RenderButtonFn = Box<dyn FnOnce(&mut Window, &mut App) -> AnyElement>;
 type FooterFn =
     Box<dyn Fn(RenderButtonFn, RenderButtonFn, &mut Window, &mut App) -> Vec<AnyElement>>;
+type OkAsyncFn = Rc<dyn Fn(&mut Window, &mut App) -> Task<Result<()>> + 'static>;
 
 /// Modal button props.
 pub struct ModalButtonProps {
@@ -81,10 +84,13 @@ pub struct Modal {
     width: Pixels,
     max_width: Option<Pixels>,
     margin_top: Option<Pixels>,
+    full_screen: bool,
 
     on_close: Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>,
     on_ok: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) -> bool + 'static>>,
+    on_ok_async: Option<OkAsyncFn>,
     on_cancel: Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) -> bool + 'static>,
+    on_close_requested: Option<Rc<dyn Fn(&mut Window, &mut App) -> bool + 'static>>,
     button_props: ModalButtonProps,
     show_close: bool,
     overlay: bool,
@@ -95,6 +101,8 @@ pub struct Modal {
     pub(crate) focus_handle: FocusHandle,
     pub(crate) layer_ix: usize,
     pub(crate) overlay_visible: bool,
+    /// Is waiting for the [`Self::on_ok_async`], this is kept by the [`Root`].
+    pub(crate) loading: bool,
 }
 
 pub(crate) fn overlay_color(overlay: bool, cx: &App) -> Hsla {
@@ -116,13 +124,17 @@ impl Modal {
             margin_top: None,
             width: px(480.),
             max_width: None,
+            full_screen: false,
             overlay: true,
             keyboard: true,
             layer_ix: 0,
             overlay_visible: false,
+            loading: false,
             on_close: Rc::new(|_, _, _| {}),
             on_ok: None,
+            on_ok_async: None,
             on_cancel: Rc::new(|_, _, _| true),
+            on_close_requested: None,
             button_props: ModalButtonProps::default(),
             show_close: true,
             overlay_closable: true,
@@ -203,6 +215,21 @@ impl Modal {
         self
     }
 
+    /// Sets the async callback for when the modal is has been confirmed.
+    ///
+    /// The OK button will show a loading state until the task is finished,
+    /// the modal will be closed if the task is succeeded, otherwise it will keep open
+    /// to let the user retry (e.g.: show the error in the modal by yourself).
+    ///
+    /// This takes precedence over [`Self::on_ok`].
+    pub fn on_ok_async(
+        mut self,
+        on_ok: impl Fn(&mut Window, &mut App) -> Task<Result<()>> + 'static,
+    ) -> Self {
+        self.on_ok_async = Some(Rc::new(on_ok));
+        self
+    }
+
     /// Sets the callback for when the modal is has been canceled.
     ///
     /// The callback should return `true` to close the modal, if return `false` the modal will not be closed.
@@ -214,6 +241,19 @@ impl Modal {
         self
     }
 
+    /// Sets the guard for when the modal is going to be dismissed, by the close icon, Cancel button,
+    /// Escape key or clicking the overlay.
+    ///
+    /// The callback should return `false` to keep the modal open, e.g.: there are unsaved changes,
+    /// you can open a nested confirm modal to ask the user.
+    pub fn on_close_requested(
+        mut self,
+        on_close_requested: impl Fn(&mut Window, &mut App) -> bool + 'static,
+    ) -> Self {
+        self.on_close_requested = Some(Rc::new(on_close_requested));
+        self
+    }
+
     /// Sets the false to hide close icon, default: true
     pub fn show_close(mut self, show_close: bool) -> Self {
         self.show_close = show_close;
@@ -226,7 +266,15 @@ impl Modal {
         self
     }
 
+    /// Set to full screen, the modal will fill the window with a small margin, defaults to `false`.
+    pub fn full_screen(mut self, full_screen: bool) -> Self {
+        self.full_screen = full_screen;
+        self
+    }
+
     /// Sets the width of the modal, defaults to 480px.
+    ///
+    /// See also [`Sizable`] for the preset sizes.
     pub fn width(mut self, width: Pixels) -> Self {
         self.width = width;
         self
@@ -263,6 +311,25 @@ impl Modal {
     }
 }
 
+impl Sizable for Modal {
+    /// Set the width of the modal by the preset size:
+    ///
+    /// - `xsmall`: 320px
+    /// - `small`: 400px
+    /// - `medium`: 480px (default)
+    /// - `large`: 640px
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.width = match size.into() {
+            Size::XSmall => px(320.),
+            Size::Small => px(400.),
+            Size::Medium => px(480.),
+            Size::Large => px(640.),
+            Size::Size(width) => width,
+        };
+        self
+    }
+}
+
 impl ParentElement for Modal {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.content.extend(elements);
@@ -278,13 +345,83 @@ impl Styled for Modal {
 impl RenderOnce for Modal {
     fn render(self, window: &mut Window, cx: &mut App) -> impl gpui::IntoElement {
         let layer_ix = self.layer_ix;
+        let loading = self.loading;
         let on_close = self.on_close.clone();
         let on_ok = self.on_ok.clone();
+        let on_ok_async = self.on_ok_async.clone();
         let on_cancel = self.on_cancel.clone();
 
-        let render_ok: RenderButtonFn = Box::new({
-            let on_ok = on_ok.clone();
+        // Dismiss the modal by the close icon, Cancel button, Escape key or clicking the overlay.
+        //
+        // The `on_cancel` result is only respected by the Cancel button.
+        let dismiss: Rc<dyn Fn(bool, &mut Window, &mut App)> = Rc::new({
+            let on_cancel = on_cancel.clone();
+            let on_close = on_close.clone();
+            let on_close_requested = self.on_close_requested.clone();
+            move |respect_cancel, window, cx| {
+                if let Some(on_close_requested) = &on_close_requested {
+                    if !on_close_requested(window, cx) {
+                        return;
+                    }
+                }
+
+                if !on_cancel(&ClickEvent::default(), window, cx) && respect_cancel {
+                    return;
+                }
+
+                on_close(&ClickEvent::default(), window, cx);
+                window.close_modal(cx);
+            }
+        });
+
+        let confirm: Rc<dyn Fn(&mut Window, &mut App)> = Rc::new({
             let on_close = on_close.clone();
+            let focus_handle = self.focus_handle.clone();
+            move |window, cx| {
+                if loading {
+                    return;
+                }
+
+                if let Some(on_ok_async) = &on_ok_async {
+                    let task = on_ok_async(window, cx);
+                    Root::update(window, cx, |root, _, cx| {
+                        root.set_modal_loading(&focus_handle, true, cx)
+                    });
+
+                    let focus_handle = focus_handle.clone();
+                    let on_close = on_close.clone();
+                    window
+                        .spawn(cx, async move |cx| {
+                            let result = task.await;
+                            _ = cx.update(|window, cx| {
+                                let closed = Root::update(window, cx, |root, window, cx| {
+                                    root.set_modal_loading(&focus_handle, false, cx);
+                                    result.is_ok()
+                                        && root.close_modal_with(&focus_handle, window, cx)
+                                });
+
+                                if closed {
+                                    on_close(&ClickEvent::default(), window, cx);
+                                }
+                            });
+                        })
+                        .detach();
+                    return;
+                }
+
+                if let Some(on_ok) = &on_ok {
+                    if !on_ok(&ClickEvent::default(), window, cx) {
+                        return;
+                    }
+                }
+
+                on_close(&ClickEvent::default(), window, cx);
+                window.close_modal(cx);
+            }
+        });
+
+        let render_ok: RenderButtonFn = Box::new({
+            let confirm = confirm.clone();
             let ok_text = self
                 .button_props
                 .ok_text
@@ -294,27 +431,13 @@ impl RenderOnce for Modal {
                 Button::new("ok")
                     .label(ok_text)
                     .with_variant(ok_variant)
-                    .on_click({
-                        let on_ok = on_ok.clone();
-                        let on_close = on_close.clone();
-
-                        move |_, window, cx| {
-                            if let Some(on_ok) = &on_ok {
-                                if !on_ok(&ClickEvent::default(), window, cx) {
-                                    return;
-                                }
-                            }
-
-                            on_close(&ClickEvent::default(), window, cx);
-                            window.close_modal(cx);
-                        }
-                    })
+                    .loading(loading)
+                    .on_click(move |_, window, cx| confirm(window, cx))
                     .into_any_element()
             }
         });
         let render_cancel: RenderButtonFn = Box::new({
-            let on_cancel = on_cancel.clone();
-            let on_close = on_close.clone();
+            let dismiss = dismiss.clone();
             let cancel_text = self
                 .button_props
                 .cancel_text
@@ -324,18 +447,7 @@ impl RenderOnce for Modal {
                 Button::new("cancel")
                     .label(cancel_text)
                     .with_variant(cancel_variant)
-                    .on_click({
-                        let on_cancel = on_cancel.clone();
-                        let on_close = on_close.clone();
-                        move |_, window, cx| {
-                            if !on_cancel(&ClickEvent::default(), window, cx) {
-                                return;
-                            }
-
-                            on_close(&ClickEvent::default(), window, cx);
-                            window.close_modal(cx);
-                        }
-                    })
+                    .on_click(move |_, window, cx| dismiss(true, window, cx))
                     .into_any_element()
             }
         });
@@ -351,8 +463,16 @@ impl RenderOnce for Modal {
             size: view_size,
         };
         let offset_top = px(layer_ix as f32 * 16.);
-        let y = self.margin_top.unwrap_or(view_size.height / 10.) + offset_top;
-        let x = bounds.center().x - self.width / 2.;
+        let (width, x, y) = if self.full_screen {
+            let margin = px(16.);
+            (view_size.width - margin * 2., margin, margin)
+        } else {
+            (
+                self.width,
+                bounds.center().x - self.width / 2.,
+                self.margin_top.unwrap_or(view_size.height / 10.) + offset_top,
+            )
+        };
 
         let base_size = window.text_style().font_size;
         let rem_size = window.rem_size();
@@ -371,8 +491,7 @@ impl RenderOnce for Modal {
             paddings.bottom = pb.to_pixels(base_size, rem_size);
         }
 
-        let animation = Animation::new(Duration::from_secs_f64(0.25))
-            .with_easing(cubic_bezier(0.32, 0.72, 0., 1.));
+        let transition = Transition::EMPHASIZED;
 
         anchored()
             .position(point(window_paddings.left, window_paddings.top))
@@ -392,13 +511,8 @@ impl RenderOnce for Modal {
                         }
 
                         this.on_mouse_down(MouseButton::Left, {
-                            let on_cancel = on_cancel.clone();
-                            let on_close = on_close.clone();
-                            move |_, window, cx| {
-                                on_cancel(&ClickEvent::default(), window, cx);
-                                on_close(&ClickEvent::default(), window, cx);
-                                window.close_modal(cx);
-                            }
+                            let dismiss = dismiss.clone();
+                            move |_, window, cx| dismiss(false, window, cx)
                         })
                     })
                     .child(
@@ -417,32 +531,26 @@ impl RenderOnce for Modal {
                             .key_context(CONTEXT)
                             .track_focus(&self.focus_handle)
                             .tab_group()
+                            .focus_trap(&self.focus_handle)
                             .when(self.keyboard, |this| {
                                 this.on_action({
-                                    let on_cancel = on_cancel.clone();
-                                    let on_close = on_close.clone();
+                                    let dismiss = dismiss.clone();
                                     move |_: &Cancel, window, cx| {
                                         // FIXME:
                                         //
                                         // Here some Modal have no focus_handle, so it will not work will Escape key.
                                         // But by now, we `cx.close_modal()` going to close the last active model, so the Escape is unexpected to work.
-                                        on_cancel(&ClickEvent::default(), window, cx);
-                                        on_close(&ClickEvent::default(), window, cx);
-                                        window.close_modal(cx);
+                                        dismiss(false, window, cx);
                                     }
                                 })
                                 .on_action({
-                                    let on_ok = on_ok.clone();
-                                    let on_close = on_close.clone();
-                                    let has_footer = self.footer.is_some();
+                                    let confirm = confirm.clone();
+                                    let can_confirm = self.on_ok.is_some()
+                                        || self.on_ok_async.is_some()
+                                        || self.footer.is_some();
                                     move |_: &Confirm, window, cx| {
-                                        if let Some(on_ok) = &on_ok {
-                                            if on_ok(&ClickEvent::default(), window, cx) {
-                                                on_close(&ClickEvent::default(), window, cx);
-                                                window.close_modal(cx);
-                                            }
-                                        } else if has_footer {
-                                            window.close_modal(cx);
+                                        if can_confirm {
+                                            confirm(window, cx);
                                         }
                                     }
                                 })
@@ -453,7 +561,8 @@ impl RenderOnce for Modal {
                             .relative()
                             .left(x)
                             .top(y)
-                            .w(self.width)
+                            .w(width)
+                            .when(self.full_screen, |this| this.h(view_size.height - y * 2.))
                             .when_some(self.max_width, |this, w| this.max_w(w))
                             .when_some(self.title, |this, title| {
                                 this.child(
@@ -474,13 +583,8 @@ impl RenderOnce for Modal {
                                     .ghost()
                                     .icon(IconName::Close)
                                     .on_click({
-                                        let on_cancel = self.on_cancel.clone();
-                                        let on_close = self.on_close.clone();
-                                        move |_, window, cx| {
-                                            on_cancel(&ClickEvent::default(), window, cx);
-                                            on_close(&ClickEvent::default(), window, cx);
-                                            window.close_modal(cx);
-                                        }
+                                        let dismiss = dismiss.clone();
+                                        move |_, window, cx| dismiss(false, window, cx)
                                     })
                             }))
                             .child(
@@ -503,8 +607,11 @@ impl RenderOnce for Modal {
                                         .children(footer(render_ok, render_cancel, window, cx)),
                                 )
                             })
-                            .with_animation("slide-down", animation.clone(), move |this, delta| {
+                            .with_transition("slide-down", transition, cx, move |this, delta| {
                                 let y_offset = px(0.) + delta * px(30.);
+                                // Scale up from 95% of the width, and keep it centered.
+                                let scaled_width = width * lerp(0.95, 1., delta);
+                                let x_offset = (width - scaled_width) / 2.;
                                 // This is equivalent to `shadow_xl` with an extra opacity.
                                 let shadow = vec![
                                     BoxShadow {
@@ -520,10 +627,15 @@ impl RenderOnce for Modal {
                                         spread_radius: px(-6.),
                                     },
                                 ];
-                                this.top(y + y_offset).shadow(shadow)
+                                this.left(x + x_offset)
+                                    .top(y + y_offset)
+                                    .w(scaled_width)
+                                    .shadow(shadow)
                             }),
                     )
-                    .with_animation("fade-in", animation, move |this, delta| this.opacity(delta)),
+                    .with_transition("fade-in", transition, cx, move |this, delta| {
+                        this.opacity(delta)
+                    }),
             )
     }
 }
@@ -6,15 +6,16 @@ use std::{
 };
 
 use gpui::{
-    div, prelude::FluentBuilder, px, Animation, AnimationExt, AnyElement, App, AppContext,
-    ClickEvent, Context, DismissEvent, ElementId, Entity, EventEmitter, InteractiveElement as _,
-    IntoElement, ParentElement as _, Render, SharedString, StatefulInteractiveElement,
-    StyleRefinement, Styled, Subscription, Window,
+    div, prelude::FluentBuilder, px, AnyElement, App, AppContext, ClickEvent, Context,
+    DismissEvent, ElementId, Entity, EventEmitter, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement, StyleRefinement, Styled,
+    Subscription, Window,
 };
 use smol::Timer;
 
 use crate::{
-    animation::cubic_bezier,
+    accessibility::{Accessibility, Politeness},
+    animation::{Easing, Transition, TransitionExt as _},
     button::{Button, ButtonVariants as _},
     h_flex, v_flex, ActiveTheme as _, Icon, IconName, Sizable as _, StyledExt,
 };
@@ -228,9 +229,9 @@ impl Notification {
         self.closing = true;
         cx.notify();
 
-        // Dismiss the notification after 0.15s to show the animation.
+        // Dismiss the notification after the exit transition.
         cx.spawn(async move |view, cx| {
-            Timer::after(Duration::from_secs_f32(0.15)).await;
+            Timer::after(Transition::FAST.duration).await;
             cx.update(|cx| {
                 if let Some(view) = view.upgrade() {
                     view.update(cx, |view, cx| {
@@ -243,6 +244,25 @@ impl Notification {
         .detach()
     }
 
+    /// Announce the title and message to the screen reader, the error is announced immediately.
+    fn announce(&self, window: &mut Window, cx: &mut App) {
+        let message = [self.title.as_ref(), self.message.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|s| s.as_ref())
+            .collect::<Vec<&str>>()
+            .join(", ");
+        if message.is_empty() {
+            return;
+        }
+
+        let politeness = match self.type_ {
+            Some(NotificationType::Error) => Politeness::Assertive,
+            _ => Politeness::Polite,
+        };
+        Accessibility::announce(message, politeness, window, cx);
+    }
+
     /// Set the content of the notification.
     pub fn content(
         mut self,
@@ -325,27 +345,31 @@ impl Render for Notification {
                             .on_click(cx.listener(|this, _, window, cx| this.dismiss(window, cx))),
                     ),
             )
-            .with_animation(
-                ElementId::NamedInteger("slide-down".into(), closing as u64),
-                Animation::new(Duration::from_secs_f64(0.25))
-                    .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
-                move |this, delta| {
-                    if closing {
-                        let x_offset = px(0.) + delta * px(45.);
-                        let opacity = 1. - delta;
-                        this.left(px(0.) + x_offset)
-                            .shadow_none()
-                            .opacity(opacity)
-                            .when(opacity < 0.85, |this| this.shadow_none())
-                    } else {
-                        let y_offset = px(-45.) + delta * px(45.);
-                        let opacity = delta;
-                        this.top(px(0.) + y_offset)
-                            .opacity(opacity)
-                            .when(opacity < 0.85, |this| this.shadow_none())
-                    }
-                },
-            )
+            .map(|this| {
+                if closing {
+                    this.with_exit_transition(
+                        ElementId::NamedInteger("slide-down".into(), closing as u64),
+                        Transition::FAST.easing(Easing::Accelerate),
+                        cx,
+                        move |this, delta| {
+                            let x_offset = px(45.) - delta * px(45.);
+                            this.left(px(0.) + x_offset).shadow_none().opacity(delta)
+                        },
+                    )
+                } else {
+                    this.with_transition(
+                        ElementId::NamedInteger("slide-down".into(), closing as u64),
+                        Transition::NORMAL,
+                        cx,
+                        move |this, delta| {
+                            let y_offset = px(-45.) + delta * px(45.);
+                            this.top(px(0.) + y_offset)
+                                .opacity(delta)
+                                .when(delta < 0.85, |this| this.shadow_none())
+                        },
+                    )
+                }
+            })
     }
 }
 
@@ -375,6 +399,7 @@ impl NotificationList {
         let notification = notification.into();
         let id = notification.id.clone();
         let autohide = notification.autohide;
+        notification.announce(window, cx);
 
         // Remove the notification by id, for keep unique.
         self.notifications.retain(|note| note.read(cx).id != id);
@@ -0,0 +1,248 @@
+//! The public top layer API to mount the floating elements above everything,
+//! e.g.: onboarding coach marks, custom pickers.
+//!
+//! ```ignore
+//! use gpui_component::{
+//!     overlay::{DismissPolicy, Overlay, OverlayAnchor},
+//!     position::Position,
+//!     ContextModal as _,
+//! };
+//!
+//! // Track the bounds of the target element in render.
+//! div().relative().child("Target").child(self.anchor.track());
+//!
+//! let id = window.open_overlay(
+//!     Overlay::new(|_, _| div().p_2().child("Click here to start"))
+//!         .anchor(&self.anchor, Position::new(Placement::Bottom))
+//!         .dismiss(DismissPolicy::OutsideClick),
+//!     cx,
+//! );
+//! ```
+//!
+//! The overlays are rendered by [`crate::Root::render_overlay_layer`].
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    anchored, canvas, deferred, div, prelude::FluentBuilder as _, AnyElement, App, Bounds, Corner,
+    InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Point, Styled as _, Window,
+};
+
+use crate::{
+    position::{Position, Positioned},
+    ContextModal as _,
+};
+
+/// The deferred priority of the overlays, above the built-in popovers and tooltips.
+const OVERLAY_PRIORITY: usize = 3;
+
+/// The id of an overlay opened by [`crate::ContextModal::open_overlay`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct OverlayId(pub(crate) usize);
+
+/// A handle to track the bounds of a target element to anchor the overlays.
+#[derive(Clone, Default)]
+pub struct OverlayAnchor {
+    bounds: Rc<Cell<Bounds<Pixels>>>,
+}
+
+impl OverlayAnchor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last painted bounds of the target element in window coordinates.
+    pub fn bounds(&self) -> Bounds<Pixels> {
+        self.bounds.get()
+    }
+
+    /// Returns an element to track the bounds, it must be added into the `relative` target element.
+    pub fn track(&self) -> impl IntoElement {
+        let bounds = self.bounds.clone();
+        canvas(
+            move |new_bounds, _, _| bounds.set(new_bounds),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full()
+    }
+}
+
+/// The policy to dismiss an overlay by clicking outside of it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DismissPolicy {
+    /// Only closed by [`crate::ContextModal::close_overlay`].
+    Manual,
+    /// Dismiss when mouse down outside of the overlay.
+    #[default]
+    OutsideClick,
+    /// Like `OutsideClick`, but keep the overlay when clicking the anchor element,
+    /// useful for a toggle button to handle the click by itself.
+    OutsideClickExceptAnchor,
+}
+
+impl DismissPolicy {
+    fn should_dismiss(&self, position: Point<Pixels>, anchor: Option<Bounds<Pixels>>) -> bool {
+        match self {
+            Self::Manual => false,
+            Self::OutsideClick => true,
+            Self::OutsideClickExceptAnchor => {
+                !anchor.is_some_and(|bounds| bounds.contains(&position))
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+enum OverlayPlacement {
+    Center,
+    Cover,
+    Point(Point<Pixels>, Corner),
+    Anchor(OverlayAnchor, Position),
+}
+
+/// A floating element mounted into the top layer by [`crate::ContextModal::open_overlay`].
+#[derive(Clone)]
+pub struct Overlay {
+    content: Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>,
+    z_index: usize,
+    placement: OverlayPlacement,
+    dismiss: DismissPolicy,
+    on_dismiss: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+}
+
+impl Overlay {
+    /// Create an overlay at the center of the window, the `content` is rebuilt in every render.
+    pub fn new<E: IntoElement>(content: impl Fn(&mut Window, &mut App) -> E + 'static) -> Self {
+        Self {
+            content: Rc::new(move |window, cx| content(window, cx).into_any_element()),
+            z_index: 0,
+            placement: OverlayPlacement::Center,
+            dismiss: DismissPolicy::default(),
+            on_dismiss: None,
+        }
+    }
+
+    /// Set the z-index, the overlay with the higher z-index is painted above, default is 0.
+    ///
+    /// The overlays with the same z-index are painted in the opened order.
+    pub fn z_index(mut self, z_index: usize) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Anchor to the bounds of the target element, placed by the [`Position`] engine,
+    /// so it flips and shifts to keep inside the window.
+    pub fn anchor(mut self, anchor: &OverlayAnchor, position: Position) -> Self {
+        self.placement = OverlayPlacement::Anchor(anchor.clone(), position);
+        self
+    }
+
+    /// Cover the whole window, e.g.: a spotlight mask.
+    pub fn cover(mut self) -> Self {
+        self.placement = OverlayPlacement::Cover;
+        self
+    }
+
+    /// Place the `corner` of the overlay at the `position` in window coordinates.
+    pub fn position(mut self, position: Point<Pixels>, corner: Corner) -> Self {
+        self.placement = OverlayPlacement::Point(position, corner);
+        self
+    }
+
+    /// Set the outside click dismissal policy, default is [`DismissPolicy::OutsideClick`].
+    pub fn dismiss(mut self, dismiss: DismissPolicy) -> Self {
+        self.dismiss = dismiss;
+        self
+    }
+
+    /// Set a callback called after the overlay is dismissed by clicking outside.
+    pub fn on_dismiss(mut self, f: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_dismiss = Some(Rc::new(f));
+        self
+    }
+
+    pub(crate) fn z_index_value(&self) -> usize {
+        self.z_index
+    }
+
+    pub(crate) fn render(&self, id: OverlayId, window: &mut Window, cx: &mut App) -> AnyElement {
+        let anchor_bounds = match &self.placement {
+            OverlayPlacement::Anchor(anchor, _) => Some(anchor.bounds()),
+            _ => None,
+        };
+        let dismiss = self.dismiss;
+        let on_dismiss = self.on_dismiss.clone();
+
+        let cover = matches!(self.placement, OverlayPlacement::Cover);
+        let content = div()
+            .when(!cover, |this| this.occlude())
+            .when(cover, |this| this.size_full())
+            .child((self.content)(window, cx))
+            .on_mouse_down_out(move |ev, window, cx| {
+                if !dismiss.should_dismiss(ev.position, anchor_bounds) {
+                    return;
+                }
+
+                window.close_overlay(id, cx);
+                if let Some(on_dismiss) = on_dismiss.as_ref() {
+                    on_dismiss(window, cx);
+                }
+            });
+
+        let el = match &self.placement {
+            OverlayPlacement::Center => div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(content)
+                .into_any_element(),
+            OverlayPlacement::Cover => div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .child(content)
+                .into_any_element(),
+            OverlayPlacement::Point(position, corner) => anchored()
+                .position(*position)
+                .anchor(*corner)
+                .snap_to_window()
+                .child(content)
+                .into_any_element(),
+            OverlayPlacement::Anchor(anchor, position) => {
+                Positioned::new(("overlay", id.0), anchor.bounds())
+                    .position(*position)
+                    .child(content)
+                    .into_any_element()
+            }
+        };
+
+        deferred(el)
+            .with_priority(OVERLAY_PRIORITY + self.z_index)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::DismissPolicy;
+
+    #[test]
+    fn test_dismiss_policy() {
+        let anchor = Bounds::new(point(px(10.), px(10.)), size(px(20.), px(20.)));
+        let inside = point(px(15.), px(15.));
+        let outside = point(px(50.), px(50.));
+
+        assert!(!DismissPolicy::Manual.should_dismiss(outside, Some(anchor)));
+        assert!(DismissPolicy::OutsideClick.should_dismiss(inside, Some(anchor)));
+        assert!(!DismissPolicy::OutsideClickExceptAnchor.should_dismiss(inside, Some(anchor)));
+        assert!(DismissPolicy::OutsideClickExceptAnchor.should_dismiss(outside, Some(anchor)));
+        assert!(DismissPolicy::OutsideClickExceptAnchor.should_dismiss(inside, None));
+    }
+}
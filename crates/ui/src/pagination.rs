@@ -0,0 +1,468 @@
+use std::ops::Range;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, App, AppContext, Context, ElementId, Entity,
+    EventEmitter, InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    StyleRefinement, Styled, Subscription, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    dropdown::{Dropdown, DropdownEvent, DropdownItem, DropdownState},
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    locale, ActiveTheme as _, DirectionExt as _, Disableable as _, IconName, IndexPath,
+    Selectable as _, Sizable, Size, StyledExt as _,
+};
+
+/// The default page sizes of the page size dropdown.
+const DEFAULT_PAGE_SIZES: [usize; 4] = [10, 20, 50, 100];
+
+/// An item of the page size dropdown.
+#[derive(Clone)]
+struct PageSizeItem {
+    size: usize,
+    title: SharedString,
+}
+
+impl PageSizeItem {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            title: t!("Pagination.page_size", size = size).into(),
+        }
+    }
+}
+
+impl DropdownItem for PageSizeItem {
+    type Value = usize;
+
+    fn title(&self) -> SharedString {
+        self.title.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.size
+    }
+}
+
+pub enum PaginationEvent {
+    /// The current page is changed, the value is the page index (0-based).
+    PageChanged(usize),
+    /// The page size is changed.
+    PageSizeChanged(usize),
+}
+
+/// The item of the page numbers in the [`Pagination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageItem {
+    /// The page index (0-based).
+    Page(usize),
+    Ellipsis,
+}
+
+/// Returns the page numbers to display, always keep the first and last pages,
+/// and the `siblings` pages around the current page, the rest are folded to ellipsis.
+fn page_items(current: usize, page_count: usize, siblings: usize) -> Vec<PageItem> {
+    // first, last, current, siblings on both sides and two ellipsis.
+    let max_items = siblings * 2 + 5;
+    if page_count <= max_items {
+        return (0..page_count).map(PageItem::Page).collect();
+    }
+
+    let last = page_count - 1;
+    let start = current
+        .saturating_sub(siblings)
+        .clamp(2, last - 2 - siblings * 2);
+    let end = (start + siblings * 2).min(last - 2);
+
+    let mut items = vec![PageItem::Page(0)];
+    if start == 2 {
+        items.push(PageItem::Page(1));
+    } else {
+        items.push(PageItem::Ellipsis);
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    if end == last - 2 {
+        items.push(PageItem::Page(last - 1));
+    } else {
+        items.push(PageItem::Ellipsis);
+    }
+    items.push(PageItem::Page(last));
+    items
+}
+
+/// State of the [`Pagination`].
+pub struct PaginationState {
+    /// The current page index (0-based).
+    page: usize,
+    page_size: usize,
+    page_sizes: Vec<usize>,
+    /// The total number of items of all pages.
+    total: usize,
+    jump_input: Entity<InputState>,
+    page_size_dropdown: Entity<DropdownState<Vec<PageSizeItem>>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl EventEmitter<PaginationEvent> for PaginationState {}
+
+impl PaginationState {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let page_size = DEFAULT_PAGE_SIZES[1];
+        let jump_input = cx.new(|cx| {
+            InputState::new(window, cx).validate(|text, _| text.chars().all(|c| c.is_ascii_digit()))
+        });
+        let page_size_dropdown =
+            Self::build_page_size_dropdown(&DEFAULT_PAGE_SIZES, page_size, window, cx);
+
+        let _subscriptions = vec![
+            cx.subscribe_in(&jump_input, window, Self::on_jump_input_event),
+            cx.subscribe_in(&page_size_dropdown, window, Self::on_page_size_event),
+        ];
+
+        Self {
+            page: 0,
+            page_size,
+            page_sizes: DEFAULT_PAGE_SIZES.to_vec(),
+            total: 0,
+            jump_input,
+            page_size_dropdown,
+            _subscriptions,
+        }
+    }
+
+    fn build_page_size_dropdown(
+        page_sizes: &[usize],
+        page_size: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<DropdownState<Vec<PageSizeItem>>> {
+        let items = page_sizes
+            .iter()
+            .map(|size| PageSizeItem::new(*size))
+            .collect::<Vec<_>>();
+        let selected_index = page_sizes
+            .iter()
+            .position(|size| *size == page_size)
+            .map(|ix| IndexPath::default().row(ix));
+        cx.new(|cx| DropdownState::new(items, selected_index, window, cx))
+    }
+
+    fn on_jump_input_event(
+        &mut self,
+        state: &Entity<InputState>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let InputEvent::PressEnter { .. } = event {
+            if let Ok(page) = state.read(cx).value().parse::<usize>() {
+                self.set_page(page.saturating_sub(1), window, cx);
+            }
+            state.update(cx, |state, cx| state.set_value("", window, cx));
+        }
+    }
+
+    fn on_page_size_event(
+        &mut self,
+        _: &Entity<DropdownState<Vec<PageSizeItem>>>,
+        event: &DropdownEvent<Vec<PageSizeItem>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let DropdownEvent::Confirm(Some(page_size)) = event else {
+            return;
+        };
+        self.set_page_size(*page_size, window, cx);
+    }
+
+    /// Set the total number of items of all pages.
+    pub fn total(mut self, total: usize) -> Self {
+        self.total = total;
+        self
+    }
+
+    /// Returns the current page index (0-based).
+    pub fn current_page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of items per page.
+    pub fn current_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the total number of items of all pages.
+    pub fn total_items(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of pages, at least 1.
+    pub fn page_count(&self) -> usize {
+        self.total.div_ceil(self.page_size).max(1)
+    }
+
+    /// Returns the range of the items in the current page.
+    pub fn items_range(&self) -> Range<usize> {
+        let start = (self.page * self.page_size).min(self.total);
+        start..(start + self.page_size).min(self.total)
+    }
+
+    /// Set the current page index (0-based), the page is clamped to the page count.
+    pub fn set_page(&mut self, page: usize, _: &mut Window, cx: &mut Context<Self>) {
+        let page = page.min(self.page_count() - 1);
+        if self.page == page {
+            return;
+        }
+
+        self.page = page;
+        cx.emit(PaginationEvent::PageChanged(page));
+        cx.notify();
+    }
+
+    /// Set the page size, default is 20.
+    ///
+    /// The first item of the current page is kept in the new page.
+    pub fn set_page_size(&mut self, page_size: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let page_size = page_size.max(1);
+        if self.page_size == page_size {
+            return;
+        }
+
+        let first_item = self.page * self.page_size;
+        self.page_size = page_size;
+        self.page = (first_item / page_size).min(self.page_count() - 1);
+        self.page_size_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_selected_value(&page_size, window, cx);
+        });
+        cx.emit(PaginationEvent::PageSizeChanged(page_size));
+        cx.emit(PaginationEvent::PageChanged(self.page));
+        cx.notify();
+    }
+
+    /// Set the options of the page size dropdown, default is `[10, 20, 50, 100]`.
+    pub fn set_page_sizes(
+        &mut self,
+        page_sizes: impl Into<Vec<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.page_sizes = page_sizes.into();
+        self.page_size_dropdown =
+            Self::build_page_size_dropdown(&self.page_sizes, self.page_size, window, cx);
+        self._subscriptions[1] =
+            cx.subscribe_in(&self.page_size_dropdown, window, Self::on_page_size_event);
+        cx.notify();
+    }
+
+    /// Set the total number of items, the current page is clamped to the new page count.
+    pub fn set_total(&mut self, total: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if self.total == total {
+            return;
+        }
+
+        self.total = total;
+        let page = self.page.min(self.page_count() - 1);
+        if page != self.page {
+            self.page = page;
+            cx.emit(PaginationEvent::PageChanged(page));
+        }
+        cx.notify();
+    }
+}
+
+/// A pagination bar with page numbers, prev/next buttons, page size dropdown and jump to page input.
+#[derive(IntoElement)]
+pub struct Pagination {
+    id: ElementId,
+    style: StyleRefinement,
+    state: Entity<PaginationState>,
+    size: Size,
+    siblings: usize,
+    show_total: bool,
+    page_size_selector: bool,
+    jumper: bool,
+}
+
+impl Pagination {
+    pub fn new(state: &Entity<PaginationState>) -> Self {
+        Self {
+            id: ("pagination", state.entity_id()).into(),
+            style: StyleRefinement::default(),
+            state: state.clone(),
+            size: Size::default(),
+            siblings: 1,
+            show_total: true,
+            page_size_selector: true,
+            jumper: true,
+        }
+    }
+
+    /// Set the number of pages to show on each side of the current page, default is 1.
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Set to show the total number of items, default is true.
+    pub fn show_total(mut self, show_total: bool) -> Self {
+        self.show_total = show_total;
+        self
+    }
+
+    /// Set to show the page size dropdown, default is true.
+    pub fn page_size_selector(mut self, page_size_selector: bool) -> Self {
+        self.page_size_selector = page_size_selector;
+        self
+    }
+
+    /// Set to show the input to jump to a page, default is true.
+    pub fn jumper(mut self, jumper: bool) -> Self {
+        self.jumper = jumper;
+        self
+    }
+}
+
+impl Sizable for Pagination {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Pagination {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Pagination {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let page = state.page;
+        let page_count = state.page_count();
+        let total = state.total;
+        let jump_input = state.jump_input.clone();
+        let page_size_dropdown = state.page_size_dropdown.clone();
+        let size = self.size;
+        let direction = cx.theme().direction;
+
+        let set_page = {
+            let state = self.state.clone();
+            move |page: usize, window: &mut Window, cx: &mut App| {
+                state.update(cx, |state, cx| state.set_page(page, window, cx));
+            }
+        };
+
+        h_flex()
+            .id(self.id)
+            .gap_1()
+            .items_center()
+            .direction(direction)
+            .refine_style(&self.style)
+            .when(self.show_total, |this| {
+                this.child(
+                    div()
+                        .mr_2()
+                        .direction(direction)
+                        .text_color(cx.theme().muted_foreground)
+                        .child(SharedString::from(t!(
+                            "Pagination.total",
+                            total = locale::format_number(total as f64, 0)
+                        ))),
+                )
+            })
+            .child(
+                Button::new("prev")
+                    .ghost()
+                    .with_size(size)
+                    .icon(IconName::ChevronLeft.mirrored(direction))
+                    .disabled(page == 0)
+                    .on_click({
+                        let set_page = set_page.clone();
+                        move |_, window, cx| set_page(page.saturating_sub(1), window, cx)
+                    }),
+            )
+            .children(
+                page_items(page, page_count, self.siblings)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, item)| match item {
+                        PageItem::Page(item_page) => Button::new(("page", ix))
+                            .ghost()
+                            .with_size(size)
+                            .min_w(px(32.))
+                            .label((item_page + 1).to_string())
+                            .selected(item_page == page)
+                            .on_click({
+                                let set_page = set_page.clone();
+                                move |_, window, cx| set_page(item_page, window, cx)
+                            })
+                            .into_any_element(),
+                        PageItem::Ellipsis => div()
+                            .px_1()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("…")
+                            .into_any_element(),
+                    }),
+            )
+            .child(
+                Button::new("next")
+                    .ghost()
+                    .with_size(size)
+                    .icon(IconName::ChevronRight.mirrored(direction))
+                    .disabled(page + 1 >= page_count)
+                    .on_click(move |_, window, cx| set_page(page + 1, window, cx)),
+            )
+            .when(self.page_size_selector, |this| {
+                this.child(
+                    Dropdown::new(&page_size_dropdown)
+                        .with_size(size)
+                        .w(px(110.))
+                        .ml_2()
+                        .direction(direction),
+                )
+            })
+            .when(self.jumper, |this| {
+                this.child(
+                    h_flex()
+                        .ml_2()
+                        .gap_2()
+                        .direction(direction)
+                        .child(SharedString::from(t!("Pagination.go_to")))
+                        .child(TextInput::new(&jump_input).with_size(size).w(px(56.))),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_items, PageItem};
+
+    fn pages(items: Vec<PageItem>) -> String {
+        items
+            .into_iter()
+            .map(|item| match item {
+                PageItem::Page(page) => (page + 1).to_string(),
+                PageItem::Ellipsis => "...".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_page_items() {
+        assert_eq!(pages(page_items(0, 1, 1)), "1");
+        assert_eq!(pages(page_items(3, 7, 1)), "1 2 3 4 5 6 7");
+        assert_eq!(pages(page_items(0, 20, 1)), "1 2 3 4 5 ... 20");
+        assert_eq!(pages(page_items(3, 20, 1)), "1 2 3 4 5 ... 20");
+        assert_eq!(pages(page_items(4, 20, 1)), "1 ... 4 5 6 ... 20");
+        assert_eq!(pages(page_items(10, 20, 1)), "1 ... 10 11 12 ... 20");
+        assert_eq!(pages(page_items(16, 20, 1)), "1 ... 16 17 18 19 20");
+        assert_eq!(pages(page_items(19, 20, 1)), "1 ... 16 17 18 19 20");
+        assert_eq!(pages(page_items(10, 20, 2)), "1 ... 9 10 11 12 13 ... 20");
+    }
+}
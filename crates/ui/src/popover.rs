@@ -1,13 +1,17 @@
 use gpui::{
-    anchored, deferred, div, prelude::FluentBuilder as _, px, AnyElement, App, Bounds, Context,
-    Corner, DismissEvent, DispatchPhase, Element, ElementId, Entity, EventEmitter, FocusHandle,
-    Focusable, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding, LayoutId,
-    ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render, Style,
+    deferred, div, prelude::FluentBuilder as _, AnyElement, App, Bounds, Context, Corner,
+    DismissEvent, DispatchPhase, Element, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
+    GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent,
+    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Render, Style,
     StyleRefinement, Styled, Window,
 };
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{actions::Cancel, Selectable, StyledExt as _};
+use crate::{
+    actions::Cancel,
+    position::{Position, Positioned},
+    ActiveTheme as _, Selectable, StyledExt as _,
+};
 
 const CONTEXT: &str = "Popover";
 
@@ -150,13 +154,38 @@ where
         (trigger)(open, window, cx)
     }
 
-    fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
-        bounds.corner(match self.anchor {
-            Corner::TopLeft => Corner::BottomLeft,
-            Corner::TopRight => Corner::BottomRight,
-            Corner::BottomLeft => Corner::TopLeft,
-            Corner::BottomRight => Corner::TopRight,
-        })
+    /// Build the content view and focus it, the content is closed on dismiss.
+    fn open_content(
+        content_build: &Rc<dyn Fn(&mut Window, &mut App) -> Entity<M> + 'static>,
+        content_view: &Rc<RefCell<Option<Entity<M>>>>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let new_content_view = (content_build)(window, cx);
+        let old_content_view = content_view.clone();
+
+        let previous_focus_handle = window.focused(cx);
+
+        window
+            .subscribe(
+                &new_content_view,
+                cx,
+                move |modal, _: &DismissEvent, window, cx| {
+                    if modal.focus_handle(cx).contains_focused(window, cx) {
+                        if let Some(previous_focus_handle) = previous_focus_handle.as_ref() {
+                            window.focus(previous_focus_handle);
+                        }
+                    }
+                    *old_content_view.borrow_mut() = None;
+
+                    window.refresh();
+                },
+            )
+            .detach();
+
+        window.focus(&new_content_view.focus_handle(cx));
+        *content_view.borrow_mut() = Some(new_content_view);
+        window.refresh();
     }
 
     fn with_element_state<R>(
@@ -236,6 +265,8 @@ impl<M: ManagedView> Element for Popover<M> {
         window: &mut Window,
         cx: &mut App,
     ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        // Mirror the anchor in the RTL layout, e.g.: `TopLeft` to `TopRight`.
+        self.anchor = cx.theme().direction.corner(self.anchor);
         let mut style = Style::default();
 
         // FIXME: Remove this and find a better way to handle this.
@@ -263,28 +294,22 @@ impl<M: ManagedView> Element for Popover<M> {
                 if let Some(content_view) = element_state.content_view.borrow_mut().as_mut() {
                     is_open = true;
 
-                    let mut anchored = anchored()
-                        .snap_to_window_with_margin(px(8.))
-                        .anchor(view.anchor);
-                    if let Some(trigger_bounds) = element_state.trigger_bounds {
-                        anchored = anchored.position(view.resolved_corner(trigger_bounds));
-                    }
+                    let positioned = Positioned::new(
+                        "popover-position",
+                        element_state.trigger_bounds.unwrap_or_default(),
+                    )
+                    .position(Position::from_corner(view.anchor));
 
                     let mut element = {
                         let content_view_mut = element_state.content_view.clone();
-                        let anchor = view.anchor;
                         let no_style = view.no_style;
                         deferred(
-                            anchored.child(
+                            positioned.child(
                                 div()
                                     .size_full()
                                     .occlude()
                                     .tab_group()
                                     .when(!no_style, |this| this.popover_style(cx))
-                                    .map(|this| match anchor {
-                                        Corner::TopLeft | Corner::TopRight => this.top_1(),
-                                        Corner::BottomLeft | Corner::BottomRight => this.bottom_1(),
-                                    })
                                     .child(content_view.clone())
                                     .when(!no_style, |this| {
                                         this.on_mouse_down_out(move |_, window, _| {
@@ -305,6 +330,28 @@ impl<M: ManagedView> Element for Popover<M> {
                 }
 
                 let mut trigger_element = view.render_trigger(is_open, window, cx);
+                // Open the popover by Enter or Space when the trigger is focused.
+                if let Some(content_build) = view.content.clone().filter(|_| !is_open) {
+                    let content_view = element_state.content_view.clone();
+                    trigger_element = div()
+                        .when_some(view.trigger_style.clone(), |mut this, trigger_style| {
+                            let style = this.style();
+                            style.size.width = trigger_style.size.width;
+                            style.display = trigger_style.display;
+                            this
+                        })
+                        .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                            let keystroke = &event.keystroke;
+                            if !keystroke.modifiers.modified()
+                                && matches!(keystroke.key.as_str(), "enter" | "space")
+                            {
+                                cx.stop_propagation();
+                                Self::open_content(&content_build, &content_view, window, cx);
+                            }
+                        })
+                        .child(trigger_element)
+                        .into_any_element();
+                }
                 let trigger_layout_id = trigger_element.request_layout(window, cx);
 
                 let layout_id = window.request_layout(
@@ -403,34 +450,7 @@ impl<M: ManagedView> Element for Popover<M> {
                     {
                         cx.stop_propagation();
                         window.prevent_default();
-
-                        let new_content_view = (content_build)(window, cx);
-                        let old_content_view1 = old_content_view.clone();
-
-                        let previous_focus_handle = window.focused(cx);
-
-                        window
-                            .subscribe(
-                                &new_content_view,
-                                cx,
-                                move |modal, _: &DismissEvent, window, cx| {
-                                    if modal.focus_handle(cx).contains_focused(window, cx) {
-                                        if let Some(previous_focus_handle) =
-                                            previous_focus_handle.as_ref()
-                                        {
-                                            window.focus(previous_focus_handle);
-                                        }
-                                    }
-                                    *old_content_view1.borrow_mut() = None;
-
-                                    window.refresh();
-                                },
-                            )
-                            .detach();
-
-                        window.focus(&new_content_view.focus_handle(cx));
-                        *old_content_view.borrow_mut() = Some(new_content_view);
-                        window.refresh();
+                        Self::open_content(&content_build, &old_content_view, window, cx);
                     }
                 });
             },
@@ -0,0 +1,571 @@
+use gpui::{
+    div, point, px, size, AnyElement, App, Bounds, Corner, Display, Element, ElementId,
+    GlobalElementId, InspectorElementId, InteractiveElement as _, IntoElement, LayoutId,
+    ParentElement, PathBuilder, Pixels, Position as StylePosition, Size,
+    StatefulInteractiveElement as _, Style, StyleRefinement, Styled, Window,
+};
+
+use crate::{ActiveTheme as _, Placement, StyledExt as _};
+
+/// The size of the arrow, from the base to the tip.
+const ARROW_SIZE: Pixels = px(5.);
+
+/// The alignment of the popover along the side of the trigger.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Align {
+    /// Align to the left edge (vertical placements) or top edge (horizontal placements).
+    #[default]
+    Start,
+    Center,
+    /// Align to the right edge (vertical placements) or bottom edge (horizontal placements).
+    End,
+}
+
+/// The positioning preferences of a popover relative to its trigger.
+///
+/// This is the shared positioning engine of the popovers in this crate, it places the popover
+/// at the preferred side of the trigger, then:
+///
+/// - Flips to the opposite side if the preferred side has not enough space.
+/// - Shifts along the side to keep the popover inside the window.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Position {
+    placement: Placement,
+    align: Align,
+    gap: Pixels,
+    margin: Pixels,
+    flip: bool,
+    shift: bool,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::new(Placement::Bottom)
+    }
+}
+
+/// The result of [`Position::resolve`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ResolvedPosition {
+    /// The bounds of the popover in window coordinates.
+    pub bounds: Bounds<Pixels>,
+    /// The placement after flipping.
+    pub placement: Placement,
+    /// The center of the arrow along the side facing the trigger, relative to the popover origin.
+    pub arrow_offset: Pixels,
+}
+
+impl Position {
+    pub fn new(placement: Placement) -> Self {
+        Self {
+            placement,
+            align: Align::Start,
+            gap: px(4.),
+            margin: px(8.),
+            flip: true,
+            shift: true,
+        }
+    }
+
+    /// Create by the anchor corner of the popover, e.g.: the `TopLeft` corner of the popover
+    /// is at the bottom left of the trigger, like the [`gpui::anchored`] anchor.
+    pub fn from_corner(corner: Corner) -> Self {
+        let (placement, align) = match corner {
+            Corner::TopLeft => (Placement::Bottom, Align::Start),
+            Corner::TopRight => (Placement::Bottom, Align::End),
+            Corner::BottomLeft => (Placement::Top, Align::Start),
+            Corner::BottomRight => (Placement::Top, Align::End),
+        };
+        Self::new(placement).align(align)
+    }
+
+    /// Set the alignment along the side of the trigger, default: [`Align::Start`].
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the gap between the trigger and the popover, default: 4px.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// Set the margin to keep from the window edges, default: 8px.
+    pub fn margin(mut self, margin: impl Into<Pixels>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    /// Set whether to flip to the opposite side when overflow, default: true.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Set whether to shift the popover to keep it inside the window, default: true.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn placement(&self) -> Placement {
+        self.placement
+    }
+
+    /// Returns the space available at the `placement` side of the trigger.
+    fn space(
+        &self,
+        placement: Placement,
+        trigger: Bounds<Pixels>,
+        viewport: Size<Pixels>,
+    ) -> Pixels {
+        let space = match placement {
+            Placement::Top => trigger.top(),
+            Placement::Bottom => viewport.height - trigger.bottom(),
+            Placement::Left => trigger.left(),
+            Placement::Right => viewport.width - trigger.right(),
+        };
+        (space - self.gap - self.margin).max(px(0.))
+    }
+
+    /// Returns the max size of the popover, a taller (or wider) popover should scroll its content.
+    pub fn max_size(&self, trigger: Bounds<Pixels>, viewport: Size<Pixels>) -> Size<Pixels> {
+        let mut main = self.space(self.placement, trigger, viewport);
+        if self.flip {
+            main = main.max(self.space(opposite(self.placement), trigger, viewport));
+        }
+        let cross = |len: Pixels| (len - self.margin * 2.).max(px(0.));
+
+        if self.placement.is_vertical() {
+            size(cross(viewport.width), main)
+        } else {
+            size(main, cross(viewport.height))
+        }
+    }
+
+    /// Resolve the bounds of a popover with `popover_size` for the `trigger` bounds,
+    /// both in window coordinates.
+    pub fn resolve(
+        &self,
+        trigger: Bounds<Pixels>,
+        popover_size: Size<Pixels>,
+        viewport: Size<Pixels>,
+    ) -> ResolvedPosition {
+        let main_size = |placement: Placement| match placement {
+            Placement::Top | Placement::Bottom => popover_size.height,
+            Placement::Left | Placement::Right => popover_size.width,
+        };
+
+        let mut placement = self.placement;
+        if self.flip && main_size(placement) > self.space(placement, trigger, viewport) {
+            let other = opposite(placement);
+            let other_space = self.space(other, trigger, viewport);
+            if main_size(other) <= other_space
+                || other_space > self.space(placement, trigger, viewport)
+            {
+                placement = other;
+            }
+        }
+
+        let cross_origin = |start: Pixels, end: Pixels, len: Pixels| match self.align {
+            Align::Start => start,
+            Align::Center => start + (end - start - len) / 2.,
+            Align::End => end - len,
+        };
+
+        let mut origin = match placement {
+            Placement::Top => point(
+                cross_origin(trigger.left(), trigger.right(), popover_size.width),
+                trigger.top() - self.gap - popover_size.height,
+            ),
+            Placement::Bottom => point(
+                cross_origin(trigger.left(), trigger.right(), popover_size.width),
+                trigger.bottom() + self.gap,
+            ),
+            Placement::Left => point(
+                trigger.left() - self.gap - popover_size.width,
+                cross_origin(trigger.top(), trigger.bottom(), popover_size.height),
+            ),
+            Placement::Right => point(
+                trigger.right() + self.gap,
+                cross_origin(trigger.top(), trigger.bottom(), popover_size.height),
+            ),
+        };
+
+        if self.shift {
+            // Keep the start edge visible if the popover is larger than the window.
+            let clamp = |v: Pixels, len: Pixels, limit: Pixels| {
+                v.min(limit - self.margin - len).max(self.margin)
+            };
+            origin.x = clamp(origin.x, popover_size.width, viewport.width);
+            origin.y = clamp(origin.y, popover_size.height, viewport.height);
+        }
+
+        // Point the arrow to the center of the trigger, but keep it away from the corners.
+        let (trigger_center, start, len) = match placement {
+            Placement::Top | Placement::Bottom => {
+                (trigger.center().x, origin.x, popover_size.width)
+            }
+            Placement::Left | Placement::Right => {
+                (trigger.center().y, origin.y, popover_size.height)
+            }
+        };
+        let inset = ARROW_SIZE * 2.;
+        let arrow_offset = if len > inset * 2. {
+            (trigger_center - start).max(inset).min(len - inset)
+        } else {
+            len / 2.
+        };
+
+        ResolvedPosition {
+            bounds: Bounds {
+                origin,
+                size: popover_size,
+            },
+            placement,
+            arrow_offset,
+        }
+    }
+}
+
+fn opposite(placement: Placement) -> Placement {
+    match placement {
+        Placement::Top => Placement::Bottom,
+        Placement::Bottom => Placement::Top,
+        Placement::Left => Placement::Right,
+        Placement::Right => Placement::Left,
+    }
+}
+
+/// An element positioned relative to a trigger bounds by the [`Position`] engine.
+///
+/// The content is constrained to the available space and scrolls if it's too tall,
+/// and an arrow pointing to the trigger can be rendered by [`Positioned::arrow`].
+///
+/// Like [`gpui::anchored`], this should be wrapped in a [`gpui::deferred`] to be painted on top.
+pub struct Positioned {
+    id: ElementId,
+    trigger: Bounds<Pixels>,
+    position: Position,
+    arrow: bool,
+    style: StyleRefinement,
+    children: Vec<AnyElement>,
+}
+
+impl Positioned {
+    /// Create a new positioned element for the `trigger` bounds in window coordinates.
+    pub fn new(id: impl Into<ElementId>, trigger: Bounds<Pixels>) -> Self {
+        Self {
+            id: id.into(),
+            trigger,
+            position: Position::default(),
+            arrow: false,
+            style: StyleRefinement::default(),
+            children: vec![],
+        }
+    }
+
+    /// Set the positioning preferences, default: [`Placement::Bottom`] with [`Align::Start`].
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set whether to render an arrow pointing to the trigger, default: false.
+    ///
+    /// The arrow is painted with the popover colors of the theme.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+}
+
+impl ParentElement for Positioned {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Positioned {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl IntoElement for Positioned {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+pub struct PositionedLayoutState {
+    content: Option<AnyElement>,
+    content_layout_id: LayoutId,
+    position: Position,
+}
+
+impl Element for Positioned {
+    type RequestLayoutState = PositionedLayoutState;
+    type PrepaintState = Option<ResolvedPosition>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut position = self.position;
+        if self.arrow {
+            position.gap += ARROW_SIZE;
+        }
+        let max_size = position.max_size(self.trigger, window.viewport_size());
+
+        let mut content = div()
+            .id("content")
+            .flex()
+            .flex_col()
+            .flex_none()
+            .refine_style(&self.style)
+            .max_w(max_size.width)
+            .max_h(max_size.height)
+            .overflow_y_scroll()
+            .children(self.children.drain(..))
+            .into_any_element();
+        let content_layout_id = content.request_layout(window, cx);
+
+        let style = Style {
+            position: StylePosition::Absolute,
+            display: Display::Flex,
+            ..Style::default()
+        };
+        let layout_id = window.request_layout(style, [content_layout_id], cx);
+
+        (
+            layout_id,
+            PositionedLayoutState {
+                content: Some(content),
+                content_layout_id,
+                position,
+            },
+        )
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let content = request_layout.content.as_mut()?;
+        let content_bounds = window.layout_bounds(request_layout.content_layout_id);
+        let resolved = request_layout.position.resolve(
+            self.trigger,
+            content_bounds.size,
+            window.viewport_size(),
+        );
+
+        let offset = resolved.bounds.origin - bounds.origin;
+        let offset = point(offset.x.round(), offset.y.round());
+        window.with_element_offset(offset, |window| content.prepaint(window, cx));
+
+        Some(ResolvedPosition {
+            bounds: Bounds {
+                origin: bounds.origin + offset,
+                size: content_bounds.size,
+            },
+            ..resolved
+        })
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        resolved: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(content) = request_layout.content.as_mut() else {
+            return;
+        };
+        content.paint(window, cx);
+
+        if let Some(resolved) = resolved.filter(|_| self.arrow) {
+            paint_arrow(&resolved, window, cx);
+        }
+    }
+}
+
+/// Paint the arrow on the side of the popover facing the trigger.
+fn paint_arrow(resolved: &ResolvedPosition, window: &mut Window, cx: &mut App) {
+    let bounds = resolved.bounds;
+    let offset = resolved.arrow_offset;
+    // The base is moved into the popover by 1px to cover the border.
+    let (base_start, tip, base_end) = match resolved.placement {
+        Placement::Top => {
+            let x = bounds.left() + offset;
+            let y = bounds.bottom() - px(1.);
+            (
+                point(x - ARROW_SIZE, y),
+                point(x, y + ARROW_SIZE),
+                point(x + ARROW_SIZE, y),
+            )
+        }
+        Placement::Bottom => {
+            let x = bounds.left() + offset;
+            let y = bounds.top() + px(1.);
+            (
+                point(x - ARROW_SIZE, y),
+                point(x, y - ARROW_SIZE),
+                point(x + ARROW_SIZE, y),
+            )
+        }
+        Placement::Left => {
+            let x = bounds.right() - px(1.);
+            let y = bounds.top() + offset;
+            (
+                point(x, y - ARROW_SIZE),
+                point(x + ARROW_SIZE, y),
+                point(x, y + ARROW_SIZE),
+            )
+        }
+        Placement::Right => {
+            let x = bounds.left() + px(1.);
+            let y = bounds.top() + offset;
+            (
+                point(x, y - ARROW_SIZE),
+                point(x - ARROW_SIZE, y),
+                point(x, y + ARROW_SIZE),
+            )
+        }
+    };
+
+    let mut fill = PathBuilder::fill();
+    fill.add_polygon(&[base_start, tip, base_end], true);
+    if let Ok(path) = fill.build() {
+        window.paint_path(path, cx.theme().popover);
+    }
+
+    let mut stroke = PathBuilder::stroke(px(1.));
+    stroke.add_polygon(&[base_start, tip, base_end], false);
+    if let Ok(path) = stroke.build() {
+        window.paint_path(path, cx.theme().border);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds, Corner};
+
+    use super::{Align, Position};
+    use crate::Placement;
+
+    #[test]
+    fn test_resolve_position() {
+        let viewport = size(px(800.), px(600.));
+        let trigger = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(80.), px(20.)),
+        };
+        let popover = size(px(200.), px(100.));
+
+        let resolved = Position::new(Placement::Bottom).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.placement, Placement::Bottom);
+        assert_eq!(resolved.bounds.origin, point(px(100.), px(124.)));
+        assert_eq!(resolved.arrow_offset, px(40.));
+
+        let resolved = Position::new(Placement::Bottom)
+            .align(Align::Center)
+            .resolve(trigger, popover, viewport);
+        assert_eq!(resolved.bounds.origin, point(px(40.), px(124.)));
+
+        let resolved = Position::new(Placement::Top).align(Align::End).resolve(
+            trigger,
+            size(px(80.), px(50.)),
+            viewport,
+        );
+        assert_eq!(resolved.placement, Placement::Top);
+        assert_eq!(resolved.bounds.origin, point(px(100.), px(46.)));
+
+        // Flip to the bottom if not enough space at the top.
+        let resolved = Position::new(Placement::Top).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.placement, Placement::Bottom);
+
+        // Stay if flipping is disabled, and shift into the window.
+        let resolved = Position::new(Placement::Top)
+            .flip(false)
+            .resolve(trigger, popover, viewport);
+        assert_eq!(resolved.placement, Placement::Top);
+        assert_eq!(resolved.bounds.origin.y, px(8.));
+
+        // Shift horizontally to keep inside the window.
+        let trigger = Bounds {
+            origin: point(px(700.), px(100.)),
+            size: size(px(80.), px(20.)),
+        };
+        let resolved = Position::new(Placement::Bottom).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.bounds.origin.x, px(592.));
+        assert_eq!(resolved.arrow_offset, px(148.));
+
+        // Flip to the left at the right edge.
+        let resolved = Position::new(Placement::Right).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.placement, Placement::Left);
+        assert_eq!(resolved.bounds.origin.x, px(496.));
+    }
+
+    #[test]
+    fn test_max_size() {
+        let viewport = size(px(800.), px(600.));
+        let trigger = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(80.), px(20.)),
+        };
+
+        let max_size = Position::new(Placement::Bottom).max_size(trigger, viewport);
+        assert_eq!(max_size, size(px(784.), px(468.)));
+        let max_size = Position::new(Placement::Top)
+            .flip(false)
+            .max_size(trigger, viewport);
+        assert_eq!(max_size, size(px(784.), px(88.)));
+    }
+
+    #[test]
+    fn test_position_from_corner() {
+        let viewport = size(px(800.), px(600.));
+        let trigger = Bounds {
+            origin: point(px(100.), px(100.)),
+            size: size(px(80.), px(20.)),
+        };
+        let popover = size(px(200.), px(100.));
+
+        let resolved = Position::from_corner(Corner::TopLeft).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.bounds.origin, point(px(100.), px(124.)));
+        let resolved = Position::from_corner(Corner::BottomRight).resolve(
+            trigger,
+            size(px(60.), px(50.)),
+            viewport,
+        );
+        assert_eq!(resolved.bounds.origin, point(px(120.), px(46.)));
+        // Flip to the bottom, if no space at the top.
+        let resolved =
+            Position::from_corner(Corner::BottomLeft).resolve(trigger, popover, viewport);
+        assert_eq!(resolved.placement, Placement::Bottom);
+    }
+}
@@ -0,0 +1,26 @@
+//! The commonly used traits of the components.
+//!
+//! ```ignore
+//! use gpui_component::prelude::*;
+//! ```
+//!
+//! The stateful components follow the same pattern, create the state entity by `State::new`,
+//! then render the element by `Component::new(&state)`, e.g.:
+//!
+//! - [`crate::input::InputState`] and [`crate::input::TextInput`]
+//! - [`crate::dropdown::DropdownState`] and [`crate::dropdown::Dropdown`]
+//! - [`crate::calendar::CalendarState`] and [`crate::calendar::Calendar`]
+//! - [`crate::table::Table`] and [`crate::table::DataTable`]
+
+pub use gpui::prelude::FluentBuilder;
+
+pub use crate::{
+    accessibility::Accessible,
+    button::{ButtonVariants, ToggleVariants},
+    context_menu::ContextMenuExt,
+    popup_menu::PopupMenuExt,
+    ActiveTheme, AxisExt, Collapsible, Colorize, ContextModal, Disableable, InteractiveElementExt,
+    ScaleFactorObserver, Selectable, Sizable, StyleSized, StyledExt,
+};
+
+pub use crate::{h_flex, v_flex};
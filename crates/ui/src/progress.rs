@@ -1,58 +1,378 @@
-use crate::ActiveTheme;
+use std::{f32::consts::TAU, time::Duration};
+
+use crate::{
+    animation::cubic_bezier,
+    h_flex,
+    plot::{
+        shape::{Arc, ArcData},
+        Plot,
+    },
+    v_flex, ActiveTheme, PixelsExt, Sizable, Size,
+};
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, App, IntoElement, ParentElement, RenderOnce, Styled,
-    Window,
+    div, linear, prelude::FluentBuilder, px, relative, Animation, AnimationExt as _, App, Bounds,
+    Hsla, IntoElement, ParentElement, Pixels, RenderOnce, SharedString, Styled, Window,
 };
+use gpui_component_macros::IntoPlot;
+
+/// The variant of the [`Progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ProgressVariant {
+    #[default]
+    Bar,
+    /// The bar is split into the number of steps.
+    Steps(usize),
+    Circular,
+}
 
 /// A Progress bar element.
+///
+/// - [`Progress::indeterminate`] to show an animated progress when the value is unknown.
+/// - [`Progress::steps`] to split the bar into segments, e.g.: the steps of a wizard.
+/// - [`Progress::circular`] to show a circle, with the size presets by [`Sizable`].
 #[derive(IntoElement)]
 pub struct Progress {
     value: f32,
-    height: f32,
+    height: Option<f32>,
+    size: Size,
+    variant: ProgressVariant,
+    indeterminate: bool,
+    color: Option<Hsla>,
+    thresholds: Vec<(f32, Hsla)>,
+    label: Option<SharedString>,
 }
 
 impl Progress {
     pub fn new() -> Self {
         Progress {
             value: Default::default(),
-            height: 8.,
+            height: None,
+            size: Size::default(),
+            variant: ProgressVariant::default(),
+            indeterminate: false,
+            color: None,
+            thresholds: Vec::new(),
+            label: None,
         }
     }
 
+    /// Set the value in percentage, from 0 to 100.
     pub fn value(mut self, value: f32) -> Self {
         self.value = value;
         self
     }
-}
 
-impl RenderOnce for Progress {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    /// Set the height of the bar in pixels, default is by the size: 4, 6, 8 and 12.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set to show an animated progress without the value, e.g.: for unknown durations.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Split the bar into the number of steps, the value fills the steps.
+    pub fn steps(mut self, steps: usize) -> Self {
+        self.variant = ProgressVariant::Steps(steps.max(1));
+        self
+    }
+
+    /// Show as a circle, the diameter is by the size: 16, 24, 40 and 64px.
+    pub fn circular(mut self) -> Self {
+        self.variant = ProgressVariant::Circular;
+        self
+    }
+
+    /// Set the label in the center of the circular progress, e.g.: `75%`.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the color of the value, default is the progress bar color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Add a threshold, the color is used when the value is greater than or equal to it.
+    ///
+    /// e.g.: `.threshold(0., red).threshold(20., green)` to use red under 20%.
+    pub fn threshold(mut self, value: f32, color: impl Into<Hsla>) -> Self {
+        self.thresholds.push((value, color.into()));
+        self
+    }
+
+    /// Returns the value in the range of 0.0 to 1.0.
+    fn fraction(&self) -> f32 {
+        (self.value / 100.).clamp(0., 1.)
+    }
+
+    fn value_color(&self, cx: &App) -> Hsla {
+        threshold_color(&self.thresholds, self.value)
+            .or(self.color)
+            .unwrap_or(cx.theme().progress_bar)
+    }
+
+    fn bar_height(&self) -> f32 {
+        self.height.unwrap_or(match self.size {
+            Size::XSmall => 4.,
+            Size::Small => 6.,
+            Size::Large => 12.,
+            _ => 8.,
+        })
+    }
+
+    fn render_bar(self, cx: &App) -> impl IntoElement {
+        let height = self.bar_height();
         // Match the theme radius, if theme radius is zero use it.
-        let radius = px(self.height / 2.).min(cx.theme().radius);
-        let relative_w = relative(match self.value {
-            v if v < 0. => 0.,
-            v if v > 100. => 1.,
-            v => v / 100.,
-        });
+        let radius = px(height / 2.).min(cx.theme().radius);
+        let color = self.value_color(cx);
+        let fraction = self.fraction();
+        let indeterminate = self.indeterminate;
+        let reduce_motion = cx.theme().reduce_motion;
 
         div()
             .w_full()
             .relative()
-            .h(px(self.height))
+            .overflow_hidden()
+            .h(px(height))
             .rounded(radius)
-            .bg(cx.theme().progress_bar.opacity(0.2))
-            .child(
-                div()
+            .bg(color.opacity(0.2))
+            .map(|this| {
+                if !indeterminate {
+                    return this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .h_full()
+                            .w(relative(fraction))
+                            .bg(color)
+                            .map(|this| match fraction {
+                                v if v >= 1. => this.rounded(radius),
+                                _ => this.rounded_l(radius),
+                            }),
+                    );
+                }
+
+                let bar = div()
                     .absolute()
                     .top_0()
-                    .left_0()
                     .h_full()
-                    .w(relative_w)
-                    .bg(cx.theme().progress_bar)
-                    .map(|this| match self.value {
-                        v if v >= 100. => this.rounded(radius),
-                        _ => this.rounded_l(radius),
-                    }),
-            )
+                    .w(relative(0.3))
+                    .rounded(radius)
+                    .bg(color);
+                if reduce_motion {
+                    return this.child(bar.w_full().opacity(0.6));
+                }
+
+                this.child(
+                    bar.with_animation(
+                        "progress-indeterminate",
+                        Animation::new(Duration::from_millis(1500))
+                            .repeat()
+                            .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                        // Move from the left outside to the right outside.
+                        |this, delta| this.left(relative(delta * 1.3 - 0.3)),
+                    ),
+                )
+            })
+    }
+
+    fn render_steps(self, steps: usize, cx: &App) -> impl IntoElement {
+        let height = self.bar_height();
+        let radius = px(height / 2.).min(cx.theme().radius);
+        let color = self.value_color(cx);
+        let fractions = step_fractions(self.fraction(), steps);
+
+        h_flex()
+            .w_full()
+            .gap_1()
+            .h(px(height))
+            .children(fractions.into_iter().map(|fraction| {
+                div()
+                    .flex_1()
+                    .relative()
+                    .h_full()
+                    .overflow_hidden()
+                    .rounded(radius)
+                    .bg(color.opacity(0.2))
+                    .child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .h_full()
+                            .w(relative(fraction))
+                            .bg(color),
+                    )
+            }))
+    }
+
+    fn render_circular(self, cx: &App) -> impl IntoElement {
+        let (diameter, thickness) = match self.size {
+            Size::XSmall => (16., 2.),
+            Size::Small => (24., 3.),
+            Size::Large => (64., 6.),
+            _ => (40., 4.),
+        };
+        let color = self.value_color(cx);
+        let ring = ProgressRing {
+            start: 0.,
+            fraction: if self.indeterminate {
+                0.25
+            } else {
+                self.fraction()
+            },
+            thickness,
+            track: color.opacity(0.2),
+            color,
+        };
+        let animated = self.indeterminate && !cx.theme().reduce_motion;
+
+        div()
+            .relative()
+            .flex_shrink_0()
+            .size(px(diameter))
+            .map(|this| {
+                if animated {
+                    this.child(
+                        ring.with_animation(
+                            "progress-circular",
+                            Animation::new(Duration::from_millis(1000))
+                                .repeat()
+                                .with_easing(linear),
+                            |mut ring, delta| {
+                                ring.start = delta;
+                                ring
+                            },
+                        ),
+                    )
+                } else {
+                    this.child(ring)
+                }
+            })
+            .when_some(self.label, |this, label| {
+                this.child(
+                    v_flex()
+                        .absolute()
+                        .inset_0()
+                        .items_center()
+                        .justify_center()
+                        .map(|this| match self.size {
+                            Size::XSmall | Size::Small => this.text_size(px(8.)),
+                            Size::Large => this.text_sm(),
+                            _ => this.text_xs(),
+                        })
+                        .child(label),
+                )
+            })
+    }
+}
+
+impl Sizable for Progress {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Progress {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        match self.variant {
+            ProgressVariant::Bar => self.render_bar(cx).into_any_element(),
+            ProgressVariant::Steps(steps) if !self.indeterminate => {
+                self.render_steps(steps, cx).into_any_element()
+            }
+            ProgressVariant::Steps(_) => self.render_bar(cx).into_any_element(),
+            ProgressVariant::Circular => self.render_circular(cx).into_any_element(),
+        }
+    }
+}
+
+/// The track and value arcs of the circular [`Progress`].
+#[derive(IntoPlot)]
+struct ProgressRing {
+    /// The start of the value arc in turns, to rotate the indeterminate arc.
+    start: f32,
+    fraction: f32,
+    thickness: f32,
+    track: Hsla,
+    color: Hsla,
+}
+
+impl Plot for ProgressRing {
+    fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, _: &mut App) {
+        let radius = bounds.size.width.min(bounds.size.height).as_f32() / 2.;
+        let arc = Arc::new()
+            .inner_radius((radius - self.thickness).max(0.))
+            .outer_radius(radius);
+
+        let track = ArcData {
+            data: &(),
+            index: 0,
+            value: 1.,
+            start_angle: 0.,
+            end_angle: TAU,
+            pad_angle: 0.,
+        };
+        arc.paint(&track, self.track, &bounds, window);
+
+        if self.fraction <= 0. {
+            return;
+        }
+        let start_angle = self.start * TAU;
+        let value = ArcData {
+            start_angle,
+            end_angle: start_angle + TAU * self.fraction.clamp(0., 1.),
+            ..track
+        };
+        arc.paint(&value, self.color, &bounds, window);
+    }
+}
+
+/// Returns the color of the greatest threshold less than or equal to the `value`.
+fn threshold_color(thresholds: &[(f32, Hsla)], value: f32) -> Option<Hsla> {
+    thresholds
+        .iter()
+        .filter(|(threshold, _)| value >= *threshold)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, color)| *color)
+}
+
+/// Returns the filled fraction of each step by the total `fraction`.
+fn step_fractions(fraction: f32, steps: usize) -> Vec<f32> {
+    let filled = fraction * steps as f32;
+    (0..steps)
+        .map(|ix| (filled - ix as f32).clamp(0., 1.))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{blue, green, red};
+
+    use super::{step_fractions, threshold_color};
+
+    #[test]
+    fn test_threshold_color() {
+        let thresholds = [(20., green()), (0., red()), (80., blue())];
+        assert_eq!(threshold_color(&thresholds, 10.), Some(red()));
+        assert_eq!(threshold_color(&thresholds, 20.), Some(green()));
+        assert_eq!(threshold_color(&thresholds, 95.), Some(blue()));
+        assert_eq!(threshold_color(&thresholds, -1.), None);
+        assert_eq!(threshold_color(&[], 50.), None);
+    }
+
+    #[test]
+    fn test_step_fractions() {
+        assert_eq!(step_fractions(0., 3), vec![0., 0., 0.]);
+        assert_eq!(step_fractions(0.5, 4), vec![1., 1., 0., 0.]);
+        assert_eq!(step_fractions(0.5, 3), vec![1., 0.5, 0.]);
+        assert_eq!(step_fractions(1., 2), vec![1., 1.]);
     }
 }
@@ -1,8 +1,12 @@
 use std::rc::Rc;
 
 use crate::{
-    checkbox::checkbox_check_icon, h_flex, text::Text, v_flex, ActiveTheme, AxisExt,
-    FocusableExt as _, Sizable, Size, StyledExt,
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Role},
+    checkbox::checkbox_check_icon,
+    form::FieldChange,
+    h_flex,
+    text::Text,
+    v_flex, ActiveTheme, AxisExt, FocusableExt as _, Sizable, Size, StyledExt,
 };
 use gpui::{
     div, prelude::FluentBuilder, px, relative, rems, AnyElement, App, Axis, Div, ElementId,
@@ -22,10 +26,15 @@ pub struct Radio {
     children: Vec<AnyElement>,
     checked: bool,
     disabled: bool,
+    invalid: bool,
     tab_stop: bool,
     tab_index: isize,
     size: Size,
+    name: Option<SharedString>,
+    value: Option<SharedString>,
     on_click: Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    on_field_change: Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App) + 'static>>,
+    accessibility: AccessibilityProps,
 }
 
 impl Radio {
@@ -38,10 +47,15 @@ impl Radio {
             children: Vec::new(),
             checked: false,
             disabled: false,
+            invalid: false,
             tab_index: 0,
             tab_stop: true,
             size: Size::default(),
+            name: None,
+            value: None,
             on_click: None,
+            on_field_change: None,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -60,6 +74,24 @@ impl Radio {
         self
     }
 
+    /// Set the invalid state to show the validation error, default is false.
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Set the name of the Radio element in the form, see [`FieldChange`].
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the value of the Radio element in the form, see [`FieldChange`].
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
     /// Set the tab index for the Radio element, default is `0`.
     pub fn tab_index(mut self, tab_index: isize) -> Self {
         self.tab_index = tab_index;
@@ -77,9 +109,22 @@ impl Radio {
         self
     }
 
+    /// Listen to the change with the form metadata, this is called after the `on_click`.
+    ///
+    /// The `checked` of the [`FieldChange`] is always true, because a Radio can't be unchecked by click.
+    pub fn on_field_change(
+        mut self,
+        handler: impl Fn(&FieldChange, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_field_change = Some(Rc::new(handler));
+        self
+    }
+
     fn handle_click(
         on_click: &Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+        on_field_change: &Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App) + 'static>>,
         checked: bool,
+        change: &FieldChange,
         window: &mut Window,
         cx: &mut App,
     ) {
@@ -87,6 +132,15 @@ impl Radio {
         if let Some(f) = on_click {
             (f)(&new_checked, window, cx);
         }
+        if let Some(f) = on_field_change {
+            (f)(change, window, cx);
+        }
+    }
+}
+
+impl Accessible for Radio {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
     }
 }
 
@@ -125,6 +179,16 @@ impl RenderOnce for Radio {
         let is_focused = focus_handle.is_focused(window);
         let disabled = self.disabled;
 
+        let mut props = AccessibilityProps::new(Role::Radio)
+            .checked(checked)
+            .disabled(disabled)
+            .invalid(self.invalid);
+        props.label = self
+            .label
+            .as_ref()
+            .and_then(|label| label.as_str().cloned());
+        Accessibility::declare(&self.id, &props.merge(&self.accessibility), window, cx);
+
         let (border_color, bg) = if checked {
             (cx.theme().primary, cx.theme().primary)
         } else {
@@ -176,13 +240,14 @@ impl RenderOnce for Radio {
                         .rounded_full()
                         .border_1()
                         .border_color(border_color)
+                        .when(self.invalid, |this| this.border_color(cx.theme().danger))
                         .when(cx.theme().shadow && !disabled, |this| this.shadow_xs())
                         .map(|this| match self.checked {
                             false => this.bg(cx.theme().background),
                             _ => this.bg(bg),
                         })
                         .child(checkbox_check_icon(
-                            self.id, self.size, checked, disabled, window, cx,
+                            self.id, self.size, checked, false, disabled, window, cx,
                         )),
                 )
                 .when(!self.children.is_empty() || self.label.is_some(), |this| {
@@ -213,10 +278,23 @@ impl RenderOnce for Radio {
                 .when(!self.disabled, |this| {
                     this.on_click({
                         let on_click = self.on_click.clone();
+                        let on_field_change = self.on_field_change.clone();
+                        let change = FieldChange {
+                            name: self.name.clone(),
+                            value: self.value.clone(),
+                            checked: true,
+                        };
                         move |_, window, cx| {
                             window.prevent_default();
                             cx.stop_propagation();
-                            Self::handle_click(&on_click, checked, window, cx);
+                            Self::handle_click(
+                                &on_click,
+                                &on_field_change,
+                                checked,
+                                &change,
+                                window,
+                                cx,
+                            );
                         }
                     })
                 }),
@@ -233,7 +311,10 @@ pub struct RadioGroup {
     layout: Axis,
     selected_index: Option<usize>,
     disabled: bool,
+    invalid: bool,
+    name: Option<SharedString>,
     on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+    on_field_change: Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App) + 'static>>,
 }
 
 impl RadioGroup {
@@ -242,9 +323,12 @@ impl RadioGroup {
             id: id.into(),
             style: StyleRefinement::default().flex_1(),
             on_change: None,
+            on_field_change: None,
             layout: Axis::Vertical,
             selected_index: None,
             disabled: false,
+            invalid: false,
+            name: None,
             radios: vec![],
         }
     }
@@ -271,6 +355,29 @@ impl RadioGroup {
         self
     }
 
+    /// Listen to the change with the form metadata, this is called after the `on_change`.
+    ///
+    /// The `value` is the value of the selected Radio, or the index if the Radio has no value.
+    pub fn on_field_change(
+        mut self,
+        handler: impl Fn(&FieldChange, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_field_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the name of the Radio group in the form, see [`FieldChange`].
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the invalid state of the whole group to show the validation error, default is false.
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
     /// Set the selected index.
     pub fn selected_index(mut self, index: Option<usize>) -> Self {
         self.selected_index = index;
@@ -323,7 +430,10 @@ impl From<String> for Radio {
 impl RenderOnce for RadioGroup {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
         let on_change = self.on_change;
+        let on_field_change = self.on_field_change;
         let disabled = self.disabled;
+        let invalid = self.invalid;
+        let name = self.name;
         let selected_ix = self.selected_index;
 
         let base = if self.layout.is_vertical() {
@@ -339,16 +449,28 @@ impl RenderOnce for RadioGroup {
             base.gap_3()
                 .children(self.radios.into_iter().enumerate().map(|(ix, mut radio)| {
                     let checked = selected_ix == Some(ix);
+                    let value = radio.value.clone().unwrap_or_else(|| ix.to_string().into());
 
                     radio.id = ix.into();
-                    radio.disabled(disabled).checked(checked).when_some(
-                        on_change.clone(),
-                        |this, on_change| {
+                    radio.invalid |= invalid;
+                    radio
+                        .disabled(disabled)
+                        .checked(checked)
+                        .when_some(on_change.clone(), |this, on_change| {
                             this.on_click(move |_, window, cx| {
                                 on_change(&ix, window, cx);
                             })
-                        },
-                    )
+                        })
+                        .when_some(on_field_change.clone(), |this, on_field_change| {
+                            let change = FieldChange {
+                                name: name.clone(),
+                                value: Some(value),
+                                checked: true,
+                            };
+                            this.on_field_change(move |_, window, cx| {
+                                on_field_change(&change, window, cx);
+                            })
+                        })
                 })),
         )
     }
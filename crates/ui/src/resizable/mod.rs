@@ -4,6 +4,7 @@ use gpui::{
     px, Along, App, AppContext, Axis, Bounds, Context, ElementId, Entity, EventEmitter, Pixels,
     Window,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::PixelsExt;
 
@@ -37,6 +38,8 @@ pub struct ResizableState {
     panels: Vec<ResizablePanelState>,
     sizes: Vec<Pixels>,
     pub(crate) resizing_panel_ix: Option<usize>,
+    /// The sizes of the panels when start resizing.
+    drag_start_sizes: Vec<Pixels>,
     bounds: Bounds<Pixels>,
 }
 
@@ -47,6 +50,7 @@ impl ResizableState {
             panels: vec![],
             sizes: vec![],
             resizing_panel_ix: None,
+            drag_start_sizes: vec![],
             bounds: Bounds::default(),
         })
     }
@@ -86,14 +90,17 @@ impl ResizableState {
         &mut self,
         panel_ix: usize,
         bounds: Bounds<Pixels>,
-        size_range: Range<Pixels>,
+        constraints: PanelConstraints,
         cx: &mut Context<Self>,
     ) {
         let size = bounds.size.along(self.axis);
         self.sizes[panel_ix] = size;
         self.panels[panel_ix].size = Some(size);
         self.panels[panel_ix].bounds = bounds;
-        self.panels[panel_ix].size_range = size_range;
+        self.panels[panel_ix].size_range = constraints.size_range;
+        self.panels[panel_ix].initial_size = constraints.initial_size;
+        self.panels[panel_ix].collapsible = constraints.collapsible;
+        self.panels[panel_ix].snap_threshold = constraints.snap_threshold;
         cx.notify();
     }
 
@@ -131,12 +138,214 @@ impl ResizableState {
         &self.sizes
     }
 
+    /// Returns true if the panel at the `ix` is collapsed.
+    pub fn is_collapsed(&self, ix: usize) -> bool {
+        self.panels.get(ix).is_some_and(|panel| panel.collapsed)
+    }
+
+    /// Collapse the panel at the `ix`, the size is given to the next panel (or the previous for the last).
+    pub fn collapse(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if self.collapse_panel(ix) {
+            cx.emit(ResizablePanelEvent::Resized);
+            cx.notify();
+        }
+    }
+
+    /// Expand the collapsed panel at the `ix` to the size before collapsed.
+    pub fn expand(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if self.expand_panel(ix, None) {
+            cx.emit(ResizablePanelEvent::Resized);
+            cx.notify();
+        }
+    }
+
+    /// Reset the panels to the initial sizes, and expand the collapsed panels.
+    ///
+    /// This is also triggered by double clicking the resize handle.
+    pub fn reset(&mut self, cx: &mut Context<Self>) {
+        for (panel, size) in self.panels.iter_mut().zip(self.sizes.iter_mut()) {
+            panel.collapsed = false;
+            panel.restore_size = None;
+            panel.size = panel.initial_size;
+            *size = panel.initial_size.unwrap_or(PANEL_MIN_SIZE);
+        }
+        cx.emit(ResizablePanelEvent::Resized);
+        cx.notify();
+    }
+
+    /// Dump the layout to persist, see [`ResizableState::load`].
+    pub fn dump(&self) -> ResizableLayout {
+        ResizableLayout {
+            sizes: self
+                .panels
+                .iter()
+                .zip(self.sizes.iter())
+                .map(|(panel, size)| match panel.collapsed {
+                    true => panel.restore_size.unwrap_or(*size),
+                    false => *size,
+                })
+                .collect(),
+            collapsed: self
+                .panels
+                .iter()
+                .enumerate()
+                .filter_map(|(ix, panel)| panel.collapsed.then_some(ix))
+                .collect(),
+        }
+    }
+
+    /// Load the layout dumped by [`ResizableState::dump`].
+    pub fn load(&mut self, layout: &ResizableLayout, cx: &mut Context<Self>) {
+        self.apply_layout(layout);
+        cx.notify();
+    }
+
+    fn apply_layout(&mut self, layout: &ResizableLayout) {
+        if layout.sizes.len() > self.panels.len() {
+            let diff = layout.sizes.len() - self.panels.len();
+            self.panels
+                .extend(vec![ResizablePanelState::default(); diff]);
+            self.sizes.extend(vec![PANEL_MIN_SIZE; diff]);
+        }
+
+        for (ix, size) in layout.sizes.iter().enumerate() {
+            let panel = &mut self.panels[ix];
+            panel.collapsed = layout.collapsed.contains(&ix);
+            if panel.collapsed {
+                panel.restore_size = Some(*size);
+                panel.size = Some(px(0.));
+                self.sizes[ix] = px(0.);
+            } else {
+                panel.restore_size = None;
+                panel.size = Some(*size);
+                self.sizes[ix] = *size;
+            }
+        }
+    }
+
+    /// Returns the index of the neighbor panel to give or take the size of the panel at the `ix`.
+    fn neighbor_ix(&self, ix: usize) -> Option<usize> {
+        if ix + 1 < self.panels.len() {
+            Some(ix + 1)
+        } else {
+            ix.checked_sub(1)
+        }
+    }
+
+    fn collapse_panel(&mut self, ix: usize) -> bool {
+        let Some(neighbor_ix) = self.neighbor_ix(ix) else {
+            return false;
+        };
+        if self.panels[ix].collapsed {
+            return false;
+        }
+
+        self.collapse_panel_into(ix, neighbor_ix);
+        true
+    }
+
+    /// Expand the panel at the `ix` to the `size`, or the size before collapsed if None.
+    fn expand_panel(&mut self, ix: usize, size: Option<Pixels>) -> bool {
+        if !self.is_collapsed(ix) {
+            return false;
+        }
+
+        let panel = &mut self.panels[ix];
+        panel.collapsed = false;
+        let range = panel.size_range.clone();
+        let size = size
+            .or(panel.restore_size.take())
+            .unwrap_or(range.start)
+            .clamp(range.start, range.end);
+
+        // Take the size from the neighbor, but keep its minimum size.
+        let size = match self.neighbor_ix(ix) {
+            Some(neighbor_ix) => {
+                let neighbor_size = self.sizes[neighbor_ix];
+                let available =
+                    (neighbor_size - self.panels[neighbor_ix].size_range.start).max(px(0.));
+                let size = size.min(available.max(range.start));
+                self.set_size(neighbor_ix, (neighbor_size - size).max(px(0.)));
+                size
+            }
+            None => size,
+        };
+        self.set_size(ix, size);
+        true
+    }
+
+    fn set_size(&mut self, ix: usize, size: Pixels) {
+        self.sizes[ix] = size;
+        self.panels[ix].size = Some(size);
+    }
+
+    /// Returns the size to snap the collapsible panel, default is half of the minimum size.
+    fn snap_threshold(&self, ix: usize) -> Pixels {
+        let panel = &self.panels[ix];
+        panel.snap_threshold.unwrap_or(panel.size_range.start / 2.)
+    }
+
+    /// Collapse or expand the collapsible panels beside the resize handle by the new `size`
+    /// of the panel at the `ix`.
+    ///
+    /// Returns true if the resize is handled, otherwise continue the normal resizing.
+    fn snap_collapsible(&mut self, ix: usize, size: Pixels) -> bool {
+        let next_ix = ix + 1;
+        let pair_size = self.sizes[ix] + self.sizes[next_ix];
+
+        for (panel_ix, panel_size) in [(ix, size), (next_ix, pair_size - size)] {
+            if !self.panels[panel_ix].collapsible {
+                continue;
+            }
+
+            let threshold = self.snap_threshold(panel_ix);
+            let collapsed = self.panels[panel_ix].collapsed;
+            if panel_size < threshold {
+                if !collapsed {
+                    self.collapse_panel_into(panel_ix, if panel_ix == ix { next_ix } else { ix });
+                }
+                return true;
+            }
+
+            if collapsed {
+                let min_size = self.panels[panel_ix].size_range.start;
+                self.expand_panel(panel_ix, Some(panel_size.max(min_size)));
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Collapse the panel at the `ix` and give the size to the `neighbor_ix`.
+    fn collapse_panel_into(&mut self, ix: usize, neighbor_ix: usize) {
+        let size = self.sizes[ix];
+        let min_size = self.panels[ix].size_range.start;
+        // Restore to the size before the dragging if collapsed by dragging.
+        let restore_size = self
+            .drag_start_sizes
+            .get(ix)
+            .copied()
+            .unwrap_or(size)
+            .max(min_size);
+        self.panels[ix].restore_size = Some(restore_size);
+        self.panels[ix].collapsed = true;
+        self.set_size(neighbor_ix, self.sizes[neighbor_ix] + size);
+        self.set_size(ix, px(0.));
+    }
+
     pub(crate) fn total_size(&self) -> Pixels {
         self.sizes.iter().map(|s| s.as_f32()).sum::<f32>().into()
     }
 
+    pub(crate) fn start_resizing(&mut self, ix: usize) {
+        self.resizing_panel_ix = Some(ix);
+        self.drag_start_sizes = self.sizes.clone();
+    }
+
     pub(crate) fn done_resizing(&mut self, cx: &mut Context<Self>) {
         self.resizing_panel_ix = None;
+        self.drag_start_sizes.clear();
         cx.emit(ResizablePanelEvent::Resized);
     }
 
@@ -173,6 +382,11 @@ impl ResizableState {
             return;
         }
 
+        if self.snap_collapsible(ix, size) {
+            cx.notify();
+            return;
+        }
+
         let size_range = self.panel_size_range(ix);
         let new_size = size.clamp(size_range.start, size_range.end);
         let is_expand = move_changed > px(0.);
@@ -230,5 +444,116 @@ impl EventEmitter<ResizablePanelEvent> for ResizableState {}
 pub(crate) struct ResizablePanelState {
     pub size: Option<Pixels>,
     pub size_range: Range<Pixels>,
+    /// The initial size to reset the panel.
+    initial_size: Option<Pixels>,
+    collapsible: bool,
+    collapsed: bool,
+    snap_threshold: Option<Pixels>,
+    /// The size before the panel is collapsed.
+    restore_size: Option<Pixels>,
     bounds: Bounds<Pixels>,
 }
+
+/// The constraints of a [`ResizablePanel`] synced to the [`ResizableState`].
+#[derive(Debug, Clone)]
+pub(crate) struct PanelConstraints {
+    pub size_range: Range<Pixels>,
+    pub initial_size: Option<Pixels>,
+    pub collapsible: bool,
+    pub snap_threshold: Option<Pixels>,
+}
+
+/// Used to serialize and deserialize the layout of the [`ResizableState`].
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResizableLayout {
+    /// The sizes of the panels, the collapsed panels keep the size before collapsed.
+    pub sizes: Vec<Pixels>,
+    /// The indexes of the collapsed panels.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collapsed: Vec<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{px, Axis, Bounds, Pixels};
+
+    use super::{ResizableLayout, ResizablePanelState, ResizableState};
+
+    fn new_state(sizes: &[f32], collapsible: &[bool]) -> ResizableState {
+        ResizableState {
+            axis: Axis::Horizontal,
+            panels: collapsible
+                .iter()
+                .map(|collapsible| ResizablePanelState {
+                    size_range: px(100.)..Pixels::MAX,
+                    collapsible: *collapsible,
+                    ..Default::default()
+                })
+                .collect(),
+            sizes: sizes.iter().map(|size| px(*size)).collect(),
+            resizing_panel_ix: None,
+            drag_start_sizes: vec![],
+            bounds: Bounds::default(),
+        }
+    }
+
+    #[test]
+    fn test_snap_collapsible() {
+        let mut state = new_state(&[200., 400.], &[true, false]);
+        state.drag_start_sizes = state.sizes.clone();
+
+        // Not reach the threshold (half of the min size).
+        assert!(!state.snap_collapsible(0, px(60.)));
+        assert!(!state.is_collapsed(0));
+
+        assert!(state.snap_collapsible(0, px(40.)));
+        assert!(state.is_collapsed(0));
+        assert_eq!(state.sizes, vec![px(0.), px(600.)]);
+        assert_eq!(state.panels[0].restore_size, Some(px(200.)));
+
+        // Keep collapsed until dragged over the threshold.
+        assert!(state.snap_collapsible(0, px(30.)));
+        assert!(state.is_collapsed(0));
+        assert!(state.snap_collapsible(0, px(60.)));
+        assert!(!state.is_collapsed(0));
+        assert_eq!(state.sizes, vec![px(100.), px(500.)]);
+
+        // The next panel is not collapsible.
+        assert!(!state.snap_collapsible(0, px(580.)));
+    }
+
+    #[test]
+    fn test_collapse_and_expand() {
+        let mut state = new_state(&[200., 300., 100.], &[false, false, true]);
+        assert!(state.collapse_panel(2));
+        assert!(!state.collapse_panel(2));
+        assert_eq!(state.sizes, vec![px(200.), px(400.), px(0.)]);
+
+        assert!(state.expand_panel(2, None));
+        assert!(!state.expand_panel(2, None));
+        assert_eq!(state.sizes, vec![px(200.), px(300.), px(100.)]);
+    }
+
+    #[test]
+    fn test_dump_and_load() {
+        let mut state = new_state(&[200., 300.], &[true, false]);
+        state.collapse_panel(0);
+        let layout = state.dump();
+        assert_eq!(
+            layout,
+            ResizableLayout {
+                sizes: vec![px(200.), px(500.)],
+                collapsed: vec![0],
+            }
+        );
+
+        let json = serde_json::to_string(&layout).unwrap();
+        let layout: ResizableLayout = serde_json::from_str(&json).unwrap();
+
+        let mut state = new_state(&[], &[]);
+        state.apply_layout(&layout);
+        assert!(state.is_collapsed(0));
+        assert_eq!(state.sizes, vec![px(0.), px(500.)]);
+        assert_eq!(state.panels[0].restore_size, Some(px(200.)));
+    }
+}
@@ -1,14 +1,15 @@
 use std::ops::{Deref, Range};
 
 use gpui::{
-    canvas, div, prelude::FluentBuilder, AnyElement, App, AppContext, Axis, Bounds, Context,
-    Element, ElementId, Empty, Entity, EventEmitter, InteractiveElement as _, IntoElement, IsZero,
-    MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, Render, RenderOnce, Style, Styled, Window,
+    canvas, div, prelude::FluentBuilder, px, Along as _, AnyElement, App, AppContext, Axis, Bounds,
+    Context, DefiniteLength, Element, ElementId, Empty, Entity, EventEmitter,
+    InteractiveElement as _, IntoElement, IsZero, MouseMoveEvent, MouseUpEvent, ParentElement,
+    Pixels, Render, RenderOnce, StatefulInteractiveElement as _, Style, Styled, Window,
 };
 
-use crate::{h_flex, resizable::PANEL_MIN_SIZE, v_flex, AxisExt};
+use crate::{h_flex, resizable::PANEL_MIN_SIZE, v_flex, ActiveTheme as _, AxisExt, Icon, IconName};
 
-use super::{resizable_panel, resize_handle, ResizableState};
+use super::{resizable_panel, resize_handle, PanelConstraints, ResizableState};
 
 pub enum ResizablePanelEvent {
     Resized,
@@ -145,6 +146,10 @@ pub struct ResizablePanel {
     initial_size: Option<Pixels>,
     /// size range limit of this panel.
     size_range: Range<Pixels>,
+    min_size: Option<DefiniteLength>,
+    max_size: Option<DefiniteLength>,
+    collapsible: bool,
+    snap_threshold: Option<Pixels>,
     children: Vec<AnyElement>,
     visible: bool,
 }
@@ -156,6 +161,10 @@ impl ResizablePanel {
             initial_size: None,
             state: None,
             size_range: (PANEL_MIN_SIZE..Pixels::MAX),
+            min_size: None,
+            max_size: None,
+            collapsible: false,
+            snap_threshold: None,
             axis: Axis::Horizontal,
             children: vec![],
             visible: true,
@@ -185,23 +194,125 @@ impl ResizablePanel {
         self.size_range = range.into();
         self
     }
+
+    /// Set the minimum size in pixels or the percentage of the group, e.g.: `px(200.)` or `relative(0.2)`.
+    ///
+    /// This overrides the start of the [`ResizablePanel::size_range`].
+    pub fn min_size(mut self, size: impl Into<DefiniteLength>) -> Self {
+        self.min_size = Some(size.into());
+        self
+    }
+
+    /// Set the maximum size in pixels or the percentage of the group, e.g.: `px(600.)` or `relative(0.5)`.
+    ///
+    /// This overrides the end of the [`ResizablePanel::size_range`].
+    pub fn max_size(mut self, size: impl Into<DefiniteLength>) -> Self {
+        self.max_size = Some(size.into());
+        self
+    }
+
+    /// Set the panel to be collapsible, default is false.
+    ///
+    /// The panel is collapsed when it is dragged smaller than the [`ResizablePanel::snap_threshold`],
+    /// and a restore handle is shown on the collapsed panel to expand it.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Set the size to snap the collapsible panel to collapse, default is half of the minimum size.
+    pub fn snap_threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.snap_threshold = Some(threshold.into());
+        self
+    }
+
+    /// Resolve the size range by the [`ResizablePanel::min_size`] and [`ResizablePanel::max_size`].
+    fn resolved_size_range(&self, group_size: Pixels, window: &Window) -> Range<Pixels> {
+        let resolve =
+            |length: DefiniteLength| length.to_pixels(group_size.into(), window.rem_size());
+        let start = self.min_size.map_or(self.size_range.start, resolve);
+        let end = self.max_size.map_or(self.size_range.end, resolve);
+        start..end.max(start)
+    }
+
+    fn render_restore_handle(&self, state: Entity<ResizableState>, cx: &App) -> impl IntoElement {
+        let ix = self.panel_ix;
+        let is_first = ix == 0;
+        let icon = match (self.axis.is_horizontal(), is_first) {
+            (true, true) => IconName::ChevronRight,
+            (true, false) => IconName::ChevronLeft,
+            (false, true) => IconName::ChevronDown,
+            (false, false) => IconName::ChevronUp,
+        };
+
+        div()
+            .id("restore-handle")
+            .absolute()
+            .flex()
+            .items_center()
+            .justify_center()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .bg(cx.theme().background)
+            .text_color(cx.theme().muted_foreground)
+            .hover(|this| this.bg(cx.theme().accent))
+            .cursor_pointer()
+            .when(self.axis.is_horizontal(), |this| {
+                this.top(gpui::relative(0.5))
+                    .mt(px(-16.))
+                    .w(px(12.))
+                    .h(px(32.))
+                    .map(|this| match is_first {
+                        true => this.left_0(),
+                        false => this.right_0(),
+                    })
+            })
+            .when(self.axis.is_vertical(), |this| {
+                this.left(gpui::relative(0.5))
+                    .ml(px(-16.))
+                    .w(px(32.))
+                    .h(px(12.))
+                    .map(|this| match is_first {
+                        true => this.top_0(),
+                        false => this.bottom_0(),
+                    })
+            })
+            .child(Icon::new(icon).size_3())
+            .on_click(move |_, _, cx| {
+                cx.stop_propagation();
+                state.update(cx, |state, cx| state.expand(ix, cx));
+            })
+    }
 }
 
 impl RenderOnce for ResizablePanel {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         if !self.visible {
             return div().id(("resizable-panel", self.panel_ix));
         }
 
         let state = self
             .state
+            .clone()
             .expect("BUG: The `state` in ResizablePanel should be present.");
-        let panel_state = state
-            .read(cx)
-            .panels
-            .get(self.panel_ix)
-            .expect("BUG: The `index` of ResizablePanel should be one of in `state`.");
-        let size_range = self.size_range.clone();
+        let (panel_state, group_size) = {
+            let state = state.read(cx);
+            let panel_state = state
+                .panels
+                .get(self.panel_ix)
+                .expect("BUG: The `index` of ResizablePanel should be one of in `state`.")
+                .clone();
+            (panel_state, state.bounds.size.along(self.axis))
+        };
+        let size_range = self.resolved_size_range(group_size, window);
+        let constraints = PanelConstraints {
+            size_range: size_range.clone(),
+            initial_size: self.initial_size,
+            collapsible: self.collapsible,
+            snap_threshold: self.snap_threshold,
+        };
+        let collapsed = panel_state.collapsed;
 
         div()
             .id(("resizable-panel", self.panel_ix))
@@ -209,11 +320,13 @@ impl RenderOnce for ResizablePanel {
             .flex_grow()
             .size_full()
             .relative()
-            .when(self.axis.is_vertical(), |this| {
-                this.min_h(size_range.start).max_h(size_range.end)
-            })
-            .when(self.axis.is_horizontal(), |this| {
-                this.min_w(size_range.start).max_w(size_range.end)
+            .when(!collapsed, |this| {
+                this.when(self.axis.is_vertical(), |this| {
+                    this.min_h(size_range.start).max_h(size_range.end)
+                })
+                .when(self.axis.is_horizontal(), |this| {
+                    this.min_w(size_range.start).max_w(size_range.end)
+                })
             })
             // 1. initial_size is None, to use auto size.
             // 2. initial_size is Some and size is none, to use the initial size of the panel for first time render.
@@ -232,13 +345,19 @@ impl RenderOnce for ResizablePanel {
                 Some(size) => this.flex_basis(size),
                 None => this,
             })
+            .when(collapsed, |this| {
+                this.flex_none()
+                    .flex_basis(px(0.))
+                    .when(self.axis.is_horizontal(), |this| this.w_0())
+                    .when(self.axis.is_vertical(), |this| this.h_0())
+            })
             .child({
                 canvas(
                     {
                         let state = state.clone();
                         move |bounds, _, cx| {
                             state.update(cx, |state, cx| {
-                                state.update_panel_size(self.panel_ix, bounds, self.size_range, cx)
+                                state.update_panel_size(self.panel_ix, bounds, constraints, cx)
                             })
                         }
                     },
@@ -247,20 +366,29 @@ impl RenderOnce for ResizablePanel {
                 .absolute()
                 .size_full()
             })
-            .children(self.children)
+            .when(collapsed, |this| {
+                this.child(self.render_restore_handle(state.clone(), cx))
+            })
+            .when(!collapsed, |this| this.children(self.children))
             .when(self.panel_ix > 0, |this| {
                 let ix = self.panel_ix - 1;
-                this.child(resize_handle(("resizable-handle", ix), self.axis).on_drag(
-                    DragPanel((ix, self.axis)),
-                    move |drag_panel, _, _, cx| {
-                        cx.stop_propagation();
-                        // Set current resizing panel ix
-                        state.update(cx, |state, _| {
-                            state.resizing_panel_ix = Some(ix);
-                        });
-                        cx.new(|_| drag_panel.deref().clone())
-                    },
-                ))
+                this.child(
+                    resize_handle(("resizable-handle", ix), self.axis)
+                        .on_drag(DragPanel((ix, self.axis)), {
+                            let state = state.clone();
+                            move |drag_panel, _, _, cx| {
+                                cx.stop_propagation();
+                                // Set current resizing panel ix
+                                state.update(cx, |state, _| {
+                                    state.start_resizing(ix);
+                                });
+                                cx.new(|_| drag_panel.deref().clone())
+                            }
+                        })
+                        .on_double_click(move |_, cx| {
+                            state.update(cx, |state, cx| state.reset(cx));
+                        }),
+                )
             })
     }
 }
@@ -25,6 +25,7 @@ pub(crate) struct ResizeHandle<T: 'static, E: 'static + Render> {
     drag_value: Option<Rc<T>>,
     placement: Option<DockPlacement>,
     on_drag: Option<Rc<dyn Fn(&Point<Pixels>, &mut Window, &mut App) -> Entity<E>>>,
+    on_double_click: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
@@ -33,6 +34,7 @@ impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
         Self {
             id: id.clone(),
             on_drag: None,
+            on_double_click: None,
             drag_value: None,
             placement: None,
             axis,
@@ -52,6 +54,11 @@ impl<T: 'static, E: 'static + Render> ResizeHandle<T, E> {
         self
     }
 
+    pub(crate) fn on_double_click(mut self, f: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_double_click = Some(Rc::new(f));
+        self
+    }
+
     pub(crate) fn placement(mut self, placement: DockPlacement) -> Self {
         self.placement = Some(placement);
         self
@@ -123,6 +130,13 @@ impl<T: 'static, E: 'static + Render> Element for ResizeHandle<T, E> {
                         move |_, position, window, cx| on_drag(&position, window, cx),
                     )
                 })
+                .when_some(self.on_double_click.clone(), |this, on_double_click| {
+                    this.on_click(move |event, window, cx| {
+                        if event.click_count() == 2 {
+                            on_double_click(window, cx);
+                        }
+                    })
+                })
                 .map(|this| match self.placement {
                     Some(DockPlacement::Left) => {
                         // Special for Left Dock
@@ -0,0 +1,261 @@
+//! The breakpoints and the container queries for the adaptive layouts.
+//!
+//! ```ignore
+//! responsive("toolbar")
+//!     .flex()
+//!     .flex_col()
+//!     .when_at_least(Breakpoint::Md, |this| this.flex_row())
+//!     .when_narrower_than(px(320.), |this| this.text_xs())
+//!     .children(items)
+//! ```
+use std::fmt::{self, Display};
+
+use gpui::{
+    canvas, div, px, AnyElement, App, Div, ElementId, InteractiveElement as _, IntoElement,
+    ParentElement, Pixels, RenderOnce, StyleRefinement, Styled, Window,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ActiveTheme as _, StyledExt as _};
+
+/// The width breakpoints, the thresholds are configured by [`crate::Theme::breakpoints`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Breakpoint {
+    /// All the breakpoints from small to large.
+    pub const ALL: [Breakpoint; 4] = [Self::Sm, Self::Md, Self::Lg, Self::Xl];
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sm => write!(f, "sm"),
+            Self::Md => write!(f, "md"),
+            Self::Lg => write!(f, "lg"),
+            Self::Xl => write!(f, "xl"),
+        }
+    }
+}
+
+/// The minimum widths of the [`Breakpoint`]s, default is same as Tailwind CSS.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Breakpoints {
+    /// Default is 640px.
+    pub sm: Pixels,
+    /// Default is 768px.
+    pub md: Pixels,
+    /// Default is 1024px.
+    pub lg: Pixels,
+    /// Default is 1280px.
+    pub xl: Pixels,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: px(640.),
+            md: px(768.),
+            lg: px(1024.),
+            xl: px(1280.),
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Returns the minimum width of the `breakpoint`.
+    pub fn width(&self, breakpoint: Breakpoint) -> Pixels {
+        match breakpoint {
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+
+    /// Returns the largest breakpoint reached by the `width`, None if less than `sm`.
+    pub fn resolve(&self, width: Pixels) -> Option<Breakpoint> {
+        Breakpoint::ALL
+            .into_iter()
+            .rev()
+            .find(|breakpoint| width >= self.width(*breakpoint))
+    }
+}
+
+/// Returns the last measured width of the container with the `id`,
+/// and a canvas to measure it, which must be added into the `relative` container.
+pub(crate) fn measure_width(
+    id: &ElementId,
+    window: &mut Window,
+    cx: &mut App,
+) -> (Pixels, impl IntoElement) {
+    let state = window.use_keyed_state(
+        ElementId::NamedChild(Box::new(id.clone()), "width".into()),
+        cx,
+        |_, _| px(0.),
+    );
+    let width = *state.read(cx);
+
+    let measure = canvas(
+        move |bounds, _, cx| {
+            state.update(cx, |width, cx| {
+                if *width != bounds.size.width {
+                    *width = bounds.size.width;
+                    cx.notify();
+                }
+            })
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .size_full();
+
+    (width, measure)
+}
+
+enum Condition {
+    WiderThan(Pixels),
+    NarrowerThan(Pixels),
+    AtLeast(Breakpoint),
+    Below(Breakpoint),
+}
+
+impl Condition {
+    fn matches(&self, width: Pixels, breakpoints: &Breakpoints) -> bool {
+        match self {
+            Self::WiderThan(min_width) => width > *min_width,
+            Self::NarrowerThan(max_width) => width < *max_width,
+            Self::AtLeast(breakpoint) => width >= breakpoints.width(*breakpoint),
+            Self::Below(breakpoint) => width < breakpoints.width(*breakpoint),
+        }
+    }
+}
+
+/// Create a [`Responsive`] container.
+pub fn responsive(id: impl Into<ElementId>) -> Responsive {
+    Responsive::new(id)
+}
+
+/// A container to change the style by its own measured width (not the window size),
+/// the changes are applied in the next frame after the width is changed.
+#[derive(IntoElement)]
+pub struct Responsive {
+    id: ElementId,
+    style: StyleRefinement,
+    children: Vec<AnyElement>,
+    conditions: Vec<(Condition, Box<dyn FnOnce(Div) -> Div>)>,
+}
+
+impl Responsive {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            children: vec![],
+            conditions: vec![],
+        }
+    }
+
+    /// Apply the `f` when the width is greater than the `width`.
+    pub fn when_wider_than(
+        mut self,
+        width: impl Into<Pixels>,
+        f: impl FnOnce(Div) -> Div + 'static,
+    ) -> Self {
+        self.conditions
+            .push((Condition::WiderThan(width.into()), Box::new(f)));
+        self
+    }
+
+    /// Apply the `f` when the width is less than the `width`.
+    pub fn when_narrower_than(
+        mut self,
+        width: impl Into<Pixels>,
+        f: impl FnOnce(Div) -> Div + 'static,
+    ) -> Self {
+        self.conditions
+            .push((Condition::NarrowerThan(width.into()), Box::new(f)));
+        self
+    }
+
+    /// Apply the `f` when the width reaches the `breakpoint` of the theme.
+    pub fn when_at_least(
+        mut self,
+        breakpoint: Breakpoint,
+        f: impl FnOnce(Div) -> Div + 'static,
+    ) -> Self {
+        self.conditions
+            .push((Condition::AtLeast(breakpoint), Box::new(f)));
+        self
+    }
+
+    /// Apply the `f` when the width is less than the `breakpoint` of the theme.
+    pub fn when_below(
+        mut self,
+        breakpoint: Breakpoint,
+        f: impl FnOnce(Div) -> Div + 'static,
+    ) -> Self {
+        self.conditions
+            .push((Condition::Below(breakpoint), Box::new(f)));
+        self
+    }
+}
+
+impl ParentElement for Responsive {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Responsive {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Responsive {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (width, measure) = measure_width(&self.id, window, cx);
+        let breakpoints = cx.theme().breakpoints;
+
+        let mut el = div().relative().refine_style(&self.style);
+        for (condition, f) in self.conditions {
+            if condition.matches(width, &breakpoints) {
+                el = f(el);
+            }
+        }
+
+        el.id(self.id).child(measure).children(self.children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{Breakpoint, Breakpoints, Condition};
+
+    #[test]
+    fn test_breakpoints() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.resolve(px(320.)), None);
+        assert_eq!(breakpoints.resolve(px(640.)), Some(Breakpoint::Sm));
+        assert_eq!(breakpoints.resolve(px(800.)), Some(Breakpoint::Md));
+        assert_eq!(breakpoints.resolve(px(1100.)), Some(Breakpoint::Lg));
+        assert_eq!(breakpoints.resolve(px(2000.)), Some(Breakpoint::Xl));
+        assert_eq!(Breakpoint::Md.to_string(), "md");
+
+        assert!(Condition::WiderThan(px(100.)).matches(px(101.), &breakpoints));
+        assert!(!Condition::WiderThan(px(100.)).matches(px(100.), &breakpoints));
+        assert!(Condition::NarrowerThan(px(100.)).matches(px(99.), &breakpoints));
+        assert!(Condition::AtLeast(Breakpoint::Md).matches(px(768.), &breakpoints));
+        assert!(!Condition::AtLeast(Breakpoint::Md).matches(px(767.), &breakpoints));
+        assert!(Condition::Below(Breakpoint::Sm).matches(px(639.), &breakpoints));
+    }
+}
@@ -0,0 +1,671 @@
+use std::ops::Range;
+
+use gpui::SharedString;
+use markdown::{
+    mdast::{self, Node},
+    ParseOptions,
+};
+
+/// The inline marks that can be toggled, see [`super::RichTextEditor::toggle_mark`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mark {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    /// Inline code.
+    Code,
+}
+
+/// The inline styles of a [`Span`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Marks {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub code: bool,
+    /// The URL of the link.
+    pub link: Option<SharedString>,
+}
+
+impl Marks {
+    /// Returns true if the `mark` is set.
+    pub fn has(&self, mark: Mark) -> bool {
+        match mark {
+            Mark::Bold => self.bold,
+            Mark::Italic => self.italic,
+            Mark::Underline => self.underline,
+            Mark::Strikethrough => self.strikethrough,
+            Mark::Code => self.code,
+        }
+    }
+
+    /// Set or unset the `mark`.
+    pub fn set(&mut self, mark: Mark, on: bool) {
+        match mark {
+            Mark::Bold => self.bold = on,
+            Mark::Italic => self.italic = on,
+            Mark::Underline => self.underline = on,
+            Mark::Strikethrough => self.strikethrough = on,
+            Mark::Code => self.code = on,
+        }
+    }
+}
+
+/// A run of text with the same [`Marks`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub marks: Marks,
+}
+
+impl Span {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            marks: Marks::default(),
+        }
+    }
+
+    /// Set the marks of the span.
+    pub fn marks(mut self, marks: Marks) -> Self {
+        self.marks = marks;
+        self
+    }
+}
+
+/// The kind of a [`Block`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    #[default]
+    Paragraph,
+    /// Heading with the level in 1..=6.
+    Heading(u8),
+    BulletedList,
+    NumberedList,
+    Blockquote,
+}
+
+impl BlockKind {
+    /// Returns true if the block is a list item.
+    pub fn is_list(&self) -> bool {
+        matches!(self, Self::BulletedList | Self::NumberedList)
+    }
+}
+
+/// A block of the [`Document`], the text of the block does not contain newlines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Block {
+    pub kind: BlockKind,
+    pub spans: Vec<Span>,
+}
+
+impl Block {
+    pub fn new(kind: BlockKind) -> Self {
+        Self {
+            kind,
+            spans: vec![],
+        }
+    }
+
+    /// Add a span to the end of the block.
+    pub fn span(mut self, span: impl Into<Span>) -> Self {
+        self.spans.push(span.into());
+        self.normalize();
+        self
+    }
+
+    /// Returns the plain text of the block.
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// Returns the byte length of the text.
+    pub fn len(&self) -> usize {
+        self.spans.iter().map(|span| span.text.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the spans in the byte `range`, the spans on the edges are cut.
+    pub(crate) fn slice(&self, range: Range<usize>) -> Vec<Span> {
+        let mut spans = vec![];
+        let mut offset = 0;
+        for span in self.spans.iter() {
+            let span_range = offset..offset + span.text.len();
+            offset = span_range.end;
+
+            let start = range.start.max(span_range.start);
+            let end = range.end.min(span_range.end);
+            if start < end {
+                spans.push(Span {
+                    text: span.text[start - span_range.start..end - span_range.start].to_string(),
+                    marks: span.marks.clone(),
+                });
+            }
+        }
+        spans
+    }
+
+    /// Replace the byte `range` with the `spans`.
+    pub(crate) fn splice(&mut self, range: Range<usize>, spans: Vec<Span>) {
+        let mut new_spans = self.slice(0..range.start);
+        new_spans.extend(spans);
+        new_spans.extend(self.slice(range.end..self.len()));
+        self.spans = new_spans;
+        self.normalize();
+    }
+
+    /// Replace the byte `range` with the `text` in the `marks`.
+    pub(crate) fn replace(&mut self, range: Range<usize>, text: &str, marks: Marks) {
+        self.splice(range, vec![Span::new(text).marks(marks)]);
+    }
+
+    /// Update the marks of the text in the byte `range`.
+    pub(crate) fn update_marks(&mut self, range: Range<usize>, f: impl Fn(&mut Marks)) {
+        let mut spans = self.slice(range.clone());
+        for span in spans.iter_mut() {
+            f(&mut span.marks);
+        }
+        self.splice(range, spans);
+    }
+
+    /// Returns the marks of the span at the byte `offset`, prefer the span before the offset.
+    pub(crate) fn marks_at(&self, offset: usize) -> Marks {
+        let mut start = 0;
+        for span in self.spans.iter() {
+            let end = start + span.text.len();
+            if offset > start && offset <= end {
+                return span.marks.clone();
+            }
+            start = end;
+        }
+
+        self.spans
+            .first()
+            .map(|span| span.marks.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the marks for the text inserted at the byte `offset`.
+    ///
+    /// The marks are inherited from the text before, but the link is not extended at its edges.
+    pub(crate) fn marks_for_insert(&self, offset: usize) -> Marks {
+        let mut marks = self.marks_at(offset);
+        if offset == 0 || offset >= self.len() || self.marks_at(offset + 1).link != marks.link {
+            marks.link = None;
+        }
+        marks
+    }
+
+    /// Returns true if all the text in the byte `range` has the `mark`.
+    ///
+    /// For an empty range, returns the mark of the text before it.
+    pub(crate) fn is_marked(&self, range: Range<usize>, mark: Mark) -> bool {
+        if range.is_empty() {
+            return self.marks_at(range.start).has(mark);
+        }
+
+        self.slice(range).iter().all(|span| span.marks.has(mark))
+    }
+
+    /// Returns the byte range of the link at the `offset`, the adjacent spans with the same link
+    /// are in one link.
+    pub(crate) fn link_range_at(&self, offset: usize) -> Option<Range<usize>> {
+        let mut links: Vec<(Range<usize>, &SharedString)> = vec![];
+        let mut start = 0;
+        for span in self.spans.iter() {
+            let end = start + span.text.len();
+            if let Some(link) = span.marks.link.as_ref() {
+                match links.last_mut() {
+                    Some((range, last)) if range.end == start && *last == link => range.end = end,
+                    _ => links.push((start..end, link)),
+                }
+            }
+            start = end;
+        }
+
+        links
+            .into_iter()
+            .map(|(range, _)| range)
+            .find(|range| offset >= range.start && offset <= range.end)
+    }
+
+    /// Remove the empty spans and merge the adjacent spans with the same marks.
+    pub(crate) fn normalize(&mut self) {
+        let mut spans: Vec<Span> = Vec::with_capacity(self.spans.len());
+        for span in std::mem::take(&mut self.spans) {
+            if span.text.is_empty() {
+                continue;
+            }
+
+            match spans.last_mut() {
+                Some(last) if last.marks == span.marks => last.text.push_str(&span.text),
+                _ => spans.push(span),
+            }
+        }
+        self.spans = spans;
+    }
+}
+
+impl From<&str> for Span {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+/// The structured document of the [`super::RichTextEditor`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+}
+
+impl Document {
+    pub fn new(blocks: impl IntoIterator<Item = Block>) -> Self {
+        Self {
+            blocks: blocks.into_iter().collect(),
+        }
+    }
+
+    /// Returns the plain text of the document, the blocks are separated by newlines.
+    pub fn text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|block| block.text())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Parse the document from Markdown.
+    ///
+    /// The unsupported elements (e.g.: tables, images) are ignored or kept as plain text,
+    /// nested lists are flattened, code blocks are converted to paragraphs in inline code,
+    /// and the underline is parsed from the `<u>` tags.
+    pub fn from_markdown(text: &str) -> Self {
+        let mut blocks = vec![];
+        if let Ok(root) = markdown::to_mdast(text, &ParseOptions::gfm()) {
+            parse_block(&root, None, &mut blocks);
+        }
+        Self { blocks }
+    }
+
+    /// Export the document to Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let mut number = 0;
+        let mut prev_kind: Option<BlockKind> = None;
+
+        for block in self.blocks.iter() {
+            let kind = block.kind;
+            if let Some(prev_kind) = prev_kind {
+                if kind.is_list() && prev_kind == kind {
+                    out.push('\n');
+                } else if kind == BlockKind::Blockquote && prev_kind == kind {
+                    out.push_str("\n>\n");
+                } else {
+                    out.push_str("\n\n");
+                }
+            }
+            number = if kind == BlockKind::NumberedList && prev_kind == Some(kind) {
+                number + 1
+            } else {
+                1
+            };
+            prev_kind = Some(kind);
+
+            let inline = inline_markdown(&block.spans);
+            match kind {
+                BlockKind::Paragraph => out.push_str(&escape_block_start(inline.trim_start())),
+                BlockKind::Heading(level) => {
+                    out.push_str(&"#".repeat(level.clamp(1, 6) as usize));
+                    out.push(' ');
+                    out.push_str(&inline);
+                }
+                BlockKind::BulletedList => {
+                    out.push_str("- ");
+                    out.push_str(&escape_block_start(inline.trim_start()));
+                }
+                BlockKind::NumberedList => {
+                    out.push_str(&format!("{}. ", number));
+                    out.push_str(&escape_block_start(inline.trim_start()));
+                }
+                BlockKind::Blockquote => {
+                    out.push_str("> ");
+                    out.push_str(&escape_block_start(inline.trim_start()));
+                }
+            }
+        }
+
+        out
+    }
+}
+
+fn parse_block(node: &Node, kind: Option<BlockKind>, blocks: &mut Vec<Block>) {
+    match node {
+        Node::Heading(heading) => {
+            let mut block = Block::new(BlockKind::Heading(heading.depth));
+            parse_inlines(&heading.children, Marks::default(), &mut block.spans);
+            block.normalize();
+            blocks.push(block);
+        }
+        Node::Paragraph(paragraph) => {
+            let mut block = Block::new(kind.unwrap_or_default());
+            parse_inlines(&paragraph.children, Marks::default(), &mut block.spans);
+            block.normalize();
+            blocks.push(block);
+        }
+        Node::List(list) => {
+            let kind = if list.ordered {
+                BlockKind::NumberedList
+            } else {
+                BlockKind::BulletedList
+            };
+            for item in list.children.iter() {
+                parse_block(item, Some(kind), blocks);
+            }
+        }
+        Node::Blockquote(quote) => {
+            for child in quote.children.iter() {
+                parse_block(child, Some(BlockKind::Blockquote), blocks);
+            }
+        }
+        Node::Code(code) => {
+            let marks = Marks {
+                code: true,
+                ..Default::default()
+            };
+            for line in code.value.lines() {
+                blocks.push(
+                    Block::new(kind.unwrap_or_default()).span(Span::new(line).marks(marks.clone())),
+                );
+            }
+        }
+        _ => {
+            if let Some(children) = node.children() {
+                for child in children.iter() {
+                    parse_block(child, kind, blocks);
+                }
+            }
+        }
+    }
+}
+
+fn parse_inlines(nodes: &[Node], marks: Marks, spans: &mut Vec<Span>) {
+    // The `<u>` tags are siblings of the underlined nodes.
+    let mut marks = marks;
+    for node in nodes.iter() {
+        match node {
+            Node::Html(html) => match html.value.trim() {
+                "<u>" => marks.underline = true,
+                "</u>" => marks.underline = false,
+                _ => {}
+            },
+            _ => parse_inline(node, &marks, spans),
+        }
+    }
+}
+
+fn parse_inline(node: &Node, marks: &Marks, spans: &mut Vec<Span>) {
+    let mut marks = marks.clone();
+    let children = match node {
+        Node::Text(text) => {
+            spans.push(Span::new(text.value.replace('\n', " ")).marks(marks));
+            return;
+        }
+        Node::InlineCode(code) => {
+            marks.code = true;
+            spans.push(Span::new(code.value.clone()).marks(marks));
+            return;
+        }
+        Node::Break(_) => {
+            spans.push(Span::new(" ").marks(marks));
+            return;
+        }
+        Node::Image(image) => {
+            spans.push(Span::new(image.alt.clone()).marks(marks));
+            return;
+        }
+        Node::Strong(mdast::Strong { children, .. }) => {
+            marks.bold = true;
+            children
+        }
+        Node::Emphasis(mdast::Emphasis { children, .. }) => {
+            marks.italic = true;
+            children
+        }
+        Node::Delete(mdast::Delete { children, .. }) => {
+            marks.strikethrough = true;
+            children
+        }
+        Node::Link(link) => {
+            marks.link = Some(link.url.clone().into());
+            &link.children
+        }
+        _ => match node.children() {
+            Some(children) => children,
+            None => return,
+        },
+    };
+
+    parse_inlines(children, marks, spans);
+}
+
+/// Convert the spans to Markdown, the adjacent spans with the same link are in one link.
+fn inline_markdown(spans: &[Span]) -> String {
+    let mut out = String::new();
+    let mut ix = 0;
+    while ix < spans.len() {
+        let link = spans[ix].marks.link.clone();
+        let end = spans[ix..]
+            .iter()
+            .position(|span| span.marks.link != link)
+            .map_or(spans.len(), |len| ix + len);
+
+        let inner = spans[ix..end].iter().map(span_markdown).collect::<String>();
+        match link {
+            Some(url) => out.push_str(&format!("[{}]({})", inner, url)),
+            None => out.push_str(&inner),
+        }
+        ix = end;
+    }
+    out
+}
+
+fn span_markdown(span: &Span) -> String {
+    let text = span.text.as_str();
+    let marks = &span.marks;
+
+    // The delimiters must be next to the non-whitespace characters, e.g.: `**bold** text`.
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    let mut out = if marks.code {
+        code_markdown(core)
+    } else {
+        escape(core)
+    };
+    if marks.strikethrough {
+        out = format!("~~{}~~", out);
+    }
+    if marks.italic {
+        out = format!("*{}*", out);
+    }
+    if marks.bold {
+        out = format!("**{}**", out);
+    }
+    if marks.underline {
+        out = format!("<u>{}</u>", out);
+    }
+
+    format!("{}{}{}", leading, out, trailing)
+}
+
+/// Wrap the code in backticks, use more backticks than the ones in the code.
+fn code_markdown(code: &str) -> String {
+    let mut max_ticks = 0;
+    let mut ticks = 0;
+    for c in code.chars() {
+        if c == '`' {
+            ticks += 1;
+            max_ticks = max_ticks.max(ticks);
+        } else {
+            ticks = 0;
+        }
+    }
+
+    let fence = "`".repeat(max_ticks + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{} {} {}", fence, code, fence)
+    } else {
+        format!("{}{}{}", fence, code, fence)
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape the text at the start of a block that would be parsed as a block marker.
+fn escape_block_start(text: &str) -> String {
+    if text.starts_with(['#', '-', '+']) {
+        return format!("\\{}", text);
+    }
+
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && text[digits..].starts_with(['.', ')']) {
+        return format!("{}\\{}", &text[..digits], &text[digits..]);
+    }
+
+    text.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Block, BlockKind, Document, Mark, Marks, Span};
+
+    fn bold() -> Marks {
+        Marks {
+            bold: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_block_edit() {
+        let mut block = Block::new(BlockKind::Paragraph)
+            .span("Hello ")
+            .span(Span::new("world").marks(bold()));
+        assert_eq!(block.text(), "Hello world");
+
+        // Insert in the bold text inherits the bold.
+        let marks = block.marks_for_insert(8);
+        assert!(marks.bold);
+        block.replace(8..8, "!!", marks);
+        assert_eq!(block.spans.len(), 2);
+        assert_eq!(block.spans[1].text, "wo!!rld");
+
+        block.update_marks(0..5, |marks| marks.set(Mark::Italic, true));
+        assert!(block.is_marked(0..5, Mark::Italic));
+        assert!(!block.is_marked(0..6, Mark::Italic));
+        assert!(block.is_marked(6..13, Mark::Bold));
+
+        block.update_marks(0..13, |marks| *marks = Marks::default());
+        assert_eq!(block.spans, vec![Span::new("Hello wo!!rld")]);
+
+        // Replace across spans.
+        block.update_marks(6..13, |marks| marks.bold = true);
+        block.replace(3..8, "p, ", Marks::default());
+        assert_eq!(block.text(), "Help, !!rld");
+        assert_eq!(block.slice(6..11), vec![Span::new("!!rld").marks(bold())]);
+    }
+
+    #[test]
+    fn test_link_marks() {
+        let link = Marks {
+            link: Some("https://example.com".into()),
+            ..Default::default()
+        };
+        let block = Block::new(BlockKind::Paragraph)
+            .span("See ")
+            .span(Span::new("the link").marks(link.clone()))
+            .span(Span::new(" here").marks(Marks { bold: true, ..link }))
+            .span(" end");
+
+        assert_eq!(block.link_range_at(6), Some(4..17));
+        assert_eq!(block.link_range_at(17), Some(4..17));
+        assert_eq!(block.link_range_at(2), None);
+        // The link is not extended at the end.
+        assert_eq!(block.marks_for_insert(17).link, None);
+        assert!(block.marks_for_insert(6).link.is_some());
+    }
+
+    #[test]
+    fn test_markdown() {
+        let doc = Document::from_markdown(
+            "# Title\n\nHello **bold** and *italic*, ~~strike~~ <u>under</u> `code` [link](https://a.b).\n\n- one\n- two\n\n1. first\n2. second\n\n> quote\n>\n> more",
+        );
+        assert_eq!(doc.blocks.len(), 8);
+        assert_eq!(doc.blocks[0].kind, BlockKind::Heading(1));
+        assert_eq!(
+            doc.blocks[1].text(),
+            "Hello bold and italic, strike under code link."
+        );
+        let spans = &doc.blocks[1].spans;
+        assert!(spans[1].marks.bold);
+        assert!(spans[3].marks.italic);
+        assert!(spans[5].marks.strikethrough);
+        assert!(spans[7].marks.underline);
+        assert!(spans[9].marks.code);
+        assert_eq!(spans[11].marks.link, Some("https://a.b".into()));
+        assert_eq!(doc.blocks[2].kind, BlockKind::BulletedList);
+        assert_eq!(doc.blocks[5].kind, BlockKind::NumberedList);
+        assert_eq!(doc.blocks[7].kind, BlockKind::Blockquote);
+        assert_eq!(doc.blocks[7].text(), "more");
+
+        let markdown = doc.to_markdown();
+        assert_eq!(
+            markdown,
+            "# Title\n\nHello **bold** and *italic*, ~~strike~~ <u>under</u> `code` [link](https://a.b).\n\n- one\n- two\n\n1. first\n2. second\n\n> quote\n>\n> more"
+        );
+        assert_eq!(Document::from_markdown(&markdown), doc);
+    }
+
+    #[test]
+    fn test_markdown_escape() {
+        let doc = Document::new([
+            Block::new(BlockKind::Paragraph).span("# not a *heading*"),
+            Block::new(BlockKind::Paragraph).span("1. not a list"),
+            Block::new(BlockKind::Paragraph).span(Span::new("a`b").marks(Marks {
+                code: true,
+                ..Default::default()
+            })),
+            Block::new(BlockKind::Paragraph)
+                .span(Span::new("bold ").marks(bold()))
+                .span("text"),
+        ]);
+
+        let markdown = doc.to_markdown();
+        assert_eq!(
+            markdown,
+            "\\# not a \\*heading\\*\n\n1\\. not a list\n\n``a`b``\n\n**bold** text"
+        );
+        // The whitespace is moved out of the marks.
+        let parsed = Document::from_markdown(&markdown);
+        assert_eq!(parsed.text(), doc.text());
+        assert_eq!(parsed.blocks[3].spans[0], Span::new("bold").marks(bold()));
+    }
+}
@@ -0,0 +1,1219 @@
+use std::ops::Range;
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, relative, rems, Action, App, AppContext as _,
+    Context, Entity, EventEmitter, FocusHandle, Focusable, FontStyle, FontWeight, HighlightStyle,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, StrikethroughStyle, Styled, Subscription, UnderlineStyle,
+    Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    divider::Divider,
+    h_flex,
+    input::{self, ChangeSet, InputEvent, InputState, TextInput},
+    v_flex, ActiveTheme, Selectable as _, Sizable as _, StyledExt as _,
+};
+
+use super::{Block, BlockKind, Document, Mark, Marks, Span};
+
+actions!(
+    rich_text,
+    [
+        ToggleBold,
+        ToggleItalic,
+        ToggleUnderline,
+        ToggleStrikethrough,
+        ToggleCode,
+        EditLink
+    ]
+);
+
+const CONTEXT: &str = "RichTextEditor";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-b", ToggleBold, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-i", ToggleItalic, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-u", ToggleUnderline, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-x", ToggleStrikethrough, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-e", ToggleCode, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-k", EditLink, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-b", ToggleBold, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-i", ToggleItalic, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-u", ToggleUnderline, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-x", ToggleStrikethrough, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-e", ToggleCode, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-k", EditLink, Some(CONTEXT)),
+    ]);
+}
+
+/// Events emitted by the [`RichTextEditor`].
+#[derive(Clone, Debug)]
+pub enum RichTextEvent {
+    /// The document has been changed, with the new document.
+    Change(Document),
+}
+
+/// A block in the editor, the text is edited by an [`InputState`] and the marks are kept
+/// in sync by the edits of the input.
+struct EditorBlock {
+    block: Block,
+    input: Entity<InputState>,
+    /// The spans for the next edit of the input, to keep the marks of the text moved from other blocks.
+    insert_spans: Option<Vec<Span>>,
+    show_placeholder: bool,
+    _subscription: Subscription,
+}
+
+/// The URL input to edit the link of a range in a block.
+struct LinkEditor {
+    input: Entity<InputState>,
+    block_ix: usize,
+    range: Range<usize>,
+    _subscription: Subscription,
+}
+
+/// A WYSIWYG rich text editor, the document is a list of blocks (paragraphs, headings,
+/// list items and quotes) in inline marks, see [`Document`].
+///
+/// - Use the toolbar or the shortcuts (e.g.: `cmd-b`, `cmd-k`) to format the selected text,
+///   or toggle the marks of the next typed text if no selection.
+/// - The document can be imported from and exported to Markdown.
+///
+/// Each block is edited by its own [`InputState`], so there are some limits:
+///
+/// - The selection can't cross the blocks, e.g.: select and copy two paragraphs at once.
+/// - The undo history is per block, and the edits of the blocks (split, merge and the kind changes)
+///   can't be undone.
+pub struct RichTextEditor {
+    blocks: Vec<EditorBlock>,
+    /// The index of the focused block.
+    active: usize,
+    /// The marks toggled without selection, to apply to the text typed at `(block_ix, offset)`.
+    pending_marks: Option<(usize, usize, Marks)>,
+    placeholder: SharedString,
+    show_toolbar: bool,
+    link_editor: Option<LinkEditor>,
+}
+
+impl RichTextEditor {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            blocks: vec![Self::new_block(Block::default(), window, cx)],
+            active: 0,
+            pending_marks: None,
+            placeholder: SharedString::default(),
+            show_toolbar: true,
+            link_editor: None,
+        }
+    }
+
+    /// Set the placeholder shown when the document is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set whether to show the formatting toolbar, default is true.
+    pub fn toolbar(mut self, show_toolbar: bool) -> Self {
+        self.show_toolbar = show_toolbar;
+        self
+    }
+
+    /// Returns the document of the editor.
+    pub fn document(&self) -> Document {
+        Document::new(self.blocks.iter().map(|block| block.block.clone()))
+    }
+
+    /// Replace the document, this will clear the undo history.
+    pub fn set_document(
+        &mut self,
+        document: Document,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut blocks = document.blocks;
+        if blocks.is_empty() {
+            blocks.push(Block::default());
+        }
+
+        self.blocks = blocks
+            .into_iter()
+            .map(|block| Self::new_block(block, window, cx))
+            .collect();
+        self.active = 0;
+        self.pending_marks = None;
+        self.link_editor = None;
+        cx.notify();
+    }
+
+    /// Export the document to Markdown.
+    pub fn to_markdown(&self) -> String {
+        self.document().to_markdown()
+    }
+
+    /// Replace the document by the Markdown, see [`Document::from_markdown`].
+    pub fn set_markdown(&mut self, markdown: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_document(Document::from_markdown(markdown), window, cx);
+    }
+
+    /// Focus the editor, the cursor is kept in the last focused block.
+    pub fn focus(&self, window: &mut Window, cx: &mut App) {
+        self.focus_handle(cx).focus(window);
+    }
+
+    /// Returns the kind of the focused block.
+    pub fn active_block_kind(&self) -> BlockKind {
+        self.blocks[self.active].block.kind
+    }
+
+    /// Set the focused block to the `kind`, or back to paragraph if it is already the `kind`.
+    pub fn toggle_block_kind(&mut self, kind: BlockKind, cx: &mut Context<Self>) {
+        self.set_block_kind(
+            self.active,
+            toggled_kind(self.active_block_kind(), kind),
+            cx,
+        );
+    }
+
+    /// Returns true if the `mark` is active at the selection of the focused block.
+    pub fn is_mark_active(&self, mark: Mark, cx: &App) -> bool {
+        let block = &self.blocks[self.active].block;
+        let range = self.selected_range(cx);
+        if range.is_empty() {
+            return self
+                .pending_marks_at(self.active, range.start)
+                .unwrap_or_else(|| block.marks_for_insert(range.start))
+                .has(mark);
+        }
+
+        block.is_marked(range, mark)
+    }
+
+    /// Toggle the `mark` of the selected text, or the text to be typed if no selection.
+    pub fn toggle_mark(&mut self, mark: Mark, cx: &mut Context<Self>) {
+        let ix = self.active;
+        let range = self.selected_range(cx);
+        if range.is_empty() {
+            let mut marks = self
+                .pending_marks_at(ix, range.start)
+                .unwrap_or_else(|| self.blocks[ix].block.marks_for_insert(range.start));
+            marks.set(mark, !marks.has(mark));
+            self.pending_marks = Some((ix, range.start, marks));
+            cx.notify();
+            return;
+        }
+
+        let block = &mut self.blocks[ix].block;
+        let on = !block.is_marked(range.clone(), mark);
+        block.update_marks(range, |marks| marks.set(mark, on));
+        self.emit_change(cx);
+    }
+
+    /// Set the link of the selected text, or the link at the cursor if no selection.
+    ///
+    /// Set `None` to remove the link.
+    pub fn set_link(&mut self, url: Option<SharedString>, cx: &mut Context<Self>) {
+        let Some(range) = self.link_range(cx) else {
+            return;
+        };
+        self.set_link_in_range(self.active, range, url, cx);
+    }
+
+    fn new_block(block: Block, window: &mut Window, cx: &mut Context<Self>) -> EditorBlock {
+        let text = block.text();
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(1, usize::MAX)
+                .default_value(text)
+        });
+        let _subscription = cx.subscribe_in(&input, window, Self::on_input_event);
+
+        EditorBlock {
+            block,
+            input,
+            insert_spans: None,
+            show_placeholder: false,
+            _subscription,
+        }
+    }
+
+    fn selected_range(&self, cx: &App) -> Range<usize> {
+        self.blocks[self.active].input.read(cx).selected_range()
+    }
+
+    fn pending_marks_at(&self, ix: usize, offset: usize) -> Option<Marks> {
+        self.pending_marks
+            .as_ref()
+            .filter(|(block_ix, block_offset, _)| *block_ix == ix && *block_offset == offset)
+            .map(|(_, _, marks)| marks.clone())
+    }
+
+    /// Returns the selected range, or the range of the link at the cursor.
+    fn link_range(&self, cx: &App) -> Option<Range<usize>> {
+        let range = self.selected_range(cx);
+        if range.is_empty() {
+            self.blocks[self.active].block.link_range_at(range.start)
+        } else {
+            Some(range)
+        }
+    }
+
+    fn set_link_in_range(
+        &mut self,
+        ix: usize,
+        range: Range<usize>,
+        url: Option<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(block) = self.blocks.get_mut(ix) else {
+            return;
+        };
+        if range.end > block.block.len() {
+            return;
+        }
+
+        block
+            .block
+            .update_marks(range, |marks| marks.link = url.clone());
+        self.emit_change(cx);
+    }
+
+    fn set_block_kind(&mut self, ix: usize, kind: BlockKind, cx: &mut Context<Self>) {
+        self.blocks[ix].block.kind = kind;
+        self.emit_change(cx);
+    }
+
+    fn emit_change(&mut self, cx: &mut Context<Self>) {
+        cx.emit(RichTextEvent::Change(self.document()));
+        cx.notify();
+    }
+
+    fn focus_block(
+        &mut self,
+        ix: usize,
+        offset: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.active = ix;
+        self.blocks[ix].input.update(cx, |input, cx| {
+            input.move_to(offset, cx);
+            input.focus(window, cx);
+        });
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: &Entity<InputState>,
+        event: &InputEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(ix) = self.blocks.iter().position(|block| &block.input == input) else {
+            return;
+        };
+
+        match event {
+            InputEvent::Change(changes) => self.apply_changes(ix, changes, cx),
+            InputEvent::Focus => {
+                self.active = ix;
+                cx.notify();
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply the edits of the input to the spans of the block.
+    fn apply_changes(&mut self, ix: usize, changes: &ChangeSet, cx: &mut Context<Self>) {
+        let pending_marks = self.pending_marks.take();
+        let block = &mut self.blocks[ix];
+
+        for edit in changes.edits() {
+            match block.insert_spans.take() {
+                Some(spans)
+                    if spans
+                        .iter()
+                        .map(|span| span.text.as_str())
+                        .collect::<String>()
+                        == edit.new_text =>
+                {
+                    block.block.splice(edit.range.clone(), spans);
+                }
+                _ => {
+                    let marks = match pending_marks.as_ref() {
+                        Some((block_ix, offset, marks))
+                            if *block_ix == ix && *offset == edit.range.start =>
+                        {
+                            marks.clone()
+                        }
+                        _ => block.block.marks_for_insert(edit.range.start),
+                    };
+                    block
+                        .block
+                        .replace(edit.range.clone(), &edit.new_text, marks);
+                }
+            }
+        }
+
+        self.emit_change(cx);
+    }
+
+    /// Replace the selection and the text after it in the block `ix` with the `lines`,
+    /// the lines after the first one are inserted as new blocks, and the text after the selection
+    /// is moved to the last block.
+    fn insert_lines(
+        &mut self,
+        ix: usize,
+        lines: Vec<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some((first, rest)) = lines.split_first() else {
+            return;
+        };
+        if rest.is_empty() {
+            return;
+        }
+
+        let input = self.blocks[ix].input.clone();
+        let range = input.read(cx).selected_range();
+        let block = &self.blocks[ix].block;
+        let len = block.len();
+        let new_blocks = split_blocks(block, range.clone(), rest);
+
+        // The spans of the block are updated by the change event of the input.
+        input.update(cx, |input, cx| {
+            input.replace_range(range.start..len, first, window, cx);
+            input.clear_history();
+        });
+
+        let new_blocks = new_blocks
+            .into_iter()
+            .map(|block| Self::new_block(block, window, cx))
+            .collect::<Vec<_>>();
+        let last_ix = ix + new_blocks.len();
+        self.blocks.splice(ix + 1..ix + 1, new_blocks);
+        self.pending_marks = None;
+        self.focus_block(last_ix, rest[rest.len() - 1].len(), window, cx);
+        cx.notify();
+    }
+
+    /// Merge the block `ix` into the previous block.
+    fn merge_into_previous(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let prev_ix = ix - 1;
+        let removed = self.blocks.remove(ix);
+        let text = removed.block.text();
+        let offset = self.blocks[prev_ix].block.len();
+        self.pending_marks = None;
+
+        if text.is_empty() {
+            self.emit_change(cx);
+        } else {
+            // The spans of the block are updated by the change event of the input.
+            let prev = &mut self.blocks[prev_ix];
+            prev.insert_spans = Some(removed.block.spans);
+            prev.input.update(cx, |input, cx| {
+                input.replace_range(offset..offset, &text, window, cx);
+                input.clear_history();
+            });
+        }
+        self.focus_block(prev_ix, offset, window, cx);
+    }
+
+    fn on_action_enter(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+
+        let range = self.blocks[ix].input.read(cx).selected_range();
+        let edit = enter_edit(&self.blocks[ix].block, &range);
+        self.apply_block_edit(ix, edit, window, cx);
+    }
+
+    /// Apply the `edit` of the Enter or Backspace to the block `ix`.
+    fn apply_block_edit(
+        &mut self,
+        ix: usize,
+        edit: BlockEdit,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match edit {
+            BlockEdit::SetKind(kind) => self.set_block_kind(ix, kind, cx),
+            BlockEdit::InsertBefore(kind) => {
+                let new_block = Self::new_block(Block::new(kind), window, cx);
+                self.blocks.insert(ix, new_block);
+                self.pending_marks = None;
+                self.focus_block(ix + 1, 0, window, cx);
+                self.emit_change(cx);
+            }
+            BlockEdit::Split => {
+                self.insert_lines(ix, vec![String::new(), String::new()], window, cx)
+            }
+            BlockEdit::MergeIntoPrevious => self.merge_into_previous(ix, window, cx),
+        }
+    }
+
+    fn on_action_backspace(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.blocks[ix].input.read(cx).selected_range() != (0..0) {
+            return;
+        }
+        cx.stop_propagation();
+
+        if let Some(edit) = backspace_edit(&self.blocks[ix].block, ix) {
+            self.apply_block_edit(ix, edit, window, cx);
+        }
+    }
+
+    fn on_action_delete(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let len = self.blocks[ix].block.len();
+        if self.blocks[ix].input.read(cx).selected_range() != (len..len)
+            || ix + 1 >= self.blocks.len()
+        {
+            return;
+        }
+        cx.stop_propagation();
+        self.merge_into_previous(ix + 1, window, cx);
+    }
+
+    fn on_action_paste(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        // Let the input to paste the single line text.
+        if !text.contains('\n') {
+            return;
+        }
+        cx.stop_propagation();
+
+        let lines = text
+            .split('\n')
+            .map(|line| line.trim_end_matches('\r').to_string())
+            .collect::<Vec<_>>();
+        self.insert_lines(ix, lines, window, cx);
+    }
+
+    /// Move the cursor to the previous or next block on the edges, returns true if moved.
+    fn move_to_block(
+        &mut self,
+        ix: usize,
+        forward: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let prev_len = ix
+            .checked_sub(1)
+            .map_or(0, |ix| self.blocks[ix].block.len());
+        let Some((ix, offset)) = adjacent_cursor(ix, forward, self.blocks.len(), prev_len) else {
+            return false;
+        };
+
+        self.focus_block(ix, offset, window, cx);
+        cx.stop_propagation();
+        true
+    }
+
+    fn on_action_move_up(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let (row, _) = self.blocks[ix].input.read(cx).cursor_display_row();
+        if row == 0 {
+            self.move_to_block(ix, false, window, cx);
+        }
+    }
+
+    fn on_action_move_down(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let (row, rows) = self.blocks[ix].input.read(cx).cursor_display_row();
+        if row + 1 >= rows {
+            self.move_to_block(ix, true, window, cx);
+        }
+    }
+
+    fn on_action_move_left(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.blocks[ix].input.read(cx).selected_range() == (0..0) {
+            self.move_to_block(ix, false, window, cx);
+        }
+    }
+
+    fn on_action_move_right(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let len = self.blocks[ix].block.len();
+        if self.blocks[ix].input.read(cx).selected_range() == (len..len) {
+            self.move_to_block(ix, true, window, cx);
+        }
+    }
+
+    fn on_action_toggle_bold(&mut self, _: &ToggleBold, _: &mut Window, cx: &mut Context<Self>) {
+        self.toggle_mark(Mark::Bold, cx);
+    }
+
+    fn on_action_toggle_italic(
+        &mut self,
+        _: &ToggleItalic,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.toggle_mark(Mark::Italic, cx);
+    }
+
+    fn on_action_toggle_underline(
+        &mut self,
+        _: &ToggleUnderline,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.toggle_mark(Mark::Underline, cx);
+    }
+
+    fn on_action_toggle_strikethrough(
+        &mut self,
+        _: &ToggleStrikethrough,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.toggle_mark(Mark::Strikethrough, cx);
+    }
+
+    fn on_action_toggle_code(&mut self, _: &ToggleCode, _: &mut Window, cx: &mut Context<Self>) {
+        self.toggle_mark(Mark::Code, cx);
+    }
+
+    fn on_action_edit_link(&mut self, _: &EditLink, window: &mut Window, cx: &mut Context<Self>) {
+        self.edit_link(window, cx);
+    }
+
+    /// Show the URL input to edit the link of the selected text.
+    fn edit_link(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(range) = self.link_range(cx) else {
+            return;
+        };
+        let block_ix = self.active;
+        let url = self.blocks[block_ix]
+            .block
+            .slice(range.clone())
+            .into_iter()
+            .find_map(|span| span.marks.link)
+            .unwrap_or_default();
+
+        let input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("https://")
+                .default_value(url)
+        });
+        input.update(cx, |input, cx| input.focus(window, cx));
+        let _subscription =
+            cx.subscribe_in(
+                &input,
+                window,
+                |this, input, event, window, cx| match event {
+                    InputEvent::PressEnter { .. } => {
+                        let url = input.read(cx).value().trim().to_string();
+                        let url = (!url.is_empty()).then(|| SharedString::from(url));
+                        this.close_link_editor(url.map(Some), window, cx);
+                    }
+                    InputEvent::Blur => this.close_link_editor(None, window, cx),
+                    _ => {}
+                },
+            );
+
+        self.link_editor = Some(LinkEditor {
+            input,
+            block_ix,
+            range,
+            _subscription,
+        });
+        cx.notify();
+    }
+
+    /// Close the link editor, and set the link if `url` is Some.
+    fn close_link_editor(
+        &mut self,
+        url: Option<Option<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(editor) = self.link_editor.take() else {
+            return;
+        };
+
+        if let Some(url) = url {
+            self.set_link_in_range(editor.block_ix, editor.range.clone(), url, cx);
+        }
+        if editor.block_ix < self.blocks.len() {
+            let offset = editor
+                .range
+                .end
+                .min(self.blocks[editor.block_ix].block.len());
+            self.focus_block(editor.block_ix, offset, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let marks = [
+            (
+                Mark::Bold,
+                "B",
+                t!("RichTextEditor.Bold"),
+                ToggleBold.boxed_clone(),
+            ),
+            (
+                Mark::Italic,
+                "I",
+                t!("RichTextEditor.Italic"),
+                ToggleItalic.boxed_clone(),
+            ),
+            (
+                Mark::Underline,
+                "U",
+                t!("RichTextEditor.Underline"),
+                ToggleUnderline.boxed_clone(),
+            ),
+            (
+                Mark::Strikethrough,
+                "S",
+                t!("RichTextEditor.Strikethrough"),
+                ToggleStrikethrough.boxed_clone(),
+            ),
+            (
+                Mark::Code,
+                "</>",
+                t!("RichTextEditor.Code"),
+                ToggleCode.boxed_clone(),
+            ),
+        ];
+        let kinds = [
+            (
+                BlockKind::Heading(1),
+                "H1",
+                t!("RichTextEditor.Heading", level = 1),
+            ),
+            (
+                BlockKind::Heading(2),
+                "H2",
+                t!("RichTextEditor.Heading", level = 2),
+            ),
+            (
+                BlockKind::Heading(3),
+                "H3",
+                t!("RichTextEditor.Heading", level = 3),
+            ),
+            (
+                BlockKind::BulletedList,
+                "•",
+                t!("RichTextEditor.BulletedList"),
+            ),
+            (
+                BlockKind::NumberedList,
+                "1.",
+                t!("RichTextEditor.NumberedList"),
+            ),
+            (BlockKind::Blockquote, "❝", t!("RichTextEditor.Blockquote")),
+        ];
+        let active_kind = self.active_block_kind();
+
+        h_flex()
+            .flex_wrap()
+            .gap_1()
+            .p_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .children(
+                marks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (mark, label, tooltip, action))| {
+                        Button::new(("mark", ix))
+                            .ghost()
+                            .xsmall()
+                            .label(label)
+                            .selected(self.is_mark_active(mark, cx))
+                            .tooltip_with_action(tooltip, action.as_ref(), Some(CONTEXT))
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.toggle_mark(mark, cx);
+                            }))
+                    }),
+            )
+            .child(
+                Button::new("link")
+                    .ghost()
+                    .xsmall()
+                    .label(t!("RichTextEditor.Link"))
+                    .selected(self.link_editor.is_some())
+                    .tooltip_with_action(t!("RichTextEditor.Link"), &EditLink, Some(CONTEXT))
+                    .on_click(cx.listener(|this, _, window, cx| this.edit_link(window, cx))),
+            )
+            .child(Divider::vertical().h_4().mx_1())
+            .children(
+                kinds
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, (kind, label, tooltip))| {
+                        Button::new(("kind", ix))
+                            .ghost()
+                            .xsmall()
+                            .label(label)
+                            .selected(active_kind == kind)
+                            .tooltip(tooltip)
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.toggle_block_kind(kind, cx);
+                            }))
+                    }),
+            )
+    }
+
+    fn render_link_editor(&self, editor: &LinkEditor, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .p_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .capture_action(cx.listener(|this, _: &input::Escape, window, cx| {
+                cx.stop_propagation();
+                this.close_link_editor(None, window, cx);
+            }))
+            .child(div().flex_1().child(TextInput::new(&editor.input).xsmall()))
+            .child(
+                Button::new("remove-link")
+                    .ghost()
+                    .xsmall()
+                    .label(t!("RichTextEditor.RemoveLink"))
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.close_link_editor(Some(None), window, cx);
+                    })),
+            )
+    }
+
+    fn render_block(&self, ix: usize, number: usize, cx: &mut Context<Self>) -> impl IntoElement {
+        let kind = self.blocks[ix].block.kind;
+        let input = &self.blocks[ix].input;
+
+        h_flex()
+            .id(("block", ix))
+            .w_full()
+            .items_start()
+            .gap_2()
+            .capture_action(cx.listener(move |this, _: &input::Enter, window, cx| {
+                this.on_action_enter(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::Backspace, window, cx| {
+                this.on_action_backspace(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::Delete, window, cx| {
+                this.on_action_delete(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::Paste, window, cx| {
+                this.on_action_paste(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::MoveUp, window, cx| {
+                this.on_action_move_up(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::MoveDown, window, cx| {
+                this.on_action_move_down(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::MoveLeft, window, cx| {
+                this.on_action_move_left(ix, window, cx)
+            }))
+            .capture_action(cx.listener(move |this, _: &input::MoveRight, window, cx| {
+                this.on_action_move_right(ix, window, cx)
+            }))
+            .map(|this| match kind {
+                BlockKind::BulletedList | BlockKind::NumberedList => this.pl_2().child(
+                    div()
+                        .flex_shrink_0()
+                        .min_w_4()
+                        .line_height(rems(1.25))
+                        .text_color(cx.theme().muted_foreground)
+                        .child(if kind == BlockKind::BulletedList {
+                            SharedString::from("•")
+                        } else {
+                            SharedString::from(format!("{}.", number))
+                        }),
+                ),
+                BlockKind::Blockquote => this.border_l_3().border_color(cx.theme().border).pl_3(),
+                _ => this,
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .map(|this| match kind {
+                        BlockKind::Heading(1) => this.text_2xl().font_semibold(),
+                        BlockKind::Heading(2) => this.text_xl().font_semibold(),
+                        BlockKind::Heading(3) => this.text_lg().font_semibold(),
+                        BlockKind::Heading(_) => this.font_semibold(),
+                        _ => this,
+                    })
+                    .child(
+                        TextInput::new(input)
+                            .appearance(false)
+                            .px_0()
+                            .py_0()
+                            .when(matches!(kind, BlockKind::Heading(_)), |this| {
+                                this.line_height(relative(1.5))
+                            }),
+                    ),
+            )
+    }
+}
+
+/// Returns the text styles of the spans in the block.
+fn text_styles(block: &Block, cx: &App) -> Vec<(Range<usize>, HighlightStyle)> {
+    let mut offset = 0;
+    block
+        .spans
+        .iter()
+        .map(|span| {
+            let range = offset..offset + span.text.len();
+            offset = range.end;
+
+            let marks = &span.marks;
+            let mut style = HighlightStyle::default();
+            if block.kind == BlockKind::Blockquote {
+                style.color = Some(cx.theme().muted_foreground);
+            }
+            if marks.bold {
+                style.font_weight = Some(FontWeight::BOLD);
+            }
+            if marks.italic {
+                style.font_style = Some(FontStyle::Italic);
+            }
+            if marks.underline || marks.link.is_some() {
+                style.underline = Some(UnderlineStyle {
+                    thickness: px(1.),
+                    ..Default::default()
+                });
+            }
+            if marks.strikethrough {
+                style.strikethrough = Some(StrikethroughStyle {
+                    thickness: px(1.),
+                    ..Default::default()
+                });
+            }
+            if marks.code {
+                style.background_color = Some(cx.theme().muted);
+            }
+            if marks.link.is_some() {
+                style.color = Some(cx.theme().link);
+            }
+
+            (range, style)
+        })
+        .collect()
+}
+
+/// The edit of the blocks by the Enter or Backspace at the edges of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockEdit {
+    /// Change the kind of the block.
+    SetKind(BlockKind),
+    /// Insert an empty block of the kind before the block.
+    InsertBefore(BlockKind),
+    /// Split the block at the selection.
+    Split,
+    /// Merge the block into the previous block.
+    MergeIntoPrevious,
+}
+
+/// Returns the kind of the block by toggling the `kind`, back to paragraph if it is already the `kind`.
+fn toggled_kind(current: BlockKind, kind: BlockKind) -> BlockKind {
+    if current == kind {
+        BlockKind::Paragraph
+    } else {
+        kind
+    }
+}
+
+/// Returns the kind of the new block split from the block of `kind`, the heading is not continued.
+fn split_kind(kind: BlockKind) -> BlockKind {
+    match kind {
+        BlockKind::Heading(_) => BlockKind::Paragraph,
+        kind => kind,
+    }
+}
+
+/// Returns the edit by the Enter with the selected `range` in the `block`.
+fn enter_edit(block: &Block, range: &Range<usize>) -> BlockEdit {
+    match block.kind {
+        // Exit the list or quote by the Enter on an empty item.
+        BlockKind::BulletedList | BlockKind::NumberedList | BlockKind::Blockquote
+            if block.is_empty() =>
+        {
+            BlockEdit::SetKind(BlockKind::Paragraph)
+        }
+        // Insert an empty block before, to keep the kind of the current block.
+        kind if *range == (0..0) && !block.is_empty() => BlockEdit::InsertBefore(split_kind(kind)),
+        _ => BlockEdit::Split,
+    }
+}
+
+/// Returns the edit by the Backspace at the start of the block `ix`.
+fn backspace_edit(block: &Block, ix: usize) -> Option<BlockEdit> {
+    if block.kind != BlockKind::Paragraph {
+        Some(BlockEdit::SetKind(BlockKind::Paragraph))
+    } else if ix > 0 {
+        Some(BlockEdit::MergeIntoPrevious)
+    } else {
+        None
+    }
+}
+
+/// Returns the new blocks after the `block`, when the `range` and the text after it is replaced
+/// by the lines, the `rest` is the lines after the first line.
+///
+/// The text after the `range` is moved to the last new block.
+fn split_blocks(block: &Block, range: Range<usize>, rest: &[String]) -> Vec<Block> {
+    let tail = block.slice(range.end..block.len());
+    let marks = block.marks_for_insert(range.start);
+    let kind = split_kind(block.kind);
+
+    let mut new_blocks = rest
+        .iter()
+        .map(|line| Block::new(kind).span(Span::new(line.clone()).marks(marks.clone())))
+        .collect::<Vec<_>>();
+    if let Some(last) = new_blocks.last_mut() {
+        last.spans.extend(tail);
+        last.normalize();
+    }
+    new_blocks
+}
+
+/// Returns the block index and the offset to move the cursor from the edge of the block `ix`
+/// to the previous or next block, the `prev_len` is the length of the previous block.
+fn adjacent_cursor(
+    ix: usize,
+    forward: bool,
+    blocks_count: usize,
+    prev_len: usize,
+) -> Option<(usize, usize)> {
+    if forward {
+        (ix + 1 < blocks_count).then_some((ix + 1, 0))
+    } else {
+        ix.checked_sub(1).map(|ix| (ix, prev_len))
+    }
+}
+
+impl EventEmitter<RichTextEvent> for RichTextEditor {}
+
+impl Focusable for RichTextEditor {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.blocks[self.active].input.focus_handle(cx)
+    }
+}
+
+impl Render for RichTextEditor {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Sync the marks and the placeholder to the inputs.
+        let is_single = self.blocks.len() == 1;
+        for (ix, block) in self.blocks.iter_mut().enumerate() {
+            let styles = text_styles(&block.block, cx);
+            block
+                .input
+                .update(cx, |input, _| input.set_text_styles(styles));
+
+            let show_placeholder = ix == 0 && is_single;
+            if block.show_placeholder != show_placeholder {
+                block.show_placeholder = show_placeholder;
+                let placeholder = if show_placeholder {
+                    self.placeholder.clone()
+                } else {
+                    SharedString::default()
+                };
+                block.input.update(cx, |input, cx| {
+                    input.set_placeholder(placeholder, window, cx);
+                });
+            }
+        }
+
+        let mut number = 0;
+        let mut prev_kind = None;
+        let blocks = (0..self.blocks.len())
+            .map(|ix| {
+                let kind = self.blocks[ix].block.kind;
+                number = if prev_kind == Some(kind) {
+                    number + 1
+                } else {
+                    1
+                };
+                prev_kind = Some(kind);
+                self.render_block(ix, number, cx)
+            })
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::on_action_toggle_bold))
+            .on_action(cx.listener(Self::on_action_toggle_italic))
+            .on_action(cx.listener(Self::on_action_toggle_underline))
+            .on_action(cx.listener(Self::on_action_toggle_strikethrough))
+            .on_action(cx.listener(Self::on_action_toggle_code))
+            .on_action(cx.listener(Self::on_action_edit_link))
+            .size_full()
+            .when(self.show_toolbar, |this| {
+                this.child(self.render_toolbar(cx))
+            })
+            .when_some(self.link_editor.as_ref(), |this, editor| {
+                this.child(self.render_link_editor(editor, cx))
+            })
+            .child(
+                v_flex()
+                    .id("blocks")
+                    .flex_1()
+                    .w_full()
+                    .overflow_y_scroll()
+                    .p_3()
+                    .gap_1()
+                    .children(blocks),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        adjacent_cursor, backspace_edit, enter_edit, split_blocks, toggled_kind, BlockEdit,
+    };
+    use crate::rich_text::{Block, BlockKind, Mark, Marks, Span};
+
+    fn bold() -> Marks {
+        Marks {
+            bold: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_enter_edit() {
+        let heading = Block::new(BlockKind::Heading(1)).span("Title");
+        // Split in the middle or at the end the block.
+        assert_eq!(enter_edit(&heading, &(2..2)), BlockEdit::Split);
+        assert_eq!(enter_edit(&heading, &(5..5)), BlockEdit::Split);
+        assert_eq!(enter_edit(&heading, &(0..2)), BlockEdit::Split);
+        // Insert before at the start, the heading is not continued.
+        assert_eq!(
+            enter_edit(&heading, &(0..0)),
+            BlockEdit::InsertBefore(BlockKind::Paragraph)
+        );
+        let item = Block::new(BlockKind::BulletedList).span("Item");
+        assert_eq!(
+            enter_edit(&item, &(0..0)),
+            BlockEdit::InsertBefore(BlockKind::BulletedList)
+        );
+
+        // Exit the list or quote on the empty item.
+        for kind in [
+            BlockKind::BulletedList,
+            BlockKind::NumberedList,
+            BlockKind::Blockquote,
+        ] {
+            assert_eq!(
+                enter_edit(&Block::new(kind), &(0..0)),
+                BlockEdit::SetKind(BlockKind::Paragraph)
+            );
+        }
+        assert_eq!(
+            enter_edit(&Block::new(BlockKind::Paragraph), &(0..0)),
+            BlockEdit::Split
+        );
+    }
+
+    #[test]
+    fn test_backspace_edit() {
+        let paragraph = Block::new(BlockKind::Paragraph).span("Hello");
+        assert_eq!(
+            backspace_edit(&paragraph, 1),
+            Some(BlockEdit::MergeIntoPrevious)
+        );
+        assert_eq!(backspace_edit(&paragraph, 0), None);
+
+        // Convert to paragraph first, then merge by the next Backspace.
+        let quote = Block::new(BlockKind::Blockquote).span("Hello");
+        assert_eq!(
+            backspace_edit(&quote, 1),
+            Some(BlockEdit::SetKind(BlockKind::Paragraph))
+        );
+        assert_eq!(
+            backspace_edit(&Block::new(BlockKind::Heading(2)), 0),
+            Some(BlockEdit::SetKind(BlockKind::Paragraph))
+        );
+    }
+
+    #[test]
+    fn test_split_blocks() {
+        let block = Block::new(BlockKind::Heading(1))
+            .span("Hello ")
+            .span(Span::new("world").marks(bold()));
+
+        // Enter in the bold text, the rest of the text is moved to the new block with the marks.
+        let lines = [String::new()];
+        let blocks = split_blocks(&block, 8..8, &lines);
+        assert_eq!(
+            blocks,
+            vec![Block::new(BlockKind::Paragraph).span(Span::new("rld").marks(bold()))]
+        );
+
+        // Paste the lines over the selection.
+        let item = Block::new(BlockKind::NumberedList).span("one two");
+        let lines = ["foo".to_string(), "bar".to_string()];
+        let blocks = split_blocks(&item, 3..4, &lines);
+        assert_eq!(
+            blocks,
+            vec![
+                Block::new(BlockKind::NumberedList).span("foo"),
+                Block::new(BlockKind::NumberedList).span("bartwo"),
+            ]
+        );
+
+        // Enter at the end.
+        let blocks = split_blocks(&item, 7..7, &[String::new()]);
+        assert_eq!(blocks, vec![Block::new(BlockKind::NumberedList)]);
+    }
+
+    #[test]
+    fn test_merge_blocks() {
+        // The spans of the removed block are spliced at the end of the previous block,
+        // the same as the editor does by the change of the input.
+        let mut prev = Block::new(BlockKind::Paragraph).span("Hello ");
+        let next = Block::new(BlockKind::Paragraph).span(Span::new("world").marks(bold()));
+        let offset = prev.len();
+        prev.splice(offset..offset, next.spans);
+        assert_eq!(prev.text(), "Hello world");
+        assert!(prev.is_marked(6..11, Mark::Bold));
+        assert!(!prev.is_marked(0..6, Mark::Bold));
+    }
+
+    #[test]
+    fn test_adjacent_cursor() {
+        // Move to the end of the previous block, or the start of the next block.
+        assert_eq!(adjacent_cursor(1, false, 3, 5), Some((0, 5)));
+        assert_eq!(adjacent_cursor(1, true, 3, 5), Some((2, 0)));
+        assert_eq!(adjacent_cursor(0, false, 3, 0), None);
+        assert_eq!(adjacent_cursor(2, true, 3, 4), None);
+    }
+
+    #[test]
+    fn test_toggled_kind() {
+        assert_eq!(
+            toggled_kind(BlockKind::Paragraph, BlockKind::Heading(1)),
+            BlockKind::Heading(1)
+        );
+        assert_eq!(
+            toggled_kind(BlockKind::Heading(1), BlockKind::Heading(2)),
+            BlockKind::Heading(2)
+        );
+        assert_eq!(
+            toggled_kind(BlockKind::BulletedList, BlockKind::BulletedList),
+            BlockKind::Paragraph
+        );
+    }
+}
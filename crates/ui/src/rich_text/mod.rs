@@ -0,0 +1,10 @@
+mod document;
+mod editor;
+
+pub use document::*;
+pub use editor::*;
+use gpui::App;
+
+pub(crate) fn init(cx: &mut App) {
+    editor::init(cx);
+}
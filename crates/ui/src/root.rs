@@ -1,8 +1,12 @@
 use crate::{
+    debug_overlay::DebugOverlay,
     drawer::Drawer,
     input::InputState,
+    loading_bar::{LoadingBar, LoadingId},
     modal::Modal,
     notification::{Notification, NotificationList},
+    overlay::{Overlay, OverlayId},
+    shortcut_help::ShortcutHelp,
     window_border, ActiveTheme, Placement,
 };
 use gpui::{
@@ -67,8 +71,29 @@ pub trait ContextModal: Sized {
     /// Returns number of notifications.
     fn notifications(&mut self, cx: &mut App) -> Rc<Vec<Entity<Notification>>>;
 
+    /// Mounts an overlay into the top layer, returns the id to close it.
+    fn open_overlay(&mut self, overlay: Overlay, cx: &mut App) -> OverlayId;
+
+    /// Returns true if the overlay with the `id` is opened.
+    fn has_overlay(&mut self, id: OverlayId, cx: &mut App) -> bool;
+
+    /// Closes the overlay with the `id`.
+    fn close_overlay(&mut self, id: OverlayId, cx: &mut App);
+
     /// Return current focused Input entity.
     fn focused_input(&mut self, cx: &mut App) -> Option<Entity<InputState>>;
+
+    /// Starts a task of the loading bar on the top of the window, returns the id to finish it.
+    fn start_loading(&mut self, cx: &mut App) -> LoadingId;
+
+    /// Set the progress (0.0 to 1.0) of the loading task, the task trickles by itself without it.
+    fn set_loading_progress(&mut self, id: LoadingId, progress: f32, cx: &mut App);
+
+    /// Finish the loading task, the bar is completed when all the tasks are finished.
+    fn finish_loading(&mut self, id: LoadingId, cx: &mut App);
+
+    /// Returns true if any loading task is not finished.
+    fn is_loading(&mut self, cx: &mut App) -> bool;
     /// Returns true if there is a focused Input entity.
     fn has_focused_input(&mut self, cx: &mut App) -> bool;
 }
@@ -132,7 +157,12 @@ impl ContextModal for Window {
             root.active_modals.push(ActiveModal {
                 focus_handle,
                 builder: Rc::new(build),
+                loading: false,
             });
+            DebugOverlay::log(
+                format!("Modal opened, layer {}", root.active_modals.len()),
+                cx,
+            );
             cx.notify();
         })
     }
@@ -144,7 +174,9 @@ impl ContextModal for Window {
     fn close_modal(&mut self, cx: &mut App) {
         Root::update(self, cx, move |root, window, cx| {
             root.focused_input = None;
-            root.active_modals.pop();
+            if root.active_modals.pop().is_some() {
+                DebugOverlay::log("Modal closed", cx);
+            }
 
             if let Some(top_modal) = root.active_modals.last() {
                 // Focus the next modal.
@@ -198,6 +230,31 @@ impl ContextModal for Window {
         Rc::new(entity.read(cx).notifications())
     }
 
+    fn open_overlay(&mut self, overlay: Overlay, cx: &mut App) -> OverlayId {
+        Root::update(self, cx, move |root, _, cx| {
+            let id = OverlayId(root.next_overlay_id);
+            root.next_overlay_id += 1;
+            root.active_overlays.push((id, overlay));
+            cx.notify();
+            id
+        })
+    }
+
+    fn has_overlay(&mut self, id: OverlayId, cx: &mut App) -> bool {
+        Root::read(self, cx)
+            .active_overlays
+            .iter()
+            .any(|(overlay_id, _)| *overlay_id == id)
+    }
+
+    fn close_overlay(&mut self, id: OverlayId, cx: &mut App) {
+        Root::update(self, cx, move |root, _, cx| {
+            root.active_overlays
+                .retain(|(overlay_id, _)| *overlay_id != id);
+            cx.notify();
+        })
+    }
+
     fn has_focused_input(&mut self, cx: &mut App) -> bool {
         Root::read(self, cx).focused_input.is_some()
     }
@@ -205,11 +262,30 @@ impl ContextModal for Window {
     fn focused_input(&mut self, cx: &mut App) -> Option<Entity<InputState>> {
         Root::read(self, cx).focused_input.clone()
     }
+
+    fn start_loading(&mut self, cx: &mut App) -> LoadingId {
+        let loading_bar = Root::read(self, cx).loading_bar.clone();
+        loading_bar.update(cx, |loading_bar, cx| loading_bar.start(cx))
+    }
+
+    fn set_loading_progress(&mut self, id: LoadingId, progress: f32, cx: &mut App) {
+        let loading_bar = Root::read(self, cx).loading_bar.clone();
+        loading_bar.update(cx, |loading_bar, cx| loading_bar.set(id, progress, cx))
+    }
+
+    fn finish_loading(&mut self, id: LoadingId, cx: &mut App) {
+        let loading_bar = Root::read(self, cx).loading_bar.clone();
+        loading_bar.update(cx, |loading_bar, cx| loading_bar.finish(id, cx))
+    }
+
+    fn is_loading(&mut self, cx: &mut App) -> bool {
+        Root::read(self, cx).loading_bar.read(cx).is_loading()
+    }
 }
 
 /// Root is a view for the App window for as the top level view (Must be the first view in the window).
 ///
-/// It is used to manage the Drawer, Modal, and Notification.
+/// It is used to manage the Drawer, Modal, Notification, Overlay, and the loading bar.
 pub struct Root {
     /// Used to store the focus handle of the previous view.
     /// When the Modal, Drawer closes, we will focus back to the previous view.
@@ -218,6 +294,10 @@ pub struct Root {
     pub(crate) active_modals: Vec<ActiveModal>,
     pub(super) focused_input: Option<Entity<InputState>>,
     pub notification: Entity<NotificationList>,
+    pub(crate) loading_bar: Entity<LoadingBar>,
+    pub(crate) shortcut_help: Option<Entity<ShortcutHelp>>,
+    active_overlays: Vec<(OverlayId, Overlay)>,
+    next_overlay_id: usize,
     drawer_size: Option<DefiniteLength>,
     view: AnyView,
 }
@@ -233,6 +313,8 @@ struct ActiveDrawer {
 pub(crate) struct ActiveModal {
     focus_handle: FocusHandle,
     builder: Rc<dyn Fn(Modal, &mut Window, &mut App) -> Modal + 'static>,
+    /// Is waiting for the async `on_ok` of the modal.
+    loading: bool,
 }
 
 impl Root {
@@ -243,6 +325,10 @@ impl Root {
             active_modals: Vec::new(),
             focused_input: None,
             notification: cx.new(|cx| NotificationList::new(window, cx)),
+            loading_bar: cx.new(|_| LoadingBar::new()),
+            shortcut_help: None,
+            active_overlays: Vec::new(),
+            next_overlay_id: 0,
             drawer_size: None,
             view,
         }
@@ -268,6 +354,55 @@ impl Root {
             .read(cx)
     }
 
+    /// Set the loading state of the modal with the focus handle.
+    pub(crate) fn set_modal_loading(
+        &mut self,
+        focus_handle: &FocusHandle,
+        loading: bool,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(modal) = self
+            .active_modals
+            .iter_mut()
+            .find(|modal| &modal.focus_handle == focus_handle)
+        {
+            modal.loading = loading;
+            cx.notify();
+        }
+    }
+
+    /// Close the modal with the focus handle, unlike the [`ContextModal::close_modal`],
+    /// this is not always the last one (e.g.: a nested modal opened during the async `on_ok`).
+    ///
+    /// Returns `false` if the modal has already been closed.
+    pub(crate) fn close_modal_with(
+        &mut self,
+        focus_handle: &FocusHandle,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(ix) = self
+            .active_modals
+            .iter()
+            .position(|modal| &modal.focus_handle == focus_handle)
+        else {
+            return false;
+        };
+
+        self.focused_input = None;
+        let is_last = ix + 1 == self.active_modals.len();
+        self.active_modals.remove(ix);
+        if is_last {
+            if let Some(top_modal) = self.active_modals.last() {
+                top_modal.focus_handle.focus(window);
+            } else {
+                self.focus_back(window, cx);
+            }
+        }
+        cx.notify();
+        true
+    }
+
     fn focus_back(&mut self, window: &mut Window, _: &mut App) {
         if let Some(handle) = self.previous_focus_handle.clone() {
             window.focus(&handle);
@@ -352,6 +487,7 @@ impl Root {
                 //
                 // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
                 modal.focus_handle = active_modal.focus_handle.clone();
+                modal.loading = active_modal.loading;
 
                 modal.layer_ix = i;
                 // Find the modal which one needs to show overlay.
@@ -372,6 +508,24 @@ impl Root {
         Some(div().children(modals))
     }
 
+    /// Render the Overlay layer, the overlays are painted above the other layers.
+    pub fn render_overlay_layer(window: &mut Window, cx: &mut App) -> Option<impl IntoElement> {
+        let root = window.root::<Root>()??;
+
+        let mut active_overlays = root.read(cx).active_overlays.clone();
+        if active_overlays.is_empty() {
+            return None;
+        }
+
+        active_overlays.sort_by_key(|(_, overlay)| overlay.z_index_value());
+        let overlays = active_overlays
+            .iter()
+            .map(|(id, overlay)| overlay.render(*id, window, cx))
+            .collect::<Vec<_>>();
+
+        Some(div().children(overlays))
+    }
+
     /// Return the root view of the Root.
     pub fn view(&self) -> &AnyView {
         &self.view
@@ -400,9 +554,13 @@ impl Render for Root {
                 .relative()
                 .size_full()
                 .font_family(".SystemUIFont")
+                .when(cx.theme().direction.is_rtl(), |this| this.text_right())
                 .bg(cx.theme().background)
                 .text_color(cx.theme().foreground)
-                .child(self.view.clone()),
+                .child(self.view.clone())
+                .children(self.shortcut_help.clone())
+                .child(self.loading_bar.clone())
+                .children(DebugOverlay::render(window, cx)),
         )
     }
 }
@@ -0,0 +1,58 @@
+use gpui::{px, Context, Pixels, Subscription, Window};
+
+/// Extension trait to observe the scale factor changes of the window.
+///
+/// The scale factor may change mid-session, e.g.: the window is moved to a monitor with
+/// a different DPI. The icons, images and text are re-rasterized by GPUI at the new scale,
+/// use this to update the custom caches that depend on the scale factor.
+pub trait ScaleFactorObserver<T> {
+    /// Call the `f` with the new scale factor when the scale factor of the window is changed.
+    fn observe_scale_factor(
+        &mut self,
+        window: &mut Window,
+        f: impl Fn(&mut T, f32, &mut Window, &mut Context<T>) + 'static,
+    ) -> Subscription;
+}
+
+impl<T: 'static> ScaleFactorObserver<T> for Context<'_, T> {
+    fn observe_scale_factor(
+        &mut self,
+        window: &mut Window,
+        f: impl Fn(&mut T, f32, &mut Window, &mut Context<T>) + 'static,
+    ) -> Subscription {
+        let mut scale_factor = window.scale_factor();
+        self.observe_window_bounds(window, move |this, window, cx| {
+            if window.scale_factor() != scale_factor {
+                scale_factor = window.scale_factor();
+                f(this, scale_factor, window, cx);
+            }
+        })
+    }
+}
+
+/// Round the logical pixels to the nearest device pixel by the `scale_factor`.
+///
+/// This keeps the size aligned to the device pixels (e.g. at 1.5x scale),
+/// to avoid blurry borders by the fractional device pixels.
+pub fn snap_to_device_pixel(value: Pixels, scale_factor: f32) -> Pixels {
+    if scale_factor <= 0. {
+        return value.round();
+    }
+
+    px((f32::from(value) * scale_factor).round() / scale_factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::snap_to_device_pixel;
+
+    #[test]
+    fn test_snap_to_device_pixel() {
+        assert_eq!(snap_to_device_pixel(px(10.3), 1.), px(10.));
+        assert_eq!(snap_to_device_pixel(px(10.3), 2.), px(10.5));
+        assert_eq!(snap_to_device_pixel(px(10.5), 1.5), px(32. / 3.));
+        assert_eq!(snap_to_device_pixel(px(10.3), 0.), px(10.));
+    }
+}
@@ -1,7 +1,12 @@
+mod scroll_sync;
+mod scroll_to;
 mod scrollable;
 mod scrollable_mask;
 mod scrollbar;
 
+pub use scroll_sync::*;
+pub(crate) use scroll_to::scroll_to_offset;
+pub use scroll_to::ScrollTarget;
 pub use scrollable::*;
 pub use scrollable_mask::*;
 pub use scrollbar::*;
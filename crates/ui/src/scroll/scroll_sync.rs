@@ -0,0 +1,216 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gpui::{
+    px, Along as _, App, Modifiers, Pixels, Point, ScrollHandle, Size, UniformListScrollHandle,
+    Window,
+};
+
+use super::ScrollbarAxis;
+use crate::VirtualListScrollHandle;
+
+/// A scrollable pane to be linked by the [`ScrollSync`].
+pub trait ScrollSyncPane: 'static {
+    /// Returns the scroll offset of the pane, the value is negative when scrolled.
+    fn offset(&self, cx: &App) -> Point<Pixels>;
+    /// Set the scroll offset of the pane.
+    fn set_offset(&self, offset: Point<Pixels>, cx: &mut App);
+    /// Returns the maximum scroll offset (the content size minus the viewport size).
+    fn max_offset(&self, cx: &App) -> Size<Pixels>;
+}
+
+impl ScrollSyncPane for ScrollHandle {
+    fn offset(&self, _: &App) -> Point<Pixels> {
+        ScrollHandle::offset(self)
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>, _: &mut App) {
+        ScrollHandle::set_offset(self, offset);
+    }
+
+    fn max_offset(&self, _: &App) -> Size<Pixels> {
+        ScrollHandle::max_offset(self)
+    }
+}
+
+impl ScrollSyncPane for VirtualListScrollHandle {
+    fn offset(&self, cx: &App) -> Point<Pixels> {
+        ScrollSyncPane::offset(self.base_handle(), cx)
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>, cx: &mut App) {
+        ScrollSyncPane::set_offset(self.base_handle(), offset, cx);
+    }
+
+    fn max_offset(&self, cx: &App) -> Size<Pixels> {
+        ScrollSyncPane::max_offset(self.base_handle(), cx)
+    }
+}
+
+impl ScrollSyncPane for UniformListScrollHandle {
+    fn offset(&self, cx: &App) -> Point<Pixels> {
+        ScrollSyncPane::offset(&self.0.borrow().base_handle, cx)
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>, cx: &mut App) {
+        ScrollSyncPane::set_offset(&self.0.borrow().base_handle, offset, cx);
+    }
+
+    fn max_offset(&self, cx: &App) -> Size<Pixels> {
+        ScrollSyncPane::max_offset(&self.0.borrow().base_handle, cx)
+    }
+}
+
+struct LinkedPane {
+    pane: Box<dyn ScrollSyncPane>,
+    last_offset: Point<Pixels>,
+    /// The scroll ratio difference to the other panes, changed by scrolling in detached.
+    bias: Point<f32>,
+}
+
+struct ScrollSyncState {
+    axis: ScrollbarAxis,
+    detach_modifiers: Modifiers,
+    panes: Vec<LinkedPane>,
+}
+
+/// A handle to link the scroll positions of two or more scrollable panes proportionally,
+/// e.g.: a Markdown editor and its preview, or side-by-side diffs.
+///
+/// Call [`ScrollSync::sync`] in the `render` of the view that contains the panes, the pane
+/// scrolled by the user will be the source to update the others.
+///
+/// Hold the [`ScrollSync::detach_modifiers`] (default is `alt`) while scrolling a pane to
+/// temporarily detach it, the relative position is kept after released.
+///
+/// ```ignore
+/// let scroll_sync = ScrollSync::new()
+///     .link(editor_state.clone())
+///     .link(preview_scroll_handle.clone());
+///
+/// // In render
+/// self.scroll_sync.sync(window, cx);
+/// ```
+#[derive(Clone)]
+pub struct ScrollSync {
+    state: Rc<RefCell<ScrollSyncState>>,
+}
+
+impl Default for ScrollSync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScrollSync {
+    /// Create a new ScrollSync to sync the vertical scroll positions.
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(ScrollSyncState {
+                axis: ScrollbarAxis::Vertical,
+                detach_modifiers: Modifiers::alt(),
+                panes: vec![],
+            })),
+        }
+    }
+
+    /// Set the axis to sync, default is vertical.
+    pub fn axis(self, axis: impl Into<ScrollbarAxis>) -> Self {
+        self.state.borrow_mut().axis = axis.into();
+        self
+    }
+
+    /// Set the modifiers to hold for detaching the scrolling pane, default is `alt`.
+    pub fn detach_modifiers(self, modifiers: Modifiers) -> Self {
+        self.state.borrow_mut().detach_modifiers = modifiers;
+        self
+    }
+
+    /// Link a pane to sync the scroll position.
+    pub fn link(self, pane: impl ScrollSyncPane) -> Self {
+        self.state.borrow_mut().panes.push(LinkedPane {
+            pane: Box::new(pane),
+            last_offset: Point::default(),
+            bias: Point::default(),
+        });
+        self
+    }
+
+    /// Sync the scroll positions from the pane scrolled since the last sync.
+    pub fn sync(&self, window: &Window, cx: &mut App) {
+        let mut state = self.state.borrow_mut();
+        let offsets = state
+            .panes
+            .iter()
+            .map(|linked| linked.pane.offset(cx))
+            .collect::<Vec<_>>();
+        let Some(source_ix) = state
+            .panes
+            .iter()
+            .zip(offsets.iter())
+            .position(|(linked, offset)| linked.last_offset != *offset)
+        else {
+            return;
+        };
+
+        let detach_modifiers = state.detach_modifiers;
+        let detached =
+            detach_modifiers.modified() && detach_modifiers.is_subset_of(&window.modifiers());
+        let source_max = state.panes[source_ix].pane.max_offset(cx);
+
+        for axis in state.axis.all() {
+            let source = &mut state.panes[source_ix];
+            let ratio = scroll_ratio(offsets[source_ix].along(axis), source_max.along(axis));
+
+            if detached {
+                let old_ratio =
+                    scroll_ratio(source.last_offset.along(axis), source_max.along(axis));
+                source.bias = source
+                    .bias
+                    .apply_along(axis, |bias| bias + ratio - old_ratio);
+                continue;
+            }
+
+            let base_ratio = ratio - source.bias.along(axis);
+            for (ix, linked) in state.panes.iter_mut().enumerate() {
+                if ix == source_ix {
+                    continue;
+                }
+
+                let max_offset = linked.pane.max_offset(cx).along(axis);
+                let target_ratio = (base_ratio + linked.bias.along(axis)).clamp(0., 1.);
+                let offset = linked
+                    .pane
+                    .offset(cx)
+                    .apply_along(axis, |_| -max_offset * target_ratio);
+                linked.pane.set_offset(offset, cx);
+            }
+        }
+
+        for linked in state.panes.iter_mut() {
+            linked.last_offset = linked.pane.offset(cx);
+        }
+    }
+}
+
+/// Returns the scroll ratio (0.0 - 1.0) of the offset in the maximum offset.
+fn scroll_ratio(offset: Pixels, max_offset: Pixels) -> f32 {
+    if max_offset <= px(0.) {
+        return 0.;
+    }
+
+    (-offset / max_offset).clamp(0., 1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scroll_ratio;
+    use gpui::px;
+
+    #[test]
+    fn test_scroll_ratio() {
+        assert_eq!(scroll_ratio(px(0.), px(100.)), 0.);
+        assert_eq!(scroll_ratio(px(-50.), px(100.)), 0.5);
+        assert_eq!(scroll_ratio(px(-150.), px(100.)), 1.);
+        assert_eq!(scroll_ratio(px(-10.), px(0.)), 0.);
+    }
+}
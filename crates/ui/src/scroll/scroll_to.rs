@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+use gpui::{point, App, Pixels, Point, Task, Timer, Window};
+
+use super::ScrollHandleOffsetable;
+use crate::animation::Transition;
+
+/// The target of the `scroll_to` method of List, Table and TextInput.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollTarget {
+    /// Scroll to the offset, same as the [`gpui::ScrollHandle::offset`],
+    /// e.g.: `point(px(0.), px(-100.))` to scroll down 100px.
+    Offset(Point<Pixels>),
+    /// Scroll to the row, this is the item of List, the row of Table, or the line of TextInput.
+    Row(usize),
+}
+
+impl From<Point<Pixels>> for ScrollTarget {
+    fn from(offset: Point<Pixels>) -> Self {
+        Self::Offset(offset)
+    }
+}
+
+impl From<usize> for ScrollTarget {
+    fn from(row: usize) -> Self {
+        Self::Row(row)
+    }
+}
+
+/// Scroll the `handle` to the `offset`, animate by [`Transition::NORMAL`] if `smooth` is true.
+///
+/// The returned task should be kept until the animation is finished,
+/// drop it to cancel the animation, e.g.: start a new scroll.
+pub(crate) fn scroll_to_offset<H>(
+    handle: &H,
+    offset: Point<Pixels>,
+    smooth: bool,
+    window: &mut Window,
+    cx: &mut App,
+) -> Task<()>
+where
+    H: ScrollHandleOffsetable + Clone + 'static,
+{
+    let transition = Transition::NORMAL;
+    let from = handle.offset();
+    if !smooth || from == offset || transition.animation(cx).is_none() {
+        handle.set_offset(offset);
+        window.refresh();
+        return Task::ready(());
+    }
+
+    let handle = handle.clone();
+    let start = Instant::now();
+    window.spawn(cx, async move |cx| loop {
+        Timer::after(Duration::from_millis(16)).await;
+
+        let t = (start.elapsed().as_secs_f32() / transition.duration.as_secs_f32()).min(1.);
+        let delta = transition.easing.ease(t);
+        handle.set_offset(point(
+            from.x + (offset.x - from.x) * delta,
+            from.y + (offset.y - from.y) * delta,
+        ));
+
+        if cx.update(|window, _| window.refresh()).is_err() || t >= 1. {
+            break;
+        }
+    })
+}
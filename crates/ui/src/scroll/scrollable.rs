@@ -1,9 +1,10 @@
 use super::{Scrollbar, ScrollbarAxis, ScrollbarState};
+use crate::ActiveTheme as _;
 use gpui::{
-    div, relative, AnyElement, App, Bounds, Div, Element, ElementId, GlobalElementId,
-    InspectorElementId, InteractiveElement, Interactivity, IntoElement, LayoutId, ParentElement,
-    Pixels, Position, ScrollHandle, SharedString, Stateful, StatefulInteractiveElement, Style,
-    StyleRefinement, Styled, Window,
+    div, prelude::FluentBuilder as _, relative, AnyElement, App, Bounds, Div, Element, ElementId,
+    GlobalElementId, InspectorElementId, InteractiveElement, Interactivity, IntoElement, LayoutId,
+    ParentElement, Pixels, Position, ScrollHandle, SharedString, Stateful,
+    StatefulInteractiveElement, Style, StyleRefinement, Styled, Window,
 };
 
 /// A scroll view is a container that allows the user to scroll through a large amount of content.
@@ -161,6 +162,12 @@ where
 
         let axis = self.axis;
         let scroll_id = self.id.clone();
+        // Reserve the space for the scrollbar in the gutter layout.
+        let gutter = cx
+            .theme()
+            .scrollbar_layout
+            .is_gutter()
+            .then(|| super::width(cx));
         let content = self.element.take().map(|c| c.into_any_element());
 
         self.with_element_state(id.unwrap(), window, cx, |_, element_state, window, cx| {
@@ -175,6 +182,12 @@ where
                         .overflow_scroll()
                         .relative()
                         .size_full()
+                        .when_some(gutter.filter(|_| axis.has_vertical()), |this, width| {
+                            this.pr(width)
+                        })
+                        .when_some(gutter.filter(|_| axis.has_horizontal()), |this, width| {
+                            this.pb(width)
+                        })
                         .child(div().children(content)),
                 )
                 .child(
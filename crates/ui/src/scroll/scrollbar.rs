@@ -32,19 +32,36 @@ impl ScrollbarShow {
     fn is_always(&self) -> bool {
         matches!(self, Self::Always)
     }
+
+    fn is_scrolling(&self) -> bool {
+        matches!(self, Self::Scrolling)
+    }
 }
 
-/// The width of the scrollbar (THUMB_ACTIVE_INSET * 2 + THUMB_ACTIVE_WIDTH)
-pub(crate) const WIDTH: Pixels = px(2. * 2. + 8.);
-const MIN_THUMB_SIZE: f32 = 48.;
+/// The layout of the scrollbar, see [`crate::Theme::scrollbar_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, Default, JsonSchema)]
+pub enum ScrollbarLayout {
+    /// The scrollbar is over the content.
+    #[default]
+    Overlay,
+    /// The scrollbar is in a gutter beside the content, the content will not be covered.
+    Gutter,
+}
 
-const THUMB_WIDTH: Pixels = px(6.);
-const THUMB_RADIUS: Pixels = px(6. / 2.);
-const THUMB_INSET: Pixels = px(2.);
+impl ScrollbarLayout {
+    #[inline]
+    pub fn is_gutter(&self) -> bool {
+        matches!(self, Self::Gutter)
+    }
+}
+
+/// Returns the width of the scrollbar (THUMB_INSET * 2 + [`crate::Theme::scrollbar_width`]).
+pub(crate) fn width(cx: &App) -> Pixels {
+    THUMB_INSET * 2 + cx.theme().scrollbar_width
+}
 
-const THUMB_ACTIVE_WIDTH: Pixels = px(8.);
-const THUMB_ACTIVE_RADIUS: Pixels = px(8. / 2.);
-const THUMB_ACTIVE_INSET: Pixels = px(2.);
+const MIN_THUMB_SIZE: f32 = 48.;
+const THUMB_INSET: Pixels = px(2.);
 
 const FADE_OUT_DURATION: f32 = 3.0;
 const FADE_OUT_DELAY: f32 = 2.0;
@@ -257,7 +274,7 @@ impl ScrollbarAxis {
     }
 
     #[inline]
-    fn all(&self) -> Vec<Axis> {
+    pub(crate) fn all(&self) -> Vec<Axis> {
         match self {
             Self::Vertical => vec![Axis::Vertical],
             Self::Horizontal => vec![Axis::Horizontal],
@@ -352,67 +369,87 @@ impl Scrollbar {
         self
     }
 
+    /// Returns the width and radius of the thumb, the thumb is thinner when not active.
+    fn thumb_style(active: bool, cx: &App) -> (Pixels, Pixels) {
+        let width = if active || !cx.theme().scrollbar_show.is_scrolling() {
+            cx.theme().scrollbar_width
+        } else {
+            cx.theme().scrollbar_width * 0.75
+        };
+        let radius = cx
+            .theme()
+            .scrollbar_thumb_radius
+            .unwrap_or(width / 2.)
+            .min(width / 2.);
+
+        (width, radius)
+    }
+
+    /// The bar background is always visible in the gutter layout.
+    fn bar_bg(cx: &App) -> Hsla {
+        if cx.theme().scrollbar_layout.is_gutter() {
+            cx.theme().scrollbar
+        } else {
+            gpui::transparent_black()
+        }
+    }
+
     fn style_for_active(cx: &App) -> (Hsla, Hsla, Hsla, Pixels, Pixels, Pixels) {
+        let (width, radius) = Self::thumb_style(true, cx);
         (
             cx.theme().scrollbar_thumb_hover,
             cx.theme().scrollbar,
             cx.theme().border,
-            THUMB_ACTIVE_WIDTH,
-            THUMB_ACTIVE_INSET,
-            THUMB_ACTIVE_RADIUS,
+            width,
+            THUMB_INSET,
+            radius,
         )
     }
 
     fn style_for_hovered_thumb(cx: &App) -> (Hsla, Hsla, Hsla, Pixels, Pixels, Pixels) {
+        let (width, radius) = Self::thumb_style(true, cx);
         (
             cx.theme().scrollbar_thumb_hover,
             cx.theme().scrollbar,
             cx.theme().border,
-            THUMB_ACTIVE_WIDTH,
-            THUMB_ACTIVE_INSET,
-            THUMB_ACTIVE_RADIUS,
+            width,
+            THUMB_INSET,
+            radius,
         )
     }
 
     fn style_for_hovered_bar(cx: &App) -> (Hsla, Hsla, Hsla, Pixels, Pixels, Pixels) {
+        let (width, radius) = Self::thumb_style(true, cx);
         (
             cx.theme().scrollbar_thumb,
             cx.theme().scrollbar,
             gpui::transparent_black(),
-            THUMB_ACTIVE_WIDTH,
-            THUMB_ACTIVE_INSET,
-            THUMB_ACTIVE_RADIUS,
+            width,
+            THUMB_INSET,
+            radius,
         )
     }
 
     fn style_for_normal(cx: &App) -> (Hsla, Hsla, Hsla, Pixels, Pixels, Pixels) {
-        let (width, inset, radius) = match cx.theme().scrollbar_show {
-            ScrollbarShow::Scrolling => (THUMB_WIDTH, THUMB_INSET, THUMB_RADIUS),
-            _ => (THUMB_ACTIVE_WIDTH, THUMB_ACTIVE_INSET, THUMB_ACTIVE_RADIUS),
-        };
-
+        let (width, radius) = Self::thumb_style(false, cx);
         (
             cx.theme().scrollbar_thumb,
             cx.theme().scrollbar,
             gpui::transparent_black(),
             width,
-            inset,
+            THUMB_INSET,
             radius,
         )
     }
 
     fn style_for_idle(cx: &App) -> (Hsla, Hsla, Hsla, Pixels, Pixels, Pixels) {
-        let (width, inset, radius) = match cx.theme().scrollbar_show {
-            ScrollbarShow::Scrolling => (THUMB_WIDTH, THUMB_INSET, THUMB_RADIUS),
-            _ => (THUMB_ACTIVE_WIDTH, THUMB_ACTIVE_INSET, THUMB_ACTIVE_RADIUS),
-        };
-
+        let (width, radius) = Self::thumb_style(false, cx);
         (
             gpui::transparent_black(),
-            gpui::transparent_black(),
+            Self::bar_bg(cx),
             gpui::transparent_black(),
             width,
-            inset,
+            THUMB_INSET,
             radius,
         )
     }
@@ -491,6 +528,7 @@ impl Element for Scrollbar {
         });
 
         let mut states = vec![];
+        let width = width(cx);
         let mut has_both = self.axis.is_both();
         let scroll_size = self
             .scroll_size
@@ -514,7 +552,7 @@ impl Element for Scrollbar {
 
             // The horizontal scrollbar is set avoid overlapping with the vertical scrollbar, if the vertical scrollbar is visible.
             let margin_end = if has_both && !is_vertical {
-                WIDTH
+                width
             } else {
                 px(0.)
             };
@@ -533,23 +571,23 @@ impl Element for Scrollbar {
 
             let bounds = Bounds {
                 origin: if is_vertical {
-                    point(hitbox.origin.x + hitbox.size.width - WIDTH, hitbox.origin.y)
+                    point(hitbox.origin.x + hitbox.size.width - width, hitbox.origin.y)
                 } else {
                     point(
                         hitbox.origin.x,
-                        hitbox.origin.y + hitbox.size.height - WIDTH,
+                        hitbox.origin.y + hitbox.size.height - width,
                     )
                 },
                 size: gpui::Size {
                     width: if is_vertical {
-                        WIDTH
+                        width
                     } else {
                         hitbox.size.width
                     },
                     height: if is_vertical {
                         hitbox.size.height
                     } else {
-                        WIDTH
+                        width
                     },
                 },
             };
@@ -622,13 +660,13 @@ impl Element for Scrollbar {
                 Bounds::from_corner_and_size(
                     Corner::TopRight,
                     bounds.top_right() + point(-inset, inset + thumb_start),
-                    size(WIDTH, thumb_length),
+                    size(width, thumb_length),
                 )
             } else {
                 Bounds::from_corner_and_size(
                     Corner::BottomLeft,
                     bounds.bottom_left() + point(inset + thumb_start, -inset),
-                    size(thumb_length, WIDTH),
+                    size(thumb_length, width),
                 )
             };
 
@@ -0,0 +1,402 @@
+use std::collections::HashSet;
+
+use gpui::{
+    actions, anchored, deferred, div, point, px, AnyElement, App, AppContext as _,
+    AsKeystroke as _, Axis, Context, Entity, FocusHandle, Focusable, Global,
+    InteractiveElement as _, IntoElement, KeyBinding, Keystroke, MouseButton, ParentElement as _,
+    Render, SharedString, Styled as _, Subscription, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::Cancel,
+    h_flex,
+    input::{InputEvent, InputState, TextInput},
+    modal::overlay_color,
+    v_flex, ActiveTheme as _, IconName, Kbd, Root, Sizable as _, StyledExt as _,
+};
+
+actions!(shortcut_help, [ToggleShortcutHelp]);
+
+const CONTEXT: &str = "ShortcutHelp";
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        // Avoid to be triggered when typing in the inputs.
+        KeyBinding::new("?", ToggleShortcutHelp, Some("!Input")),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+    ]);
+
+    cx.on_action(|_: &ToggleShortcutHelp, cx| {
+        let Some(active_window) = cx.active_window() else {
+            return;
+        };
+
+        cx.defer(move |cx| {
+            _ = active_window.update(cx, |_, window, cx| {
+                ShortcutHelp::toggle(window, cx);
+            });
+        });
+    });
+}
+
+/// A keyboard shortcut shown in the [`ShortcutHelp`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortcut {
+    category: SharedString,
+    label: SharedString,
+    keystrokes: Vec<Keystroke>,
+}
+
+impl Shortcut {
+    /// Create a new shortcut, the `keystrokes` is separated by space, e.g.: `cmd-k cmd-s`.
+    ///
+    /// The invalid keystrokes will be ignored.
+    pub fn new(
+        category: impl Into<SharedString>,
+        label: impl Into<SharedString>,
+        keystrokes: &str,
+    ) -> Self {
+        Self {
+            category: category.into(),
+            label: label.into(),
+            keystrokes: keystrokes
+                .split_whitespace()
+                .filter_map(|key| Keystroke::parse(key).ok())
+                .collect(),
+        }
+    }
+
+    /// Create the shortcut from the key binding, the category is the namespace of the action.
+    fn from_binding(binding: &KeyBinding) -> Option<Self> {
+        let name = binding.action().name();
+        let (namespace, action) = name.rsplit_once("::")?;
+        if binding.keystrokes().is_empty() || namespace == "zed" {
+            return None;
+        }
+
+        Some(Self {
+            category: humanize(namespace).into(),
+            label: humanize(action).into(),
+            keystrokes: binding
+                .keystrokes()
+                .iter()
+                .map(|key| key.as_keystroke().clone())
+                .collect(),
+        })
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        if query.is_empty() {
+            return true;
+        }
+
+        self.label.to_lowercase().contains(query)
+            || self.category.to_lowercase().contains(query)
+            || self
+                .keystrokes
+                .iter()
+                .any(|key| key.unparse().contains(query))
+    }
+}
+
+/// The explicitly provided shortcuts, see [`ShortcutHelp::set_shortcuts`].
+struct GlobalShortcuts(Vec<Shortcut>);
+
+impl Global for GlobalShortcuts {}
+
+/// A searchable cheat-sheet overlay of the keyboard shortcuts, grouped by category.
+///
+/// Toggle it by [`ToggleShortcutHelp`] (default `?`), the shortcuts are introspected from the
+/// registered key bindings, or explicitly provided by [`ShortcutHelp::set_shortcuts`].
+pub struct ShortcutHelp {
+    focus_handle: FocusHandle,
+    previous_focus_handle: Option<FocusHandle>,
+    search: Entity<InputState>,
+    shortcuts: Vec<Shortcut>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ShortcutHelp {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let search = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t!("ShortcutHelp.search_placeholder"))
+        });
+        let _subscriptions =
+            vec![
+                cx.subscribe_in(&search, window, |_, _, event: &InputEvent, _, cx| {
+                    if let InputEvent::Change(_) = event {
+                        cx.notify();
+                    }
+                }),
+            ];
+
+        let shortcuts = match cx.try_global::<GlobalShortcuts>() {
+            Some(shortcuts) => shortcuts.0.clone(),
+            None => {
+                let keymap = cx.key_bindings();
+                let keymap = keymap.borrow();
+                dedup_shortcuts(keymap.bindings().filter_map(Shortcut::from_binding))
+            }
+        };
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            previous_focus_handle: window.focused(cx),
+            search,
+            shortcuts,
+            _subscriptions,
+        }
+    }
+
+    /// Set the shortcuts to show, instead of the registered key bindings.
+    pub fn set_shortcuts(shortcuts: Vec<Shortcut>, cx: &mut App) {
+        cx.set_global(GlobalShortcuts(shortcuts));
+    }
+
+    /// Toggle the shortcut help overlay in the window.
+    pub fn toggle(window: &mut Window, cx: &mut App) {
+        if Root::read(window, cx).shortcut_help.is_some() {
+            Self::close(window, cx);
+        } else {
+            let view = cx.new(|cx| Self::new(window, cx));
+            view.read(cx).search.focus_handle(cx).focus(window);
+            Root::update(window, cx, |root, _, cx| {
+                root.shortcut_help = Some(view);
+                cx.notify();
+            });
+        }
+    }
+
+    fn close(window: &mut Window, cx: &mut App) {
+        let view = Root::update(window, cx, |root, _, cx| {
+            cx.notify();
+            root.shortcut_help.take()
+        });
+
+        if let Some(focus_handle) =
+            view.and_then(|view| view.read(cx).previous_focus_handle.clone())
+        {
+            focus_handle.focus(window);
+        }
+    }
+
+    fn render_group(
+        &self,
+        category: SharedString,
+        shortcuts: Vec<&Shortcut>,
+        cx: &App,
+    ) -> impl IntoElement {
+        v_flex()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .font_semibold()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(category),
+            )
+            .children(shortcuts.into_iter().map(|shortcut| {
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .text_sm()
+                    .child(shortcut.label.clone())
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .children(shortcut.keystrokes.iter().cloned().map(Kbd::new)),
+                    )
+            }))
+    }
+}
+
+impl Focusable for ShortcutHelp {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ShortcutHelp {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let query = self.search.read(cx).value().trim().to_lowercase();
+        let groups = group_shortcuts(&self.shortcuts, &query);
+        let view_size = window.viewport_size();
+
+        let content: AnyElement = if groups.is_empty() {
+            div()
+                .py_6()
+                .text_center()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(SharedString::from(t!("ShortcutHelp.no_results")))
+                .into_any_element()
+        } else {
+            v_flex()
+                .gap_4()
+                .children(
+                    groups
+                        .into_iter()
+                        .map(|(category, shortcuts)| self.render_group(category, shortcuts, cx)),
+                )
+                .into_any_element()
+        };
+
+        deferred(
+            anchored().position(point(px(0.), px(0.))).child(
+                div()
+                    .id("shortcut-help")
+                    .occlude()
+                    .w(view_size.width)
+                    .h(view_size.height)
+                    .bg(overlay_color(true, cx))
+                    .flex()
+                    .justify_center()
+                    .pt(view_size.height / 10.)
+                    .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                        Self::close(window, cx);
+                    })
+                    .child(
+                        v_flex()
+                            .key_context(CONTEXT)
+                            .track_focus(&self.focus_handle)
+                            .on_action(|_: &Cancel, window, cx| Self::close(window, cx))
+                            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                            .w(px(560.))
+                            .max_h(view_size.height * 0.7)
+                            .popover_style(cx)
+                            .shadow_xl()
+                            .p_4()
+                            .gap_3()
+                            .child(
+                                div()
+                                    .font_semibold()
+                                    .child(SharedString::from(t!("ShortcutHelp.title"))),
+                            )
+                            .child(
+                                TextInput::new(&self.search)
+                                    .small()
+                                    .cleanable()
+                                    .prefix(IconName::Search),
+                            )
+                            .child(
+                                div().flex_1().overflow_hidden().child(
+                                    v_flex()
+                                        .size_full()
+                                        .scrollable(Axis::Vertical)
+                                        .child(content),
+                                ),
+                            ),
+                    ),
+            ),
+        )
+        .with_priority(2)
+    }
+}
+
+/// Remove the duplicated shortcuts, e.g.: the same action bound in different contexts.
+fn dedup_shortcuts(shortcuts: impl Iterator<Item = Shortcut>) -> Vec<Shortcut> {
+    let mut seen = HashSet::new();
+    shortcuts
+        .filter(|shortcut| {
+            let key = (
+                shortcut.category.clone(),
+                shortcut.label.clone(),
+                shortcut
+                    .keystrokes
+                    .iter()
+                    .map(|key| key.unparse())
+                    .collect::<Vec<_>>(),
+            );
+            seen.insert(key)
+        })
+        .collect()
+}
+
+/// Group the shortcuts matched the `query` (lowercase) by the category, sorted by the category name.
+fn group_shortcuts<'a>(
+    shortcuts: &'a [Shortcut],
+    query: &str,
+) -> Vec<(SharedString, Vec<&'a Shortcut>)> {
+    let mut groups: Vec<(SharedString, Vec<&Shortcut>)> = vec![];
+    for shortcut in shortcuts.iter().filter(|shortcut| shortcut.matches(query)) {
+        match groups
+            .iter_mut()
+            .find(|(category, _)| category == &shortcut.category)
+        {
+            Some((_, items)) => items.push(shortcut),
+            None => groups.push((shortcut.category.clone(), vec![shortcut])),
+        }
+    }
+    groups.sort_by(|a, b| a.0.cmp(&b.0));
+    groups
+}
+
+/// Convert the action name or namespace to a human readable label,
+/// e.g.: `SelectAll` to `Select All`, `date_picker` to `Date Picker`.
+fn humanize(name: &str) -> String {
+    let mut label = String::with_capacity(name.len() + 4);
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if c == '_' {
+            label.push(' ');
+        } else if c.is_uppercase() && prev.map_or(false, |prev| prev.is_lowercase()) {
+            label.push(' ');
+            label.push(c);
+        } else if prev.map_or(true, |prev| prev == '_') {
+            label.extend(c.to_uppercase());
+        } else {
+            label.push(c);
+        }
+        prev = Some(c);
+    }
+    label
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(humanize("SelectAll"), "Select All");
+        assert_eq!(humanize("date_picker"), "Date Picker");
+        assert_eq!(humanize("input"), "Input");
+        assert_eq!(humanize("MoveToPrevWord"), "Move To Prev Word");
+    }
+
+    #[test]
+    fn test_group_shortcuts() {
+        let shortcuts = vec![
+            Shortcut::new("Input", "Copy", "cmd-c"),
+            Shortcut::new("Editor", "Save", "ctrl-s"),
+            Shortcut::new("Input", "Paste", "cmd-v"),
+            Shortcut::new("Editor", "Open Recent", "cmd-k cmd-r"),
+        ];
+        assert_eq!(shortcuts[3].keystrokes.len(), 2);
+
+        let groups = group_shortcuts(&shortcuts, "");
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "Editor");
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].0, "Input");
+        assert_eq!(groups[1].1[1].label, "Paste");
+
+        let groups = group_shortcuts(&shortcuts, "paste");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1[0].label, "Paste");
+
+        let groups = group_shortcuts(&shortcuts, "ctrl-s");
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].1[0].label, "Save");
+
+        assert!(group_shortcuts(&shortcuts, "unknown").is_empty());
+
+        let shortcuts = dedup_shortcuts(
+            shortcuts
+                .into_iter()
+                .chain([Shortcut::new("Input", "Copy", "cmd-c")]),
+        );
+        assert_eq!(shortcuts.len(), 4);
+    }
+}
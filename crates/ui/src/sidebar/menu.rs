@@ -146,6 +146,7 @@ impl RenderOnce for SidebarMenuItem {
         let is_active = self.active;
         let is_open = self.is_open();
         let is_submenu = self.is_submenu();
+        let direction = cx.theme().direction;
 
         div()
             .id(self.id.clone())
@@ -198,9 +199,13 @@ impl RenderOnce for SidebarMenuItem {
                             )
                             .when(is_submenu, |this| {
                                 this.child(
-                                    Icon::new(IconName::ChevronRight)
+                                    Icon::new(IconName::ChevronRight.mirrored(direction))
                                         .size_4()
-                                        .when(is_open, |this| this.rotate(percentage(90. / 360.))),
+                                        .when(is_open, |this| {
+                                            let degrees =
+                                                if direction.is_rtl() { -90. } else { 90. };
+                                            this.rotate(percentage(degrees / 360.))
+                                        }),
                                 )
                             })
                     })
@@ -1,14 +1,20 @@
-use crate::{ActiveTheme, StyledExt};
+use crate::{animation::cubic_bezier, h_flex, v_flex, ActiveTheme, StyledExt};
 use gpui::{
-    bounce, div, ease_in_out, Animation, AnimationExt, IntoElement, RenderOnce, StyleRefinement,
-    Styled,
+    bounce, div, ease_in_out, linear_color_stop, linear_gradient, prelude::FluentBuilder as _, px,
+    relative, transparent_black, Animation, AnimationExt, AnyElement, App, IntoElement,
+    ParentElement, RenderOnce, StyleRefinement, Styled, Window,
 };
 use std::time::Duration;
 
+/// A placeholder block to show while the content is loading.
+///
+/// Use [`Skeleton::new`] for a rectangle, [`Skeleton::circle`] for a circle,
+/// or the presets: [`SkeletonText`], [`SkeletonTableRow`] and [`SkeletonList`].
 #[derive(IntoElement)]
 pub struct Skeleton {
     style: StyleRefinement,
     secondary: bool,
+    shimmer: bool,
 }
 
 impl Skeleton {
@@ -16,14 +22,26 @@ impl Skeleton {
         Self {
             style: StyleRefinement::default(),
             secondary: false,
+            shimmer: false,
         }
     }
 
+    /// Create a circle skeleton, default size is 40px.
+    pub fn circle() -> Self {
+        Self::new().size_10().rounded_full()
+    }
+
     /// Set use secondary color.
     pub fn secondary(mut self, secondary: bool) -> Self {
         self.secondary = secondary;
         self
     }
+
+    /// Set to use a moving shimmer animation instead of the pulse animation.
+    pub fn shimmer(mut self, shimmer: bool) -> Self {
+        self.shimmer = shimmer;
+        self
+    }
 }
 
 impl Styled for Skeleton {
@@ -34,7 +52,7 @@ impl Styled for Skeleton {
 
 impl RenderOnce for Skeleton {
     fn render(self, _: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        div()
+        let base = div()
             .w_full()
             .h_4()
             .bg(if self.secondary {
@@ -42,16 +60,250 @@ impl RenderOnce for Skeleton {
             } else {
                 cx.theme().skeleton
             })
+            .refine_style(&self.style);
+
+        if cx.theme().reduce_motion {
+            return base.into_any_element();
+        }
+
+        if !self.shimmer {
+            return base
+                .with_animation(
+                    "skeleton",
+                    Animation::new(Duration::from_secs(2))
+                        .repeat()
+                        .with_easing(bounce(ease_in_out)),
+                    move |this, delta| {
+                        let v = 1.0 - delta * 0.5;
+                        this.opacity(v)
+                    },
+                )
+                .into_any_element();
+        }
+
+        let highlight = cx.theme().background.opacity(0.5);
+        base.relative()
+            .overflow_hidden()
+            .child(
+                h_flex()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .w(relative(0.5))
+                    .child(div().size_full().bg(linear_gradient(
+                        90.,
+                        linear_color_stop(transparent_black(), 0.),
+                        linear_color_stop(highlight, 1.),
+                    )))
+                    .child(div().size_full().bg(linear_gradient(
+                        90.,
+                        linear_color_stop(highlight, 0.),
+                        linear_color_stop(transparent_black(), 1.),
+                    )))
+                    .with_animation(
+                        "skeleton-shimmer",
+                        Animation::new(Duration::from_millis(1500))
+                            .repeat()
+                            .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                        // Move from the left outside to the right outside.
+                        move |this, delta| this.left(relative(delta * 1.5 - 0.5)),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+/// A paragraph of text lines skeleton, the last line is shorter.
+#[derive(IntoElement)]
+pub struct SkeletonText {
+    style: StyleRefinement,
+    lines: usize,
+    shimmer: bool,
+}
+
+impl SkeletonText {
+    /// Create a new text skeleton with the number of lines.
+    pub fn new(lines: usize) -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            lines: lines.max(1),
+            shimmer: false,
+        }
+    }
+
+    /// Set to use the shimmer animation, see [`Skeleton::shimmer`].
+    pub fn shimmer(mut self, shimmer: bool) -> Self {
+        self.shimmer = shimmer;
+        self
+    }
+}
+
+impl Styled for SkeletonText {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for SkeletonText {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        let lines = self.lines;
+
+        v_flex()
+            .w_full()
+            .gap_2()
             .refine_style(&self.style)
-            .with_animation(
-                "skeleton",
-                Animation::new(Duration::from_secs(2))
-                    .repeat()
-                    .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
+            .children((0..lines).map(|ix| {
+                Skeleton::new()
+                    .shimmer(self.shimmer)
+                    .rounded_md()
+                    .when(lines > 1 && ix + 1 == lines, |this| this.w(relative(0.6)))
+            }))
+    }
+}
+
+/// A table row skeleton with the number of columns.
+#[derive(IntoElement)]
+pub struct SkeletonTableRow {
+    style: StyleRefinement,
+    columns: usize,
+    shimmer: bool,
+}
+
+impl SkeletonTableRow {
+    /// Create a new table row skeleton with the number of columns.
+    pub fn new(columns: usize) -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            columns: columns.max(1),
+            shimmer: false,
+        }
+    }
+
+    /// Set to use the shimmer animation, see [`Skeleton::shimmer`].
+    pub fn shimmer(mut self, shimmer: bool) -> Self {
+        self.shimmer = shimmer;
+        self
+    }
+}
+
+impl Styled for SkeletonTableRow {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for SkeletonTableRow {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        h_flex()
+            .w_full()
+            .gap_4()
+            .px_2()
+            .py_2p5()
+            .border_b_1()
+            .border_color(cx.theme().table_row_border)
+            .refine_style(&self.style)
+            .children(
+                (0..self.columns)
+                    .map(|_| Skeleton::new().shimmer(self.shimmer).flex_1().rounded_md()),
             )
     }
 }
+
+/// A list skeleton, each item has an avatar and two lines of text.
+#[derive(IntoElement)]
+pub struct SkeletonList {
+    style: StyleRefinement,
+    items: usize,
+    avatar: bool,
+    shimmer: bool,
+}
+
+impl SkeletonList {
+    /// Create a new list skeleton with the number of items.
+    pub fn new(items: usize) -> Self {
+        Self {
+            style: StyleRefinement::default(),
+            items,
+            avatar: true,
+            shimmer: false,
+        }
+    }
+
+    /// Set to show the avatar circle of the items, default: true
+    pub fn avatar(mut self, avatar: bool) -> Self {
+        self.avatar = avatar;
+        self
+    }
+
+    /// Set to use the shimmer animation, see [`Skeleton::shimmer`].
+    pub fn shimmer(mut self, shimmer: bool) -> Self {
+        self.shimmer = shimmer;
+        self
+    }
+}
+
+impl Styled for SkeletonList {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for SkeletonList {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        v_flex()
+            .w_full()
+            .gap_4()
+            .refine_style(&self.style)
+            .children((0..self.items).map(|_| {
+                h_flex()
+                    .gap_3()
+                    .when(self.avatar, |this| {
+                        this.child(Skeleton::circle().shimmer(self.shimmer).flex_shrink_0())
+                    })
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .gap_2()
+                            .child(
+                                Skeleton::new()
+                                    .shimmer(self.shimmer)
+                                    .w(relative(0.5))
+                                    .rounded_md(),
+                            )
+                            .child(
+                                Skeleton::new()
+                                    .shimmer(self.shimmer)
+                                    .h(px(12.))
+                                    .rounded_md(),
+                            ),
+                    )
+            }))
+    }
+}
+
+/// Extension trait to swap the content for a skeleton while loading.
+pub trait SkeletonExt: ParentElement + Sized {
+    /// Renders the `skeleton` if `loading` is true, otherwise renders the `content`.
+    ///
+    /// The `content` is only built when not loading.
+    ///
+    /// ```ignore
+    /// div().when_loading(self.loading, SkeletonText::new(3), || self.render_content())
+    /// ```
+    fn when_loading<E: IntoElement>(
+        mut self,
+        loading: bool,
+        skeleton: impl IntoElement,
+        content: impl FnOnce() -> E,
+    ) -> Self {
+        let child: AnyElement = if loading {
+            skeleton.into_any_element()
+        } else {
+            content().into_any_element()
+        };
+        self.extend(std::iter::once(child));
+        self
+    }
+}
+
+impl<T: ParentElement> SkeletonExt for T {}
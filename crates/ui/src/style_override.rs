@@ -0,0 +1,203 @@
+use std::{any::TypeId, collections::HashMap, rc::Rc};
+
+use gpui::{App, Global, Refineable as _, StyleRefinement, Styled};
+
+pub(crate) fn init(cx: &mut App) {
+    cx.set_global(StyleOverrides::default());
+}
+
+/// The style builder passed to a style override function.
+///
+/// This is a [`Styled`] wrapper of a [`StyleRefinement`], so all of the
+/// styled methods (`rounded_none`, `bg`, `px_2` ...) are available.
+#[derive(Default, Clone)]
+pub struct StyleOverride {
+    style: StyleRefinement,
+}
+
+impl StyleOverride {
+    /// Returns the style refinement built by the override.
+    pub fn into_style(self) -> StyleRefinement {
+        self.style
+    }
+}
+
+impl Styled for StyleOverride {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+type StyleOverrideFn = Rc<dyn Fn(StyleOverride, &App) -> StyleOverride>;
+
+/// A global registry of style overrides keyed by component kind.
+///
+/// The overrides of a component kind are applied after the component's
+/// default styles, but before the styles set on the element instance,
+/// so you can change the look of all components (e.g. all Buttons have no radius)
+/// without forking them.
+///
+/// ```ignore
+/// use gpui_component::{button::Button, StyleOverrides};
+///
+/// StyleOverrides::global_mut(cx).register::<Button>(|this, _| this.rounded_none());
+/// ```
+///
+/// The component kind is the type of the component, e.g.: [`crate::button::Button`],
+/// [`crate::input::TextInput`], for some generic components there are marker types,
+/// e.g.: [`crate::table::TableRow`], [`crate::table::TableStripeRow`].
+#[derive(Default)]
+pub struct StyleOverrides {
+    overrides: HashMap<TypeId, Vec<StyleOverrideFn>>,
+}
+
+impl Global for StyleOverrides {}
+
+impl StyleOverrides {
+    /// Returns the global style overrides.
+    #[inline]
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    /// Returns the global style overrides mutable reference.
+    #[inline]
+    pub fn global_mut(cx: &mut App) -> &mut Self {
+        cx.global_mut::<Self>()
+    }
+
+    /// Register a style override for the component kind `K`.
+    ///
+    /// Multiple overrides can be registered for the same kind,
+    /// they will be applied in the order of registration.
+    pub fn register<K: 'static>(
+        &mut self,
+        f: impl Fn(StyleOverride, &App) -> StyleOverride + 'static,
+    ) -> &mut Self {
+        self.overrides
+            .entry(TypeId::of::<K>())
+            .or_default()
+            .push(Rc::new(f));
+        self
+    }
+
+    /// Remove all the style overrides of the component kind `K`.
+    pub fn clear<K: 'static>(&mut self) -> &mut Self {
+        self.overrides.remove(&TypeId::of::<K>());
+        self
+    }
+
+    /// Returns true if there has any style override for the component kind `K`.
+    pub fn has<K: 'static>(&self) -> bool {
+        self.overrides
+            .get(&TypeId::of::<K>())
+            .is_some_and(|fns| !fns.is_empty())
+    }
+
+    /// Returns the merged style for the component kind `K`.
+    ///
+    /// Returns an empty [`StyleRefinement`] if there is no override registered.
+    pub fn style_for<K: 'static>(cx: &App) -> StyleRefinement {
+        let Some(this) = cx.try_global::<Self>() else {
+            return StyleRefinement::default();
+        };
+        let Some(fns) = this.overrides.get(&TypeId::of::<K>()) else {
+            return StyleRefinement::default();
+        };
+
+        merge_styles(
+            StyleRefinement::default(),
+            fns.iter().map(|f| move |style| f(style, cx)),
+            &StyleRefinement::default(),
+        )
+    }
+}
+
+/// Merge the styles in order of the precedence, the later wins:
+///
+/// 1. The `base` style of the component.
+/// 2. The `overrides` of the component kind, in the order of registration.
+/// 3. The `instance` style set on the element.
+fn merge_styles<F>(
+    base: StyleRefinement,
+    overrides: impl IntoIterator<Item = F>,
+    instance: &StyleRefinement,
+) -> StyleRefinement
+where
+    F: FnOnce(StyleOverride) -> StyleOverride,
+{
+    let mut style = overrides
+        .into_iter()
+        .fold(StyleOverride { style: base }, |style, f| f(style))
+        .into_style();
+    style.refine(instance);
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{px, StyleRefinement, Styled as _};
+
+    use super::{merge_styles, StyleOverride};
+    use crate::StyledExt as _;
+
+    #[test]
+    fn test_merge_styles() {
+        let base = StyleOverride::default()
+            .rounded(px(4.))
+            .bg(gpui::red())
+            .p(px(1.));
+        let overrides = [
+            |this: StyleOverride| this.bg(gpui::blue()).p(px(2.)),
+            |this: StyleOverride| this.p(px(3.)),
+        ];
+        let instance = StyleOverride::default().bg(gpui::green()).into_style();
+
+        let style = merge_styles(base.clone().into_style(), overrides, &instance);
+        // The base is kept if not overridden.
+        assert_eq!(style.corner_radii.top_left, Some(px(4.).into()));
+        // The later override wins.
+        assert_eq!(style.padding.left, Some(px(3.).into()));
+        // The instance style wins over the overrides.
+        assert_eq!(style.background, Some(gpui::green().into()));
+
+        let style = merge_styles(
+            base.clone().into_style(),
+            overrides,
+            &StyleRefinement::default(),
+        );
+        assert_eq!(style.background, Some(gpui::blue().into()));
+
+        let no_overrides: [fn(StyleOverride) -> StyleOverride; 0] = [];
+        let style = merge_styles(base.into_style(), no_overrides, &StyleRefinement::default());
+        assert_eq!(style.background, Some(gpui::red().into()));
+        assert_eq!(style.padding.left, Some(px(1.).into()));
+    }
+
+    #[test]
+    fn test_refine_style_precedence() {
+        // Like `StyleOverrides::style_for`, the overrides are merged without the base.
+        let overrides = merge_styles(
+            StyleRefinement::default(),
+            [|this: StyleOverride| this.rounded_none().p(px(2.))],
+            &StyleRefinement::default(),
+        );
+        let instance = StyleOverride::default().p(px(3.)).into_style();
+
+        // Like the components: the defaults, then the overrides, then the instance style.
+        let style = StyleOverride::default()
+            .rounded(px(4.))
+            .bg(gpui::red())
+            .p(px(1.))
+            .refine_style(&overrides)
+            .refine_style(&instance)
+            .into_style();
+        // The default is kept if not overridden.
+        assert_eq!(style.background, Some(gpui::red().into()));
+        // The override wins over the default.
+        assert_eq!(style.corner_radii.top_left, Some(px(0.).into()));
+        // Both the override and the instance set the padding, the instance style wins.
+        assert_eq!(style.padding.left, Some(px(3.).into()));
+        assert_eq!(style.padding.right, Some(px(3.).into()));
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
     ActiveTheme,
 };
 use gpui::{
-    div, point, px, App, Axis, BoxShadow, Corners, DefiniteLength, Div, Edges, Element,
-    FocusHandle, Hsla, ParentElement, Pixels, Refineable, StyleRefinement, Styled, Window,
+    div, point, px, AbsoluteLength, App, Axis, BoxShadow, Corner, Corners, DefiniteLength, Div,
+    Edges, Element, FlexDirection, FocusHandle, Hsla, Length, ParentElement, Pixels, Refineable,
+    StyleRefinement, Styled, Window,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Returns a `Div` as horizontal flex layout.
@@ -79,6 +81,13 @@ pub trait StyledExt: Styled + Sized {
         self.flex().flex_col()
     }
 
+    /// Hide the element, it will not take any space in the layout.
+    #[inline]
+    fn hidden(mut self) -> Self {
+        self.style().display = Some(gpui::Display::None);
+        self
+    }
+
     /// Apply paddings to the element.
     fn paddings<L>(self, paddings: impl Into<Edges<L>>) -> Self
     where
@@ -349,6 +358,157 @@ impl From<Pixels> for Size {
     }
 }
 
+/// The density of elements, used to scale the heights and paddings.
+#[derive(Clone, Default, Copy, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    /// Returns the scale factor of the heights, `Comfortable` is 1.0.
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        match self {
+            Density::Compact => 0.8,
+            Density::Comfortable => 1.,
+            Density::Spacious => 1.25,
+        }
+    }
+
+    /// Returns true if the density is compact.
+    #[inline]
+    pub fn is_compact(&self) -> bool {
+        matches!(self, Self::Compact)
+    }
+
+    /// Scale the length by the density, the pixels are rounded to whole pixels.
+    pub fn scale_length(&self, length: AbsoluteLength) -> AbsoluteLength {
+        match length {
+            AbsoluteLength::Pixels(value) => AbsoluteLength::Pixels((value * self.scale()).round()),
+            AbsoluteLength::Rems(value) => AbsoluteLength::Rems(value * self.scale()),
+        }
+    }
+}
+
+/// The layout direction of the elements, the layout is mirrored in `Rtl` for the
+/// right-to-left languages (e.g.: Arabic, Hebrew).
+///
+/// Only the layout is mirrored, the text is not reordered by the bidirectional algorithm,
+/// and the Left / Right keys in the inputs follow the painted text.
+#[derive(Clone, Default, Copy, PartialEq, Eq, Debug, Deserialize, Serialize, JsonSchema)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    /// Returns true if the direction is right-to-left.
+    #[inline]
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+
+    /// Returns the mirrored placement, the `Left` and `Right` are swapped in `Rtl`.
+    pub fn placement(&self, placement: Placement) -> Placement {
+        match (self, placement) {
+            (Self::Rtl, Placement::Left) => Placement::Right,
+            (Self::Rtl, Placement::Right) => Placement::Left,
+            _ => placement,
+        }
+    }
+
+    /// Returns the mirrored side, the `Left` and `Right` are swapped in `Rtl`.
+    pub fn side(&self, side: Side) -> Side {
+        match (self, side) {
+            (Self::Rtl, Side::Left) => Side::Right,
+            (Self::Rtl, Side::Right) => Side::Left,
+            _ => side,
+        }
+    }
+
+    /// Returns the mirrored corner, the left and right corners are swapped in `Rtl`.
+    pub fn corner(&self, corner: Corner) -> Corner {
+        if !self.is_rtl() {
+            return corner;
+        }
+
+        match corner {
+            Corner::TopLeft => Corner::TopRight,
+            Corner::TopRight => Corner::TopLeft,
+            Corner::BottomLeft => Corner::BottomRight,
+            Corner::BottomRight => Corner::BottomLeft,
+        }
+    }
+}
+
+/// A trait to scale the element by the [`Density`].
+pub(crate) trait DensityExt: Styled + Sized {
+    /// Scale the absolute sizes and paddings already set on the element by the `density`.
+    ///
+    /// This must be called after the default sizes of the component,
+    /// but before refining the user styles, so the user styles are not scaled.
+    fn density(mut self, density: Density) -> Self {
+        if density == Density::Comfortable {
+            return self;
+        }
+
+        let style = self.style();
+        for length in [
+            &mut style.size.width,
+            &mut style.size.height,
+            &mut style.min_size.height,
+        ] {
+            if let Some(Length::Definite(DefiniteLength::Absolute(value))) = length {
+                *value = density.scale_length(*value);
+            }
+        }
+        for length in [
+            &mut style.padding.top,
+            &mut style.padding.right,
+            &mut style.padding.bottom,
+            &mut style.padding.left,
+        ] {
+            if let Some(DefiniteLength::Absolute(value)) = length {
+                *value = density.scale_length(*value);
+            }
+        }
+
+        self
+    }
+}
+
+impl<T: Styled> DensityExt for T {}
+
+/// A trait to mirror the element by the [`Direction`].
+pub(crate) trait DirectionExt: Styled + Sized {
+    /// Mirror the horizontal paddings, margins and the row direction already set on the element.
+    ///
+    /// This must be called after the default styles of the component,
+    /// but before refining the user styles, so the user styles are not mirrored.
+    fn direction(mut self, direction: Direction) -> Self {
+        if !direction.is_rtl() {
+            return self;
+        }
+
+        let style = self.style();
+        std::mem::swap(&mut style.padding.left, &mut style.padding.right);
+        std::mem::swap(&mut style.margin.left, &mut style.margin.right);
+        style.flex_direction = match style.flex_direction {
+            Some(FlexDirection::Row) => Some(FlexDirection::RowReverse),
+            Some(FlexDirection::RowReverse) => Some(FlexDirection::Row),
+            other => other,
+        };
+
+        self
+    }
+}
+
+impl<T: Styled> DirectionExt for T {}
+
 /// A trait for defining element that can be selected.
 pub trait Selectable: Sized {
     /// Set the selected state of the element.
@@ -711,9 +871,12 @@ impl PixelsExt for Pixels {
 
 #[cfg(test)]
 mod tests {
-    use gpui::px;
+    use gpui::{
+        div, px, relative, rems, AbsoluteLength, Corner, DefiniteLength, FlexDirection, Length,
+        Styled as _,
+    };
 
-    use crate::Size;
+    use crate::{Density, DensityExt as _, Direction, DirectionExt as _, Placement, Side, Size};
 
     #[test]
     fn test_size_max_min() {
@@ -740,4 +903,52 @@ mod tests {
             Size::Size(px(10.))
         );
     }
+
+    #[test]
+    fn test_density() {
+        assert_eq!(
+            Density::Compact.scale_length(px(32.).into()),
+            AbsoluteLength::Pixels(px(26.))
+        );
+        assert_eq!(
+            Density::Spacious.scale_length(rems(2.).into()),
+            AbsoluteLength::Rems(rems(2.5))
+        );
+
+        let mut el = div()
+            .h(px(30.))
+            .w_full()
+            .py(px(10.))
+            .density(Density::Spacious);
+        let style = el.style();
+        assert_eq!(style.size.height, Some(px(38.).into()));
+        assert_eq!(style.size.width, Some(relative(1.).into()));
+        assert_eq!(
+            style.padding.top,
+            Some(DefiniteLength::Absolute(px(13.).into()))
+        );
+
+        let mut el = div().h(px(30.)).density(Density::Comfortable);
+        assert_eq!(el.style().size.height, Some(Length::from(px(30.))));
+    }
+
+    #[test]
+    fn test_direction() {
+        let mut el = div().flex().flex_row().pl(px(4.)).direction(Direction::Rtl);
+        let style = el.style();
+        assert_eq!(style.flex_direction, Some(FlexDirection::RowReverse));
+        assert_eq!(style.padding.left, None);
+        assert_eq!(
+            style.padding.right,
+            Some(DefiniteLength::Absolute(px(4.).into()))
+        );
+
+        let mut el = div().flex().flex_row().direction(Direction::Ltr);
+        assert_eq!(el.style().flex_direction, Some(FlexDirection::Row));
+
+        assert_eq!(Direction::Rtl.placement(Placement::Left), Placement::Right);
+        assert_eq!(Direction::Rtl.placement(Placement::Top), Placement::Top);
+        assert_eq!(Direction::Rtl.corner(Corner::TopLeft), Corner::TopRight);
+        assert_eq!(Direction::Ltr.side(Side::Left), Side::Left);
+    }
 }
@@ -1,10 +1,17 @@
 use crate::{
-    h_flex, text::Text, tooltip::Tooltip, ActiveTheme, Disableable, Side, Sizable, Size, StyledExt,
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Role},
+    form::{apply_change, before_change_pending, BeforeChange, FieldChange},
+    h_flex,
+    indicator::Indicator,
+    text::Text,
+    tooltip::Tooltip,
+    v_flex, ActiveTheme, Disableable, FocusableExt as _, Side, Sizable, Size, StyleOverrides,
+    StyledExt,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, App, ElementId,
-    InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString,
-    StatefulInteractiveElement, StyleRefinement, Styled, Window,
+    InteractiveElement, IntoElement, ParentElement as _, Refineable as _, RenderOnce, SharedString,
+    StatefulInteractiveElement, StyleRefinement, Styled, Task, Window,
 };
 use std::{rc::Rc, time::Duration};
 
@@ -15,11 +22,20 @@ pub struct Switch {
     style: StyleRefinement,
     checked: bool,
     disabled: bool,
+    invalid: bool,
     label: Option<Text>,
     label_side: Side,
+    description: Option<Text>,
+    name: Option<SharedString>,
+    value: Option<SharedString>,
     on_click: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
+    on_field_change: Option<Rc<dyn Fn(&FieldChange, &mut Window, &mut App)>>,
+    before_change: Option<BeforeChange>,
     size: Size,
     tooltip: Option<SharedString>,
+    tab_stop: bool,
+    tab_index: isize,
+    accessibility: AccessibilityProps,
 }
 
 impl Switch {
@@ -30,11 +46,20 @@ impl Switch {
             style: StyleRefinement::default(),
             checked: false,
             disabled: false,
+            invalid: false,
             label: None,
+            description: None,
+            name: None,
+            value: None,
             on_click: None,
+            on_field_change: None,
+            before_change: None,
             label_side: Side::Right,
             size: Size::Medium,
             tooltip: None,
+            tab_stop: true,
+            tab_index: 0,
+            accessibility: AccessibilityProps::default(),
         }
     }
 
@@ -48,6 +73,12 @@ impl Switch {
         self
     }
 
+    /// Set the description below the label.
+    pub fn description(mut self, description: impl Into<Text>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn on_click<F>(mut self, handler: F) -> Self
     where
         F: Fn(&bool, &mut Window, &mut App) + 'static,
@@ -56,6 +87,58 @@ impl Switch {
         self
     }
 
+    /// Listen to the change with the form metadata, this is called after the `on_click`.
+    pub fn on_field_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&FieldChange, &mut Window, &mut App) + 'static,
+    {
+        self.on_field_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the guard to confirm the change before the `on_click` and `on_field_change` are called,
+    /// the first argument is the new checked state.
+    ///
+    /// The switch shows the loading state until the task is resolved,
+    /// and the change is vetoed if it resolves `false`, e.g.: the user cancels a confirm dialog.
+    ///
+    /// ```ignore
+    /// Switch::new("sync")
+    ///     .checked(self.sync)
+    ///     .on_before_change(|checked, window, cx| {
+    ///         let (tx, rx) = oneshot::channel();
+    ///         // Open a confirm dialog, send the result to `tx`.
+    ///         cx.background_spawn(async move { rx.await.unwrap_or(false) })
+    ///     })
+    ///     .on_click(cx.listener(|this, checked, _, _| this.sync = *checked))
+    /// ```
+    pub fn on_before_change<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&bool, &mut Window, &mut App) -> Task<bool> + 'static,
+    {
+        self.before_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the name of the switch in the form, see [`FieldChange`].
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the value of the switch in the form, see [`FieldChange`].
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Set the invalid state to show the validation error, default is false.
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    /// Set the side of the label to the switch, default is [`Side::Right`].
     pub fn label_side(mut self, label_side: Side) -> Self {
         self.label_side = label_side;
         self
@@ -65,6 +148,18 @@ impl Switch {
         self.tooltip = Some(tooltip.into());
         self
     }
+
+    /// Set the tab stop for the switch, default is true.
+    pub fn tab_stop(mut self, tab_stop: bool) -> Self {
+        self.tab_stop = tab_stop;
+        self
+    }
+
+    /// Set the tab index for the switch, default is 0.
+    pub fn tab_index(mut self, tab_index: isize) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
 }
 
 impl Styled for Switch {
@@ -80,6 +175,12 @@ impl Sizable for Switch {
     }
 }
 
+impl Accessible for Switch {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Disableable for Switch {
     fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -91,7 +192,31 @@ impl RenderOnce for Switch {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let checked = self.checked;
         let on_click = self.on_click.clone();
+        let on_field_change = self.on_field_change.clone();
         let toggle_state = window.use_keyed_state(self.id.clone(), cx, |_, _| checked);
+        let focus_handle = window
+            .use_keyed_state(self.id.clone(), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let is_focused = focus_handle.is_focused(window);
+        let pending = self
+            .before_change
+            .as_ref()
+            .map(|_| before_change_pending(&self.id, window, cx));
+        let loading = pending.as_ref().is_some_and(|pending| *pending.read(cx));
+
+        let mut props = AccessibilityProps::new(Role::Switch)
+            .checked(checked)
+            .disabled(self.disabled)
+            .invalid(self.invalid);
+        props.label = self
+            .label
+            .as_ref()
+            .and_then(|label| label.as_str().cloned());
+        Accessibility::declare(&self.id, &props.merge(&self.accessibility), window, cx);
+        if cx.theme().reduce_motion && *toggle_state.read(cx) != checked {
+            toggle_state.update(cx, |this, _| *this = checked);
+        }
 
         let (bg, toggle_bg) = match checked {
             true => (cx.theme().primary, cx.theme().background),
@@ -122,9 +247,19 @@ impl RenderOnce for Switch {
             cx.theme().radius
         };
 
-        div().refine_style(&self.style).child(
+        let mut style = StyleOverrides::style_for::<Self>(cx);
+        style.refine(&self.style);
+
+        div().refine_style(&style).child(
             h_flex()
                 .id(self.id.clone())
+                .when(!self.disabled, |this| {
+                    this.track_focus(
+                        &focus_handle
+                            .tab_stop(self.tab_stop)
+                            .tab_index(self.tab_index),
+                    )
+                })
                 .gap_2()
                 .items_start()
                 .when(self.label_side.is_left(), |this| this.flex_row_reverse())
@@ -139,7 +274,11 @@ impl RenderOnce for Switch {
                         .items_center()
                         .border(inset)
                         .border_color(cx.theme().transparent)
+                        .when(self.invalid, |this| {
+                            this.border_1().border_color(cx.theme().danger)
+                        })
                         .bg(bg)
+                        .focus_ring(is_focused, px(2.), window, cx)
                         .when_some(self.tooltip.clone(), |this, tooltip| {
                             this.tooltip(move |window, cx| {
                                 Tooltip::new(tooltip.clone()).build(window, cx)
@@ -152,6 +291,16 @@ impl RenderOnce for Switch {
                                 .bg(toggle_bg)
                                 .shadow_md()
                                 .size(bar_width)
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .when(loading, |this| {
+                                    this.child(
+                                        Indicator::new()
+                                            .with_size(Size::Size(bar_width - inset * 2))
+                                            .color(cx.theme().muted_foreground),
+                                    )
+                                })
                                 .map(|this| {
                                     let prev_checked = toggle_state.read(cx);
                                     if !self.disabled && *prev_checked != checked {
@@ -188,25 +337,60 @@ impl RenderOnce for Switch {
                                 }),
                         ),
                 )
-                .when_some(self.label, |this, label| {
-                    this.child(div().line_height(bg_height).child(label).map(
-                        |this| match self.size {
-                            Size::XSmall | Size::Small => this.text_sm(),
-                            _ => this.text_base(),
-                        },
-                    ))
+                .when(self.label.is_some() || self.description.is_some(), |this| {
+                    this.child(
+                        v_flex()
+                            .when_some(self.label, |this, label| {
+                                this.child(div().line_height(bg_height).child(label).map(|this| {
+                                    match self.size {
+                                        Size::XSmall | Size::Small => this.text_sm(),
+                                        _ => this.text_base(),
+                                    }
+                                }))
+                            })
+                            .when_some(self.description, |this, description| {
+                                this.child(
+                                    div()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .map(|this| match self.size {
+                                            Size::XSmall | Size::Small => this.text_xs(),
+                                            _ => this.text_sm(),
+                                        })
+                                        .child(description),
+                                )
+                            }),
+                    )
                 })
-                .when_some(
-                    on_click
-                        .as_ref()
-                        .map(|c| c.clone())
-                        .filter(|_| !self.disabled),
-                    |this, on_click| {
+                .when(
+                    !self.disabled && !loading && (on_click.is_some() || on_field_change.is_some()),
+                    |this| {
                         let toggle_state = toggle_state.clone();
-                        this.on_mouse_down(gpui::MouseButton::Left, move |_, window, cx| {
+                        let before_change = self.before_change.clone();
+                        let change = FieldChange {
+                            name: self.name.clone(),
+                            value: self.value.clone(),
+                            checked: !checked,
+                        };
+                        this.on_mouse_down(gpui::MouseButton::Left, |_, window, _| {
+                            // Avoid focus on mouse down.
+                            window.prevent_default();
+                        })
+                        .on_click(move |_, window, cx| {
                             cx.stop_propagation();
                             _ = toggle_state.update(cx, |this, _| *this = checked);
-                            on_click(&!checked, window, cx);
+                            let on_click = on_click.clone();
+                            let on_field_change = on_field_change.clone();
+                            let apply =
+                                move |change: &FieldChange, window: &mut Window, cx: &mut App| {
+                                    if let Some(on_click) = &on_click {
+                                        on_click(&change.checked, window, cx);
+                                    }
+                                    if let Some(on_field_change) = &on_field_change {
+                                        on_field_change(change, window, cx);
+                                    }
+                                };
+                            let guard = before_change.clone().zip(pending.clone());
+                            apply_change(change.clone(), guard, apply, window, cx);
                         })
                     },
                 ),
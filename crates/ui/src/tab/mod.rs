@@ -1,5 +1,13 @@
+use gpui::App;
+
 mod tab;
 mod tab_bar;
+mod tabs;
 
 pub use tab::*;
 pub use tab_bar::*;
+pub use tabs::*;
+
+pub(crate) fn init(cx: &mut App) {
+    tabs::init(cx);
+}
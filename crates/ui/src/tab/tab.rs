@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::{h_flex, ActiveTheme, Icon, IconName, Selectable, Sizable, Size, StyledExt};
+use crate::{
+    accessibility::{Accessibility, AccessibilityProps, Accessible, Role},
+    h_flex, ActiveTheme, Icon, IconName, Selectable, Sizable, Size, StyledExt,
+};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
     div, px, relative, AnyElement, App, ClickEvent, Div, Edges, ElementId, Hsla,
@@ -392,6 +395,7 @@ pub struct Tab {
     pub(super) disabled: bool,
     pub(super) selected: bool,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    accessibility: AccessibilityProps,
 }
 
 impl From<&'static str> for Tab {
@@ -441,6 +445,7 @@ impl Default for Tab {
             variant: TabVariant::default(),
             size: Size::default(),
             on_click: None,
+            accessibility: AccessibilityProps::default(),
         }
     }
 }
@@ -546,6 +551,12 @@ impl Selectable for Tab {
     }
 }
 
+impl Accessible for Tab {
+    fn accessibility_props(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl InteractiveElement for Tab {
     fn interactivity(&mut self) -> &mut gpui::Interactivity {
         self.base.interactivity()
@@ -568,7 +579,13 @@ impl Sizable for Tab {
 }
 
 impl RenderOnce for Tab {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let mut props = AccessibilityProps::new(Role::Tab)
+            .selected(self.selected)
+            .disabled(self.disabled);
+        props.label = self.label.clone();
+        Accessibility::declare(&self.id, &props.merge(&self.accessibility), window, cx);
+
         let mut tab_style = if self.selected {
             self.variant.selected(cx)
         } else {
@@ -0,0 +1,471 @@
+use std::rc::Rc;
+
+use crate::button::{Button, ButtonVariants as _};
+use crate::{
+    h_flex, v_flex, ActiveTheme, AxisExt, Disableable as _, FocusableExt as _, Icon, IconName,
+    Selectable as _, Sizable, Size, StyledExt,
+};
+use gpui::prelude::FluentBuilder as _;
+use gpui::{
+    actions, div, px, AnyElement, App, Axis, ElementId, FocusHandle, InteractiveElement,
+    IntoElement, KeyBinding, ParentElement, RenderOnce, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
+};
+
+use super::{Tab, TabBar, TabVariant};
+
+const CONTEXT: &str = "Tabs";
+
+actions!(
+    tabs,
+    [
+        SelectPrevTab,
+        SelectNextTab,
+        SelectFirstTab,
+        SelectLastTab,
+        CloseSelectedTab
+    ]
+);
+
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrevTab, Some(CONTEXT)),
+        KeyBinding::new("up", SelectPrevTab, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNextTab, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNextTab, Some(CONTEXT)),
+        KeyBinding::new("home", SelectFirstTab, Some(CONTEXT)),
+        KeyBinding::new("end", SelectLastTab, Some(CONTEXT)),
+        KeyBinding::new("delete", CloseSelectedTab, Some(CONTEXT)),
+    ]);
+}
+
+/// A tab item of the [`Tabs`].
+pub struct TabItem {
+    label: SharedString,
+    icon: Option<Icon>,
+    badge: usize,
+    disabled: bool,
+    closable: bool,
+    content: Option<Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>>,
+}
+
+impl TabItem {
+    /// Create a new tab item with a label.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            badge: 0,
+            disabled: false,
+            closable: false,
+            content: None,
+        }
+    }
+
+    /// Set the icon before the label.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the count of the badge after the label, the badge is hidden if the count is 0.
+    pub fn badge(mut self, count: usize) -> Self {
+        self.badge = count;
+        self
+    }
+
+    /// Set disabled state of the tab, the disabled tab will be skipped by keyboard navigation.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the tab to show a close button, see [`Tabs::on_close`].
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set the content builder of the tab.
+    ///
+    /// The content is lazily built only when the tab is selected.
+    pub fn content<E>(mut self, content: impl Fn(&mut Window, &mut App) -> E + 'static) -> Self
+    where
+        E: IntoElement,
+    {
+        self.content = Some(Rc::new(move |window, cx| {
+            content(window, cx).into_any_element()
+        }));
+        self
+    }
+}
+
+impl From<&'static str> for TabItem {
+    fn from(label: &'static str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<SharedString> for TabItem {
+    fn from(label: SharedString) -> Self {
+        Self::new(label)
+    }
+}
+
+struct TabsState {
+    focus_handle: FocusHandle,
+    scroll_handle: ScrollHandle,
+}
+
+/// A general purpose tabs component with a tab bar and the content of the selected tab.
+///
+/// Unlike the [`TabBar`], the `Tabs` supports the vertical layout and keyboard navigation
+/// (arrow keys, `home`, `end` and `delete` to close) when the tab bar is focused.
+///
+/// ```ignore
+/// Tabs::new("settings")
+///     .selected_index(self.selected_ix)
+///     .on_change(cx.listener(|this, ix: &usize, _, cx| {
+///         this.selected_ix = *ix;
+///         cx.notify();
+///     }))
+///     .child(TabItem::new("General").content(|_, _| "General settings"))
+///     .child(TabItem::new("Inbox").badge(3).content(|_, _| "Inbox"))
+/// ```
+#[derive(IntoElement)]
+pub struct Tabs {
+    id: ElementId,
+    style: StyleRefinement,
+    items: Vec<TabItem>,
+    selected_index: usize,
+    layout: Axis,
+    variant: TabVariant,
+    size: Size,
+    menu: bool,
+    on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+    on_close: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+}
+
+impl Tabs {
+    /// Create a new Tabs with default Horizontal layout.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            style: StyleRefinement::default(),
+            items: vec![],
+            selected_index: 0,
+            layout: Axis::Horizontal,
+            variant: TabVariant::default(),
+            size: Size::default(),
+            menu: false,
+            on_change: None,
+            on_close: None,
+        }
+    }
+
+    /// Set the layout to Vertical, the tab bar will be placed on the left side.
+    ///
+    /// The Pill or Outline variant is recommended for the vertical layout.
+    pub fn vertical(self) -> Self {
+        self.layout(Axis::Vertical)
+    }
+
+    /// Set the layout of the Tabs. Default is `Axis::Horizontal`.
+    pub fn layout(mut self, layout: Axis) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the Tab variant, all tabs will inherit the variant.
+    pub fn with_variant(mut self, variant: TabVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the Tab variant to Pill.
+    pub fn pill(self) -> Self {
+        self.with_variant(TabVariant::Pill)
+    }
+
+    /// Set the Tab variant to Outline.
+    pub fn outline(self) -> Self {
+        self.with_variant(TabVariant::Outline)
+    }
+
+    /// Set the Tab variant to Segmented.
+    pub fn segmented(self) -> Self {
+        self.with_variant(TabVariant::Segmented)
+    }
+
+    /// Set the Tab variant to Underline.
+    pub fn underline(self) -> Self {
+        self.with_variant(TabVariant::Underline)
+    }
+
+    /// Enable or disable the popup menu to list all tabs (Only for the Horizontal layout).
+    pub fn with_menu(mut self, menu: bool) -> Self {
+        self.menu = menu;
+        self
+    }
+
+    /// Set the selected index, default is `0`.
+    pub fn selected_index(mut self, index: usize) -> Self {
+        self.selected_index = index;
+        self
+    }
+
+    /// Listen to the change of the selected index.
+    pub fn on_change(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Listen to the close of a closable tab, the first parameter is the index of the tab.
+    ///
+    /// The Tabs is stateless, the tab should be removed by the caller.
+    pub fn on_close(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Add a tab item.
+    pub fn child(mut self, item: impl Into<TabItem>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Add multiple tab items.
+    pub fn children(mut self, items: impl IntoIterator<Item = impl Into<TabItem>>) -> Self {
+        self.items.extend(items.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl Styled for Tabs {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl Sizable for Tabs {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+/// Returns the next enabled index from `ix` in the direction, wrapping around the ends.
+fn next_enabled_index(disabled: &[bool], ix: usize, forward: bool) -> Option<usize> {
+    let len = disabled.len();
+    (1..=len)
+        .map(|offset| {
+            if forward {
+                (ix + offset) % len
+            } else {
+                (ix + len * 2 - offset) % len
+            }
+        })
+        .find(|&ix| !disabled[ix])
+}
+
+impl RenderOnce for Tabs {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| TabsState {
+            focus_handle: cx.focus_handle(),
+            scroll_handle: ScrollHandle::new(),
+        });
+        let focus_handle = state.read(cx).focus_handle.clone();
+        let scroll_handle = state.read(cx).scroll_handle.clone();
+        let is_focused = focus_handle.is_focused(window);
+
+        let selected_ix = self.selected_index.min(self.items.len().saturating_sub(1));
+        let disabled: Rc<[bool]> = self.items.iter().map(|item| item.disabled).collect();
+        let closable = self
+            .items
+            .get(selected_ix)
+            .map_or(false, |item| item.closable && !item.disabled);
+        let content = self
+            .items
+            .get(selected_ix)
+            .and_then(|item| item.content.clone());
+
+        let on_change = self.on_change.clone();
+        let select = Rc::new({
+            let scroll_handle = scroll_handle.clone();
+            move |ix: Option<usize>, window: &mut Window, cx: &mut App| {
+                let Some(ix) = ix else {
+                    return;
+                };
+                scroll_handle.scroll_to_item(ix);
+                if ix != selected_ix {
+                    if let Some(on_change) = on_change.as_ref() {
+                        on_change(&ix, window, cx);
+                    }
+                }
+            }
+        });
+
+        let tabs = self.items.into_iter().enumerate().map(|(ix, item)| {
+            Tab::new(item.label)
+                .when(self.layout.is_vertical(), |this| this.w_full())
+                .when_some(item.icon, |this, icon| {
+                    this.prefix(div().pl_2().child(icon).into_any_element())
+                })
+                .when(item.badge > 0 || item.closable, |this| {
+                    this.suffix(
+                        h_flex()
+                            .pr_1()
+                            .gap_1()
+                            .when(item.badge > 0, |this| {
+                                this.child(
+                                    div()
+                                        .px_1p5()
+                                        .rounded_full()
+                                        .text_xs()
+                                        .bg(cx.theme().primary)
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(if item.badge > 99 {
+                                            "99+".to_string()
+                                        } else {
+                                            item.badge.to_string()
+                                        }),
+                                )
+                            })
+                            .when(item.closable, |this| {
+                                this.child(
+                                    Button::new(("close", ix))
+                                        .icon(IconName::Close)
+                                        .ghost()
+                                        .xsmall()
+                                        .disabled(item.disabled)
+                                        .when_some(self.on_close.clone(), |this, on_close| {
+                                            this.on_click(move |_, window, cx| {
+                                                on_close(&ix, window, cx);
+                                            })
+                                        }),
+                                )
+                            })
+                            .into_any_element(),
+                    )
+                })
+                .disabled(item.disabled)
+        });
+
+        let bar = if self.layout.is_vertical() {
+            v_flex()
+                .id("tab-bar")
+                .flex_shrink_0()
+                .gap_1()
+                .p_1()
+                .overflow_y_scroll()
+                .track_scroll(&scroll_handle)
+                .border_r_1()
+                .border_color(cx.theme().border)
+                .children(tabs.enumerate().map(|(ix, tab)| {
+                    let select = select.clone();
+                    tab.id(ix)
+                        .with_variant(self.variant)
+                        .with_size(self.size)
+                        .selected(selected_ix == ix)
+                        .on_click(move |_, window, cx| select(Some(ix), window, cx))
+                }))
+                .into_any_element()
+        } else {
+            TabBar::new("tab-bar")
+                .with_variant(self.variant)
+                .with_size(self.size)
+                .with_menu(self.menu)
+                .track_scroll(&scroll_handle)
+                .selected_index(selected_ix)
+                .on_click({
+                    let select = select.clone();
+                    move |ix, window, cx| select(Some(*ix), window, cx)
+                })
+                .children(tabs)
+                .into_any_element()
+        };
+
+        let base = if self.layout.is_vertical() {
+            h_flex().items_start()
+        } else {
+            v_flex()
+        };
+
+        base.id(self.id)
+            .refine_style(&self.style)
+            .child(
+                div()
+                    .id("tab-bar-container")
+                    .track_focus(&focus_handle)
+                    .key_context(CONTEXT)
+                    .when(self.layout.is_vertical(), |this| this.h_full())
+                    .focus_ring(is_focused, px(2.), window, cx)
+                    .on_action({
+                        let select = select.clone();
+                        let disabled = disabled.clone();
+                        move |_: &SelectPrevTab, window, cx| {
+                            select(
+                                next_enabled_index(&disabled, selected_ix, false),
+                                window,
+                                cx,
+                            );
+                        }
+                    })
+                    .on_action({
+                        let select = select.clone();
+                        let disabled = disabled.clone();
+                        move |_: &SelectNextTab, window, cx| {
+                            select(next_enabled_index(&disabled, selected_ix, true), window, cx);
+                        }
+                    })
+                    .on_action({
+                        let select = select.clone();
+                        let disabled = disabled.clone();
+                        move |_: &SelectFirstTab, window, cx| {
+                            select(disabled.iter().position(|d| !d), window, cx);
+                        }
+                    })
+                    .on_action({
+                        let select = select.clone();
+                        let disabled = disabled.clone();
+                        move |_: &SelectLastTab, window, cx| {
+                            select(disabled.iter().rposition(|d| !d), window, cx);
+                        }
+                    })
+                    .when_some(
+                        self.on_close.clone().filter(|_| closable),
+                        |this, on_close| {
+                            this.on_action(move |_: &CloseSelectedTab, window, cx| {
+                                on_close(&selected_ix, window, cx);
+                            })
+                        },
+                    )
+                    .child(bar),
+            )
+            .child(
+                div()
+                    .id("tab-content")
+                    .flex_1()
+                    .size_full()
+                    .overflow_hidden()
+                    .when_some(content, |this, content| this.child(content(window, cx))),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_enabled_index;
+
+    #[test]
+    fn test_next_enabled_index() {
+        let disabled = [false, true, false, false];
+        assert_eq!(next_enabled_index(&disabled, 0, true), Some(2));
+        assert_eq!(next_enabled_index(&disabled, 3, true), Some(0));
+        assert_eq!(next_enabled_index(&disabled, 2, false), Some(0));
+        assert_eq!(next_enabled_index(&disabled, 0, false), Some(3));
+        assert_eq!(next_enabled_index(&[true, false], 1, true), Some(1));
+        assert_eq!(next_enabled_index(&[true], 0, true), None);
+        assert_eq!(next_enabled_index(&[], 0, true), None);
+    }
+}
@@ -0,0 +1,443 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, AnyElement, App, Context, IntoElement,
+    ParentElement as _, SharedString, Styled as _, TextAlign, Window,
+};
+
+use crate::{
+    h_flex,
+    locale::{format_date, format_number, DateStyle},
+    progress::Progress,
+    tag::{Tag, TagVariant},
+    ActiveTheme as _, Icon, IconName, Sizable as _,
+};
+
+use super::{Column, ColumnSort, Table, TableDelegate};
+
+/// The type of the cells in a [`Column`], decides how the [`CellValue`]s are formatted and rendered,
+/// see [`Column::number`], [`Column::date`] and so on.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ColumnKind {
+    /// The plain text.
+    #[default]
+    Text,
+    /// The number formatted by the current locale with the `precision` digits after the decimal separator,
+    /// aligned to the right.
+    Number { precision: usize },
+    /// The date formatted by the current locale.
+    Date(DateStyle),
+    /// The check icon for `true`.
+    Boolean,
+    /// The progress bar of the `0..=100` value.
+    Progress,
+    /// The text in a [`Tag`].
+    Badge,
+    /// The bars of the values.
+    Sparkline,
+}
+
+/// The value of a cell, see [`RecordsDelegate::column`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellValue {
+    Empty,
+    Text(SharedString),
+    Number(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    Bool(bool),
+    /// A badge with the text and the variant.
+    Badge(SharedString, TagVariant),
+    Values(Vec<f64>),
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        Self::Text(SharedString::from(value.to_string()))
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+impl From<SharedString> for CellValue {
+    fn from(value: SharedString) -> Self {
+        Self::Text(value)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for CellValue {
+            fn from(value: $ty) -> Self {
+                Self::Number(value as f64)
+            }
+        })*
+    };
+}
+impl_from_number!(f64, f32, i64, i32, u64, u32, usize);
+
+impl From<NaiveDate> for CellValue {
+    fn from(value: NaiveDate) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl From<NaiveDateTime> for CellValue {
+    fn from(value: NaiveDateTime) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Vec<f64>> for CellValue {
+    fn from(value: Vec<f64>) -> Self {
+        Self::Values(value)
+    }
+}
+
+impl<T: Into<CellValue>> From<Option<T>> for CellValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Self::Empty)
+    }
+}
+
+impl CellValue {
+    /// Returns the formatted text by the `kind`, used to copy, export and group the cells.
+    pub fn text(&self, kind: ColumnKind) -> SharedString {
+        match (self, kind) {
+            (Self::Empty, _) => SharedString::default(),
+            (Self::Text(text), _) | (Self::Badge(text, _), _) => text.clone(),
+            (Self::Number(value), ColumnKind::Number { precision }) => {
+                format_number(*value, precision).into()
+            }
+            (Self::Number(value), ColumnKind::Progress) => {
+                format!("{}%", format_number(*value, 0)).into()
+            }
+            (Self::Number(value), _) => value.to_string().into(),
+            (Self::Date(date), ColumnKind::Date(style)) => format_date(date, style).into(),
+            (Self::Date(date), _) => date.to_string().into(),
+            (Self::DateTime(datetime), ColumnKind::Date(style)) => format!(
+                "{} {}",
+                format_date(&datetime.date(), style),
+                datetime.format("%H:%M")
+            )
+            .into(),
+            (Self::DateTime(datetime), _) => datetime.to_string().into(),
+            (Self::Bool(value), _) => value.to_string().into(),
+            (Self::Values(values), _) => values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+                .into(),
+        }
+    }
+
+    /// Compare the values to sort the rows, the empty values are the smallest,
+    /// the values of the different types are compared by the texts.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Empty, Self::Empty) => Ordering::Equal,
+            (Self::Empty, _) => Ordering::Less,
+            (_, Self::Empty) => Ordering::Greater,
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::Date(a), Self::Date(b)) => a.cmp(b),
+            (Self::DateTime(a), Self::DateTime(b)) => a.cmp(b),
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Values(a), Self::Values(b)) => {
+                a.last().unwrap_or(&0.).total_cmp(b.last().unwrap_or(&0.))
+            }
+            (a, b) => a.text(ColumnKind::Text).cmp(&b.text(ColumnKind::Text)),
+        }
+    }
+
+    /// Render the value in a cell of the `column`, aligned by the [`Column::align`].
+    pub fn render(&self, column: &Column, cx: &App) -> AnyElement {
+        let content = match (self, column.kind) {
+            (Self::Empty, _) => div().into_any_element(),
+            (Self::Bool(value), _) => div()
+                .when(*value, |this| {
+                    this.child(
+                        Icon::new(IconName::Check)
+                            .small()
+                            .text_color(cx.theme().success),
+                    )
+                })
+                .into_any_element(),
+            (Self::Number(value), ColumnKind::Progress) => h_flex()
+                .w_full()
+                .gap_2()
+                .child(div().flex_1().child(Progress::new().value(*value as f32)))
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(self.text(column.kind)),
+                )
+                .into_any_element(),
+            (Self::Badge(text, variant), _) => Tag::secondary()
+                .with_variant(*variant)
+                .small()
+                .child(text.clone())
+                .into_any_element(),
+            (Self::Text(text), ColumnKind::Badge) => Tag::secondary()
+                .small()
+                .child(text.clone())
+                .into_any_element(),
+            (Self::Values(values), _) => render_sparkline(values, cx).into_any_element(),
+            (value, kind) => div().truncate().child(value.text(kind)).into_any_element(),
+        };
+
+        h_flex()
+            .size_full()
+            .map(|this| match column.align {
+                TextAlign::Right => this.justify_end(),
+                TextAlign::Center => this.justify_center(),
+                TextAlign::Left => this,
+            })
+            .child(content)
+            .into_any_element()
+    }
+}
+
+/// Returns the heights of the bars in `0.0..=1.0`.
+fn sparkline_heights(values: &[f64]) -> Vec<f32> {
+    let (min, max) = values.iter().fold((f64::MAX, f64::MIN), |(min, max), v| {
+        (min.min(*v), max.max(*v))
+    });
+    values
+        .iter()
+        .map(|value| {
+            if max > min {
+                (0.1 + 0.9 * (value - min) / (max - min)) as f32
+            } else {
+                0.5
+            }
+        })
+        .collect()
+}
+
+fn render_sparkline(values: &[f64], cx: &App) -> impl IntoElement {
+    h_flex()
+        .h(px(16.))
+        .items_end()
+        .gap(px(1.))
+        .children(sparkline_heights(values).into_iter().map(|height| {
+            div()
+                .w(px(3.))
+                .h(relative(height))
+                .rounded_t(px(1.))
+                .bg(cx.theme().chart_1)
+        }))
+}
+
+type CellGetter<R> = Rc<dyn Fn(&R) -> CellValue>;
+
+/// A [`TableDelegate`] of the records with the columns defined by the getters of the [`CellValue`]s,
+/// the cells are rendered, sorted, copied and exported by the [`ColumnKind`] of the columns.
+///
+/// ```ignore
+/// let delegate = RecordsDelegate::new(stocks)
+///     .column(Column::new("symbol", "Symbol"), |s: &Stock| s.symbol.clone().into())
+///     .column(Column::new("price", "Price").number(2).sortable(), |s| s.price.into())
+///     .column(Column::new("date", "Date").date(DateStyle::Short), |s| s.date.into())
+///     .column(Column::new("trend", "Trend").sparkline(), |s| s.history.clone().into());
+///
+/// let table = cx.new(|cx| Table::new(delegate, window, cx));
+/// ```
+pub struct RecordsDelegate<R> {
+    records: Vec<R>,
+    /// The indices of the records in the display order.
+    order: Vec<usize>,
+    columns: Vec<(Column, CellGetter<R>)>,
+}
+
+impl<R: 'static> RecordsDelegate<R> {
+    pub fn new(records: Vec<R>) -> Self {
+        Self {
+            order: (0..records.len()).collect(),
+            records,
+            columns: vec![],
+        }
+    }
+
+    /// Add a column with the getter of the cell value.
+    pub fn column(mut self, column: Column, value: impl Fn(&R) -> CellValue + 'static) -> Self {
+        self.columns.push((column, Rc::new(value)));
+        self
+    }
+
+    /// Returns the records in the original order.
+    pub fn records(&self) -> &[R] {
+        &self.records
+    }
+
+    /// Replace the records, the sort order is reset.
+    pub fn set_records(&mut self, records: Vec<R>) {
+        self.order = (0..records.len()).collect();
+        self.records = records;
+    }
+
+    /// Returns the record at the `row_ix` in the display order.
+    pub fn record(&self, row_ix: usize) -> Option<&R> {
+        self.order.get(row_ix).map(|ix| &self.records[*ix])
+    }
+
+    /// Returns the cell value at the `row_ix` in the display order and the `col_ix`.
+    pub fn cell_value(&self, row_ix: usize, col_ix: usize) -> CellValue {
+        match (self.record(row_ix), self.columns.get(col_ix)) {
+            (Some(record), Some((_, value))) => value(record),
+            _ => CellValue::Empty,
+        }
+    }
+
+    fn sort_by(&mut self, col_ix: usize, sort: ColumnSort) {
+        let Some((_, value)) = self.columns.get(col_ix) else {
+            return;
+        };
+
+        let records = &self.records;
+        match sort {
+            ColumnSort::Default => self.order.sort(),
+            ColumnSort::Ascending => self
+                .order
+                .sort_by(|a, b| value(&records[*a]).compare(&value(&records[*b]))),
+            ColumnSort::Descending => self
+                .order
+                .sort_by(|a, b| value(&records[*b]).compare(&value(&records[*a]))),
+        }
+    }
+}
+
+impl<R: 'static> TableDelegate for RecordsDelegate<R> {
+    fn columns_count(&self, _: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _: &App) -> usize {
+        self.records.len()
+    }
+
+    fn column(&self, col_ix: usize, _: &App) -> &Column {
+        &self.columns[col_ix].0
+    }
+
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+        self.sort_by(col_ix, sort);
+        cx.notify();
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> impl IntoElement {
+        self.cell_value(row_ix, col_ix)
+            .render(&self.columns[col_ix].0, cx)
+    }
+
+    fn cell_text(&self, row_ix: usize, col_ix: usize, _: &App) -> SharedString {
+        self.cell_value(row_ix, col_ix)
+            .text(self.columns[col_ix].0.kind)
+    }
+
+    fn move_column(
+        &mut self,
+        col_ix: usize,
+        to_ix: usize,
+        _: &mut Window,
+        _: &mut Context<Table<Self>>,
+    ) {
+        let column = self.columns.remove(col_ix);
+        self.columns.insert(to_ix, column);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use chrono::NaiveDate;
+
+    use super::{sparkline_heights, CellValue, ColumnKind, RecordsDelegate};
+    use crate::table::{Column, ColumnSort};
+
+    #[test]
+    fn test_cell_text() {
+        let date = NaiveDate::from_ymd_opt(2024, 8, 3).unwrap();
+        assert_eq!(
+            CellValue::from(1234.5).text(ColumnKind::Number { precision: 2 }),
+            "1,234.50"
+        );
+        assert_eq!(CellValue::from(42).text(ColumnKind::Progress), "42%");
+        assert_eq!(
+            CellValue::from(date).text(ColumnKind::Date(Default::default())),
+            "08/03/2024"
+        );
+        assert_eq!(CellValue::from(date).text(ColumnKind::Text), "2024-08-03");
+        assert_eq!(CellValue::from(None::<bool>).text(ColumnKind::Boolean), "");
+        assert_eq!(
+            CellValue::from(vec![1., 2.5]).text(ColumnKind::Sparkline),
+            "1, 2.5"
+        );
+    }
+
+    #[test]
+    fn test_cell_compare() {
+        assert_eq!(
+            CellValue::from(2).compare(&CellValue::from(10)),
+            Ordering::Less
+        );
+        assert_eq!(
+            CellValue::from("b").compare(&CellValue::from("a")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            CellValue::Empty.compare(&CellValue::from(0)),
+            Ordering::Less
+        );
+        assert_eq!(sparkline_heights(&[1., 3.]), vec![0.1, 1.0]);
+        assert_eq!(sparkline_heights(&[2., 2.]), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_records_delegate_sort() {
+        let mut delegate = RecordsDelegate::new(vec![("b", 2), ("c", 1), ("a", 3)])
+            .column(Column::new("name", "Name"), |r: &(&'static str, i32)| {
+                r.0.into()
+            })
+            .column(Column::new("count", "Count").number(0), |r| r.1.into());
+        let names = |d: &RecordsDelegate<(&'static str, i32)>| {
+            (0..3).map(|ix| d.record(ix).unwrap().0).collect::<Vec<_>>()
+        };
+
+        delegate.sort_by(0, ColumnSort::Ascending);
+        assert_eq!(names(&delegate), vec!["a", "b", "c"]);
+        delegate.sort_by(1, ColumnSort::Descending);
+        assert_eq!(names(&delegate), vec!["a", "b", "c"]);
+        delegate.sort_by(1, ColumnSort::Ascending);
+        assert_eq!(names(&delegate), vec!["c", "b", "a"]);
+        delegate.sort_by(1, ColumnSort::Default);
+        assert_eq!(names(&delegate), vec!["b", "c", "a"]);
+        assert_eq!(delegate.cell_value(0, 1), CellValue::Number(2.));
+    }
+}
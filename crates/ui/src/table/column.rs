@@ -3,7 +3,9 @@ use gpui::{
     ParentElement as _, Pixels, Render, SharedString, Styled as _, TextAlign, Window,
 };
 
-use crate::ActiveTheme as _;
+use crate::{locale::DateStyle, ActiveTheme as _};
+
+use super::ColumnKind;
 
 /// Represents a column in a table, used for initializing table columns.
 #[derive(Debug, Clone)]
@@ -18,6 +20,7 @@ pub struct Column {
     pub resizable: bool,
     pub movable: bool,
     pub selectable: bool,
+    pub kind: ColumnKind,
 }
 
 impl Default for Column {
@@ -33,6 +36,7 @@ impl Default for Column {
             resizable: true,
             movable: true,
             selectable: true,
+            kind: ColumnKind::Text,
         }
     }
 }
@@ -112,6 +116,12 @@ impl Column {
         self
     }
 
+    /// Set whether the column is fixed on right side, default is false.
+    pub fn fixed_right(mut self) -> Self {
+        self.fixed = Some(ColumnFixed::Right);
+        self
+    }
+
     /// Set whether the column is resizable, default is true.
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
@@ -129,13 +139,64 @@ impl Column {
         self.selectable = selectable;
         self
     }
+
+    /// Set the [`ColumnKind`] of the cells, default is [`ColumnKind::Text`].
+    pub fn kind(mut self, kind: ColumnKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set as a number column with the `precision`, and align the text to the right.
+    pub fn number(mut self, precision: usize) -> Self {
+        self.kind = ColumnKind::Number { precision };
+        self.align = TextAlign::Right;
+        self
+    }
+
+    /// Set as a date column formatted by the `style`.
+    pub fn date(mut self, style: DateStyle) -> Self {
+        self.kind = ColumnKind::Date(style);
+        self
+    }
+
+    /// Set as a boolean column, and align to the center.
+    pub fn boolean(mut self) -> Self {
+        self.kind = ColumnKind::Boolean;
+        self.align = TextAlign::Center;
+        self
+    }
+
+    /// Set as a progress column.
+    pub fn progress(mut self) -> Self {
+        self.kind = ColumnKind::Progress;
+        self
+    }
+
+    /// Set as a badge column.
+    pub fn badge(mut self) -> Self {
+        self.kind = ColumnKind::Badge;
+        self
+    }
+
+    /// Set as a sparkline column.
+    pub fn sparkline(mut self) -> Self {
+        self.kind = ColumnKind::Sparkline;
+        self
+    }
 }
 
 impl FluentBuilder for Column {}
 
+/// The fixed side of the columns.
+///
+/// In the [`crate::Direction::Rtl`] layout the column order is mirrored, so the `Left`
+/// columns (the start of the columns) are placed on the right, and vice versa.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColumnFixed {
+    /// Fixed on the left side, the columns must be at the start of the columns.
     Left,
+    /// Fixed on the right side, the columns must be at the end of the columns.
+    Right,
 }
 
 /// Used to sort the column runtime info in Table internal.
@@ -148,6 +209,8 @@ pub(crate) struct ColGroup {
     pub(crate) width: Pixels,
     /// The bounds of the column in the table after it renders.
     pub(crate) bounds: Bounds<Pixels>,
+    /// Whether the column is hidden by the column chooser.
+    pub(crate) hidden: bool,
 }
 
 impl ColGroup {
@@ -0,0 +1,49 @@
+use gpui::{
+    div, App, Entity, IntoElement, ParentElement as _, RenderOnce, StyleRefinement, Styled, Window,
+};
+
+use crate::StyledExt as _;
+
+use super::{Table, TableDelegate};
+
+/// A table element bind to the [`Table`] state.
+///
+/// This is the same as rendering the [`Table`] entity directly, but follows the
+/// `Component::new(&state)` pattern of the other stateful components (e.g.: [`crate::input::TextInput`],
+/// [`crate::dropdown::Dropdown`]), and allows to style the container.
+///
+/// ```ignore
+/// let table = cx.new(|cx| Table::new(delegate, window, cx));
+///
+/// DataTable::new(&table).h_96()
+/// ```
+#[derive(IntoElement)]
+pub struct DataTable<D: TableDelegate> {
+    state: Entity<Table<D>>,
+    style: StyleRefinement,
+}
+
+impl<D: TableDelegate> DataTable<D> {
+    /// Create a new [`DataTable`] element bind to the [`Table`] state.
+    pub fn new(state: &Entity<Table<D>>) -> Self {
+        Self {
+            state: state.clone(),
+            style: StyleRefinement::default(),
+        }
+    }
+}
+
+impl<D: TableDelegate> Styled for DataTable<D> {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl<D: TableDelegate> RenderOnce for DataTable<D> {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        div()
+            .size_full()
+            .refine_style(&self.style)
+            .child(self.state)
+    }
+}
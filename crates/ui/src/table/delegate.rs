@@ -1,15 +1,16 @@
 use std::ops::Range;
 
 use gpui::{
-    div, App, Context, Div, InteractiveElement as _, IntoElement, ParentElement as _, Stateful,
-    Styled as _, Window,
+    div, px, AnyElement, App, Context, Div, InteractiveElement as _, IntoElement,
+    ParentElement as _, Pixels, SharedString, Stateful, Styled as _, Window,
 };
 
 use crate::{
+    empty_state::{EmptyState, ErrorState},
     h_flex,
     popup_menu::PopupMenu,
-    table::{loading::Loading, Column, ColumnSort, Table},
-    ActiveTheme as _, Icon, IconName, Size,
+    table::{loading::Loading, Column, ColumnSort, Table, TableRowGroup},
+    ActiveTheme as _, CachedText, Size,
 };
 
 #[allow(unused)]
@@ -43,7 +44,7 @@ pub trait TableDelegate: Sized + 'static {
     ) -> impl IntoElement {
         div()
             .size_full()
-            .child(self.column(col_ix, cx).name.clone())
+            .child(CachedText::new(self.column(col_ix, cx).name.clone()))
     }
 
     /// Render the row at the given row and column.
@@ -70,6 +71,70 @@ pub trait TableDelegate: Sized + 'static {
         cx: &mut Context<Table<Self>>,
     ) -> impl IntoElement;
 
+    /// Return the plain text of the cell at the given row and column.
+    ///
+    /// This is used to group rows by the column, see [`Table::group_by`],
+    /// and to copy or export the cells, see [`Table::export_csv`].
+    fn cell_text(&self, row_ix: usize, col_ix: usize, cx: &App) -> SharedString {
+        SharedString::default()
+    }
+
+    /// Render the group header content, default to `{column name}: {value} ({count})`.
+    fn render_group_header(
+        &self,
+        group: &TableRowGroup,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> impl IntoElement {
+        h_flex()
+            .gap_1()
+            .child(format!(
+                "{}: {}",
+                self.column(group.col_ix, cx).name,
+                group.value
+            ))
+            .child(div().text_color(cx.theme().muted_foreground).child(format!(
+                "({})",
+                crate::locale::format_number(group.rows.len() as f64, 0)
+            )))
+    }
+
+    /// Render the aggregate cell of the group at the given column,
+    /// return None to leave the cell empty.
+    ///
+    /// The aggregate rows are only rendered when [`Table::group_aggregates`] is enabled,
+    /// use `group.rows` to calculate the sum, avg or count.
+    fn render_aggregate_td(
+        &self,
+        group: &TableRowGroup,
+        col_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> Option<AnyElement> {
+        None
+    }
+
+    /// Return true if the row at the given `row_ix` can be expanded,
+    /// only works when [`Table::expandable`] is enabled.
+    fn row_expandable(&self, row_ix: usize, cx: &App) -> bool {
+        true
+    }
+
+    /// Return the height of the expanded detail of the row, default is 200px.
+    fn expanded_row_height(&self, row_ix: usize, cx: &App) -> Pixels {
+        px(200.)
+    }
+
+    /// Render the expanded detail of the row at the given `row_ix`.
+    fn render_expanded_row(
+        &self,
+        row_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> impl IntoElement {
+        div()
+    }
+
     /// Move the column at the given `col_ix` to insert before the column at the given `to_ix`.
     fn move_column(
         &mut self,
@@ -80,14 +145,26 @@ pub trait TableDelegate: Sized + 'static {
     ) {
     }
 
-    /// Return a Element to show when table is empty.
+    /// Return a Element to show when table is empty, default is [`EmptyState::no_data`].
     fn render_empty(&self, window: &mut Window, cx: &mut Context<Table<Self>>) -> impl IntoElement {
-        h_flex()
-            .size_full()
-            .justify_center()
-            .text_color(cx.theme().muted_foreground.opacity(0.6))
-            .child(Icon::new(IconName::Inbox).size_12())
-            .into_any_element()
+        EmptyState::no_data()
+    }
+
+    /// Returns the error message if failed to load the rows, default is None.
+    ///
+    /// When this returns Some, the [`TableDelegate::render_error`] will be rendered instead of the rows.
+    fn load_error(&self, cx: &App) -> Option<SharedString> {
+        None
+    }
+
+    /// Return a Element to show when failed to load the rows, default is [`ErrorState`].
+    fn render_error(
+        &self,
+        error: SharedString,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) -> impl IntoElement {
+        ErrorState::new().description(error)
     }
 
     /// Return true to show the loading view.
@@ -131,6 +208,26 @@ pub trait TableDelegate: Sized + 'static {
     /// so you must check if there is more data to load or lock the loading state.
     fn load_more(&mut self, window: &mut Window, cx: &mut Context<Table<Self>>) {}
 
+    /// Return the total number of rows of all pages to show the pagination bar below the table.
+    ///
+    /// Default is None, the pagination is disabled, the rows of the delegate are all rows.
+    /// When enabled, the rows of the delegate are the rows of the current page,
+    /// see [`TableDelegate::page_changed`].
+    fn total_rows(&self, cx: &App) -> Option<usize> {
+        None
+    }
+
+    /// Called when the page or page size of the pagination is changed,
+    /// the delegate should load the rows of the `page` (0-based).
+    fn page_changed(
+        &mut self,
+        page: usize,
+        page_size: usize,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+    }
+
     /// Render the last empty column, default to empty.
     fn render_last_empty_col(
         &mut self,
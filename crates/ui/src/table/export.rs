@@ -0,0 +1,204 @@
+use gpui::{App, Context, SharedString, Window};
+
+use crate::{
+    clipboard::{escape_html, ClipboardContent},
+    input,
+};
+
+use super::{SelectionState, Table, TableDelegate};
+
+impl<D: TableDelegate> Table<D> {
+    /// Returns the visible column indices in display order.
+    fn visible_col_ixs(&self) -> Vec<usize> {
+        (0..self.col_groups.len())
+            .filter(|&col_ix| self.is_col_visible(col_ix))
+            .collect()
+    }
+
+    fn cells_text(&self, rows: &[usize], cols: &[usize], cx: &App) -> Vec<Vec<SharedString>> {
+        rows.iter()
+            .map(|&row_ix| {
+                cols.iter()
+                    .map(|&col_ix| self.delegate.cell_text(row_ix, col_ix, cx))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the cells text of the selection, or None if there is no selection.
+    ///
+    /// - Row: the visible cells of the selected row.
+    /// - Column: the cells of all rows in the selected column.
+    /// - Cell: the cells in the selected range.
+    fn selection_cells(&self, cx: &App) -> Option<Vec<Vec<SharedString>>> {
+        let (rows, cols) = match self.selection_state {
+            SelectionState::Row => (vec![self.selected_row?], self.visible_col_ixs()),
+            SelectionState::Column => (
+                (0..self.delegate.rows_count(cx)).collect(),
+                vec![self.selected_col?],
+            ),
+            SelectionState::Cell => {
+                let range = self.selected_cells?;
+                (
+                    range.rows().collect(),
+                    range
+                        .cols()
+                        .filter(|&col_ix| self.is_col_visible(col_ix))
+                        .collect(),
+                )
+            }
+        };
+
+        Some(self.cells_text(&rows, &cols, cx))
+    }
+
+    pub(super) fn action_copy(&mut self, _: &input::Copy, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(cells) = self.selection_cells(cx) else {
+            cx.propagate();
+            return;
+        };
+
+        ClipboardContent::new(to_tsv(&cells))
+            .html(to_html_table(&cells))
+            .write(cx);
+    }
+
+    /// Export all rows of the visible columns as CSV, the first line is the column names.
+    ///
+    /// The cell values are from [`TableDelegate::cell_text`].
+    pub fn export_csv(&self, cx: &App) -> String {
+        let cols = self.visible_col_ixs();
+        let rows = (0..self.delegate.rows_count(cx)).collect::<Vec<_>>();
+
+        let mut lines = vec![cols
+            .iter()
+            .map(|&col_ix| self.col_groups[col_ix].column.name.clone())
+            .collect::<Vec<_>>()];
+        lines.extend(self.cells_text(&rows, &cols, cx));
+        to_csv(&lines)
+    }
+
+    /// Export all rows of the visible columns as a JSON array of objects keyed by the column key.
+    ///
+    /// The cell values are from [`TableDelegate::cell_text`].
+    pub fn export_json(&self, cx: &App) -> String {
+        let cols = self.visible_col_ixs();
+        let rows = (0..self.delegate.rows_count(cx)).collect::<Vec<_>>();
+
+        let items = self
+            .cells_text(&rows, &cols, cx)
+            .into_iter()
+            .map(|cells| {
+                serde_json::Value::Object(
+                    cols.iter()
+                        .zip(cells)
+                        .map(|(&col_ix, text)| {
+                            (
+                                self.col_groups[col_ix].column.key.to_string(),
+                                serde_json::Value::String(text.to_string()),
+                            )
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        serde_json::Value::Array(items).to_string()
+    }
+}
+
+/// Join the cells by tab and the rows by newline, the tabs and newlines in the cells are replaced by spaces.
+fn to_tsv(rows: &[Vec<SharedString>]) -> String {
+    rows.iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|text| text.replace(['\t', '\r', '\n'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Join the cells by comma and the rows by CRLF (RFC 4180).
+fn to_csv(rows: &[Vec<SharedString>]) -> String {
+    rows.iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|text| escape_csv(text))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Returns the rows as an HTML table, for pasting into the spreadsheets and the rich text editors.
+fn to_html_table(rows: &[Vec<SharedString>]) -> String {
+    let mut html = String::from("<table>");
+    for cells in rows {
+        html.push_str("<tr>");
+        for text in cells {
+            html.push_str(&format!("<td>{}</td>", escape_html(text)));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    html
+}
+
+fn escape_csv(text: &str) -> String {
+    if text.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{to_csv, to_html_table, to_tsv};
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<SharedString>> {
+        rows.iter()
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|s| SharedString::from(s.to_string()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_to_tsv() {
+        let rows = rows(&[&["a", "b\tc"], &["line\nbreak", ""]]);
+        assert_eq!(to_tsv(&rows), "a\tb c\nline break\t");
+    }
+
+    #[test]
+    fn test_to_html_table() {
+        let rows = rows(&[&["a", "<b>"], &["", "c & d"]]);
+        assert_eq!(
+            to_html_table(&rows),
+            "<table><tr><td>a</td><td>&lt;b&gt;</td></tr><tr><td></td><td>c &amp; d</td></tr></table>"
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let rows = rows(&[
+            &["name", "price"],
+            &["Apple, Inc.", "1.5"],
+            &["say \"hi\"", ""],
+        ]);
+        assert_eq!(
+            to_csv(&rows),
+            "name,price\r\n\"Apple, Inc.\",1.5\r\n\"say \"\"hi\"\"\","
+        );
+    }
+}
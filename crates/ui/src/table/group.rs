@@ -0,0 +1,454 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use gpui::SharedString;
+
+/// A group of rows in the [`crate::table::Table`], grouped by the cell text of a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRowGroup {
+    /// The column index of this group grouped by.
+    pub col_ix: usize,
+    /// The group value, the `cell_text` of the grouped column.
+    pub value: SharedString,
+    /// The nesting depth of the group, 0 for the top level group.
+    pub depth: usize,
+    /// The row indices of the delegate in this group, including the rows in the nested groups.
+    pub rows: Vec<usize>,
+    /// Whether the group is collapsed.
+    pub collapsed: bool,
+    /// The parent group index in the groups.
+    pub(crate) parent: Option<usize>,
+    /// The path of values from the top level group to this group, used to keep the collapsed state.
+    pub(crate) path: Vec<SharedString>,
+}
+
+/// The row entry to display in the Table when the rows are grouped or expanded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayRow {
+    /// The group header, the value is the group index.
+    GroupHeader(usize),
+    /// The delegate row, the value is the row index.
+    Row(usize),
+    /// The aggregate row of a group, the value is the group index.
+    Aggregate(usize),
+    /// The placeholder of the expanded row detail, the value is the row index,
+    /// the detail takes multiple display rows.
+    Detail(usize),
+}
+
+impl DisplayRow {
+    #[inline]
+    pub(crate) fn row_ix(&self) -> Option<usize> {
+        match self {
+            DisplayRow::Row(row_ix) => Some(*row_ix),
+            _ => None,
+        }
+    }
+}
+
+/// The display rows mapping of the Table, used when the rows are grouped or expanded.
+#[derive(Debug, Default)]
+pub(crate) struct RowGroups {
+    /// The column indices to group by, the first is the top level group.
+    pub(crate) group_by: Vec<usize>,
+    /// Whether to add an aggregate row at the end of each group.
+    pub(crate) aggregates: bool,
+    pub(crate) groups: Vec<TableRowGroup>,
+    pub(crate) display_rows: Vec<DisplayRow>,
+    /// The innermost group index of each display row.
+    display_groups: Vec<Option<usize>>,
+    collapsed: HashSet<Vec<SharedString>>,
+    /// The expanded rows, the value is the number of display rows the detail takes.
+    expanded: BTreeMap<usize, usize>,
+    /// The expanded rows detail as `(row_ix, display_ix, count)` in display order.
+    pub(crate) details: Vec<(usize, usize, usize)>,
+    /// The first row to display when not grouped, the rows before it are frozen.
+    first_row: usize,
+    rows_count: Option<usize>,
+}
+
+impl RowGroups {
+    #[inline]
+    pub(crate) fn is_grouped(&self) -> bool {
+        !self.group_by.is_empty()
+    }
+
+    /// Returns true if the display rows are different from the delegate rows.
+    #[inline]
+    pub(crate) fn is_mapped(&self) -> bool {
+        self.is_grouped() || !self.expanded.is_empty()
+    }
+
+    /// Set the expanded rows with the number of display rows of the detail.
+    pub(crate) fn set_expanded(&mut self, expanded: BTreeMap<usize, usize>) {
+        if self.expanded != expanded {
+            self.expanded = expanded;
+            self.invalidate();
+        }
+    }
+
+    /// Mark the groups need to rebuild in next prepare.
+    pub(crate) fn invalidate(&mut self) {
+        self.rows_count = None;
+    }
+
+    pub(crate) fn set_group_by(&mut self, group_by: Vec<usize>) {
+        if self.group_by != group_by {
+            self.group_by = group_by;
+            self.collapsed.clear();
+            self.invalidate();
+        }
+    }
+
+    pub(crate) fn set_aggregates(&mut self, aggregates: bool) {
+        if self.aggregates != aggregates {
+            self.aggregates = aggregates;
+            self.invalidate();
+        }
+    }
+
+    /// Toggle the collapsed state of the group at the given index.
+    ///
+    /// Returns the new collapsed state.
+    pub(crate) fn toggle(&mut self, group_ix: usize) -> Option<bool> {
+        let group = self.groups.get(group_ix)?;
+        let collapsed = !group.collapsed;
+        if collapsed {
+            self.collapsed.insert(group.path.clone());
+        } else {
+            self.collapsed.remove(&group.path);
+        }
+        self.invalidate();
+        Some(collapsed)
+    }
+
+    /// Set all groups to collapsed or expanded.
+    pub(crate) fn set_all_collapsed(&mut self, collapsed: bool) {
+        self.collapsed.clear();
+        if collapsed {
+            self.collapsed
+                .extend(self.groups.iter().map(|group| group.path.clone()));
+        }
+        self.invalidate();
+    }
+
+    /// Rebuild the groups if needed.
+    ///
+    /// The rows before `first_row` are skipped when not grouped,
+    /// the `cell_text` is used to get the group value of the row at the column.
+    pub(crate) fn prepare_if_needed<F>(&mut self, rows_count: usize, first_row: usize, cell_text: F)
+    where
+        F: Fn(usize, usize) -> SharedString,
+    {
+        if self.rows_count == Some(rows_count) && self.first_row == first_row {
+            return;
+        }
+
+        self.rows_count = Some(rows_count);
+        self.first_row = first_row;
+        self.groups.clear();
+        self.display_rows.clear();
+        self.display_groups.clear();
+        self.details.clear();
+        if !self.is_mapped() {
+            return;
+        }
+
+        if self.is_grouped() {
+            let rows = (0..rows_count).collect();
+            self.build_level(rows, 0, None, &[], false, &cell_text);
+        } else {
+            for row_ix in first_row..rows_count {
+                self.push_row(row_ix, None);
+            }
+        }
+    }
+
+    /// Push the row and the detail placeholders if the row is expanded.
+    fn push_row(&mut self, row_ix: usize, group_ix: Option<usize>) {
+        self.display_rows.push(DisplayRow::Row(row_ix));
+        self.display_groups.push(group_ix);
+
+        if let Some(&count) = self.expanded.get(&row_ix) {
+            self.details.push((row_ix, self.display_rows.len(), count));
+            for _ in 0..count {
+                self.display_rows.push(DisplayRow::Detail(row_ix));
+                self.display_groups.push(group_ix);
+            }
+        }
+    }
+
+    fn build_level<F>(
+        &mut self,
+        rows: Vec<usize>,
+        depth: usize,
+        parent: Option<usize>,
+        parent_path: &[SharedString],
+        hidden: bool,
+        cell_text: &F,
+    ) where
+        F: Fn(usize, usize) -> SharedString,
+    {
+        let Some(&col_ix) = self.group_by.get(depth) else {
+            if !hidden {
+                for row_ix in rows {
+                    self.push_row(row_ix, parent);
+                }
+            }
+            return;
+        };
+
+        // Keep the group order by the first appearance of the value.
+        let mut buckets: Vec<(SharedString, Vec<usize>)> = vec![];
+        let mut bucket_ixs: HashMap<SharedString, usize> = HashMap::new();
+        for row_ix in rows {
+            let value = cell_text(row_ix, col_ix);
+            match bucket_ixs.get(&value) {
+                Some(&ix) => buckets[ix].1.push(row_ix),
+                None => {
+                    bucket_ixs.insert(value.clone(), buckets.len());
+                    buckets.push((value, vec![row_ix]));
+                }
+            }
+        }
+
+        for (value, rows) in buckets {
+            let mut path = parent_path.to_vec();
+            path.push(value.clone());
+            let collapsed = self.collapsed.contains(&path);
+            let group_ix = self.groups.len();
+            self.groups.push(TableRowGroup {
+                col_ix,
+                value,
+                depth,
+                rows: rows.clone(),
+                collapsed,
+                parent,
+                path: path.clone(),
+            });
+
+            if !hidden {
+                self.display_rows.push(DisplayRow::GroupHeader(group_ix));
+                self.display_groups.push(Some(group_ix));
+            }
+
+            self.build_level(
+                rows,
+                depth + 1,
+                Some(group_ix),
+                &path,
+                hidden || collapsed,
+                cell_text,
+            );
+
+            if !hidden && self.aggregates {
+                self.display_rows.push(DisplayRow::Aggregate(group_ix));
+                self.display_groups.push(Some(group_ix));
+            }
+        }
+    }
+
+    /// Returns the number of the display rows.
+    pub(crate) fn len(&self) -> usize {
+        self.display_rows.len()
+    }
+
+    pub(crate) fn get(&self, display_ix: usize) -> Option<DisplayRow> {
+        self.display_rows.get(display_ix).copied()
+    }
+
+    /// Returns the display index of the delegate row.
+    pub(crate) fn position_of(&self, row_ix: usize) -> Option<usize> {
+        self.display_rows
+            .iter()
+            .position(|row| *row == DisplayRow::Row(row_ix))
+    }
+
+    /// Returns the prev or next visible delegate row of the given row.
+    pub(crate) fn step(&self, row_ix: Option<usize>, forward: bool, wrap: bool) -> Option<usize> {
+        let rows: Vec<usize> = self
+            .display_rows
+            .iter()
+            .filter_map(DisplayRow::row_ix)
+            .collect();
+        if rows.is_empty() {
+            return None;
+        }
+
+        let Some(pos) = row_ix.and_then(|row_ix| rows.iter().position(|r| *r == row_ix)) else {
+            return Some(if forward {
+                rows[0]
+            } else {
+                rows[rows.len() - 1]
+            });
+        };
+
+        if forward {
+            match rows.get(pos + 1) {
+                Some(row_ix) => Some(*row_ix),
+                None if wrap => Some(rows[0]),
+                None => Some(rows[pos]),
+            }
+        } else if pos > 0 {
+            Some(rows[pos - 1])
+        } else if wrap {
+            Some(rows[rows.len() - 1])
+        } else {
+            Some(rows[pos])
+        }
+    }
+
+    /// Returns the group headers chain (from top level) to stick on the top
+    /// if the display row at `display_ix` is the first visible row.
+    pub(crate) fn sticky_groups(&self, display_ix: usize) -> Vec<usize> {
+        let mut group_ix = self.display_groups.get(display_ix).copied().flatten();
+        // If the first visible row is a group header, the header itself no need to stick.
+        if let Some(DisplayRow::GroupHeader(ix)) = self.get(display_ix) {
+            group_ix = self.groups.get(ix).and_then(|g| g.parent);
+        }
+
+        let mut chain = vec![];
+        while let Some(ix) = group_ix {
+            chain.push(ix);
+            group_ix = self.groups.get(ix).and_then(|g| g.parent);
+        }
+        chain.reverse();
+        chain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{DisplayRow, RowGroups};
+
+    fn cell_text(row_ix: usize, col_ix: usize) -> SharedString {
+        let data = [["A", "x"], ["B", "x"], ["A", "y"], ["A", "x"], ["B", "y"]];
+        data[row_ix][col_ix].into()
+    }
+
+    #[test]
+    fn test_group_by_single_column() {
+        let mut groups = RowGroups::default();
+        groups.set_group_by(vec![0]);
+        groups.prepare_if_needed(5, 0, cell_text);
+
+        assert_eq!(groups.groups.len(), 2);
+        assert_eq!(groups.groups[0].value, "A");
+        assert_eq!(groups.groups[0].rows, vec![0, 2, 3]);
+        assert_eq!(groups.groups[1].value, "B");
+        assert_eq!(groups.groups[1].rows, vec![1, 4]);
+        assert_eq!(
+            groups.display_rows,
+            vec![
+                DisplayRow::GroupHeader(0),
+                DisplayRow::Row(0),
+                DisplayRow::Row(2),
+                DisplayRow::Row(3),
+                DisplayRow::GroupHeader(1),
+                DisplayRow::Row(1),
+                DisplayRow::Row(4),
+            ]
+        );
+        assert_eq!(groups.position_of(1), Some(5));
+        assert_eq!(groups.sticky_groups(2), vec![0]);
+        assert_eq!(groups.sticky_groups(4), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_group_by_nested_and_collapse() {
+        let mut groups = RowGroups::default();
+        groups.set_group_by(vec![0, 1]);
+        groups.set_aggregates(true);
+        groups.prepare_if_needed(5, 0, cell_text);
+
+        // A
+        //   x: 0, 3
+        //   y: 2
+        // B
+        //   x: 1
+        //   y: 4
+        assert_eq!(groups.groups.len(), 6);
+        assert_eq!(groups.groups[1].value, "x");
+        assert_eq!(groups.groups[1].depth, 1);
+        assert_eq!(groups.groups[1].rows, vec![0, 3]);
+        assert_eq!(groups.sticky_groups(2), vec![0, 1]);
+
+        assert_eq!(groups.toggle(0), Some(true));
+        groups.prepare_if_needed(5, 0, cell_text);
+        assert!(groups.groups[0].collapsed);
+        assert_eq!(
+            &groups.display_rows[0..3],
+            &[
+                DisplayRow::GroupHeader(0),
+                DisplayRow::Aggregate(0),
+                DisplayRow::GroupHeader(3),
+            ]
+        );
+        assert_eq!(groups.position_of(0), None);
+        assert_eq!(groups.step(None, true, true), Some(1));
+        assert_eq!(groups.step(Some(4), true, true), Some(1));
+        assert_eq!(groups.step(Some(4), true, false), Some(4));
+        assert_eq!(groups.step(Some(1), false, true), Some(4));
+    }
+
+    #[test]
+    fn test_regroup_after_sort() {
+        let mut groups = RowGroups::default();
+        groups.set_group_by(vec![0]);
+        groups.prepare_if_needed(5, 0, cell_text);
+        assert_eq!(groups.groups[0].value, "A");
+
+        // Sort by the column 0 descending, the rows count is not changed.
+        let sorted = [1, 4, 0, 2, 3];
+        let sorted_cell_text = |row_ix: usize, col_ix: usize| cell_text(sorted[row_ix], col_ix);
+        groups.prepare_if_needed(5, 0, sorted_cell_text);
+        assert_eq!(groups.groups[0].value, "A");
+
+        groups.invalidate();
+        groups.prepare_if_needed(5, 0, sorted_cell_text);
+        assert_eq!(groups.groups[0].value, "B");
+        assert_eq!(groups.groups[0].rows, vec![0, 1]);
+        assert_eq!(groups.groups[1].value, "A");
+        assert_eq!(groups.groups[1].rows, vec![2, 3, 4]);
+        assert_eq!(groups.position_of(0), Some(1));
+    }
+
+    #[test]
+    fn test_expanded_rows() {
+        let mut groups = RowGroups::default();
+        groups.set_expanded([(1, 2)].into_iter().collect());
+        assert!(groups.is_mapped());
+        groups.prepare_if_needed(3, 0, cell_text);
+        assert_eq!(
+            groups.display_rows,
+            vec![
+                DisplayRow::Row(0),
+                DisplayRow::Row(1),
+                DisplayRow::Detail(1),
+                DisplayRow::Detail(1),
+                DisplayRow::Row(2),
+            ]
+        );
+        assert_eq!(groups.details, vec![(1, 2, 2)]);
+        assert_eq!(groups.position_of(2), Some(4));
+
+        // Skip the frozen rows.
+        groups.prepare_if_needed(3, 1, cell_text);
+        assert_eq!(groups.details, vec![(1, 1, 2)]);
+        assert_eq!(groups.position_of(0), None);
+
+        groups.set_group_by(vec![0]);
+        groups.prepare_if_needed(3, 1, cell_text);
+        assert_eq!(
+            &groups.display_rows[..4],
+            &[
+                DisplayRow::GroupHeader(0),
+                DisplayRow::Row(0),
+                DisplayRow::Row(2),
+                DisplayRow::GroupHeader(1),
+            ]
+        );
+        assert_eq!(groups.details, vec![(1, 5, 2)]);
+    }
+}
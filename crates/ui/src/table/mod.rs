@@ -1,30 +1,63 @@
-use std::{ops::Range, rc::Rc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     actions::{Cancel, SelectNext, SelectPrev},
     context_menu::ContextMenuExt,
-    h_flex,
+    h_flex, input,
+    pagination::{Pagination, PaginationEvent, PaginationState},
     popup_menu::PopupMenu,
-    scroll::{self, ScrollableMask, Scrollbar, ScrollbarState},
-    v_flex, ActiveTheme, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt,
-    VirtualListScrollHandle,
+    scroll::{
+        self, ScrollHandleOffsetable as _, ScrollTarget, ScrollableMask, Scrollbar, ScrollbarState,
+    },
+    snap_to_device_pixel, v_flex, ActiveTheme, Density, Direction, DirectionExt as _,
+    FocusableExt as _, Icon, IconName, Side, Sizable, Size, StyleOverrides, StyleSized as _,
+    StyledExt, VirtualListScrollHandle,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, App, AppContext, Axis, Bounds,
-    Context, Div, DragMoveEvent, Edges, EventEmitter, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, KeyBinding, ListSizingBehavior, MouseButton, MouseDownEvent, ParentElement,
+    actions, canvas, div, linear_color_stop, linear_gradient, point, prelude::FluentBuilder, px,
+    uniform_list, Action, AnyElement, App, AppContext, Axis, Bounds, Context, Div, DragMoveEvent,
+    Edges, Entity, EventEmitter, FocusHandle, Focusable, InteractiveElement, IntoElement,
+    KeyBinding, ListSizingBehavior, MouseButton, MouseDownEvent, MouseMoveEvent, ParentElement,
     Pixels, Point, Render, ScrollStrategy, ScrollWheelEvent, SharedString,
-    StatefulInteractiveElement as _, Styled, Task, UniformListScrollHandle, Window,
+    StatefulInteractiveElement as _, Styled, Subscription, Task, UniformListScrollHandle, Window,
 };
+use rust_i18n::t;
+use serde::Deserialize;
+use smol::Timer;
 
+mod cell;
 mod column;
+mod data_table;
 mod delegate;
+mod export;
+mod group;
 mod loading;
+mod state;
 
+pub use cell::{CellValue, ColumnKind, RecordsDelegate};
 pub use column::*;
+pub use data_table::DataTable;
 pub use delegate::*;
+pub use group::TableRowGroup;
+use group::{DisplayRow, RowGroups};
+pub use state::*;
+
+actions!(table, [SelectPrevColumn, SelectNextColumn, ShowAllColumns]);
 
-actions!(table, [SelectPrevColumn, SelectNextColumn]);
+/// Toggle the visibility of the column at the index, used in the column chooser menu.
+#[derive(Action, Clone, PartialEq, Eq, Deserialize)]
+#[action(namespace = table, no_json)]
+pub struct ToggleColumnVisible(pub usize);
+
+/// The width of the toggle to expand the row detail.
+const EXPAND_TOGGLE_WIDTH: Pixels = px(28.);
+/// The duration of the row expand and collapse animation.
+const EXPAND_DURATION: Duration = Duration::from_millis(150);
 
 pub(crate) fn init(cx: &mut App) {
     let context = Some("Table");
@@ -34,13 +67,65 @@ pub(crate) fn init(cx: &mut App) {
         KeyBinding::new("down", SelectNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", input::Copy, context),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", input::Copy, context),
     ]);
 }
 
+/// The style override kind of the table rows, see [`StyleOverrides`].
+pub struct TableRow;
+
+/// The style override kind of the odd rows (the stripe rows) in the table, see [`StyleOverrides`].
+///
+/// This is applied after [`TableRow`], e.g.: to get zebra rows for all tables:
+///
+/// ```ignore
+/// StyleOverrides::global_mut(cx)
+///     .register::<TableStripeRow>(|this, cx| this.bg(cx.theme().table_even));
+/// ```
+pub struct TableStripeRow;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum SelectionState {
     Column,
     Row,
+    Cell,
+}
+
+/// The rectangular range of the selected cells, see [`Table::cell_selectable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRange {
+    /// The `(row_ix, col_ix)` of the cell where the selection starts.
+    pub anchor: (usize, usize),
+    /// The `(row_ix, col_ix)` of the cell where the selection ends.
+    pub head: (usize, usize),
+}
+
+impl CellRange {
+    /// Create a range with only one cell.
+    pub fn new(row_ix: usize, col_ix: usize) -> Self {
+        Self {
+            anchor: (row_ix, col_ix),
+            head: (row_ix, col_ix),
+        }
+    }
+
+    /// Returns the row indices of the range.
+    pub fn rows(&self) -> Range<usize> {
+        self.anchor.0.min(self.head.0)..self.anchor.0.max(self.head.0) + 1
+    }
+
+    /// Returns the column indices of the range.
+    pub fn cols(&self) -> Range<usize> {
+        self.anchor.1.min(self.head.1)..self.anchor.1.max(self.head.1) + 1
+    }
+
+    /// Returns true if the cell is in the range.
+    pub fn contains(&self, row_ix: usize, col_ix: usize) -> bool {
+        self.rows().contains(&row_ix) && self.cols().contains(&col_ix)
+    }
 }
 
 #[derive(Clone)]
@@ -50,8 +135,16 @@ pub enum TableEvent {
     /// Double click on the row.
     DoubleClickedRow(usize),
     SelectColumn(usize),
+    /// The selected cell range is changed.
+    SelectCells(CellRange),
     ColumnWidthsChanged(Vec<Pixels>),
     MoveColumn(usize, usize),
+    /// The group at the index is collapsed (true) or expanded (false).
+    ToggleGroup(usize, bool),
+    /// The row at the index is expanded (true) or collapsed (false).
+    ExpandRow(usize, bool),
+    /// The column at the index is shown (true) or hidden (false).
+    ColumnVisibilityChanged(usize, bool),
 }
 
 /// The visible range of the rows and columns.
@@ -82,6 +175,10 @@ pub struct Table<D: TableDelegate> {
     bounds: Bounds<Pixels>,
     /// The bounds of the fixed head cols.
     fixed_head_cols_bounds: Bounds<Pixels>,
+    /// The bounds of the right fixed head cols.
+    fixed_right_head_cols_bounds: Bounds<Pixels>,
+    /// The measured height of the sticky group headers, 0 if no sticky headers.
+    sticky_headers_height: Pixels,
 
     col_groups: Vec<ColGroup>,
 
@@ -93,6 +190,8 @@ pub struct Table<D: TableDelegate> {
     pub col_selectable: bool,
     /// Whether the table can select row.
     pub row_selectable: bool,
+    /// Whether the table can select a rectangular range of cells.
+    pub cell_selectable: bool,
     /// Whether the table can sort.
     pub sortable: bool,
     /// Whether the table can resize columns.
@@ -101,6 +200,8 @@ pub struct Table<D: TableDelegate> {
     pub col_movable: bool,
     /// Enable/disable fixed columns feature.
     pub col_fixed: bool,
+    /// Whether to show the column chooser menu when right click the table head.
+    pub col_chooser: bool,
 
     pub vertical_scroll_handle: UniformListScrollHandle,
     pub vertical_scroll_state: ScrollbarState,
@@ -111,7 +212,12 @@ pub struct Table<D: TableDelegate> {
     selected_row: Option<usize>,
     selection_state: SelectionState,
     right_clicked_row: Option<usize>,
+    /// Whether the table head is right clicked, to show the column chooser menu.
+    right_clicked_head: bool,
     selected_col: Option<usize>,
+    selected_cells: Option<CellRange>,
+    /// Whether the mouse is dragging to select the cells.
+    selecting_cells: bool,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
@@ -120,13 +226,40 @@ pub struct Table<D: TableDelegate> {
     stripe: bool,
     /// Set to use border style of the table.
     border: bool,
+    /// Set to highlight the row when mouse hover.
+    row_hover: bool,
+    /// Set to render the divider between rows.
+    row_dividers: bool,
     /// The cell size of the table.
     size: Size,
+    /// The row density of the table, None to use the density of the theme.
+    density: Option<Density>,
+    /// The density of the theme, updated on render.
+    theme_density: Density,
+    /// The layout direction of the theme, updated on render.
+    direction: Direction,
+    /// The number of the top rows to keep visible when scrolling.
+    frozen_rows: usize,
+    /// The scale factor of the window in the last render, used to snap the row height.
+    scale_factor: f32,
+    /// Set to show the toggle to expand the row detail.
+    expandable: bool,
+    /// The expanded rows with the time of expanding.
+    expanded_rows: HashMap<usize, Instant>,
+    /// The rows in the collapsing animation with the time of collapsing.
+    collapsing_rows: HashMap<usize, Instant>,
     /// The visible range of the rows and columns.
     visible_range: VisibleRangeState,
+    /// The row groups, when the table is grouped by columns.
+    row_groups: RowGroups,
+    /// The pagination state, created when the delegate has [`TableDelegate::total_rows`].
+    pagination: Option<Entity<PaginationState>>,
 
     _measure: Vec<Duration>,
     _load_more_task: Task<()>,
+    /// The smooth scrolling tasks of the vertical and horizontal axis.
+    _scroll_tasks: [Task<()>; 2],
+    _pagination_subscription: Option<Subscription>,
 }
 
 impl<D> Table<D>
@@ -135,7 +268,7 @@ where
 {
     pub fn new(delegate: D, _: &mut Window, cx: &mut Context<Self>) -> Self {
         let mut this = Self {
-            focus_handle: cx.focus_handle(),
+            focus_handle: cx.focus_handle().tab_stop(true),
             delegate,
             col_groups: Vec::new(),
             horizontal_scroll_handle: VirtualListScrollHandle::new(),
@@ -145,24 +278,45 @@ where
             selection_state: SelectionState::Row,
             selected_row: None,
             right_clicked_row: None,
+            right_clicked_head: false,
             selected_col: None,
+            selected_cells: None,
+            selecting_cells: false,
             resizing_col: None,
             bounds: Bounds::default(),
             fixed_head_cols_bounds: Bounds::default(),
+            fixed_right_head_cols_bounds: Bounds::default(),
+            sticky_headers_height: px(0.),
             stripe: false,
             border: true,
+            row_hover: true,
+            row_dividers: true,
             size: Size::default(),
+            density: None,
+            theme_density: Density::default(),
+            direction: Direction::default(),
+            frozen_rows: 0,
+            scale_factor: 1.,
+            expandable: false,
+            expanded_rows: HashMap::new(),
+            collapsing_rows: HashMap::new(),
             scrollbar_visible: Edges::all(true),
             visible_range: VisibleRangeState::default(),
+            row_groups: RowGroups::default(),
+            pagination: None,
             loop_selection: true,
             col_selectable: true,
             row_selectable: true,
+            cell_selectable: false,
             sortable: true,
             col_movable: true,
             col_resizable: true,
             col_fixed: true,
+            col_chooser: true,
             _load_more_task: Task::ready(()),
+            _scroll_tasks: [Task::ready(()), Task::ready(())],
             _measure: Vec::new(),
+            _pagination_subscription: None,
         };
 
         this.prepare_col_groups(cx);
@@ -194,6 +348,76 @@ where
         self
     }
 
+    /// Set to highlight the row when mouse hover, default to true.
+    pub fn row_hover(mut self, row_hover: bool) -> Self {
+        self.row_hover = row_hover;
+        self
+    }
+
+    pub fn set_row_hover(&mut self, row_hover: bool, cx: &mut Context<Self>) {
+        self.row_hover = row_hover;
+        cx.notify();
+    }
+
+    /// Set to render the dividers between rows, default to true.
+    pub fn row_dividers(mut self, row_dividers: bool) -> Self {
+        self.row_dividers = row_dividers;
+        self
+    }
+
+    pub fn set_row_dividers(&mut self, row_dividers: bool, cx: &mut Context<Self>) {
+        self.row_dividers = row_dividers;
+        cx.notify();
+    }
+
+    /// Set the row density of the table, default to the [`crate::Theme::density`].
+    ///
+    /// The density scales the row height based on the [`Size`] of the table.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    pub fn set_density(&mut self, density: Density, cx: &mut Context<Self>) {
+        self.density = Some(density);
+        cx.notify();
+    }
+
+    /// Returns the row density of the table.
+    pub fn row_density(&self) -> Density {
+        self.density.unwrap_or(self.theme_density)
+    }
+
+    /// Returns the height of the rows by the size and density, snapped to the device pixels.
+    fn row_height(&self) -> Pixels {
+        snap_to_device_pixel(
+            self.size.table_row_height() * self.row_density().scale(),
+            self.scale_factor,
+        )
+    }
+
+    /// Set the number of the top rows to keep visible when scrolling, default is 0.
+    ///
+    /// The frozen rows are ignored when the rows are grouped.
+    pub fn frozen_rows(mut self, frozen_rows: usize) -> Self {
+        self.frozen_rows = frozen_rows;
+        self
+    }
+
+    pub fn set_frozen_rows(&mut self, frozen_rows: usize, cx: &mut Context<Self>) {
+        self.frozen_rows = frozen_rows;
+        cx.notify();
+    }
+
+    /// Returns the number of the frozen rows to render.
+    fn frozen_rows_count(&self, rows_count: usize) -> usize {
+        if self.row_groups.is_grouped() {
+            return 0;
+        }
+
+        self.frozen_rows.min(rows_count)
+    }
+
     /// Set to loop selection, default to true.
     pub fn loop_selection(mut self, loop_selection: bool) -> Self {
         self.loop_selection = loop_selection;
@@ -212,6 +436,12 @@ where
         self
     }
 
+    /// Set to show the column chooser menu when right click the table head, default to true.
+    pub fn col_chooser(mut self, col_chooser: bool) -> Self {
+        self.col_chooser = col_chooser;
+        self
+    }
+
     /// Set to enable/disable column sortable, default true
     pub fn sortable(mut self, sortable: bool) -> Self {
         self.sortable = sortable;
@@ -224,6 +454,15 @@ where
         self
     }
 
+    /// Set to enable/disable the cell range selection, default false
+    ///
+    /// Click a cell to select it, then drag or shift-click to select a rectangular range of cells.
+    /// The rows of the range are in the data order.
+    pub fn cell_selectable(mut self, cell_selectable: bool) -> Self {
+        self.cell_selectable = cell_selectable;
+        self
+    }
+
     /// Set to enable/disable column selectable, default true
     pub fn col_selectable(mut self, col_selectable: bool) -> Self {
         self.col_selectable = col_selectable;
@@ -251,8 +490,216 @@ where
         self
     }
 
+    /// Set to group the rows by the given columns, the first column is the top level group.
+    ///
+    /// The group value is the [`TableDelegate::cell_text`] of the column.
+    pub fn group_by(mut self, col_ixs: impl IntoIterator<Item = usize>) -> Self {
+        self.row_groups.set_group_by(col_ixs.into_iter().collect());
+        self
+    }
+
+    /// Set to group the rows by the given columns, pass empty to ungroup.
+    pub fn set_group_by(
+        &mut self,
+        col_ixs: impl IntoIterator<Item = usize>,
+        cx: &mut Context<Self>,
+    ) {
+        self.row_groups.set_group_by(col_ixs.into_iter().collect());
+        cx.notify();
+    }
+
+    /// Set to show an aggregate row at the end of each group, default to false.
+    ///
+    /// The aggregate cells are rendered by [`TableDelegate::render_aggregate_td`].
+    pub fn group_aggregates(mut self, aggregates: bool) -> Self {
+        self.row_groups.set_aggregates(aggregates);
+        self
+    }
+
+    /// Set to show an aggregate row at the end of each group.
+    pub fn set_group_aggregates(&mut self, aggregates: bool, cx: &mut Context<Self>) {
+        self.row_groups.set_aggregates(aggregates);
+        cx.notify();
+    }
+
+    /// Returns the row groups, empty if the table is not grouped.
+    pub fn groups(&self) -> &[TableRowGroup] {
+        &self.row_groups.groups
+    }
+
+    /// Toggle the collapsed state of the group at the given index.
+    pub fn toggle_group(&mut self, group_ix: usize, cx: &mut Context<Self>) {
+        if let Some(collapsed) = self.row_groups.toggle(group_ix) {
+            cx.emit(TableEvent::ToggleGroup(group_ix, collapsed));
+            cx.notify();
+        }
+    }
+
+    /// Collapse or expand all the groups.
+    pub fn set_groups_collapsed(&mut self, collapsed: bool, cx: &mut Context<Self>) {
+        self.row_groups.set_all_collapsed(collapsed);
+        cx.notify();
+    }
+
+    /// Set to show a toggle at the start of each row to expand the row detail, default is false.
+    ///
+    /// The detail is rendered by [`TableDelegate::render_expanded_row`].
+    pub fn expandable(mut self, expandable: bool) -> Self {
+        self.expandable = expandable;
+        self
+    }
+
+    pub fn set_expandable(&mut self, expandable: bool, cx: &mut Context<Self>) {
+        self.expandable = expandable;
+        cx.notify();
+    }
+
+    /// Returns true if the row at the index is expanded.
+    pub fn is_row_expanded(&self, row_ix: usize) -> bool {
+        self.expanded_rows.contains_key(&row_ix) && !self.collapsing_rows.contains_key(&row_ix)
+    }
+
+    /// Returns the expanded rows in ascending order.
+    pub fn expanded_rows(&self) -> Vec<usize> {
+        let mut rows: Vec<usize> = self
+            .expanded_rows
+            .keys()
+            .filter(|row_ix| !self.collapsing_rows.contains_key(row_ix))
+            .copied()
+            .collect();
+        rows.sort();
+        rows
+    }
+
+    /// Expand or collapse the row at the index, multiple rows can be expanded at the same time.
+    pub fn set_row_expanded(&mut self, row_ix: usize, expanded: bool, cx: &mut Context<Self>) {
+        if self.is_row_expanded(row_ix) == expanded {
+            return;
+        }
+
+        if expanded {
+            self.collapsing_rows.remove(&row_ix);
+            self.expanded_rows.insert(row_ix, Instant::now());
+        } else {
+            self.collapsing_rows.insert(row_ix, Instant::now());
+
+            // Remove the row after the collapse animation.
+            cx.spawn(async move |view, cx| {
+                Timer::after(EXPAND_DURATION).await;
+                _ = view.update(cx, |view, cx| {
+                    let finished = view
+                        .collapsing_rows
+                        .get(&row_ix)
+                        .is_some_and(|at| at.elapsed() >= EXPAND_DURATION);
+                    if finished {
+                        view.collapsing_rows.remove(&row_ix);
+                        view.expanded_rows.remove(&row_ix);
+                        cx.notify();
+                    }
+                });
+            })
+            .detach();
+        }
+        cx.emit(TableEvent::ExpandRow(row_ix, expanded));
+        cx.notify();
+    }
+
+    /// Toggle the expanded state of the row at the index.
+    pub fn toggle_row_expanded(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        let expanded = !self.is_row_expanded(row_ix);
+        self.set_row_expanded(row_ix, expanded, cx);
+    }
+
+    /// Collapse all the expanded rows.
+    pub fn collapse_all_rows(&mut self, cx: &mut Context<Self>) {
+        self.expanded_rows.clear();
+        self.collapsing_rows.clear();
+        cx.notify();
+    }
+
+    /// Returns the width of left side area not scrolled with the columns.
+    fn left_fixed_width(&self) -> Pixels {
+        let toggle_width = if self.expandable {
+            EXPAND_TOGGLE_WIDTH
+        } else {
+            px(0.)
+        };
+
+        toggle_width + self.fixed_head_cols_bounds.size.width
+    }
+
+    /// Returns the column index of the `ix` item in the scrolling columns.
+    ///
+    /// The scrolling columns are placed in the reverse order in the `Rtl` direction,
+    /// so the first column is at the right side.
+    fn scrolling_col_ix(
+        &self,
+        ix: usize,
+        left_columns_count: usize,
+        middle_columns_count: usize,
+    ) -> usize {
+        if self.direction.is_rtl() {
+            left_columns_count + middle_columns_count.saturating_sub(ix + 1)
+        } else {
+            left_columns_count + ix
+        }
+    }
+
+    /// Returns the sizes of the scrolling columns for the virtual list, in the order of [`Self::scrolling_col_ix`].
+    fn scrolling_col_sizes(
+        &self,
+        left_columns_count: usize,
+        middle_columns_count: usize,
+    ) -> Rc<Vec<gpui::Size<Pixels>>> {
+        let mut col_sizes: Vec<_> = self
+            .col_groups
+            .iter()
+            .skip(left_columns_count)
+            .take(middle_columns_count)
+            .map(|col| col.bounds.size)
+            .collect();
+        if self.direction.is_rtl() {
+            col_sizes.reverse();
+        }
+        Rc::new(col_sizes)
+    }
+
+    /// Returns the range of the scrolling columns (start from 0) by the `visible_range` of the virtual list.
+    fn scrolling_cols_range(
+        &self,
+        visible_range: Range<usize>,
+        middle_columns_count: usize,
+    ) -> Range<usize> {
+        if self.direction.is_rtl() {
+            middle_columns_count.saturating_sub(visible_range.end)
+                ..middle_columns_count.saturating_sub(visible_range.start)
+        } else {
+            visible_range
+        }
+    }
+
+    /// The wrapper of the virtual list of the scrolling columns, and the cell range highlight above it.
+    ///
+    /// In the `Rtl` direction, the width is limited to the columns, to place the columns
+    /// at the right side when they are narrower than the table.
+    fn render_scrolling_cols_wrap(&self, col_sizes: &[gpui::Size<Pixels>]) -> Div {
+        div()
+            .relative()
+            .size_full()
+            .when(self.direction.is_rtl(), |this| {
+                this.max_w(
+                    col_sizes
+                        .iter()
+                        .fold(px(0.), |width, size| width + size.width),
+                )
+            })
+    }
+
     /// When we update columns or rows, we need to refresh the table.
+    ///
+    /// This also rebuilds the row groups, call it after the cell values are changed in a grouped table.
     pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.row_groups.invalidate();
         self.prepare_col_groups(cx);
     }
 
@@ -260,16 +707,95 @@ where
         self.col_groups = (0..self.delegate.columns_count(cx))
             .map(|col_ix| {
                 let column = self.delegate().column(col_ix, cx);
+                // Keep the hidden state of the column.
+                let hidden = self
+                    .col_groups
+                    .iter()
+                    .any(|col_group| col_group.hidden && col_group.column.key == column.key);
                 ColGroup {
                     width: column.width,
                     bounds: Bounds::default(),
                     column: column.clone(),
+                    hidden,
                 }
             })
             .collect();
         cx.notify();
     }
 
+    /// Returns true if the column at the given index is visible.
+    pub fn is_col_visible(&self, col_ix: usize) -> bool {
+        self.col_groups
+            .get(col_ix)
+            .map_or(false, |col_group| !col_group.hidden)
+    }
+
+    /// Show or hide the column at the given index, the last visible column can't be hidden.
+    pub fn set_col_visible(&mut self, col_ix: usize, visible: bool, cx: &mut Context<Self>) {
+        if !visible && self.col_groups.iter().filter(|g| !g.hidden).count() <= 1 {
+            return;
+        }
+        let Some(col_group) = self.col_groups.get_mut(col_ix) else {
+            return;
+        };
+        if col_group.hidden != visible {
+            return;
+        }
+
+        col_group.hidden = !visible;
+        col_group.bounds = Bounds::default();
+        if !visible && self.selected_col == Some(col_ix) {
+            self.selected_col = None;
+        }
+        cx.emit(TableEvent::ColumnVisibilityChanged(col_ix, visible));
+        cx.notify();
+    }
+
+    fn action_toggle_col_visible(
+        &mut self,
+        action: &ToggleColumnVisible,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let col_ix = action.0;
+        self.set_col_visible(col_ix, !self.is_col_visible(col_ix), cx);
+    }
+
+    fn action_show_all_columns(
+        &mut self,
+        _: &ShowAllColumns,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        for col_ix in 0..self.col_groups.len() {
+            self.set_col_visible(col_ix, true, cx);
+        }
+    }
+
+    /// Build the column chooser menu to show or hide the columns.
+    fn col_chooser_menu(&self, menu: PopupMenu) -> PopupMenu {
+        let visible_count = self.col_groups.iter().filter(|g| !g.hidden).count();
+        self.col_groups
+            .iter()
+            .enumerate()
+            .fold(menu, |menu, (col_ix, col_group)| {
+                let visible = !col_group.hidden;
+                menu.menu_with_check_and_disabled(
+                    col_group.column.name.clone(),
+                    visible,
+                    Box::new(ToggleColumnVisible(col_ix)),
+                    // The last visible column can't be hidden.
+                    visible && visible_count <= 1,
+                )
+            })
+            .separator()
+            .menu_with_disabled(
+                t!("Table.Show All Columns"),
+                Box::new(ShowAllColumns),
+                visible_count == self.col_groups.len(),
+            )
+    }
+
     fn fixed_left_cols_count(&self) -> usize {
         if !self.col_fixed {
             return 0;
@@ -281,19 +807,105 @@ where
             .count()
     }
 
+    fn fixed_right_cols_count(&self) -> usize {
+        if !self.col_fixed {
+            return 0;
+        }
+
+        self.col_groups
+            .iter()
+            .filter(|col| col.column.fixed == Some(ColumnFixed::Right))
+            .count()
+    }
+
     /// Scroll to the row at the given index.
     pub fn scroll_to_row(&mut self, row_ix: usize, cx: &mut Context<Self>) {
-        self.vertical_scroll_handle
-            .scroll_to_item(row_ix, ScrollStrategy::Top);
+        if let Some(ix) = self.display_ix(row_ix) {
+            self.vertical_scroll_handle
+                .scroll_to_item(ix, ScrollStrategy::Top);
+        }
         cx.notify();
     }
 
+    /// Scroll to the offset or the row, animate the scrolling if `smooth` is true.
+    ///
+    /// The [`ScrollTarget::Offset`] is the offset of the rows (without the table head).
+    pub fn scroll_to(
+        &mut self,
+        target: impl Into<ScrollTarget>,
+        smooth: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let vertical_offset = self.vertical_scroll_handle.offset();
+        let horizontal_offset = self.horizontal_scroll_handle.offset();
+        let offset = match target.into() {
+            ScrollTarget::Offset(offset) => offset,
+            ScrollTarget::Row(row_ix) => {
+                let Some(ix) = self.display_ix(row_ix) else {
+                    return;
+                };
+                point(horizontal_offset.x, -self.row_height() * ix as f32)
+            }
+        };
+
+        let vertical_max = self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .base_handle
+            .max_offset();
+        let horizontal_max = self.horizontal_scroll_handle.max_offset();
+        let y = offset.y.clamp(-vertical_max.height, px(0.));
+        let x = offset.x.clamp(-horizontal_max.width, px(0.));
+
+        self._scroll_tasks = [
+            scroll::scroll_to_offset(
+                &self.vertical_scroll_handle,
+                point(vertical_offset.x, y),
+                smooth,
+                window,
+                cx,
+            ),
+            scroll::scroll_to_offset(
+                &self.horizontal_scroll_handle,
+                point(x, horizontal_offset.y),
+                smooth,
+                window,
+                cx,
+            ),
+        ];
+    }
+
+    /// Returns the index of the row in the uniform list,
+    /// this is different from the `row_ix` when the rows are grouped or frozen.
+    ///
+    /// Returns `None` if the row is frozen or in a collapsed group.
+    fn display_ix(&self, row_ix: usize) -> Option<usize> {
+        if self.row_groups.is_mapped() {
+            self.row_groups.position_of(row_ix)
+        } else {
+            row_ix.checked_sub(self.frozen_rows)
+        }
+    }
+
     // Scroll to the column at the given index.
     pub fn scroll_to_col(&mut self, col_ix: usize, cx: &mut Context<Self>) {
-        let col_ix = col_ix.saturating_sub(self.fixed_left_cols_count());
+        // The right fixed columns are always visible.
+        if col_ix >= self.col_groups.len() - self.fixed_right_cols_count() {
+            return;
+        }
+        let left_columns_count = self.fixed_left_cols_count();
+        let mut ix = col_ix.saturating_sub(left_columns_count);
+        // The scrolling columns are placed in the reverse order in the `Rtl` direction.
+        if self.direction.is_rtl() {
+            let middle_columns_count =
+                self.col_groups.len() - left_columns_count - self.fixed_right_cols_count();
+            ix = middle_columns_count.saturating_sub(ix + 1);
+        }
 
         self.horizontal_scroll_handle
-            .scroll_to_item(col_ix, ScrollStrategy::Top);
+            .scroll_to_item(ix, ScrollStrategy::Top);
         cx.notify();
     }
 
@@ -307,9 +919,9 @@ where
         self.selection_state = SelectionState::Row;
         self.right_clicked_row = None;
         self.selected_row = Some(row_ix);
-        if let Some(row_ix) = self.selected_row {
+        if let Some(ix) = self.display_ix(row_ix) {
             self.vertical_scroll_handle
-                .scroll_to_item(row_ix, ScrollStrategy::Top);
+                .scroll_to_item(ix, ScrollStrategy::Top);
         }
         cx.emit(TableEvent::SelectRow(row_ix));
         cx.notify();
@@ -331,14 +943,72 @@ where
         cx.notify();
     }
 
+    /// Returns the selected cell range.
+    pub fn selected_cells(&self) -> Option<CellRange> {
+        if self.selection_state == SelectionState::Cell {
+            self.selected_cells
+        } else {
+            None
+        }
+    }
+
+    /// Sets the selected cell range.
+    pub fn set_selected_cells(&mut self, range: CellRange, cx: &mut Context<Self>) {
+        self.selection_state = SelectionState::Cell;
+        self.selected_cells = Some(range);
+        cx.emit(TableEvent::SelectCells(range));
+        cx.notify();
+    }
+
     /// Clear the selection of the table.
     pub fn clear_selection(&mut self, cx: &mut Context<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = None;
         self.selected_col = None;
+        self.selected_cells = None;
         cx.notify();
     }
 
+    /// Returns the pagination state, if the delegate has [`TableDelegate::total_rows`].
+    ///
+    /// The pagination is created on the first render.
+    pub fn pagination(&self) -> Option<&Entity<PaginationState>> {
+        self.pagination.as_ref()
+    }
+
+    /// Create the pagination if needed, and sync the total rows from the delegate.
+    fn prepare_pagination(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(total_rows) = self.delegate.total_rows(cx) else {
+            return;
+        };
+
+        let pagination = match &self.pagination {
+            Some(pagination) => pagination.clone(),
+            None => {
+                let pagination = cx.new(|cx| PaginationState::new(window, cx));
+                self._pagination_subscription = Some(cx.subscribe_in(
+                    &pagination,
+                    window,
+                    |this, pagination, event: &PaginationEvent, window, cx| {
+                        if let PaginationEvent::PageChanged(page) = event {
+                            let page_size = pagination.read(cx).current_page_size();
+                            this.clear_selection(cx);
+                            this.vertical_scroll_handle
+                                .scroll_to_item(0, ScrollStrategy::Top);
+                            this.delegate.page_changed(*page, page_size, window, cx);
+                        }
+                    },
+                ));
+                self.pagination = Some(pagination.clone());
+                pagination
+            }
+        };
+
+        pagination.update(cx, |pagination, cx| {
+            pagination.set_total(total_rows, window, cx);
+        });
+    }
+
     /// Returns the visible range of the rows and columns.
     pub fn visible_range(&self) -> &VisibleRangeState {
         &self.visible_range
@@ -352,6 +1022,7 @@ where
         cx: &mut Context<Self>,
     ) {
         if ev.button == MouseButton::Right {
+            self.right_clicked_head = false;
             self.right_clicked_row = Some(row_ix);
         } else {
             self.set_selected_row(row_ix, cx);
@@ -362,24 +1033,80 @@ where
         }
     }
 
-    fn on_col_head_click(&mut self, col_ix: usize, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.col_selectable {
-            return;
-        }
-
-        let Some(col_group) = self.col_groups.get(col_ix) else {
-            return;
+    fn on_cell_mouse_down(
+        &mut self,
+        ev: &MouseDownEvent,
+        row_ix: usize,
+        col_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let anchor = match self.selected_cells {
+            Some(range) if ev.modifiers.shift && self.selection_state == SelectionState::Cell => {
+                range.anchor
+            }
+            _ => (row_ix, col_ix),
         };
 
-        if !col_group.column.selectable {
-            return;
-        }
+        self.on_row_click(ev, row_ix, window, cx);
+        self.selecting_cells = true;
+        self.set_selected_cells(
+            CellRange {
+                anchor,
+                head: (row_ix, col_ix),
+            },
+            cx,
+        );
+        cx.stop_propagation();
+    }
+
+    fn on_cell_mouse_move(
+        &mut self,
+        ev: &MouseMoveEvent,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.selecting_cells {
+            return;
+        }
+
+        if ev.pressed_button != Some(MouseButton::Left) {
+            self.selecting_cells = false;
+            return;
+        }
+
+        if let Some(range) = self.selected_cells {
+            if range.head != (row_ix, col_ix) {
+                self.set_selected_cells(
+                    CellRange {
+                        anchor: range.anchor,
+                        head: (row_ix, col_ix),
+                    },
+                    cx,
+                );
+            }
+        }
+    }
+
+    fn on_col_head_click(&mut self, col_ix: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.col_selectable {
+            return;
+        }
+
+        let Some(col_group) = self.col_groups.get(col_ix) else {
+            return;
+        };
+
+        if !col_group.column.selectable {
+            return;
+        }
 
         self.set_selected_col(col_ix, cx)
     }
 
     fn has_selection(&self) -> bool {
-        self.selected_row.is_some() || self.selected_col.is_some()
+        self.selected_row.is_some() || self.selected_col.is_some() || self.selected_cells.is_some()
     }
 
     fn action_cancel(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
@@ -396,6 +1123,16 @@ where
             return;
         }
 
+        if self.row_groups.is_grouped() {
+            if let Some(row_ix) =
+                self.row_groups
+                    .step(self.selected_row, false, self.loop_selection)
+            {
+                self.set_selected_row(row_ix, cx);
+            }
+            return;
+        }
+
         let mut selected_row = self.selected_row.unwrap_or(0);
         if selected_row > 0 {
             selected_row = selected_row.saturating_sub(1);
@@ -414,6 +1151,16 @@ where
             return;
         }
 
+        if self.row_groups.is_grouped() {
+            if let Some(row_ix) = self
+                .row_groups
+                .step(self.selected_row, true, self.loop_selection)
+            {
+                self.set_selected_row(row_ix, cx);
+            }
+            return;
+        }
+
         let selected_row = match self.selected_row {
             Some(selected_row) if selected_row < rows_count.saturating_sub(1) => selected_row + 1,
             Some(selected_row) => {
@@ -437,14 +1184,24 @@ where
     ) {
         let mut selected_col = self.selected_col.unwrap_or(0);
         let columns_count = self.delegate.columns_count(cx);
-        if selected_col > 0 {
-            selected_col = selected_col.saturating_sub(1);
-        } else {
-            if self.loop_selection {
+        // Skip the hidden columns.
+        for _ in 0..columns_count {
+            if selected_col > 0 {
+                selected_col = selected_col.saturating_sub(1);
+            } else if self.loop_selection {
                 selected_col = columns_count.saturating_sub(1);
+            } else {
+                break;
             }
+
+            if self.is_col_visible(selected_col) {
+                break;
+            }
+        }
+
+        if self.is_col_visible(selected_col) {
+            self.set_selected_col(selected_col, cx);
         }
-        self.set_selected_col(selected_col, cx);
     }
 
     fn action_select_next_col(
@@ -454,15 +1211,25 @@ where
         cx: &mut Context<Self>,
     ) {
         let mut selected_col = self.selected_col.unwrap_or(0);
-        if selected_col < self.delegate.columns_count(cx).saturating_sub(1) {
-            selected_col += 1;
-        } else {
-            if self.loop_selection {
+        let columns_count = self.delegate.columns_count(cx);
+        // Skip the hidden columns.
+        for _ in 0..columns_count {
+            if selected_col < columns_count.saturating_sub(1) {
+                selected_col += 1;
+            } else if self.loop_selection {
                 selected_col = 0;
+            } else {
+                break;
+            }
+
+            if self.is_col_visible(selected_col) {
+                break;
             }
         }
 
-        self.set_selected_col(selected_col, cx);
+        if self.is_col_visible(selected_col) {
+            self.set_selected_col(selected_col, cx);
+        }
     }
 
     /// Scroll table when mouse position is near the edge of the table bounds.
@@ -553,6 +1320,8 @@ where
         }
 
         self.delegate_mut().perform_sort(col_ix, sort, window, cx);
+        // The rows are reordered with the same count, the groups must be rebuilt.
+        self.row_groups.invalidate();
 
         cx.notify();
     }
@@ -572,6 +1341,27 @@ where
         let col_group = self.col_groups.remove(col_ix);
         self.col_groups.insert(to_ix, col_group);
 
+        if self.row_groups.is_grouped() {
+            let group_by = self
+                .row_groups
+                .group_by
+                .iter()
+                .map(|&ix| {
+                    if ix == col_ix {
+                        to_ix
+                    } else if col_ix < ix && ix <= to_ix {
+                        ix - 1
+                    } else if to_ix <= ix && ix < col_ix {
+                        ix + 1
+                    } else {
+                        ix
+                    }
+                })
+                .collect();
+            self.row_groups.group_by = group_by;
+            self.row_groups.invalidate();
+        }
+
         cx.emit(TableEvent::MoveColumn(col_ix, to_ix));
         cx.notify();
     }
@@ -613,6 +1403,21 @@ where
         }
 
         if axis == Axis::Vertical {
+            // Convert the display range to the delegate rows range.
+            let visible_range = if self.row_groups.is_mapped() {
+                let rows = visible_range
+                    .filter_map(|ix| self.row_groups.get(ix).and_then(|row| row.row_ix()));
+                let (start, end) = rows.fold((usize::MAX, 0), |(start, end), row_ix| {
+                    (start.min(row_ix), end.max(row_ix + 1))
+                });
+                if start >= end {
+                    return;
+                }
+                start..end
+            } else {
+                visible_range
+            };
+
             if self.visible_range.rows == visible_range {
                 return;
             }
@@ -638,12 +1443,20 @@ where
         let col_padding = col_group.column.paddings;
 
         div()
+            .when(col_group.hidden, |this| this.hidden())
             .w(col_width)
             .h_full()
             .flex_shrink_0()
             .overflow_hidden()
             .whitespace_nowrap()
             .table_cell_size(self.size)
+            .when(self.row_density() != Density::Comfortable, |this| {
+                // Keep the content height, only change the vertical paddings.
+                let paddings = self.size.table_cell_padding();
+                let content_height = self.size.table_row_height() - paddings.top - paddings.bottom;
+                let padding_y = ((self.row_height() - content_height) / 2.).max(px(0.));
+                this.pt(padding_y).pb(padding_y)
+            })
             .map(|this| match col_padding {
                 Some(padding) => this
                     .pl(padding.left)
@@ -654,28 +1467,131 @@ where
             })
     }
 
-    /// Show Column selection style, when the column is selected and the selection state is Column.
-    fn render_col_wrap(&self, col_ix: usize, _: &mut Window, cx: &mut Context<Self>) -> Div {
-        let el = h_flex().h_full();
-        let selectable = self.col_selectable
-            && self
-                .col_groups
-                .get(col_ix)
-                .map(|col_group| col_group.column.selectable)
-                .unwrap_or(false);
+    /// The wrapper of the cell to handle the cell selection by mouse.
+    ///
+    /// The selected column and cells are painted by [`Self::render_cell_range_run`] in one quad per row section.
+    fn render_col_wrap(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        h_flex()
+            .h_full()
+            .when(!self.is_col_visible(col_ix), |this| this.hidden())
+            .when(self.cell_selectable, |this| {
+                this.on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, ev, window, cx| {
+                        this.on_cell_mouse_down(ev, row_ix, col_ix, window, cx);
+                    }),
+                )
+                .on_mouse_move(cx.listener(move |this, ev, _, cx| {
+                    this.on_cell_mouse_move(ev, row_ix, col_ix, cx);
+                }))
+            })
+    }
 
-        if selectable
-            && self.selected_col == Some(col_ix)
-            && self.selection_state == SelectionState::Column
-        {
-            el.bg(cx.theme().table_active)
-        } else {
-            el
+    /// Render the selected column or cells of the row in the `cols` section as one merged quad,
+    /// instead of a background for each cell.
+    ///
+    /// The `offset_x` is the x of the first column of the section, e.g. the horizontal scroll offset,
+    /// the run is mirrored in the section in the `Rtl` direction.
+    fn render_cell_range_run(
+        &self,
+        row_ix: usize,
+        cols: Range<usize>,
+        offset_x: Pixels,
+        cx: &mut Context<Self>,
+    ) -> Option<Div> {
+        let selected = match self.selection_state {
+            SelectionState::Cell => {
+                let range = self.selected_cells?;
+                if !range.rows().contains(&row_ix) {
+                    return None;
+                }
+                range.cols()
+            }
+            SelectionState::Column => {
+                let col_ix = self.selected_col?;
+                let selectable = self.col_selectable
+                    && self
+                        .col_groups
+                        .get(col_ix)
+                        .is_some_and(|col_group| col_group.column.selectable);
+                if !selectable {
+                    return None;
+                }
+                col_ix..col_ix + 1
+            }
+            SelectionState::Row => return None,
+        };
+        let start = selected.start.max(cols.start);
+        let end = selected.end.min(cols.end);
+        if start >= end {
+            return None;
         }
+
+        let widths = cols.clone().map(|col_ix| {
+            if self.is_col_visible(col_ix) {
+                self.col_groups[col_ix].width
+            } else {
+                px(0.)
+            }
+        });
+        let widths = widths.collect::<Vec<_>>();
+        let (mut left, width) =
+            merged_run(widths.iter().copied(), start - cols.start..end - cols.start)?;
+        // The columns of the section are mirrored in the `Rtl` direction.
+        if self.direction.is_rtl() {
+            left = widths.iter().fold(px(0.), |total, w| total + *w) - left - width;
+        }
+
+        Some(
+            div()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(offset_x + left)
+                .w(width)
+                .bg(cx.theme().table_active),
+        )
+    }
+
+    /// Returns the end of the display rows that are fully occluded by the sticky group headers,
+    /// the rows before it are not visible, so no need to paint them.
+    ///
+    /// The occluded height is the measured height of the sticky headers of the last frame,
+    /// but not more than the headers of the current first row, so a visible row is never skipped.
+    fn sticky_occluded_rows_end(&self) -> usize {
+        if !self.row_groups.is_grouped() {
+            return 0;
+        }
+
+        let row_height = self.row_height();
+        let scroll_top = -self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .base_handle
+            .offset()
+            .y;
+        if scroll_top <= px(0.) || row_height <= px(0.) {
+            return 0;
+        }
+
+        let first_ix = (scroll_top / row_height).floor() as usize;
+        let sticky_count = self.row_groups.sticky_groups(first_ix).len();
+        let occluded_height = self
+            .sticky_headers_height
+            .min(row_height * sticky_count as f32);
+        occluded_rows_end(scroll_top, row_height, occluded_height)
     }
 
     fn render_vertical_scrollbar(
         &self,
+        frozen_rows_count: usize,
         _: &mut Window,
         cx: &mut Context<Self>,
     ) -> Option<impl IntoElement> {
@@ -685,10 +1601,10 @@ where
             div()
                 .occlude()
                 .absolute()
-                .top(self.size.table_row_height())
+                .top(self.row_height() * (1 + frozen_rows_count) as f32)
                 .right_0()
                 .bottom_0()
-                .w(scroll::WIDTH)
+                .w(scroll::width(cx))
                 .on_scroll_wheel(cx.listener(|_, _: &ScrollWheelEvent, _, cx| {
                     cx.notify();
                 }))
@@ -706,10 +1622,19 @@ where
         div()
             .occlude()
             .absolute()
-            .left(self.fixed_head_cols_bounds.size.width)
-            .right_0()
+            .map(|this| {
+                let (start, end) = (
+                    self.left_fixed_width(),
+                    self.fixed_right_head_cols_bounds.size.width,
+                );
+                if self.direction.is_rtl() {
+                    this.left(end).right(start)
+                } else {
+                    this.left(start).right(end)
+                }
+            })
             .bottom_0()
-            .h(scroll::WIDTH)
+            .h(scroll::width(cx))
             .on_scroll_wheel(cx.listener(|_, _: &ScrollWheelEvent, _, cx| {
                 cx.notify();
             }))
@@ -872,10 +1797,12 @@ where
 
         let movable = self.col_movable && col_group.column.movable;
         let paddings = col_group.column.paddings;
+        let fixed = col_group.column.fixed;
         let name = col_group.column.name.clone();
 
         h_flex()
             .h_full()
+            .when(col_group.hidden, |this| this.hidden())
             .child(
                 self.render_cell(col_ix, window, cx)
                     .id(("col-header", col_ix))
@@ -885,6 +1812,13 @@ where
                             this.on_col_head_click(col_ix, window, cx);
                         }),
                     )
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(|this, _, _, _| {
+                            this.right_clicked_row = None;
+                            this.right_clicked_head = true;
+                        }),
+                    )
                     .child(
                         h_flex()
                             .size_full()
@@ -924,6 +1858,10 @@ where
                                 if drag.entity_id != cx.entity_id() {
                                     return;
                                 }
+                                // Only allow to move the cols in the same fixed side.
+                                if table.col_groups[drag.col_ix].column.fixed != fixed {
+                                    return;
+                                }
 
                                 table.move_column(drag.col_ix, col_ix, window, cx);
                             },
@@ -949,24 +1887,47 @@ where
     fn render_table_head(
         &mut self,
         left_columns_count: usize,
+        right_columns_count: usize,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let view = cx.entity().clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
+        let columns_count = self.col_groups.len();
+        let middle_columns_count = columns_count - left_columns_count - right_columns_count;
+        let ths = (0..middle_columns_count)
+            .map(|ix| {
+                let col_ix = self.scrolling_col_ix(ix, left_columns_count, middle_columns_count);
+                self.render_th(col_ix, window, cx)
+            })
+            .collect::<Vec<_>>();
 
         // Reset fixed head columns bounds, if no fixed columns are present
         if left_columns_count == 0 {
             self.fixed_head_cols_bounds = Bounds::default();
         }
+        if right_columns_count == 0 {
+            self.fixed_right_head_cols_bounds = Bounds::default();
+        }
 
         h_flex()
+            .relative()
             .w_full()
-            .h(self.size.table_row_height())
+            .h(self.row_height())
             .flex_shrink_0()
             .border_b_1()
             .border_color(cx.theme().border)
             .text_color(cx.theme().table_head_foreground)
+            .direction(self.direction)
+            .when(self.expandable, |this| {
+                this.child(
+                    div()
+                        .w(EXPAND_TOGGLE_WIDTH)
+                        .h_full()
+                        .flex_shrink_0()
+                        .bg(cx.theme().table_head),
+                )
+            })
             .when(left_columns_count > 0, |this| {
                 let view = view.clone();
                 // Render left fixed columns
@@ -975,6 +1936,7 @@ where
                         .relative()
                         .h_full()
                         .bg(cx.theme().table_head)
+                        .direction(self.direction)
                         .children(
                             self.col_groups
                                 .iter()
@@ -984,15 +1946,7 @@ where
                         )
                         .child(
                             // Fixed columns border
-                            div()
-                                .absolute()
-                                .top_0()
-                                .right_0()
-                                .bottom_0()
-                                .w_0()
-                                .flex_shrink_0()
-                                .border_r_1()
-                                .border_color(cx.theme().border),
+                            self.render_fixed_cols_border(Side::Right, cx),
                         )
                         .child(
                             canvas(
@@ -1015,20 +1969,151 @@ where
                     .relative()
                     .track_scroll(&horizontal_scroll_handle)
                     .bg(cx.theme().table_head)
-                    .child(
-                        h_flex()
-                            .relative()
-                            .children(
-                                self.col_groups
-                                    .iter()
-                                    .skip(left_columns_count)
-                                    .enumerate()
-                                    .map(|(col_ix, _)| {
-                                        self.render_th(left_columns_count + col_ix, window, cx)
-                                    }),
+                    .child(h_flex().relative().map(|this| {
+                        let empty_col = self.delegate.render_last_empty_col(window, cx);
+                        if self.direction.is_rtl() {
+                            // Place the columns at the right side, if they are narrower than the table.
+                            this.flex_shrink_0()
+                                .min_w_full()
+                                .justify_end()
+                                .child(empty_col)
+                                .children(ths)
+                        } else {
+                            this.children(ths).child(empty_col)
+                        }
+                    })),
+            )
+            .when(right_columns_count > 0, |this| {
+                // Render right fixed columns
+                this.child(
+                    h_flex()
+                        .relative()
+                        .h_full()
+                        .flex_shrink_0()
+                        .bg(cx.theme().table_head)
+                        .direction(self.direction)
+                        .children(
+                            (columns_count - right_columns_count..columns_count)
+                                .map(|col_ix| self.render_th(col_ix, window, cx)),
+                        )
+                        .child(
+                            // Fixed columns border
+                            self.render_fixed_cols_border(Side::Left, cx),
+                        )
+                        .child(
+                            canvas(
+                                move |bounds, _, cx| {
+                                    view.update(cx, |r, _| r.fixed_right_head_cols_bounds = bounds)
+                                },
+                                |_, _, _, _| {},
                             )
-                            .child(self.delegate.render_last_empty_col(window, cx)),
-                    ),
+                            .absolute()
+                            .size_full(),
+                        ),
+                )
+            })
+            .children(self.render_fixed_cols_shadows(left_columns_count, right_columns_count, cx))
+    }
+
+    /// Render the border of the fixed columns at the `side` of them,
+    /// the side is mirrored in the `Rtl` direction, because the fixed columns are placed
+    /// at the start (the right side) of the table.
+    fn render_fixed_cols_border(&self, side: Side, cx: &App) -> Div {
+        div()
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .w_0()
+            .flex_shrink_0()
+            .border_color(cx.theme().border)
+            .map(|this| match self.direction.side(side) {
+                Side::Left => this.left_0().border_l_1(),
+                Side::Right => this.right_0().border_r_1(),
+            })
+    }
+
+    /// Render the shadows at the boundary of the fixed columns,
+    /// only visible when there are columns scrolled under the fixed columns.
+    ///
+    /// The left fixed columns are at the right side in the `Rtl` direction,
+    /// the columns before the scroll offset are under them.
+    fn render_fixed_cols_shadows(
+        &self,
+        left_columns_count: usize,
+        right_columns_count: usize,
+        cx: &mut Context<Self>,
+    ) -> Vec<Div> {
+        const SHADOW_WIDTH: Pixels = px(6.);
+
+        let shadow = cx.theme().foreground.opacity(0.08);
+        let offset_x = self.horizontal_scroll_handle.offset().x;
+        let max_offset_x = self.horizontal_scroll_handle.max_offset().width;
+        let (scrolled_start, scrolled_end) = if self.direction.is_rtl() {
+            (offset_x > -max_offset_x, offset_x < px(0.))
+        } else {
+            (offset_x < px(0.), offset_x > -max_offset_x)
+        };
+        let render_shadow = |side: Side, inset: Pixels| {
+            let this = div().absolute().top_0().bottom_0().w(SHADOW_WIDTH);
+            match self.direction.side(side) {
+                Side::Left => this.left(inset).bg(linear_gradient(
+                    90.,
+                    linear_color_stop(shadow, 0.),
+                    linear_color_stop(shadow.opacity(0.), 1.),
+                )),
+                Side::Right => this.right(inset).bg(linear_gradient(
+                    270.,
+                    linear_color_stop(shadow, 0.),
+                    linear_color_stop(shadow.opacity(0.), 1.),
+                )),
+            }
+        };
+
+        let mut shadows = vec![];
+        if left_columns_count > 0 && scrolled_start {
+            shadows.push(render_shadow(Side::Left, self.left_fixed_width()));
+        }
+        if right_columns_count > 0 && scrolled_end {
+            shadows.push(render_shadow(
+                Side::Right,
+                self.fixed_right_head_cols_bounds.size.width,
+            ));
+        }
+        shadows
+    }
+
+    /// Render the right fixed cells of the row, the `row_ix` is used to render the cell range selection.
+    fn render_right_fixed_cells(
+        &mut self,
+        row_ix: Option<usize>,
+        right_columns_count: usize,
+        render_td: impl Fn(&mut Self, usize, &mut Window, &mut Context<Self>) -> AnyElement,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let columns_count = self.col_groups.len();
+
+        h_flex()
+            .relative()
+            .h_full()
+            .flex_shrink_0()
+            .direction(self.direction)
+            .children(row_ix.and_then(|row_ix| {
+                self.render_cell_range_run(
+                    row_ix,
+                    columns_count - right_columns_count..columns_count,
+                    px(0.),
+                    cx,
+                )
+            }))
+            .children(
+                (columns_count - right_columns_count..columns_count)
+                    .map(|col_ix| render_td(self, col_ix, window, cx))
+                    .collect::<Vec<_>>(),
+            )
+            .child(
+                // Fixed columns border
+                self.render_fixed_cols_border(Side::Left, cx),
             )
     }
 
@@ -1038,6 +2123,7 @@ where
         row_ix: usize,
         rows_count: usize,
         left_columns_count: usize,
+        right_columns_count: usize,
         col_sizes: Rc<Vec<gpui::Size<Pixels>>>,
         columns_count: usize,
         extra_rows_count: usize,
@@ -1052,7 +2138,10 @@ where
         if row_ix < rows_count {
             let is_last_row = row_ix == rows_count - 1;
             let table_is_filled = extra_rows_count == 0;
-            let need_render_border = if is_last_row {
+            let is_last_frozen_row = row_ix + 1 == self.frozen_rows_count(rows_count);
+            let need_render_border = if !self.row_dividers || is_last_frozen_row {
+                false
+            } else if is_last_row {
                 if is_selected {
                     true
                 } else if table_is_filled {
@@ -1069,49 +2158,58 @@ where
 
             tr.h_flex()
                 .w_full()
-                .h(self.size.table_row_height())
+                .h(self.row_height())
                 .when(need_render_border, |this| {
                     this.border_b_1().border_color(cx.theme().table_row_border)
                 })
                 .when(is_stripe_row, |this| this.bg(cx.theme().table_even))
+                .direction(self.direction)
+                .refine_style(&StyleOverrides::style_for::<TableRow>(cx))
+                .when(row_ix % 2 != 0, |this| {
+                    this.refine_style(&StyleOverrides::style_for::<TableStripeRow>(cx))
+                })
                 .refine_style(&style)
                 .hover(|this| {
-                    if is_selected || self.right_clicked_row == Some(row_ix) {
+                    if !self.row_hover || is_selected || self.right_clicked_row == Some(row_ix) {
                         this
                     } else {
                         this.bg(cx.theme().table_hover)
                     }
                 })
+                .when(self.expandable, |this| {
+                    this.child(self.render_expand_toggle(row_ix, rows_count, cx))
+                })
                 .when(left_columns_count > 0, |this| {
                     // Left fixed columns
                     this.child(
                         h_flex()
                             .relative()
                             .h_full()
+                            .direction(self.direction)
+                            .children(self.render_cell_range_run(
+                                row_ix,
+                                0..left_columns_count,
+                                px(0.),
+                                cx,
+                            ))
                             .children({
                                 let mut items = Vec::with_capacity(left_columns_count);
 
                                 (0..left_columns_count).for_each(|col_ix| {
-                                    items.push(self.render_col_wrap(col_ix, window, cx).child(
-                                        self.render_cell(col_ix, window, cx).child(
-                                            self.measure_render_td(row_ix, col_ix, window, cx),
+                                    items.push(
+                                        self.render_col_wrap(row_ix, col_ix, window, cx).child(
+                                            self.render_cell(col_ix, window, cx).child(
+                                                self.measure_render_td(row_ix, col_ix, window, cx),
+                                            ),
                                         ),
-                                    ));
+                                    );
                                 });
 
                                 items
                             })
                             .child(
                                 // Fixed columns border
-                                div()
-                                    .absolute()
-                                    .top_0()
-                                    .right_0()
-                                    .bottom_0()
-                                    .w_0()
-                                    .flex_shrink_0()
-                                    .border_r_1()
-                                    .border_color(cx.theme().border),
+                                self.render_fixed_cols_border(Side::Right, cx),
                             ),
                     )
                 })
@@ -1120,48 +2218,92 @@ where
                         .flex_1()
                         .h_full()
                         .overflow_hidden()
-                        .relative()
+                        .direction(self.direction)
                         .child(
-                            crate::virtual_list::virtual_list(
-                                view,
-                                row_ix,
-                                Axis::Horizontal,
-                                col_sizes,
-                                {
-                                    move |table, visible_range: Range<usize>, window, cx| {
-                                        table.update_visible_range_if_need(
-                                            visible_range.clone(),
-                                            Axis::Horizontal,
-                                            window,
-                                            cx,
-                                        );
-
-                                        let mut items = Vec::with_capacity(
-                                            visible_range.end - visible_range.start,
-                                        );
-
-                                        visible_range.for_each(|col_ix| {
-                                            let col_ix = col_ix + left_columns_count;
-                                            let el =
-                                                table.render_col_wrap(col_ix, window, cx).child(
-                                                    table.render_cell(col_ix, window, cx).child(
-                                                        table.measure_render_td(
-                                                            row_ix, col_ix, window, cx,
-                                                        ),
+                            self.render_scrolling_cols_wrap(&col_sizes)
+                                .children(self.render_cell_range_run(
+                                    row_ix,
+                                    left_columns_count..columns_count - right_columns_count,
+                                    horizontal_scroll_handle.offset().x,
+                                    cx,
+                                ))
+                                .child(
+                                    crate::virtual_list::virtual_list(
+                                        view,
+                                        row_ix,
+                                        Axis::Horizontal,
+                                        col_sizes,
+                                        {
+                                            move |table, visible_range: Range<usize>, window, cx| {
+                                                let middle_columns_count = columns_count
+                                                    - left_columns_count
+                                                    - right_columns_count;
+                                                table.update_visible_range_if_need(
+                                                    table.scrolling_cols_range(
+                                                        visible_range.clone(),
+                                                        middle_columns_count,
                                                     ),
+                                                    Axis::Horizontal,
+                                                    window,
+                                                    cx,
                                                 );
 
-                                            items.push(el);
-                                        });
+                                                let mut items = Vec::with_capacity(
+                                                    visible_range.end - visible_range.start,
+                                                );
 
-                                        items
-                                    }
-                                },
-                            )
-                            .with_scroll_handle(&self.horizontal_scroll_handle),
+                                                visible_range.for_each(|ix| {
+                                                    let col_ix = table.scrolling_col_ix(
+                                                        ix,
+                                                        left_columns_count,
+                                                        middle_columns_count,
+                                                    );
+                                                    let el = table
+                                                        .render_col_wrap(row_ix, col_ix, window, cx)
+                                                        .child(
+                                                            table
+                                                                .render_cell(col_ix, window, cx)
+                                                                .child(table.measure_render_td(
+                                                                    row_ix, col_ix, window, cx,
+                                                                )),
+                                                        );
+
+                                                    items.push(el);
+                                                });
+
+                                                items
+                                            }
+                                        },
+                                    )
+                                    .with_scroll_handle(&self.horizontal_scroll_handle),
+                                ),
                         )
                         .child(self.delegate.render_last_empty_col(window, cx)),
                 )
+                .when(right_columns_count > 0, |this| {
+                    // Right fixed columns
+                    this.child(self.render_right_fixed_cells(
+                        Some(row_ix),
+                        right_columns_count,
+                        move |table, col_ix, window, cx| {
+                            table
+                                .render_col_wrap(row_ix, col_ix, window, cx)
+                                .child(
+                                    table
+                                        .render_cell(col_ix, window, cx)
+                                        .child(table.measure_render_td(row_ix, col_ix, window, cx)),
+                                )
+                                .into_any_element()
+                        },
+                        window,
+                        cx,
+                    ))
+                })
+                .children(self.render_fixed_cols_shadows(
+                    left_columns_count,
+                    right_columns_count,
+                    cx,
+                ))
                 // Row selected style
                 .when_some(self.selected_row, |this, _| {
                     this.when(
@@ -1213,9 +2355,14 @@ where
                 .h_flex()
                 .w_full()
                 .h_full()
-                .border_t_1()
-                .border_color(cx.theme().table_row_border)
+                .when(self.row_dividers, |this| {
+                    this.border_t_1().border_color(cx.theme().table_row_border)
+                })
                 .when(is_stripe_row, |this| this.bg(cx.theme().table_even))
+                .direction(self.direction)
+                .when(self.expandable, |this| {
+                    this.child(div().w(EXPAND_TOGGLE_WIDTH).flex_shrink_0())
+                })
                 .children((0..columns_count).map(|col_ix| {
                     h_flex()
                         .left(horizontal_scroll_handle.offset().x)
@@ -1225,11 +2372,312 @@ where
         }
     }
 
+    /// Render the toggle to expand the row detail, the frozen rows can't be expanded.
+    fn render_expand_toggle(
+        &self,
+        row_ix: usize,
+        rows_count: usize,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let expandable = row_ix >= self.frozen_rows_count(rows_count)
+            && self.delegate.row_expandable(row_ix, cx);
+        let icon = if self.is_row_expanded(row_ix) {
+            IconName::ChevronDown
+        } else {
+            IconName::ChevronRight.mirrored(self.direction)
+        };
+
+        h_flex()
+            .id(("row-expand", row_ix))
+            .w(EXPAND_TOGGLE_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .justify_center()
+            .when(expandable, |this| {
+                this.cursor_pointer()
+                    .child(
+                        Icon::new(icon)
+                            .size_4()
+                            .text_color(cx.theme().muted_foreground),
+                    )
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                    .on_click(cx.listener(move |table, _, _, cx| {
+                        table.toggle_row_expanded(row_ix, cx);
+                    }))
+            })
+    }
+
+    /// Render the details of the expanded rows as overlay of the table body,
+    /// only the visible details are rendered.
+    fn render_expanded_rows(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Vec<AnyElement> {
+        if self.row_groups.details.is_empty() {
+            return vec![];
+        }
+
+        let row_height = self.row_height();
+        let (offset_y, body_height) = {
+            let handle = &self.vertical_scroll_handle.0.borrow().base_handle;
+            (handle.offset().y, handle.bounds().size.height)
+        };
+
+        let mut items = vec![];
+        for (row_ix, display_ix, count) in self.row_groups.details.clone() {
+            let top = row_height * display_ix as f32 + offset_y;
+            let height = row_height * count as f32;
+            if top + height < px(0.) || top > body_height {
+                continue;
+            }
+
+            let collapsing = self.collapsing_rows.get(&row_ix);
+            let elapsed = collapsing
+                .or(self.expanded_rows.get(&row_ix))
+                .map(|at| at.elapsed())
+                .unwrap_or(EXPAND_DURATION);
+            if elapsed < EXPAND_DURATION {
+                window.request_animation_frame();
+            }
+            let mut progress = (elapsed.as_secs_f32() / EXPAND_DURATION.as_secs_f32()).min(1.);
+            if collapsing.is_some() {
+                progress = 1. - progress;
+            }
+
+            items.push(
+                div()
+                    .id(("table-row-detail", row_ix))
+                    .occlude()
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .top(top)
+                    .h(height * gpui::ease_in_out(progress))
+                    .overflow_hidden()
+                    .bg(cx.theme().table)
+                    .when(self.row_dividers, |this| {
+                        this.border_b_1().border_color(cx.theme().table_row_border)
+                    })
+                    .child(
+                        div()
+                            .h(height)
+                            .child(self.delegate.render_expanded_row(row_ix, window, cx)),
+                    )
+                    .into_any_element(),
+            );
+        }
+        items
+    }
+
+    fn render_group_header_row(
+        &mut self,
+        group_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let Some(group) = self.row_groups.groups.get(group_ix).cloned() else {
+            return div().into_any_element();
+        };
+
+        let paddings = self.size.table_cell_padding();
+        let icon = if group.collapsed {
+            IconName::ChevronRight.mirrored(self.direction)
+        } else {
+            IconName::ChevronDown
+        };
+
+        h_flex()
+            .id(("table-group", group_ix))
+            .w_full()
+            .h(self.row_height())
+            .flex_shrink_0()
+            .gap_1()
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .table_cell_size(self.size)
+            .pl(paddings.left + px(16.) * group.depth as f32)
+            .direction(self.direction)
+            .bg(cx.theme().table_head)
+            .text_color(cx.theme().table_head_foreground)
+            .border_b_1()
+            .border_color(cx.theme().table_row_border)
+            .child(
+                Icon::new(icon)
+                    .size_4()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(self.delegate.render_group_header(&group, window, cx))
+            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+            .on_click(cx.listener(move |table, _, _, cx| {
+                table.toggle_group(group_ix, cx);
+            }))
+            .into_any_element()
+    }
+
+    fn render_aggregate_row(
+        &mut self,
+        group_ix: usize,
+        left_columns_count: usize,
+        right_columns_count: usize,
+        col_sizes: Rc<Vec<gpui::Size<Pixels>>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let Some(group) = self.row_groups.groups.get(group_ix).cloned() else {
+            return div().into_any_element();
+        };
+        let right_group = group.clone();
+        let view = cx.entity().clone();
+        let middle_columns_count = self.col_groups.len() - left_columns_count - right_columns_count;
+
+        h_flex()
+            .w_full()
+            .h(self.row_height())
+            .font_medium()
+            .bg(cx.theme().table_head.opacity(0.5))
+            .border_b_1()
+            .border_color(cx.theme().table_row_border)
+            .direction(self.direction)
+            .when(self.expandable, |this| {
+                this.child(div().w(EXPAND_TOGGLE_WIDTH).flex_shrink_0())
+            })
+            .when(left_columns_count > 0, |this| {
+                this.child(
+                    h_flex()
+                        .relative()
+                        .h_full()
+                        .direction(self.direction)
+                        .children((0..left_columns_count).map(|col_ix| {
+                            self.render_cell(col_ix, window, cx).children(
+                                self.delegate
+                                    .render_aggregate_td(&group, col_ix, window, cx),
+                            )
+                        }))
+                        .child(self.render_fixed_cols_border(Side::Right, cx)),
+                )
+            })
+            .child(
+                h_flex()
+                    .flex_1()
+                    .h_full()
+                    .overflow_hidden()
+                    .relative()
+                    .direction(self.direction)
+                    .child(
+                        self.render_scrolling_cols_wrap(&col_sizes).child(
+                            crate::virtual_list::virtual_list(
+                                view,
+                                ("table-aggregate", group_ix),
+                                Axis::Horizontal,
+                                col_sizes,
+                                move |table, visible_range: Range<usize>, window, cx| {
+                                    visible_range
+                                        .map(|ix| {
+                                            let col_ix = table.scrolling_col_ix(
+                                                ix,
+                                                left_columns_count,
+                                                middle_columns_count,
+                                            );
+                                            table.render_cell(col_ix, window, cx).children(
+                                                table.delegate.render_aggregate_td(
+                                                    &group, col_ix, window, cx,
+                                                ),
+                                            )
+                                        })
+                                        .collect::<Vec<_>>()
+                                },
+                            )
+                            .with_scroll_handle(&self.horizontal_scroll_handle),
+                        ),
+                    )
+                    .child(self.delegate.render_last_empty_col(window, cx)),
+            )
+            .when(right_columns_count > 0, |this| {
+                this.child(self.render_right_fixed_cells(
+                    None,
+                    right_columns_count,
+                    move |table, col_ix, window, cx| {
+                        table
+                            .render_cell(col_ix, window, cx)
+                            .children(table.delegate.render_aggregate_td(
+                                &right_group,
+                                col_ix,
+                                window,
+                                cx,
+                            ))
+                            .into_any_element()
+                    },
+                    window,
+                    cx,
+                ))
+            })
+            .into_any_element()
+    }
+
+    /// Render the headers of the groups that the first visible row belongs to,
+    /// to keep them stick on the top of the table body when scrolling.
+    fn render_sticky_group_headers(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<impl IntoElement> {
+        if !self.row_groups.is_grouped() {
+            return None;
+        }
+
+        let row_height = self.row_height();
+        let offset_y = self
+            .vertical_scroll_handle
+            .0
+            .borrow()
+            .base_handle
+            .offset()
+            .y;
+        if offset_y >= px(0.) {
+            self.sticky_headers_height = px(0.);
+            return None;
+        }
+
+        let first_ix = (-offset_y / row_height).floor() as usize;
+        let sticky_groups = self.row_groups.sticky_groups(first_ix);
+        if sticky_groups.is_empty() {
+            self.sticky_headers_height = px(0.);
+            return None;
+        }
+
+        let view = cx.entity().clone();
+        Some(
+            v_flex()
+                .occlude()
+                .absolute()
+                .top_0()
+                .left_0()
+                .right_0()
+                .children(
+                    sticky_groups
+                        .into_iter()
+                        .map(|group_ix| self.render_group_header_row(group_ix, window, cx)),
+                )
+                .child(
+                    // To save the height of the sticky headers.
+                    canvas(
+                        move |bounds, _, cx| {
+                            view.update(cx, |r, _| r.sticky_headers_height = bounds.size.height)
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                ),
+        )
+    }
+
     /// Calculate the extra rows needed to fill the table empty space when `stripe` is true.
     fn calculate_extra_rows_needed(&self, rows_count: usize) -> usize {
         let mut extra_rows_needed = 0;
 
-        let row_height = self.size.table_row_height();
+        let row_height = self.row_height();
         let total_height = self
             .vertical_scroll_handle
             .0
@@ -1318,6 +2766,24 @@ where
 {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         self.measure(window, cx);
+        self.scale_factor = window.scale_factor();
+        self.theme_density = cx.theme().density;
+        if self.direction != cx.theme().direction {
+            self.direction = cx.theme().direction;
+            // Scroll to the first column, it is at the end of the scrolling in the `Rtl` direction.
+            self.horizontal_scroll_handle
+                .set_offset(point(px(0.), px(0.)));
+            if self.direction.is_rtl() {
+                let middle_columns_count = self.col_groups.len()
+                    - self.fixed_left_cols_count()
+                    - self.fixed_right_cols_count();
+                if middle_columns_count > 0 {
+                    self.horizontal_scroll_handle
+                        .scroll_to_item(middle_columns_count - 1, ScrollStrategy::Top);
+                }
+            }
+        }
+        self.prepare_pagination(window, cx);
 
         let view = cx.entity().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
@@ -1328,13 +2794,42 @@ where
             .iter()
             .filter(|col| self.col_fixed && col.column.fixed == Some(ColumnFixed::Left))
             .count();
+        let right_columns_count = self.fixed_right_cols_count();
+        let middle_columns_count = self.col_groups.len() - left_columns_count - right_columns_count;
         let rows_count = self.delegate.rows_count(cx);
+        let frozen_rows_count = self.frozen_rows_count(rows_count);
+        let row_height = self.row_height();
+        let expanded = self
+            .expanded_rows
+            .keys()
+            .filter(|&&row_ix| frozen_rows_count <= row_ix && row_ix < rows_count)
+            .map(|&row_ix| {
+                let height = self.delegate.expanded_row_height(row_ix, cx);
+                (row_ix, ((height / row_height).ceil() as usize).max(1))
+            })
+            .collect::<BTreeMap<_, _>>();
+        self.row_groups.set_expanded(expanded);
+        if self.row_groups.is_mapped() {
+            let delegate = &self.delegate;
+            self.row_groups
+                .prepare_if_needed(rows_count, frozen_rows_count, |row_ix, col_ix| {
+                    delegate.cell_text(row_ix, col_ix, cx)
+                });
+        }
+        let is_mapped = self.row_groups.is_mapped();
+        // The rows count in the uniform list, the frozen rows are rendered outside.
+        let list_rows_count = if is_mapped {
+            self.row_groups.len()
+        } else {
+            rows_count - frozen_rows_count
+        };
         let loading = self.delegate.loading(cx);
-        let extra_rows_count = self.calculate_extra_rows_needed(rows_count);
+        let load_error = self.delegate.load_error(cx);
+        let extra_rows_count = self.calculate_extra_rows_needed(list_rows_count);
         let render_rows_count = if self.stripe {
-            rows_count + extra_rows_count
+            list_rows_count + extra_rows_count
         } else {
-            rows_count
+            list_rows_count
         };
 
         let inner_table = v_flex()
@@ -1346,105 +2841,222 @@ where
             .on_action(cx.listener(Self::action_select_prev))
             .on_action(cx.listener(Self::action_select_next_col))
             .on_action(cx.listener(Self::action_select_prev_col))
+            .on_action(cx.listener(Self::action_toggle_col_visible))
+            .on_action(cx.listener(Self::action_show_all_columns))
+            .on_action(cx.listener(Self::action_copy))
             .size_full()
             .overflow_hidden()
-            .child(self.render_table_head(left_columns_count, window, cx))
+            .child(self.render_table_head(left_columns_count, right_columns_count, window, cx))
             .context_menu({
                 let view = view.clone();
                 move |this, window: &mut Window, cx: &mut Context<PopupMenu>| {
-                    if let Some(row_ix) = view.read(cx).right_clicked_row {
-                        view.read(cx)
-                            .delegate
-                            .context_menu(row_ix, this, window, cx)
+                    let right_clicked_head =
+                        view.update(cx, |table, _| std::mem::take(&mut table.right_clicked_head));
+                    let table = view.read(cx);
+                    if right_clicked_head {
+                        if table.col_chooser {
+                            table
+                                .col_chooser_menu(this)
+                                .action_context(table.focus_handle.clone())
+                        } else {
+                            this
+                        }
+                    } else if let Some(row_ix) = table.right_clicked_row {
+                        table.delegate.context_menu(row_ix, this, window, cx)
                     } else {
                         this
                     }
                 }
             })
             .map(|this| {
-                if rows_count == 0 {
+                if let Some(error) = load_error {
+                    this.child(
+                        div()
+                            .size_full()
+                            .child(self.delegate.render_error(error, window, cx)),
+                    )
+                } else if rows_count == 0 {
                     this.child(
                         div()
                             .size_full()
                             .child(self.delegate.render_empty(window, cx)),
                     )
                 } else {
-                    this.child(
-                        h_flex().id("table-body").flex_grow().size_full().child(
-                            uniform_list(
-                                "table-uniform-list",
-                                render_rows_count,
-                                cx.processor(
-                                    move |table, visible_range: Range<usize>, window, cx| {
-                                        // We must calculate the col sizes here, because the col sizes
-                                        // need render_th first, then that method will set the bounds of each col.
-                                        let col_sizes: Rc<Vec<gpui::Size<Pixels>>> = Rc::new(
-                                            table
-                                                .col_groups
-                                                .iter()
-                                                .skip(left_columns_count)
-                                                .map(|col| col.bounds.size)
-                                                .collect(),
-                                        );
-
-                                        table.load_more_if_need(
-                                            rows_count,
-                                            visible_range.end,
-                                            window,
-                                            cx,
-                                        );
-                                        table.update_visible_range_if_need(
-                                            visible_range.clone(),
-                                            Axis::Vertical,
-                                            window,
-                                            cx,
-                                        );
-
-                                        if visible_range.end > rows_count {
-                                            table.scroll_to_row(
-                                                std::cmp::min(
-                                                    visible_range.start,
-                                                    rows_count.saturating_sub(1),
-                                                ),
-                                                cx,
-                                            );
-                                        }
+                    this.when(frozen_rows_count > 0, |this| {
+                        let col_sizes: Rc<Vec<gpui::Size<Pixels>>> =
+                            self.scrolling_col_sizes(left_columns_count, middle_columns_count);
 
-                                        let mut items = Vec::with_capacity(
-                                            visible_range.end.saturating_sub(visible_range.start),
-                                        );
-
-                                        // Render fake rows to fill the table
-                                        visible_range.for_each(|row_ix| {
-                                            // Render real rows for available data
-                                            items.push(table.render_table_row(
+                        // Frozen rows
+                        this.child(
+                            v_flex()
+                                .flex_shrink_0()
+                                .border_b_1()
+                                .border_color(cx.theme().border)
+                                .children(
+                                    (0..frozen_rows_count)
+                                        .map(|row_ix| {
+                                            self.render_table_row(
                                                 row_ix,
                                                 rows_count,
                                                 left_columns_count,
+                                                right_columns_count,
                                                 col_sizes.clone(),
                                                 columns_count,
                                                 extra_rows_count,
                                                 window,
                                                 cx,
-                                            ));
-                                        });
-
-                                        items
-                                    },
+                                            )
+                                            .into_any_element()
+                                        })
+                                        .collect::<Vec<_>>(),
                                 ),
-                            )
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .id("table-body")
                             .flex_grow()
                             .size_full()
-                            .with_sizing_behavior(ListSizingBehavior::Auto)
-                            .track_scroll(vertical_scroll_handle)
-                            .into_any_element(),
-                        ),
+                            .child(
+                                uniform_list(
+                                    "table-uniform-list",
+                                    render_rows_count,
+                                    cx.processor(
+                                        move |table, visible_range: Range<usize>, window, cx| {
+                                            // We must calculate the col sizes here, because the col sizes
+                                            // need render_th first, then that method will set the bounds of each col.
+                                            let col_sizes: Rc<Vec<gpui::Size<Pixels>>> = table
+                                                .scrolling_col_sizes(
+                                                    left_columns_count,
+                                                    middle_columns_count,
+                                                );
+
+                                            table.load_more_if_need(
+                                                list_rows_count,
+                                                visible_range.end,
+                                                window,
+                                                cx,
+                                            );
+                                            let visible_rows = if is_mapped {
+                                                visible_range.clone()
+                                            } else {
+                                                visible_range.start + frozen_rows_count
+                                                    ..visible_range.end + frozen_rows_count
+                                            };
+                                            table.update_visible_range_if_need(
+                                                visible_rows,
+                                                Axis::Vertical,
+                                                window,
+                                                cx,
+                                            );
+
+                                            if visible_range.end > list_rows_count {
+                                                table.vertical_scroll_handle.scroll_to_item(
+                                                    std::cmp::min(
+                                                        visible_range.start,
+                                                        list_rows_count.saturating_sub(1),
+                                                    ),
+                                                    ScrollStrategy::Top,
+                                                );
+                                            }
+
+                                            let mut items = Vec::with_capacity(
+                                                visible_range
+                                                    .end
+                                                    .saturating_sub(visible_range.start),
+                                            );
+
+                                            // Skip the rows hidden under the sticky group headers
+                                            let occluded_end = table.sticky_occluded_rows_end();
+
+                                            // Render fake rows to fill the table
+                                            visible_range.for_each(|ix| {
+                                                if ix < occluded_end {
+                                                    items.push(
+                                                        div()
+                                                            .w_full()
+                                                            .h(row_height)
+                                                            .into_any_element(),
+                                                    );
+                                                    return;
+                                                }
+
+                                                let row_ix = if !is_mapped {
+                                                    ix + frozen_rows_count
+                                                } else {
+                                                    match table.row_groups.get(ix) {
+                                                        Some(DisplayRow::Row(row_ix)) => row_ix,
+                                                        // The placeholder of the detail, the detail is rendered in overlay.
+                                                        Some(DisplayRow::Detail(_)) => {
+                                                            items.push(
+                                                                div()
+                                                                    .w_full()
+                                                                    .h(row_height)
+                                                                    .into_any_element(),
+                                                            );
+                                                            return;
+                                                        }
+                                                        Some(DisplayRow::GroupHeader(group_ix)) => {
+                                                            items.push(
+                                                                table.render_group_header_row(
+                                                                    group_ix, window, cx,
+                                                                ),
+                                                            );
+                                                            return;
+                                                        }
+                                                        Some(DisplayRow::Aggregate(group_ix)) => {
+                                                            items.push(table.render_aggregate_row(
+                                                                group_ix,
+                                                                left_columns_count,
+                                                                right_columns_count,
+                                                                col_sizes.clone(),
+                                                                window,
+                                                                cx,
+                                                            ));
+                                                            return;
+                                                        }
+                                                        // The fake rows
+                                                        None => rows_count + ix - list_rows_count,
+                                                    }
+                                                };
+
+                                                // Render real rows for available data
+                                                items.push(
+                                                    table
+                                                        .render_table_row(
+                                                            row_ix,
+                                                            rows_count,
+                                                            left_columns_count,
+                                                            right_columns_count,
+                                                            col_sizes.clone(),
+                                                            columns_count,
+                                                            extra_rows_count,
+                                                            window,
+                                                            cx,
+                                                        )
+                                                        .into_any_element(),
+                                                );
+                                            });
+
+                                            items
+                                        },
+                                    ),
+                                )
+                                .flex_grow()
+                                .size_full()
+                                .with_sizing_behavior(ListSizingBehavior::Auto)
+                                .track_scroll(vertical_scroll_handle)
+                                .into_any_element(),
+                            )
+                            .relative()
+                            .children(self.render_expanded_rows(window, cx))
+                            .children(self.render_sticky_group_headers(window, cx)),
                     )
                 }
             });
 
         let view = cx.entity().clone();
-        div()
+        let table = div()
             .size_full()
             .when(self.border, |this| {
                 this.rounded(cx.theme().radius)
@@ -1452,6 +3064,7 @@ where
                     .border_color(cx.theme().border)
             })
             .bg(cx.theme().table)
+            .focus_ring(self.focus_handle.is_focused(window), px(0.), window, cx)
             .when(loading, |this| {
                 this.child(self.delegate().render_loading(self.size, window, cx))
             })
@@ -1483,9 +3096,90 @@ where
                             this.child(self.render_horizontal_scrollbar(window, cx))
                         })
                         .when(self.scrollbar_visible.right && rows_count > 0, |this| {
-                            this.children(self.render_vertical_scrollbar(window, cx))
+                            this.children(self.render_vertical_scrollbar(
+                                frozen_rows_count,
+                                window,
+                                cx,
+                            ))
                         }),
                 )
-            })
+            });
+
+        match &self.pagination {
+            Some(pagination) if self.delegate.total_rows(cx).is_some() => v_flex()
+                .size_full()
+                .gap_2()
+                .child(div().flex_1().min_h_0().child(table))
+                .child(
+                    h_flex()
+                        .justify_end()
+                        .child(Pagination::new(pagination).with_size(self.size)),
+                )
+                .into_any_element(),
+            _ => table.into_any_element(),
+        }
+    }
+}
+
+/// Returns the end of the display rows, which are fully hidden under the `occluded_height` at the top of body.
+///
+/// The display rows have the same `row_height`, including the group headers and the placeholders of the
+/// expanded row details, so the row at `ix` is at `ix * row_height`.
+fn occluded_rows_end(scroll_top: Pixels, row_height: Pixels, occluded_height: Pixels) -> usize {
+    if row_height <= px(0.) {
+        return 0;
+    }
+
+    ((scroll_top + occluded_height) / row_height).floor() as usize
+}
+
+/// Returns the (left, width) of the merged `selected` columns by the column `widths`,
+/// or None if the selected columns have no width.
+fn merged_run(
+    widths: impl IntoIterator<Item = Pixels>,
+    selected: Range<usize>,
+) -> Option<(Pixels, Pixels)> {
+    let mut left = px(0.);
+    let mut width = px(0.);
+    for (ix, w) in widths.into_iter().enumerate() {
+        if ix < selected.start {
+            left += w;
+        } else if ix < selected.end {
+            width += w;
+        } else {
+            break;
+        }
+    }
+
+    (width > px(0.)).then_some((left, width))
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{merged_run, occluded_rows_end};
+
+    #[test]
+    fn test_merged_run() {
+        let widths = [px(100.), px(0.), px(50.), px(80.)];
+        assert_eq!(merged_run(widths, 0..1), Some((px(0.), px(100.))));
+        assert_eq!(merged_run(widths, 1..3), Some((px(100.), px(50.))));
+        assert_eq!(merged_run(widths, 2..10), Some((px(100.), px(130.))));
+        // The hidden column has no width.
+        assert_eq!(merged_run(widths, 1..2), None);
+        assert_eq!(merged_run(widths, 4..5), None);
+    }
+
+    #[test]
+    fn test_occluded_rows_end() {
+        let row_height = px(20.);
+        assert_eq!(occluded_rows_end(px(0.), row_height, px(0.)), 0);
+        // The rows 0..2 are scrolled out, and the rows 2..4 are under 2 sticky headers.
+        assert_eq!(occluded_rows_end(px(40.), row_height, px(40.)), 4);
+        // The row 4 is partly visible below the headers.
+        assert_eq!(occluded_rows_end(px(50.), row_height, px(40.)), 4);
+        assert_eq!(occluded_rows_end(px(60.), row_height, px(40.)), 5);
+        assert_eq!(occluded_rows_end(px(60.), px(0.), px(40.)), 0);
     }
 }
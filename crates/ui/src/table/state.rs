@@ -0,0 +1,112 @@
+use gpui::{Context, Pixels, SharedString, Window};
+use serde::{Deserialize, Serialize};
+
+use super::{Table, TableDelegate};
+
+/// Used to serialize and deserialize the column layout of the [`Table`].
+///
+/// Use [`TableState::save`] to save the user's column order, widths and visibility,
+/// and [`TableState::load`] to restore it in the next session.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableState {
+    /// The columns in display order.
+    pub columns: Vec<ColumnState>,
+}
+
+/// Used to serialize and deserialize a column of the [`Table`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnState {
+    /// The key of the column, see [`super::Column::key`].
+    pub key: SharedString,
+    pub width: Pixels,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+}
+
+impl TableState {
+    /// Save the column layout of the table.
+    pub fn save<D: TableDelegate>(table: &Table<D>) -> Self {
+        Self {
+            columns: table
+                .col_groups
+                .iter()
+                .map(|col_group| ColumnState {
+                    key: col_group.column.key.clone(),
+                    width: col_group.width,
+                    hidden: col_group.hidden,
+                })
+                .collect(),
+        }
+    }
+
+    /// Restore the column layout to the table.
+    ///
+    /// The columns are matched by key, the unknown columns in the state are ignored,
+    /// and the new columns that are not in the state are kept after the restored columns.
+    pub fn load<D: TableDelegate>(
+        &self,
+        table: &mut Table<D>,
+        window: &mut Window,
+        cx: &mut Context<Table<D>>,
+    ) {
+        let mut to_ix = 0;
+        for col_state in self.columns.iter() {
+            let Some(col_ix) = table
+                .col_groups
+                .iter()
+                .position(|col_group| col_group.column.key == col_state.key)
+            else {
+                continue;
+            };
+
+            table.move_column(col_ix, to_ix, window, cx);
+            let col_group = &mut table.col_groups[to_ix];
+            col_group.width = col_state.width;
+            col_group.hidden = col_state.hidden;
+            to_ix += 1;
+        }
+
+        // Keep at least one column visible.
+        if table.col_groups.iter().all(|col_group| col_group.hidden) {
+            if let Some(col_group) = table.col_groups.first_mut() {
+                col_group.hidden = false;
+            }
+        }
+
+        let widths = table.col_groups.iter().map(|g| g.width).collect();
+        cx.emit(super::TableEvent::ColumnWidthsChanged(widths));
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{ColumnState, TableState};
+
+    #[test]
+    fn test_table_state_json() {
+        let state = TableState {
+            columns: vec![
+                ColumnState {
+                    key: "name".into(),
+                    width: px(120.),
+                    hidden: false,
+                },
+                ColumnState {
+                    key: "price".into(),
+                    width: px(80.),
+                    hidden: true,
+                },
+            ],
+        };
+
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"columns":[{"key":"name","width":120.0},{"key":"price","width":80.0,"hidden":true}]}"#
+        );
+        assert_eq!(serde_json::from_str::<TableState>(&json).unwrap(), state);
+    }
+}
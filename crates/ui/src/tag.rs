@@ -1,8 +1,12 @@
-use crate::{theme::ActiveTheme as _, ColorName, Sizable, Size, StyledExt};
+use std::rc::Rc;
+
+use crate::{
+    theme::ActiveTheme as _, ColorName, Icon, IconName, Sizable, Size, StyleOverrides, StyledExt,
+};
 use gpui::{
     div, prelude::FluentBuilder as _, relative, rems, transparent_white, AbsoluteLength,
-    AnyElement, App, Hsla, InteractiveElement as _, IntoElement, ParentElement, RenderOnce,
-    StyleRefinement, Styled, Window,
+    AnyElement, App, ClickEvent, ElementId, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement, RenderOnce, StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -23,7 +27,7 @@ pub enum TagVariant {
 }
 
 impl TagVariant {
-    fn bg(&self, cx: &App) -> Hsla {
+    pub(crate) fn bg(&self, cx: &App) -> Hsla {
         match self {
             Self::Primary => cx.theme().primary,
             Self::Secondary => cx.theme().secondary,
@@ -42,7 +46,7 @@ impl TagVariant {
         }
     }
 
-    fn border(&self, cx: &App) -> Hsla {
+    pub(crate) fn border(&self, cx: &App) -> Hsla {
         match self {
             Self::Primary => cx.theme().primary,
             Self::Secondary => cx.theme().border,
@@ -61,7 +65,7 @@ impl TagVariant {
         }
     }
 
-    fn fg(&self, outline: bool, cx: &App) -> Hsla {
+    pub(crate) fn fg(&self, outline: bool, cx: &App) -> Hsla {
         match self {
             Self::Primary => {
                 if outline {
@@ -117,30 +121,57 @@ impl TagVariant {
     }
 }
 
-/// Tag is a small status indicator.
+/// Tag is a small status indicator, also can be used as a chip with an icon and a close button.
 ///
 /// Only support: Medium, Small
 #[derive(IntoElement)]
 pub struct Tag {
+    id: Option<ElementId>,
     style: StyleRefinement,
     variant: TagVariant,
     outline: bool,
     size: Size,
     rounded: Option<AbsoluteLength>,
+    icon: Option<Icon>,
+    on_close: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
     children: Vec<AnyElement>,
 }
 impl Tag {
     fn new() -> Self {
         Self {
+            id: None,
             style: StyleRefinement::default(),
             variant: TagVariant::default(),
             outline: false,
             size: Size::default(),
             rounded: None,
+            icon: None,
+            on_close: None,
             children: Vec::new(),
         }
     }
 
+    /// Set the element id of the tag, this is required when there are multiple closable tags.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the icon before the label.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the tag as closable, a close button will be shown after the label.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_close = Some(Rc::new(on_close));
+        self
+    }
+
     pub fn with_variant(mut self, variant: TagVariant) -> Self {
         self.variant = variant;
         self
@@ -246,8 +277,10 @@ impl RenderOnce for Tag {
         );
 
         div()
+            .id(self.id.unwrap_or("tag".into()))
             .flex()
             .items_center()
+            .gap_1()
             .border_1()
             .line_height(relative(1.))
             .text_xs()
@@ -260,7 +293,24 @@ impl RenderOnce for Tag {
             .border_color(border)
             .rounded(rounded)
             .hover(|this| this.opacity(0.9))
+            .refine_style(&StyleOverrides::style_for::<Self>(cx))
             .refine_style(&self.style)
+            .when_some(self.icon, |this, icon| this.child(icon.xsmall()))
             .children(self.children)
+            .when_some(self.on_close, |this, on_close| {
+                this.child(
+                    div()
+                        .id("close")
+                        .flex_shrink_0()
+                        .rounded_full()
+                        .cursor_pointer()
+                        .hover(|this| this.bg(fg.opacity(0.15)))
+                        .child(Icon::new(IconName::Close).xsmall())
+                        .on_click(move |ev, window, cx| {
+                            cx.stop_propagation();
+                            on_close(ev, window, cx);
+                        }),
+                )
+            })
     }
 }
@@ -10,6 +10,7 @@ use html5ever::tendril::TendrilSink;
 use html5ever::{local_name, parse_document, LocalName, ParseOpts};
 use markup5ever_rcdom::{Node, NodeData, RcDom};
 
+use crate::text::link::autolink_ranges;
 use crate::text::node::{
     self, ImageNode, InlineNode, LinkMark, NodeContext, Paragraph, Table, TableRow, TextMark,
 };
@@ -230,6 +231,33 @@ fn trim_text(text: &str) -> String {
     out
 }
 
+/// Push the text to the paragraph, and autolink the bare URLs in it.
+fn push_text(paragraph: &mut Paragraph, text: &str) {
+    let ranges = autolink_ranges(text);
+    if ranges.is_empty() {
+        paragraph.push_str(text);
+        return;
+    }
+
+    let marks = ranges
+        .into_iter()
+        .map(|range| {
+            let link = LinkMark {
+                url: text[range.clone()].to_string().into(),
+                ..Default::default()
+            };
+            (
+                range,
+                TextMark {
+                    link: Some(link),
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    paragraph.push(InlineNode::new(text.to_string()).marks(marks));
+}
+
 fn parse_paragraph(
     paragraph: &mut Paragraph,
     node: &Rc<Node>,
@@ -255,7 +283,7 @@ fn parse_paragraph(
         NodeData::Text { ref contents } => {
             let part = &contents.borrow();
             text.push_str(&part);
-            paragraph.push_str(&text);
+            push_text(paragraph, &text);
         }
         NodeData::Element { name, attrs, .. } => match name.local {
             local_name!("em") | local_name!("i") => {
@@ -366,7 +394,7 @@ fn parse_node(
         NodeData::Text { ref contents } => {
             let text = contents.borrow().to_string();
             if text.len() > 0 {
-                paragraph.push_str(&text);
+                push_text(paragraph, &text);
             }
 
             None
@@ -476,6 +504,7 @@ fn parse_node(
                     children,
                     spread: false,
                     checked: None,
+                    task_span: None,
                 })
             }
             local_name!("table") => {
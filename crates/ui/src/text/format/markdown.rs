@@ -23,7 +23,7 @@ pub(crate) fn parse(
     highlight_theme: &HighlightTheme,
 ) -> Result<node::Node, SharedString> {
     markdown::to_mdast(&raw, &ParseOptions::gfm())
-        .map(|n| ast_to_node(n, style, cx, highlight_theme))
+        .map(|n| ast_to_node(n, raw, style, cx, highlight_theme))
         .map_err(|e| e.to_string().into())
 }
 
@@ -176,7 +176,7 @@ fn parse_paragraph(paragraph: &mut Paragraph, node: &mdast::Node, cx: &mut NodeC
             }
         },
         Node::FootnoteReference(foot) => {
-            let prefix = format!("[{}]", foot.identifier);
+            let prefix = format!("[{}]", cx.footnote_number(&foot.identifier));
             paragraph.push(InlineNode::new(&prefix).marks(vec![(
                 0..prefix.len(),
                 TextMark {
@@ -218,17 +218,40 @@ fn parse_paragraph(paragraph: &mut Paragraph, node: &mdast::Node, cx: &mut NodeC
 
 fn ast_to_node(
     value: mdast::Node,
+    raw: &str,
     style: &TextViewStyle,
     cx: &mut NodeContext,
     highlight_theme: &HighlightTheme,
 ) -> node::Node {
     match value {
         Node::Root(val) => {
-            let children = val
+            // Move the footnote definitions to the end, after all references are numbered.
+            let (definitions, others): (Vec<_>, Vec<_>) = val
                 .children
                 .into_iter()
-                .map(|c| ast_to_node(c, style, cx, highlight_theme))
+                .partition(|c| matches!(c, Node::FootnoteDefinition(_)));
+            let mut children: Vec<_> = others
+                .into_iter()
+                .map(|c| ast_to_node(c, raw, style, cx, highlight_theme))
                 .collect();
+
+            if !definitions.is_empty() {
+                let mut definitions = definitions
+                    .into_iter()
+                    .map(|c| {
+                        let number = match &c {
+                            Node::FootnoteDefinition(def) => cx.footnote_number(&def.identifier),
+                            _ => 0,
+                        };
+                        (number, ast_to_node(c, raw, style, cx, highlight_theme))
+                    })
+                    .collect::<Vec<_>>();
+                definitions.sort_by_key(|(number, _)| *number);
+
+                children.push(node::Node::Divider);
+                children.extend(definitions.into_iter().map(|(_, node)| node));
+            }
+
             node::Node::Root { children }
         }
         Node::Paragraph(val) => {
@@ -243,7 +266,7 @@ fn ast_to_node(
             let children = val
                 .children
                 .into_iter()
-                .map(|c| ast_to_node(c, style, cx, highlight_theme))
+                .map(|c| ast_to_node(c, raw, style, cx, highlight_theme))
                 .collect();
             node::Node::Blockquote { children }
         }
@@ -251,7 +274,7 @@ fn ast_to_node(
             let children = list
                 .children
                 .into_iter()
-                .map(|c| ast_to_node(c, style, cx, highlight_theme))
+                .map(|c| ast_to_node(c, raw, style, cx, highlight_theme))
                 .collect();
             node::Node::List {
                 ordered: list.ordered,
@@ -262,18 +285,23 @@ fn ast_to_node(
             let children = val
                 .children
                 .into_iter()
-                .map(|c| ast_to_node(c, style, cx, highlight_theme))
+                .map(|c| ast_to_node(c, raw, style, cx, highlight_theme))
                 .collect();
+            let task_span = val
+                .checked
+                .and(val.position.as_ref())
+                .and_then(|pos| task_marker_span(raw, pos.start.offset));
             node::Node::ListItem {
                 children,
                 spread: val.spread,
                 checked: val.checked,
+                task_span,
             }
         }
         Node::Break(_) => node::Node::Break { html: false },
         Node::Code(raw) => node::Node::CodeBlock(CodeBlock::new(
             raw.value.into(),
-            raw.lang.map(|s| s.into()),
+            raw.lang.as_deref().and_then(code_lang),
             style,
             highlight_theme,
         )),
@@ -355,7 +383,7 @@ fn ast_to_node(
         }
         Node::FootnoteDefinition(def) => {
             let mut paragraph = Paragraph::default();
-            let prefix = format!("[{}]: ", def.identifier);
+            let prefix = format!("[{}]: ", cx.footnote_number(&def.identifier));
             paragraph.push(InlineNode::new(&prefix).marks(vec![(
                 0..prefix.len(),
                 TextMark {
@@ -393,3 +421,61 @@ fn ast_to_node(
         }
     }
 }
+
+/// Returns the span of the task list marker (e.g.: `[ ]`, `[x]`) of the list item at `offset`.
+fn task_marker_span(raw: &str, offset: usize) -> Option<Span> {
+    let start = offset + raw.get(offset..)?.find('[')?;
+    let marker = raw.get(start..start + 3)?;
+    if matches!(marker, "[ ]" | "[x]" | "[X]") {
+        Some(Span {
+            start,
+            end: start + 3,
+        })
+    } else {
+        None
+    }
+}
+
+/// Returns the language of the code block from the fence info string.
+///
+/// e.g.: `rust`, `rust,ignore`, `{.python}`, `js title="index.js"`
+fn code_lang(info: &str) -> Option<SharedString> {
+    let lang = info
+        .trim()
+        .trim_start_matches('{')
+        .trim_start_matches('.')
+        .split(|c: char| c == ',' || c == '}' || c.is_whitespace())
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    if lang.is_empty() {
+        None
+    } else {
+        Some(lang.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_lang, task_marker_span};
+
+    #[test]
+    fn test_code_lang() {
+        assert_eq!(code_lang("rust"), Some("rust".into()));
+        assert_eq!(code_lang("Rust,ignore"), Some("rust".into()));
+        assert_eq!(code_lang("{.python}"), Some("python".into()));
+        assert_eq!(code_lang("js title=\"index.js\""), Some("js".into()));
+        assert_eq!(code_lang(" "), None);
+    }
+
+    #[test]
+    fn test_task_marker_span() {
+        let raw = "- [ ] Todo\n- [x] Done\n- [link](url)";
+        let span = task_marker_span(raw, 0).unwrap();
+        assert_eq!(&raw[span.start..span.end], "[ ]");
+        let span = task_marker_span(raw, 11).unwrap();
+        assert_eq!(&raw[span.start..span.end], "[x]");
+        assert!(task_marker_span(raw, 22).is_none());
+    }
+}
@@ -6,12 +6,17 @@ use std::{
 
 use gpui::{
     point, px, quad, App, BorderStyle, Bounds, CursorStyle, Edges, Element, ElementId,
-    GlobalElementId, Half, HighlightStyle, Hitbox, HitboxBehavior, InspectorElementId, IntoElement,
-    LayoutId, MouseMoveEvent, MouseUpEvent, Pixels, Point, SharedString, StyledText, TextLayout,
-    Window,
+    GlobalElementId, Half, HighlightStyle, Hitbox, HitboxBehavior, Hsla, InspectorElementId,
+    IntoElement, LayoutId, MouseMoveEvent, MouseUpEvent, Pixels, Point, SharedString, StyledText,
+    TextLayout, Window,
 };
 
-use crate::{global_state::GlobalState, input::Selection, text::node::LinkMark, ActiveTheme};
+use crate::{
+    global_state::GlobalState,
+    input::Selection,
+    text::{link::open_link, node::LinkMark},
+    ActiveTheme,
+};
 
 /// A inline element used to render a inline text and support selectable.
 ///
@@ -142,13 +147,21 @@ impl Inline {
         (true, true, selection)
     }
 
+    /// Returns the range of the sentence currently being spoken in this inline text.
+    fn speaking_range(&self, cx: &App) -> Option<Range<usize>> {
+        let text_view_state = GlobalState::global(cx).text_view_state()?;
+        let speaking = text_view_state.read(cx).speaking()?;
+        let start = self.text.find(speaking.as_ref())?;
+        Some(start..start + speaking.len())
+    }
+
     /// Paint the selection background.
     fn paint_selection(
         selection: &Selection,
+        color: Hsla,
         text_layout: &TextLayout,
         bounds: &Bounds<Pixels>,
         window: &mut Window,
-        cx: &mut App,
     ) {
         let mut start = selection.start;
         let mut end = selection.end;
@@ -170,7 +183,7 @@ impl Inline {
                     point(end_position.x, end_position.y + line_height),
                 ),
                 px(0.),
-                cx.theme().selection,
+                color,
                 Edges::default(),
                 gpui::transparent_black(),
                 BorderStyle::default(),
@@ -182,7 +195,7 @@ impl Inline {
                     point(bounds.right(), start_position.y + line_height),
                 ),
                 px(0.),
-                cx.theme().selection,
+                color,
                 Edges::default(),
                 gpui::transparent_black(),
                 BorderStyle::default(),
@@ -195,7 +208,7 @@ impl Inline {
                         point(bounds.right(), end_position.y),
                     ),
                     px(0.),
-                    cx.theme().selection,
+                    color,
                     Edges::default(),
                     gpui::transparent_black(),
                     BorderStyle::default(),
@@ -208,7 +221,7 @@ impl Inline {
                     point(end_position.x, end_position.y + line_height),
                 ),
                 px(0.),
-                cx.theme().selection,
+                color,
                 Edges::default(),
                 gpui::transparent_black(),
                 BorderStyle::default(),
@@ -313,12 +326,26 @@ impl Element for Inline {
 
         // link cursor pointer
         let mouse_position = window.mouse_position();
-        if let Some(_) = Self::link_for_position(&text_layout, &self.links, mouse_position) {
+        let text_view_state = GlobalState::global(cx).text_view_state().cloned();
+        if let Some(link) = Self::link_for_position(&text_layout, &self.links, mouse_position) {
             window.set_cursor_style(CursorStyle::PointingHand, &hitbox);
+            if hitbox.is_hovered(window) {
+                if let Some(text_view_state) = text_view_state.as_ref() {
+                    text_view_state.update(cx, |state, _| {
+                        state.set_hovered_link(&link.url, mouse_position)
+                    });
+                }
+            }
+        }
+
+        if let Some(range) = self.speaking_range(cx) {
+            let color = cx.theme().yellow.opacity(0.3);
+            Self::paint_selection(&range.into(), color, &text_layout, &bounds, window);
         }
 
         if let Some(selection) = &state.selection {
-            Self::paint_selection(selection, &text_layout, &bounds, window, cx);
+            let color = cx.theme().selection;
+            Self::paint_selection(selection, color, &text_layout, &bounds, window);
         }
 
         // mouse move, update hovered link
@@ -346,8 +373,10 @@ impl Element for Inline {
             window.on_mouse_event({
                 let links = self.links.clone();
                 let text_layout = text_layout.clone();
+                let on_link_click =
+                    text_view_state.and_then(|state| state.read(cx).on_link_click().cloned());
 
-                move |event: &MouseUpEvent, phase, _, cx| {
+                move |event: &MouseUpEvent, phase, window, cx| {
                     if !bounds.contains(&event.position) || !phase.bubble() {
                         return;
                     }
@@ -356,7 +385,7 @@ impl Element for Inline {
                         Self::link_for_position(&text_layout, &links, event.position)
                     {
                         cx.stop_propagation();
-                        cx.open_url(&link.url);
+                        open_link(on_link_click.as_ref(), &link.url, window, cx);
                     }
                 }
             });
@@ -0,0 +1,122 @@
+use std::{ops::Range, rc::Rc};
+
+use gpui::{AnyElement, App, SharedString, Window};
+
+/// The action to take after a link is clicked, returned by [`super::TextView::on_link_click`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkAction {
+    /// Open the link with the system, e.g.: the default browser.
+    OpenExternal,
+    /// The link has been handled by the host, e.g.: navigated in the app.
+    Handled,
+}
+
+pub(crate) type LinkClickHandler =
+    Rc<dyn Fn(&SharedString, &mut Window, &mut App) -> LinkAction + 'static>;
+
+/// Open the link by the handler, or open it externally if there is no handler.
+pub(crate) fn open_link(
+    handler: Option<&LinkClickHandler>,
+    url: &SharedString,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let action = handler
+        .map(|handler| handler(url, window, cx))
+        .unwrap_or(LinkAction::OpenExternal);
+
+    if action == LinkAction::OpenExternal {
+        cx.open_url(url);
+    }
+}
+
+/// A provider to render the preview of a link when hovering, see [`super::TextView::link_preview`].
+pub trait LinkPreviewProvider: 'static {
+    /// Returns the preview element of the `url`, or None to not show the preview.
+    ///
+    /// This is called on every render while hovering, so the provider should cache
+    /// the preview data (e.g.: fetched page title) by itself.
+    fn preview(&self, url: &SharedString, window: &mut Window, cx: &mut App) -> Option<AnyElement>;
+}
+
+impl<F> LinkPreviewProvider for F
+where
+    F: Fn(&SharedString, &mut Window, &mut App) -> Option<AnyElement> + 'static,
+{
+    fn preview(&self, url: &SharedString, window: &mut Window, cx: &mut App) -> Option<AnyElement> {
+        self(url, window, cx)
+    }
+}
+
+/// Returns the ranges of the bare URLs (start with `http://` or `https://`) in the text.
+pub(crate) fn autolink_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+    let mut offset = 0;
+
+    while let Some(ix) = text[offset..].find("http") {
+        let start = offset + ix;
+        let rest = &text[start..];
+        let scheme_len = if rest.starts_with("https://") {
+            8
+        } else if rest.starts_with("http://") {
+            7
+        } else {
+            offset = start + 4;
+            continue;
+        };
+
+        // The URL must not be a part of a word.
+        let in_word = text[..start]
+            .chars()
+            .next_back()
+            .map_or(false, |c| c.is_ascii_alphanumeric());
+
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\''))
+            .unwrap_or(rest.len());
+        // Trim the trailing punctuations, e.g.: "See https://example.com."
+        let len = rest[..len]
+            .trim_end_matches(|c: char| matches!(c, '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']'))
+            .len();
+
+        if !in_word && len > scheme_len {
+            ranges.push(start..start + len);
+        }
+        offset = start + len.max(scheme_len);
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::autolink_ranges;
+
+    fn links(text: &str) -> Vec<&str> {
+        autolink_ranges(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_autolink_ranges() {
+        assert_eq!(
+            links("See https://example.com/a?b=1 and http://foo.bar."),
+            vec!["https://example.com/a?b=1", "http://foo.bar"]
+        );
+        assert_eq!(
+            links("(https://example.com), https://"),
+            vec!["https://example.com"]
+        );
+        assert_eq!(links("nohttps://example.com http"), Vec::<&str>::new());
+        assert_eq!(
+            links("中文https://example.com"),
+            vec!["https://example.com"]
+        );
+        assert_eq!(
+            links("链接 https://example.com/路径"),
+            vec!["https://example.com/路径"]
+        );
+    }
+}
@@ -1,11 +1,17 @@
 mod format;
 mod inline;
+mod link;
 mod node;
+mod selection_toolbar;
+mod speech;
 mod style;
 mod text_view;
 mod utils;
 
 use gpui::App;
+pub use link::{LinkAction, LinkPreviewProvider};
+pub use selection_toolbar::*;
+pub use speech::*;
 pub use style::*;
 pub use text_view::*;
 
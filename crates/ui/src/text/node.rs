@@ -8,20 +8,25 @@ use gpui::{
     div, img, prelude::FluentBuilder as _, px, relative, rems, AnyElement, App, DefiniteLength,
     Div, ElementId, FontStyle, FontWeight, Half, HighlightStyle, InteractiveElement as _,
     IntoElement, Length, ObjectFit, ParentElement, SharedString, SharedUri,
-    StatefulInteractiveElement, Styled, StyledImage as _, Window,
+    StatefulInteractiveElement, Styled, StyledImage as _, WeakEntity, Window,
 };
 use markdown::mdast;
 use ropey::Rope;
 
 use crate::{
+    clipboard::Clipboard,
     h_flex,
     highlighter::{HighlightTheme, SyntaxHighlighter},
+    indicator::Indicator,
     text::inline::{Inline, InlineState},
     tooltip::Tooltip,
-    v_flex, ActiveTheme as _, Icon, IconName, StyledExt,
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _, StyledExt,
 };
 
-use super::{utils::list_item_prefix, TextViewStyle};
+use super::{
+    link::open_link, text_view::FenceState, utils::list_item_prefix, FenceOutput, TaskToggleEvent,
+    TextViewState, TextViewStyle,
+};
 
 #[allow(unused)]
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -175,6 +180,14 @@ impl PartialEq for Paragraph {
 }
 
 impl Paragraph {
+    /// Returns the text of the paragraph without the marks.
+    pub(super) fn plain_text(&self) -> String {
+        self.children
+            .iter()
+            .map(|c| c.text.as_ref())
+            .collect::<String>()
+    }
+
     pub(crate) fn new(text: String) -> Self {
         Self {
             span: None,
@@ -340,6 +353,19 @@ impl CodeBlock {
         }
     }
 
+    /// Returns the state of the custom fence renderer of the TextView, if registered for the lang.
+    fn fence_state(
+        &self,
+        code: &SharedString,
+        node_cx: &NodeContext,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<FenceState> {
+        let lang = self.lang.as_ref()?;
+        let text_view = node_cx.text_view.as_ref()?.upgrade()?;
+        text_view.update(cx, |state, cx| state.fence_state(lang, code, window, cx))
+    }
+
     fn code(&self) -> SharedString {
         self.state.lock().unwrap().text.clone()
     }
@@ -354,11 +380,43 @@ impl CodeBlock {
         text
     }
 
-    fn render(&self, node_cx: &NodeContext, _: &mut Window, cx: &mut App) -> AnyElement {
+    fn render(&self, node_cx: &NodeContext, window: &mut Window, cx: &mut App) -> AnyElement {
         let style = &node_cx.style;
+        let code = self.code();
+
+        if let Some(fence) = self.fence_state(&code, node_cx, window, cx) {
+            return div()
+                .id(("fence", Arc::as_ptr(&self.state) as usize))
+                .mb(style.paragraph_gap)
+                .map(|this| match fence {
+                    FenceState::Loading => this
+                        .flex()
+                        .justify_center()
+                        .p_3()
+                        .child(Indicator::new().small()),
+                    FenceState::Ready(FenceOutput::Image(source)) => {
+                        this.child(img(source).max_w_full())
+                    }
+                    FenceState::Ready(FenceOutput::Element(builder)) => {
+                        this.child(builder(window, cx))
+                    }
+                    FenceState::Failed(err) => this
+                        .p_3()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().danger.opacity(0.1))
+                        .text_sm()
+                        .text_color(cx.theme().danger)
+                        .child(err),
+                })
+                .into_any_element();
+        }
+
+        let line_numbers = style.code_block_line_numbers;
+        let lines = code.lines().count().max(1);
 
         div()
-            .id("codeblock")
+            .id(("codeblock", Arc::as_ptr(&self.state) as usize))
+            .group("codeblock")
             .mb(style.paragraph_gap)
             .p_3()
             .rounded(cx.theme().radius)
@@ -367,12 +425,46 @@ impl CodeBlock {
             .text_size(rems(0.875))
             .relative()
             .refine_style(&style.code_block)
-            .child(Inline::new(
-                "code",
-                self.state.clone(),
-                vec![],
-                self.styles.clone(),
-            ))
+            .child(
+                h_flex()
+                    .id("code")
+                    .items_start()
+                    .gap_3()
+                    // Keep the lines not wrapped to align with the line numbers.
+                    .when(line_numbers, |this| {
+                        this.whitespace_nowrap().overflow_x_scroll()
+                    })
+                    .when(line_numbers, |this| {
+                        this.child(
+                            v_flex()
+                                .flex_shrink_0()
+                                .items_end()
+                                .text_color(cx.theme().muted_foreground)
+                                .children(
+                                    (1..=lines).map(|line| {
+                                        div().child(SharedString::from(line.to_string()))
+                                    }),
+                                ),
+                        )
+                    })
+                    .child(div().flex_1().child(Inline::new(
+                        "code",
+                        self.state.clone(),
+                        vec![],
+                        self.styles.clone(),
+                    ))),
+            )
+            .when(style.code_block_copy_button, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .top_1()
+                        .right_1()
+                        .opacity(0.)
+                        .group_hover("codeblock", |this| this.opacity(1.))
+                        .child(Clipboard::new("copy").value(code)),
+                )
+            })
             .into_any_element()
     }
 }
@@ -381,13 +473,33 @@ impl CodeBlock {
 #[derive(Default, Clone, PartialEq)]
 pub(crate) struct NodeContext {
     pub(crate) link_refs: HashMap<SharedString, LinkMark>,
+    /// The footnote identifiers in the order of the first reference.
+    pub(crate) footnotes: Vec<SharedString>,
     pub(crate) style: TextViewStyle,
+    /// The TextView state to dispatch the events, this is set when rendering.
+    pub(crate) text_view: Option<WeakEntity<TextViewState>>,
 }
 
 impl NodeContext {
     pub(super) fn add_ref(&mut self, identifier: SharedString, link: LinkMark) {
         self.link_refs.insert(identifier, link);
     }
+
+    /// Returns the number (start from 1) of the footnote, add it if not exists.
+    pub(super) fn footnote_number(&mut self, identifier: &str) -> usize {
+        match self
+            .footnotes
+            .iter()
+            .position(|id| id.as_ref() == identifier)
+        {
+            Some(ix) => ix + 1,
+            None => {
+                self.footnotes
+                    .push(SharedString::from(identifier.to_string()));
+                self.footnotes.len()
+            }
+        }
+    }
 }
 
 /// The AST Node of the rich text.
@@ -414,6 +526,8 @@ pub(crate) enum Node {
         spread: bool,
         /// Whether the list item is checked, if None, it's not a checkbox
         checked: Option<bool>,
+        /// The source range of the checkbox marker, e.g.: `[ ]`
+        task_span: Option<Span>,
     },
     CodeBlock(CodeBlock),
     Table(Table),
@@ -527,6 +641,93 @@ impl Node {
 
         text
     }
+
+    /// Returns the markdown of the selected blocks.
+    ///
+    /// The blocks are copied as a whole, except a partially selected paragraph
+    /// will use the selected text.
+    pub(super) fn selected_markdown(&self) -> String {
+        match self {
+            Node::Root { children } | Node::Blockquote { children } => children
+                .iter()
+                .map(|c| c.selected_markdown())
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            Node::List { children, ordered } => children
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| !c.selected_text().is_empty())
+                .map(|(i, c)| {
+                    let prefix = if *ordered {
+                        format!("{}. ", i + 1)
+                    } else {
+                        "- ".to_string()
+                    };
+                    format!("{}{}", prefix, c.to_markdown().trim_end())
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Node::Paragraph(paragraph) => {
+                let selected = paragraph.selected_text();
+                if selected.len() < paragraph.text_len() {
+                    selected
+                } else {
+                    paragraph.to_markdown().trim_end().to_string()
+                }
+            }
+            _ => {
+                if self.selected_text().is_empty() {
+                    String::new()
+                } else {
+                    self.to_markdown().trim_end().to_string()
+                }
+            }
+        }
+    }
+
+    /// Returns the plain text of the node for reading, one line per block.
+    ///
+    /// The code blocks are omitted, because they are not suitable for reading aloud.
+    pub(crate) fn plain_text(&self) -> String {
+        let mut text = String::new();
+        match self {
+            Node::Root { children }
+            | Node::Blockquote { children }
+            | Node::List { children, .. }
+            | Node::ListItem { children, .. } => {
+                for c in children.iter() {
+                    text.push_str(&c.plain_text());
+                }
+            }
+            Node::Paragraph(paragraph)
+            | Node::Heading {
+                children: paragraph,
+                ..
+            } => {
+                text.push_str(&paragraph.plain_text());
+                text.push('\n');
+            }
+            Node::Table(table) => {
+                for row in table.children.iter() {
+                    let cells: Vec<String> = row
+                        .children
+                        .iter()
+                        .map(|cell| cell.children.plain_text())
+                        .collect();
+                    text.push_str(&cells.join(" "));
+                    text.push('\n');
+                }
+            }
+            Node::CodeBlock(_)
+            | Node::Definition { .. }
+            | Node::Break { .. }
+            | Node::Divider
+            | Node::Unknown => {}
+        }
+
+        text
+    }
 }
 
 impl Paragraph {
@@ -576,13 +777,18 @@ impl Paragraph {
                         .when_some(image.width, |this, width| this.w(width))
                         .when_some(image.link.clone(), |this, link| {
                             let title = image.title();
+                            let on_link_click = node_cx
+                                .text_view
+                                .as_ref()
+                                .and_then(|state| state.upgrade())
+                                .and_then(|state| state.read(cx).on_link_click().cloned());
                             this.cursor_pointer()
                                 .tooltip(move |window, cx| {
                                     Tooltip::new(title.clone()).build(window, cx)
                                 })
-                                .on_click(move |_, _, cx| {
+                                .on_click(move |_, window, cx| {
                                     cx.stop_propagation();
-                                    cx.open_url(&link.url);
+                                    open_link(on_link_click.as_ref(), &link.url, window, cx);
                                 })
                         })
                         .into_any_element(),
@@ -672,6 +878,7 @@ impl Node {
                 children,
                 spread,
                 checked,
+                task_span,
             } => v_flex()
                 .id("li")
                 .when(*spread, |this| this.child(div()))
@@ -723,8 +930,16 @@ impl Node {
                                         })
                                         .when_some(*checked, |this, checked| {
                                             // Todo list checkbox
+                                            let on_toggle = node_cx
+                                                .text_view
+                                                .as_ref()
+                                                .and_then(|state| state.upgrade())
+                                                .and_then(|state| state.read(cx).on_task_toggle())
+                                                .zip(*task_span);
+
                                             this.child(
                                                 div()
+                                                    .id("checkbox")
                                                     .flex()
                                                     .mt(rems(0.4))
                                                     .mr_1p5()
@@ -741,7 +956,26 @@ impl Node {
                                                                 .size_2()
                                                                 .text_xs(),
                                                         )
-                                                    }),
+                                                    })
+                                                    .when_some(
+                                                        on_toggle,
+                                                        |this, (on_toggle, span)| {
+                                                            this.cursor_pointer().on_click(
+                                                                move |_, window, cx| {
+                                                                    cx.stop_propagation();
+                                                                    on_toggle(
+                                                                        &TaskToggleEvent {
+                                                                            range: span.start
+                                                                                ..span.end,
+                                                                            checked: !checked,
+                                                                        },
+                                                                        window,
+                                                                        cx,
+                                                                    );
+                                                                },
+                                                            )
+                                                        },
+                                                    ),
                                             )
                                         })
                                         .child(div().overflow_hidden().child(text)),
@@ -804,6 +1038,7 @@ impl Node {
                 .id("table")
                 .mb(rems(1.))
                 .w_full()
+                .overflow_hidden()
                 .border_1()
                 .border_color(cx.theme().border)
                 .rounded(cx.theme().radius)
@@ -814,6 +1049,9 @@ impl Node {
                             div()
                                 .id("row")
                                 .w_full()
+                                .when(row_ix == 0, |this| {
+                                    this.bg(cx.theme().table_head).font_semibold()
+                                })
                                 .when(row_ix < table.children.len() - 1, |this| this.border_b_1())
                                 .border_color(cx.theme().border)
                                 .flex()
@@ -1032,8 +1270,7 @@ impl Paragraph {
 impl Node {
     /// Converts the node to markdown format.
     ///
-    /// This is used to generate markdown for test.
-    #[allow(dead_code)]
+    /// This is used to copy the selection as markdown, and generate markdown for test.
     pub(crate) fn to_markdown(&self) -> String {
         match self {
             Node::Root { children } => children
@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use gpui::{
+    anchored, canvas, deferred, point, prelude::FluentBuilder as _, px, App, Bounds, ClipboardItem,
+    Corner, ElementId, InteractiveElement as _, IntoElement, ParentElement as _, Pixels,
+    SharedString, Styled as _, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+type SelectionHandler = Rc<dyn Fn(&SharedString, &mut Window, &mut App)>;
+
+/// An action of the [`SelectionToolbar`].
+#[derive(Clone)]
+pub struct SelectionToolbarItem {
+    id: SharedString,
+    icon: Icon,
+    tooltip: Option<SharedString>,
+    handler: SelectionHandler,
+}
+
+impl SelectionToolbarItem {
+    /// Create a new item, the `handler` will be called with the selected text.
+    pub fn new(
+        id: impl Into<SharedString>,
+        icon: impl Into<Icon>,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            icon: icon.into(),
+            tooltip: None,
+            handler: Rc::new(handler),
+        }
+    }
+
+    /// Set the tooltip of the item.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Copy the selected text to the clipboard.
+    pub fn copy() -> Self {
+        Self::new("copy", IconName::Copy, |text, _, cx| {
+            cx.write_to_clipboard(ClipboardItem::new_string(text.trim().to_string()));
+        })
+        .tooltip(t!("SelectionToolbar.copy"))
+    }
+
+    /// Search the selected text on the web.
+    ///
+    /// The `{query}` in the `url` will be replaced by the encoded selected text,
+    /// e.g.: `https://www.google.com/search?q={query}`.
+    pub fn search_web(url: impl Into<SharedString>) -> Self {
+        let url: SharedString = url.into();
+        Self::new("search-web", IconName::Globe, move |text, _, cx| {
+            cx.open_url(&url.replace("{query}", &encode_query(text.trim())));
+        })
+        .tooltip(t!("SelectionToolbar.search_web"))
+    }
+}
+
+/// A floating toolbar shown above the selected text.
+///
+/// Use [`crate::text::TextView::selection_toolbar`] or [`crate::input::InputState::selection_toolbar`]
+/// to enable it.
+#[derive(Clone, Default)]
+pub struct SelectionToolbar {
+    items: Vec<SelectionToolbarItem>,
+}
+
+impl SelectionToolbar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item to the toolbar.
+    pub fn item(mut self, item: SelectionToolbarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Render the toolbar above the `selection` bounds (in window coordinates).
+    ///
+    /// - `selected_text` is used to read the selected text when an item is clicked.
+    /// - `on_bounds` is called with the bounds of the toolbar after prepaint.
+    pub(crate) fn render(
+        &self,
+        id: impl Into<ElementId>,
+        selection: Bounds<Pixels>,
+        selected_text: impl Fn(&App) -> Option<SharedString> + 'static,
+        on_bounds: impl Fn(Bounds<Pixels>, &mut App) + 'static,
+        cx: &App,
+    ) -> impl IntoElement {
+        let selected_text = Rc::new(selected_text);
+
+        deferred(
+            anchored()
+                .position(point(selection.left(), selection.top() - px(4.)))
+                .anchor(Corner::BottomLeft)
+                .snap_to_window_with_margin(px(8.))
+                .child(
+                    h_flex()
+                        .id(id)
+                        .occlude()
+                        .popover_style(cx)
+                        .p_0p5()
+                        .gap_0p5()
+                        .children(self.items.iter().map(|item| {
+                            let handler = item.handler.clone();
+                            let selected_text = selected_text.clone();
+
+                            Button::new(item.id.clone())
+                                .icon(item.icon.clone())
+                                .ghost()
+                                .xsmall()
+                                .when_some(item.tooltip.clone(), |this, tooltip| {
+                                    this.tooltip(tooltip)
+                                })
+                                .on_click(move |_, window, cx| {
+                                    if let Some(text) = selected_text(cx) {
+                                        handler(&text, window, cx);
+                                    }
+                                })
+                        }))
+                        .child(
+                            canvas(move |bounds, _, cx| on_bounds(bounds, cx), |_, _, _, _| {})
+                                .absolute()
+                                .size_full(),
+                        ),
+                ),
+        )
+        .with_priority(1)
+    }
+}
+
+/// Percent-encode the text to be used in the URL query.
+fn encode_query(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_query;
+
+    #[test]
+    fn test_encode_query() {
+        assert_eq!(encode_query("hello world"), "hello+world");
+        assert_eq!(encode_query("a&b=c/d"), "a%26b%3Dc%2Fd");
+        assert_eq!(encode_query("你好"), "%E4%BD%A0%E5%A5%BD");
+        assert_eq!(encode_query("v1.0_rc-1~"), "v1.0_rc-1~");
+    }
+}
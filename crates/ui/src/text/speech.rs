@@ -0,0 +1,187 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+use gpui::{App, Context, SharedString, Task};
+
+/// The text-to-speech provider used by [`SpeechState`], e.g.: the system TTS or a cloud service.
+pub trait SpeechProvider: 'static {
+    /// Speak the text segment (a sentence in most cases).
+    ///
+    /// The returned task should be resolved when the segment is finished, an error will stop the reading.
+    fn speak(&self, text: SharedString, cx: &mut App) -> Task<Result<()>>;
+
+    /// Stop the speaking immediately, this is called when paused.
+    fn stop(&self, _: &mut App) {}
+}
+
+/// The read-aloud state, splits the text into sentences and speaks them one by one
+/// with the [`SpeechProvider`].
+///
+/// Use [`crate::text::TextView::speech`] to render the play/pause controls and highlight
+/// the sentence currently being spoken, or feed any text by [`SpeechState::set_text`]
+/// (e.g.: the value of an [`crate::input::InputState`]).
+pub struct SpeechState {
+    provider: Rc<dyn SpeechProvider>,
+    text: SharedString,
+    segments: Vec<SharedString>,
+    /// The index of the current segment, `None` if not started.
+    current: Option<usize>,
+    playing: bool,
+    _task: Option<Task<()>>,
+}
+
+impl SpeechState {
+    pub fn new(provider: impl SpeechProvider) -> Self {
+        Self {
+            provider: Rc::new(provider),
+            text: SharedString::default(),
+            segments: vec![],
+            current: None,
+            playing: false,
+            _task: None,
+        }
+    }
+
+    /// Set the text to read, this will stop the current reading if the text is changed.
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut Context<Self>) {
+        let text = text.into();
+        if self.text == text {
+            return;
+        }
+
+        self.stop(cx);
+        self.segments = split_sentences(&text);
+        self.text = text;
+        cx.notify();
+    }
+
+    /// Returns true if is speaking.
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns the segment currently being spoken (or paused at).
+    pub fn current_segment(&self) -> Option<&SharedString> {
+        self.segments.get(self.current?)
+    }
+
+    /// Start or resume the reading, the paused segment will be read from the beginning.
+    pub fn play(&mut self, cx: &mut Context<Self>) {
+        if self.playing || self.segments.is_empty() {
+            return;
+        }
+
+        self.playing = true;
+        let ix = self.current.unwrap_or(0);
+        self.speak(ix, cx);
+    }
+
+    /// Pause the reading.
+    pub fn pause(&mut self, cx: &mut Context<Self>) {
+        if !self.playing {
+            return;
+        }
+
+        self.playing = false;
+        self._task = None;
+        self.provider.stop(cx);
+        cx.notify();
+    }
+
+    /// Toggle between play and pause.
+    pub fn toggle(&mut self, cx: &mut Context<Self>) {
+        if self.playing {
+            self.pause(cx);
+        } else {
+            self.play(cx);
+        }
+    }
+
+    /// Stop the reading and reset to the first segment.
+    pub fn stop(&mut self, cx: &mut Context<Self>) {
+        self.pause(cx);
+        self.current = None;
+        cx.notify();
+    }
+
+    fn speak(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(segment) = self.segments.get(ix).cloned() else {
+            // All segments are finished.
+            self.playing = false;
+            self.current = None;
+            self._task = None;
+            cx.notify();
+            return;
+        };
+
+        self.current = Some(ix);
+        let task = self.provider.speak(segment, cx);
+        self._task = Some(cx.spawn(async move |this, cx| {
+            let result = task.await;
+            _ = this.update(cx, |this, cx| {
+                if !this.playing {
+                    return;
+                }
+
+                if result.is_err() {
+                    this.playing = false;
+                    cx.notify();
+                    return;
+                }
+
+                this.speak(ix + 1, cx);
+            });
+        }));
+        cx.notify();
+    }
+}
+
+/// Split the text into sentences by the line breaks and the sentence terminators.
+fn split_sentences(text: &str) -> Vec<SharedString> {
+    let mut sentences = vec![];
+    for line in text.lines() {
+        let mut start = 0;
+        let mut chars = line.char_indices().peekable();
+        while let Some((ix, c)) = chars.next() {
+            let end = ix + c.len_utf8();
+            let is_terminator = match c {
+                '。' | '！' | '？' => true,
+                '.' | '!' | '?' => chars.peek().map_or(true, |(_, next)| next.is_whitespace()),
+                _ => false,
+            };
+
+            if is_terminator {
+                let sentence = line[start..end].trim();
+                if !sentence.is_empty() {
+                    sentences.push(SharedString::from(sentence.to_string()));
+                }
+                start = end;
+            }
+        }
+
+        let sentence = line[start..].trim();
+        if !sentence.is_empty() {
+            sentences.push(SharedString::from(sentence.to_string()));
+        }
+    }
+
+    sentences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_sentences;
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("Hello world. This is v1.0 release!\nIs it ok?  Yes"),
+            vec!["Hello world.", "This is v1.0 release!", "Is it ok?", "Yes"]
+        );
+        assert_eq!(
+            split_sentences("你好。这是 GPUI！\n\n 好的吗？"),
+            vec!["你好。", "这是 GPUI！", "好的吗？"]
+        );
+        assert!(split_sentences("").is_empty());
+    }
+}
@@ -20,6 +20,10 @@ pub struct TextViewStyle {
     pub highlight_theme: Arc<HighlightTheme>,
     /// The style refinement for code blocks.
     pub code_block: StyleRefinement,
+    /// Show line numbers in code blocks, default is false.
+    pub code_block_line_numbers: bool,
+    /// Show a copy button in code blocks, default is true.
+    pub code_block_copy_button: bool,
     pub is_dark: bool,
 }
 
@@ -28,6 +32,8 @@ impl PartialEq for TextViewStyle {
         self.paragraph_gap == other.paragraph_gap
             && self.heading_base_font_size == other.heading_base_font_size
             && self.highlight_theme == other.highlight_theme
+            && self.code_block_line_numbers == other.code_block_line_numbers
+            && self.code_block_copy_button == other.code_block_copy_button
     }
 }
 
@@ -39,6 +45,8 @@ impl Default for TextViewStyle {
             heading_font_size: None,
             highlight_theme: HighlightTheme::default_light().clone(),
             code_block: StyleRefinement::default(),
+            code_block_line_numbers: false,
+            code_block_copy_button: true,
             is_dark: false,
         }
     }
@@ -64,4 +72,16 @@ impl TextViewStyle {
         self.code_block = style;
         self
     }
+
+    /// Set to show line numbers in code blocks, default is false.
+    pub fn code_block_line_numbers(mut self, line_numbers: bool) -> Self {
+        self.code_block_line_numbers = line_numbers;
+        self
+    }
+
+    /// Set to show a copy button in code blocks, default is true.
+    pub fn code_block_copy_button(mut self, copy_button: bool) -> Self {
+        self.code_block_copy_button = copy_button;
+        self
+    }
 }
@@ -1,37 +1,62 @@
+use std::collections::HashMap;
 use std::future::Future;
+use std::ops::Range;
 use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::task::Poll;
 use std::time::Duration;
 
 use gpui::prelude::FluentBuilder;
 use gpui::{
-    div, AnyElement, App, AppContext, Bounds, ClipboardItem, Context, Element, ElementId, Entity,
-    EntityId, FocusHandle, GlobalElementId, InspectorElementId, InteractiveElement, IntoElement,
-    KeyBinding, LayoutId, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Pixels,
-    Point, RenderOnce, SharedString, Size, Styled, Timer, Window,
+    actions, anchored, deferred, div, point, px, AnyElement, App, AppContext, Bounds, Context,
+    Element, ElementId, Entity, EntityId, FocusHandle, GlobalElementId, Half as _, ImageSource,
+    InspectorElementId, InteractiveElement, IntoElement, KeyBinding, LayoutId, MouseDownEvent,
+    MouseMoveEvent, MouseUpEvent, ParentElement, Pixels, Point, RenderOnce, SharedString, Size,
+    Styled, Subscription, Task, Timer, Window,
 };
+use rust_i18n::t;
 use smol::stream::StreamExt;
 
+use crate::clipboard::ClipboardContent;
 use crate::highlighter::HighlightTheme;
 use crate::{
+    button::{Button, ButtonVariants as _},
+    debug_overlay::DebugOverlay,
     global_state::GlobalState,
     input::{self},
     text::{
+        link::LinkClickHandler,
         node::{self, NodeContext},
-        TextViewStyle,
+        LinkAction, LinkPreviewProvider, SelectionToolbar, SpeechState, TextViewStyle,
     },
 };
-use crate::{v_flex, ActiveTheme};
+use crate::{h_flex, v_flex, ActiveTheme, IconName, Sizable as _, StyledExt as _};
 
 const CONTEXT: &'static str = "TextView";
 
+actions!(text_view, [CopyAsMarkdown]);
+
 pub(crate) fn init(cx: &mut App) {
     cx.bind_keys(vec![
         #[cfg(target_os = "macos")]
         KeyBinding::new("cmd-c", input::Copy, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-c", input::Copy, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-c", CopyAsMarkdown, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-c", CopyAsMarkdown, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", input::SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", input::SelectAll, Some(CONTEXT)),
+        KeyBinding::new("shift-left", input::SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("shift-right", input::SelectRight, Some(CONTEXT)),
+        KeyBinding::new("shift-up", input::SelectUp, Some(CONTEXT)),
+        KeyBinding::new("shift-down", input::SelectDown, Some(CONTEXT)),
+        KeyBinding::new("shift-home", input::SelectToStartOfLine, Some(CONTEXT)),
+        KeyBinding::new("shift-end", input::SelectToEndOfLine, Some(CONTEXT)),
     ]);
 }
 
@@ -42,20 +67,24 @@ struct TextViewElement {
 
 impl RenderOnce for TextViewElement {
     fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let text_view = self.state.downgrade();
         self.state.update(cx, |state, cx| {
             div().map(|this| match &mut state.parsed_result {
-                Some(Ok(content)) => this.child(content.root_node.render(
-                    None,
-                    true,
-                    true,
-                    &content.node_cx,
-                    window,
-                    cx,
-                )),
+                Some(Ok(content)) => {
+                    content.node_cx.text_view = Some(text_view);
+                    this.child(content.root_node.render(
+                        None,
+                        true,
+                        true,
+                        &content.node_cx,
+                        window,
+                        cx,
+                    ))
+                }
                 Some(Err(err)) => this.child(
                     v_flex()
                         .gap_1()
-                        .child("Failed to parse content")
+                        .child(SharedString::from(t!("TextView.parse_error")))
                         .child(err.to_string()),
                 ),
                 None => this,
@@ -86,12 +115,69 @@ pub struct TextView {
     init_state: Option<InitState>,
     state: Entity<TextViewState>,
     selectable: bool,
+    speech: Option<Entity<SpeechState>>,
+    selection_toolbar: Option<SelectionToolbar>,
+    on_task_toggle: Option<Rc<dyn Fn(&TaskToggleEvent, &mut Window, &mut App)>>,
+    fence_renderers: HashMap<SharedString, FenceRenderer>,
+    on_link_click: Option<LinkClickHandler>,
+    link_preview: Option<Rc<dyn LinkPreviewProvider>>,
+}
+
+type FenceRenderer =
+    Rc<dyn Fn(&SharedString, &mut Window, &mut App) -> Task<anyhow::Result<FenceOutput>>>;
+
+/// The output of a custom fence renderer, see [`TextView::fence_renderer`].
+#[derive(Clone)]
+pub enum FenceOutput {
+    /// An image, e.g.: a SVG or PNG rendered by an external tool.
+    Image(ImageSource),
+    /// An element builder, this will be called on every render.
+    Element(Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>),
+}
+
+impl FenceOutput {
+    /// Create a [`FenceOutput::Element`] with the element builder.
+    pub fn element<E: IntoElement>(builder: impl Fn(&mut Window, &mut App) -> E + 'static) -> Self {
+        Self::Element(Rc::new(move |window, cx| {
+            builder(window, cx).into_any_element()
+        }))
+    }
+}
+
+/// The render state of a custom fence code block.
+#[derive(Clone)]
+pub(crate) enum FenceState {
+    Loading,
+    Ready(FenceOutput),
+    Failed(SharedString),
+}
+
+/// The event when a task list checkbox is clicked, see [`TextView::on_task_toggle`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskToggleEvent {
+    /// The range of the checkbox marker (e.g.: `[ ]`) in the source text.
+    pub range: Range<usize>,
+    /// The new checked state.
+    pub checked: bool,
+}
+
+impl TaskToggleEvent {
+    /// Returns the marker text of the new checked state, to replace the `range` in the source text.
+    pub fn marker(&self) -> &'static str {
+        if self.checked {
+            "[x]"
+        } else {
+            "[ ]"
+        }
+    }
 }
 
 #[derive(PartialEq)]
 pub(crate) struct ParsedContent {
     pub(crate) root_node: node::Node,
     pub(crate) node_cx: node::NodeContext,
+    /// The plain text for reading aloud.
+    pub(crate) plain_text: SharedString,
 }
 
 /// The type of the text view.
@@ -213,6 +299,21 @@ pub(crate) struct TextViewState {
     /// Is current in selection.
     is_selecting: bool,
     is_selectable: bool,
+    /// The bounds of the selection toolbar, the mouse events in it will not change the selection.
+    toolbar_bounds: Option<Bounds<Pixels>>,
+    /// The sentence currently being spoken, see [`TextView::speech`].
+    speaking: Option<SharedString>,
+    _speech_subscription: Option<(EntityId, Subscription)>,
+    on_task_toggle: Option<Rc<dyn Fn(&TaskToggleEvent, &mut Window, &mut App)>>,
+    fence_renderers: HashMap<SharedString, FenceRenderer>,
+    /// The results of the custom fence renderers by `(lang, code)`,
+    /// and whether it has been used since the last parse.
+    fence_cache: HashMap<(SharedString, SharedString), (FenceState, bool)>,
+    on_link_click: Option<LinkClickHandler>,
+    /// The hovered link and the mouse position when start hovering, to show the preview.
+    hovered_link: Option<(SharedString, Point<Pixels>)>,
+    /// The hovered link of the current frame, updated by the inline elements in paint.
+    next_hovered_link: Option<(SharedString, Point<Pixels>)>,
 }
 
 impl TextViewState {
@@ -227,6 +328,15 @@ impl TextViewState {
             selection_positions: (None, None),
             is_selecting: false,
             is_selectable: false,
+            toolbar_bounds: None,
+            speaking: None,
+            _speech_subscription: None,
+            on_task_toggle: None,
+            fence_renderers: HashMap::new(),
+            fence_cache: HashMap::new(),
+            on_link_click: None,
+            hovered_link: None,
+            next_hovered_link: None,
         }
     }
 }
@@ -243,12 +353,46 @@ impl TextViewState {
     fn clear_selection(&mut self) {
         self.selection_positions = (None, None);
         self.is_selecting = false;
+        self.toolbar_bounds = None;
     }
 
     fn start_selection(&mut self, pos: Point<Pixels>) {
         let pos = pos - self.bounds.origin;
         self.selection_positions = (Some(pos), Some(pos));
         self.is_selecting = true;
+        self.toolbar_bounds = None;
+    }
+
+    fn is_in_toolbar(&self, pos: &Point<Pixels>) -> bool {
+        self.toolbar_bounds
+            .map_or(false, |bounds| bounds.contains(pos))
+    }
+
+    /// Select all the text in the TextView.
+    fn select_all(&mut self) {
+        self.selection_positions = (
+            Some(point(px(0.), px(0.))),
+            Some(point(self.bounds.size.width, self.bounds.size.height)),
+        );
+        self.is_selecting = false;
+        self.toolbar_bounds = None;
+    }
+
+    /// Move the end of the selection by the `delta`, or set the `x` if provided.
+    ///
+    /// If there is no selection, nothing happens.
+    fn move_selection_end(&mut self, delta: Point<Pixels>, x: Option<Pixels>) {
+        let (Some(start), Some(end)) = self.selection_positions else {
+            return;
+        };
+
+        let size = self.bounds.size;
+        let end = point(
+            x.unwrap_or(end.x + delta.x).clamp(px(0.), size.width),
+            (end.y + delta.y).clamp(px(0.), size.height),
+        );
+        self.selection_positions = (Some(start), Some(end));
+        self.toolbar_bounds = None;
     }
 
     fn update_selection(&mut self, pos: Point<Pixels>) {
@@ -274,6 +418,90 @@ impl TextViewState {
         self.is_selectable
     }
 
+    pub(crate) fn speaking(&self) -> Option<&SharedString> {
+        self.speaking.as_ref()
+    }
+
+    pub(crate) fn on_task_toggle(
+        &self,
+    ) -> Option<Rc<dyn Fn(&TaskToggleEvent, &mut Window, &mut App)>> {
+        self.on_task_toggle.clone()
+    }
+
+    pub(crate) fn on_link_click(&self) -> Option<&LinkClickHandler> {
+        self.on_link_click.as_ref()
+    }
+
+    /// Set the link under the mouse, this is called by the inline elements in paint.
+    pub(crate) fn set_hovered_link(&mut self, url: &SharedString, position: Point<Pixels>) {
+        // Keep the position when hovering the same link, to avoid the preview following the mouse.
+        let position = match &self.hovered_link {
+            Some((hovered_url, hovered_position)) if hovered_url == url => *hovered_position,
+            _ => position,
+        };
+        self.next_hovered_link = Some((url.clone(), position));
+    }
+
+    /// Returns the state of the code block rendered by the custom fence renderer,
+    /// or None if there is no renderer for the `lang`.
+    ///
+    /// The renderer is called once for each `(lang, code)`, then the result is cached.
+    pub(crate) fn fence_state(
+        &mut self,
+        lang: &SharedString,
+        code: &SharedString,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<FenceState> {
+        let renderer = self.fence_renderers.get(lang)?.clone();
+        let key = (lang.clone(), code.clone());
+        if let Some((state, used)) = self.fence_cache.get_mut(&key) {
+            *used = true;
+            return Some(state.clone());
+        }
+
+        let task = renderer(code, window, cx);
+        self.fence_cache
+            .insert(key.clone(), (FenceState::Loading, true));
+        cx.spawn(async move |this, cx| {
+            let result = task.await;
+            _ = this.update(cx, |state, cx| {
+                let fence = match result {
+                    Ok(output) => FenceState::Ready(output),
+                    Err(err) => FenceState::Failed(err.to_string().into()),
+                };
+                state.fence_cache.insert(key, (fence, true));
+                if let Some(parent_entity) = state.parent_entity {
+                    let app = &mut **cx;
+                    app.notify(parent_entity);
+                }
+            });
+        })
+        .detach();
+
+        Some(FenceState::Loading)
+    }
+
+    /// Sync the text to the speech state and observe it to re-render the parent view.
+    fn sync_speech(&mut self, speech: &Entity<SpeechState>, cx: &mut Context<Self>) {
+        if let Some(Ok(content)) = &self.parsed_result {
+            let plain_text = content.plain_text.clone();
+            speech.update(cx, |speech, cx| speech.set_text(plain_text, cx));
+        }
+
+        if self._speech_subscription.as_ref().map(|(id, _)| *id) != Some(speech.entity_id()) {
+            let subscription = cx.observe(speech, |state, _, cx| {
+                if let Some(parent_entity) = state.parent_entity {
+                    let app = &mut **cx;
+                    app.notify(parent_entity);
+                }
+            });
+            self._speech_subscription = Some((speech.entity_id(), subscription));
+        }
+
+        self.speaking = speech.read(cx).current_segment().cloned();
+    }
+
     /// Return the bounds of the selection in window coordinates.
     pub(crate) fn selection_bounds(&self) -> Bounds<Pixels> {
         selection_bounds(
@@ -293,6 +521,17 @@ impl TextViewState {
                 .selected_text(),
         )
     }
+
+    fn selection_markdown(&self) -> Option<String> {
+        Some(
+            self.parsed_result
+                .as_ref()?
+                .as_ref()
+                .ok()?
+                .root_node
+                .selected_markdown(),
+        )
+    }
 }
 
 #[derive(IntoElement, Clone)]
@@ -326,6 +565,14 @@ impl From<TextView> for Text {
 }
 
 impl Text {
+    /// Returns the plain string if this is `String`.
+    pub fn as_str(&self) -> Option<&SharedString> {
+        match self {
+            Self::String(s) => Some(s),
+            Self::TextView(_) => None,
+        }
+    }
+
     /// Set the style for [`TextView`].
     ///
     /// Do nothing if this is `String`.
@@ -396,6 +643,12 @@ impl TextView {
             init_state: Some(init_state),
             state,
             selectable: false,
+            speech: None,
+            selection_toolbar: None,
+            on_task_toggle: None,
+            fence_renderers: HashMap::new(),
+            on_link_click: None,
+            link_preview: None,
         }
     }
 
@@ -423,6 +676,12 @@ impl TextView {
             init_state: Some(init_state),
             state,
             selectable: false,
+            speech: None,
+            selection_toolbar: None,
+            on_task_toggle: None,
+            fence_renderers: HashMap::new(),
+            on_link_click: None,
+            link_preview: None,
         }
     }
 
@@ -444,7 +703,7 @@ impl TextView {
     pub fn style(mut self, style: TextViewStyle) -> Self {
         if let Some(init_state) = &mut self.init_state {
             match init_state {
-                InitState::Initializing { style: s, .. } => *s = Box::new(style),
+                InitState::Initializing { style: s, .. } => **s = style,
                 InitState::Initialized { tx } => {
                     let _ = tx.try_send(Update::Style(Box::new(style)));
                 }
@@ -459,12 +718,166 @@ impl TextView {
         self
     }
 
+    /// Set the [`SpeechState`] to read the text aloud.
+    ///
+    /// This will show the play/pause controls, and highlight the sentence currently being spoken.
+    pub fn speech(mut self, speech: &Entity<SpeechState>) -> Self {
+        self.speech = Some(speech.clone());
+        self
+    }
+
+    /// Set the [`SelectionToolbar`] to show above the selected text, only works when [`Self::selectable`].
+    pub fn selection_toolbar(mut self, toolbar: SelectionToolbar) -> Self {
+        self.selection_toolbar = Some(toolbar);
+        self
+    }
+
+    /// Set the handler when a task list checkbox is clicked.
+    ///
+    /// The checkbox state is read from the source text, so the handler should
+    /// update the text, e.g.: replace the [`TaskToggleEvent::range`] with [`TaskToggleEvent::marker`].
+    pub fn on_task_toggle(
+        mut self,
+        handler: impl Fn(&TaskToggleEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_task_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Register a custom renderer for the fenced code blocks of the `lang`, e.g.: `mermaid`.
+    ///
+    /// The renderer receives the code and returns a task to render it, a spinner
+    /// is shown until the task is ready. The result is cached by the code.
+    ///
+    /// ```ignore
+    /// TextView::markdown("doc", text, window, cx).fence_renderer("mermaid", |code, _, cx| {
+    ///     let code = code.clone();
+    ///     cx.spawn(async move |_| {
+    ///         let svg = render_mermaid(&code).await?;
+    ///         Ok(FenceOutput::Image(ImageSource::from(Arc::new(Image::from_bytes(ImageFormat::Svg, svg)))))
+    ///     })
+    /// })
+    /// ```
+    pub fn fence_renderer(
+        mut self,
+        lang: impl Into<SharedString>,
+        renderer: impl Fn(&SharedString, &mut Window, &mut App) -> Task<anyhow::Result<FenceOutput>>
+            + 'static,
+    ) -> Self {
+        let lang: SharedString = lang.into();
+        self.fence_renderers
+            .insert(lang.to_lowercase().into(), Rc::new(renderer));
+        self
+    }
+
+    /// Copy the selected text, the markdown of the selection is kept as the metadata.
+    /// Set the handler when a link is clicked, the returned [`LinkAction`] decides
+    /// whether to open the link externally.
+    ///
+    /// Default is to open all links externally.
+    ///
+    /// ```ignore
+    /// TextView::markdown("doc", text, window, cx).on_link_click(|url, _, cx| {
+    ///     if url.starts_with("app://") {
+    ///         navigate(url, cx);
+    ///         LinkAction::Handled
+    ///     } else {
+    ///         LinkAction::OpenExternal
+    ///     }
+    /// })
+    /// ```
+    pub fn on_link_click(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) -> LinkAction + 'static,
+    ) -> Self {
+        self.on_link_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the [`LinkPreviewProvider`] to show a preview when hovering the links.
+    pub fn link_preview(mut self, provider: impl LinkPreviewProvider) -> Self {
+        self.link_preview = Some(Rc::new(provider));
+        self
+    }
+
     fn on_action_copy(state: &Entity<TextViewState>, cx: &mut App) {
         let Some(selected_text) = state.read(cx).selection_text() else {
             return;
         };
+        let markdown = state.read(cx).selection_markdown().unwrap_or_default();
+
+        ClipboardContent::new(selected_text.trim())
+            .markdown(markdown)
+            .write(cx);
+    }
+
+    fn on_action_copy_as_markdown(state: &Entity<TextViewState>, cx: &mut App) {
+        let Some(markdown) = state.read(cx).selection_markdown() else {
+            return;
+        };
+
+        ClipboardContent::new(markdown.clone())
+            .markdown(markdown)
+            .write(cx);
+    }
+
+    /// Select all, or move the end of the selection by keyboard.
+    fn on_action_select(
+        state: &Entity<TextViewState>,
+        delta: Point<Pixels>,
+        x: Option<Pixels>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        state.update(cx, |state, _| state.move_selection_end(delta, x));
+        window.refresh();
+    }
+}
+
+impl TextView {
+    fn render_link_preview(&self, window: &mut Window, cx: &mut App) -> Option<impl IntoElement> {
+        let provider = self.link_preview.as_ref()?;
+        let (url, position) = self.state.read(cx).hovered_link.clone()?;
+        let preview = provider.preview(&url, window, cx)?;
+
+        Some(
+            deferred(
+                anchored()
+                    .position(point(position.x, position.y + window.line_height()))
+                    .snap_to_window_with_margin(px(8.))
+                    .child(
+                        div()
+                            .occlude()
+                            .popover_style(cx)
+                            .p_2()
+                            .max_w(px(360.))
+                            .child(preview),
+                    ),
+            )
+            .with_priority(1),
+        )
+    }
+
+    fn render_selection_toolbar(&self, cx: &App) -> Option<impl IntoElement> {
+        let toolbar = self.selection_toolbar.as_ref()?;
+        let state = self.state.read(cx);
+        if !self.selectable || toolbar.is_empty() || state.is_selecting || !state.has_selection() {
+            return None;
+        }
 
-        cx.write_to_clipboard(ClipboardItem::new_string(selected_text.trim().to_string()));
+        Some(toolbar.render(
+            "selection-toolbar",
+            state.selection_bounds(),
+            {
+                let state = self.state.clone();
+                move |cx| state.read(cx).selection_text().map(Into::into)
+            },
+            {
+                let state = self.state.clone();
+                move |bounds, cx| state.update(cx, |state, _| state.toolbar_bounds = Some(bounds))
+            },
+            cx,
+        ))
     }
 }
 
@@ -495,6 +908,7 @@ impl Element for TextView {
         window: &mut Window,
         cx: &mut App,
     ) -> (LayoutId, Self::RequestLayoutState) {
+        let _span = tracing::trace_span!("request_layout", component = "TextView").entered();
         if let Some(InitState::Initializing {
             type_,
             text,
@@ -524,6 +938,10 @@ impl Element for TextView {
                         if let Some(state) = state.upgrade() {
                             _ = state.update(cx, |state, cx| {
                                 state.parsed_result = Some(parsed_result);
+                                // Drop the fence results that not used since the last parse.
+                                state
+                                    .fence_cache
+                                    .retain(|_, (_, used)| std::mem::take(used));
                                 if let Some(parent_entity) = state.parent_entity {
                                     let app = &mut **cx;
                                     app.notify(parent_entity);
@@ -560,18 +978,117 @@ impl Element for TextView {
             .as_ref()
             .expect("focus_handle should init by TextViewState::new");
 
+        let focus_handle = focus_handle.clone();
+        if let Some(speech) = &self.speech {
+            self.state
+                .update(cx, |state, cx| state.sync_speech(speech, cx));
+        }
+        let on_task_toggle = self.on_task_toggle.clone();
+        let fence_renderers = self.fence_renderers.clone();
+        let on_link_click = self.on_link_click.clone();
+        self.state.update(cx, |state, _| {
+            state.on_task_toggle = on_task_toggle;
+            state.fence_renderers = fence_renderers;
+            state.on_link_click = on_link_click;
+        });
+
         let mut el = div()
             .key_context(CONTEXT)
-            .track_focus(focus_handle)
+            .track_focus(&focus_handle)
             .on_action({
                 let state = self.state.clone();
                 move |_: &input::Copy, _, cx| {
                     Self::on_action_copy(&state, cx);
                 }
             })
+            .when(self.selectable, |this| {
+                let line_height = window.line_height();
+                let char_width = line_height.half();
+                let width = self.state.read(cx).bounds.size.width;
+
+                this.on_action({
+                    let state = self.state.clone();
+                    move |_: &CopyAsMarkdown, _, cx| {
+                        Self::on_action_copy_as_markdown(&state, cx);
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectAll, window, cx| {
+                        state.update(cx, |state, _| state.select_all());
+                        window.refresh();
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectLeft, window, cx| {
+                        Self::on_action_select(&state, point(-char_width, px(0.)), None, window, cx)
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectRight, window, cx| {
+                        Self::on_action_select(&state, point(char_width, px(0.)), None, window, cx)
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectUp, window, cx| {
+                        Self::on_action_select(
+                            &state,
+                            point(px(0.), -line_height),
+                            None,
+                            window,
+                            cx,
+                        )
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectDown, window, cx| {
+                        Self::on_action_select(&state, point(px(0.), line_height), None, window, cx)
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectToStartOfLine, window, cx| {
+                        Self::on_action_select(&state, Point::default(), Some(px(0.)), window, cx)
+                    }
+                })
+                .on_action({
+                    let state = self.state.clone();
+                    move |_: &input::SelectToEndOfLine, window, cx| {
+                        Self::on_action_select(&state, Point::default(), Some(width), window, cx)
+                    }
+                })
+            })
+            .when_some(self.speech.clone(), |this, speech| {
+                let is_playing = speech.read(cx).is_playing();
+                this.child(
+                    h_flex().justify_end().child(
+                        Button::new("speech")
+                            .icon(if is_playing {
+                                IconName::Pause
+                            } else {
+                                IconName::Play
+                            })
+                            .ghost()
+                            .xsmall()
+                            .on_click(move |_, _, cx| {
+                                speech.update(cx, |speech, cx| speech.toggle(cx));
+                            }),
+                    ),
+                )
+            })
             .child(TextViewElement {
                 state: self.state.clone(),
             })
+            .when_some(self.render_selection_toolbar(cx), |this, toolbar| {
+                this.child(toolbar)
+            })
+            .when_some(self.render_link_preview(window, cx), |this, preview| {
+                this.child(preview)
+            })
             .into_any_element();
         let layout_id = el.request_layout(window, cx);
         (layout_id, el)
@@ -586,6 +1103,7 @@ impl Element for TextView {
         window: &mut Window,
         cx: &mut App,
     ) -> Self::PrepaintState {
+        let _span = tracing::trace_span!("prepaint", component = "TextView").entered();
         request_layout.prepaint(window, cx);
     }
 
@@ -599,6 +1117,9 @@ impl Element for TextView {
         window: &mut Window,
         cx: &mut App,
     ) {
+        let _span = tracing::trace_span!("paint", component = "TextView").entered();
+        let focus_handle = self.state.read(cx).focus_handle.clone();
+        DebugOverlay::record("TextView", bounds, focus_handle.as_ref(), window, cx);
         let entity_id = window.current_view();
         let is_selectable = self.selectable;
 
@@ -608,23 +1129,45 @@ impl Element for TextView {
             state.is_selectable = is_selectable;
         });
 
+        self.state
+            .update(cx, |state, _| state.next_hovered_link = None);
         GlobalState::global_mut(cx)
             .text_view_state_stack
             .push(self.state.clone());
         request_layout.paint(window, cx);
         GlobalState::global_mut(cx).text_view_state_stack.pop();
 
+        // Update the hovered link, and re-render to show the preview if changed.
+        let hovered_changed = self.state.update(cx, |state, _| {
+            let next = state.next_hovered_link.take();
+            let changed = next.as_ref().map(|(url, _)| url)
+                != state.hovered_link.as_ref().map(|(url, _)| url);
+            state.hovered_link = next;
+            changed
+        });
+        if hovered_changed && self.link_preview.is_some() {
+            cx.notify(entity_id);
+        }
+
         if self.selectable {
             let is_selecting = self.state.read(cx).is_selecting;
             let has_selection = self.state.read(cx).has_selection();
 
             window.on_mouse_event({
                 let state = self.state.clone();
-                move |event: &MouseDownEvent, phase, _, cx| {
+                let focus_handle = focus_handle.clone();
+                move |event: &MouseDownEvent, phase, window, cx| {
                     if !bounds.contains(&event.position) || !phase.bubble() {
                         return;
                     }
+                    if state.read(cx).is_in_toolbar(&event.position) {
+                        return;
+                    }
 
+                    // Focus to handle the copy and keyboard selection actions.
+                    if let Some(focus_handle) = &focus_handle {
+                        window.focus(focus_handle);
+                    }
                     state.update(cx, |state, _| {
                         state.start_selection(event.position);
                     });
@@ -661,7 +1204,9 @@ impl Element for TextView {
                 window.on_mouse_event({
                     let state = self.state.clone();
                     move |event: &MouseDownEvent, _, _, cx| {
-                        if bounds.contains(&event.position) {
+                        if bounds.contains(&event.position)
+                            || state.read(cx).is_in_toolbar(&event.position)
+                        {
                             return;
                         }
 
@@ -693,7 +1238,14 @@ fn parse_content(
         }
         TextViewType::Html => super::format::html::parse(text, &mut node_cx),
     };
-    res.map(move |root_node| ParsedContent { root_node, node_cx })
+    res.map(move |root_node| {
+        let plain_text = root_node.plain_text().trim_end().to_string().into();
+        ParsedContent {
+            root_node,
+            node_cx,
+            plain_text,
+        }
+    })
 }
 
 fn selection_bounds(
@@ -0,0 +1,396 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+use gpui::{
+    size, App, Bounds, Element, ElementId, Font, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, ShapedLine, SharedString, Style, StyledText, TextAlign, TextRun,
+    WhiteSpace, Window,
+};
+use smallvec::SmallVec;
+
+pub(crate) fn init(cx: &mut App) {
+    cx.set_global(TextCache::default());
+}
+
+/// The default max number of the shaped texts in the [`TextCache`].
+const DEFAULT_CAPACITY: usize = 4096;
+/// The max number of the decorated lines of a text, the oldest one is replaced when it is full.
+const MAX_RUNS_PER_TEXT: usize = 4;
+
+/// A least recently used map, the least recently used entry is evicted when it is full.
+struct LruMap<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    /// The keys ordered by the last used tick.
+    order: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruMap<K, V> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let (value, tick) = self.entries.get_mut(key)?;
+        self.tick += 1;
+        if let Some(key) = self.order.remove(tick) {
+            self.order.insert(self.tick, key);
+        }
+        *tick = self.tick;
+        Some(value)
+    }
+
+    /// Insert the value and returns the number of the evicted entries.
+    fn insert(&mut self, key: K, value: V) -> usize {
+        self.tick += 1;
+        if let Some((_, tick)) = self.entries.insert(key.clone(), (value, self.tick)) {
+            self.order.remove(&tick);
+        }
+        self.order.insert(self.tick, key);
+        self.shrink()
+    }
+
+    fn set_capacity(&mut self, capacity: usize) -> usize {
+        self.capacity = capacity;
+        self.shrink()
+    }
+
+    fn shrink(&mut self) -> usize {
+        let mut evicted = 0;
+        while self.entries.len() > self.capacity {
+            let Some((_, key)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+            evicted += 1;
+        }
+        evicted
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    text: SharedString,
+    font: Font,
+    /// The bits of the font size in pixels.
+    font_size: u32,
+}
+
+/// The shaped lines of a text, each one is decorated by a different run (e.g.: color).
+///
+/// The line layout is shared by the decorated lines.
+type CacheEntry = SmallVec<[(TextRun, ShapedLine); 1]>;
+
+/// The metrics of the [`TextCache`], used to tune the capacity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextCacheMetrics {
+    /// The number of lookups that reused a shaped text.
+    pub hits: u64,
+    /// The number of lookups that shaped the text.
+    pub misses: u64,
+    /// The number of texts evicted as the cache is full.
+    pub evictions: u64,
+    /// The number of texts currently in the cache.
+    pub len: usize,
+    pub capacity: usize,
+}
+
+impl TextCacheMetrics {
+    /// Returns the ratio of the hits in all lookups, 0.0 if there is no lookup.
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.;
+        }
+
+        self.hits as f32 / total as f32
+    }
+}
+
+/// A global LRU cache of the shaped single-line texts.
+///
+/// The texts are keyed by the text, font and font size, the color (and other decorations)
+/// doesn't affect the shaping, so the themes or states (e.g. selected, disabled) changes
+/// only decorate the cached layout again.
+///
+/// GPUI only keeps the shaped lines of the last frame, this cache keeps the frequently
+/// rendered texts (e.g.: the labels in the table cells, list rows and menu items) across frames,
+/// which cuts the shaping costs when scrolling a large table back and forth.
+///
+/// The cache is used by [`CachedText`], see [`TextCache::metrics`] to tune the capacity.
+pub struct TextCache {
+    lines: LruMap<CacheKey, CacheEntry>,
+    metrics: TextCacheMetrics,
+}
+
+impl Default for TextCache {
+    fn default() -> Self {
+        Self {
+            lines: LruMap::new(DEFAULT_CAPACITY),
+            metrics: TextCacheMetrics::default(),
+        }
+    }
+}
+
+impl Global for TextCache {}
+
+impl TextCache {
+    pub fn global(cx: &App) -> &Self {
+        cx.global::<Self>()
+    }
+
+    pub fn global_mut(cx: &mut App) -> &mut Self {
+        cx.global_mut::<Self>()
+    }
+
+    /// Returns the metrics of the cache.
+    pub fn metrics(&self) -> TextCacheMetrics {
+        TextCacheMetrics {
+            len: self.lines.len(),
+            capacity: self.lines.capacity,
+            ..self.metrics
+        }
+    }
+
+    /// Reset the hits, misses and evictions counters of the metrics.
+    pub fn reset_metrics(&mut self) {
+        self.metrics = TextCacheMetrics::default();
+    }
+
+    /// Set the max number of the texts in the cache, default is 4096.
+    ///
+    /// The least recently used texts are evicted if the cache is larger than the capacity.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.metrics.evictions += self.lines.set_capacity(capacity) as u64;
+    }
+
+    /// Clear all shaped texts, e.g.: after loading new fonts.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Shape a single-line text with the run, or return the cached one.
+    ///
+    /// The `text` must not contain newlines, and the `run` must cover the whole text.
+    pub fn shape_line(
+        text: SharedString,
+        font_size: Pixels,
+        run: TextRun,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> ShapedLine {
+        let key = CacheKey {
+            text: text.clone(),
+            font: run.font.clone(),
+            font_size: f32::from(font_size).to_bits(),
+        };
+
+        let this = Self::global_mut(cx);
+        let entry = this.lines.get_mut(&key);
+        if let Some(line) = entry
+            .as_ref()
+            .and_then(|entry| entry.iter().find(|(r, _)| *r == run))
+            .map(|(_, line)| line.clone())
+        {
+            this.metrics.hits += 1;
+            return line;
+        }
+        this.metrics.misses += 1;
+
+        let line =
+            window
+                .text_system()
+                .shape_line(text, font_size, std::slice::from_ref(&run), None);
+
+        let this = Self::global_mut(cx);
+        match this.lines.get_mut(&key) {
+            Some(entry) => {
+                if entry.len() >= MAX_RUNS_PER_TEXT {
+                    entry.remove(0);
+                }
+                entry.push((run, line.clone()));
+            }
+            None => {
+                let evicted = this
+                    .lines
+                    .insert(key, SmallVec::from_buf([(run, line.clone())]));
+                this.metrics.evictions += evicted as u64;
+            }
+        }
+
+        line
+    }
+}
+
+/// A text element that reuses the shaped line from the global [`TextCache`].
+///
+/// Only the single-line text (`white-space: nowrap`, no text overflow, line clamp and alignment)
+/// is cached, otherwise it is rendered as a [`StyledText`].
+pub struct CachedText {
+    text: SharedString,
+    fallback: Option<StyledText>,
+}
+
+impl CachedText {
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            text: text.into(),
+            fallback: None,
+        }
+    }
+}
+
+pub struct CachedTextLayout {
+    line: ShapedLine,
+    line_height: Pixels,
+}
+
+impl IntoElement for CachedText {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for CachedText {
+    type RequestLayoutState = Option<CachedTextLayout>;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let text_style = window.text_style();
+        let cacheable = text_style.white_space == WhiteSpace::Nowrap
+            && text_style.text_overflow.is_none()
+            && text_style.line_clamp.is_none()
+            && text_style.text_align == TextAlign::Left
+            && !self.text.contains('\n');
+
+        if !cacheable {
+            let mut fallback = StyledText::new(self.text.clone());
+            let (layout_id, _) = fallback.request_layout(id, inspector_id, window, cx);
+            self.fallback = Some(fallback);
+            return (layout_id, None);
+        }
+
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let line_height = text_style
+            .line_height
+            .to_pixels(font_size.into(), window.rem_size());
+        let run = text_style.to_run(self.text.len());
+        let line = TextCache::shape_line(self.text.clone(), font_size, run, window, cx);
+
+        let mut style = Style::default();
+        style.size = size(line.width.into(), line_height.into());
+        style.flex_shrink = 0.;
+        let layout_id = window.request_layout(style, [], cx);
+
+        (layout_id, Some(CachedTextLayout { line, line_height }))
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(fallback) = self.fallback.as_mut() {
+            fallback.prepaint(id, inspector_id, bounds, &mut (), window, cx);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        layout: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(fallback) = self.fallback.as_mut() {
+            fallback.paint(id, inspector_id, bounds, &mut (), &mut (), window, cx);
+            return;
+        }
+
+        if let Some(layout) = layout {
+            _ = layout
+                .line
+                .paint(bounds.origin, layout.line_height, window, cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LruMap, TextCacheMetrics};
+
+    #[test]
+    fn test_lru_map() {
+        let mut map = LruMap::new(2);
+        assert_eq!(map.insert("a", 1), 0);
+        assert_eq!(map.insert("b", 2), 0);
+
+        // "a" is used, so "b" is the least recently used.
+        assert_eq!(map.get_mut(&"a"), Some(&mut 1));
+        assert_eq!(map.insert("c", 3), 1);
+        assert_eq!(map.get_mut(&"b"), None);
+        assert_eq!(map.len(), 2);
+
+        // Replace the value doesn't evict.
+        assert_eq!(map.insert("a", 10), 0);
+        assert_eq!(map.get_mut(&"a"), Some(&mut 10));
+
+        assert_eq!(map.set_capacity(1), 1);
+        assert_eq!(map.get_mut(&"c"), None);
+        assert_eq!(map.get_mut(&"a"), Some(&mut 10));
+
+        map.clear();
+        assert_eq!(map.len(), 0);
+    }
+
+    #[test]
+    fn test_hit_rate() {
+        assert_eq!(TextCacheMetrics::default().hit_rate(), 0.);
+        let metrics = TextCacheMetrics {
+            hits: 3,
+            misses: 1,
+            ..Default::default()
+        };
+        assert_eq!(metrics.hit_rate(), 0.75);
+    }
+}
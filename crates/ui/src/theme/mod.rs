@@ -1,5 +1,11 @@
-use crate::{highlighter::HighlightTheme, scroll::ScrollbarShow};
-use gpui::{px, App, Global, Hsla, Pixels, SharedString, Window, WindowAppearance};
+use crate::{
+    highlighter::HighlightTheme,
+    responsive::Breakpoints,
+    scroll::{ScrollbarLayout, ScrollbarShow},
+    Density, Direction,
+};
+use anyhow::{anyhow, Result};
+use gpui::{px, App, Global, Hsla, Pixels, SharedString, Subscription, Window, WindowAppearance};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -11,11 +17,13 @@ use std::{
 mod color;
 mod registry;
 mod schema;
+mod scope;
 mod theme_color;
 
 pub use color::*;
 pub use registry::*;
 pub use schema::*;
+pub use scope::ThemeScope;
 pub use theme_color::*;
 
 pub fn init(cx: &mut App) {
@@ -32,7 +40,7 @@ pub trait ActiveTheme {
 impl ActiveTheme for App {
     #[inline(always)]
     fn theme(&self) -> &Theme {
-        Theme::global(self)
+        scope::ThemeScopes::current(self).unwrap_or_else(|| Theme::global(self))
     }
 }
 
@@ -54,10 +62,27 @@ pub struct Theme {
     pub transparent: Hsla,
     /// Show the scrollbar mode, default: Scrolling
     pub scrollbar_show: ScrollbarShow,
+    /// The width of the scrollbar thumb, default is 8px.
+    pub scrollbar_width: Pixels,
+    /// The radius of the scrollbar thumb, default is None to use the half of the width.
+    pub scrollbar_thumb_radius: Option<Pixels>,
+    /// The layout of the scrollbar, default is Overlay.
+    ///
+    /// The `Gutter` layout is reserved the space for the scrollbar in the Scrollable and List.
+    pub scrollbar_layout: ScrollbarLayout,
     /// Tile grid size, default is 4px.
     pub tile_grid_size: Pixels,
     /// The shadow of the tile panel.
     pub tile_shadow: bool,
+    /// Disable the transition animations of the controls (e.g.: Switch, Checkbox), default is false.
+    pub reduce_motion: bool,
+    /// The density to scale the heights and paddings of the controls
+    /// (e.g.: Button, Input, Table, List, Menu), default is Comfortable.
+    pub density: Density,
+    /// The layout direction, the components are mirrored in `Rtl`, default is Ltr.
+    pub direction: Direction,
+    /// The width thresholds of the [`crate::responsive::Breakpoint`]s.
+    pub breakpoints: Breakpoints,
 }
 
 impl Default for Theme {
@@ -169,6 +194,54 @@ impl Theme {
         }
     }
 
+    /// Returns the active theme config.
+    pub fn active_config(&self) -> &Rc<ThemeConfig> {
+        if self.is_dark() {
+            &self.dark_theme
+        } else {
+            &self.light_theme
+        }
+    }
+
+    /// Change the active theme by the theme name in [`ThemeRegistry`].
+    ///
+    /// The mode is changed to the mode of the theme.
+    pub fn change_theme(name: &str, window: Option<&mut Window>, cx: &mut App) -> Result<()> {
+        let Some(config) = ThemeRegistry::global(cx).theme(name).cloned() else {
+            return Err(anyhow!("theme not found: {}", name));
+        };
+
+        Theme::global_mut(cx).apply_config(&config);
+        if let Some(window) = window {
+            window.refresh();
+        }
+        Ok(())
+    }
+
+    /// Subscribe to the changes of the active theme.
+    ///
+    /// The callback is called when switching the theme or the mode,
+    /// or the active theme is reloaded from the [`ThemeRegistry`].
+    pub fn on_change(
+        cx: &mut App,
+        f: impl Fn(&ThemeChangeEvent, &mut App) + 'static,
+    ) -> Subscription {
+        let mut last_config = Theme::global(cx).active_config().clone();
+        cx.observe_global::<Theme>(move |cx| {
+            let config = Theme::global(cx).active_config().clone();
+            if Rc::ptr_eq(&config, &last_config) {
+                return;
+            }
+
+            last_config = config.clone();
+            let event = ThemeChangeEvent {
+                name: config.name.clone(),
+                mode: Theme::global(cx).mode,
+            };
+            f(&event, cx);
+        })
+    }
+
     /// Get the editor background color, if not set, use the theme background color.
     #[inline]
     pub(crate) fn editor_background(&self) -> Hsla {
@@ -196,8 +269,15 @@ impl From<ThemeColor> for Theme {
             radius_lg: px(8.),
             shadow: true,
             scrollbar_show: ScrollbarShow::default(),
+            scrollbar_width: px(8.),
+            scrollbar_thumb_radius: None,
+            scrollbar_layout: ScrollbarLayout::default(),
             tile_grid_size: px(8.),
             tile_shadow: true,
+            reduce_motion: false,
+            density: Density::default(),
+            direction: Direction::default(),
+            breakpoints: Breakpoints::default(),
             colors,
             light_theme: Rc::new(ThemeConfig::default()),
             dark_theme: Rc::new(ThemeConfig::default()),
@@ -206,6 +286,15 @@ impl From<ThemeColor> for Theme {
     }
 }
 
+/// The event emitted when the active theme is changed, see [`Theme::on_change`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeChangeEvent {
+    /// The name of the new active theme.
+    pub name: SharedString,
+    /// The mode of the new active theme.
+    pub mode: ThemeMode,
+}
+
 #[derive(
     Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Hash, Serialize, Deserialize, JsonSchema,
 )]
@@ -5,13 +5,14 @@ use notify::Watcher as _;
 use std::{
     collections::HashMap,
     fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, LazyLock},
 };
 
 const DEFAULT_THEME: &str = include_str!("./default-theme.json");
-pub(crate) const DEFAULT_THEME_COLORS: LazyLock<
+const PRESET_THEMES: &str = include_str!("./preset-themes.json");
+pub(crate) static DEFAULT_THEME_COLORS: LazyLock<
     HashMap<ThemeMode, (Arc<ThemeColor>, Arc<HighlightTheme>)>,
 > = LazyLock::new(|| {
     let mut colors = HashMap::new();
@@ -77,6 +78,10 @@ pub(super) fn init(cx: &mut App) {
 pub struct ThemeRegistry {
     themes_dir: PathBuf,
     default_themes: HashMap<ThemeMode, Rc<ThemeConfig>>,
+    /// The built-in preset themes.
+    preset_themes: Vec<Rc<ThemeConfig>>,
+    /// The themes registered by the application at runtime.
+    registered_themes: Vec<Rc<ThemeConfig>>,
     themes: HashMap<SharedString, Rc<ThemeConfig>>,
     has_custom_themes: bool,
 }
@@ -92,7 +97,7 @@ impl ThemeRegistry {
         cx.global_mut::<Self>()
     }
 
-    /// Watch themes directory.
+    /// Watch themes directory, the `.json` and `.toml` theme files in it will be loaded.
     ///
     /// And reload themes to trigger the `on_load` callback.
     pub fn watch_dir<F>(themes_dir: PathBuf, cx: &mut App, on_load: F) -> Result<()>
@@ -117,6 +122,41 @@ impl ThemeRegistry {
         Ok(())
     }
 
+    /// Register a theme at runtime, the theme with the same name will be replaced.
+    ///
+    /// If the theme is the active theme, it will be applied immediately.
+    pub fn register(&mut self, theme: ThemeConfig) {
+        let theme = Rc::new(theme);
+        self.registered_themes.retain(|t| t.name != theme.name);
+        self.registered_themes.push(theme.clone());
+        self.has_custom_themes = true;
+        self.themes.insert(theme.name.clone(), theme);
+    }
+
+    /// Register all themes in the theme set, returns the names of the themes.
+    pub fn register_set(&mut self, theme_set: ThemeSet) -> Vec<SharedString> {
+        theme_set
+            .themes
+            .into_iter()
+            .map(|theme| {
+                let name = theme.name.clone();
+                self.register(theme);
+                name
+            })
+            .collect()
+    }
+
+    /// Load and register the themes from a `.json` or `.toml` file, returns the names of the themes.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<Vec<SharedString>> {
+        let theme_set = ThemeSet::from_file(path)?;
+        Ok(self.register_set(theme_set))
+    }
+
+    /// Returns the theme by name.
+    pub fn theme(&self, name: &str) -> Option<&Rc<ThemeConfig>> {
+        self.themes.get(name)
+    }
+
     /// Returns a reference to the map of themes (including default themes).
     pub fn themes(&self) -> &HashMap<SharedString, Rc<ThemeConfig>> {
         &self.themes
@@ -158,9 +198,16 @@ impl ThemeRegistry {
                 self.default_themes.insert(ThemeMode::Light, Rc::new(theme));
             }
         }
+        self.preset_themes = serde_json::from_str::<ThemeSet>(PRESET_THEMES)
+            .expect("failed to parse preset themes.")
+            .themes
+            .into_iter()
+            .map(Rc::new)
+            .collect();
         self.themes = self
             .default_themes
             .values()
+            .chain(self.preset_themes.iter())
             .map(|theme| {
                 let name = theme.name.clone();
                 (name, Rc::clone(theme))
@@ -223,10 +270,12 @@ impl ThemeRegistry {
             for entry in fs::read_dir(&self.themes_dir)? {
                 let entry = entry?;
                 let path = entry.path();
-                if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("json") {
-                    let file_content = fs::read_to_string(path.clone())?;
-
-                    match serde_json::from_str::<ThemeSet>(&file_content) {
+                let is_theme_file = matches!(
+                    path.extension().and_then(|s| s.to_str()),
+                    Some("json" | "toml")
+                );
+                if path.is_file() && is_theme_file {
+                    match ThemeSet::from_file(&path) {
                         Ok(theme_set) => {
                             themes.extend(theme_set.themes);
                         }
@@ -247,6 +296,12 @@ impl ThemeRegistry {
             self.themes
                 .insert(theme.name.clone(), Rc::new((**theme).clone()));
         }
+        for theme in self.preset_themes.iter() {
+            self.themes.insert(theme.name.clone(), theme.clone());
+        }
+        for theme in self.registered_themes.iter() {
+            self.themes.insert(theme.name.clone(), theme.clone());
+        }
 
         for theme in themes.iter() {
             if self.themes.contains_key(&theme.name) {
@@ -266,3 +321,83 @@ impl ThemeRegistry {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Hsla, Rgba};
+
+    use super::ThemeRegistry;
+    use crate::{ThemeColor, ThemeConfig, ThemeMode};
+
+    /// Returns the WCAG contrast ratio of the two colors.
+    fn contrast_ratio(a: Hsla, b: Hsla) -> f32 {
+        fn luminance(color: Hsla) -> f32 {
+            let rgba = Rgba::from(color);
+            let channel = |c: f32| {
+                if c <= 0.03928 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * channel(rgba.r) + 0.7152 * channel(rgba.g) + 0.0722 * channel(rgba.b)
+        }
+
+        let (a, b) = (luminance(a), luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[test]
+    fn test_register_themes() {
+        let mut registry = ThemeRegistry::default();
+        registry.init_default_themes();
+        assert!(registry.theme("Default Light").is_some());
+        assert!(registry.theme("Default Dark").is_some());
+        for name in [
+            "Nord",
+            "Dracula",
+            "One Light",
+            "High Contrast Light",
+            "High Contrast Dark",
+            "Color Blind Safe Light",
+            "Color Blind Safe Dark",
+        ] {
+            assert!(registry.theme(name).is_some(), "missing preset: {}", name);
+        }
+
+        registry.register(ThemeConfig {
+            name: "Custom".into(),
+            mode: ThemeMode::Dark,
+            radius: Some(0.),
+            ..Default::default()
+        });
+        assert_eq!(registry.theme("Custom").unwrap().radius, Some(0.));
+
+        // Registered themes are kept after reload.
+        registry.reload().unwrap();
+        assert_eq!(registry.theme("Custom").unwrap().mode, ThemeMode::Dark);
+        assert!(registry.theme("Nord").is_some());
+    }
+
+    #[test]
+    fn test_high_contrast_themes() {
+        let mut registry = ThemeRegistry::default();
+        registry.init_default_themes();
+
+        for name in ["High Contrast Light", "High Contrast Dark"] {
+            let config = registry.theme(name).unwrap();
+            let mut colors = ThemeColor::default();
+            colors.apply_config(config, &ThemeColor::default());
+
+            for (token, color) in [
+                ("foreground", colors.foreground),
+                ("muted.foreground", colors.muted_foreground),
+                ("border", colors.border),
+                ("ring", colors.ring),
+            ] {
+                let ratio = contrast_ratio(color, colors.background);
+                assert!(ratio >= 7., "{} {}: {}", name, token, ratio);
+            }
+        }
+    }
+}
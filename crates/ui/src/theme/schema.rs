@@ -1,13 +1,14 @@
-use std::{rc::Rc, sync::Arc};
+use std::{fs, path::Path, rc::Rc, sync::Arc};
 
-use anyhow::Result;
-use gpui::{Hsla, SharedString};
+use anyhow::{anyhow, Result};
+use gpui::{px, Hsla, SharedString};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     highlighter::{HighlightTheme, HighlightThemeStyle},
-    Colorize, Theme, ThemeColor, ThemeMode,
+    scroll::{ScrollbarLayout, ScrollbarShow},
+    Colorize, Theme, ThemeColor, ThemeMode, DEFAULT_THEME_COLORS,
 };
 
 /// Represents a theme configuration.
@@ -29,6 +30,39 @@ pub struct ThemeSet {
     pub themes: Vec<ThemeConfig>,
 }
 
+impl ThemeSet {
+    /// Parse a theme set from the JSON content.
+    pub fn from_json(content: &str) -> Result<Self> {
+        Ok(serde_json::from_str::<Self>(content)?.normalize())
+    }
+
+    /// Parse a theme set from the TOML content.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        Ok(toml::from_str::<Self>(content)?.normalize())
+    }
+
+    /// Load a theme set from a `.json` or `.toml` file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&content),
+            Some("toml") => Self::from_toml(&content),
+            _ => Err(anyhow!("unsupported theme file: {}", path.display())),
+        }
+    }
+
+    /// Apply the theme set level `font.size` to the themes that not set it.
+    fn normalize(mut self) -> Self {
+        if let Some(font_size) = self.font_size {
+            for theme in self.themes.iter_mut() {
+                theme.font_size.get_or_insert(font_size);
+            }
+        }
+        self
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct ThemeConfig {
@@ -38,6 +72,44 @@ pub struct ThemeConfig {
     pub name: SharedString,
     /// The mode of the theme, default is light.
     pub mode: ThemeMode,
+
+    /// The base font family, default is the system UI font.
+    #[serde(rename = "font.family")]
+    pub font_family: Option<SharedString>,
+    /// The base font size, default is 16.
+    #[serde(rename = "font.size")]
+    pub font_size: Option<f32>,
+    /// The radius for the general elements, default is 6.
+    #[serde(rename = "radius")]
+    pub radius: Option<f32>,
+    /// The radius for the large elements, e.g.: Modal, Notification, default is 8.
+    #[serde(rename = "radius.lg")]
+    pub radius_lg: Option<f32>,
+    /// The show mode of the scrollbar, default is synced with the system.
+    #[serde(rename = "scrollbar.show")]
+    pub scrollbar_show: Option<ScrollbarShow>,
+    /// The width of the scrollbar thumb, default is 8.
+    #[serde(rename = "scrollbar.width")]
+    pub scrollbar_width: Option<f32>,
+    /// The radius of the scrollbar thumb, default is the half of the width.
+    #[serde(rename = "scrollbar.thumb.radius")]
+    pub scrollbar_thumb_radius: Option<f32>,
+    /// The layout of the scrollbar, default is Overlay.
+    #[serde(rename = "scrollbar.layout")]
+    pub scrollbar_layout: Option<ScrollbarLayout>,
+    /// The minimum width of the `sm` breakpoint, default is 640.
+    #[serde(rename = "breakpoint.sm")]
+    pub breakpoint_sm: Option<f32>,
+    /// The minimum width of the `md` breakpoint, default is 768.
+    #[serde(rename = "breakpoint.md")]
+    pub breakpoint_md: Option<f32>,
+    /// The minimum width of the `lg` breakpoint, default is 1024.
+    #[serde(rename = "breakpoint.lg")]
+    pub breakpoint_lg: Option<f32>,
+    /// The minimum width of the `xl` breakpoint, default is 1280.
+    #[serde(rename = "breakpoint.xl")]
+    pub breakpoint_xl: Option<f32>,
+
     /// The colors of the theme.
     pub colors: ThemeConfigColors,
     /// The highlight theme, this part is combilbility with `style` section in Zed theme.
@@ -620,6 +692,8 @@ impl Theme {
                 style: style.clone(),
             });
             self.highlight_theme = highlight_theme.clone();
+        } else if let Some((_, highlight_theme)) = DEFAULT_THEME_COLORS.get(&config.mode) {
+            self.highlight_theme = highlight_theme.clone();
         }
 
         let default_theme = if config.mode.is_dark() {
@@ -630,14 +704,104 @@ impl Theme {
 
         self.colors.apply_config(&config, &default_theme);
         self.mode = config.mode;
+
+        if let Some(font_family) = &config.font_family {
+            self.font_family = font_family.clone();
+        }
+        if let Some(font_size) = config.font_size {
+            self.font_size = px(font_size);
+        }
+        if let Some(radius) = config.radius {
+            self.radius = px(radius);
+        }
+        if let Some(radius_lg) = config.radius_lg {
+            self.radius_lg = px(radius_lg);
+        }
+        if let Some(scrollbar_show) = config.scrollbar_show {
+            self.scrollbar_show = scrollbar_show;
+        }
+        if let Some(scrollbar_width) = config.scrollbar_width {
+            self.scrollbar_width = px(scrollbar_width);
+        }
+        if let Some(scrollbar_thumb_radius) = config.scrollbar_thumb_radius {
+            self.scrollbar_thumb_radius = Some(px(scrollbar_thumb_radius));
+        }
+        if let Some(scrollbar_layout) = config.scrollbar_layout {
+            self.scrollbar_layout = scrollbar_layout;
+        }
+
+        if let Some(breakpoint_sm) = config.breakpoint_sm {
+            self.breakpoints.sm = px(breakpoint_sm);
+        }
+        if let Some(breakpoint_md) = config.breakpoint_md {
+            self.breakpoints.md = px(breakpoint_md);
+        }
+        if let Some(breakpoint_lg) = config.breakpoint_lg {
+            self.breakpoints.lg = px(breakpoint_lg);
+        }
+        if let Some(breakpoint_xl) = config.breakpoint_xl {
+            self.breakpoints.xl = px(breakpoint_xl);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::try_parse_color;
+    use super::{try_parse_color, ThemeSet};
+    use crate::{scroll::ScrollbarLayout, ThemeMode};
     use gpui::hsla;
 
+    #[test]
+    fn test_parse_theme_set() {
+        let json = r##"{
+            "name": "Test",
+            "font.size": 14,
+            "themes": [
+                { "name": "Test Light", "radius": 2, "colors": { "background": "#ffffff" } },
+                { "name": "Test Scrollbar", "scrollbar.width": 10, "scrollbar.layout": "Gutter", "breakpoint.md": 700 },
+                { "name": "Test Dark", "mode": "dark", "font.size": 15 }
+            ]
+        }"##;
+        let theme_set = ThemeSet::from_json(json).unwrap();
+        assert_eq!(theme_set.themes.len(), 3);
+        assert_eq!(theme_set.themes[0].mode, ThemeMode::Light);
+        assert_eq!(theme_set.themes[0].radius, Some(2.));
+        assert_eq!(theme_set.themes[0].font_size, Some(14.));
+        assert_eq!(theme_set.themes[1].scrollbar_width, Some(10.));
+        assert_eq!(
+            theme_set.themes[1].scrollbar_layout,
+            Some(ScrollbarLayout::Gutter)
+        );
+        assert_eq!(theme_set.themes[1].breakpoint_md, Some(700.));
+        assert_eq!(theme_set.themes[1].breakpoint_sm, None);
+        assert_eq!(theme_set.themes[2].mode, ThemeMode::Dark);
+        assert_eq!(theme_set.themes[2].font_size, Some(15.));
+
+        let toml = r##"
+            name = "Test"
+
+            [[themes]]
+            name = "Test Dark"
+            mode = "dark"
+            "radius.lg" = 10
+            "font.family" = "Inter"
+
+            [themes.colors]
+            background = "#000000"
+            "primary.background" = "#3b82f6"
+        "##;
+        let theme_set = ThemeSet::from_toml(toml).unwrap();
+        let theme = &theme_set.themes[0];
+        assert_eq!(theme.name, "Test Dark");
+        assert_eq!(theme.mode, ThemeMode::Dark);
+        assert_eq!(theme.radius_lg, Some(10.));
+        assert_eq!(theme.font_family, Some("Inter".into()));
+        assert_eq!(theme.colors.background, Some("#000000".into()));
+        assert_eq!(theme.colors.primary, Some("#3b82f6".into()));
+
+        assert!(ThemeSet::from_toml("themes = 1").is_err());
+    }
+
     #[test]
     fn test_try_parse_color() {
         assert_eq!(
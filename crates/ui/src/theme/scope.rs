@@ -0,0 +1,133 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Window,
+};
+
+use crate::{ActiveTheme, Theme};
+
+/// The stack of the scoped themes, the last one is the active theme.
+#[derive(Default)]
+pub(super) struct ThemeScopes(Vec<Rc<Theme>>);
+
+impl Global for ThemeScopes {}
+
+impl ThemeScopes {
+    /// Returns the current scoped theme, None if not in any [`ThemeScope`].
+    #[inline]
+    pub(super) fn current(cx: &App) -> Option<&Theme> {
+        cx.try_global::<Self>()
+            .and_then(|scopes| scopes.0.last())
+            .map(|theme| theme.as_ref())
+    }
+}
+
+type ThemeOverrideFn = Rc<dyn Fn(&mut Theme, &App)>;
+
+/// An element to override the theme tokens (colors, radius, density ...) for a subtree.
+///
+/// The [`ActiveTheme::theme`] returns the overridden theme while the children are
+/// rendering, prepainting and painting, e.g. a sidebar that uses a darker surface:
+///
+/// ```ignore
+/// ThemeScope::new(sidebar).theme(|theme, _| {
+///     theme.background = theme.sidebar;
+///     theme.density = Density::Compact;
+/// })
+/// ```
+///
+/// NOTE: The deferred elements (e.g.: Popover, ContextMenu) are painted out of the
+/// scope, so they still use the global theme.
+pub struct ThemeScope {
+    child: AnyElement,
+    overrides: Vec<ThemeOverrideFn>,
+}
+
+impl ThemeScope {
+    /// Create a new theme scope for the `child` element.
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            overrides: vec![],
+        }
+    }
+
+    /// Add a function to override the theme of the scope.
+    ///
+    /// The theme is a copy of the current active theme, so the scopes can be nested.
+    pub fn theme(mut self, f: impl Fn(&mut Theme, &App) + 'static) -> Self {
+        self.overrides.push(Rc::new(f));
+        self
+    }
+
+    fn with_theme<R>(theme: &Rc<Theme>, cx: &mut App, f: impl FnOnce(&mut App) -> R) -> R {
+        cx.default_global::<ThemeScopes>().0.push(theme.clone());
+        let result = f(cx);
+        cx.default_global::<ThemeScopes>().0.pop();
+        result
+    }
+}
+
+impl IntoElement for ThemeScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ThemeScope {
+    type RequestLayoutState = Rc<Theme>;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut theme = cx.theme().clone();
+        for f in self.overrides.iter() {
+            f(&mut theme, cx);
+        }
+        let theme = Rc::new(theme);
+
+        let layout_id = Self::with_theme(&theme, cx, |cx| self.child.request_layout(window, cx));
+        (layout_id, theme)
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        theme: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        Self::with_theme(theme, cx, |cx| self.child.prepaint(window, cx));
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        theme: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        Self::with_theme(theme, cx, |cx| self.child.paint(window, cx));
+    }
+}
@@ -1,24 +1,46 @@
-use std::{borrow::Cow, rc::Rc};
+use std::rc::Rc;
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use gpui::{
-    prelude::FluentBuilder as _, px, relative, App, ClickEvent, Context, ElementId, Empty, Entity,
-    EventEmitter, FocusHandle, InteractiveElement, IntoElement, ParentElement, Render, RenderOnce,
-    SharedString, StatefulInteractiveElement, StyleRefinement, Styled, Window,
+    actions, div, prelude::FluentBuilder as _, px, relative, App, ClickEvent, Context, ElementId,
+    Empty, Entity, EventEmitter, FocusHandle, Hsla, InteractiveElement, IntoElement, KeyBinding,
+    ParentElement, Pixels, Render, RenderOnce, ScrollWheelEvent, SharedString,
+    StatefulInteractiveElement, StyleRefinement, Styled, Window,
 };
 use rust_i18n::t;
 
 use crate::{
+    accessibility::{Accessibility, AccessibilityProps, Role},
     button::{Button, ButtonVariants as _},
-    h_flex, v_flex, ActiveTheme, Disableable as _, IconName, Selectable, Sizable, Size,
+    h_flex, locale,
+    tooltip::Tooltip,
+    v_flex, ActiveTheme, Colorize as _, Disableable as _, IconName, Selectable, Sizable, Size,
     StyledExt as _,
 };
 
-use super::utils::days_in_month;
+use super::utils::{add_months, days_in_month, week_number};
+
+const CONTEXT: &str = "Calendar";
+/// The scrolled distance to turn a page by the mouse wheel.
+const WHEEL_PAGE_DISTANCE: Pixels = px(50.);
+
+actions!(calendar, [PrevPage, NextPage, PrevYear, NextYear]);
+
+pub(crate) fn init(cx: &mut App) {
+    let context = Some(CONTEXT);
+    cx.bind_keys([
+        KeyBinding::new("pageup", PrevPage, context),
+        KeyBinding::new("pagedown", NextPage, context),
+        KeyBinding::new("shift-pageup", PrevYear, context),
+        KeyBinding::new("shift-pagedown", NextYear, context),
+    ]);
+}
 
 pub enum CalendarEvent {
     /// The user selected a date.
     Selected(Date),
+    /// The mouse is hovered on a day, `None` when the mouse leaves the day.
+    Hovered(Option<NaiveDate>),
 }
 
 /// The date of the calendar.
@@ -246,6 +268,49 @@ impl Matcher {
     }
 }
 
+/// The decoration of a day, returned by the [`CalendarState::day_decorator`].
+///
+/// e.g.: Mark the holidays with a color, or the available days with a dot.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayDecoration {
+    color: Option<Hsla>,
+    dot: Option<Hsla>,
+    disabled: bool,
+    tooltip: Option<SharedString>,
+}
+
+impl DayDecoration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text color of the day.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Show a dot with the color below the day.
+    pub fn dot(mut self, color: impl Into<Hsla>) -> Self {
+        self.dot = Some(color.into());
+        self
+    }
+
+    /// Set the day to be disabled, the day can't be selected.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the tooltip of the day.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+}
+
+type DayDecorator = Rc<dyn Fn(&NaiveDate) -> Option<DayDecoration>>;
+
 #[derive(IntoElement)]
 pub struct Calendar {
     id: ElementId,
@@ -254,6 +319,8 @@ pub struct Calendar {
     style: StyleRefinement,
     /// Number of the months view to show.
     number_of_months: usize,
+    today_button: bool,
+    week_numbers: bool,
 }
 
 /// Use to store the state of the calendar.
@@ -268,7 +335,15 @@ pub struct CalendarState {
     today: NaiveDate,
     /// Number of the months view to show.
     number_of_months: usize,
+    /// The first day of the week, default is Sunday.
+    week_start: Weekday,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
     pub(crate) disabled_matcher: Option<Rc<Matcher>>,
+    day_decorator: Option<DayDecorator>,
+    hovered_date: Option<NaiveDate>,
+    /// The accumulated mouse wheel delta to turn the page.
+    wheel_delta: Pixels,
 }
 
 impl CalendarState {
@@ -284,7 +359,13 @@ impl CalendarState {
             year_page: 0,
             today,
             number_of_months: 1,
+            week_start: Weekday::Sun,
+            min_date: None,
+            max_date: None,
             disabled_matcher: None,
+            day_decorator: None,
+            hovered_date: None,
+            wheel_delta: px(0.),
         }
         .year_range((today.year() - 50, today.year() + 50))
     }
@@ -307,6 +388,133 @@ impl CalendarState {
         self.disabled_matcher = Some(Rc::new(disabled.into()));
     }
 
+    /// Set the first day of the week, default is [`Weekday::Sun`].
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Set the first day of the week of the calendar, see [`CalendarState::week_start`].
+    pub fn set_week_start(&mut self, week_start: Weekday, _: &mut Window, cx: &mut Context<Self>) {
+        self.week_start = week_start;
+        cx.notify();
+    }
+
+    /// Set the min date can be selected, the days before it are disabled,
+    /// and the pages before its month can't be turned to.
+    pub fn min_date(mut self, date: NaiveDate) -> Self {
+        self.min_date = Some(date);
+        self
+    }
+
+    /// Set the max date can be selected, see [`CalendarState::min_date`].
+    pub fn max_date(mut self, date: NaiveDate) -> Self {
+        self.max_date = Some(date);
+        self
+    }
+
+    /// Set the min and max date of the calendar, `None` to remove the limit.
+    pub fn set_date_limit(
+        &mut self,
+        min_date: Option<NaiveDate>,
+        max_date: Option<NaiveDate>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.min_date = min_date;
+        self.max_date = max_date;
+        cx.notify();
+    }
+
+    /// Returns true if the date is before the min date or after the max date.
+    fn is_out_of_limit(&self, date: &NaiveDate) -> bool {
+        self.min_date.is_some_and(|min| *date < min) || self.max_date.is_some_and(|max| *date > max)
+    }
+
+    /// Returns true if the whole month is out of the min and max date.
+    fn is_month_out_of_limit(&self, year: i32, month: u32) -> bool {
+        self.min_date
+            .is_some_and(|min| (year, month) < (min.year(), min.month()))
+            || self
+                .max_date
+                .is_some_and(|max| (year, month) > (max.year(), max.month()))
+    }
+
+    /// Returns true if the whole year is out of the min and max date.
+    fn is_year_out_of_limit(&self, year: i32) -> bool {
+        self.min_date.is_some_and(|min| year < min.year())
+            || self.max_date.is_some_and(|max| year > max.year())
+    }
+
+    /// Set the day decorator of the calendar state.
+    ///
+    /// The decorator is called for each rendered day, returns `None` to keep the day as default.
+    ///
+    /// ```ignore
+    /// CalendarState::new(window, cx).day_decorator(|date| {
+    ///     (date.weekday() == Weekday::Sun)
+    ///         .then(|| DayDecoration::new().color(red()).tooltip("Holiday"))
+    /// })
+    /// ```
+    pub fn day_decorator(
+        mut self,
+        decorator: impl Fn(&NaiveDate) -> Option<DayDecoration> + 'static,
+    ) -> Self {
+        self.day_decorator = Some(Rc::new(decorator));
+        self
+    }
+
+    /// Set the day decorator of the calendar, see [`CalendarState::day_decorator`].
+    pub fn set_day_decorator(
+        &mut self,
+        decorator: impl Fn(&NaiveDate) -> Option<DayDecoration> + 'static,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.day_decorator = Some(Rc::new(decorator));
+        cx.notify();
+    }
+
+    /// Returns the decoration of the date by the day decorator.
+    fn decoration(&self, date: &NaiveDate) -> Option<DayDecoration> {
+        self.day_decorator
+            .as_ref()
+            .and_then(|decorator| decorator(date))
+    }
+
+    /// Returns true if the date is disabled by the min and max date,
+    /// the disabled matcher or the day decorator.
+    fn is_disabled(&self, date: &NaiveDate) -> bool {
+        self.is_out_of_limit(date)
+            || self
+                .disabled_matcher
+                .as_ref()
+                .map_or(false, |matcher| matcher.matched(date))
+            || self
+                .decoration(date)
+                .map_or(false, |decoration| decoration.disabled)
+    }
+
+    /// Returns the date under the mouse.
+    pub fn hovered_date(&self) -> Option<NaiveDate> {
+        self.hovered_date
+    }
+
+    fn set_hovered_date(&mut self, date: NaiveDate, hovered: bool, cx: &mut Context<Self>) {
+        let hovered_date = if hovered {
+            Some(date)
+        } else if self.hovered_date == Some(date) {
+            None
+        } else {
+            return;
+        };
+
+        if self.hovered_date != hovered_date {
+            self.hovered_date = hovered_date;
+            cx.emit(CalendarEvent::Hovered(hovered_date));
+        }
+    }
+
     /// Set the date of the calendar.
     ///
     /// When you set a range date, the mode will be automatically set to `Mode::Range`.
@@ -316,7 +524,10 @@ impl CalendarState {
         let invalid = self
             .disabled_matcher
             .as_ref()
-            .map_or(false, |matcher| matcher.date_matched(&date));
+            .map_or(false, |matcher| matcher.date_matched(&date))
+            || [date.start(), date.end()].iter().flatten().any(|date| {
+                self.is_out_of_limit(date) || self.decoration(date).map_or(false, |d| d.disabled)
+            });
 
         if invalid {
             return;
@@ -377,25 +588,19 @@ impl CalendarState {
 
     /// Get year and month by offset month.
     fn offset_year_month(&self, offset_month: usize) -> (i32, u32) {
-        let mut month = self.current_month as i32 + offset_month as i32;
-        let mut year = self.current_year;
-        while month < 1 {
-            month += 12;
-            year -= 1;
-        }
-        while month > 12 {
-            month -= 12;
-            year += 1;
-        }
-
-        (year, month as u32)
+        add_months(
+            self.current_year,
+            self.current_month as u32,
+            offset_month as i32,
+        )
     }
 
-    /// Returns the days of the month in a 2D vector to render on calendar.
-    fn days(&self) -> Vec<Vec<NaiveDate>> {
+    /// Returns the weeks of each month to render on calendar.
+    fn days(&self) -> Vec<Vec<Vec<NaiveDate>>> {
         (0..self.number_of_months)
-            .flat_map(|offset| {
-                days_in_month(self.current_year, self.current_month as u32 + offset as u32)
+            .map(|offset| {
+                let (year, month) = self.offset_year_month(offset);
+                days_in_month(year, month, self.week_start)
             })
             .collect()
     }
@@ -408,70 +613,146 @@ impl CalendarState {
         self.year_page < self.years.len() as i32 - 1
     }
 
-    fn prev_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.has_prev_year_page() {
+    /// Move the current month by the `months`, the `months` can be negative.
+    fn move_months(&mut self, months: i32, cx: &mut Context<Self>) {
+        let (year, month) = add_months(self.current_year, self.current_month as u32, months);
+        self.current_year = year;
+        self.current_month = month as u8;
+        if let Some(page) = self.years.iter().position(|years| years.contains(&year)) {
+            self.year_page = page as i32;
+        }
+        cx.notify()
+    }
+
+    /// Returns true if the current months can be moved by the `months`,
+    /// the moved page must contain any month in the min and max date.
+    fn can_move_months(&self, months: i32) -> bool {
+        let (year, month) = add_months(self.current_year, self.current_month as u32, months);
+        if months < 0 {
+            let (year, month) = add_months(year, month, self.number_of_months.max(1) as i32 - 1);
+            !self.is_month_out_of_limit(year, month)
+        } else {
+            !self.is_month_out_of_limit(year, month)
+        }
+    }
+
+    fn has_prev_page(&self) -> bool {
+        match self.view_mode {
+            ViewMode::Day => self.can_move_months(-1),
+            ViewMode::Month => !self.is_year_out_of_limit(self.current_year - 1),
+            ViewMode::Year => self.has_prev_year_page(),
+        }
+    }
+
+    fn has_next_page(&self) -> bool {
+        match self.view_mode {
+            ViewMode::Day => self.can_move_months(1),
+            ViewMode::Month => !self.is_year_out_of_limit(self.current_year + 1),
+            ViewMode::Year => self.has_next_year_page(),
+        }
+    }
+
+    /// Turn to the previous page of the current view, a month in the day view,
+    /// a year in the month view, and a year page in the year view.
+    fn prev_page(&mut self, cx: &mut Context<Self>) {
+        if !self.has_prev_page() {
             return;
         }
 
-        self.year_page -= 1;
-        cx.notify()
+        match self.view_mode {
+            ViewMode::Day => self.move_months(-1, cx),
+            ViewMode::Month => self.move_months(-12, cx),
+            ViewMode::Year => {
+                if self.has_prev_year_page() {
+                    self.year_page -= 1;
+                    cx.notify()
+                }
+            }
+        }
     }
 
-    fn next_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.has_next_year_page() {
+    /// Turn to the next page of the current view, see [`CalendarState::prev_page`].
+    fn next_page(&mut self, cx: &mut Context<Self>) {
+        if !self.has_next_page() {
             return;
         }
 
-        self.year_page += 1;
-        cx.notify()
+        match self.view_mode {
+            ViewMode::Day => self.move_months(1, cx),
+            ViewMode::Month => self.move_months(12, cx),
+            ViewMode::Year => {
+                if self.has_next_year_page() {
+                    self.year_page += 1;
+                    cx.notify()
+                }
+            }
+        }
     }
 
-    fn prev_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        self.current_month = if self.current_month == 1 {
-            12
-        } else {
-            self.current_month - 1
-        };
-        self.current_year = if self.current_month == 12 {
-            self.current_year - 1
-        } else {
-            self.current_year
-        };
-        cx.notify()
+    /// Show the month of today in the day view, the selected date is not changed.
+    pub fn show_today(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.view_mode = ViewMode::Day;
+        let months = (self.today.year() - self.current_year) * 12 + self.today.month() as i32
+            - self.current_month as i32;
+        self.move_months(months, cx);
     }
 
-    fn next_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        self.current_month = if self.current_month == 12 {
-            1
-        } else {
-            self.current_month + 1
-        };
-        self.current_year = if self.current_month == 1 {
-            self.current_year + 1
-        } else {
-            self.current_year
-        };
-        cx.notify()
+    fn is_showing_today(&self) -> bool {
+        self.view_mode.is_day()
+            && self.current_year == self.today.year()
+            && self.current_month as u32 == self.today.month()
+    }
+
+    fn on_action_prev_page(&mut self, _: &PrevPage, _: &mut Window, cx: &mut Context<Self>) {
+        self.prev_page(cx);
+    }
+
+    fn on_action_next_page(&mut self, _: &NextPage, _: &mut Window, cx: &mut Context<Self>) {
+        self.next_page(cx);
+    }
+
+    fn on_action_prev_year(&mut self, _: &PrevYear, _: &mut Window, cx: &mut Context<Self>) {
+        if self.can_move_months(-12) {
+            self.move_months(-12, cx);
+        }
+    }
+
+    fn on_action_next_year(&mut self, _: &NextYear, _: &mut Window, cx: &mut Context<Self>) {
+        if self.can_move_months(12) {
+            self.move_months(12, cx);
+        }
+    }
+
+    fn on_scroll_wheel(
+        &mut self,
+        event: &ScrollWheelEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let delta = event.delta.pixel_delta(window.line_height());
+        if delta.y == px(0.) {
+            return;
+        }
+
+        // Reset the accumulated delta when the direction is changed.
+        if self.wheel_delta.signum() != delta.y.signum() {
+            self.wheel_delta = px(0.);
+        }
+        self.wheel_delta += delta.y;
+
+        if self.wheel_delta >= WHEEL_PAGE_DISTANCE {
+            self.wheel_delta = px(0.);
+            self.prev_page(cx);
+        } else if self.wheel_delta <= -WHEEL_PAGE_DISTANCE {
+            self.wheel_delta = px(0.);
+            self.next_page(cx);
+        }
+        cx.stop_propagation();
     }
 
     fn month_name(&self, offset_month: usize) -> SharedString {
         let (_, month) = self.offset_year_month(offset_month);
-        match month {
-            1 => t!("Calendar.month.January"),
-            2 => t!("Calendar.month.February"),
-            3 => t!("Calendar.month.March"),
-            4 => t!("Calendar.month.April"),
-            5 => t!("Calendar.month.May"),
-            6 => t!("Calendar.month.June"),
-            7 => t!("Calendar.month.July"),
-            8 => t!("Calendar.month.August"),
-            9 => t!("Calendar.month.September"),
-            10 => t!("Calendar.month.October"),
-            11 => t!("Calendar.month.November"),
-            12 => t!("Calendar.month.December"),
-            _ => Cow::Borrowed(""),
-        }
-        .into()
+        locale::month_name(month)
     }
 
     fn year_name(&self, offset_month: usize) -> SharedString {
@@ -519,6 +800,8 @@ impl Calendar {
             state: state.clone(),
             style: StyleRefinement::default(),
             number_of_months: 1,
+            today_button: true,
+            week_numbers: false,
         }
     }
 
@@ -528,6 +811,18 @@ impl Calendar {
         self
     }
 
+    /// Set whether to show the ISO week numbers before the weeks, default is false.
+    pub fn week_numbers(mut self, week_numbers: bool) -> Self {
+        self.week_numbers = week_numbers;
+        self
+    }
+
+    /// Set whether to show the today button in the header, default is true.
+    pub fn today_button(mut self, today_button: bool) -> Self {
+        self.today_button = today_button;
+        self
+    }
+
     fn render_day(
         &self,
         d: &NaiveDate,
@@ -544,13 +839,17 @@ impl Calendar {
 
         let date = *d;
         let is_today = *d == state.today;
-        let disabled = state
-            .disabled_matcher
-            .as_ref()
-            .map_or(false, |disabled| disabled.matched(&date));
+        let decoration = state.decoration(&date).unwrap_or_default();
+        let disabled = state.is_disabled(&date);
 
         let date_id: SharedString = format!("{}_{}", date.format("%Y-%m-%d"), offset_month).into();
 
+        let mut props = AccessibilityProps::new(Role::Button)
+            .selected(is_active)
+            .disabled(disabled);
+        props.label = Some(locale::format_date(&date, locale::DateStyle::Long).into());
+        Accessibility::declare(&date_id.clone().into(), &props, window, cx);
+
         self.item_button(
             date_id,
             day.to_string(),
@@ -564,6 +863,37 @@ impl Calendar {
         .when(is_today && !is_active, |this| {
             this.border_1().border_color(cx.theme().border)
         }) // Add border for today
+        .when_some(decoration.color, |this, color| {
+            this.when(
+                is_current_month && !disabled && !is_active && !is_in_range,
+                |this| this.text_color(color),
+            )
+        })
+        .when_some(decoration.dot, |this, color| {
+            this.relative().child(
+                h_flex()
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .bottom(px(2.))
+                    .justify_center()
+                    .child(
+                        div()
+                            .size_1()
+                            .rounded_full()
+                            .bg(color)
+                            .when(!is_current_month || disabled, |this| this.opacity(0.5)),
+                    ),
+            )
+        })
+        .when_some(decoration.tooltip, |this, tooltip| {
+            this.tooltip(move |window, cx| Tooltip::new(tooltip.clone()).build(window, cx))
+        })
+        .on_hover(
+            window.listener_for(&self.state, move |view, hovered: &bool, _, cx| {
+                view.set_hovered_date(date, *hovered, cx)
+            }),
+        )
         .when(!disabled, |this| {
             this.on_click(window.listener_for(
                 &self.state,
@@ -604,7 +934,6 @@ impl Calendar {
         let state = self.state.read(cx);
         let current_year = state.current_year;
         let view_mode = state.view_mode;
-        let disabled = view_mode.is_month();
         let multiple_months = self.number_of_months > 1;
         let icon_size = match self.size {
             Size::Small => Size::Small,
@@ -621,17 +950,11 @@ impl Calendar {
                     .icon(IconName::ArrowLeft)
                     .tab_stop(false)
                     .ghost()
-                    .disabled(disabled)
+                    .disabled(!state.has_prev_page())
                     .with_size(icon_size)
-                    .when(view_mode.is_day(), |this| {
-                        this.on_click(window.listener_for(&self.state, CalendarState::prev_month))
-                    })
-                    .when(view_mode.is_year(), |this| {
-                        this.when(!state.has_prev_year_page(), |this| this.disabled(true))
-                            .on_click(
-                                window.listener_for(&self.state, CalendarState::prev_year_page),
-                            )
-                    }),
+                    .on_click(
+                        window.listener_for(&self.state, |view, _, _, cx| view.prev_page(cx)),
+                    ),
             )
             .when(!multiple_months, |this| {
                 this.child(
@@ -696,21 +1019,36 @@ impl Calendar {
                 ))
             })
             .child(
-                Button::new("next")
-                    .icon(IconName::ArrowRight)
-                    .ghost()
-                    .tab_stop(false)
-                    .disabled(disabled)
-                    .with_size(icon_size)
-                    .when(view_mode.is_day(), |this| {
-                        this.on_click(window.listener_for(&self.state, CalendarState::next_month))
+                h_flex()
+                    .gap_0p5()
+                    .when(self.today_button, |this| {
+                        this.child(
+                            Button::new("today")
+                                .ghost()
+                                .label(t!("Calendar.Today"))
+                                .compact()
+                                .tab_stop(false)
+                                .with_size(icon_size)
+                                .disabled(state.is_showing_today())
+                                .on_click(
+                                    window.listener_for(&self.state, |view, _, window, cx| {
+                                        view.show_today(window, cx)
+                                    }),
+                                ),
+                        )
                     })
-                    .when(view_mode.is_year(), |this| {
-                        this.when(!state.has_next_year_page(), |this| this.disabled(true))
+                    .child(
+                        Button::new("next")
+                            .icon(IconName::ArrowRight)
+                            .ghost()
+                            .tab_stop(false)
+                            .disabled(!state.has_next_page())
+                            .with_size(icon_size)
                             .on_click(
-                                window.listener_for(&self.state, CalendarState::next_year_page),
-                            )
-                    }),
+                                window
+                                    .listener_for(&self.state, |view, _, _, cx| view.next_page(cx)),
+                            ),
+                    ),
             )
     }
 
@@ -725,7 +1063,7 @@ impl Calendar {
         disabled: bool,
         _: &mut Window,
         cx: &mut App,
-    ) -> impl IntoElement + Styled + StatefulInteractiveElement {
+    ) -> impl IntoElement + Styled + ParentElement + StatefulInteractiveElement {
         h_flex()
             .id(id.into())
             .map(|this| match self.size {
@@ -754,6 +1092,10 @@ impl Calendar {
                     this.bg(cx.theme().accent)
                         .text_color(cx.theme().accent_foreground)
                 })
+                .active(|this| {
+                    this.bg(cx.theme().accent.darken(0.1))
+                        .text_color(cx.theme().accent_foreground)
+                })
             })
             .when(active, |this| {
                 this.bg(cx.theme().primary)
@@ -764,7 +1106,7 @@ impl Calendar {
 
     fn render_days(&self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let state = self.state.read(cx);
-        let weeks = [
+        let mut weeks = [
             t!("Calendar.week.0"),
             t!("Calendar.week.1"),
             t!("Calendar.week.2"),
@@ -773,6 +1115,8 @@ impl Calendar {
             t!("Calendar.week.5"),
             t!("Calendar.week.6"),
         ];
+        weeks.rotate_left(state.week_start.num_days_from_sunday() as usize);
+        let week_numbers = self.week_numbers;
 
         h_flex()
             .map(|this| match self.size {
@@ -781,29 +1125,44 @@ impl Calendar {
                 _ => this.gap_4().text_sm(),
             })
             .justify_between()
-            .children(
-                state
-                    .days()
-                    .chunks(5)
-                    .enumerate()
-                    .map(|(offset_month, days)| {
-                        v_flex()
+            .children(state.days().iter().enumerate().map(|(offset_month, days)| {
+                v_flex()
+                    .gap_0p5()
+                    .child(
+                        h_flex()
                             .gap_0p5()
-                            .child(
-                                h_flex().gap_0p5().justify_between().children(
-                                    weeks
-                                        .iter()
-                                        .map(|week| self.render_week(week.clone(), window, cx)),
-                                ),
-                            )
-                            .children(days.iter().map(|week| {
-                                h_flex().gap_0p5().justify_between().children(
-                                    week.iter()
-                                        .map(|d| self.render_day(d, offset_month, window, cx)),
+                            .justify_between()
+                            .when(week_numbers, |this| {
+                                this.child(self.render_week(t!("Calendar.week_number"), window, cx))
+                            })
+                            .children(
+                                weeks
+                                    .iter()
+                                    .map(|week| self.render_week(week.clone(), window, cx)),
+                            ),
+                    )
+                    .children(days.iter().map(|week| {
+                        h_flex()
+                            .gap_0p5()
+                            .justify_between()
+                            .when(week_numbers, |this| {
+                                this.child(
+                                    self.render_week(
+                                        week_number(week)
+                                            .map(|n| n.to_string())
+                                            .unwrap_or_default(),
+                                        window,
+                                        cx,
+                                    )
+                                    .text_xs(),
                                 )
-                            }))
-                    }),
-            )
+                            })
+                            .children(
+                                week.iter()
+                                    .map(|d| self.render_day(d, offset_month, window, cx)),
+                            )
+                    }))
+            }))
     }
 
     fn render_week(
@@ -811,7 +1170,7 @@ impl Calendar {
         week: impl Into<SharedString>,
         _: &mut Window,
         cx: &mut App,
-    ) -> impl IntoElement {
+    ) -> impl IntoElement + Styled {
         h_flex()
             .map(|this| match self.size {
                 Size::Small => this.size_7().rounded(cx.theme().radius / 2.0),
@@ -828,6 +1187,9 @@ impl Calendar {
         let state = self.state.read(cx);
         let months = state.months();
         let current_month = state.current_month;
+        let disabled_months = (1..=12)
+            .map(|month| state.is_month_out_of_limit(state.current_year, month))
+            .collect::<Vec<_>>();
 
         h_flex()
             .mt_3()
@@ -846,27 +1208,30 @@ impl Calendar {
                     .enumerate()
                     .map(|(ix, month)| {
                         let active = (ix + 1) as u8 == current_month;
+                        let disabled = disabled_months[ix];
 
                         self.item_button(
                             ix,
                             month.to_string(),
                             active,
                             false,
-                            false,
-                            false,
+                            disabled,
+                            disabled,
                             window,
                             cx,
                         )
                         .w(relative(0.3))
                         .text_sm()
-                        .on_click(window.listener_for(
-                            &self.state,
-                            move |view, _, window, cx| {
-                                view.current_month = (ix + 1) as u8;
-                                view.set_view_mode(ViewMode::Day, window, cx);
-                                cx.notify();
-                            },
-                        ))
+                        .when(!disabled, |this| {
+                            this.on_click(window.listener_for(
+                                &self.state,
+                                move |view, _, window, cx| {
+                                    view.current_month = (ix + 1) as u8;
+                                    view.set_view_mode(ViewMode::Day, window, cx);
+                                    cx.notify();
+                                },
+                            ))
+                        })
                     })
                     .collect::<Vec<_>>(),
             )
@@ -875,7 +1240,10 @@ impl Calendar {
     fn render_years(&self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let state = self.state.read(cx);
         let current_year = state.current_year;
-        let current_page_years = &self.state.read(cx).years[state.year_page as usize].clone();
+        let current_page_years = state.years[state.year_page as usize]
+            .iter()
+            .map(|year| (*year, state.is_year_out_of_limit(*year)))
+            .collect::<Vec<_>>();
 
         h_flex()
             .id("years")
@@ -891,8 +1259,8 @@ impl Calendar {
                 current_page_years
                     .iter()
                     .enumerate()
-                    .map(|(ix, year)| {
-                        let year = *year;
+                    .map(|(ix, (year, disabled))| {
+                        let (year, disabled) = (*year, *disabled);
                         let active = year == current_year;
 
                         self.item_button(
@@ -900,20 +1268,22 @@ impl Calendar {
                             year.to_string(),
                             active,
                             false,
-                            false,
-                            false,
+                            disabled,
+                            disabled,
                             window,
                             cx,
                         )
                         .w(relative(0.2))
-                        .on_click(window.listener_for(
-                            &self.state,
-                            move |view, _, window, cx| {
-                                view.current_year = year;
-                                view.set_view_mode(ViewMode::Day, window, cx);
-                                cx.notify();
-                            },
-                        ))
+                        .when(!disabled, |this| {
+                            this.on_click(window.listener_for(
+                                &self.state,
+                                move |view, _, window, cx| {
+                                    view.current_year = year;
+                                    view.set_view_mode(ViewMode::Day, window, cx);
+                                    cx.notify();
+                                },
+                            ))
+                        })
                     })
                     .collect::<Vec<_>>(),
             )
@@ -944,7 +1314,13 @@ impl RenderOnce for Calendar {
 
         v_flex()
             .id(self.id.clone())
+            .key_context(CONTEXT)
             .track_focus(&self.state.read(cx).focus_handle)
+            .on_action(window.listener_for(&self.state, CalendarState::on_action_prev_page))
+            .on_action(window.listener_for(&self.state, CalendarState::on_action_next_page))
+            .on_action(window.listener_for(&self.state, CalendarState::on_action_prev_year))
+            .on_action(window.listener_for(&self.state, CalendarState::on_action_next_year))
+            .on_scroll_wheel(window.listener_for(&self.state, CalendarState::on_scroll_wheel))
             .border_1()
             .border_color(cx.theme().border)
             .rounded(cx.theme().radius_lg)
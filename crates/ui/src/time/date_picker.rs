@@ -70,7 +70,7 @@ pub struct DatePickerState {
     date: Date,
     open: bool,
     calendar: Entity<CalendarState>,
-    date_format: SharedString,
+    date_format: Option<SharedString>,
     number_of_months: usize,
     disabled_matcher: Option<Rc<Matcher>>,
     _subscriptions: Vec<Subscription>,
@@ -115,6 +115,7 @@ impl DatePickerState {
                     this.update_date(*date, true, window, cx);
                     this.focus_handle.focus(window);
                 }
+                CalendarEvent::Hovered(_) => {}
             },
         )];
 
@@ -123,16 +124,17 @@ impl DatePickerState {
             date,
             calendar,
             open: false,
-            date_format: "%Y/%m/%d".into(),
+            date_format: None,
             number_of_months: 1,
             disabled_matcher: None,
             _subscriptions,
         }
     }
 
-    /// Set the date format of the date picker to display in Input, default: "%Y/%m/%d".
+    /// Set the date format of the date picker to display in Input,
+    /// default is the short date format of the current locale, see [`crate::locale::date_format`].
     pub fn date_format(mut self, format: impl Into<SharedString>) -> Self {
-        self.date_format = format.into();
+        self.date_format = Some(format.into());
         self
     }
 
@@ -359,7 +361,12 @@ impl RenderOnce for DatePicker {
             .unwrap_or_else(|| t!("DatePicker.placeholder").into());
         let display_title = state
             .date
-            .format(&state.date_format)
+            .format(
+                &state
+                    .date_format
+                    .clone()
+                    .unwrap_or_else(crate::locale::date_format),
+            )
             .unwrap_or(placeholder.clone());
 
         div()
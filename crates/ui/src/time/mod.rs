@@ -1,3 +1,4 @@
 pub mod calendar;
 pub mod date_picker;
+pub mod relative_time;
 mod utils;
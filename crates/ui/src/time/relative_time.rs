@@ -0,0 +1,265 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local};
+use gpui::{
+    div, prelude::FluentBuilder as _, App, ElementId, Entity, InteractiveElement as _, IntoElement,
+    ParentElement as _, RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement,
+    Styled, Task, Window,
+};
+use smol::Timer;
+
+use crate::{locale, tooltip::Tooltip, StyledExt as _};
+
+/// The max duration to wait for the next update, to correct the drift after the system sleep.
+const MAX_TICK: Duration = Duration::from_secs(3600);
+
+/// The timer to re-render the [`RelativeTime`] and [`Countdown`].
+#[derive(Default)]
+struct Ticker {
+    deadline: Option<Instant>,
+    /// The deadline of the completed [`Countdown`].
+    completed: Option<DateTime<Local>>,
+    _task: Option<Task<()>>,
+}
+
+impl Ticker {
+    /// Schedule to re-render after the `wait`, the earlier scheduled tick is kept.
+    fn schedule(
+        ticker: &Entity<Self>,
+        wait: Duration,
+        on_tick: impl FnOnce(&Entity<Self>, &mut Window, &mut App) + 'static,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let deadline = Instant::now() + wait.min(MAX_TICK);
+        if ticker
+            .read(cx)
+            .deadline
+            .is_some_and(|scheduled| scheduled <= deadline)
+        {
+            return;
+        }
+
+        let entity = ticker.clone();
+        let task = window.spawn(cx, async move |cx| {
+            Timer::at(deadline).await;
+            _ = cx.update(|window, cx| {
+                entity.update(cx, |ticker, cx| {
+                    ticker.deadline = None;
+                    ticker._task = None;
+                    cx.notify();
+                });
+                on_tick(&entity, window, cx);
+            });
+        });
+        ticker.update(cx, |ticker, _| {
+            ticker.deadline = Some(deadline);
+            ticker._task = Some(task);
+        });
+    }
+}
+
+/// Returns the duration until the relative time of the `seconds` (the time minus now) changes.
+fn next_tick(seconds: i64) -> Duration {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+
+    let abs = seconds.abs();
+    let step = if abs < HOUR { MINUTE } else { HOUR };
+    let wait = if seconds <= 0 {
+        // The past time goes larger.
+        step - abs % step
+    } else if abs < MINUTE {
+        // Keep `just now` until a minute passed the time.
+        abs + MINUTE
+    } else {
+        // The future time goes smaller.
+        abs % step + 1
+    };
+    Duration::from_secs(wait.max(1) as u64)
+}
+
+/// A label of the relative time to now in the current locale, e.g.: `3 minutes ago`, `in 2 days`.
+///
+/// The label is re-rendered when the text changes, and shows the full time in the tooltip.
+///
+/// ```ignore
+/// RelativeTime::new("updated-at", item.updated_at)
+/// ```
+#[derive(IntoElement)]
+pub struct RelativeTime {
+    id: ElementId,
+    time: DateTime<Local>,
+    tooltip: bool,
+    style: StyleRefinement,
+}
+
+impl RelativeTime {
+    pub fn new(id: impl Into<ElementId>, time: DateTime<Local>) -> Self {
+        Self {
+            id: id.into(),
+            time,
+            tooltip: true,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    /// Set whether to show the full time in the tooltip, default is true.
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+}
+
+impl Styled for RelativeTime {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for RelativeTime {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let ticker = window.use_keyed_state(self.id.clone(), cx, |_, _| Ticker::default());
+        let delta = self.time - Local::now();
+        Ticker::schedule(
+            &ticker,
+            next_tick(delta.num_seconds()),
+            |_, _, _| {},
+            window,
+            cx,
+        );
+
+        let full_time: SharedString = self.time.format("%Y-%m-%d %H:%M:%S").to_string().into();
+        div()
+            .id(self.id)
+            .refine_style(&self.style)
+            .child(locale::format_relative_time(delta))
+            .when(self.tooltip, |this| {
+                this.tooltip(move |window, cx| Tooltip::new(full_time.clone()).build(window, cx))
+            })
+    }
+}
+
+/// Format the remaining duration of the [`Countdown`], e.g.: `05:09`, `1:02:03`.
+fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+/// A label of the remaining time to the deadline, updated every second, e.g.: `04:59`.
+///
+/// The [`Countdown::on_complete`] is called once when the deadline is reached.
+///
+/// ```ignore
+/// Countdown::new("resend", Local::now() + TimeDelta::seconds(60))
+///     .on_complete(|window, cx| { ... })
+/// ```
+#[derive(IntoElement)]
+pub struct Countdown {
+    id: ElementId,
+    deadline: DateTime<Local>,
+    on_complete: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    style: StyleRefinement,
+}
+
+impl Countdown {
+    pub fn new(id: impl Into<ElementId>, deadline: DateTime<Local>) -> Self {
+        Self {
+            id: id.into(),
+            deadline,
+            on_complete: None,
+            style: StyleRefinement::default(),
+        }
+    }
+
+    /// Set the callback when the deadline is reached, it is called once for each deadline.
+    pub fn on_complete(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_complete = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Styled for Countdown {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Countdown {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let ticker = window.use_keyed_state(self.id.clone(), cx, |_, _| Ticker::default());
+        let remaining = (self.deadline - Local::now()).to_std().unwrap_or_default();
+
+        if ticker.read(cx).completed != Some(self.deadline) {
+            // Tick at the next whole second of the remaining time.
+            let wait = match remaining.subsec_nanos() {
+                0 if remaining.is_zero() => Duration::ZERO,
+                0 => Duration::from_secs(1),
+                nanos => Duration::from_nanos(nanos as u64),
+            };
+            let deadline = self.deadline;
+            let on_complete = self.on_complete.clone();
+            Ticker::schedule(
+                &ticker,
+                wait,
+                move |ticker, window, cx| {
+                    if Local::now() < deadline || ticker.read(cx).completed == Some(deadline) {
+                        return;
+                    }
+                    ticker.update(cx, |ticker, _| ticker.completed = Some(deadline));
+                    if let Some(on_complete) = on_complete {
+                        on_complete(window, cx);
+                    }
+                },
+                window,
+                cx,
+            );
+        }
+
+        // Round up, to show `00:00` only when completed.
+        let remaining = if remaining.subsec_nanos() > 0 {
+            Duration::from_secs(remaining.as_secs() + 1)
+        } else {
+            remaining
+        };
+        div()
+            .id(self.id)
+            .refine_style(&self.style)
+            .child(format_countdown(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_countdown, next_tick};
+
+    #[test]
+    fn test_next_tick() {
+        let secs = Duration::from_secs;
+        assert_eq!(next_tick(0), secs(60));
+        assert_eq!(next_tick(-20), secs(40));
+        assert_eq!(next_tick(-150), secs(30));
+        assert_eq!(next_tick(-3600), secs(3600));
+        assert_eq!(next_tick(-5000), secs(2200));
+        assert_eq!(next_tick(20), secs(80));
+        assert_eq!(next_tick(150), secs(31));
+        assert_eq!(next_tick(7300), secs(101));
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::ZERO), "00:00");
+        assert_eq!(format_countdown(Duration::from_secs(309)), "05:09");
+        assert_eq!(format_countdown(Duration::from_secs(3723)), "1:02:03");
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
 trait NaiveDateExt {
     fn days_in_month(&self) -> i32;
@@ -28,68 +28,62 @@ impl NaiveDateExt for chrono::NaiveDate {
     }
 }
 
-pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
-    let mut year = year;
-    let mut month = month;
-    if month > 12 {
-        year += 1;
-        month = 1;
-    }
-    if month < 1 {
-        year -= 1;
-        month = 12;
-    }
+/// Returns the year and month (1-12) after adding the `months`, the `months` can be negative.
+pub(crate) fn add_months(year: i32, month: u32, months: i32) -> (i32, u32) {
+    let total = year * 12 + month as i32 - 1 + months;
+    (total.div_euclid(12), total.rem_euclid(12) as u32 + 1)
+}
 
+/// Returns the weeks of the month to render on calendar, each week starts with the `week_start`.
+///
+/// The days of the previous and next months are filled in the first and last weeks,
+/// at least 5 weeks are returned to keep the height of the calendar, or 6 weeks if the month needs.
+pub(crate) fn days_in_month(year: i32, month: u32, week_start: Weekday) -> Vec<Vec<NaiveDate>> {
+    let (year, month) = add_months(year, month, 0);
     let date = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
     let num_days = date.days_in_month();
-    let start_weekday = date.weekday().num_days_from_sunday();
+    let offset = date.weekday().days_since(week_start) as i32;
 
-    // Get the days in the month, 2023-02 will returns
+    // Get the days in the month, 2023-02 (week start is Sunday) will returns
     // "29|30|31| 1| 2| 3| 4",
     // " 5| 6| 7| 8| 9|10|11",
     // "12|13|14|15|16|17|18",
     // "19|20|21|22|23|24|25",
     // "26|27|28| 1| 2| 3| 4",
-    let mut days = vec![];
-    for n in 0..5 {
-        let mut week_days = vec![];
-        for weekday in 0..7 {
-            let (mut y, mut m) = (year, month);
-
-            // If the day is less than the start weekday, we need to go back to the previous month.
-            if n == 0 && weekday < start_weekday {
-                m = if m == 1 { 12 } else { m - 1 };
-                y = if m == 1 { year - 1 } else { y };
-            }
-
-            // If start_weekday is 3, and n is 0 and weekday is 3, then day is 1.
-            // If start_weekday is 3, and n is 1 and weekday is 4, then day is 9.
-            let day = n * 7 + weekday as i32 - start_weekday as i32;
-
-            // If the day is greater than the number of days in the month, we need to go to the next month.
-            if day > num_days {
-                m = if m == 12 { 1 } else { m + 1 };
-                y = if m == 1 { year + 1 } else { y };
-            }
-
-            #[allow(clippy::expect_fun_call)]
-            let date = date
-                .checked_add_signed(Duration::days(day as i64))
-                .expect(&format!("invalid date {}-{} days {}", y, m, day));
-            week_days.push(date);
-        }
-
-        days.push(week_days);
-    }
+    let num_weeks = ((offset + num_days) as f32 / 7.).ceil().max(5.) as i32;
+    let first = date - Duration::days(offset as i64);
+    (0..num_weeks)
+        .map(|n| {
+            (0..7)
+                .map(|weekday| first + Duration::days((n * 7 + weekday) as i64))
+                .collect()
+        })
+        .collect()
+}
 
-    days
+/// Returns the ISO week number of the week, the week contains 7 days starting by any weekday.
+///
+/// The 4th day of the week is always in the ISO week that contains the most days of the week.
+pub(crate) fn week_number(week: &[NaiveDate]) -> Option<u32> {
+    week.get(3).map(|date| date.iso_week().week())
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{Datelike, NaiveDate};
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    use super::{add_months, days_in_month, week_number, NaiveDateExt};
 
-    use super::{days_in_month, NaiveDateExt};
+    #[test]
+    fn test_add_months() {
+        assert_eq!(add_months(2024, 1, 0), (2024, 1));
+        assert_eq!(add_months(2024, 1, 1), (2024, 2));
+        assert_eq!(add_months(2024, 12, 1), (2025, 1));
+        assert_eq!(add_months(2024, 1, -1), (2023, 12));
+        assert_eq!(add_months(2024, 3, -15), (2022, 12));
+        assert_eq!(add_months(2024, 6, 12), (2025, 6));
+        assert_eq!(add_months(2024, 6, 30), (2026, 12));
+    }
 
     #[test]
     fn test_days_in_month() {
@@ -114,8 +108,8 @@ mod tests {
     #[test]
     fn test_days() {
         #[track_caller]
-        fn assert_case(date: NaiveDate, expected: Vec<&str>) {
-            let out = days_in_month(date.year(), date.month())
+        fn assert_case(date: NaiveDate, week_start: Weekday, expected: Vec<&str>) {
+            let out = days_in_month(date.year(), date.month(), week_start)
                 .iter()
                 .map(|week| {
                     week.iter()
@@ -138,6 +132,7 @@ mod tests {
 
         assert_case(
             NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+            Weekday::Sun,
             vec![
                 "7-28|7-29|7-30|7-31| 1| 2| 3",
                 " 4| 5| 6| 7| 8| 9|10",
@@ -148,6 +143,7 @@ mod tests {
         );
         assert_case(
             NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            Weekday::Sun,
             vec![
                 "2024-12-29|2024-12-30|2024-12-31| 1| 2| 3| 4",
                 " 5| 6| 7| 8| 9|10|11",
@@ -159,6 +155,7 @@ mod tests {
 
         assert_case(
             NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            Weekday::Sun,
             vec![
                 "1-28|1-29|1-30|1-31| 1| 2| 3",
                 " 4| 5| 6| 7| 8| 9|10",
@@ -169,6 +166,7 @@ mod tests {
         );
         assert_case(
             NaiveDate::from_ymd_opt(2023, 2, 20).unwrap(),
+            Weekday::Sun,
             vec![
                 "1-29|1-30|1-31| 1| 2| 3| 4",
                 " 5| 6| 7| 8| 9|10|11",
@@ -177,5 +175,52 @@ mod tests {
                 "26|27|28|3-1|3-2|3-3|3-4",
             ],
         );
+
+        assert_case(
+            NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+            Weekday::Mon,
+            vec![
+                "7-29|7-30|7-31| 1| 2| 3| 4",
+                " 5| 6| 7| 8| 9|10|11",
+                "12|13|14|15|16|17|18",
+                "19|20|21|22|23|24|25",
+                "26|27|28|29|30|31|9-1",
+            ],
+        );
+        // The 2024-03 starts on Friday, need 6 weeks.
+        assert_case(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            Weekday::Sun,
+            vec![
+                "2-25|2-26|2-27|2-28|2-29| 1| 2",
+                " 3| 4| 5| 6| 7| 8| 9",
+                "10|11|12|13|14|15|16",
+                "17|18|19|20|21|22|23",
+                "24|25|26|27|28|29|30",
+                "31|4-1|4-2|4-3|4-4|4-5|4-6",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_week_number() {
+        let week_number = |year, month, week_start| {
+            days_in_month(year, month, week_start)
+                .iter()
+                .filter_map(|week| week_number(week))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(week_number(2024, 8, Weekday::Mon), vec![31, 32, 33, 34, 35]);
+        assert_eq!(week_number(2024, 8, Weekday::Sun), vec![31, 32, 33, 34, 35]);
+        assert_eq!(week_number(2021, 1, Weekday::Mon), vec![53, 1, 2, 3, 4]);
+        assert_eq!(
+            week_number(2024, 12, Weekday::Mon),
+            vec![48, 49, 50, 51, 52, 1]
+        );
+        assert_eq!(
+            days_in_month(2024, 13, Weekday::Sun),
+            days_in_month(2025, 1, Weekday::Sun)
+        );
     }
 }
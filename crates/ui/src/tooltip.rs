@@ -1,9 +1,73 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
 use gpui::{
-    div, prelude::FluentBuilder, px, Action, AnyElement, AnyView, App, AppContext, Context,
-    IntoElement, ParentElement, Render, SharedString, StyleRefinement, Styled, Window,
+    canvas, deferred, div, prelude::FluentBuilder, px, Action, AnyElement, AnyView, App,
+    AppContext, Bounds, Context, Div, ElementId, Global, IntoElement, ParentElement, Pixels,
+    Render, SharedString, Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Task,
+    Window,
+};
+
+use crate::{
+    h_flex,
+    position::{Align, Position, Positioned},
+    text::Text,
+    ActiveTheme, Kbd, Placement, StyledExt,
 };
 
-use crate::{h_flex, text::Text, ActiveTheme, Kbd, StyledExt};
+pub(crate) fn init(cx: &mut App) {
+    cx.set_global(TooltipGlobal {
+        delay: TooltipDelay::default(),
+        last_active_at: None,
+    });
+}
+
+/// The delay policy of the tooltips, see [`Tooltip::set_delay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooltipDelay {
+    /// The delay before the tooltip is shown, default: 500ms.
+    pub show: Duration,
+    /// The delay before the tooltip is hidden after the mouse left, default: 0ms.
+    pub hide: Duration,
+    /// The tooltip will be shown immediately, if another tooltip is visible or has been hidden
+    /// within this duration, default: 300ms.
+    ///
+    /// This is shared across the elements, to avoid waiting again when moving along a toolbar.
+    pub warm_up: Duration,
+}
+
+impl Default for TooltipDelay {
+    fn default() -> Self {
+        Self {
+            show: Duration::from_millis(500),
+            hide: Duration::ZERO,
+            warm_up: Duration::from_millis(300),
+        }
+    }
+}
+
+struct TooltipGlobal {
+    delay: TooltipDelay,
+    /// The last time that a tooltip was visible.
+    last_active_at: Option<Instant>,
+}
+
+impl Global for TooltipGlobal {}
+
+impl TooltipGlobal {
+    fn show_delay(&self) -> Duration {
+        let is_warm = self
+            .last_active_at
+            .map_or(false, |at| at.elapsed() < self.delay.warm_up);
+        if is_warm {
+            Duration::ZERO
+        } else {
+            self.delay.show
+        }
+    }
+}
 
 enum TooltipContext {
     Text(Text),
@@ -60,6 +124,138 @@ impl Tooltip {
     pub fn build(self, _: &mut Window, cx: &mut App) -> AnyView {
         cx.new(|_| self).into()
     }
+
+    /// Set the delay policy of the tooltips in this crate (e.g.: [`crate::button::Button::tooltip`]).
+    pub fn set_delay(delay: TooltipDelay, cx: &mut App) {
+        cx.global_mut::<TooltipGlobal>().delay = delay;
+    }
+}
+
+type BuildTooltipFn = Rc<dyn Fn(&mut Window, &mut App) -> AnyView>;
+
+/// The state of the element with the managed tooltip, see [`managed_tooltip`].
+#[derive(Default)]
+struct TooltipTriggerState {
+    hovered: bool,
+    visible: bool,
+    bounds: Bounds<Pixels>,
+    view: Option<AnyView>,
+    _task: Option<Task<()>>,
+}
+
+impl TooltipTriggerState {
+    fn set_hovered(&mut self, hovered: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.hovered == hovered {
+            return;
+        }
+
+        self.hovered = hovered;
+        let global = cx.global_mut::<TooltipGlobal>();
+        let delay = if hovered {
+            global.show_delay()
+        } else {
+            if self.visible {
+                global.last_active_at = Some(Instant::now());
+            }
+            global.delay.hide
+        };
+
+        if delay.is_zero() {
+            self.set_visible(hovered, cx);
+            return;
+        }
+
+        self._task = Some(cx.spawn_in(window, async move |this, cx| {
+            cx.background_executor().timer(delay).await;
+            _ = this.update(cx, |this, cx| this.set_visible(hovered, cx));
+        }));
+    }
+
+    fn set_visible(&mut self, visible: bool, cx: &mut Context<Self>) {
+        self._task = None;
+        if self.visible == visible {
+            return;
+        }
+
+        self.visible = visible;
+        if !visible {
+            self.view = None;
+        }
+        cx.global_mut::<TooltipGlobal>().last_active_at = Some(Instant::now());
+        cx.notify();
+    }
+}
+
+/// Returns the position of the tooltip at the `placement` side, centered on the trigger.
+///
+/// The gap is in the margin of the [`Tooltip`].
+fn tooltip_position(placement: Placement) -> Position {
+    Position::new(placement).align(Align::Center).gap(px(0.))
+}
+
+/// Add a tooltip to the element, managed by this crate instead of GPUI:
+///
+/// - Follow the shared [`TooltipDelay`] policy.
+/// - Positioned at the `placement` side of the element, centered on the element, and flipped to
+///   the opposite side when there is not enough space.
+/// - Shown when the element is focused (e.g.: by the keyboard).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn managed_tooltip(
+    el: Stateful<Div>,
+    id: &ElementId,
+    placement: Placement,
+    is_focused: bool,
+    build: BuildTooltipFn,
+    on_hover: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Stateful<Div> {
+    let state = window.use_keyed_state(
+        ElementId::NamedChild(Box::new(id.clone()), "tooltip".into()),
+        cx,
+        |_, _| TooltipTriggerState::default(),
+    );
+
+    let (visible, bounds) = {
+        let state = state.read(cx);
+        (state.visible || is_focused, state.bounds)
+    };
+    let view = visible.then(|| {
+        state.update(cx, |state, cx| {
+            state.view.get_or_insert_with(|| build(window, cx)).clone()
+        })
+    });
+
+    el.on_hover({
+        let state = state.clone();
+        move |hovered, window, cx| {
+            state.update(cx, |state, cx| state.set_hovered(*hovered, window, cx));
+            if let Some(on_hover) = &on_hover {
+                on_hover(hovered, window, cx);
+            }
+        }
+    })
+    .child(
+        canvas(
+            {
+                let state = state.clone();
+                move |bounds, _, cx| state.update(cx, |state, _| state.bounds = bounds)
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full(),
+    )
+    .when_some(view, |this, view| {
+        this.child(
+            deferred(
+                Positioned::new("tooltip-position", bounds)
+                    .position(tooltip_position(placement))
+                    .child(view),
+            )
+            .with_priority(2),
+        )
+    })
 }
 
 impl FluentBuilder for Tooltip {}
@@ -120,3 +316,36 @@ impl Render for Tooltip {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::tooltip_position;
+    use crate::Placement;
+
+    #[test]
+    fn test_tooltip_position() {
+        let viewport = size(px(400.), px(300.));
+        let tooltip = size(px(60.), px(30.));
+
+        // Centered on the trigger.
+        let trigger = Bounds::new(point(px(100.), px(100.)), size(px(20.), px(20.)));
+        let resolved = tooltip_position(Placement::Top).resolve(trigger, tooltip, viewport);
+        assert_eq!(resolved.placement, Placement::Top);
+        assert_eq!(resolved.bounds.origin, point(px(80.), px(70.)));
+        let resolved = tooltip_position(Placement::Right).resolve(trigger, tooltip, viewport);
+        assert_eq!(resolved.bounds.origin, point(px(120.), px(95.)));
+
+        // Flipped to the opposite side at the window edges.
+        let trigger = Bounds::new(point(px(100.), px(10.)), size(px(20.), px(20.)));
+        let resolved = tooltip_position(Placement::Top).resolve(trigger, tooltip, viewport);
+        assert_eq!(resolved.placement, Placement::Bottom);
+        assert_eq!(resolved.bounds.origin, point(px(80.), px(30.)));
+
+        let trigger = Bounds::new(point(px(370.), px(100.)), size(px(20.), px(20.)));
+        let resolved = tooltip_position(Placement::Right).resolve(trigger, tooltip, viewport);
+        assert_eq!(resolved.placement, Placement::Left);
+        assert_eq!(resolved.bounds.origin, point(px(310.), px(95.)));
+    }
+}
@@ -0,0 +1,479 @@
+//! A guided tour to introduce the features step by step with the coach marks.
+//!
+//! ```ignore
+//! let tour = cx.new(|cx| {
+//!     Tour::new(cx)
+//!         .step(TourStep::new("search", "Search", "Find anything in the workspace."))
+//!         .step(TourStep::new("settings", "Settings", "Change the theme here.").placement(Placement::Left))
+//! });
+//!
+//! // Track the bounds of the targets in render.
+//! div().id("search").relative().children(tour.read(cx).target("search"));
+//!
+//! tour.update(cx, |tour, cx| tour.start(window, cx));
+//! ```
+use std::collections::HashMap;
+
+use gpui::{
+    div, point, prelude::FluentBuilder as _, px, size, App, Bounds, Context, Entity, EventEmitter,
+    FocusHandle, Focusable, InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _,
+    Pixels, Point, ScrollHandle, SharedString, Size, Styled as _, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::{Cancel, SelectLeft, SelectRight},
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    overlay::{DismissPolicy, Overlay, OverlayAnchor, OverlayId},
+    position::{Position, Positioned},
+    v_flex, ActiveTheme as _, ContextModal as _, Placement, Sizable as _, StyledExt as _,
+};
+
+const CONTEXT: &str = "Tour";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        KeyBinding::new("left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("right", SelectRight, Some(CONTEXT)),
+    ]);
+}
+
+/// The padding of the spotlight around the target.
+const SPOTLIGHT_PADDING: Pixels = px(4.);
+/// The z-index of the tour overlay, above the application overlays.
+const TOUR_Z_INDEX: usize = 10;
+
+/// Events emitted by the [`Tour`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TourEvent {
+    /// Moved to the step at the index.
+    Step(usize),
+    /// All the steps are finished.
+    Complete,
+    /// The tour is skipped at the step index.
+    Skip(usize),
+}
+
+/// A step of the [`Tour`], targeting an element registered by [`Tour::target`].
+#[derive(Clone)]
+pub struct TourStep {
+    target: SharedString,
+    title: SharedString,
+    description: SharedString,
+    placement: Placement,
+    scroll_handle: Option<ScrollHandle>,
+}
+
+impl TourStep {
+    pub fn new(
+        target: impl Into<SharedString>,
+        title: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            title: title.into(),
+            description: description.into(),
+            placement: Placement::Bottom,
+            scroll_handle: None,
+        }
+    }
+
+    /// Set the placement of the card to the target, default is bottom.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set the scroll container of the target, to scroll the target into view when the step is shown.
+    pub fn scroll_within(mut self, scroll_handle: &ScrollHandle) -> Self {
+        self.scroll_handle = Some(scroll_handle.clone());
+        self
+    }
+}
+
+/// A guided tour, renders a spotlight mask around the target of each step,
+/// with a card to go to the next or previous step, or skip the tour.
+///
+/// The tour is rendered in the overlay layer of the [`crate::Root`].
+pub struct Tour {
+    focus_handle: FocusHandle,
+    steps: Vec<TourStep>,
+    anchors: HashMap<SharedString, OverlayAnchor>,
+    current: Option<usize>,
+    overlay_id: Option<OverlayId>,
+}
+
+impl EventEmitter<TourEvent> for Tour {}
+
+impl Focusable for Tour {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Tour {
+    pub fn new(cx: &mut App) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            steps: vec![],
+            anchors: HashMap::new(),
+            current: None,
+            overlay_id: None,
+        }
+    }
+
+    /// Add a step.
+    pub fn step(mut self, step: TourStep) -> Self {
+        self.anchors.entry(step.target.clone()).or_default();
+        self.steps.push(step);
+        self
+    }
+
+    /// Returns an element to track the bounds of the target with the `id`,
+    /// it must be added into the `relative` target element.
+    ///
+    /// Returns None if no step targets the `id`.
+    pub fn target(&self, id: &str) -> Option<impl IntoElement> {
+        self.anchors.get(id).map(|anchor| anchor.track())
+    }
+
+    /// Returns the index of the current step, None if the tour is not started.
+    pub fn current_step(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns true if the tour is started.
+    pub fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Start the tour from the first step.
+    pub fn start(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.steps.is_empty() {
+            return;
+        }
+
+        if self.overlay_id.is_none() {
+            let tour = cx.entity();
+            let overlay = Overlay::new(move |window, cx| Self::render_overlay(&tour, window, cx))
+                .cover()
+                .z_index(TOUR_Z_INDEX)
+                .dismiss(DismissPolicy::Manual);
+            self.overlay_id = Some(window.open_overlay(overlay, cx));
+        }
+
+        self.focus_handle.focus(window);
+        self.go_to(0, window, cx);
+    }
+
+    /// Go to the next step, or complete the tour at the last step.
+    pub fn next(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(ix) = self.current else {
+            return;
+        };
+
+        if ix + 1 < self.steps.len() {
+            self.go_to(ix + 1, window, cx);
+        } else {
+            self.close(window, cx);
+            cx.emit(TourEvent::Complete);
+        }
+    }
+
+    /// Go to the previous step.
+    pub fn prev(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(ix) = self.current.filter(|ix| *ix > 0) {
+            self.go_to(ix - 1, window, cx);
+        }
+    }
+
+    /// Skip the rest steps of the tour.
+    pub fn skip(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(ix) = self.current else {
+            return;
+        };
+
+        self.close(window, cx);
+        cx.emit(TourEvent::Skip(ix));
+    }
+
+    fn go_to(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        self.current = Some(ix);
+        let step = &self.steps[ix];
+        if let (Some(scroll_handle), Some(anchor)) =
+            (step.scroll_handle.as_ref(), self.anchors.get(&step.target))
+        {
+            let offset = scroll_into_view(
+                scroll_handle.offset(),
+                anchor.bounds(),
+                scroll_handle.bounds(),
+            );
+            scroll_handle.set_offset(offset);
+        }
+
+        cx.emit(TourEvent::Step(ix));
+        cx.notify();
+        window.refresh();
+    }
+
+    fn close(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.current = None;
+        if let Some(id) = self.overlay_id.take() {
+            window.close_overlay(id, cx);
+        }
+        cx.notify();
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        self.skip(window, cx);
+    }
+
+    fn on_action_prev(&mut self, _: &SelectLeft, window: &mut Window, cx: &mut Context<Self>) {
+        self.prev(window, cx);
+    }
+
+    fn on_action_next(&mut self, _: &SelectRight, window: &mut Window, cx: &mut Context<Self>) {
+        self.next(window, cx);
+    }
+
+    fn listener<E: ?Sized>(
+        tour: &Entity<Self>,
+        f: impl Fn(&mut Self, &E, &mut Window, &mut Context<Self>) + 'static,
+    ) -> impl Fn(&E, &mut Window, &mut App) + 'static {
+        let tour = tour.clone();
+        move |ev, window, cx| tour.update(cx, |this, cx| f(this, ev, window, cx))
+    }
+
+    fn render_overlay(tour: &Entity<Self>, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let this = tour.read(cx);
+        let Some((ix, step)) = this.current.and_then(|ix| Some((ix, this.steps.get(ix)?))) else {
+            return div().into_any_element();
+        };
+
+        let total = this.steps.len();
+        let is_last = ix + 1 == total;
+        let target = this
+            .anchors
+            .get(&step.target)
+            .map(|anchor| anchor.bounds())
+            .filter(|bounds| bounds.size.width > px(0.) && bounds.size.height > px(0.))
+            .map(|bounds| bounds.dilate(SPOTLIGHT_PADDING));
+        let viewport = window.viewport_size();
+        let mask_color = cx.theme().overlay;
+
+        let card = v_flex()
+            .id("tour-card")
+            .track_focus(&this.focus_handle)
+            .key_context(CONTEXT)
+            .on_action(Self::listener(tour, Self::on_action_cancel))
+            .on_action(Self::listener(tour, Self::on_action_prev))
+            .on_action(Self::listener(tour, Self::on_action_next))
+            .occlude()
+            .w(px(320.))
+            .gap_2()
+            .p_4()
+            .bg(cx.theme().popover)
+            .text_color(cx.theme().popover_foreground)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius_lg)
+            .shadow_lg()
+            .child(div().font_semibold().child(step.title.clone()))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(step.description.clone()),
+            )
+            .child(
+                h_flex()
+                    .pt_2()
+                    .gap_2()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t!("Tour.step", current = ix + 1, total = total).to_string()),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .when(!is_last, |this| {
+                                this.child(
+                                    Button::new("skip")
+                                        .ghost()
+                                        .small()
+                                        .label(t!("Tour.skip"))
+                                        .on_click(Self::listener(tour, |this, _, window, cx| {
+                                            this.skip(window, cx)
+                                        })),
+                                )
+                            })
+                            .when(ix > 0, |this| {
+                                this.child(
+                                    Button::new("prev")
+                                        .outline()
+                                        .small()
+                                        .label(t!("Tour.prev"))
+                                        .on_click(Self::listener(tour, |this, _, window, cx| {
+                                            this.prev(window, cx)
+                                        })),
+                                )
+                            })
+                            .child(
+                                Button::new("next")
+                                    .primary()
+                                    .small()
+                                    .label(if is_last {
+                                        t!("Tour.done")
+                                    } else {
+                                        t!("Tour.next")
+                                    })
+                                    .on_click(Self::listener(tour, |this, _, window, cx| {
+                                        this.next(window, cx)
+                                    })),
+                            ),
+                    ),
+            );
+
+        let Some(target) = target else {
+            // The target is not visible, show the card at the center.
+            return div()
+                .size_full()
+                .occlude()
+                .bg(mask_color)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(card)
+                .into_any_element();
+        };
+
+        div()
+            .size_full()
+            .children(spotlight_mask(viewport, target).map(|bounds| {
+                div()
+                    .absolute()
+                    .left(bounds.left())
+                    .top(bounds.top())
+                    .w(bounds.size.width)
+                    .h(bounds.size.height)
+                    .occlude()
+                    .bg(mask_color)
+            }))
+            .child(
+                div()
+                    .absolute()
+                    .left(target.left())
+                    .top(target.top())
+                    .w(target.size.width)
+                    .h(target.size.height)
+                    .border_2()
+                    .border_color(cx.theme().primary)
+                    .rounded(cx.theme().radius),
+            )
+            .child(
+                Positioned::new("tour-card-position", target)
+                    .position(Position::new(step.placement).gap(px(8.)))
+                    .child(card),
+            )
+            .into_any_element()
+    }
+}
+
+/// Returns the bounds of the mask around the `target`, in the order of top, bottom, left, right.
+fn spotlight_mask(viewport: Size<Pixels>, target: Bounds<Pixels>) -> [Bounds<Pixels>; 4] {
+    let top = target.top().clamp(px(0.), viewport.height);
+    let bottom = target.bottom().clamp(top, viewport.height);
+    let left = target.left().clamp(px(0.), viewport.width);
+    let right = target.right().clamp(left, viewport.width);
+
+    [
+        Bounds::new(point(px(0.), px(0.)), size(viewport.width, top)),
+        Bounds::new(
+            point(px(0.), bottom),
+            size(viewport.width, viewport.height - bottom),
+        ),
+        Bounds::new(point(px(0.), top), size(left, bottom - top)),
+        Bounds::new(
+            point(right, top),
+            size(viewport.width - right, bottom - top),
+        ),
+    ]
+}
+
+/// Returns the scroll offset to make the `target` visible in the `viewport` of the scroll container.
+fn scroll_into_view(
+    offset: Point<Pixels>,
+    target: Bounds<Pixels>,
+    viewport: Bounds<Pixels>,
+) -> Point<Pixels> {
+    let mut offset = offset;
+    if target.top() < viewport.top() {
+        offset.y += viewport.top() - target.top();
+    } else if target.bottom() > viewport.bottom() {
+        offset.y -= (target.bottom() - viewport.bottom()).min(target.top() - viewport.top());
+    }
+    if target.left() < viewport.left() {
+        offset.x += viewport.left() - target.left();
+    } else if target.right() > viewport.right() {
+        offset.x -= (target.right() - viewport.right()).min(target.left() - viewport.left());
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::{scroll_into_view, spotlight_mask};
+
+    #[test]
+    fn test_spotlight_mask() {
+        let viewport = size(px(100.), px(100.));
+        let target = Bounds::new(point(px(10.), px(20.)), size(px(30.), px(40.)));
+        assert_eq!(
+            spotlight_mask(viewport, target),
+            [
+                Bounds::new(point(px(0.), px(0.)), size(px(100.), px(20.))),
+                Bounds::new(point(px(0.), px(60.)), size(px(100.), px(40.))),
+                Bounds::new(point(px(0.), px(20.)), size(px(10.), px(40.))),
+                Bounds::new(point(px(40.), px(20.)), size(px(60.), px(40.))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scroll_into_view() {
+        let viewport = Bounds::new(point(px(0.), px(100.)), size(px(200.), px(100.)));
+        let offset = point(px(0.), px(-50.));
+
+        // Already visible.
+        let target = Bounds::new(point(px(0.), px(120.)), size(px(50.), px(20.)));
+        assert_eq!(scroll_into_view(offset, target, viewport), offset);
+
+        // Above the viewport.
+        let target = Bounds::new(point(px(0.), px(80.)), size(px(50.), px(20.)));
+        assert_eq!(
+            scroll_into_view(offset, target, viewport),
+            point(px(0.), px(-30.))
+        );
+
+        // Below the viewport.
+        let target = Bounds::new(point(px(0.), px(250.)), size(px(50.), px(20.)));
+        assert_eq!(
+            scroll_into_view(offset, target, viewport),
+            point(px(0.), px(-120.))
+        );
+
+        // Taller than the viewport, align to the top.
+        let target = Bounds::new(point(px(0.), px(150.)), size(px(50.), px(300.)));
+        assert_eq!(
+            scroll_into_view(offset, target, viewport),
+            point(px(0.), px(-100.))
+        );
+    }
+}
@@ -0,0 +1,592 @@
+use std::time::Duration;
+
+use gpui::{
+    deferred, div, prelude::FluentBuilder as _, px, relative, Context, EventEmitter,
+    InteractiveElement as _, IntoElement, Keystroke, MouseButton, ParentElement, Pixels, Render,
+    SharedString, Styled, Task, Window,
+};
+use rust_i18n::t;
+use smol::Timer;
+
+use crate::{h_flex, v_flex, ActiveTheme, Icon, IconName};
+
+/// The action of a [`KeyboardKey`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyKind {
+    /// Insert the text, the letters will be uppercase when shift is on.
+    Char(SharedString),
+    Space,
+    Backspace,
+    Enter,
+    Tab,
+    Left,
+    Right,
+    /// Uppercase the next letter, long-press to toggle caps lock.
+    Shift,
+    /// Switch to the layout at the index of the [`VirtualKeyboard`] layouts.
+    Switch(usize),
+}
+
+/// A key on the [`VirtualKeyboard`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardKey {
+    pub kind: KeyKind,
+    /// The label of the key, default is the text of the char key.
+    pub label: Option<SharedString>,
+    /// The alternate characters shown on long-press.
+    pub alternates: Vec<SharedString>,
+    /// The relative width of the key, default is 1.
+    pub width: f32,
+}
+
+impl KeyboardKey {
+    pub fn new(kind: KeyKind) -> Self {
+        Self {
+            kind,
+            label: None,
+            alternates: vec![],
+            width: 1.,
+        }
+    }
+
+    /// Create a key to insert the text.
+    pub fn char(text: impl Into<SharedString>) -> Self {
+        Self::new(KeyKind::Char(text.into()))
+    }
+
+    /// Set the label of the key.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the alternate characters shown on long-press.
+    pub fn alternates(mut self, alternates: impl IntoIterator<Item = &'static str>) -> Self {
+        self.alternates = alternates.into_iter().map(SharedString::from).collect();
+        self
+    }
+
+    /// Set the relative width of the key, default is 1.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the text to insert by the given `text` and shift state.
+    fn text_for(text: &SharedString, shift: bool) -> SharedString {
+        if shift {
+            text.to_uppercase().into()
+        } else {
+            text.clone()
+        }
+    }
+}
+
+/// A layout of the [`VirtualKeyboard`], the rows of keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardLayout {
+    pub rows: Vec<Vec<KeyboardKey>>,
+}
+
+impl KeyboardLayout {
+    pub fn new(rows: Vec<Vec<KeyboardKey>>) -> Self {
+        Self { rows }
+    }
+
+    /// Create a layout of char keys, each char in the row string is a key.
+    ///
+    /// ```ignore
+    /// let layout = KeyboardLayout::from_rows(["123", "456", "789"]);
+    /// ```
+    pub fn from_rows<'a>(rows: impl IntoIterator<Item = &'a str>) -> Self {
+        Self::new(
+            rows.into_iter()
+                .map(|row| {
+                    row.chars()
+                        .map(|c| KeyboardKey::char(c.to_string()))
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Add a row of keys to the layout.
+    pub fn row(mut self, keys: impl IntoIterator<Item = KeyboardKey>) -> Self {
+        self.rows.push(keys.into_iter().collect());
+        self
+    }
+
+    /// Set the alternate characters of the char keys, the key is the text of the char key.
+    pub fn with_alternates(
+        mut self,
+        alternates: impl IntoIterator<Item = (&'static str, &'static [&'static str])>,
+    ) -> Self {
+        for (text, chars) in alternates {
+            for key in self.rows.iter_mut().flatten() {
+                if key.kind == KeyKind::Char(text.into()) {
+                    key.alternates = chars.iter().map(|c| SharedString::from(*c)).collect();
+                }
+            }
+        }
+        self
+    }
+
+    /// The QWERTY letters layout, press the `?123` key to switch to the layout at `symbols_ix`.
+    pub fn qwerty(symbols_ix: usize) -> Self {
+        Self::from_rows(["qwertyuiop", "asdfghjkl"])
+            .row(
+                [KeyboardKey::new(KeyKind::Shift).width(1.5)]
+                    .into_iter()
+                    .chain("zxcvbnm".chars().map(|c| KeyboardKey::char(c.to_string())))
+                    .chain([KeyboardKey::new(KeyKind::Backspace).width(1.5)]),
+            )
+            .row(Self::bottom_row("?123", symbols_ix))
+            .with_alternates([
+                ("a", &["à", "á", "â", "ä", "æ", "ã", "å"][..]),
+                ("c", &["ç"]),
+                ("e", &["è", "é", "ê", "ë"]),
+                ("i", &["ì", "í", "î", "ï"]),
+                ("n", &["ñ"]),
+                ("o", &["ò", "ó", "ô", "ö", "õ", "ø"]),
+                ("s", &["ß"]),
+                ("u", &["ù", "ú", "û", "ü"]),
+                ("y", &["ý", "ÿ"]),
+            ])
+    }
+
+    /// The numbers and symbols layout, press the `ABC` key to switch to the layout at `letters_ix`.
+    pub fn symbols(letters_ix: usize) -> Self {
+        Self::from_rows(["1234567890", "@#$%&-+()"])
+            .row(
+                "/*\"':;!?"
+                    .chars()
+                    .map(|c| KeyboardKey::char(c.to_string()))
+                    .chain([KeyboardKey::new(KeyKind::Backspace).width(2.)]),
+            )
+            .row(Self::bottom_row("ABC", letters_ix))
+            .with_alternates([
+                ("-", &["_", "–", "—"][..]),
+                ("$", &["€", "£", "¥", "¢"]),
+                ("(", &["[", "{", "<"]),
+                (")", &["]", "}", ">"]),
+            ])
+    }
+
+    /// The numeric keypad layout.
+    pub fn numeric() -> Self {
+        Self::from_rows(["789", "456", "123"]).row([
+            KeyboardKey::char("."),
+            KeyboardKey::char("0"),
+            KeyboardKey::new(KeyKind::Backspace),
+        ])
+    }
+
+    fn bottom_row(switch_label: &'static str, switch_ix: usize) -> Vec<KeyboardKey> {
+        vec![
+            KeyboardKey::new(KeyKind::Switch(switch_ix))
+                .label(switch_label)
+                .width(1.5),
+            KeyboardKey::char(","),
+            KeyboardKey::new(KeyKind::Space).width(5.),
+            KeyboardKey::char("."),
+            KeyboardKey::new(KeyKind::Enter).width(1.5),
+        ]
+    }
+
+    /// Returns the max total width of the rows.
+    fn width(&self) -> f32 {
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(|key| key.width).sum::<f32>())
+            .fold(0., f32::max)
+    }
+}
+
+/// Events emitted by the [`VirtualKeyboard`].
+#[derive(Debug, Clone)]
+pub enum VirtualKeyboardEvent {
+    /// A key is pressed, the `Char` contains the inserted text.
+    KeyPress(KeyKind),
+}
+
+/// An on-screen keyboard for touch devices, the keys are sent to the focused element,
+/// e.g. the focused [`crate::input::InputState`].
+///
+/// The keys will not take the focus, so the input keeps focused while typing.
+pub struct VirtualKeyboard {
+    layouts: Vec<KeyboardLayout>,
+    layout_ix: usize,
+    shift: bool,
+    caps_lock: bool,
+    key_height: Pixels,
+    long_press_delay: Duration,
+    /// The (row, col) of the pressed key.
+    pressed: Option<(usize, usize)>,
+    /// The (row, col) of the key that shows the alternates.
+    alternates_key: Option<(usize, usize)>,
+    _long_press_task: Task<()>,
+}
+
+impl VirtualKeyboard {
+    /// Create a QWERTY keyboard with a numbers and symbols layout.
+    pub fn new(_: &mut Window, _: &mut Context<Self>) -> Self {
+        Self {
+            layouts: vec![KeyboardLayout::qwerty(1), KeyboardLayout::symbols(0)],
+            layout_ix: 0,
+            shift: false,
+            caps_lock: false,
+            key_height: px(44.),
+            long_press_delay: Duration::from_millis(500),
+            pressed: None,
+            alternates_key: None,
+            _long_press_task: Task::ready(()),
+        }
+    }
+
+    /// Create a numeric keypad.
+    pub fn numeric(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self::new(window, cx).layouts(vec![KeyboardLayout::numeric()])
+    }
+
+    /// Set the layouts of the keyboard, the first layout is shown by default.
+    ///
+    /// Use [`KeyKind::Switch`] key to switch between the layouts.
+    pub fn layouts(mut self, layouts: Vec<KeyboardLayout>) -> Self {
+        self.layouts = layouts;
+        self.layout_ix = 0;
+        self
+    }
+
+    /// Set the height of the keys, default is 44px.
+    pub fn key_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.key_height = height.into();
+        self
+    }
+
+    /// Set the delay of long-press to show the alternate characters, default is 500ms.
+    pub fn long_press_delay(mut self, delay: Duration) -> Self {
+        self.long_press_delay = delay;
+        self
+    }
+
+    /// Returns the index of the current layout.
+    pub fn layout_ix(&self) -> usize {
+        self.layout_ix
+    }
+
+    /// Switch to the layout at the index.
+    pub fn set_layout(&mut self, ix: usize, cx: &mut Context<Self>) {
+        if ix < self.layouts.len() {
+            self.layout_ix = ix;
+            self.alternates_key = None;
+            cx.notify();
+        }
+    }
+
+    fn key(&self, (row, col): (usize, usize)) -> Option<&KeyboardKey> {
+        self.layouts
+            .get(self.layout_ix)
+            .and_then(|layout| layout.rows.get(row))
+            .and_then(|keys| keys.get(col))
+    }
+
+    fn is_shift(&self) -> bool {
+        self.shift || self.caps_lock
+    }
+
+    /// Send the key to the focused element.
+    fn send_key(&mut self, kind: KeyKind, window: &mut Window, cx: &mut Context<Self>) {
+        let keystroke = match &kind {
+            KeyKind::Char(text) => Keystroke {
+                key: text.to_string(),
+                key_char: Some(text.to_string()),
+                ..Default::default()
+            },
+            KeyKind::Space => Keystroke::parse("space").unwrap(),
+            KeyKind::Backspace => Keystroke::parse("backspace").unwrap(),
+            KeyKind::Enter => Keystroke::parse("enter").unwrap(),
+            KeyKind::Tab => Keystroke::parse("tab").unwrap(),
+            KeyKind::Left => Keystroke::parse("left").unwrap(),
+            KeyKind::Right => Keystroke::parse("right").unwrap(),
+            KeyKind::Shift => {
+                self.shift = !self.shift;
+                self.caps_lock = false;
+                cx.notify();
+                return;
+            }
+            KeyKind::Switch(ix) => {
+                self.set_layout(*ix, cx);
+                return;
+            }
+        };
+
+        window.dispatch_keystroke(keystroke, cx);
+        if matches!(kind, KeyKind::Char(_)) && self.shift {
+            self.shift = false;
+            cx.notify();
+        }
+        cx.emit(VirtualKeyboardEvent::KeyPress(kind));
+    }
+
+    fn on_key_down(&mut self, pos: (usize, usize), window: &mut Window, cx: &mut Context<Self>) {
+        // Avoid the key to take the focus from the input.
+        window.prevent_default();
+        self.pressed = Some(pos);
+        self.alternates_key = None;
+
+        let delay = self.long_press_delay;
+        self._long_press_task = cx.spawn(async move |this, cx| {
+            Timer::after(delay).await;
+            _ = this.update(cx, |this, cx| {
+                if this.pressed != Some(pos) {
+                    return;
+                }
+                let Some(key) = this.key(pos) else {
+                    return;
+                };
+
+                if key.kind == KeyKind::Shift {
+                    this.caps_lock = true;
+                    this.shift = false;
+                    this.pressed = None;
+                } else if !key.alternates.is_empty() {
+                    this.alternates_key = Some(pos);
+                }
+                cx.notify();
+            });
+        });
+        cx.notify();
+    }
+
+    fn on_key_up(&mut self, pos: (usize, usize), window: &mut Window, cx: &mut Context<Self>) {
+        if self.pressed != Some(pos) || self.alternates_key.is_some() {
+            return;
+        }
+
+        let shift = self.is_shift();
+        let Some(kind) = self.key(pos).map(|key| match &key.kind {
+            KeyKind::Char(text) => KeyKind::Char(KeyboardKey::text_for(text, shift)),
+            kind => kind.clone(),
+        }) else {
+            return;
+        };
+        self.send_key(kind, window, cx);
+    }
+
+    fn release(&mut self, cx: &mut Context<Self>) {
+        if self.pressed.is_some() {
+            self.pressed = None;
+            self._long_press_task = Task::ready(());
+            cx.notify();
+        }
+    }
+
+    fn render_alternates(&self, key: &KeyboardKey, cx: &mut Context<Self>) -> impl IntoElement {
+        let shift = self.is_shift();
+        deferred(
+            h_flex()
+                .id("alternates")
+                .occlude()
+                .absolute()
+                .bottom_full()
+                .left_0()
+                .mb_1()
+                .p_1()
+                .gap_1()
+                .bg(cx.theme().popover)
+                .border_1()
+                .border_color(cx.theme().border)
+                .rounded(cx.theme().radius)
+                .shadow_md()
+                .on_mouse_down_out(cx.listener(|this, _, _, cx| {
+                    this.alternates_key = None;
+                    cx.notify();
+                }))
+                .children(key.alternates.iter().enumerate().map(|(ix, text)| {
+                    let text = KeyboardKey::text_for(text, shift);
+                    div()
+                        .id(ix)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .min_w(self.key_height * 0.8)
+                        .h(self.key_height)
+                        .px_2()
+                        .rounded(cx.theme().radius)
+                        .hover(|this| {
+                            this.bg(cx.theme().accent)
+                                .text_color(cx.theme().accent_foreground)
+                        })
+                        .child(text.clone())
+                        .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                            window.prevent_default();
+                            cx.stop_propagation();
+                        })
+                        .on_mouse_up(
+                            MouseButton::Left,
+                            cx.listener(move |this, _, window, cx| {
+                                this.alternates_key = None;
+                                this.send_key(KeyKind::Char(text.clone()), window, cx);
+                                cx.notify();
+                            }),
+                        )
+                })),
+        )
+        .with_priority(1)
+    }
+
+    fn render_key(
+        &self,
+        pos: (usize, usize),
+        key: &KeyboardKey,
+        total_width: f32,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let shift = self.is_shift();
+        let active = self.pressed == Some(pos)
+            || (key.kind == KeyKind::Shift && shift)
+            || self.alternates_key == Some(pos);
+        let is_char = matches!(key.kind, KeyKind::Char(_));
+
+        let label = match (&key.label, &key.kind) {
+            (Some(label), _) => label.clone().into_any_element(),
+            (None, KeyKind::Char(text)) => KeyboardKey::text_for(text, shift).into_any_element(),
+            (None, KeyKind::Space) => {
+                SharedString::from(t!("VirtualKeyboard.Space")).into_any_element()
+            }
+            (None, KeyKind::Enter) => {
+                SharedString::from(t!("VirtualKeyboard.Enter")).into_any_element()
+            }
+            (None, KeyKind::Tab) => "Tab".into_any_element(),
+            (None, KeyKind::Backspace) => Icon::new(IconName::Delete).into_any_element(),
+            (None, KeyKind::Left) => Icon::new(IconName::ArrowLeft).into_any_element(),
+            (None, KeyKind::Right) => Icon::new(IconName::ArrowRight).into_any_element(),
+            (None, KeyKind::Shift) => Icon::new(IconName::ArrowUp)
+                .when(self.caps_lock, |this| this.text_color(cx.theme().primary))
+                .into_any_element(),
+            (None, KeyKind::Switch(_)) => "".into_any_element(),
+        };
+
+        div()
+            .relative()
+            .w(relative(key.width / total_width))
+            .h(self.key_height)
+            .p(px(2.))
+            .child(
+                div()
+                    .id(("key", pos.0 * 1000 + pos.1))
+                    .size_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .rounded(cx.theme().radius)
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .shadow_xs()
+                    .map(|this| {
+                        if active {
+                            this.bg(cx.theme().accent)
+                                .text_color(cx.theme().accent_foreground)
+                        } else if is_char {
+                            this.bg(cx.theme().background)
+                        } else {
+                            this.bg(cx.theme().secondary)
+                                .text_color(cx.theme().secondary_foreground)
+                        }
+                    })
+                    .when(!is_char, |this| this.text_sm())
+                    .child(label)
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |this, _, window, cx| {
+                            this.on_key_down(pos, window, cx);
+                        }),
+                    )
+                    .on_mouse_up(
+                        MouseButton::Left,
+                        cx.listener(move |this, _, window, cx| {
+                            this.on_key_up(pos, window, cx);
+                        }),
+                    ),
+            )
+            .when(self.alternates_key == Some(pos), |this| {
+                this.child(self.render_alternates(key, cx))
+            })
+    }
+}
+
+impl EventEmitter<VirtualKeyboardEvent> for VirtualKeyboard {}
+
+impl Render for VirtualKeyboard {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let Some(layout) = self.layouts.get(self.layout_ix) else {
+            return v_flex().id("virtual-keyboard");
+        };
+        let total_width = layout.width().max(1.);
+
+        v_flex()
+            .id("virtual-keyboard")
+            .w_full()
+            .p_1()
+            .bg(cx.theme().muted)
+            .text_color(cx.theme().foreground)
+            .on_mouse_down(MouseButton::Left, |_, window, _| {
+                // Avoid focus on mouse down.
+                window.prevent_default();
+            })
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _, _, cx| this.release(cx)),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _, _, cx| this.release(cx)),
+            )
+            .children(layout.rows.iter().enumerate().map(|(row_ix, keys)| {
+                h_flex().w_full().justify_center().children(
+                    keys.iter().enumerate().map(|(col_ix, key)| {
+                        self.render_key((row_ix, col_ix), key, total_width, cx)
+                    }),
+                )
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyKind, KeyboardKey, KeyboardLayout};
+
+    #[test]
+    fn test_layouts() {
+        let qwerty = KeyboardLayout::qwerty(1);
+        assert_eq!(qwerty.rows.len(), 4);
+        assert_eq!(qwerty.width(), 10.);
+        assert_eq!(qwerty.rows[2][0].kind, KeyKind::Shift);
+        assert_eq!(qwerty.rows[3][0].kind, KeyKind::Switch(1));
+        assert_eq!(qwerty.rows[1][0].alternates.len(), 7);
+
+        let symbols = KeyboardLayout::symbols(0);
+        assert_eq!(symbols.width(), 10.);
+        assert_eq!(symbols.rows[3][0].kind, KeyKind::Switch(0));
+
+        let numeric = KeyboardLayout::numeric();
+        assert_eq!(numeric.width(), 3.);
+        assert_eq!(numeric.rows[3][2].kind, KeyKind::Backspace);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let layout = KeyboardLayout::from_rows(["123", "456", "789"]);
+        assert_eq!(layout.rows.len(), 3);
+        assert_eq!(layout.width(), 3.);
+        assert_eq!(layout.rows[1][2].kind, KeyKind::Char("6".into()));
+    }
+
+    #[test]
+    fn test_text_for() {
+        assert_eq!(KeyboardKey::text_for(&"a".into(), true), "A");
+        assert_eq!(KeyboardKey::text_for(&"é".into(), true), "É");
+        assert_eq!(KeyboardKey::text_for(&"1".into(), true), "1");
+        assert_eq!(KeyboardKey::text_for(&"a".into(), false), "a");
+    }
+}
@@ -26,7 +26,7 @@ use gpui::{
 };
 use smallvec::SmallVec;
 
-use crate::{scroll::ScrollHandleOffsetable, AxisExt, PixelsExt};
+use crate::{debug_overlay::DebugOverlay, scroll::ScrollHandleOffsetable, AxisExt, PixelsExt};
 
 struct VirtualListScrollHandleState {
     axis: Axis,
@@ -325,6 +325,7 @@ impl Element for VirtualList {
         window: &mut Window,
         cx: &mut App,
     ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        let _span = tracing::trace_span!("request_layout", component = "VirtualList").entered();
         let rem_size = window.rem_size();
         let font_size = window.text_style().font_size.to_pixels(rem_size);
         let mut size_layout = ItemSizeLayout::default();
@@ -495,6 +496,7 @@ impl Element for VirtualList {
         window: &mut Window,
         cx: &mut App,
     ) -> Self::PrepaintState {
+        let _span = tracing::trace_span!("prepaint", component = "VirtualList").entered();
         layout.size_layout.last_layout_bounds = bounds;
 
         let style = self
@@ -701,6 +703,8 @@ impl Element for VirtualList {
         window: &mut Window,
         cx: &mut App,
     ) {
+        let _span = tracing::trace_span!("paint", component = "VirtualList").entered();
+        DebugOverlay::record("VirtualList", bounds, None, window, cx);
         self.base.interactivity().paint(
             global_id,
             inspector_id,
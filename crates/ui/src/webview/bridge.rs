@@ -0,0 +1,236 @@
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::{App, Window};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+/// The script to define the `window.bridge` of the page.
+///
+/// - `bridge.call(name, args, { timeout })` calls a function of the [`Bridge`], returns a promise
+///   resolved with the result, or rejected with the error of the function, the invalid arguments
+///   or the timeout (default 30 seconds, `0` to disable).
+/// - `bridge.on(event, callback)` subscribes the events emitted by [`super::WebView::emit`],
+///   returns a function to unsubscribe.
+pub(super) const INIT_SCRIPT: &str = r##"(function() {
+  if (!window.ipc || window.bridge) return;
+
+  let nextId = 1;
+  const pending = new Map();
+  const listeners = new Map();
+
+  function call(name, args, options) {
+    const timeout = options && options.timeout !== undefined ? options.timeout : 30000;
+    const id = nextId++;
+    return new Promise(function(resolve, reject) {
+      const timer = timeout > 0
+        ? setTimeout(function() {
+            pending.delete(id);
+            reject(new Error("The call of `" + name + "` is timed out after " + timeout + "ms."));
+          }, timeout)
+        : null;
+      pending.set(id, { resolve: resolve, reject: reject, timer: timer });
+      window.ipc.postMessage(JSON.stringify({
+        gpui: "bridge",
+        id: id,
+        name: name,
+        args: args === undefined ? null : args,
+      }));
+    });
+  }
+
+  function on(event, callback) {
+    if (!listeners.has(event)) listeners.set(event, new Set());
+    listeners.get(event).add(callback);
+    return function() { listeners.get(event).delete(callback); };
+  }
+
+  function __respond(id, ok, value) {
+    const call = pending.get(id);
+    if (!call) return;
+    pending.delete(id);
+    if (call.timer) clearTimeout(call.timer);
+    if (ok) {
+      call.resolve(value);
+    } else {
+      const error = new Error(value);
+      error.name = "BridgeError";
+      call.reject(error);
+    }
+  }
+
+  function __dispatch(event, payload) {
+    (listeners.get(event) || []).forEach(function(callback) {
+      try { callback(payload); } catch (e) { console.error(e); }
+    });
+  }
+
+  Object.defineProperty(window, "bridge", {
+    value: Object.freeze({ call: call, on: on, __respond: __respond, __dispatch: __dispatch }),
+  });
+})();"##;
+
+type BridgeFunc = dyn Fn(Value, &mut Window, &mut App) -> Result<Value, String>;
+
+/// The typed functions can be called by the page with `bridge.call(name, args)`,
+/// install it by [`super::WebViewHooks::bridge`].
+///
+/// The arguments and the result are converted by serde, the invalid arguments and the errors
+/// returned by the functions reject the promise with the error message.
+///
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct OpenFile { path: String }
+///
+/// let bridge = Bridge::new().func("open_file", |args: OpenFile, _, cx| {
+///     Ok(std::fs::read_to_string(args.path)?)
+/// });
+/// ```
+///
+/// ```js
+/// const content = await bridge.call("open_file", { path: "a.txt" });
+/// const unsubscribe = bridge.on("theme_changed", (theme) => applyTheme(theme));
+/// ```
+#[derive(Clone, Default)]
+pub struct Bridge {
+    funcs: HashMap<String, Rc<BridgeFunc>>,
+}
+
+impl Bridge {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a function with the `name`, replace the function if the name is registered.
+    pub fn func<A, R>(
+        mut self,
+        name: impl Into<String>,
+        f: impl Fn(A, &mut Window, &mut App) -> anyhow::Result<R> + 'static,
+    ) -> Self
+    where
+        A: DeserializeOwned,
+        R: Serialize,
+    {
+        let name = name.into();
+        let func_name = name.clone();
+        self.funcs.insert(
+            name,
+            Rc::new(move |args, window, cx| {
+                let args = decode_args(&func_name, args)?;
+                let result = f(args, window, cx).map_err(|err| err.to_string())?;
+                serde_json::to_value(result).map_err(|err| err.to_string())
+            }),
+        );
+        self
+    }
+
+    /// Returns true if no function is registered.
+    pub fn is_empty(&self) -> bool {
+        self.funcs.is_empty()
+    }
+
+    pub(super) fn call(&self, call: BridgeCall, window: &mut Window, cx: &mut App) -> String {
+        let result = match self.funcs.get(&call.name) {
+            Some(func) => func(call.args, window, cx),
+            None => Err(format!("The function `{}` is not registered.", call.name)),
+        };
+        respond_script(call.id, result)
+    }
+}
+
+fn decode_args<A: DeserializeOwned>(name: &str, args: Value) -> Result<A, String> {
+    serde_json::from_value(args).map_err(|err| format!("Invalid arguments of `{}`: {}", name, err))
+}
+
+/// A call of a [`Bridge`] function from the page.
+#[derive(Debug, Deserialize)]
+pub(super) struct BridgeCall {
+    id: u64,
+    name: String,
+    #[serde(default)]
+    args: Value,
+}
+
+/// Parse the IPC message posted by `bridge.call`.
+pub(super) fn parse_call(body: &str) -> Option<BridgeCall> {
+    #[derive(Deserialize)]
+    struct Message {
+        gpui: String,
+        #[serde(flatten)]
+        call: BridgeCall,
+    }
+
+    serde_json::from_str::<Message>(body)
+        .ok()
+        .filter(|message| message.gpui == "bridge")
+        .map(|message| message.call)
+}
+
+/// Returns the script to resolve or reject the call of the `id`.
+fn respond_script(id: u64, result: Result<Value, String>) -> String {
+    let (ok, value) = match result {
+        Ok(value) => (true, value),
+        Err(err) => (false, Value::String(err)),
+    };
+    format!(
+        "window.bridge && window.bridge.__respond({}, {}, {});",
+        id, ok, value
+    )
+}
+
+/// Returns the script to dispatch the `event` to the listeners of `bridge.on`.
+pub(super) fn dispatch_script(event: &str, payload: &impl Serialize) -> serde_json::Result<String> {
+    Ok(format!(
+        "window.bridge && window.bridge.__dispatch({}, {});",
+        serde_json::to_string(event)?,
+        serde_json::to_string(payload)?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::{decode_args, dispatch_script, parse_call, respond_script};
+
+    #[test]
+    fn test_parse_call() {
+        let call =
+            parse_call(r#"{"gpui":"bridge","id":3,"name":"open","args":{"path":"a"}}"#).unwrap();
+        assert_eq!(call.id, 3);
+        assert_eq!(call.name, "open");
+        assert_eq!(call.args, json!({"path": "a"}));
+
+        assert!(parse_call(r#"{"gpui":"console","id":1,"name":"a"}"#).is_none());
+        assert!(parse_call("{}").is_none());
+    }
+
+    #[test]
+    fn test_decode_args() {
+        #[derive(Debug, Deserialize)]
+        struct Args {
+            #[allow(unused)]
+            path: String,
+        }
+
+        assert!(decode_args::<Args>("open", json!({"path": "a"})).is_ok());
+        let err = decode_args::<Args>("open", json!({"path": 1})).unwrap_err();
+        assert!(err.starts_with("Invalid arguments of `open`: invalid type"));
+    }
+
+    #[test]
+    fn test_scripts() {
+        assert_eq!(
+            respond_script(1, Ok(json!({"a": 1}))),
+            r#"window.bridge && window.bridge.__respond(1, true, {"a":1});"#
+        );
+        assert_eq!(
+            respond_script(2, Err("Not \"found\"".into())),
+            r#"window.bridge && window.bridge.__respond(2, false, "Not \"found\"");"#
+        );
+        assert_eq!(
+            dispatch_script("theme", &"dark").unwrap(),
+            r#"window.bridge && window.bridge.__dispatch("theme", "dark");"#
+        );
+    }
+}
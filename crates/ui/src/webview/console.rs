@@ -0,0 +1,471 @@
+use std::collections::{HashSet, VecDeque};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Axis, Context, Entity, InteractiveElement,
+    IntoElement, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _,
+    Styled as _, Subscription, Window,
+};
+use rust_i18n::t;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    log_view::LogLevel,
+    v_flex, ActiveTheme as _, Icon, IconName, Selectable as _, Sizable as _, StyledExt as _,
+};
+
+use super::{WebView, WebViewEvent};
+
+/// The script to forward the `console` messages and the uncaught errors of the page by the IPC.
+pub(super) const INIT_SCRIPT: &str = r##"(function() {
+  if (!window.ipc) return;
+
+  function serialize(value) {
+    if (value === undefined) return "undefined";
+    if (typeof value === "function" || typeof value === "symbol" || typeof value === "bigint") {
+      return String(value);
+    }
+    if (value instanceof Error) return value.stack || String(value);
+    const seen = new WeakSet();
+    try {
+      return JSON.parse(JSON.stringify(value, function(_, v) {
+        if (typeof v === "object" && v !== null) {
+          if (seen.has(v)) return "[Circular]";
+          seen.add(v);
+        }
+        if (v === undefined) return "undefined";
+        if (typeof v === "function" || typeof v === "bigint") return String(v);
+        return v;
+      }));
+    } catch (_) {
+      return String(value);
+    }
+  }
+
+  function post(level, args) {
+    try {
+      window.ipc.postMessage(JSON.stringify({
+        gpui: "console",
+        level: level,
+        url: location.href,
+        args: Array.prototype.map.call(args, serialize),
+      }));
+    } catch (_) {}
+  }
+
+  ["log", "debug", "info", "warn", "error", "trace"].forEach(function(level) {
+    const native = console[level];
+    console[level] = function() {
+      post(level, arguments);
+      return native.apply(console, arguments);
+    };
+  });
+
+  window.addEventListener("error", function(event) {
+    post("error", [event.error || event.message]);
+  });
+  window.addEventListener("unhandledrejection", function(event) {
+    post("error", ["Uncaught (in promise)", event.reason]);
+  });
+})();"##;
+
+/// A `console` message (e.g.: `console.log`) or an uncaught error of the page,
+/// see [`super::WebViewHooks::capture_console`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsoleMessageEvent {
+    pub level: LogLevel,
+    /// The URL of the page.
+    pub url: String,
+    /// The arguments serialized as JSON, the values can't be serialized are strings.
+    pub args: Vec<Value>,
+}
+
+impl ConsoleMessageEvent {
+    /// Returns the arguments joined by spaces like the browser console,
+    /// the strings are not quoted.
+    pub fn message(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| match arg {
+                Value::String(s) => s.clone(),
+                arg => arg.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Deserialize)]
+struct IpcConsoleMessage {
+    gpui: String,
+    level: String,
+    url: String,
+    #[serde(default)]
+    args: Vec<Value>,
+}
+
+/// Parse the IPC message posted by the [`INIT_SCRIPT`].
+pub(super) fn parse_message(body: &str) -> Option<ConsoleMessageEvent> {
+    let message: IpcConsoleMessage = serde_json::from_str(body).ok()?;
+    if message.gpui != "console" {
+        return None;
+    }
+
+    let level = match message.level.as_str() {
+        "trace" => LogLevel::Trace,
+        "debug" => LogLevel::Debug,
+        "warn" => LogLevel::Warn,
+        "error" => LogLevel::Error,
+        _ => LogLevel::Info,
+    };
+    Some(ConsoleMessageEvent {
+        level,
+        url: message.url,
+        args: message.args,
+    })
+}
+
+/// Returns the one line preview of the object or array `value`, e.g.: `{a: 1, b: {…}}`.
+fn preview(value: &Value) -> String {
+    let item = |value: &Value| match value {
+        Value::Object(_) => "{…}".to_string(),
+        Value::Array(items) => format!("Array({})", items.len()),
+        value => value.to_string(),
+    };
+
+    match value {
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, value)| format!("{}: {}", key, item(value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Array(items) => format!(
+            "({}) [{}]",
+            items.len(),
+            items.iter().map(item).collect::<Vec<_>>().join(", ")
+        ),
+        value => value.to_string(),
+    }
+}
+
+struct ConsoleItem {
+    id: usize,
+    message: ConsoleMessageEvent,
+}
+
+/// A console panel to show the [`ConsoleMessageEvent`]s of the [`WebView`]s,
+/// filtered by the minimum level, the object and array arguments can be expanded to inspect.
+///
+/// ```ignore
+/// let console = cx.new(|cx| {
+///     let mut console = ConsolePanel::new();
+///     console.track(&webview, cx);
+///     console
+/// });
+/// ```
+pub struct ConsolePanel {
+    items: VecDeque<ConsoleItem>,
+    next_id: usize,
+    capacity: usize,
+    min_level: LogLevel,
+    /// The paths (`<id>/<key>/...`) of the expanded values.
+    expanded: HashSet<String>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Default for ConsolePanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConsolePanel {
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+            next_id: 0,
+            capacity: 1000,
+            min_level: LogLevel::Trace,
+            expanded: HashSet::new(),
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Set the maximum number of the messages to keep, default is 1000.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Track the console messages of the `webview`.
+    pub fn track(&mut self, webview: &Entity<WebView>, cx: &mut Context<Self>) {
+        self._subscriptions
+            .push(cx.subscribe(webview, |this, _, event: &WebViewEvent, cx| {
+                if let WebViewEvent::ConsoleMessage(message) = event {
+                    this.push(message.clone(), cx);
+                }
+            }));
+    }
+
+    /// Append a message, the oldest messages are dropped over the capacity.
+    pub fn push(&mut self, message: ConsoleMessageEvent, cx: &mut Context<Self>) {
+        self.items.push_back(ConsoleItem {
+            id: self.next_id,
+            message,
+        });
+        self.next_id += 1;
+        while self.items.len() > self.capacity {
+            if let Some(item) = self.items.pop_front() {
+                let prefix = format!("{}/", item.id);
+                self.expanded.retain(|path| !path.starts_with(&prefix));
+            }
+        }
+        cx.notify();
+    }
+
+    /// Returns the messages, the oldest is the first.
+    pub fn messages(&self) -> impl Iterator<Item = &ConsoleMessageEvent> {
+        self.items.iter().map(|item| &item.message)
+    }
+
+    /// Remove all the messages.
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.items.clear();
+        self.expanded.clear();
+        cx.notify();
+    }
+
+    /// Only show the messages with the `level` or higher, default is [`LogLevel::Trace`] to show all.
+    pub fn set_min_level(&mut self, level: LogLevel, cx: &mut Context<Self>) {
+        self.min_level = level;
+        cx.notify();
+    }
+
+    pub fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn toggle_expanded(&mut self, path: &str, cx: &mut Context<Self>) {
+        if !self.expanded.remove(path) {
+            self.expanded.insert(path.to_string());
+        }
+        cx.notify();
+    }
+
+    fn render_value(
+        &self,
+        key: Option<&str>,
+        value: &Value,
+        path: String,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let key = key.map(|key| {
+            div()
+                .flex_shrink_0()
+                .text_color(cx.theme().primary)
+                .child(format!("{}:", key))
+        });
+
+        let entries: Vec<(String, &Value)> = match value {
+            Value::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(ix, v)| (ix.to_string(), v))
+                .collect(),
+            value => {
+                let color = match value {
+                    Value::String(_) => cx.theme().success,
+                    Value::Number(_) | Value::Bool(_) => cx.theme().info,
+                    _ => cx.theme().muted_foreground,
+                };
+                return h_flex()
+                    .gap_1()
+                    .children(key)
+                    .child(div().text_color(color).child(value.to_string()))
+                    .into_any_element();
+            }
+        };
+
+        let expanded = self.expanded.contains(&path);
+        v_flex()
+            .child(
+                h_flex()
+                    .id(SharedString::from(path.clone()))
+                    .gap_1()
+                    .cursor_pointer()
+                    .child(
+                        Icon::new(if expanded {
+                            IconName::ChevronDown
+                        } else {
+                            IconName::ChevronRight
+                        })
+                        .xsmall()
+                        .text_color(cx.theme().muted_foreground),
+                    )
+                    .children(key)
+                    .child(div().truncate().child(preview(value)))
+                    .on_click(cx.listener({
+                        let path = path.clone();
+                        move |this, _, _, cx| this.toggle_expanded(&path, cx)
+                    })),
+            )
+            .when(expanded, |this| {
+                this.child(
+                    v_flex()
+                        .pl_4()
+                        .children(entries.into_iter().map(|(key, value)| {
+                            let path = format!("{}/{}", path, key);
+                            self.render_value(Some(&key), value, path, cx)
+                        })),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn render_item(&self, item: &ConsoleItem, cx: &mut Context<Self>) -> impl IntoElement {
+        let level = item.message.level;
+        let (icon, bg) = match level {
+            LogLevel::Error => (
+                Some(IconName::CircleX),
+                Some(cx.theme().danger.opacity(0.1)),
+            ),
+            LogLevel::Warn => (
+                Some(IconName::TriangleAlert),
+                Some(cx.theme().warning.opacity(0.1)),
+            ),
+            _ => (None, None),
+        };
+
+        h_flex()
+            .items_start()
+            .gap_2()
+            .px_2()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .when_some(bg, |this, bg| this.bg(bg))
+            .child(
+                div().flex_shrink_0().w(px(14.)).pt_0p5().children(
+                    icon.map(|icon| Icon::new(icon).xsmall().text_color(level.color(cx))),
+                ),
+            )
+            .child(
+                h_flex()
+                    .flex_1()
+                    .flex_wrap()
+                    .items_start()
+                    .gap_x_2()
+                    .overflow_hidden()
+                    .when(level >= LogLevel::Warn, |this| {
+                        this.text_color(level.color(cx))
+                    })
+                    .children(
+                        item.message
+                            .args
+                            .iter()
+                            .enumerate()
+                            .map(|(ix, arg)| match arg {
+                                Value::String(s) => div().child(s.clone()).into_any_element(),
+                                arg => {
+                                    self.render_value(None, arg, format!("{}/{}", item.id, ix), cx)
+                                }
+                            }),
+                    ),
+            )
+    }
+}
+
+impl Render for ConsolePanel {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let levels = [
+            (LogLevel::Trace, t!("WebView.console_all")),
+            (LogLevel::Info, t!("WebView.console_info")),
+            (LogLevel::Warn, t!("WebView.console_warnings")),
+            (LogLevel::Error, t!("WebView.console_errors")),
+        ];
+
+        v_flex()
+            .size_full()
+            .child(
+                h_flex()
+                    .p_1()
+                    .gap_1()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .children(levels.into_iter().map(|(level, label)| {
+                        Button::new(level.as_str())
+                            .ghost()
+                            .xsmall()
+                            .label(label)
+                            .selected(self.min_level == level)
+                            .on_click(
+                                cx.listener(move |this, _, _, cx| this.set_min_level(level, cx)),
+                            )
+                    }))
+                    .child(div().flex_1())
+                    .child(
+                        Button::new("clear")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::Delete)
+                            .tooltip(t!("WebView.console_clear"))
+                            .on_click(cx.listener(|this, _, _, cx| this.clear(cx))),
+                    ),
+            )
+            .child(
+                div().flex_1().overflow_hidden().child(
+                    v_flex()
+                        .font_family("Menlo, Monaco, Consolas, monospace")
+                        .text_xs()
+                        .children(
+                            self.items
+                                .iter()
+                                .filter(|item| item.message.level >= self.min_level)
+                                .map(|item| self.render_item(item, cx))
+                                .collect::<Vec<_>>(),
+                        )
+                        .scrollable(Axis::Vertical),
+                ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{parse_message, preview};
+    use crate::log_view::LogLevel;
+
+    #[test]
+    fn test_parse_message() {
+        let message = parse_message(
+            r#"{"gpui":"console","level":"warn","url":"https://a.com","args":["count",1,{"a":true}]}"#,
+        )
+        .unwrap();
+        assert_eq!(message.level, LogLevel::Warn);
+        assert_eq!(message.url, "https://a.com");
+        assert_eq!(message.message(), r#"count 1 {"a":true}"#);
+
+        let message =
+            parse_message(r#"{"gpui":"console","level":"log","url":"","args":[]}"#).unwrap();
+        assert_eq!(message.level, LogLevel::Info);
+        assert!(parse_message(r#"{"gpui":"other","level":"log","url":""}"#).is_none());
+        assert!(parse_message("hello").is_none());
+    }
+
+    #[test]
+    fn test_preview() {
+        assert_eq!(
+            preview(&json!({"a": 1, "b": {"c": 2}, "d": [1, 2]})),
+            r#"{a: 1, b: {…}, d: Array(2)}"#
+        );
+        assert_eq!(preview(&json!([1, "x", null])), r#"(3) [1, "x", null]"#);
+        assert_eq!(preview(&json!(true)), "true");
+    }
+}
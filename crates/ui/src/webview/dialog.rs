@@ -0,0 +1,470 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use gpui::{App, AppContext as _, ParentElement as _, SharedString, Styled as _, Window};
+use rust_i18n::t;
+use serde::Deserialize;
+use wry::{
+    http::{header, Request, Response, StatusCode},
+    RequestAsyncResponder,
+};
+
+use crate::{
+    input::{InputState, TextInput},
+    modal::ModalButtonProps,
+    v_flex, ContextModal as _,
+};
+
+/// The custom scheme to route the dialogs and the permission requests from the page.
+pub(super) const SCHEME: &str = "gpui-webview";
+
+/// The script to override the JavaScript dialogs and the permission requests of the page,
+/// the dialogs use a synchronous request to wait the answer, if the request is failed
+/// (e.g.: blocked by the page's Content Security Policy), fallback to the native ones.
+const INIT_SCRIPT: &str = r##"(function() {
+  const base = "__BASE__";
+
+  function ask(kind, params) {
+    const xhr = new XMLHttpRequest();
+    xhr.open("POST", base + "dialog/" + kind, false);
+    xhr.setRequestHeader("Content-Type", "text/plain");
+    xhr.send(JSON.stringify(Object.assign({ url: location.href }, params)));
+    if (xhr.status !== 200) throw new Error("The dialog is not handled.");
+    return JSON.parse(xhr.responseText).value;
+  }
+
+  const nativeAlert = window.alert;
+  const nativeConfirm = window.confirm;
+  const nativePrompt = window.prompt;
+  const text = (value) => (value === undefined || value === null ? "" : String(value));
+
+  window.alert = function(message) {
+    try { ask("alert", { message: text(message) }); } catch (_) { nativeAlert.call(window, message); }
+  };
+  window.confirm = function(message) {
+    try { return ask("confirm", { message: text(message) }) !== null; }
+    catch (_) { return nativeConfirm.call(window, message); }
+  };
+  window.prompt = function(message, defaultValue) {
+    try {
+      return ask("prompt", {
+        message: text(message),
+        default_value: defaultValue === undefined ? null : text(defaultValue),
+      });
+    } catch (_) { return nativePrompt.call(window, message, defaultValue); }
+  };
+
+  async function permit(kind) {
+    try {
+      const response = await fetch(base + "permission/" + kind, {
+        method: "POST",
+        headers: { "Content-Type": "text/plain" },
+        body: JSON.stringify({ url: location.href }),
+      });
+      if (!response.ok) return true;
+      return (await response.json()).value !== null;
+    } catch (_) {
+      // Let the native permission flow to decide.
+      return true;
+    }
+  }
+  const denied = () => new DOMException("Permission denied", "NotAllowedError");
+
+  if (navigator.mediaDevices && navigator.mediaDevices.getUserMedia) {
+    const getUserMedia = navigator.mediaDevices.getUserMedia.bind(navigator.mediaDevices);
+    navigator.mediaDevices.getUserMedia = async function(constraints) {
+      if (constraints && constraints.video && !(await permit("camera"))) throw denied();
+      if (constraints && constraints.audio && !(await permit("microphone"))) throw denied();
+      return getUserMedia(constraints);
+    };
+  }
+
+  if (navigator.geolocation) {
+    const getCurrentPosition = navigator.geolocation.getCurrentPosition.bind(navigator.geolocation);
+    navigator.geolocation.getCurrentPosition = function(success, error, options) {
+      permit("geolocation").then((allowed) => {
+        if (allowed) {
+          getCurrentPosition(success, error, options);
+        } else if (error) {
+          error({ code: 1, message: "User denied Geolocation", PERMISSION_DENIED: 1 });
+        }
+      });
+    };
+  }
+
+  if (window.Notification && Notification.requestPermission) {
+    const requestPermission = Notification.requestPermission.bind(Notification);
+    Notification.requestPermission = async function(callback) {
+      const result = (await permit("notifications")) ? await requestPermission() : "denied";
+      if (callback) callback(result);
+      return result;
+    };
+  }
+})();"##;
+
+pub(super) fn init_script() -> String {
+    // https://docs.rs/wry/latest/wry/struct.WebViewBuilder.html#method.with_custom_protocol
+    let base = if cfg!(any(target_os = "windows", target_os = "android")) {
+        format!("http://{}.localhost/", SCHEME)
+    } else {
+        format!("{}://localhost/", SCHEME)
+    };
+    INIT_SCRIPT.replace("__BASE__", &base)
+}
+
+/// The kind of a [`JsDialog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsDialogKind {
+    Alert,
+    Confirm,
+    Prompt,
+}
+
+/// A JavaScript `alert`, `confirm` or `prompt` dialog opened by the page.
+///
+/// The `beforeunload` dialogs are not routed, they are always the native ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsDialog {
+    pub kind: JsDialogKind,
+    /// The URL of the page.
+    pub url: String,
+    pub message: String,
+    /// The default value of the `prompt`.
+    pub default_value: Option<String>,
+}
+
+/// The kind of a [`PermissionRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionKind {
+    Camera,
+    Microphone,
+    Geolocation,
+    Notifications,
+}
+
+/// A permission requested by the page.
+///
+/// When approved, the page continues to request the permission from the platform,
+/// the platform may still ask the user by a native prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionRequest {
+    pub kind: PermissionKind,
+    /// The URL of the page.
+    pub url: String,
+}
+
+pub(super) enum PageRequest {
+    Dialog(JsDialog),
+    Permission(PermissionRequest),
+}
+
+#[derive(Deserialize)]
+struct RequestBody {
+    url: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    default_value: Option<String>,
+}
+
+/// Parse the request of the [`SCHEME`], the path is `/dialog/<kind>` or `/permission/<kind>`.
+pub(super) fn parse_request(path: &str, body: &[u8]) -> Option<PageRequest> {
+    let body: RequestBody = serde_json::from_slice(body).ok()?;
+    let (category, kind) = path.trim_start_matches('/').split_once('/')?;
+
+    match category {
+        "dialog" => Some(PageRequest::Dialog(JsDialog {
+            kind: match kind {
+                "alert" => JsDialogKind::Alert,
+                "confirm" => JsDialogKind::Confirm,
+                "prompt" => JsDialogKind::Prompt,
+                _ => return None,
+            },
+            url: body.url,
+            message: body.message,
+            default_value: body.default_value,
+        })),
+        "permission" => Some(PageRequest::Permission(PermissionRequest {
+            kind: match kind {
+                "camera" => PermissionKind::Camera,
+                "microphone" => PermissionKind::Microphone,
+                "geolocation" => PermissionKind::Geolocation,
+                "notifications" => PermissionKind::Notifications,
+                _ => return None,
+            },
+            url: body.url,
+        })),
+        _ => None,
+    }
+}
+
+pub(super) fn parse(request: &Request<Vec<u8>>) -> Option<PageRequest> {
+    parse_request(request.uri().path(), request.body())
+}
+
+pub(super) fn respond(responder: RequestAsyncResponder, status: StatusCode, body: String) {
+    let mut response = Response::new(Cow::Owned(body.into_bytes()));
+    *response.status_mut() = status;
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+        header::HeaderValue::from_static("*"),
+    );
+    responder.respond(response);
+}
+
+/// The native webview is hidden while the dialogs are open,
+/// since it is always above the GPUI content (e.g.: the modals).
+#[derive(Clone)]
+pub(super) struct DialogGuard {
+    pub(super) webview: Rc<wry::WebView>,
+    pub(super) visible: Rc<Cell<bool>>,
+    pub(super) open_count: Rc<Cell<usize>>,
+}
+
+impl DialogGuard {
+    pub(super) fn open(&self) {
+        self.open_count.set(self.open_count.get() + 1);
+        _ = self.webview.set_visible(false);
+    }
+
+    fn close(&self) {
+        self.open_count.set(self.open_count.get().saturating_sub(1));
+        if self.open_count.get() == 0 && self.visible.get() {
+            _ = self.webview.set_visible(true);
+        }
+    }
+}
+
+/// The responder of a [`JsDialog`] or a [`PermissionRequest`].
+///
+/// If dropped without responding, it responds as canceled (denied).
+pub struct DialogResponder {
+    responder: Option<RequestAsyncResponder>,
+    guard: DialogGuard,
+}
+
+impl DialogResponder {
+    pub(super) fn new(responder: RequestAsyncResponder, guard: DialogGuard) -> Self {
+        guard.open();
+        Self {
+            responder: Some(responder),
+            guard,
+        }
+    }
+
+    /// Respond the `value` to the page, None to cancel (deny).
+    ///
+    /// - Alert: the value is ignored.
+    /// - Confirm and permission: Some to confirm (approve).
+    /// - Prompt: the input value.
+    pub fn respond(mut self, value: Option<String>) {
+        self.respond_value(value);
+    }
+
+    fn respond_value(&mut self, value: Option<String>) {
+        let Some(responder) = self.responder.take() else {
+            return;
+        };
+        let body = serde_json::json!({ "value": value }).to_string();
+        respond(responder, StatusCode::OK, body);
+        self.guard.close();
+    }
+}
+
+impl Drop for DialogResponder {
+    fn drop(&mut self) {
+        self.respond_value(None);
+    }
+}
+
+/// Returns the host of the `url`, or the `url` if no host.
+fn host(url: &str) -> &str {
+    url.split_once("://")
+        .map(|(_, rest)| rest)
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .filter(|host| !host.is_empty())
+        .unwrap_or(url)
+}
+
+/// The handler of the JavaScript dialogs and the permission requests from the page,
+/// see [`super::WebViewHooks::dialog_handler`].
+///
+/// The default implementation opens the modals of the [`crate::Root`].
+pub trait DialogHandler: 'static {
+    fn on_dialog(
+        &self,
+        dialog: JsDialog,
+        responder: DialogResponder,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        open_dialog_modal(dialog, responder, window, cx);
+    }
+
+    fn on_permission(
+        &self,
+        request: PermissionRequest,
+        responder: DialogResponder,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        open_permission_modal(request, responder, window, cx);
+    }
+}
+
+/// The [`DialogHandler`] to open the modals.
+pub struct ModalDialogHandler;
+
+impl DialogHandler for ModalDialogHandler {}
+
+fn open_dialog_modal(
+    dialog: JsDialog,
+    responder: DialogResponder,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let title = SharedString::from(t!("WebView.dialog_title", host = host(&dialog.url)));
+    let message = SharedString::from(dialog.message);
+    let input = (dialog.kind == JsDialogKind::Prompt).then(|| {
+        let default_value = dialog.default_value.unwrap_or_default();
+        cx.new(|cx| InputState::new(window, cx).default_value(default_value))
+    });
+    if let Some(input) = input.as_ref() {
+        input.update(cx, |input, cx| input.focus(window, cx));
+    }
+
+    let kind = dialog.kind;
+    let responder = Rc::new(RefCell::new(Some(responder)));
+    window.open_modal(cx, move |modal, _, _| {
+        let modal = modal
+            .title(title.clone())
+            .child(
+                v_flex()
+                    .gap_3()
+                    .child(message.clone())
+                    .children(input.as_ref().map(TextInput::new)),
+            )
+            .on_ok({
+                let responder = responder.clone();
+                let input = input.clone();
+                move |_, _, cx| {
+                    let value = input
+                        .as_ref()
+                        .map(|input| input.read(cx).value().to_string())
+                        .unwrap_or_default();
+                    if let Some(responder) = responder.borrow_mut().take() {
+                        responder.respond(Some(value));
+                    }
+                    true
+                }
+            })
+            .on_cancel({
+                let responder = responder.clone();
+                move |_, _, _| {
+                    if let Some(responder) = responder.borrow_mut().take() {
+                        responder.respond(None);
+                    }
+                    true
+                }
+            });
+
+        match kind {
+            JsDialogKind::Alert => modal.alert(),
+            JsDialogKind::Confirm | JsDialogKind::Prompt => modal.confirm(),
+        }
+    });
+}
+
+fn open_permission_modal(
+    request: PermissionRequest,
+    responder: DialogResponder,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let host = host(&request.url).to_string();
+    let title = SharedString::from(t!("WebView.permission_title", host = host));
+    let message = SharedString::from(match request.kind {
+        PermissionKind::Camera => t!("WebView.permission_camera"),
+        PermissionKind::Microphone => t!("WebView.permission_microphone"),
+        PermissionKind::Geolocation => t!("WebView.permission_geolocation"),
+        PermissionKind::Notifications => t!("WebView.permission_notifications"),
+    });
+
+    let responder = Rc::new(RefCell::new(Some(responder)));
+    window.open_modal(cx, move |modal, _, _| {
+        modal
+            .title(title.clone())
+            .child(message.clone())
+            .confirm()
+            .button_props(
+                ModalButtonProps::default()
+                    .ok_text(t!("WebView.permission_allow"))
+                    .cancel_text(t!("WebView.permission_deny")),
+            )
+            .on_ok({
+                let responder = responder.clone();
+                move |_, _, _| {
+                    if let Some(responder) = responder.borrow_mut().take() {
+                        responder.respond(Some(String::new()));
+                    }
+                    true
+                }
+            })
+            .on_cancel({
+                let responder = responder.clone();
+                move |_, _, _| {
+                    if let Some(responder) = responder.borrow_mut().take() {
+                        responder.respond(None);
+                    }
+                    true
+                }
+            })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        host, init_script, parse_request, JsDialogKind, PageRequest, PermissionKind, SCHEME,
+    };
+
+    #[test]
+    fn test_parse_request() {
+        let Some(PageRequest::Dialog(dialog)) = parse_request(
+            "/dialog/prompt",
+            br#"{"url":"https://a.com/b","message":"Name?","default_value":"Jason"}"#,
+        ) else {
+            panic!("expected a dialog");
+        };
+        assert_eq!(dialog.kind, JsDialogKind::Prompt);
+        assert_eq!(dialog.message, "Name?");
+        assert_eq!(dialog.default_value.as_deref(), Some("Jason"));
+
+        let Some(PageRequest::Permission(request)) =
+            parse_request("/permission/camera", br#"{"url":"https://a.com"}"#)
+        else {
+            panic!("expected a permission request");
+        };
+        assert_eq!(request.kind, PermissionKind::Camera);
+        assert_eq!(request.url, "https://a.com");
+
+        assert!(parse_request("/dialog/unknown", br#"{"url":""}"#).is_none());
+        assert!(parse_request("/dialog/alert", b"invalid").is_none());
+    }
+
+    #[test]
+    fn test_host() {
+        assert_eq!(host("https://a.com/b?c"), "a.com");
+        assert_eq!(host("http://localhost:3000"), "localhost:3000");
+        assert_eq!(host("about:blank"), "about:blank");
+        assert!(init_script().contains(SCHEME));
+    }
+}
@@ -0,0 +1,260 @@
+use std::path::PathBuf;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, App, Context, Entity, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, SharedString, Styled as _, Subscription, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    empty_state::EmptyState,
+    h_flex,
+    label::Label,
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+use super::{WebView, WebViewEvent};
+
+/// The status of a [`DownloadItem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadStatus {
+    Downloading,
+    Completed,
+    Failed,
+    Canceled,
+}
+
+impl DownloadStatus {
+    fn label(&self) -> SharedString {
+        match self {
+            Self::Downloading => t!("WebView.downloading"),
+            Self::Completed => t!("WebView.download_completed"),
+            Self::Failed => t!("WebView.download_failed"),
+            Self::Canceled => t!("WebView.download_canceled"),
+        }
+        .into()
+    }
+}
+
+/// A download in the [`DownloadList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadItem {
+    pub url: String,
+    pub path: Option<PathBuf>,
+    pub status: DownloadStatus,
+}
+
+impl DownloadItem {
+    /// Returns the file name of the path, or the last segment of the URL.
+    pub fn file_name(&self) -> SharedString {
+        self.path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .or_else(|| {
+                self.url
+                    .split(['?', '#'])
+                    .next()
+                    .and_then(|url| url.rsplit('/').find(|segment| !segment.is_empty()))
+                    .map(|segment| segment.to_string())
+            })
+            .unwrap_or_else(|| self.url.clone())
+            .into()
+    }
+}
+
+/// Update the `items` by the download events, the newest download is the first.
+fn apply_event(items: &mut Vec<DownloadItem>, event: &WebViewEvent) {
+    match event {
+        WebViewEvent::DownloadRequested(event) => items.insert(
+            0,
+            DownloadItem {
+                url: event.url.clone(),
+                path: Some(event.path.clone()),
+                status: if event.accepted {
+                    DownloadStatus::Downloading
+                } else {
+                    DownloadStatus::Canceled
+                },
+            },
+        ),
+        WebViewEvent::DownloadCompleted(event) => {
+            let Some(item) = items
+                .iter_mut()
+                .find(|item| item.url == event.url && item.status == DownloadStatus::Downloading)
+            else {
+                return;
+            };
+
+            item.status = if event.success {
+                DownloadStatus::Completed
+            } else {
+                DownloadStatus::Failed
+            };
+            if event.path.is_some() {
+                item.path = event.path.clone();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A list to show the downloads of the [`WebView`]s.
+///
+/// ```ignore
+/// let downloads = cx.new(|cx| {
+///     let mut list = DownloadList::new();
+///     list.track(&webview, cx);
+///     list
+/// });
+/// ```
+pub struct DownloadList {
+    items: Vec<DownloadItem>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Default for DownloadList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DownloadList {
+    pub fn new() -> Self {
+        Self {
+            items: vec![],
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Track the downloads of the `webview`.
+    pub fn track(&mut self, webview: &Entity<WebView>, cx: &mut Context<Self>) {
+        self._subscriptions
+            .push(cx.subscribe(webview, |this, _, event: &WebViewEvent, cx| {
+                apply_event(&mut this.items, event);
+                cx.notify();
+            }));
+    }
+
+    /// Returns the downloads, the newest is the first.
+    pub fn items(&self) -> &[DownloadItem] {
+        &self.items
+    }
+
+    /// Remove the finished downloads.
+    pub fn clear(&mut self, cx: &mut Context<Self>) {
+        self.items
+            .retain(|item| item.status == DownloadStatus::Downloading);
+        cx.notify();
+    }
+
+    fn render_item(&self, item: &DownloadItem, cx: &App) -> impl IntoElement {
+        let (icon, color) = match item.status {
+            DownloadStatus::Downloading => (IconName::LoaderCircle, cx.theme().muted_foreground),
+            DownloadStatus::Completed => (IconName::CircleCheck, cx.theme().success),
+            DownloadStatus::Failed => (IconName::CircleX, cx.theme().danger),
+            DownloadStatus::Canceled => (IconName::CircleX, cx.theme().muted_foreground),
+        };
+
+        h_flex()
+            .gap_2()
+            .px_2()
+            .py_1()
+            .rounded(cx.theme().radius)
+            .hover(|this| this.bg(cx.theme().list_hover))
+            .child(Icon::new(IconName::File).small())
+            .child(
+                v_flex()
+                    .flex_1()
+                    .overflow_hidden()
+                    .child(Label::new(item.file_name()).text_sm().truncate())
+                    .child(
+                        Label::new(item.status.label())
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground),
+                    ),
+            )
+            .child(Icon::new(icon).small().text_color(color))
+    }
+}
+
+impl Render for DownloadList {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let has_finished = self
+            .items
+            .iter()
+            .any(|item| item.status != DownloadStatus::Downloading);
+
+        v_flex()
+            .size_full()
+            .gap_1()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .px_2()
+                    .child(Label::new(t!("WebView.downloads")).font_semibold())
+                    .child(
+                        Button::new("clear")
+                            .ghost()
+                            .xsmall()
+                            .label(t!("WebView.clear_downloads"))
+                            .when(!has_finished, |this| this.invisible())
+                            .on_click(cx.listener(|this, _, _, cx| this.clear(cx))),
+                    ),
+            )
+            .when(self.items.is_empty(), |this| {
+                this.child(
+                    div().flex_1().child(
+                        EmptyState::new()
+                            .icon(IconName::Inbox)
+                            .title(t!("WebView.no_downloads"))
+                            .small(),
+                    ),
+                )
+            })
+            .children(self.items.iter().map(|item| self.render_item(item, cx)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{apply_event, DownloadItem, DownloadStatus};
+    use crate::webview::{DownloadCompletedEvent, DownloadRequestedEvent, WebViewEvent};
+
+    #[test]
+    fn test_apply_event() {
+        let mut items = vec![];
+        let requested = |url: &str, accepted| {
+            WebViewEvent::DownloadRequested(DownloadRequestedEvent {
+                url: url.to_string(),
+                path: PathBuf::from(format!("/downloads/{}", url.rsplit('/').next().unwrap())),
+                accepted,
+            })
+        };
+        apply_event(&mut items, &requested("https://a.com/a.zip", true));
+        apply_event(&mut items, &requested("https://a.com/b.exe", false));
+        assert_eq!(items[0].status, DownloadStatus::Canceled);
+        assert_eq!(items[1].status, DownloadStatus::Downloading);
+
+        apply_event(
+            &mut items,
+            &WebViewEvent::DownloadCompleted(DownloadCompletedEvent {
+                url: "https://a.com/a.zip".to_string(),
+                path: None,
+                success: true,
+            }),
+        );
+        assert_eq!(items[1].status, DownloadStatus::Completed);
+        assert_eq!(items[1].file_name(), "a.zip");
+
+        let item = DownloadItem {
+            url: "https://a.com/files/c.pdf?token=1".to_string(),
+            path: None,
+            status: DownloadStatus::Failed,
+        };
+        assert_eq!(item.file_name(), "c.pdf");
+    }
+}
@@ -0,0 +1,482 @@
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use gpui::{
+    div, prelude::FluentBuilder as _, App, AppContext as _, Context, Empty, Entity, FocusHandle,
+    Focusable, InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Render,
+    SharedString, Styled as _, Subscription, Task, Window,
+};
+use rust_i18n::t;
+use serde::Deserialize;
+
+use crate::{
+    actions::SelectPrev,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    input::{Enter, Escape, InputEvent, InputState, TextInput},
+    label::Label,
+    ActiveTheme as _, Disableable as _, IconName, Selectable as _, Sizable as _,
+};
+
+const KEY_CONTEXT: &str = "WebViewFindBar";
+
+/// The script to find the text in the page, the matches are highlighted by the CSS Custom Highlight API,
+/// so the DOM of the page is not changed.
+///
+/// If the API is not supported, only the current match is selected.
+///
+/// The arguments are `(query, case_sensitive, highlight_all, step)`,
+/// an empty query to clear the matches.
+const FIND_SCRIPT: &str = r##"(function(query, caseSensitive, highlightAll, step) {
+  const state = window.__gpuiFind || (window.__gpuiFind = { key: null, ranges: [], current: -1 });
+  const highlights = window.CSS && CSS.highlights && window.Highlight ? CSS.highlights : null;
+  if (highlights && !state.sheet) {
+    state.sheet = new CSSStyleSheet();
+    state.sheet.replaceSync(
+      "::highlight(gpui-find) { background-color: #ffff00; color: black; }" +
+      "::highlight(gpui-find-current) { background-color: #ff9632; color: black; }"
+    );
+    document.adoptedStyleSheets = [...document.adoptedStyleSheets, state.sheet];
+  }
+
+  // Returns the text to match, with the offsets of each UTF-16 unit in the original text,
+  // the lowercase of a character may have a different length, e.g.: `İ`.
+  const fold = (text) => {
+    if (caseSensitive) return { text: text, offsets: null };
+    let folded = "";
+    const offsets = [];
+    let ix = 0;
+    for (const c of text) {
+      const lower = c.toLowerCase();
+      folded += lower;
+      for (let i = 0; i < lower.length; i++) offsets.push(ix);
+      ix += c.length;
+    }
+    offsets.push(ix);
+    return { text: folded, offsets: offsets };
+  };
+
+  const key = caseSensitive + ":" + query;
+  if (state.key !== key) {
+    state.key = key;
+    state.ranges = [];
+    state.current = -1;
+
+    if (query && document.body) {
+      const needle = fold(query).text;
+      const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT, {
+        acceptNode(node) {
+          const tag = node.parentNode && node.parentNode.nodeName;
+          return tag === "SCRIPT" || tag === "STYLE" || tag === "NOSCRIPT"
+            ? NodeFilter.FILTER_REJECT
+            : NodeFilter.FILTER_ACCEPT;
+        },
+      });
+      while (walker.nextNode()) {
+        const node = walker.currentNode;
+        const { text, offsets } = fold(node.nodeValue);
+        let ix = text.indexOf(needle);
+        while (ix !== -1) {
+          const end = ix + needle.length;
+          const range = document.createRange();
+          range.setStart(node, offsets ? offsets[ix] : ix);
+          range.setEnd(node, offsets ? offsets[end] : end);
+          state.ranges.push(range);
+          ix = text.indexOf(needle, end);
+        }
+      }
+    }
+  }
+
+  const count = state.ranges.length;
+  if (count > 0) {
+    state.current = state.current < 0
+      ? (step < 0 ? count - 1 : 0)
+      : (state.current + step + count) % count;
+  }
+  const current = count > 0 ? state.ranges[state.current] : null;
+  if (highlights) {
+    highlights.delete("gpui-find");
+    highlights.delete("gpui-find-current");
+    if (highlightAll && count > 0) {
+      highlights.set("gpui-find", new Highlight(...state.ranges));
+    }
+    if (current) {
+      const highlight = new Highlight(current);
+      highlight.priority = 1;
+      highlights.set("gpui-find-current", highlight);
+    }
+  } else if (current) {
+    const selection = window.getSelection();
+    selection.removeAllRanges();
+    selection.addRange(current);
+  }
+  if (current) {
+    const element = current.startContainer.parentElement;
+    if (element) element.scrollIntoView({ block: "center", inline: "nearest" });
+  }
+  return JSON.stringify({ count: count, current: count > 0 ? state.current + 1 : 0 });
+})"##;
+
+pub(super) fn init(cx: &mut App) {
+    cx.bind_keys(vec![KeyBinding::new(
+        "shift-enter",
+        SelectPrev,
+        Some(KEY_CONTEXT),
+    )]);
+}
+
+/// The options of the find in page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FindOptions {
+    /// Match the case of the query, default is false.
+    pub case_sensitive: bool,
+    /// Highlight all the matches, otherwise only the current match, default is false.
+    pub highlight_all: bool,
+}
+
+/// The matches of the find in page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct FindResult {
+    /// The count of the matches.
+    pub count: usize,
+    /// The 1-based index of the current match, 0 if no matches.
+    pub current: usize,
+}
+
+fn find_script(query: &str, options: FindOptions, step: isize) -> String {
+    format!(
+        "{}({}, {}, {}, {})",
+        FIND_SCRIPT,
+        serde_json::to_string(query).unwrap_or_default(),
+        options.case_sensitive,
+        options.highlight_all,
+        step
+    )
+}
+
+/// The result of the script is serialized to JSON by the webview,
+/// so the returned JSON string may be encoded twice.
+fn parse_find_result(json: &str) -> Option<FindResult> {
+    if let Ok(result) = serde_json::from_str::<FindResult>(json) {
+        return Some(result);
+    }
+
+    let json = serde_json::from_str::<String>(json).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Find the `query` in the page, and move the current match by the `step`.
+pub(super) fn find_in_page(
+    webview: &wry::WebView,
+    query: &str,
+    options: FindOptions,
+    step: isize,
+    cx: &App,
+) -> Task<anyhow::Result<FindResult>> {
+    let (tx, rx) = smol::channel::bounded(1);
+    if let Err(err) =
+        webview.evaluate_script_with_callback(&find_script(query, options, step), move |json| {
+            _ = tx.try_send(json);
+        })
+    {
+        return Task::ready(Err(err.into()));
+    }
+
+    cx.background_executor().spawn(async move {
+        let json = rx.recv().await?;
+        parse_find_result(&json).ok_or_else(|| anyhow!("invalid find result: {}", json))
+    })
+}
+
+/// The find bar docked at the top of the [`super::WebView`].
+///
+/// The native webview is always above the GPUI content,
+/// so the bar is placed above the webview rather than floating over it.
+pub(super) struct FindBar {
+    webview: Rc<wry::WebView>,
+    input: Entity<InputState>,
+    query: SharedString,
+    options: FindOptions,
+    result: FindResult,
+    open: bool,
+    _search_task: Task<()>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl FindBar {
+    pub(super) fn new(
+        webview: Rc<wry::WebView>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Entity<Self> {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder(t!("WebView.find")));
+
+        cx.new(|cx| {
+            let _subscriptions =
+                vec![
+                    cx.subscribe(&input, |this: &mut Self, input, event: &InputEvent, cx| {
+                        if let InputEvent::Change(_) = event {
+                            let query = input.read(cx).value();
+                            this.find(query, 0, cx).detach();
+                        }
+                    }),
+                ];
+
+            Self {
+                webview,
+                input,
+                query: SharedString::default(),
+                options: FindOptions::default(),
+                result: FindResult::default(),
+                open: false,
+                _search_task: Task::ready(()),
+                _subscriptions,
+            }
+        })
+    }
+
+    pub(super) fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub(super) fn options(&self) -> FindOptions {
+        self.options
+    }
+
+    pub(super) fn show(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.open = true;
+        self.input.update(cx, |input, cx| input.focus(window, cx));
+        if !self.query.is_empty() {
+            self.find(self.query.clone(), 0, cx).detach();
+        }
+        cx.notify();
+    }
+
+    pub(super) fn hide(&mut self, cx: &mut Context<Self>) {
+        self.open = false;
+        self.clear(cx);
+        cx.notify();
+    }
+
+    /// Remove the highlights of the matches.
+    pub(super) fn clear(&mut self, cx: &mut Context<Self>) {
+        self.result = FindResult::default();
+        _ = find_in_page(&self.webview, "", self.options, 0, cx);
+    }
+
+    pub(super) fn set_options(&mut self, options: FindOptions, cx: &mut Context<Self>) {
+        self.options = options;
+        if !self.query.is_empty() {
+            self.find(self.query.clone(), 0, cx).detach();
+        }
+        cx.notify();
+    }
+
+    /// Find the `query` and move the current match by the `step`, the result is updated to the bar.
+    pub(super) fn find(
+        &mut self,
+        query: impl Into<SharedString>,
+        step: isize,
+        cx: &mut Context<Self>,
+    ) -> Task<anyhow::Result<FindResult>> {
+        self.query = query.into();
+        let task = find_in_page(&self.webview, &self.query, self.options, step, cx);
+
+        let (tx, rx) = smol::channel::bounded(1);
+        self._search_task = cx.spawn(async move |this, cx| {
+            let result = task.await;
+            if let Ok(result) = &result {
+                let result = *result;
+                _ = this.update(cx, |this, cx| {
+                    this.result = result;
+                    cx.notify();
+                });
+            }
+            _ = tx.send(result).await;
+        });
+
+        cx.background_executor()
+            .spawn(async move { rx.recv().await? })
+    }
+
+    pub(super) fn next(&mut self, cx: &mut Context<Self>) -> Task<anyhow::Result<FindResult>> {
+        self.find(self.query.clone(), 1, cx)
+    }
+
+    pub(super) fn prev(&mut self, cx: &mut Context<Self>) -> Task<anyhow::Result<FindResult>> {
+        self.find(self.query.clone(), -1, cx)
+    }
+
+    fn on_action_next(&mut self, _: &Enter, _: &mut Window, cx: &mut Context<Self>) {
+        self.next(cx).detach();
+    }
+
+    fn on_action_prev(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
+        self.prev(cx).detach();
+    }
+
+    fn on_action_escape(&mut self, _: &Escape, _: &mut Window, cx: &mut Context<Self>) {
+        self.hide(cx);
+    }
+
+    fn label(&self) -> String {
+        if self.result.count == 0 {
+            return "0/0".to_string();
+        }
+        format!("{}/{}", self.result.current, self.result.count)
+    }
+}
+
+impl Focusable for FindBar {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.input.read(cx).focus_handle(cx)
+    }
+}
+
+impl Render for FindBar {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.open {
+            return Empty.into_any_element();
+        }
+
+        let has_matches = self.result.count > 0;
+        let options = self.options;
+
+        h_flex()
+            .id("find-bar")
+            .key_context(KEY_CONTEXT)
+            .on_action(cx.listener(Self::on_action_next))
+            .on_action(cx.listener(Self::on_action_prev))
+            .on_action(cx.listener(Self::on_action_escape))
+            .w_full()
+            .gap_2()
+            .py_2()
+            .px_3()
+            .bg(cx.theme().popover)
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    TextInput::new(&self.input)
+                        .focus_bordered(false)
+                        .suffix(
+                            h_flex()
+                                .gap_1()
+                                .child(
+                                    Button::new("case-sensitive")
+                                        .xsmall()
+                                        .compact()
+                                        .ghost()
+                                        .icon(IconName::CaseSensitive)
+                                        .selected(options.case_sensitive)
+                                        .tooltip(t!("WebView.match_case"))
+                                        .on_click(cx.listener(move |this, _, _, cx| {
+                                            this.set_options(
+                                                FindOptions {
+                                                    case_sensitive: !options.case_sensitive,
+                                                    ..options
+                                                },
+                                                cx,
+                                            );
+                                        })),
+                                )
+                                .child(
+                                    Button::new("highlight-all")
+                                        .xsmall()
+                                        .compact()
+                                        .ghost()
+                                        .icon(IconName::Eye)
+                                        .selected(options.highlight_all)
+                                        .tooltip(t!("WebView.highlight_all"))
+                                        .on_click(cx.listener(move |this, _, _, cx| {
+                                            this.set_options(
+                                                FindOptions {
+                                                    highlight_all: !options.highlight_all,
+                                                    ..options
+                                                },
+                                                cx,
+                                            );
+                                        })),
+                                ),
+                        )
+                        .small()
+                        .w_full()
+                        .shadow_none(),
+                ),
+            )
+            .child(
+                Button::new("prev")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::ChevronUp)
+                    .disabled(!has_matches)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.prev(cx).detach();
+                    })),
+            )
+            .child(
+                Button::new("next")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::ChevronDown)
+                    .disabled(!has_matches)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.next(cx).detach();
+                    })),
+            )
+            .child(
+                Label::new(self.label())
+                    .when(!has_matches, |this| {
+                        this.text_color(cx.theme().muted_foreground)
+                    })
+                    .text_left()
+                    .min_w_16(),
+            )
+            .child(
+                Button::new("close")
+                    .xsmall()
+                    .ghost()
+                    .icon(IconName::Close)
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.hide(cx);
+                    })),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_script, parse_find_result, FindOptions, FindResult};
+
+    #[test]
+    fn test_find_script() {
+        let script = find_script(
+            "a \"b\"",
+            FindOptions {
+                case_sensitive: true,
+                highlight_all: false,
+            },
+            -1,
+        );
+        assert!(script.ends_with(r#"("a \"b\"", true, false, -1)"#));
+    }
+
+    #[test]
+    fn test_parse_find_result() {
+        let result = FindResult {
+            count: 3,
+            current: 1,
+        };
+        assert_eq!(
+            parse_find_result(r#"{"count":3,"current":1}"#),
+            Some(result)
+        );
+        assert_eq!(
+            parse_find_result(r#""{\"count\":3,\"current\":1}""#),
+            Some(result)
+        );
+        assert_eq!(parse_find_result("null"), None);
+    }
+}
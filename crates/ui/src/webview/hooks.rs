@@ -0,0 +1,373 @@
+use std::{borrow::Cow, path::PathBuf, rc::Rc};
+
+use wry::http::{Request, Response, StatusCode};
+
+use super::{
+    bridge::{self, Bridge, BridgeCall},
+    console,
+    dialog::{self, DialogHandler, PageRequest},
+    WebViewEvent,
+};
+
+/// The navigation requested by the page or the user, see [`WebViewHooks::on_before_navigation`].
+///
+/// The platform webviews only report the URL of the navigation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeforeNavigationEvent {
+    pub url: String,
+}
+
+/// The decision of a [`BeforeNavigationEvent`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum NavigationAction {
+    #[default]
+    Allow,
+    Cancel,
+    /// Cancel the navigation and load the URL instead, e.g.: the callback of an OAuth flow.
+    Redirect(String),
+}
+
+/// A download started by the page, see [`WebViewHooks::on_download`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadRequestedEvent {
+    pub url: String,
+    /// The destination path.
+    pub path: PathBuf,
+    /// Is the download accepted.
+    pub accepted: bool,
+}
+
+/// A download is finished, whether it was successful or not.
+///
+/// The platform webviews don't report the download progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadCompletedEvent {
+    pub url: String,
+    /// The path the file was saved to, always None on macOS.
+    pub path: Option<PathBuf>,
+    pub success: bool,
+}
+
+/// The decision of a download.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DownloadAction {
+    /// Accept the download to the suggested path.
+    #[default]
+    Accept,
+    /// Accept the download to the absolute path.
+    AcceptTo(PathBuf),
+    Cancel,
+}
+
+/// The handler to serve the requests of a custom scheme,
+/// see [`WebViewHooks::request_handler`].
+pub trait RequestHandler: 'static {
+    /// Returns the response of the `request`, or None to respond `404 Not Found`.
+    fn handle(&self, request: &Request<Vec<u8>>) -> Option<Response<Cow<'static, [u8]>>>;
+}
+
+impl<F> RequestHandler for F
+where
+    F: Fn(&Request<Vec<u8>>) -> Option<Response<Cow<'static, [u8]>>> + 'static,
+{
+    fn handle(&self, request: &Request<Vec<u8>>) -> Option<Response<Cow<'static, [u8]>>> {
+        self(request)
+    }
+}
+
+pub(super) enum HookMessage {
+    Event(WebViewEvent),
+    LoadUrl(String),
+    Page(PageRequest, wry::RequestAsyncResponder),
+    Call(BridgeCall),
+}
+
+/// The hooks of the navigation, the downloads, the resource requests, the dialogs,
+/// the console and the bridge of a [`super::WebView`].
+///
+/// The hooks must be installed into the [`wry::WebViewBuilder`] before building,
+/// then pass to [`super::WebView::hooks`] to receive the events.
+///
+/// ```ignore
+/// let hooks = WebViewHooks::new()
+///     .on_before_navigation(|event| {
+///         if event.url.starts_with("myapp://oauth/callback") {
+///             NavigationAction::Cancel
+///         } else {
+///             NavigationAction::Allow
+///         }
+///     })
+///     .request_handler("app", |request: &Request<Vec<u8>>| {
+///         serve_bundled(request.uri().path())
+///     });
+///
+/// let webview = hooks.install(wry::WebViewBuilder::new()).build_as_child(&window_handle)?;
+/// let webview = cx.new(|cx| WebView::new(webview, window, cx).hooks(&hooks, window, cx));
+/// ```
+#[derive(Clone)]
+pub struct WebViewHooks {
+    before_navigation: Option<Rc<dyn Fn(&BeforeNavigationEvent) -> NavigationAction>>,
+    download: Option<Rc<dyn Fn(&str, &PathBuf) -> DownloadAction>>,
+    request_handlers: Vec<(String, Rc<dyn RequestHandler>)>,
+    pub(super) dialog_handler: Option<Rc<dyn DialogHandler>>,
+    capture_console: bool,
+    pub(super) bridge: Option<Bridge>,
+    pub(super) tx: smol::channel::Sender<HookMessage>,
+    pub(super) rx: smol::channel::Receiver<HookMessage>,
+}
+
+impl Default for WebViewHooks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebViewHooks {
+    pub fn new() -> Self {
+        let (tx, rx) = smol::channel::unbounded();
+        Self {
+            before_navigation: None,
+            download: None,
+            request_handlers: vec![],
+            dialog_handler: None,
+            capture_console: false,
+            bridge: None,
+            tx,
+            rx,
+        }
+    }
+
+    /// Set the callback to decide whether to allow, cancel or redirect a navigation,
+    /// a [`WebViewEvent::BeforeNavigation`] is emitted with the decision.
+    pub fn on_before_navigation(
+        mut self,
+        f: impl Fn(&BeforeNavigationEvent) -> NavigationAction + 'static,
+    ) -> Self {
+        self.before_navigation = Some(Rc::new(f));
+        self
+    }
+
+    /// Set the callback to accept or cancel a download with the URL and the suggested path,
+    /// and choose the destination path.
+    ///
+    /// A [`WebViewEvent::DownloadRequested`] is emitted with the decision,
+    /// and a [`WebViewEvent::DownloadCompleted`] when the accepted download is finished.
+    ///
+    /// Default accepts all downloads to the suggested path.
+    pub fn on_download(mut self, f: impl Fn(&str, &PathBuf) -> DownloadAction + 'static) -> Self {
+        self.download = Some(Rc::new(f));
+        self
+    }
+
+    /// Serve the requests of the `scheme` (e.g.: `app` for `app://index.html`) by the `handler`,
+    /// e.g.: an [`super::AssetHandler`] to serve the files bundled into the binary.
+    ///
+    /// On Windows and Android the URL is `http://<scheme>.<path>` instead.
+    pub fn request_handler(
+        mut self,
+        scheme: impl Into<String>,
+        handler: impl RequestHandler,
+    ) -> Self {
+        self.request_handlers
+            .push((scheme.into(), Rc::new(handler)));
+        self
+    }
+
+    /// Route the JavaScript dialogs (`alert`, `confirm` and `prompt`) and the permission
+    /// requests (camera, microphone, geolocation and notifications) of the page to the `handler`,
+    /// e.g.: [`super::ModalDialogHandler`] to open the modals.
+    ///
+    /// The native webview is hidden while a dialog is open, since it is always above the modals.
+    pub fn dialog_handler(mut self, handler: impl DialogHandler) -> Self {
+        self.dialog_handler = Some(Rc::new(handler));
+        self
+    }
+
+    /// Capture the `console` messages and the uncaught errors of the page,
+    /// a [`WebViewEvent::ConsoleMessage`] is emitted for each, e.g.: to show in a
+    /// [`super::ConsolePanel`].
+    pub fn capture_console(mut self) -> Self {
+        self.capture_console = true;
+        self
+    }
+
+    /// Install the `bridge` to let the page call the typed functions by `bridge.call`,
+    /// and subscribe the events emitted by [`super::WebView::emit`] by `bridge.on`.
+    pub fn bridge(mut self, bridge: Bridge) -> Self {
+        self.bridge = Some(bridge);
+        self
+    }
+
+    fn decide(&self, event: &BeforeNavigationEvent) -> NavigationAction {
+        self.before_navigation
+            .as_ref()
+            .map(|f| f(event))
+            .unwrap_or_default()
+    }
+
+    fn decide_download(&self, url: &str, path: &PathBuf) -> DownloadAction {
+        self.download
+            .as_ref()
+            .map(|f| f(url, path))
+            .unwrap_or_default()
+    }
+
+    /// Install the hooks into the `builder`.
+    pub fn install<'a>(&self, builder: wry::WebViewBuilder<'a>) -> wry::WebViewBuilder<'a> {
+        let hooks = self.clone();
+        let mut builder = builder.with_navigation_handler(move |url| {
+            let event = BeforeNavigationEvent { url };
+            let action = hooks.decide(&event);
+            if let NavigationAction::Redirect(url) = &action {
+                _ = hooks.tx.try_send(HookMessage::LoadUrl(url.clone()));
+            }
+            let allowed = action == NavigationAction::Allow;
+            _ = hooks
+                .tx
+                .try_send(HookMessage::Event(WebViewEvent::BeforeNavigation(
+                    event, action,
+                )));
+            allowed
+        });
+
+        let hooks = self.clone();
+        builder = builder.with_download_started_handler(move |url, path| {
+            let action = hooks.decide_download(&url, path);
+            if let DownloadAction::AcceptTo(new_path) = &action {
+                *path = new_path.clone();
+            }
+            let accepted = action != DownloadAction::Cancel;
+            _ = hooks
+                .tx
+                .try_send(HookMessage::Event(WebViewEvent::DownloadRequested(
+                    DownloadRequestedEvent {
+                        url,
+                        path: path.clone(),
+                        accepted,
+                    },
+                )));
+            accepted
+        });
+
+        let tx = self.tx.clone();
+        builder = builder.with_download_completed_handler(move |url, path, success| {
+            _ = tx.try_send(HookMessage::Event(WebViewEvent::DownloadCompleted(
+                DownloadCompletedEvent { url, path, success },
+            )));
+        });
+
+        for (scheme, handler) in &self.request_handlers {
+            let handler = handler.clone();
+            builder = builder.with_custom_protocol(scheme.clone(), move |_, request| {
+                handler.handle(&request).unwrap_or_else(not_found)
+            });
+        }
+
+        if self.dialog_handler.is_some() {
+            let tx = self.tx.clone();
+            builder = builder
+                .with_initialization_script(dialog::init_script())
+                .with_asynchronous_custom_protocol(
+                    dialog::SCHEME.to_string(),
+                    move |_, request, responder| match dialog::parse(&request) {
+                        Some(request) => {
+                            _ = tx.try_send(HookMessage::Page(request, responder));
+                        }
+                        None => dialog::respond(responder, StatusCode::BAD_REQUEST, String::new()),
+                    },
+                );
+        }
+
+        if self.capture_console {
+            builder = builder.with_initialization_script(console::INIT_SCRIPT);
+        }
+        if self.bridge.is_some() {
+            builder = builder.with_initialization_script(bridge::INIT_SCRIPT);
+        }
+        if self.capture_console || self.bridge.is_some() {
+            let tx = self.tx.clone();
+            builder = builder.with_ipc_handler(move |request| {
+                let body = request.body();
+                if let Some(message) = console::parse_message(body) {
+                    _ = tx.try_send(HookMessage::Event(WebViewEvent::ConsoleMessage(message)));
+                } else if let Some(call) = bridge::parse_call(body) {
+                    _ = tx.try_send(HookMessage::Call(call));
+                }
+            });
+        }
+
+        builder
+    }
+}
+
+fn not_found() -> Response<Cow<'static, [u8]>> {
+    super::response(StatusCode::NOT_FOUND, "text/plain", &[][..])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{BeforeNavigationEvent, DownloadAction, NavigationAction, WebViewHooks};
+
+    #[test]
+    fn test_navigation_action() {
+        let event = |url: &str| BeforeNavigationEvent {
+            url: url.to_string(),
+        };
+        assert_eq!(
+            WebViewHooks::new().decide(&event("https://a.com")),
+            NavigationAction::Allow
+        );
+
+        let hooks = WebViewHooks::new().on_before_navigation(|event| {
+            if event.url.starts_with("https://blocked.com") {
+                NavigationAction::Cancel
+            } else if event.url.starts_with("http://") {
+                NavigationAction::Redirect(event.url.replacen("http://", "https://", 1))
+            } else {
+                NavigationAction::Allow
+            }
+        });
+        assert_eq!(
+            hooks.decide(&event("https://blocked.com/a")),
+            NavigationAction::Cancel
+        );
+        assert_eq!(
+            hooks.decide(&event("http://a.com")),
+            NavigationAction::Redirect("https://a.com".to_string())
+        );
+        assert_eq!(
+            hooks.decide(&event("https://a.com")),
+            NavigationAction::Allow
+        );
+        assert_eq!(super::not_found().status(), 404);
+    }
+
+    #[test]
+    fn test_download_action() {
+        let path = PathBuf::from("/tmp/a.zip");
+        assert_eq!(
+            WebViewHooks::new().decide_download("https://a.com/a.zip", &path),
+            DownloadAction::Accept
+        );
+
+        let hooks = WebViewHooks::new().on_download(|url, path| {
+            if url.ends_with(".exe") {
+                DownloadAction::Cancel
+            } else {
+                DownloadAction::AcceptTo(
+                    PathBuf::from("/downloads").join(path.file_name().unwrap()),
+                )
+            }
+        });
+        assert_eq!(
+            hooks.decide_download("https://a.com/a.exe", &path),
+            DownloadAction::Cancel
+        );
+        assert_eq!(
+            hooks.decide_download("https://a.com/a.zip", &path),
+            DownloadAction::AcceptTo(PathBuf::from("/downloads/a.zip"))
+        );
+    }
+}
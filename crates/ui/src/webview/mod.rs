@@ -0,0 +1,592 @@
+use std::{cell::Cell, ops::Deref, rc::Rc};
+
+use wry::{
+    dpi::{self, LogicalSize},
+    Rect,
+};
+
+use gpui::{
+    actions, canvas, div, App, Bounds, ContentMask, Context, DismissEvent, Element, ElementId,
+    Entity, EventEmitter, FocusHandle, Focusable, GlobalElementId, Hitbox, InteractiveElement,
+    IntoElement, KeyBinding, LayoutId, MouseDownEvent, ParentElement as _, Pixels, Render,
+    SharedString, Size, Style, Styled as _, Task, Window,
+};
+
+use crate::{input::Search, v_flex, PixelsExt};
+
+mod bridge;
+mod console;
+mod dialog;
+mod download_list;
+mod find;
+mod hooks;
+mod scheme;
+
+pub use bridge::Bridge;
+pub use console::{ConsoleMessageEvent, ConsolePanel};
+use dialog::{DialogGuard, PageRequest};
+pub use dialog::{
+    DialogHandler, DialogResponder, JsDialog, JsDialogKind, ModalDialogHandler, PermissionKind,
+    PermissionRequest,
+};
+pub use download_list::{DownloadItem, DownloadList, DownloadStatus};
+use find::FindBar;
+pub use find::{FindOptions, FindResult};
+use hooks::HookMessage;
+pub use hooks::{
+    BeforeNavigationEvent, DownloadAction, DownloadCompletedEvent, DownloadRequestedEvent,
+    NavigationAction, RequestHandler, WebViewHooks,
+};
+pub use scheme::{mime_type, response, AssetHandler};
+
+const CONTEXT: &str = "WebView";
+
+actions!(webview, [ZoomIn, ZoomOut, ResetZoom, ToggleDevTools]);
+
+/// The zoom levels of [`WebView::zoom_in`] and [`WebView::zoom_out`], same as the browsers.
+const ZOOM_LEVELS: [f64; 15] = [
+    0.25, 0.33, 0.5, 0.67, 0.75, 0.8, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0,
+];
+
+pub(crate) fn init(cx: &mut App) {
+    find::init(cx);
+    cx.bind_keys([
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-f", Search, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-f", Search, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-=", ZoomIn, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-=", ZoomIn, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd--", ZoomOut, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl--", ZoomOut, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-0", ResetZoom, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-0", ResetZoom, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-alt-i", ToggleDevTools, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-i", ToggleDevTools, Some(CONTEXT)),
+    ]);
+}
+
+pub struct WebView {
+    focus_handle: FocusHandle,
+    webview: Rc<wry::WebView>,
+    visible: Rc<Cell<bool>>,
+    /// The number of the open dialogs, see [`WebViewHooks::dialog_handler`].
+    open_dialogs: Rc<Cell<usize>>,
+    bounds: Bounds<Pixels>,
+    /// The last bounds set to the native webview, to skip the unchanged bounds in each paint.
+    native_bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+    zoom_level: f64,
+    find_bar: Entity<FindBar>,
+    _hooks_task: Option<Task<()>>,
+}
+
+/// The events emitted by the [`WebView`].
+#[derive(Debug, Clone)]
+pub enum WebViewEvent {
+    /// A navigation is requested, with the decision of [`WebViewHooks::on_before_navigation`].
+    BeforeNavigation(BeforeNavigationEvent, NavigationAction),
+    /// A download is requested, see [`WebViewHooks::on_download`].
+    DownloadRequested(DownloadRequestedEvent),
+    DownloadCompleted(DownloadCompletedEvent),
+    /// The zoom level is changed, e.g.: to show a zoom indicator.
+    ZoomChanged(f64),
+    /// A console message of the page, see [`WebViewHooks::capture_console`].
+    ConsoleMessage(ConsoleMessageEvent),
+}
+
+impl Drop for WebView {
+    fn drop(&mut self) {
+        self.hide();
+    }
+}
+
+impl WebView {
+    pub fn new(webview: wry::WebView, window: &mut Window, cx: &mut App) -> Self {
+        let _ = webview.set_bounds(Rect::default());
+        let webview = Rc::new(webview);
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            visible: Rc::new(Cell::new(true)),
+            open_dialogs: Rc::new(Cell::new(0)),
+            bounds: Bounds::default(),
+            native_bounds: Rc::new(Cell::new(None)),
+            zoom_level: 1.0,
+            find_bar: FindBar::new(webview.clone(), window, cx),
+            webview,
+            _hooks_task: None,
+        }
+    }
+
+    /// Receive the events from the `hooks` installed into the builder of this webview.
+    pub fn hooks(
+        mut self,
+        hooks: &WebViewHooks,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let rx = hooks.rx.clone();
+        let dialog_handler = hooks.dialog_handler.clone();
+        let bridge = hooks.bridge.clone();
+        self._hooks_task = Some(cx.spawn_in(window, async move |this, cx| {
+            while let Ok(message) = rx.recv().await {
+                let result = this.update_in(cx, |this, window, cx| match message {
+                    HookMessage::Event(event) => cx.emit(event),
+                    HookMessage::LoadUrl(url) => {
+                        _ = this.webview.load_url(&url);
+                    }
+                    HookMessage::Call(call) => {
+                        let Some(bridge) = bridge.as_ref() else {
+                            return;
+                        };
+                        let script = bridge.call(call, window, cx);
+                        _ = this.webview.evaluate_script(&script);
+                    }
+                    HookMessage::Page(request, responder) => {
+                        let Some(handler) = dialog_handler.as_ref() else {
+                            return;
+                        };
+                        let responder = DialogResponder::new(responder, this.dialog_guard());
+                        match request {
+                            PageRequest::Dialog(dialog) => {
+                                handler.on_dialog(dialog, responder, window, cx)
+                            }
+                            PageRequest::Permission(request) => {
+                                handler.on_permission(request, responder, window, cx)
+                            }
+                        }
+                    }
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        }));
+        self
+    }
+
+    fn dialog_guard(&self) -> DialogGuard {
+        DialogGuard {
+            webview: self.webview.clone(),
+            visible: self.visible.clone(),
+            open_count: self.open_dialogs.clone(),
+        }
+    }
+
+    pub fn show(&mut self) {
+        // Keep hidden until the open dialogs are closed.
+        if self.open_dialogs.get() == 0 {
+            let _ = self.webview.set_visible(true);
+        }
+        self.visible.set(true);
+    }
+
+    pub fn hide(&mut self) {
+        _ = self.webview.focus_parent();
+        _ = self.webview.set_visible(false);
+        self.visible.set(false);
+    }
+
+    pub fn visible(&self) -> bool {
+        self.visible.get()
+    }
+
+    pub fn bounds(&self) -> Bounds<Pixels> {
+        self.bounds
+    }
+
+    /// Go back in the webview history.
+    pub fn back(&mut self) -> anyhow::Result<()> {
+        Ok(self.webview.evaluate_script("history.back();")?)
+    }
+
+    pub fn load_url(&mut self, url: &str) {
+        self.webview.load_url(url).unwrap();
+    }
+
+    /// Load the `url` with the additional request `headers`, e.g.: an `Authorization` header.
+    pub fn load_url_with_headers(
+        &mut self,
+        url: &str,
+        headers: wry::http::HeaderMap,
+    ) -> anyhow::Result<()> {
+        Ok(self.webview.load_url_with_headers(url, headers)?)
+    }
+
+    /// Find the `query` in the page and select the first match,
+    /// the find bar is updated if it is open.
+    ///
+    /// Use an empty `query` to clear the matches.
+    pub fn find(
+        &mut self,
+        query: impl Into<SharedString>,
+        cx: &mut App,
+    ) -> Task<anyhow::Result<FindResult>> {
+        self.find_bar.update(cx, |bar, cx| bar.find(query, 0, cx))
+    }
+
+    /// Select the next match of the last query.
+    pub fn find_next(&mut self, cx: &mut App) -> Task<anyhow::Result<FindResult>> {
+        self.find_bar.update(cx, |bar, cx| bar.next(cx))
+    }
+
+    /// Select the previous match of the last query.
+    pub fn find_prev(&mut self, cx: &mut App) -> Task<anyhow::Result<FindResult>> {
+        self.find_bar.update(cx, |bar, cx| bar.prev(cx))
+    }
+
+    /// Returns the options of the find in page.
+    pub fn find_options(&self, cx: &App) -> FindOptions {
+        self.find_bar.read(cx).options()
+    }
+
+    /// Set the options of the find in page, the last query is searched again.
+    pub fn set_find_options(&mut self, options: FindOptions, cx: &mut App) {
+        self.find_bar
+            .update(cx, |bar, cx| bar.set_options(options, cx));
+    }
+
+    /// Returns true if the find bar is open.
+    pub fn is_find_bar_open(&self, cx: &App) -> bool {
+        self.find_bar.read(cx).is_open()
+    }
+
+    /// Open the find bar and focus the query input, also opened by `cmd-f` / `ctrl-f`.
+    pub fn open_find_bar(&mut self, window: &mut Window, cx: &mut App) {
+        self.find_bar.update(cx, |bar, cx| bar.show(window, cx));
+    }
+
+    /// Close the find bar and clear the matches, also closed by `escape`.
+    pub fn close_find_bar(&mut self, cx: &mut App) {
+        self.find_bar.update(cx, |bar, cx| bar.hide(cx));
+    }
+
+    /// Emit the `event` with the `payload` to the listeners of `bridge.on` in the page,
+    /// see [`WebViewHooks::bridge`].
+    ///
+    /// Only the main frame of the page receives the event.
+    pub fn emit(&self, event: &str, payload: &impl serde::Serialize) -> anyhow::Result<()> {
+        let script = bridge::dispatch_script(event, payload)?;
+        Ok(self.webview.evaluate_script(&script)?)
+    }
+
+    /// Returns the zoom level of the page, default is `1.0`.
+    pub fn zoom_level(&self) -> f64 {
+        self.zoom_level
+    }
+
+    /// Set the zoom level of the page, e.g.: `1.5` for 150%,
+    /// a [`WebViewEvent::ZoomChanged`] is emitted if changed.
+    pub fn set_zoom_level(&mut self, zoom_level: f64, cx: &mut Context<Self>) {
+        let zoom_level = zoom_level.clamp(ZOOM_LEVELS[0], ZOOM_LEVELS[ZOOM_LEVELS.len() - 1]);
+        if zoom_level == self.zoom_level {
+            return;
+        }
+
+        _ = self.webview.zoom(zoom_level);
+        self.zoom_level = zoom_level;
+        cx.emit(WebViewEvent::ZoomChanged(zoom_level));
+        cx.notify();
+    }
+
+    /// Zoom in to the next zoom level, also by `cmd-=` / `ctrl-=`.
+    pub fn zoom_in(&mut self, cx: &mut Context<Self>) {
+        self.set_zoom_level(next_zoom_level(self.zoom_level, true), cx);
+    }
+
+    /// Zoom out to the previous zoom level, also by `cmd--` / `ctrl--`.
+    pub fn zoom_out(&mut self, cx: &mut Context<Self>) {
+        self.set_zoom_level(next_zoom_level(self.zoom_level, false), cx);
+    }
+
+    /// Reset the zoom level to `1.0`, also by `cmd-0` / `ctrl-0`.
+    pub fn reset_zoom(&mut self, cx: &mut Context<Self>) {
+        self.set_zoom_level(1.0, cx);
+    }
+
+    /// Open the native print dialog of the page, which can also save the page as a PDF.
+    ///
+    /// The platform webviews have no API to print to a PDF file without the dialog,
+    /// or to capture a screenshot of the page.
+    pub fn print(&self) -> anyhow::Result<()> {
+        Ok(self.webview.print()?)
+    }
+
+    /// Open the devtools of the page, also toggled by `cmd-alt-i` / `ctrl-shift-i`.
+    ///
+    /// The devtools must be enabled by [`wry::WebViewBuilder::with_devtools`],
+    /// and the `webview-devtools` feature is required in the release builds.
+    ///
+    /// The platform webviews open the devtools in a separate window (or docked in the native
+    /// window on Windows), it can't be docked into the GPUI layout, use a [`ConsolePanel`]
+    /// to show the console messages inside the app.
+    #[cfg(any(debug_assertions, feature = "webview-devtools"))]
+    pub fn open_devtools(&self) {
+        self.webview.open_devtools();
+    }
+
+    /// Close the devtools, not supported on Windows.
+    #[cfg(any(debug_assertions, feature = "webview-devtools"))]
+    pub fn close_devtools(&self) {
+        self.webview.close_devtools();
+    }
+
+    /// Returns true if the devtools is open, always false on Windows.
+    #[cfg(any(debug_assertions, feature = "webview-devtools"))]
+    pub fn is_devtools_open(&self) -> bool {
+        self.webview.is_devtools_open()
+    }
+
+    /// Toggle the devtools.
+    #[cfg(any(debug_assertions, feature = "webview-devtools"))]
+    pub fn toggle_devtools(&self) {
+        if self.is_devtools_open() {
+            self.close_devtools();
+        } else {
+            self.open_devtools();
+        }
+    }
+
+    fn on_action_toggle_devtools(
+        &mut self,
+        _: &ToggleDevTools,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) {
+        #[cfg(any(debug_assertions, feature = "webview-devtools"))]
+        self.toggle_devtools();
+    }
+
+    fn on_action_zoom_in(&mut self, _: &ZoomIn, _: &mut Window, cx: &mut Context<Self>) {
+        self.zoom_in(cx);
+    }
+
+    fn on_action_zoom_out(&mut self, _: &ZoomOut, _: &mut Window, cx: &mut Context<Self>) {
+        self.zoom_out(cx);
+    }
+
+    fn on_action_reset_zoom(&mut self, _: &ResetZoom, _: &mut Window, cx: &mut Context<Self>) {
+        self.reset_zoom(cx);
+    }
+
+    fn on_action_search(&mut self, _: &Search, window: &mut Window, cx: &mut Context<Self>) {
+        self.open_find_bar(window, cx);
+    }
+}
+
+/// Returns the next zoom level of the [`ZOOM_LEVELS`] bigger (`forward`) or smaller than `current`.
+fn next_zoom_level(current: f64, forward: bool) -> f64 {
+    let level = if forward {
+        ZOOM_LEVELS
+            .iter()
+            .find(|level| **level > current + f64::EPSILON)
+    } else {
+        ZOOM_LEVELS
+            .iter()
+            .rev()
+            .find(|level| **level < current - f64::EPSILON)
+    };
+    level.copied().unwrap_or(current)
+}
+
+impl Deref for WebView {
+    type Target = wry::WebView;
+
+    fn deref(&self) -> &Self::Target {
+        &self.webview
+    }
+}
+
+impl Focusable for WebView {
+    fn focus_handle(&self, _cx: &gpui::App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<DismissEvent> for WebView {}
+impl EventEmitter<WebViewEvent> for WebView {}
+
+impl Render for WebView {
+    fn render(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl IntoElement {
+        let view = cx.entity().clone();
+
+        v_flex()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_search))
+            .on_action(cx.listener(Self::on_action_zoom_in))
+            .on_action(cx.listener(Self::on_action_zoom_out))
+            .on_action(cx.listener(Self::on_action_reset_zoom))
+            .on_action(cx.listener(Self::on_action_toggle_devtools))
+            .size_full()
+            .child(self.find_bar.clone())
+            .child(
+                div()
+                    .relative()
+                    .flex_1()
+                    .w_full()
+                    .child({
+                        let view = cx.entity().clone();
+                        canvas(
+                            move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    })
+                    .child(
+                        WebViewElement::new(self.webview.clone(), view, window, cx)
+                            .native_bounds(self.native_bounds.clone()),
+                    ),
+            )
+    }
+}
+
+/// A webview element can display a wry webview.
+pub struct WebViewElement {
+    parent: Entity<WebView>,
+    view: Rc<wry::WebView>,
+    native_bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+}
+
+impl WebViewElement {
+    /// Create a new webview element from a wry WebView.
+    pub fn new(
+        view: Rc<wry::WebView>,
+        parent: Entity<WebView>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Self {
+        Self {
+            view,
+            parent,
+            native_bounds: Rc::new(Cell::new(None)),
+        }
+    }
+
+    fn native_bounds(mut self, native_bounds: Rc<Cell<Option<Bounds<Pixels>>>>) -> Self {
+        self.native_bounds = native_bounds;
+        self
+    }
+}
+
+impl IntoElement for WebViewElement {
+    type Element = WebViewElement;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for WebViewElement {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<Hitbox>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&gpui::InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.flex_grow = 0.0;
+        style.flex_shrink = 1.;
+        style.size = Size::full();
+        // If the parent view is no longer visible, we don't need to layout the webview
+
+        let id = window.request_layout(style, [], cx);
+        (id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&gpui::InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        if !self.parent.read(cx).visible() {
+            return None;
+        }
+
+        // The native webview is painted by the platform, only update the bounds if changed.
+        if self.native_bounds.get() != Some(bounds) {
+            self.native_bounds.set(Some(bounds));
+            self.view
+                .set_bounds(Rect {
+                    size: dpi::Size::Logical(LogicalSize {
+                        width: (bounds.size.width.as_f32()).into(),
+                        height: (bounds.size.height.as_f32()).into(),
+                    }),
+                    position: dpi::Position::Logical(dpi::LogicalPosition::new(
+                        bounds.origin.x.into(),
+                        bounds.origin.y.into(),
+                    )),
+                })
+                .unwrap();
+        }
+
+        // Create a hitbox to handle mouse event
+        Some(window.insert_hitbox(bounds, gpui::HitboxBehavior::Normal))
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&gpui::InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        window: &mut Window,
+        _: &mut App,
+    ) {
+        let bounds = hitbox.clone().map(|h| h.bounds).unwrap_or(bounds);
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            let webview = self.view.clone();
+            window.on_mouse_event(move |event: &MouseDownEvent, _, _, _| {
+                if !bounds.contains(&event.position) {
+                    // Click white space to blur the input focus
+                    let _ = webview.focus_parent();
+                }
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_zoom_level;
+
+    #[test]
+    fn test_next_zoom_level() {
+        assert_eq!(next_zoom_level(1.0, true), 1.1);
+        assert_eq!(next_zoom_level(1.0, false), 0.9);
+        assert_eq!(next_zoom_level(1.2, true), 1.25);
+        assert_eq!(next_zoom_level(1.2, false), 1.1);
+        assert_eq!(next_zoom_level(3.0, true), 3.0);
+        assert_eq!(next_zoom_level(0.25, false), 0.25);
+    }
+}
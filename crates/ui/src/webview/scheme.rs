@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+
+use gpui::AssetSource;
+use wry::http::{header, Request, Response, StatusCode};
+
+use super::RequestHandler;
+
+/// Returns the MIME type by the extension of the `path`, default is `application/octet-stream`.
+pub fn mime_type(path: &str) -> &'static str {
+    let ext = path
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "json" | "map" => "application/json",
+        "txt" => "text/plain; charset=utf-8",
+        "xml" => "application/xml",
+        "wasm" => "application/wasm",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Returns a response with the `status`, the `Content-Type` of `mime` and the `body`.
+pub fn response(
+    status: StatusCode,
+    mime: &str,
+    body: impl Into<Cow<'static, [u8]>>,
+) -> Response<Cow<'static, [u8]>> {
+    let mut response = Response::new(body.into());
+    *response.status_mut() = status;
+    if let Ok(mime) = header::HeaderValue::from_str(mime) {
+        response.headers_mut().insert(header::CONTENT_TYPE, mime);
+    }
+    response
+}
+
+/// A [`RequestHandler`] to serve the files of an [`AssetSource`], e.g.: a web UI bundled
+/// into the binary by `rust-embed`, without a local HTTP server.
+///
+/// The response is the whole file, since the platform webviews don't support streaming
+/// the body of a custom scheme.
+///
+/// ```ignore
+/// #[derive(RustEmbed)]
+/// #[folder = "web/dist"]
+/// struct WebAssets;
+///
+/// impl AssetSource for WebAssets { ... }
+///
+/// let hooks = WebViewHooks::new().request_handler("app", AssetHandler::new(WebAssets));
+/// // Load `app://localhost/` (or `http://app.localhost/` on Windows) to serve `index.html`.
+/// ```
+pub struct AssetHandler<A> {
+    source: A,
+    prefix: String,
+    index: String,
+    fallback: Option<String>,
+}
+
+impl<A: AssetSource> AssetHandler<A> {
+    pub fn new(source: A) -> Self {
+        Self {
+            source,
+            prefix: String::new(),
+            index: "index.html".to_string(),
+            fallback: None,
+        }
+    }
+
+    /// Set the prefix of the paths in the source, e.g.: `web/` to serve `web/index.html`
+    /// for `/index.html`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set the file to serve for the directories, default is `index.html`.
+    pub fn index(mut self, index: impl Into<String>) -> Self {
+        self.index = index.into();
+        self
+    }
+
+    /// Set the file to serve for the missing paths, e.g.: `index.html` for the client-side
+    /// routing of a single page application, default is `404 Not Found`.
+    pub fn fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Returns the path in the source of the request `path`.
+    fn source_path(&self, path: &str) -> String {
+        let path = path.trim_start_matches('/');
+        if path.is_empty() || path.ends_with('/') {
+            format!("{}{}{}", self.prefix, path, self.index)
+        } else {
+            format!("{}{}", self.prefix, path)
+        }
+    }
+
+    fn load(&self, path: &str) -> Option<Response<Cow<'static, [u8]>>> {
+        match self.source.load(path) {
+            Ok(Some(data)) => Some(response(StatusCode::OK, mime_type(path), data)),
+            Ok(None) => None,
+            Err(err) => Some(response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "text/plain; charset=utf-8",
+                err.to_string().into_bytes(),
+            )),
+        }
+    }
+}
+
+impl<A: AssetSource> RequestHandler for AssetHandler<A> {
+    fn handle(&self, request: &Request<Vec<u8>>) -> Option<Response<Cow<'static, [u8]>>> {
+        let path = request.uri().path();
+        // Don't serve the files outside of the source.
+        if path.split('/').any(|segment| segment == "..") {
+            return None;
+        }
+
+        let path = self.source_path(path);
+        self.load(&path).or_else(|| {
+            let fallback = self.fallback.as_ref()?;
+            self.load(&format!("{}{}", self.prefix, fallback))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use gpui::{AssetSource, SharedString};
+    use wry::http::Request;
+
+    use super::{mime_type, AssetHandler};
+    use crate::webview::RequestHandler as _;
+
+    struct Assets;
+
+    impl AssetSource for Assets {
+        fn load(&self, path: &str) -> anyhow::Result<Option<Cow<'static, [u8]>>> {
+            Ok(match path {
+                "web/index.html" => Some(Cow::Borrowed(b"<html>")),
+                "web/app.js" => Some(Cow::Borrowed(b"main()")),
+                _ => None,
+            })
+        }
+
+        fn list(&self, _: &str) -> anyhow::Result<Vec<SharedString>> {
+            Ok(vec![])
+        }
+    }
+
+    #[test]
+    fn test_mime_type() {
+        assert_eq!(mime_type("index.html"), "text/html; charset=utf-8");
+        assert_eq!(
+            mime_type("/assets/app.min.JS"),
+            "text/javascript; charset=utf-8"
+        );
+        assert_eq!(mime_type("logo.svg"), "image/svg+xml");
+        assert_eq!(mime_type("a.dir/LICENSE"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_asset_handler() {
+        let request = |path: &str| Request::get(path).body(vec![]).unwrap();
+        let handler = AssetHandler::new(Assets).prefix("web/");
+
+        let response = handler.handle(&request("app://localhost/")).unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.body().as_ref(), b"<html>");
+        assert_eq!(
+            response.headers()["content-type"],
+            "text/html; charset=utf-8"
+        );
+
+        let response = handler.handle(&request("app://localhost/app.js")).unwrap();
+        assert_eq!(response.body().as_ref(), b"main()");
+        assert!(handler
+            .handle(&request("app://localhost/../web/app.js"))
+            .is_none());
+        assert!(handler
+            .handle(&request("app://localhost/settings"))
+            .is_none());
+
+        let handler = handler.fallback("index.html");
+        let response = handler
+            .handle(&request("app://localhost/settings"))
+            .unwrap();
+        assert_eq!(response.body().as_ref(), b"<html>");
+    }
+}
@@ -0,0 +1,816 @@
+use gpui::{
+    actions, canvas, div, point, prelude::FluentBuilder as _, px, App, AppContext as _, Bounds,
+    Context, Corner, Entity, EventEmitter, FocusHandle, Focusable, Hsla, InteractiveElement as _,
+    IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent, ParentElement,
+    PathBuilder, Pixels, Point, Render, ScrollWheelEvent, SharedString, Styled, Subscription,
+    Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::Cancel,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    history::History,
+    input::{InputEvent, InputState, TextInput},
+    ActiveTheme, Selectable as _, Sizable as _,
+};
+
+mod scene;
+pub use scene::*;
+
+actions!(whiteboard, [Undo, Redo, Delete]);
+
+const CONTEXT: &str = "Whiteboard";
+pub(crate) fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("backspace", Delete, Some(CONTEXT)),
+        KeyBinding::new("delete", Delete, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-z", Undo, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-z", Redo, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-z", Undo, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-y", Redo, Some(CONTEXT)),
+    ]);
+}
+
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 8.;
+const HANDLE_SIZE: Pixels = px(8.);
+const TEXT_FONT_SIZE: Pixels = px(16.);
+/// The line height ratio of the text shape, the font size is `height / TEXT_LINE_HEIGHT`.
+const TEXT_LINE_HEIGHT: f32 = 1.25;
+
+/// The drawing tool of the [`Whiteboard`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WhiteboardTool {
+    /// Select, move and resize shapes, drag on empty space to pan.
+    #[default]
+    Select,
+    Rect,
+    Ellipse,
+    Arrow,
+    Text,
+    /// Freehand ink strokes.
+    Pen,
+}
+
+impl WhiteboardTool {
+    fn label(&self) -> SharedString {
+        match self {
+            Self::Select => t!("Whiteboard.Select"),
+            Self::Rect => t!("Whiteboard.Rect"),
+            Self::Ellipse => t!("Whiteboard.Ellipse"),
+            Self::Arrow => t!("Whiteboard.Arrow"),
+            Self::Text => t!("Whiteboard.Text"),
+            Self::Pen => t!("Whiteboard.Pen"),
+        }
+        .into()
+    }
+}
+
+/// Events emitted by the [`Whiteboard`].
+#[derive(Clone, Debug)]
+pub enum WhiteboardEvent {
+    /// The scene has been changed.
+    Change,
+}
+
+enum DragState {
+    Pan {
+        start: Point<Pixels>,
+        offset: Point<Pixels>,
+    },
+    Draw(Shape),
+    Move {
+        start: Point<Pixels>,
+        original: Shape,
+    },
+    Resize {
+        /// The fixed corner on resizing.
+        anchor: Point<Pixels>,
+        original: Shape,
+    },
+}
+
+/// An infinite canvas to draw shapes and freehand strokes.
+///
+/// - Scroll to pan, scroll with `cmd` (macOS) or `ctrl` to zoom.
+/// - Drag on empty space or with middle mouse button to pan.
+/// - The scene can be serialized to JSON by [`Whiteboard::to_json`].
+pub struct Whiteboard {
+    focus_handle: FocusHandle,
+    scene: Scene,
+    history: History<SceneChange>,
+    tool: WhiteboardTool,
+    color: Option<Hsla>,
+    stroke_width: Pixels,
+    offset: Point<Pixels>,
+    zoom: f32,
+    bounds: Bounds<Pixels>,
+    selected: Option<usize>,
+    drag: Option<DragState>,
+    editing_text: Option<(Point<Pixels>, Entity<InputState>)>,
+    show_toolbar: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Whiteboard {
+    pub fn new(_: &mut Window, cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            scene: Scene::default(),
+            history: History::new(),
+            tool: WhiteboardTool::default(),
+            color: None,
+            stroke_width: px(2.),
+            offset: Point::default(),
+            zoom: 1.,
+            bounds: Bounds::default(),
+            selected: None,
+            drag: None,
+            editing_text: None,
+            show_toolbar: true,
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Set whether to show the built-in toolbar, default is true.
+    pub fn toolbar(mut self, show_toolbar: bool) -> Self {
+        self.show_toolbar = show_toolbar;
+        self
+    }
+
+    /// Returns the scene of the whiteboard.
+    pub fn scene(&self) -> &Scene {
+        &self.scene
+    }
+
+    /// Replace the scene, this will clear the undo history.
+    pub fn set_scene(&mut self, scene: Scene, cx: &mut Context<Self>) {
+        self.scene = scene;
+        self.history.clear();
+        self.selected = None;
+        self.drag = None;
+        cx.notify();
+    }
+
+    /// Serialize the scene to JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        self.scene.to_json()
+    }
+
+    /// Load the scene from JSON, this will clear the undo history.
+    pub fn load_json(&mut self, json: &str, cx: &mut Context<Self>) -> anyhow::Result<()> {
+        let scene = Scene::from_json(json)?;
+        self.set_scene(scene, cx);
+        Ok(())
+    }
+
+    pub fn tool(&self) -> WhiteboardTool {
+        self.tool
+    }
+
+    pub fn set_tool(&mut self, tool: WhiteboardTool, window: &mut Window, cx: &mut Context<Self>) {
+        self.commit_text(window, cx);
+        self.tool = tool;
+        if tool != WhiteboardTool::Select {
+            self.selected = None;
+        }
+        cx.notify();
+    }
+
+    /// Set the color for new shapes, default is the theme foreground color.
+    pub fn set_color(&mut self, color: impl Into<Hsla>, cx: &mut Context<Self>) {
+        self.color = Some(color.into());
+        cx.notify();
+    }
+
+    /// Set the stroke width for new shapes, default is 2px.
+    pub fn set_stroke_width(&mut self, stroke_width: impl Into<Pixels>, cx: &mut Context<Self>) {
+        self.stroke_width = stroke_width.into();
+        cx.notify();
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Set the zoom level, clamped in 0.1 to 8.0.
+    pub fn set_zoom(&mut self, zoom: f32, cx: &mut Context<Self>) {
+        let center = point(self.bounds.size.width / 2., self.bounds.size.height / 2.);
+        self.zoom_at(zoom, center);
+        cx.notify();
+    }
+
+    /// Reset the pan and zoom.
+    pub fn reset_view(&mut self, cx: &mut Context<Self>) {
+        self.offset = Point::default();
+        self.zoom = 1.;
+        cx.notify();
+    }
+
+    /// Returns the selected shape id.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Zoom to the `zoom` level and keep the scene point at the `position` (relative to bounds) fixed.
+    fn zoom_at(&mut self, zoom: f32, position: Point<Pixels>) {
+        let zoom = zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        let scene_pos = point(
+            (position.x - self.offset.x) / self.zoom,
+            (position.y - self.offset.y) / self.zoom,
+        );
+        self.zoom = zoom;
+        self.offset = point(
+            position.x - scene_pos.x * zoom,
+            position.y - scene_pos.y * zoom,
+        );
+    }
+
+    /// Convert the window position to the scene position.
+    fn to_scene(&self, position: Point<Pixels>) -> Point<Pixels> {
+        point(
+            (position.x - self.bounds.origin.x - self.offset.x) / self.zoom,
+            (position.y - self.bounds.origin.y - self.offset.y) / self.zoom,
+        )
+    }
+
+    /// Convert the scene position to the position relative to the whiteboard bounds.
+    fn to_local(&self, position: Point<Pixels>) -> Point<Pixels> {
+        point(
+            self.offset.x + position.x * self.zoom,
+            self.offset.y + position.y * self.zoom,
+        )
+    }
+
+    fn color(&self, cx: &App) -> Hsla {
+        self.color.unwrap_or(cx.theme().foreground)
+    }
+
+    fn new_shape(&self, kind: ShapeKind, position: Point<Pixels>, cx: &App) -> Shape {
+        Shape {
+            id: self.scene.next_id(),
+            kind,
+            points: match kind {
+                ShapeKind::Ink => vec![position],
+                _ => vec![position, position],
+            },
+            text: SharedString::default(),
+            color: self.color(cx),
+            stroke_width: self.stroke_width,
+        }
+    }
+
+    fn push_change(
+        &mut self,
+        ix: usize,
+        old: Option<Shape>,
+        new: Option<Shape>,
+        cx: &mut Context<Self>,
+    ) {
+        let change = SceneChange::new(ix, old, new);
+        self.scene.apply(&change, false);
+        self.history.push(change);
+        cx.emit(WhiteboardEvent::Change);
+        cx.notify();
+    }
+
+    fn add_shape(&mut self, shape: Shape, cx: &mut Context<Self>) {
+        let ix = self.scene.shapes.len();
+        self.push_change(ix, None, Some(shape), cx);
+    }
+
+    pub fn undo(&mut self, cx: &mut Context<Self>) {
+        if let Some(changes) = self.history.undo() {
+            for change in changes {
+                self.scene.apply(&change, true);
+            }
+            self.selected = None;
+            cx.emit(WhiteboardEvent::Change);
+            cx.notify();
+        }
+    }
+
+    pub fn redo(&mut self, cx: &mut Context<Self>) {
+        if let Some(changes) = self.history.redo() {
+            for change in changes {
+                self.scene.apply(&change, false);
+            }
+            self.selected = None;
+            cx.emit(WhiteboardEvent::Change);
+            cx.notify();
+        }
+    }
+
+    /// Delete the selected shape.
+    pub fn delete_selected(&mut self, cx: &mut Context<Self>) {
+        let Some(id) = self.selected.take() else {
+            return;
+        };
+        let Some(ix) = self.scene.shapes.iter().position(|s| s.id == id) else {
+            return;
+        };
+
+        let old = self.scene.shapes[ix].clone();
+        self.push_change(ix, Some(old), None, cx);
+    }
+
+    fn on_action_undo(&mut self, _: &Undo, _: &mut Window, cx: &mut Context<Self>) {
+        self.undo(cx);
+    }
+
+    fn on_action_redo(&mut self, _: &Redo, _: &mut Window, cx: &mut Context<Self>) {
+        self.redo(cx);
+    }
+
+    fn on_action_delete(&mut self, _: &Delete, _: &mut Window, cx: &mut Context<Self>) {
+        self.delete_selected(cx);
+    }
+
+    fn on_action_cancel(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
+        if self.selected.is_none() && self.drag.is_none() {
+            cx.propagate();
+            return;
+        }
+
+        self.selected = None;
+        self.drag = None;
+        cx.notify();
+    }
+
+    /// Start to edit a new text shape at the scene position.
+    fn start_text(&mut self, position: Point<Pixels>, window: &mut Window, cx: &mut Context<Self>) {
+        let input = cx.new(|cx| InputState::new(window, cx));
+        input.update(cx, |input, cx| input.focus(window, cx));
+        self._subscriptions = vec![cx.subscribe_in(
+            &input,
+            window,
+            |this, _, ev: &InputEvent, window, cx| match ev {
+                InputEvent::PressEnter { .. } | InputEvent::Blur => this.commit_text(window, cx),
+                _ => {}
+            },
+        )];
+        self.editing_text = Some((position, input));
+        cx.notify();
+    }
+
+    /// Commit the editing text to a text shape.
+    fn commit_text(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((position, input)) = self.editing_text.take() else {
+            return;
+        };
+        self._subscriptions.clear();
+
+        let text = input.read(cx).value();
+        if !text.trim().is_empty() {
+            let width = TEXT_FONT_SIZE * 0.6 * text.chars().count() as f32;
+            let height = TEXT_FONT_SIZE * TEXT_LINE_HEIGHT;
+            let mut shape = self.new_shape(ShapeKind::Text, position, cx);
+            shape.points[1] = point(position.x + width, position.y + height);
+            shape.text = text;
+            self.add_shape(shape, cx);
+        }
+        self.focus_handle.focus(window);
+        cx.notify();
+    }
+
+    /// Returns the corner of the selected shape's resize handle at the window position.
+    fn handle_at(&self, position: Point<Pixels>) -> Option<(Corner, Shape)> {
+        let shape = self.scene.shape(self.selected?)?;
+        let bounds = shape.bounds();
+        let local = position - self.bounds.origin;
+        [
+            Corner::TopLeft,
+            Corner::TopRight,
+            Corner::BottomLeft,
+            Corner::BottomRight,
+        ]
+        .into_iter()
+        .find(|corner| {
+            let p = self.to_local(bounds.corner(*corner));
+            (local.x - p.x).abs() <= HANDLE_SIZE && (local.y - p.y).abs() <= HANDLE_SIZE
+        })
+        .map(|corner| (corner, shape.clone()))
+    }
+
+    fn on_mouse_down(&mut self, ev: &MouseDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.commit_text(window, cx);
+        self.focus_handle.focus(window);
+
+        if ev.button == MouseButton::Middle {
+            self.drag = Some(DragState::Pan {
+                start: ev.position,
+                offset: self.offset,
+            });
+            return;
+        }
+
+        let position = self.to_scene(ev.position);
+        self.drag = match self.tool {
+            WhiteboardTool::Select => {
+                let tolerance = px(4.) / self.zoom;
+                if let Some((corner, original)) = self.handle_at(ev.position) {
+                    let bounds = original.bounds();
+                    let anchor = match corner {
+                        Corner::TopLeft => bounds.bottom_right(),
+                        Corner::TopRight => bounds.bottom_left(),
+                        Corner::BottomLeft => bounds.top_right(),
+                        Corner::BottomRight => bounds.origin,
+                    };
+                    Some(DragState::Resize { anchor, original })
+                } else if let Some(id) = self.scene.hit_test(position, tolerance) {
+                    self.selected = Some(id);
+                    self.scene.shape(id).map(|shape| DragState::Move {
+                        start: position,
+                        original: shape.clone(),
+                    })
+                } else {
+                    self.selected = None;
+                    Some(DragState::Pan {
+                        start: ev.position,
+                        offset: self.offset,
+                    })
+                }
+            }
+            WhiteboardTool::Rect => Some(DragState::Draw(self.new_shape(
+                ShapeKind::Rect,
+                position,
+                cx,
+            ))),
+            WhiteboardTool::Ellipse => Some(DragState::Draw(self.new_shape(
+                ShapeKind::Ellipse,
+                position,
+                cx,
+            ))),
+            WhiteboardTool::Arrow => Some(DragState::Draw(self.new_shape(
+                ShapeKind::Arrow,
+                position,
+                cx,
+            ))),
+            WhiteboardTool::Pen => Some(DragState::Draw(self.new_shape(
+                ShapeKind::Ink,
+                position,
+                cx,
+            ))),
+            WhiteboardTool::Text => {
+                self.start_text(position, window, cx);
+                None
+            }
+        };
+        cx.notify();
+    }
+
+    fn on_mouse_move(&mut self, ev: &MouseMoveEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let position = self.to_scene(ev.position);
+        let Some(drag) = self.drag.as_mut() else {
+            return;
+        };
+
+        match drag {
+            DragState::Pan { start, offset } => {
+                self.offset = *offset + (ev.position - *start);
+            }
+            DragState::Draw(shape) => {
+                if shape.kind == ShapeKind::Ink {
+                    // Skip the points too close to the last one, to reduce the jitter.
+                    let last = shape.points[shape.points.len() - 1];
+                    let min_distance = px(2.) / self.zoom;
+                    if (position.x - last.x).abs() < min_distance
+                        && (position.y - last.y).abs() < min_distance
+                    {
+                        return;
+                    }
+                    shape.points.push(position);
+                } else {
+                    shape.points[1] = position;
+                }
+            }
+            DragState::Move { start, original } => {
+                let mut shape = original.clone();
+                shape.translate(position - *start);
+                if let Some(s) = self.scene.shapes.iter_mut().find(|s| s.id == shape.id) {
+                    *s = shape;
+                }
+            }
+            DragState::Resize { anchor, original } => {
+                let mut shape = original.clone();
+                let to = Bounds::from_corners(anchor.min(&position), anchor.max(&position));
+                shape.transform(original.bounds(), to);
+                if let Some(s) = self.scene.shapes.iter_mut().find(|s| s.id == shape.id) {
+                    *s = shape;
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_mouse_up(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(drag) = self.drag.take() else {
+            return;
+        };
+
+        match drag {
+            DragState::Pan { .. } => {}
+            DragState::Draw(mut shape) => {
+                let bounds = shape.bounds();
+                let is_empty = match shape.kind {
+                    ShapeKind::Ink => false,
+                    _ => bounds.size.width < px(2.) && bounds.size.height < px(2.),
+                };
+                if !is_empty {
+                    if shape.kind == ShapeKind::Ink {
+                        shape.points = smooth_points(&shape.points, 2);
+                    }
+                    self.add_shape(shape, cx);
+                }
+            }
+            DragState::Move { original, .. } | DragState::Resize { original, .. } => {
+                let Some(ix) = self.scene.shapes.iter().position(|s| s.id == original.id) else {
+                    return;
+                };
+
+                let new = self.scene.shapes[ix].clone();
+                if new != original {
+                    // Restore the original shape, then apply the change by history.
+                    self.scene.shapes[ix] = original.clone();
+                    self.push_change(ix, Some(original), Some(new), cx);
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    fn on_scroll_wheel(&mut self, ev: &ScrollWheelEvent, _: &mut Window, cx: &mut Context<Self>) {
+        let delta = ev.delta.pixel_delta(px(20.));
+        if ev.modifiers.secondary() {
+            let zoom = self.zoom * (1. + f32::from(delta.y) / 200.);
+            self.zoom_at(zoom, ev.position - self.bounds.origin);
+        } else {
+            self.offset = self.offset + delta;
+        }
+        cx.notify();
+    }
+
+    fn render_toolbar(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .occlude()
+            .absolute()
+            .top_2()
+            .left_2()
+            .gap_1()
+            .p_1()
+            .rounded(cx.theme().radius)
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .shadow_sm()
+            .children(
+                [
+                    WhiteboardTool::Select,
+                    WhiteboardTool::Rect,
+                    WhiteboardTool::Ellipse,
+                    WhiteboardTool::Arrow,
+                    WhiteboardTool::Text,
+                    WhiteboardTool::Pen,
+                ]
+                .into_iter()
+                .enumerate()
+                .map(|(ix, tool)| {
+                    Button::new(("tool", ix))
+                        .ghost()
+                        .xsmall()
+                        .label(tool.label())
+                        .selected(self.tool == tool)
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            this.set_tool(tool, window, cx);
+                        }))
+                }),
+            )
+    }
+
+    /// Render the text shapes, selection and the text editor as elements.
+    fn render_overlays(&self, cx: &mut Context<Self>) -> Vec<gpui::AnyElement> {
+        let mut items = vec![];
+
+        for shape in self.scene.shapes.iter() {
+            if shape.kind != ShapeKind::Text {
+                continue;
+            }
+
+            let bounds = shape.bounds();
+            let origin = self.to_local(bounds.origin);
+            let font_size = bounds.size.height / TEXT_LINE_HEIGHT * self.zoom;
+            items.push(
+                div()
+                    .absolute()
+                    .left(origin.x)
+                    .top(origin.y)
+                    .w(bounds.size.width * self.zoom)
+                    .h(bounds.size.height * self.zoom)
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_size(font_size)
+                    .line_height(font_size * TEXT_LINE_HEIGHT)
+                    .text_color(shape.color)
+                    .child(shape.text.clone())
+                    .into_any_element(),
+            );
+        }
+
+        if let Some(shape) = self.selected.and_then(|id| self.scene.shape(id)) {
+            let bounds = shape.bounds();
+            let origin = self.to_local(bounds.origin);
+            let size = bounds.size.map(|v| v * self.zoom);
+            let ring = cx.theme().ring;
+
+            items.push(
+                div()
+                    .absolute()
+                    .left(origin.x - px(2.))
+                    .top(origin.y - px(2.))
+                    .w(size.width + px(4.))
+                    .h(size.height + px(4.))
+                    .border_1()
+                    .border_color(ring)
+                    .into_any_element(),
+            );
+            for corner in [
+                Corner::TopLeft,
+                Corner::TopRight,
+                Corner::BottomLeft,
+                Corner::BottomRight,
+            ] {
+                let p = self.to_local(bounds.corner(corner));
+                items.push(
+                    div()
+                        .absolute()
+                        .left(p.x - HANDLE_SIZE / 2.)
+                        .top(p.y - HANDLE_SIZE / 2.)
+                        .size(HANDLE_SIZE)
+                        .border_1()
+                        .border_color(ring)
+                        .bg(cx.theme().background)
+                        .into_any_element(),
+                );
+            }
+        }
+
+        if let Some((position, input)) = self.editing_text.as_ref() {
+            let origin = self.to_local(*position);
+            items.push(
+                div()
+                    .id("text-editor")
+                    .occlude()
+                    .absolute()
+                    .left(origin.x)
+                    .top(origin.y)
+                    .w(px(200.))
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                    .child(TextInput::new(input).xsmall())
+                    .into_any_element(),
+            );
+        }
+
+        items
+    }
+}
+
+/// Paint the shape to the window, the `origin` and `zoom` is used to transform
+/// the scene coordinates to the window coordinates.
+fn paint_shape(shape: &Shape, origin: Point<Pixels>, zoom: f32, window: &mut Window) {
+    let map = |p: &Point<Pixels>| point(origin.x + p.x * zoom, origin.y + p.y * zoom);
+    let points: Vec<_> = shape.points.iter().map(map).collect();
+    if points.is_empty() {
+        return;
+    }
+
+    let mut builder = PathBuilder::stroke(shape.stroke_width * zoom);
+    match shape.kind {
+        ShapeKind::Text => return,
+        ShapeKind::Rect => {
+            let bounds = Bounds::from_corners(points[0].min(&points[1]), points[0].max(&points[1]));
+            builder.add_polygon(
+                &[
+                    bounds.origin,
+                    bounds.top_right(),
+                    bounds.bottom_right(),
+                    bounds.bottom_left(),
+                ],
+                true,
+            );
+        }
+        ShapeKind::Ellipse => {
+            let bounds = Bounds::from_corners(points[0].min(&points[1]), points[0].max(&points[1]));
+            let center = bounds.center();
+            let radii = point(bounds.size.width / 2., bounds.size.height / 2.);
+            builder.move_to(point(bounds.left(), center.y));
+            builder.arc_to(radii, px(0.), false, true, point(bounds.right(), center.y));
+            builder.arc_to(radii, px(0.), false, true, point(bounds.left(), center.y));
+            builder.close();
+        }
+        ShapeKind::Arrow => {
+            let (start, end) = (points[0], points[1]);
+            builder.move_to(start);
+            builder.line_to(end);
+
+            let angle = f32::from(end.y - start.y).atan2(f32::from(end.x - start.x));
+            let head = (shape.stroke_width * 5. * zoom).max(px(8.));
+            for a in [angle + 2.6, angle - 2.6] {
+                builder.move_to(end);
+                builder.line_to(point(end.x + head * a.cos(), end.y + head * a.sin()));
+            }
+        }
+        ShapeKind::Ink => {
+            builder.move_to(points[0]);
+            if points.len() == 1 {
+                builder.line_to(points[0] + point(px(0.5), px(0.5)));
+            } else {
+                // Draw the quadratic curves through the middle points to get smooth stroke.
+                for w in points.windows(2).skip(1) {
+                    let mid = point((w[0].x + w[1].x) / 2., (w[0].y + w[1].y) / 2.);
+                    builder.curve_to(mid, w[0]);
+                }
+                builder.line_to(points[points.len() - 1]);
+            }
+        }
+    }
+
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, shape.color);
+    }
+}
+
+impl EventEmitter<WhiteboardEvent> for Whiteboard {}
+
+impl Focusable for Whiteboard {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Whiteboard {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity().clone();
+        let offset = self.offset;
+        let zoom = self.zoom;
+        let mut shapes: Vec<Shape> = self.scene.shapes.clone();
+        if let Some(DragState::Draw(shape)) = self.drag.as_ref() {
+            shapes.push(shape.clone());
+        }
+
+        div()
+            .id("whiteboard")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::on_action_undo))
+            .on_action(cx.listener(Self::on_action_redo))
+            .on_action(cx.listener(Self::on_action_delete))
+            .on_action(cx.listener(Self::on_action_cancel))
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .bg(cx.theme().background)
+            .when(self.tool != WhiteboardTool::Select, |this| {
+                this.cursor_crosshair()
+            })
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_down(MouseButton::Middle, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| this.on_mouse_up(window, cx)),
+            )
+            .on_mouse_up(
+                MouseButton::Middle,
+                cx.listener(|this, _, window, cx| this.on_mouse_up(window, cx)),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| this.on_mouse_up(window, cx)),
+            )
+            .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+            .child(
+                canvas(
+                    move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    move |bounds, _, window, _| {
+                        let origin = bounds.origin + offset;
+                        for shape in shapes.iter() {
+                            paint_shape(shape, origin, zoom, window);
+                        }
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children(self.render_overlays(cx))
+            .when(self.show_toolbar, |this| {
+                this.child(self.render_toolbar(window, cx))
+            })
+    }
+}
@@ -0,0 +1,325 @@
+use gpui::{point, px, Bounds, Hsla, Pixels, Point, SharedString};
+use serde::{Deserialize, Serialize};
+
+use crate::history::HistoryItem;
+
+/// The kind of a [`Shape`] in the [`Scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShapeKind {
+    Rect,
+    Ellipse,
+    Arrow,
+    Text,
+    /// The freehand stroke.
+    Ink,
+}
+
+/// A shape in the whiteboard scene.
+///
+/// All points are in scene coordinates:
+///
+/// - `Rect`, `Ellipse`, `Text`: two corners of the bounds.
+/// - `Arrow`: the start and end points.
+/// - `Ink`: the stroke points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shape {
+    pub id: usize,
+    pub kind: ShapeKind,
+    pub points: Vec<Point<Pixels>>,
+    #[serde(default, skip_serializing_if = "is_empty_text")]
+    pub text: SharedString,
+    pub color: Hsla,
+    pub stroke_width: Pixels,
+}
+
+fn is_empty_text(text: &SharedString) -> bool {
+    text.is_empty()
+}
+
+impl Shape {
+    /// Returns the bounds of the shape, not including the stroke width.
+    pub fn bounds(&self) -> Bounds<Pixels> {
+        let Some(first) = self.points.first() else {
+            return Bounds::default();
+        };
+
+        let (min, max) = self
+            .points
+            .iter()
+            .fold((*first, *first), |(min, max), p| (min.min(p), max.max(p)));
+        Bounds::from_corners(min, max)
+    }
+
+    /// Returns true if the `position` hits the shape with the `tolerance`.
+    pub fn contains(&self, position: Point<Pixels>, tolerance: Pixels) -> bool {
+        let tolerance = tolerance + self.stroke_width / 2.;
+        match self.kind {
+            ShapeKind::Rect | ShapeKind::Ellipse | ShapeKind::Text => {
+                self.bounds().dilate(tolerance).contains(&position)
+            }
+            ShapeKind::Arrow | ShapeKind::Ink => {
+                if self.points.len() == 1 {
+                    return distance(self.points[0], position) <= tolerance;
+                }
+
+                self.points
+                    .windows(2)
+                    .any(|w| distance_to_segment(position, w[0], w[1]) <= tolerance)
+            }
+        }
+    }
+
+    /// Move the shape by the `delta`.
+    pub fn translate(&mut self, delta: Point<Pixels>) {
+        for p in self.points.iter_mut() {
+            *p = *p + delta;
+        }
+    }
+
+    /// Map the shape points from the `from` bounds to the `to` bounds.
+    pub fn transform(&mut self, from: Bounds<Pixels>, to: Bounds<Pixels>) {
+        let scale = |v: Pixels, from_origin: Pixels, from_size: Pixels, to_origin, to_size| {
+            if from_size == px(0.) {
+                to_origin
+            } else {
+                to_origin + (v - from_origin) * (to_size / from_size)
+            }
+        };
+
+        for p in self.points.iter_mut() {
+            p.x = scale(
+                p.x,
+                from.origin.x,
+                from.size.width,
+                to.origin.x,
+                to.size.width,
+            );
+            p.y = scale(
+                p.y,
+                from.origin.y,
+                from.size.height,
+                to.origin.y,
+                to.size.height,
+            );
+        }
+    }
+}
+
+fn distance(a: Point<Pixels>, b: Point<Pixels>) -> Pixels {
+    let dx = f32::from(a.x - b.x);
+    let dy = f32::from(a.y - b.y);
+    px((dx * dx + dy * dy).sqrt())
+}
+
+fn distance_to_segment(p: Point<Pixels>, a: Point<Pixels>, b: Point<Pixels>) -> Pixels {
+    let (ax, ay) = (f32::from(a.x), f32::from(a.y));
+    let (dx, dy) = (f32::from(b.x) - ax, f32::from(b.y) - ay);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0. {
+        return distance(p, a);
+    }
+
+    let t = (((f32::from(p.x) - ax) * dx + (f32::from(p.y) - ay) * dy) / len2).clamp(0., 1.);
+    distance(p, point(px(ax + t * dx), px(ay + t * dy)))
+}
+
+/// Smooth the freehand stroke points by Chaikin's corner cutting, keep the start and end points.
+pub(crate) fn smooth_points(points: &[Point<Pixels>], iterations: usize) -> Vec<Point<Pixels>> {
+    let mut points = points.to_vec();
+    for _ in 0..iterations {
+        if points.len() < 3 {
+            break;
+        }
+
+        let mut smoothed = Vec::with_capacity(points.len() * 2);
+        smoothed.push(points[0]);
+        for w in points.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            smoothed.push(point(a.x * 0.75 + b.x * 0.25, a.y * 0.75 + b.y * 0.25));
+            smoothed.push(point(a.x * 0.25 + b.x * 0.75, a.y * 0.25 + b.y * 0.75));
+        }
+        smoothed.push(points[points.len() - 1]);
+        points = smoothed;
+    }
+    points
+}
+
+/// The scene of a [`super::Whiteboard`], can be serialized to JSON.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scene {
+    pub shapes: Vec<Shape>,
+}
+
+impl Scene {
+    /// Parse the scene from JSON.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serialize the scene to JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns the next available shape id.
+    pub(crate) fn next_id(&self) -> usize {
+        self.shapes.iter().map(|s| s.id + 1).max().unwrap_or(0)
+    }
+
+    pub fn shape(&self, id: usize) -> Option<&Shape> {
+        self.shapes.iter().find(|s| s.id == id)
+    }
+
+    /// Returns the id of the top most shape at the position.
+    pub fn hit_test(&self, position: Point<Pixels>, tolerance: Pixels) -> Option<usize> {
+        self.shapes
+            .iter()
+            .rev()
+            .find(|s| s.contains(position, tolerance))
+            .map(|s| s.id)
+    }
+
+    /// Apply the change to the scene, use `undo` to revert the change.
+    pub(crate) fn apply(&mut self, change: &SceneChange, undo: bool) {
+        let (from, to) = if undo {
+            (&change.new, &change.old)
+        } else {
+            (&change.old, &change.new)
+        };
+
+        let id = from.as_ref().or(to.as_ref()).map(|s| s.id);
+        let ix = id.and_then(|id| self.shapes.iter().position(|s| s.id == id));
+        match (ix, to) {
+            (Some(ix), Some(shape)) => self.shapes[ix] = shape.clone(),
+            (Some(ix), None) => {
+                self.shapes.remove(ix);
+            }
+            (None, Some(shape)) => {
+                let ix = change.ix.min(self.shapes.len());
+                self.shapes.insert(ix, shape.clone());
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// A change of a shape in the scene, used for undo/redo in [`crate::history::History`].
+///
+/// - Add: `old` is None.
+/// - Remove: `new` is None.
+/// - Update: both are present.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SceneChange {
+    /// The index of the shape in the scene.
+    ix: usize,
+    old: Option<Shape>,
+    new: Option<Shape>,
+    version: usize,
+}
+
+impl SceneChange {
+    pub(crate) fn new(ix: usize, old: Option<Shape>, new: Option<Shape>) -> Self {
+        Self {
+            ix,
+            old,
+            new,
+            version: 0,
+        }
+    }
+}
+
+impl HistoryItem for SceneChange {
+    fn version(&self) -> usize {
+        self.version
+    }
+
+    fn set_version(&mut self, version: usize) {
+        self.version = version;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, Bounds, Hsla};
+
+    use super::{smooth_points, Scene, SceneChange, Shape, ShapeKind};
+
+    fn shape(id: usize, kind: ShapeKind, points: &[(f32, f32)]) -> Shape {
+        Shape {
+            id,
+            kind,
+            points: points.iter().map(|(x, y)| point(px(*x), px(*y))).collect(),
+            text: "".into(),
+            color: Hsla::default(),
+            stroke_width: px(2.),
+        }
+    }
+
+    #[test]
+    fn test_hit_test() {
+        let scene = Scene {
+            shapes: vec![
+                shape(0, ShapeKind::Rect, &[(0., 0.), (100., 100.)]),
+                shape(1, ShapeKind::Arrow, &[(50., 50.), (200., 50.)]),
+            ],
+        };
+
+        assert_eq!(scene.hit_test(point(px(10.), px(10.)), px(0.)), Some(0));
+        assert_eq!(scene.hit_test(point(px(60.), px(51.)), px(0.)), Some(1));
+        assert_eq!(scene.hit_test(point(px(150.), px(60.)), px(2.)), None);
+        assert_eq!(scene.hit_test(point(px(150.), px(60.)), px(10.)), Some(1));
+        assert_eq!(scene.next_id(), 2);
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut s = shape(0, ShapeKind::Ink, &[(0., 0.), (10., 20.)]);
+        s.translate(point(px(5.), px(5.)));
+        assert_eq!(
+            s.bounds(),
+            Bounds::from_corners(point(px(5.), px(5.)), point(px(15.), px(25.)))
+        );
+
+        s.transform(
+            s.bounds(),
+            Bounds::from_corners(point(px(0.), px(0.)), point(px(20.), px(40.))),
+        );
+        assert_eq!(
+            s.points,
+            vec![point(px(0.), px(0.)), point(px(20.), px(40.))]
+        );
+    }
+
+    #[test]
+    fn test_smooth_points() {
+        let points = [(0., 0.), (4., 0.), (4., 4.)].map(|(x, y)| point(px(x), px(y)));
+        let smoothed = smooth_points(&points, 1);
+        assert_eq!(smoothed.len(), 6);
+        assert_eq!(smoothed[0], points[0]);
+        assert_eq!(smoothed[1], point(px(1.), px(0.)));
+        assert_eq!(smoothed[5], points[2]);
+    }
+
+    #[test]
+    fn test_apply_change_and_json() {
+        let mut scene = Scene::default();
+        let rect = shape(0, ShapeKind::Rect, &[(0., 0.), (10., 10.)]);
+        let mut moved = rect.clone();
+        moved.translate(point(px(10.), px(0.)));
+
+        let add = SceneChange::new(0, None, Some(rect.clone()));
+        let update = SceneChange::new(0, Some(rect.clone()), Some(moved.clone()));
+        scene.apply(&add, false);
+        scene.apply(&update, false);
+        assert_eq!(scene.shapes, vec![moved.clone()]);
+
+        let json = scene.to_json().unwrap();
+        assert_eq!(Scene::from_json(&json).unwrap(), scene);
+
+        scene.apply(&update, true);
+        assert_eq!(scene.shapes, vec![rect]);
+        scene.apply(&add, true);
+        assert!(scene.shapes.is_empty());
+    }
+}